@@ -0,0 +1,56 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use spin::Mutex;
+
+use wie_backend::AudioSink;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AudioSinkCall {
+    PlayWave { channel: u8, sampling_rate: u32, wave_data: Vec<i16> },
+    MidiNoteOn { channel_id: u8, note: u8, velocity: u8 },
+    MidiNoteOff { channel_id: u8, note: u8, velocity: u8 },
+    MidiProgramChange { channel_id: u8, program: u8 },
+    MidiControlChange { channel_id: u8, control: u8, value: u8 },
+}
+
+// records all calls, clones share the record
+#[derive(Clone, Default)]
+pub struct TestAudioSink {
+    calls: Arc<Mutex<Vec<AudioSinkCall>>>,
+}
+
+impl TestAudioSink {
+    pub fn calls(&self) -> Vec<AudioSinkCall> {
+        self.calls.lock().clone()
+    }
+
+    fn record(&self, call: AudioSinkCall) {
+        self.calls.lock().push(call);
+    }
+}
+
+impl AudioSink for TestAudioSink {
+    fn play_wave(&self, channel: u8, sampling_rate: u32, wave_data: &[i16]) {
+        self.record(AudioSinkCall::PlayWave {
+            channel,
+            sampling_rate,
+            wave_data: wave_data.to_vec(),
+        })
+    }
+
+    fn midi_note_on(&self, channel_id: u8, note: u8, velocity: u8) {
+        self.record(AudioSinkCall::MidiNoteOn { channel_id, note, velocity })
+    }
+
+    fn midi_note_off(&self, channel_id: u8, note: u8, velocity: u8) {
+        self.record(AudioSinkCall::MidiNoteOff { channel_id, note, velocity })
+    }
+
+    fn midi_program_change(&self, channel_id: u8, program: u8) {
+        self.record(AudioSinkCall::MidiProgramChange { channel_id, program })
+    }
+
+    fn midi_control_change(&self, channel_id: u8, control: u8, value: u8) {
+        self.record(AudioSinkCall::MidiControlChange { channel_id, control, value })
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use spin::Mutex;
+
+use wie_backend::{Database, DatabaseRepository, Instant, RecordId, System};
+
+use crate::platform::test_now;
+
+// record stores kept in memory, keyed by (app id, name)
+#[derive(Clone, Default)]
+pub struct InMemoryDatabaseRepository {
+    stores: Arc<Mutex<BTreeMap<(String, String), Arc<Mutex<InMemoryStore>>>>>,
+}
+
+impl InMemoryDatabaseRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl DatabaseRepository for InMemoryDatabaseRepository {
+    async fn open(&self, _system: &System, name: &str, app_id: &str) -> Option<Box<dyn Database>> {
+        let store = self
+            .stores
+            .lock()
+            .entry((app_id.to_owned(), name.to_owned()))
+            .or_insert_with(|| Arc::new(Mutex::new(InMemoryStore::new())))
+            .clone();
+
+        Some(Box::new(InMemoryDatabase { store }))
+    }
+
+    async fn exists(&self, _system: &System, name: &str, app_id: &str) -> bool {
+        self.stores.lock().contains_key(&(app_id.to_owned(), name.to_owned()))
+    }
+
+    async fn list(&self, _system: &System, app_id: &str) -> Vec<String> {
+        self.stores
+            .lock()
+            .keys()
+            .filter(|(x, _)| x == app_id)
+            .map(|(_, name)| name.to_string())
+            .collect()
+    }
+
+    async fn delete(&self, _system: &System, name: &str, app_id: &str) -> bool {
+        self.stores.lock().remove(&(app_id.to_owned(), name.to_owned())).is_some()
+    }
+
+    async fn rename(&self, _system: &System, name: &str, new_name: &str, app_id: &str) -> bool {
+        let mut stores = self.stores.lock();
+
+        let new_key = (app_id.to_owned(), new_name.to_owned());
+        if stores.contains_key(&new_key) {
+            return false;
+        }
+
+        let Some(store) = stores.remove(&(app_id.to_owned(), name.to_owned())) else {
+            return false;
+        };
+        stores.insert(new_key, store);
+
+        true
+    }
+}
+
+struct InMemoryStore {
+    next_id: RecordId,
+    version: u32,
+    last_modified: Instant,
+    records: BTreeMap<RecordId, Vec<u8>>,
+}
+
+impl InMemoryStore {
+    fn new() -> Self {
+        Self {
+            next_id: 1, // XXX midp requires first record to be 1
+            version: 0,
+            last_modified: test_now(),
+            records: BTreeMap::new(),
+        }
+    }
+
+    fn touch(&mut self) {
+        self.version += 1;
+        self.last_modified = test_now();
+    }
+}
+
+pub struct InMemoryDatabase {
+    store: Arc<Mutex<InMemoryStore>>,
+}
+
+#[async_trait::async_trait]
+impl Database for InMemoryDatabase {
+    async fn next_id(&self) -> RecordId {
+        self.store.lock().next_id
+    }
+
+    async fn add(&mut self, data: &[u8]) -> Option<RecordId> {
+        let mut store = self.store.lock();
+
+        let id = store.next_id;
+        store.next_id += 1;
+        store.records.insert(id, data.to_vec());
+        store.touch();
+
+        Some(id)
+    }
+
+    async fn get(&self, id: RecordId) -> Option<Vec<u8>> {
+        self.store.lock().records.get(&id).cloned()
+    }
+
+    async fn set(&mut self, id: RecordId, data: &[u8]) -> bool {
+        let mut store = self.store.lock();
+
+        store.records.insert(id, data.to_vec());
+        store.next_id = store.next_id.max(id + 1);
+        store.touch();
+
+        true
+    }
+
+    async fn delete(&mut self, id: RecordId) -> bool {
+        let mut store = self.store.lock();
+
+        if store.records.remove(&id).is_none() {
+            return false;
+        }
+        store.touch();
+
+        true
+    }
+
+    async fn get_record_ids(&self) -> Vec<RecordId> {
+        self.store.lock().records.keys().cloned().collect()
+    }
+
+    async fn get_record_size(&self, id: RecordId) -> Option<usize> {
+        self.store.lock().records.get(&id).map(|x| x.len())
+    }
+
+    async fn count(&self) -> usize {
+        self.store.lock().records.len()
+    }
+
+    async fn size(&self) -> usize {
+        self.store.lock().records.values().map(|x| x.len()).sum()
+    }
+
+    async fn version(&self) -> u32 {
+        self.store.lock().version
+    }
+
+    async fn last_modified(&self) -> Instant {
+        self.store.lock().last_modified
+    }
+}
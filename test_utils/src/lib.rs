@@ -1,10 +1,18 @@
 #![no_std]
 extern crate alloc;
 
+mod audio_sink;
+mod database;
 mod jvm;
+mod network;
 mod platform;
+mod screen;
 
 pub use self::{
-    jvm::run_jvm_test,
+    audio_sink::{AudioSinkCall, TestAudioSink},
+    database::{InMemoryDatabase, InMemoryDatabaseRepository},
+    jvm::{run_jvm_test, run_jvm_test_with_platform},
+    network::{TestListener, TestNetwork, TestTcpSocket, TestUdpSocket, serve},
     platform::{TestPlatform, TestPlatformEvent},
+    screen::{TestFrame, TestScreen},
 };
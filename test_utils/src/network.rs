@@ -0,0 +1,301 @@
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
+    future::{Future, poll_fn},
+    net::{Ipv4Addr, SocketAddrV4},
+    pin::pin,
+    task::{Context, Poll},
+};
+
+use spin::Mutex;
+
+use wie_backend::{Network, NetworkError, NetworkResult, TcpSocket, UdpSocket};
+
+const LOCAL_ADDR: Ipv4Addr = Ipv4Addr::new(10, 0, 0, 1);
+const FIRST_EPHEMERAL_PORT: u16 = 49152;
+
+type Datagrams = Arc<Mutex<VecDeque<(Vec<u8>, SocketAddrV4)>>>;
+
+#[derive(Default)]
+struct TestNetworkInner {
+    hosts: BTreeMap<String, Ipv4Addr>,
+    listeners: BTreeMap<SocketAddrV4, VecDeque<TestTcpSocket>>,
+    udp_sockets: BTreeMap<SocketAddrV4, Datagrams>,
+    last_port: u16,
+}
+
+impl TestNetworkInner {
+    fn allocate_port(&mut self) -> u16 {
+        self.last_port = self.last_port.max(FIRST_EPHEMERAL_PORT - 1) + 1;
+
+        self.last_port
+    }
+}
+
+// in-memory network, to run stand-in servers in tests.
+// tests can `listen` and `accept` connections from the app, or bind udp sockets with `Network::bind_udp`.
+#[derive(Clone, Default)]
+pub struct TestNetwork {
+    inner: Arc<Mutex<TestNetworkInner>>,
+}
+
+impl TestNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_host(&self, name: &str, addr: Ipv4Addr) {
+        self.inner.lock().hosts.insert(name.to_owned(), addr);
+    }
+
+    pub fn listen(&self, addr: SocketAddrV4) -> TestListener {
+        self.inner.lock().listeners.insert(addr, VecDeque::new());
+
+        TestListener { network: self.clone(), addr }
+    }
+}
+
+#[async_trait::async_trait]
+impl Network for TestNetwork {
+    async fn resolve(&self, host: &str) -> NetworkResult<Vec<Ipv4Addr>> {
+        if let Ok(addr) = host.parse() {
+            return Ok(vec![addr]);
+        }
+
+        self.inner.lock().hosts.get(host).map(|x| vec![*x]).ok_or(NetworkError::HostNotFound)
+    }
+
+    async fn connect_tcp(&self, addr: SocketAddrV4) -> NetworkResult<Box<dyn TcpSocket>> {
+        let mut inner = self.inner.lock();
+
+        let local_addr = SocketAddrV4::new(LOCAL_ADDR, inner.allocate_port());
+        let pending = inner.listeners.get_mut(&addr).ok_or(NetworkError::ConnectionRefused)?;
+
+        let (client, server) = TestTcpSocket::pair(local_addr, addr);
+        pending.push_back(server);
+
+        Ok(Box::new(client))
+    }
+
+    fn bind_udp(&self, addr: SocketAddrV4) -> NetworkResult<Box<dyn UdpSocket>> {
+        let mut inner = self.inner.lock();
+
+        let addr = if addr.port() == 0 {
+            SocketAddrV4::new(LOCAL_ADDR, inner.allocate_port())
+        } else {
+            addr
+        };
+        if inner.udp_sockets.contains_key(&addr) {
+            return Err(NetworkError::AddressInUse);
+        }
+
+        let datagrams = Datagrams::default();
+        inner.udp_sockets.insert(addr, datagrams.clone());
+
+        Ok(Box::new(TestUdpSocket {
+            network: self.clone(),
+            addr,
+            datagrams,
+        }))
+    }
+}
+
+pub struct TestListener {
+    network: TestNetwork,
+    addr: SocketAddrV4,
+}
+
+impl TestListener {
+    // returns pending connection without waiting, as tests drive the system by ticking it
+    pub fn accept(&self) -> Option<TestTcpSocket> {
+        self.network.inner.lock().listeners.get_mut(&self.addr)?.pop_front()
+    }
+
+    // waits for connection, for servers running with `serve`
+    pub async fn accept_async(&self) -> TestTcpSocket {
+        poll_fn(|cx| match self.accept() {
+            Some(x) => Poll::Ready(x),
+            None => pending(cx),
+        })
+        .await
+    }
+}
+
+impl Drop for TestListener {
+    fn drop(&mut self) {
+        self.network.inner.lock().listeners.remove(&self.addr);
+    }
+}
+
+#[derive(Default)]
+struct Pipe {
+    data: VecDeque<u8>,
+    closed: bool,
+}
+
+pub struct TestTcpSocket {
+    local_addr: SocketAddrV4,
+    peer_addr: SocketAddrV4,
+    incoming: Arc<Mutex<Pipe>>,
+    outgoing: Arc<Mutex<Pipe>>,
+}
+
+impl TestTcpSocket {
+    fn pair(client_addr: SocketAddrV4, server_addr: SocketAddrV4) -> (Self, Self) {
+        let client_to_server = Arc::new(Mutex::new(Pipe::default()));
+        let server_to_client = Arc::new(Mutex::new(Pipe::default()));
+
+        (
+            Self {
+                local_addr: client_addr,
+                peer_addr: server_addr,
+                incoming: server_to_client.clone(),
+                outgoing: client_to_server.clone(),
+            },
+            Self {
+                local_addr: server_addr,
+                peer_addr: client_addr,
+                incoming: client_to_server,
+                outgoing: server_to_client,
+            },
+        )
+    }
+
+    pub fn local_addr(&self) -> SocketAddrV4 {
+        self.local_addr
+    }
+
+    pub fn peer_addr(&self) -> SocketAddrV4 {
+        self.peer_addr
+    }
+
+    // reads all data received so far without waiting
+    pub fn read_available(&self) -> Vec<u8> {
+        self.incoming.lock().data.drain(..).collect()
+    }
+
+    pub fn is_peer_closed(&self) -> bool {
+        let incoming = self.incoming.lock();
+
+        incoming.closed && incoming.data.is_empty()
+    }
+}
+
+#[async_trait::async_trait]
+impl TcpSocket for TestTcpSocket {
+    async fn read(&self, buf: &mut [u8]) -> NetworkResult<usize> {
+        poll_fn(|cx| {
+            let mut incoming = self.incoming.lock();
+            if incoming.data.is_empty() && !incoming.closed {
+                return pending(cx);
+            }
+
+            let length = buf.len().min(incoming.data.len());
+            for (dest, src) in buf.iter_mut().zip(incoming.data.drain(..length)) {
+                *dest = src;
+            }
+
+            Poll::Ready(Ok(length))
+        })
+        .await
+    }
+
+    async fn write(&self, buf: &[u8]) -> NetworkResult<usize> {
+        let mut outgoing = self.outgoing.lock();
+        if outgoing.closed {
+            return Err(NetworkError::ConnectionReset);
+        }
+        outgoing.data.extend(buf);
+
+        Ok(buf.len())
+    }
+
+    fn shutdown(&self) {
+        self.outgoing.lock().closed = true;
+        self.incoming.lock().closed = true;
+    }
+}
+
+pub struct TestUdpSocket {
+    network: TestNetwork,
+    addr: SocketAddrV4,
+    datagrams: Datagrams,
+}
+
+#[async_trait::async_trait]
+impl UdpSocket for TestUdpSocket {
+    async fn send_to(&self, buf: &[u8], addr: SocketAddrV4) -> NetworkResult<usize> {
+        // datagrams to unbound address are dropped silently, like on real network
+        if let Some(datagrams) = self.network.inner.lock().udp_sockets.get(&addr) {
+            datagrams.lock().push_back((buf.to_vec(), self.addr));
+        }
+
+        Ok(buf.len())
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> NetworkResult<(usize, SocketAddrV4)> {
+        poll_fn(|cx| {
+            let Some((data, addr)) = self.datagrams.lock().pop_front() else {
+                return pending(cx);
+            };
+
+            let length = buf.len().min(data.len());
+            buf[..length].copy_from_slice(&data[..length]);
+
+            Poll::Ready(Ok((length, addr)))
+        })
+        .await
+    }
+
+    fn local_addr(&self) -> SocketAddrV4 {
+        self.addr
+    }
+}
+
+impl Drop for TestUdpSocket {
+    fn drop(&mut self) {
+        self.network.inner.lock().udp_sockets.remove(&self.addr);
+    }
+}
+
+// runs stand-in server alongside the app code in the same task, until both are done.
+// jvm tests run inside a single task, so the server can't be spawned separately
+pub async fn serve<C, S>(client: C, server: S) -> C::Output
+where
+    C: Future,
+    S: Future<Output = ()>,
+{
+    let mut client = pin!(client);
+    let mut server = pin!(server);
+    let mut client_output = None;
+    let mut server_done = false;
+
+    poll_fn(|cx| {
+        if !server_done {
+            server_done = server.as_mut().poll(cx).is_ready();
+        }
+        if client_output.is_none()
+            && let Poll::Ready(x) = client.as_mut().poll(cx)
+        {
+            client_output = Some(x);
+        }
+
+        match client_output.take_if(|_| server_done) {
+            Some(x) => Poll::Ready(x),
+            None => pending(cx),
+        }
+    })
+    .await
+}
+
+// like platform sockets, we don't wake the task. socket tasks poll us again after a backoff interval
+fn pending<T>(_: &mut Context<'_>) -> Poll<T> {
+    Poll::Pending
+}
@@ -1,28 +1,37 @@
 use alloc::{boxed::Box, vec::Vec};
 use core::sync::atomic::{AtomicU64, Ordering};
 
-use wie_backend::{AudioSink, DatabaseRepository, Instant, Platform, Screen, canvas::Image};
-use wie_util::Result;
+use wie_backend::{AudioSink, DatabaseRepository, DeviceProfile, Instant, Network, Platform, Screen};
+
+use crate::{InMemoryDatabaseRepository, TestAudioSink, TestNetwork, TestScreen};
 
 static TEST_EPOCH: AtomicU64 = AtomicU64::new(0);
 
+// advances on each call, so that timestamps are distinct
+pub(crate) fn test_now() -> Instant {
+    let epoch = TEST_EPOCH.fetch_add(8, Ordering::SeqCst);
+    Instant::from_epoch_millis(epoch) // TODO
+}
+
 pub enum TestPlatformEvent {
     Stdout(Vec<u8>),
     Exit,
 }
 
+// screen, audio sink, database repository and network can be cloned before passing platform to `System`, to inspect them in tests
 #[derive(Default)]
 pub struct TestPlatform {
     screen: TestScreen,
+    device_profile: DeviceProfile,
+    audio_sink: TestAudioSink,
+    database_repository: InMemoryDatabaseRepository,
+    network: TestNetwork,
     event_handler: Option<Box<dyn Fn(TestPlatformEvent) + Sync + Send>>,
 }
 
 impl TestPlatform {
     pub fn new() -> Self {
-        Self {
-            screen: TestScreen,
-            event_handler: None,
-        }
+        Self::default()
     }
 
     pub fn with_event_handler<T>(event_handler: T) -> Self
@@ -30,84 +39,76 @@ impl TestPlatform {
         T: Fn(TestPlatformEvent) + Sync + Send + 'static,
     {
         Self {
-            screen: TestScreen,
             event_handler: Some(Box::new(event_handler)),
+            ..Default::default()
         }
     }
-}
 
-impl Platform for TestPlatform {
-    fn screen(&self) -> &dyn Screen {
-        &self.screen
-    }
+    pub fn with_screen_size(mut self, width: u32, height: u32) -> Self {
+        self.screen = TestScreen::new(width, height);
 
-    fn now(&self) -> Instant {
-        let epoch = TEST_EPOCH.fetch_add(8, Ordering::SeqCst);
-        Instant::from_epoch_millis(epoch) // TODO
+        self
     }
 
-    fn database_repository(&self) -> &dyn DatabaseRepository {
-        todo!()
+    pub fn with_device_profile(mut self, device_profile: DeviceProfile) -> Self {
+        self.device_profile = device_profile;
+
+        self
     }
 
-    fn audio_sink(&self) -> Box<dyn AudioSink> {
-        Box::new(TestAudioSink)
+    pub fn test_screen(&self) -> TestScreen {
+        self.screen.clone()
     }
 
-    fn write_stdout(&self, buf: &[u8]) {
-        if let Some(event_handler) = &self.event_handler {
-            (event_handler)(TestPlatformEvent::Stdout(buf.to_vec()))
-        }
+    pub fn test_audio_sink(&self) -> TestAudioSink {
+        self.audio_sink.clone()
     }
 
-    fn write_stderr(&self, _buf: &[u8]) {}
+    pub fn test_database_repository(&self) -> InMemoryDatabaseRepository {
+        self.database_repository.clone()
+    }
 
-    fn exit(&self) {
-        if let Some(event_handler) = &self.event_handler {
-            (event_handler)(TestPlatformEvent::Exit);
-        }
+    pub fn test_network(&self) -> TestNetwork {
+        self.network.clone()
     }
 }
 
-pub struct TestAudioSink;
-
-impl AudioSink for TestAudioSink {
-    fn play_wave(&self, _channel: u8, _sampling_rate: u32, _wave_data: &[i16]) {
-        todo!()
+impl Platform for TestPlatform {
+    fn screen(&self) -> &dyn Screen {
+        &self.screen
     }
 
-    fn midi_note_on(&self, _channel_id: u8, _note: u8, _velocity: u8) {
-        todo!()
+    fn device_profile(&self) -> &DeviceProfile {
+        &self.device_profile
     }
 
-    fn midi_note_off(&self, _channel_id: u8, _note: u8, _velocity: u8) {
-        todo!()
+    fn now(&self) -> Instant {
+        test_now()
     }
 
-    fn midi_program_change(&self, _channel_id: u8, _program: u8) {
-        todo!()
+    fn database_repository(&self) -> &dyn DatabaseRepository {
+        &self.database_repository
     }
 
-    fn midi_control_change(&self, _channel_id: u8, _control: u8, _value: u8) {
-        todo!()
+    fn audio_sink(&self) -> Box<dyn AudioSink> {
+        Box::new(self.audio_sink.clone())
     }
-}
-
-#[derive(Default)]
-pub struct TestScreen;
 
-impl Screen for TestScreen {
-    fn request_redraw(&self) -> Result<()> {
-        Ok(())
+    fn network(&self) -> &dyn Network {
+        &self.network
     }
 
-    fn paint(&self, _image: &dyn Image) {}
-
-    fn width(&self) -> u32 {
-        320
+    fn write_stdout(&self, buf: &[u8]) {
+        if let Some(event_handler) = &self.event_handler {
+            (event_handler)(TestPlatformEvent::Stdout(buf.to_vec()))
+        }
     }
 
-    fn height(&self) -> u32 {
-        240
+    fn write_stderr(&self, _buf: &[u8]) {}
+
+    fn exit(&self) {
+        if let Some(event_handler) = &self.event_handler {
+            (event_handler)(TestPlatformEvent::Exit);
+        }
     }
 }
@@ -0,0 +1,95 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use spin::Mutex;
+
+use wie_backend::{
+    Screen,
+    canvas::{Color, Image},
+};
+use wie_util::Result;
+
+#[derive(Clone)]
+pub struct TestFrame {
+    pub width: u32,
+    pub height: u32,
+    pub colors: Vec<Color>,
+}
+
+impl TestFrame {
+    pub fn pixel(&self, x: u32, y: u32) -> Color {
+        self.colors[(y * self.width + x) as usize]
+    }
+}
+
+#[derive(Default)]
+struct TestScreenState {
+    last_frame: Option<TestFrame>,
+    frame_count: usize,
+    text_input: bool,
+}
+
+// keeps the last painted frame, clones share the state
+#[derive(Clone)]
+pub struct TestScreen {
+    width: u32,
+    height: u32,
+    state: Arc<Mutex<TestScreenState>>,
+}
+
+impl TestScreen {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            state: Arc::new(Mutex::new(TestScreenState::default())),
+        }
+    }
+
+    pub fn last_frame(&self) -> Option<TestFrame> {
+        self.state.lock().last_frame.clone()
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.state.lock().frame_count
+    }
+
+    pub fn text_input(&self) -> bool {
+        self.state.lock().text_input
+    }
+}
+
+impl Default for TestScreen {
+    fn default() -> Self {
+        Self::new(320, 240)
+    }
+}
+
+impl Screen for TestScreen {
+    fn request_redraw(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn paint(&self, image: &dyn Image) {
+        let frame = TestFrame {
+            width: image.width(),
+            height: image.height(),
+            colors: image.colors(),
+        };
+
+        let mut state = self.state.lock();
+        state.last_frame = Some(frame);
+        state.frame_count += 1;
+    }
+
+    fn width(&self) -> u32 {
+        self.width
+    }
+
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn set_text_input(&self, enabled: bool) {
+        self.state.lock().text_input = enabled;
+    }
+}
@@ -1,3 +1,5 @@
+mod animation;
+mod indexed;
 mod lbmp;
 
 use alloc::{borrow::Cow, boxed::Box, string::ToString, vec, vec::Vec};
@@ -12,6 +14,11 @@ use wie_util::{Result, WieError};
 
 use self::lbmp::decode_lbmp;
 
+pub use self::{
+    animation::{Animation, AnimationFrame, decode_animation},
+    indexed::{IndexedImage, decode_indexed_image},
+};
+
 lazy_static::lazy_static! {
     static ref FONT: FontRef<'static> = FontRef::try_from_slice(include_bytes!("../../fonts/neodgm.ttf")).unwrap();
 }
@@ -37,6 +44,10 @@ pub trait Image: Send {
     fn get_pixel(&self, x: i32, y: i32) -> Color;
     fn raw(&self) -> Cow<'_, [u8]>;
     fn colors(&self) -> Vec<Color>;
+
+    fn as_indexed(&self) -> Option<&IndexedImage> {
+        None
+    }
 }
 
 pub trait ImageBuffer: Send {
@@ -296,6 +307,37 @@ where
 
         self.put_pixel(x, y, computed_color);
     }
+
+    // resolve palette indices on the fly, skipping transparent pixels without blending
+    #[allow(clippy::too_many_arguments)]
+    fn draw_indexed(&mut self, dx: i32, dy: i32, w: u32, h: u32, src: &IndexedImage, sx: i32, sy: i32, clip: Clip) {
+        let x_start = 0.max(-sx).max(-dx).max(clip.x - dx);
+        let x_end = (w as i32)
+            .min(src.width() as i32 - sx)
+            .min(self.image_buffer.width() as i32 - dx)
+            .min(clip.x + clip.width as i32 - dx);
+        let y_start = 0.max(-sy).max(-dy).max(clip.y - dy);
+        let y_end = (h as i32)
+            .min(src.height() as i32 - sy)
+            .min(self.image_buffer.height() as i32 - dy)
+            .min(clip.y + clip.height as i32 - dy);
+
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                let index = src.index(sx + x, sy + y);
+                if src.transparent_index() == Some(index) {
+                    continue;
+                }
+
+                let color = src.resolve(index);
+                if color.a == 0xff {
+                    self.put_pixel(dx + x, dy + y, color);
+                } else if color.a != 0 {
+                    self.blend_pixel(dx + x, dy + y, color);
+                }
+            }
+        }
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -308,6 +350,10 @@ where
     }
 
     fn draw(&mut self, dx: i32, dy: i32, w: u32, h: u32, src: &dyn Image, sx: i32, sy: i32, clip: Clip) {
+        if let Some(src) = src.as_indexed() {
+            return self.draw_indexed(dx, dy, w, h, src, sx, sy, clip);
+        }
+
         for y in 0..(h as i32) {
             for x in 0..(w as i32) {
                 if sx + x < 0 || sy + y < 0 || sx + x >= src.width() as i32 || sy + y >= src.height() as i32 {
@@ -435,9 +481,29 @@ where
         }
     }
 
-    fn draw_arc(&mut self, x: i32, y: i32, w: u32, h: u32, _start_angle: u32, _arc_angle: u32, color: Color, clip: Clip) {
-        // TODO unimplemented
-        self.draw_rect(x, y, w, h, color, clip);
+    fn draw_arc(&mut self, x: i32, y: i32, w: u32, h: u32, start_angle: u32, arc_angle: u32, color: Color, clip: Clip) {
+        extern crate std; // XXX
+
+        let (start, arc) = normalize_arc(start_angle as i32, arc_angle as i32);
+        let (rx, ry) = (w as f32 / 2.0, h as f32 / 2.0);
+        let (cx, cy) = (x as f32 + rx, y as f32 + ry);
+
+        // parametric angle of ellipse matches the angle relative to the bounding box
+        let steps = ((w.max(h) as f32) * arc as f32 / 90.0).max(1.0) as i32;
+        for i in 0..=steps {
+            let theta = (start as f32 + arc as f32 * i as f32 / steps as f32).to_radians();
+            let px = (cx + rx * theta.cos()).round() as i32;
+            let py = (cy - ry * theta.sin()).round() as i32;
+
+            if px < clip.x || px >= clip.x + clip.width as i32 || py < clip.y || py >= clip.y + clip.height as i32 {
+                continue;
+            }
+            if px < 0 || py < 0 || px >= self.image_buffer.width() as i32 || py >= self.image_buffer.height() as i32 {
+                continue;
+            }
+
+            self.put_pixel(px, py, color);
+        }
     }
 
     fn draw_round_rect(&mut self, x: i32, y: i32, w: u32, h: u32, _arc_width: u32, _arc_height: u32, color: Color, clip: Clip) {
@@ -460,9 +526,44 @@ where
         }
     }
 
-    fn fill_arc(&mut self, x: i32, y: i32, w: u32, h: u32, _start_angle: u32, _arc_angle: u32, color: Color, clip: Clip) {
-        // TODO unimplemented
-        self.fill_rect(x, y, w, h, color, clip);
+    fn fill_arc(&mut self, x: i32, y: i32, w: u32, h: u32, start_angle: u32, arc_angle: u32, color: Color, clip: Clip) {
+        extern crate std; // XXX
+
+        if w == 0 || h == 0 {
+            return;
+        }
+
+        let (start, arc) = normalize_arc(start_angle as i32, arc_angle as i32);
+        let (rx, ry) = (w as f32 / 2.0, h as f32 / 2.0);
+
+        for py in y..y + (h as i32) {
+            for px in x..x + (w as i32) {
+                if px < 0 || py < 0 || px >= self.image_buffer.width() as i32 || py >= self.image_buffer.height() as i32 {
+                    continue;
+                }
+                if px < clip.x || px >= clip.x + clip.width as i32 || py < clip.y || py >= clip.y + clip.height as i32 {
+                    continue;
+                }
+
+                // normalize to unit circle, so angles are relative to the bounding box
+                let nx = (px - x) as f32 + 0.5 - rx;
+                let ny = ry - ((py - y) as f32 + 0.5);
+                let (nx, ny) = (nx / rx, ny / ry);
+                if nx * nx + ny * ny > 1.0 {
+                    continue;
+                }
+
+                if arc < 360 {
+                    let angle = ny.atan2(nx).to_degrees();
+                    let delta = (angle - start as f32).rem_euclid(360.0);
+                    if delta > arc as f32 {
+                        continue;
+                    }
+                }
+
+                self.put_pixel(px, py, color);
+            }
+        }
     }
 
     fn fill_round_rect(&mut self, x: i32, y: i32, w: u32, h: u32, _arc_width: u32, _arc_height: u32, color: Color, clip: Clip) {
@@ -475,6 +576,17 @@ where
     }
 }
 
+// returns start angle and positive arc angle, both in degrees
+fn normalize_arc(start_angle: i32, arc_angle: i32) -> (i32, i32) {
+    let (start, arc) = if arc_angle < 0 {
+        (start_angle + arc_angle, -arc_angle)
+    } else {
+        (start_angle, arc_angle)
+    };
+
+    (start.rem_euclid(360), arc.min(360))
+}
+
 pub struct Clip {
     pub x: i32,
     pub y: i32,
@@ -529,11 +641,22 @@ pub fn string_width(string: &str, pt_size: f32) -> f32 {
     string.chars().map(|c| font.h_advance(font.scaled_glyph(c).id)).sum::<f32>()
 }
 
+pub fn font_ascent(pt_size: f32) -> f32 {
+    FONT.as_scaled(FONT.pt_to_px_scale(pt_size).unwrap()).ascent()
+}
+
+// descent is returned as positive distance below baseline
+pub fn font_descent(pt_size: f32) -> f32 {
+    -FONT.as_scaled(FONT.pt_to_px_scale(pt_size).unwrap()).descent()
+}
+
 #[cfg(test)]
 mod tests {
     use wie_util::Result;
 
-    use crate::canvas::{Clip, Image, ImageBufferCanvas};
+    use alloc::vec;
+
+    use crate::canvas::{Clip, Image, ImageBufferCanvas, IndexedImage, decode_indexed_image};
 
     use super::{ArgbPixel, Canvas, Color, VecImageBuffer};
 
@@ -563,4 +686,110 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_arc() -> Result<()> {
+        let red = Color { a: 255, r: 255, g: 0, b: 0 };
+        let mut canvas = ImageBufferCanvas::new(VecImageBuffer::<ArgbPixel>::new(10, 10));
+        let clip = Clip {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+        };
+
+        // upper right quarter
+        canvas.fill_arc(0, 0, 10, 10, 0, 90, red, clip);
+        assert_eq!(canvas.image().get_pixel(7, 2).r, 255);
+        assert_eq!(canvas.image().get_pixel(2, 7).a, 0);
+        assert_eq!(canvas.image().get_pixel(9, 0).a, 0);
+
+        // negative arc angle goes clockwise
+        let clip = Clip {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+        };
+        canvas.fill_arc(0, 0, 10, 10, 0, -90i32 as u32, red, clip);
+        assert_eq!(canvas.image().get_pixel(7, 7).r, 255);
+        assert_eq!(canvas.image().get_pixel(2, 7).a, 0);
+
+        let clip = Clip {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+        };
+        canvas.draw_arc(0, 0, 8, 8, 90, 180, red, clip);
+        assert_eq!(canvas.image().get_pixel(0, 4).r, 255);
+        assert_eq!(canvas.image().get_pixel(4, 0).r, 255);
+        assert_eq!(canvas.image().get_pixel(4, 4).a, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_indexed_palette_swap() -> Result<()> {
+        let red = Color { a: 255, r: 255, g: 0, b: 0 };
+        let blue = Color { a: 255, r: 0, g: 0, b: 255 };
+
+        // 2x2 image, index 0 is transparent
+        let mut indexed = IndexedImage::new(2, 2, vec![0, 1, 1, 0], vec![red, red], Some(0));
+
+        let mut canvas = ImageBufferCanvas::new(VecImageBuffer::<ArgbPixel>::new(2, 2));
+        let clip = Clip {
+            x: 0,
+            y: 0,
+            width: 2,
+            height: 2,
+        };
+        canvas.draw(0, 0, 2, 2, &indexed, 0, 0, clip);
+        assert_eq!(canvas.image().get_pixel(0, 0).a, 0);
+        assert_eq!(canvas.image().get_pixel(1, 0).r, 255);
+
+        indexed.set_palette(1, &[blue]);
+        let clip = Clip {
+            x: 0,
+            y: 0,
+            width: 2,
+            height: 2,
+        };
+        canvas.draw(0, 0, 2, 2, &indexed, 0, 0, clip);
+        assert_eq!(canvas.image().get_pixel(1, 0).r, 0);
+        assert_eq!(canvas.image().get_pixel(0, 1).b, 255);
+        assert_eq!(canvas.image().get_pixel(1, 1).a, 0);
+
+        // palette is capped to 256 entries
+        indexed.set_palette(255, &[blue, blue]);
+        assert_eq!(indexed.palette().len(), 256);
+        indexed.set_palette(usize::MAX, &[blue]);
+        assert_eq!(indexed.palette().len(), 256);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_broken_bmp() {
+        // 2x2 4bpp bmp with 2 color palette
+        let mut bmp = vec![0u8; 54 + 8 + 8];
+        bmp[0..2].copy_from_slice(b"BM");
+        bmp[10..14].copy_from_slice(&62u32.to_le_bytes());
+        bmp[14..18].copy_from_slice(&40u32.to_le_bytes());
+        bmp[18..22].copy_from_slice(&2u32.to_le_bytes());
+        bmp[22..26].copy_from_slice(&2u32.to_le_bytes());
+        bmp[28..30].copy_from_slice(&4u16.to_le_bytes());
+        bmp[46..50].copy_from_slice(&2u32.to_le_bytes());
+        bmp[62] = 0x10;
+
+        let image = decode_indexed_image(&bmp).unwrap().unwrap();
+        assert_eq!(image.indices(), [0, 0, 1, 0]);
+
+        // truncated pixel data, header and pixel offset
+        assert!(decode_indexed_image(&bmp[..66]).is_err());
+        assert!(decode_indexed_image(&bmp[..40]).is_err());
+
+        bmp[10..14].copy_from_slice(&0x1000u32.to_le_bytes());
+        assert!(decode_indexed_image(&bmp).is_err());
+    }
 }
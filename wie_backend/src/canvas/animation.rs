@@ -0,0 +1,154 @@
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+
+use bytemuck::pod_collect_to_vec;
+
+use wie_util::{Result, WieError};
+
+use crate::canvas::{ArgbPixel, Image, VecImageBuffer};
+
+pub struct AnimationFrame {
+    pub image: Box<dyn Image>,
+    pub delay: u32, // in milliseconds
+}
+
+pub struct Animation {
+    pub frames: Vec<AnimationFrame>,
+    pub loop_count: u32, // 0 means infinite
+}
+
+// returns None if image is not animated
+pub fn decode_animation(data: &[u8]) -> Result<Option<Animation>> {
+    if data.starts_with(b"GIF8") {
+        decode_gif_animation(data)
+    } else {
+        Ok(None)
+    }
+}
+
+fn decode_gif_animation(data: &[u8]) -> Result<Option<Animation>> {
+    extern crate std; // XXX
+
+    use std::io::Cursor;
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+
+    let mut decoder = options.read_info(Cursor::new(data)).map_err(|x| WieError::FatalError(x.to_string()))?;
+
+    let width = decoder.width() as u32;
+    let height = decoder.height() as u32;
+    let loop_count = match decoder.repeat() {
+        gif::Repeat::Infinite => 0,
+        gif::Repeat::Finite(x) => x as u32,
+    };
+
+    // frames only cover part of the screen, so we keep composited rgba screen and snapshot it after each frame
+    let mut screen = vec![0u8; (width * height * 4) as usize];
+    let mut frames = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().map_err(|x| WieError::FatalError(x.to_string()))? {
+        let previous = matches!(frame.dispose, gif::DisposalMethod::Previous).then(|| screen.clone());
+
+        let (left, top) = (frame.left as u32, frame.top as u32);
+        for y in 0..frame.height as u32 {
+            for x in 0..frame.width as u32 {
+                let (dx, dy) = (left + x, top + y);
+                if dx >= width || dy >= height {
+                    continue;
+                }
+
+                let src = ((y * frame.width as u32 + x) * 4) as usize;
+                if frame.buffer[src + 3] == 0 {
+                    continue;
+                }
+
+                let dst = ((dy * width + dx) * 4) as usize;
+                screen[dst..dst + 4].copy_from_slice(&frame.buffer[src..src + 4]);
+            }
+        }
+
+        let argb = screen.chunks(4).flat_map(|x| [x[2], x[1], x[0], x[3]]).collect::<Vec<_>>();
+        frames.push(AnimationFrame {
+            image: Box::new(VecImageBuffer::<ArgbPixel>::from_raw(width, height, pod_collect_to_vec(&argb))),
+            delay: frame.delay as u32 * 10,
+        });
+
+        match frame.dispose {
+            gif::DisposalMethod::Background => {
+                for y in top..(top + frame.height as u32).min(height) {
+                    for x in left..(left + frame.width as u32).min(width) {
+                        let dst = ((y * width + x) * 4) as usize;
+                        screen[dst..dst + 4].fill(0);
+                    }
+                }
+            }
+            gif::DisposalMethod::Previous => screen = previous.unwrap(),
+            _ => {}
+        }
+    }
+
+    if frames.len() < 2 {
+        return Ok(None);
+    }
+
+    Ok(Some(Animation { frames, loop_count }))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use wie_util::Result;
+
+    use super::decode_animation;
+
+    fn encode_gif(frames: &[(&[u8], u16)]) -> Vec<u8> {
+        let mut result = Vec::new();
+        {
+            // index 0: transparent, 1: red, 2: blue
+            let palette = [0, 0, 0, 255, 0, 0, 0, 0, 255];
+            let mut encoder = gif::Encoder::new(&mut result, 2, 2, &palette).unwrap();
+            encoder.set_repeat(gif::Repeat::Finite(3)).unwrap();
+
+            for (pixels, delay) in frames {
+                let mut frame = gif::Frame::from_indexed_pixels(2, 2, *pixels, Some(0));
+                frame.delay = *delay;
+                encoder.write_frame(&frame).unwrap();
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_decode_animation() -> Result<()> {
+        let data = encode_gif(&[(&[1, 1, 0, 0], 10), (&[0, 2, 2, 0], 20)]);
+
+        let animation = decode_animation(&data)?.unwrap();
+        assert_eq!(animation.loop_count, 3);
+        assert_eq!(animation.frames.len(), 2);
+        assert_eq!(animation.frames[0].delay, 100);
+        assert_eq!(animation.frames[1].delay, 200);
+
+        let first = &animation.frames[0].image;
+        assert_eq!(first.get_pixel(0, 0).r, 255);
+        assert_eq!(first.get_pixel(0, 1).a, 0);
+
+        // transparent pixels in second frame keep the pixels from first frame
+        let second = &animation.frames[1].image;
+        assert_eq!(second.get_pixel(0, 0).r, 255);
+        assert_eq!(second.get_pixel(1, 0).b, 255);
+        assert_eq!(second.get_pixel(0, 1).b, 255);
+        assert_eq!(second.get_pixel(1, 1).a, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_single_frame() -> Result<()> {
+        let data = encode_gif(&[(&[1, 1, 0, 0], 0)]);
+
+        assert!(decode_animation(&data)?.is_none());
+
+        Ok(())
+    }
+}
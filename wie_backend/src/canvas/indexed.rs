@@ -0,0 +1,327 @@
+use alloc::{borrow::Cow, format, string::ToString, vec, vec::Vec};
+
+use wie_util::{Result, WieError};
+
+use crate::canvas::{Color, Image, ImageBuffer};
+
+const MAX_PALETTE_SIZE: usize = 256;
+
+// 8bpp image which keeps palette, so palette can be swapped after loading
+
+pub struct IndexedImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+    palette: Vec<Color>,
+    transparent_index: Option<u8>,
+}
+
+impl IndexedImage {
+    pub fn new(width: u32, height: u32, data: Vec<u8>, palette: Vec<Color>, transparent_index: Option<u8>) -> Self {
+        Self {
+            width,
+            height,
+            data,
+            palette,
+            transparent_index,
+        }
+    }
+
+    pub fn indices(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn palette(&self) -> &[Color] {
+        &self.palette
+    }
+
+    pub fn transparent_index(&self) -> Option<u8> {
+        self.transparent_index
+    }
+
+    // entries past 8bpp index range are ignored
+    pub fn set_palette(&mut self, start: usize, colors: &[Color]) {
+        let end = start.saturating_add(colors.len()).min(MAX_PALETTE_SIZE);
+        if start >= end {
+            return;
+        }
+
+        if self.palette.len() < end {
+            self.palette.resize(end, Color { a: 0xff, r: 0, g: 0, b: 0 });
+        }
+
+        self.palette[start..end].copy_from_slice(&colors[..end - start]);
+    }
+
+    pub fn set_transparent_index(&mut self, transparent_index: Option<u8>) {
+        self.transparent_index = transparent_index;
+    }
+
+    pub fn index(&self, x: i32, y: i32) -> u8 {
+        self.data[((y as u32) * self.width + (x as u32)) as usize]
+    }
+
+    pub fn resolve(&self, index: u8) -> Color {
+        if self.transparent_index == Some(index) {
+            return Color { a: 0, r: 0, g: 0, b: 0 };
+        }
+
+        self.palette.get(index as usize).copied().unwrap_or(Color { a: 0xff, r: 0, g: 0, b: 0 })
+    }
+
+    fn nearest_index(&self, color: Color) -> u8 {
+        if color.a == 0
+            && let Some(x) = self.transparent_index
+        {
+            return x;
+        }
+
+        self.palette
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| self.transparent_index != Some(*i as u8))
+            .min_by_key(|(_, x)| {
+                let r = x.r as i32 - color.r as i32;
+                let g = x.g as i32 - color.g as i32;
+                let b = x.b as i32 - color.b as i32;
+
+                r * r + g * g + b * b
+            })
+            .map(|(i, _)| i as u8)
+            .unwrap_or(0)
+    }
+}
+
+impl Image for IndexedImage {
+    fn width(&self) -> u32 {
+        self.width
+    }
+
+    fn height(&self) -> u32 {
+        self.height
+    }
+
+    fn bytes_per_pixel(&self) -> u32 {
+        1
+    }
+
+    fn get_pixel(&self, x: i32, y: i32) -> Color {
+        self.resolve(self.index(x, y))
+    }
+
+    fn raw(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.data)
+    }
+
+    fn colors(&self) -> Vec<Color> {
+        self.data.iter().map(|&x| self.resolve(x)).collect()
+    }
+
+    fn as_indexed(&self) -> Option<&IndexedImage> {
+        Some(self)
+    }
+}
+
+impl ImageBuffer for IndexedImage {
+    fn put_pixel(&mut self, x: i32, y: i32, color: Color) {
+        if x < 0 || y < 0 || (x as u32) >= self.width || (y as u32) >= self.height {
+            return;
+        }
+
+        let index = self.nearest_index(color);
+
+        self.data[((y as u32) * self.width + (x as u32)) as usize] = index;
+    }
+
+    fn put_pixels(&mut self, x: i32, y: i32, width: u32, colors: &[Color]) {
+        for (i, color) in colors.iter().enumerate() {
+            let x = x + (i as i32 % (width as i32));
+            let y = y + (i as i32 / (width as i32));
+
+            self.put_pixel(x, y, *color);
+        }
+    }
+}
+
+// returns None if image is not palette based
+pub fn decode_indexed_image(data: &[u8]) -> Result<Option<IndexedImage>> {
+    if data.starts_with(b"\x89PNG") {
+        decode_png(data)
+    } else if data.starts_with(b"GIF8") {
+        decode_gif(data)
+    } else if data.starts_with(b"BM") {
+        decode_bmp(data)
+    } else {
+        Ok(None)
+    }
+}
+
+fn decode_png(data: &[u8]) -> Result<Option<IndexedImage>> {
+    extern crate std; // XXX
+
+    use std::io::Cursor;
+
+    let mut decoder = png::Decoder::new(Cursor::new(data));
+    decoder.set_transformations(png::Transformations::IDENTITY);
+
+    let mut reader = decoder.read_info().map_err(|x| WieError::FatalError(x.to_string()))?;
+    let info = reader.info();
+
+    if info.color_type != png::ColorType::Indexed {
+        return Ok(None);
+    }
+
+    let width = info.width;
+    let height = info.height;
+    let bit_depth = info.bit_depth as u32;
+
+    let mut palette = info.palette.as_ref().map(|x| palette_from_rgb(x)).unwrap_or_default();
+    if let Some(trns) = &info.trns {
+        for (color, &alpha) in palette.iter_mut().zip(trns.iter()) {
+            color.a = alpha;
+        }
+    }
+    let transparent_index = palette.iter().position(|x| x.a == 0).map(|x| x as u8);
+
+    let line_size = (width * bit_depth).div_ceil(8);
+    let mut buf = vec![0; (line_size * height) as usize];
+    let output = reader.next_frame(&mut buf).map_err(|x| WieError::FatalError(x.to_string()))?;
+
+    let data = unpack_rows(&buf, width, height, output.line_size as _, bit_depth, false)?;
+
+    Ok(Some(IndexedImage::new(width, height, data, palette, transparent_index)))
+}
+
+fn decode_gif(data: &[u8]) -> Result<Option<IndexedImage>> {
+    extern crate std; // XXX
+
+    use std::io::Cursor;
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::Indexed);
+
+    let mut decoder = options.read_info(Cursor::new(data)).map_err(|x| WieError::FatalError(x.to_string()))?;
+
+    let width = decoder.width() as u32;
+    let height = decoder.height() as u32;
+    let global_palette = decoder.global_palette().map(|x| x.to_vec());
+    let background = decoder.bg_color().unwrap_or(0) as u8;
+
+    let frame = decoder
+        .read_next_frame()
+        .map_err(|x| WieError::FatalError(x.to_string()))?
+        .ok_or_else(|| WieError::FatalError("Empty gif".into()))?;
+
+    let palette = frame
+        .palette
+        .as_ref()
+        .or(global_palette.as_ref())
+        .map(|x| palette_from_rgb(x))
+        .unwrap_or_default();
+
+    let fill = frame.transparent.unwrap_or(background);
+    let mut data = vec![fill; (width * height) as usize];
+    for y in 0..frame.height as u32 {
+        for x in 0..frame.width as u32 {
+            let (dx, dy) = (frame.left as u32 + x, frame.top as u32 + y);
+            if dx >= width || dy >= height {
+                continue;
+            }
+
+            data[(dy * width + dx) as usize] = frame.buffer[(y * frame.width as u32 + x) as usize];
+        }
+    }
+
+    Ok(Some(IndexedImage::new(width, height, data, palette, frame.transparent)))
+}
+
+fn decode_bmp(data: &[u8]) -> Result<Option<IndexedImage>> {
+    let invalid = || WieError::FatalError("Invalid bmp".into());
+
+    let read_u16 = |offset: usize| data.get(offset..offset + 2).map(|x| u16::from_le_bytes([x[0], x[1]])).ok_or_else(invalid);
+    let read_u32 = |offset: usize| {
+        data.get(offset..offset + 4)
+            .map(|x| u32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+            .ok_or_else(invalid)
+    };
+
+    let pixel_offset = read_u32(10)? as usize;
+    let header_size = read_u32(14)? as usize;
+    let width = read_u32(18)? as i32;
+    let height = read_u32(22)? as i32;
+    let bit_count = read_u16(28)? as u32;
+    let compression = read_u32(30)?;
+    let colors_used = read_u32(46)? as usize;
+
+    if bit_count > 8 {
+        return Ok(None);
+    }
+    if !matches!(bit_count, 1 | 2 | 4 | 8) || width <= 0 {
+        return Err(invalid());
+    }
+    if compression != 0 {
+        return Err(WieError::Unimplemented(format!("Unsupported bmp compression {compression}")));
+    }
+
+    let palette_len = if colors_used == 0 { 1 << bit_count } else { colors_used };
+    let palette = data
+        .get(14 + header_size..)
+        .ok_or_else(invalid)?
+        .chunks_exact(4)
+        .take(palette_len)
+        .map(|x| Color {
+            a: 0xff,
+            r: x[2],
+            g: x[1],
+            b: x[0],
+        })
+        .collect::<Vec<_>>();
+
+    let line_size = (width as u32 * bit_count).div_ceil(32) * 4;
+    let rows = unpack_rows(
+        data.get(pixel_offset..).ok_or_else(invalid)?,
+        width as u32,
+        height.unsigned_abs(),
+        line_size as _,
+        bit_count,
+        height > 0,
+    )?;
+
+    Ok(Some(IndexedImage::new(width as _, height.unsigned_abs(), rows, palette, None)))
+}
+
+fn palette_from_rgb(data: &[u8]) -> Vec<Color> {
+    data.chunks_exact(3)
+        .map(|x| Color {
+            a: 0xff,
+            r: x[0],
+            g: x[1],
+            b: x[2],
+        })
+        .collect()
+}
+
+fn unpack_rows(buf: &[u8], width: u32, height: u32, line_size: usize, bit_depth: u32, bottom_up: bool) -> Result<Vec<u8>> {
+    let mut result = Vec::with_capacity((width as usize).saturating_mul(height as usize).min(buf.len().saturating_mul(8)));
+    let mask = ((1u32 << bit_depth) - 1) as u8;
+
+    for y in 0..height {
+        let row = if bottom_up { height - y - 1 } else { y } as usize;
+        let line = row
+            .checked_mul(line_size)
+            .and_then(|x| buf.get(x..))
+            .ok_or_else(|| WieError::FatalError("Image data is too short".into()))?;
+
+        for x in 0..width {
+            let bit = x * bit_depth;
+            let shift = 8 - bit_depth - (bit % 8);
+            let byte = line
+                .get((bit / 8) as usize)
+                .ok_or_else(|| WieError::FatalError("Image data is too short".into()))?;
+
+            result.push((byte >> shift) & mask);
+        }
+    }
+
+    Ok(result)
+}
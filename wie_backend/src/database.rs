@@ -1,22 +1,49 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 
-use crate::System;
+use crate::{Instant, System};
 
 pub type RecordId = u32;
 
 #[async_trait::async_trait]
 pub trait Database: Send {
     async fn next_id(&self) -> RecordId;
-    async fn add(&mut self, data: &[u8]) -> RecordId;
+    // returns None if record couldn't be written
+    async fn add(&mut self, data: &[u8]) -> Option<RecordId>;
     async fn get(&self, id: RecordId) -> Option<Vec<u8>>;
     async fn set(&mut self, id: RecordId, data: &[u8]) -> bool;
     async fn delete(&mut self, id: RecordId) -> bool;
 
     async fn get_record_ids(&self) -> Vec<RecordId>;
+    async fn get_record_size(&self, id: RecordId) -> Option<usize>;
+    async fn count(&self) -> usize;
+    // sum of all record sizes
+    async fn size(&self) -> usize;
+
+    // incremented on each add, set and delete
+    async fn version(&self) -> u32;
+    async fn last_modified(&self) -> Instant;
 }
 
 #[async_trait::async_trait]
 pub trait DatabaseRepository {
-    async fn open(&self, system: &System, name: &str, app_id: &str) -> Box<dyn Database>;
+    // returns None if name is not accepted by the repository or database couldn't be opened
+    async fn open(&self, system: &System, name: &str, app_id: &str) -> Option<Box<dyn Database>>;
     async fn exists(&self, system: &System, name: &str, app_id: &str) -> bool;
+    async fn list(&self, system: &System, app_id: &str) -> Vec<String>;
+    async fn delete(&self, system: &System, name: &str, app_id: &str) -> bool;
+    async fn rename(&self, system: &System, name: &str, new_name: &str, app_id: &str) -> bool;
+}
+
+// remaining bytes of the storage quota, which is shared by all databases of an app
+pub async fn storage_available(system: &System, app_id: &str) -> usize {
+    let repository = system.platform().database_repository();
+
+    let mut used = 0;
+    for name in repository.list(system, app_id).await {
+        if let Some(database) = repository.open(system, &name, app_id).await {
+            used += database.size().await;
+        }
+    }
+
+    system.platform().device_profile().storage_quota.saturating_sub(used)
 }
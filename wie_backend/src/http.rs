@@ -0,0 +1,372 @@
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::net::SocketAddrV4;
+
+use crate::network::{Network, NetworkError, TcpSocket};
+
+const DEFAULT_PORT: u16 = 80;
+const MAX_LINE_LENGTH: usize = 8192;
+const READ_CHUNK_SIZE: usize = 4096;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HttpError {
+    InvalidUrl,
+    UnsupportedScheme,
+    InvalidResponse,
+    Network(NetworkError),
+}
+
+impl From<NetworkError> for HttpError {
+    fn from(err: NetworkError) -> Self {
+        Self::Network(err)
+    }
+}
+
+pub type HttpResult<T> = core::result::Result<T, HttpError>;
+
+// minimal http/1.1 client. connection is not reused, each request opens new connection.
+#[derive(Clone, Debug)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub proxy: Option<(String, u16)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    pub fn new(url: &str) -> Self {
+        Self {
+            method: "GET".into(),
+            url: url.into(),
+            headers: Vec::new(),
+            proxy: None,
+            body: Vec::new(),
+        }
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    // replaces existing header with the same name
+    pub fn set_header(&mut self, name: &str, value: &str) {
+        if let Some(header) = self.headers.iter_mut().find(|(x, _)| x.eq_ignore_ascii_case(name)) {
+            header.1 = value.into();
+        } else {
+            self.headers.push((name.into(), value.into()));
+        }
+    }
+
+    pub async fn send(&self, network: &dyn Network) -> HttpResult<HttpResponse> {
+        let url = Url::parse(&self.url)?;
+
+        let (host, port) = match &self.proxy {
+            Some((host, port)) => (host.as_str(), *port),
+            None => (url.host.as_str(), url.port),
+        };
+        let addr = *network.resolve(host).await?.first().ok_or(NetworkError::HostNotFound)?;
+
+        tracing::debug!("Sending http request {} {} via {}:{}", self.method, self.url, addr, port);
+
+        let socket = network.connect_tcp(SocketAddrV4::new(addr, port)).await?;
+
+        // proxies require absolute url as request target
+        let target = if self.proxy.is_some() { &self.url } else { &url.path };
+        let mut request = format!("{} {} HTTP/1.1\r\n", self.method, target);
+        if self.header("Host").is_none() {
+            if url.port == DEFAULT_PORT {
+                request += &format!("Host: {}\r\n", url.host);
+            } else {
+                request += &format!("Host: {}:{}\r\n", url.host, url.port);
+            }
+        }
+        for (name, value) in &self.headers {
+            request += &format!("{name}: {value}\r\n");
+        }
+        if !self.body.is_empty() && self.header("Content-Length").is_none() {
+            request += &format!("Content-Length: {}\r\n", self.body.len());
+        }
+        if self.header("Connection").is_none() {
+            request += "Connection: close\r\n";
+        }
+        request += "\r\n";
+
+        let mut data = request.into_bytes();
+        data.extend_from_slice(&self.body);
+
+        let mut written = 0;
+        while written < data.len() {
+            written += socket.write(&data[written..]).await?;
+        }
+
+        let mut connection = Connection {
+            socket,
+            buffer: VecDeque::new(),
+            end_of_stream: false,
+        };
+
+        // skip interim responses like `100 Continue`
+        let header = loop {
+            let header = HttpResponseHeader::read(&mut connection).await?;
+            if !(100..200).contains(&header.code) {
+                break header;
+            }
+        };
+
+        let body = if self.method.eq_ignore_ascii_case("HEAD") || header.code == 204 || header.code == 304 {
+            Body::Empty
+        } else if header
+            .header("Transfer-Encoding")
+            .is_some_and(|x| x.to_ascii_lowercase().contains("chunked"))
+        {
+            Body::Chunked { remaining: 0, done: false }
+        } else if let Some(length) = header.content_length() {
+            Body::Length(length)
+        } else {
+            Body::UntilClose
+        };
+
+        Ok(HttpResponse { header, connection, body })
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HttpResponseHeader {
+    pub code: u16,
+    pub message: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpResponseHeader {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    pub fn content_length(&self) -> Option<u64> {
+        self.header("Content-Length")?.trim().parse().ok()
+    }
+
+    pub fn content_type(&self) -> Option<&str> {
+        self.header("Content-Type")
+    }
+
+    pub fn content_encoding(&self) -> Option<&str> {
+        self.header("Content-Encoding")
+    }
+
+    async fn read(connection: &mut Connection) -> HttpResult<Self> {
+        // `HTTP/1.1 200 OK`
+        let status_line = connection.read_line().await?;
+        let mut parts = status_line.splitn(3, ' ');
+        if !parts.next().is_some_and(|x| x.starts_with("HTTP/")) {
+            return Err(HttpError::InvalidResponse);
+        }
+        let code = parts.next().and_then(|x| x.parse().ok()).ok_or(HttpError::InvalidResponse)?;
+        let message = parts.next().unwrap_or_default().to_owned();
+
+        let mut headers = Vec::new();
+        loop {
+            let line = connection.read_line().await?;
+            if line.is_empty() {
+                break;
+            }
+
+            let (name, value) = line.split_once(':').ok_or(HttpError::InvalidResponse)?;
+            headers.push((name.trim().to_owned(), value.trim().to_owned()));
+        }
+
+        Ok(Self { code, message, headers })
+    }
+}
+
+enum Body {
+    Empty,
+    Length(u64),
+    Chunked { remaining: u64, done: bool },
+    UntilClose,
+}
+
+pub struct HttpResponse {
+    pub header: HttpResponseHeader,
+    connection: Connection,
+    body: Body,
+}
+
+impl HttpResponse {
+    // returns 0 at the end of body
+    pub async fn read(&mut self, buf: &mut [u8]) -> HttpResult<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let Self { connection, body, .. } = self;
+        match body {
+            Body::Empty => Ok(0),
+            Body::Length(remaining) => {
+                if *remaining == 0 {
+                    return Ok(0);
+                }
+
+                let length = (*remaining).min(buf.len() as u64) as usize;
+                let read = connection.read_body(&mut buf[..length]).await?;
+                *remaining -= read as u64;
+
+                Ok(read)
+            }
+            Body::Chunked { remaining, done } => {
+                if *done {
+                    return Ok(0);
+                }
+
+                if *remaining == 0 {
+                    // chunk size in hex, optionally followed by extensions
+                    let line = connection.read_line().await?;
+                    let size = line.split(';').next().unwrap_or_default().trim();
+                    let size = u64::from_str_radix(size, 16).map_err(|_| HttpError::InvalidResponse)?;
+
+                    if size == 0 {
+                        // skip trailers
+                        while !connection.read_line().await?.is_empty() {}
+                        *done = true;
+
+                        return Ok(0);
+                    }
+                    *remaining = size;
+                }
+
+                let length = (*remaining).min(buf.len() as u64) as usize;
+                let read = connection.read_body(&mut buf[..length]).await?;
+                *remaining -= read as u64;
+
+                if *remaining == 0 {
+                    // crlf after chunk data
+                    connection.read_line().await?;
+                }
+
+                Ok(read)
+            }
+            Body::UntilClose => connection.read(buf).await,
+        }
+    }
+
+    pub async fn read_to_end(&mut self) -> HttpResult<Vec<u8>> {
+        let mut result = Vec::new();
+        let mut buf = [0; READ_CHUNK_SIZE];
+
+        loop {
+            let read = self.read(&mut buf).await?;
+            if read == 0 {
+                break;
+            }
+            result.extend_from_slice(&buf[..read]);
+        }
+
+        Ok(result)
+    }
+}
+
+struct Connection {
+    socket: Box<dyn TcpSocket>,
+    buffer: VecDeque<u8>,
+    end_of_stream: bool,
+}
+
+impl Connection {
+    async fn fill(&mut self) -> HttpResult<()> {
+        let mut buf = [0; READ_CHUNK_SIZE];
+
+        let read = self.socket.read(&mut buf).await?;
+        if read == 0 {
+            self.end_of_stream = true;
+        }
+        self.buffer.extend(&buf[..read]);
+
+        Ok(())
+    }
+
+    // without line terminator
+    async fn read_line(&mut self) -> HttpResult<String> {
+        loop {
+            if let Some(position) = self.buffer.iter().position(|&x| x == b'\n') {
+                let line = self.buffer.drain(..=position).collect::<Vec<_>>();
+
+                return Ok(String::from_utf8_lossy(&line).trim_end_matches(['\r', '\n']).to_string());
+            }
+
+            if self.end_of_stream || self.buffer.len() > MAX_LINE_LENGTH {
+                return Err(HttpError::InvalidResponse);
+            }
+            self.fill().await?;
+        }
+    }
+
+    // returns 0 on end of stream
+    async fn read(&mut self, buf: &mut [u8]) -> HttpResult<usize> {
+        if self.buffer.is_empty() && !self.end_of_stream {
+            self.fill().await?;
+        }
+
+        let length = buf.len().min(self.buffer.len());
+        for (dest, src) in buf.iter_mut().zip(self.buffer.drain(..length)) {
+            *dest = src;
+        }
+
+        Ok(length)
+    }
+
+    // body with known length can't end before it
+    async fn read_body(&mut self, buf: &mut [u8]) -> HttpResult<usize> {
+        match self.read(buf).await? {
+            0 => Err(NetworkError::ConnectionReset.into()),
+            read => Ok(read),
+        }
+    }
+}
+
+struct Url {
+    host: String,
+    port: u16,
+    path: String,
+}
+
+impl Url {
+    fn parse(url: &str) -> HttpResult<Self> {
+        let (scheme, rest) = url.split_once("://").ok_or(HttpError::InvalidUrl)?;
+        if !scheme.eq_ignore_ascii_case("http") {
+            return Err(HttpError::UnsupportedScheme);
+        }
+
+        let (authority, path) = match rest.find(['/', '?', '#']) {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+        let path = path.split('#').next().unwrap_or_default();
+        let path = if path.starts_with('/') { path.to_owned() } else { format!("/{path}") };
+
+        let authority = authority.rsplit_once('@').map(|x| x.1).unwrap_or(authority);
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| HttpError::InvalidUrl)?),
+            None => (authority, DEFAULT_PORT),
+        };
+        if host.is_empty() {
+            return Err(HttpError::InvalidUrl);
+        }
+
+        Ok(Self {
+            host: host.to_owned(),
+            port,
+            path,
+        })
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter().find(|(x, _)| x.eq_ignore_ascii_case(name)).map(|(_, x)| x.as_str())
+}
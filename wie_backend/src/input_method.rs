@@ -0,0 +1,895 @@
+mod hangul;
+
+use alloc::{string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::time::Instant;
+
+use self::hangul::{ARAEA, DOUBLE_ARAEA};
+
+// keys repeated within this interval cycle through the letters of multi-tap keys
+pub const MULTITAP_TIMEOUT: u64 = 1000;
+
+const MAX_HISTORY: usize = 64;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputKey {
+    Digit(u8),
+    Star,
+    Pound,
+    Left,
+    Right,
+    Clear,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputMode {
+    Cheonjiin,
+    Naragul,
+    EnglishLower,
+    EnglishUpper,
+    Numeric,
+}
+
+impl InputMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Cheonjiin => "천지인",
+            Self::Naragul => "나랏글",
+            Self::EnglishLower => "abc",
+            Self::EnglishUpper => "ABC",
+            Self::Numeric => "123",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputConstraint {
+    Any,
+    EmailAddr,
+    Numeric,
+    PhoneNumber,
+    Url,
+    Decimal,
+}
+
+impl InputConstraint {
+    // constraint values of midp TextField, also used by wipi text components. modifier flags are ignored
+    pub fn from_raw(raw: i32) -> Self {
+        match raw & 0xffff {
+            1 => Self::EmailAddr,
+            2 => Self::Numeric,
+            3 => Self::PhoneNumber,
+            4 => Self::Url,
+            5 => Self::Decimal,
+            _ => Self::Any,
+        }
+    }
+
+    // modes cycled by mode switch key, first one is the default
+    pub fn modes(&self) -> &'static [InputMode] {
+        match self {
+            Self::Any => &[
+                InputMode::Cheonjiin,
+                InputMode::Naragul,
+                InputMode::EnglishLower,
+                InputMode::EnglishUpper,
+                InputMode::Numeric,
+            ],
+            Self::EmailAddr | Self::Url => &[InputMode::EnglishLower, InputMode::EnglishUpper, InputMode::Numeric],
+            Self::Numeric | Self::PhoneNumber | Self::Decimal => &[InputMode::Numeric],
+        }
+    }
+
+    // whether directly entered text may contain the character
+    pub fn accepts(&self, ch: char) -> bool {
+        match self {
+            Self::Any | Self::EmailAddr | Self::Url => !ch.is_control(),
+            Self::Numeric => ch.is_ascii_digit() || ch == '-',
+            Self::PhoneNumber => ch.is_ascii_digit() || matches!(ch, '+' | '*' | '#' | 'p' | 'w'),
+            Self::Decimal => ch.is_ascii_digit() || matches!(ch, '-' | '.'),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+struct Syllable {
+    initial: Option<char>,
+    medial: Option<char>,
+    r#final: Option<char>,
+}
+
+impl Syllable {
+    fn render(&self, result: &mut Vec<char>) {
+        if let (Some(initial), Some(medial)) = (self.initial, self.medial)
+            && let Some(x) = hangul::compose(initial, medial, self.r#final)
+        {
+            result.push(x);
+            return;
+        }
+
+        result.extend(self.initial.iter().chain(self.medial.iter()).chain(self.r#final.iter()));
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Composition {
+    None,
+    Letter(char),
+    Hangul(Syllable),
+}
+
+impl Composition {
+    fn render(&self) -> Vec<char> {
+        let mut result = Vec::new();
+        match self {
+            Self::None => {}
+            Self::Letter(x) => result.push(*x),
+            Self::Hangul(x) => x.render(&mut result),
+        }
+
+        result
+    }
+}
+
+#[derive(Clone)]
+struct State {
+    text: Vec<char>,
+    cursor: usize,
+    composition: Composition,
+}
+
+// keypad text input engine with multi-tap english and cheonjiin/naragul hangul input.
+// text being composed is shown at the cursor, and committed on cursor move, mode switch or non-combinable input
+pub struct InputMethod {
+    constraint: InputConstraint,
+    mode: InputMode,
+    max_length: usize,
+    state: State,
+    // states before each key of current composition, restored on clear and multi-tap
+    history: Vec<State>,
+    last_key: Option<(InputKey, Instant)>,
+    last_jamo: Option<char>,
+    // vowel strokes of cheonjiin and the state before the first stroke
+    strokes: Vec<char>,
+    vowel_start: Option<(State, usize)>,
+}
+
+impl InputMethod {
+    pub fn new(constraint: InputConstraint, max_length: usize) -> Self {
+        Self {
+            constraint,
+            mode: constraint.modes()[0],
+            max_length,
+            state: State {
+                text: Vec::new(),
+                cursor: 0,
+                composition: Composition::None,
+            },
+            history: Vec::new(),
+            last_key: None,
+            last_jamo: None,
+            strokes: Vec::new(),
+            vowel_start: None,
+        }
+    }
+
+    // text including the composing characters
+    pub fn text(&self) -> String {
+        let composing = self.state.composition.render();
+
+        self.state.text[..self.state.cursor]
+            .iter()
+            .chain(composing.iter())
+            .chain(self.state.text[self.state.cursor..].iter())
+            .collect()
+    }
+
+    // caret position in `text()`, placed after the composing characters
+    pub fn cursor(&self) -> usize {
+        self.state.cursor + self.state.composition.render().len()
+    }
+
+    pub fn composing(&self) -> Option<Range<usize>> {
+        let length = self.state.composition.render().len();
+        if length == 0 {
+            return None;
+        }
+
+        Some(self.state.cursor..self.state.cursor + length)
+    }
+
+    pub fn mode(&self) -> InputMode {
+        self.mode
+    }
+
+    pub fn constraint(&self) -> InputConstraint {
+        self.constraint
+    }
+
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    pub fn set_mode(&mut self, mode: InputMode) -> bool {
+        if !self.constraint.modes().contains(&mode) {
+            return false;
+        }
+
+        self.commit();
+        self.mode = mode;
+
+        true
+    }
+
+    pub fn set_constraint(&mut self, constraint: InputConstraint) {
+        self.commit();
+        self.constraint = constraint;
+        if !constraint.modes().contains(&self.mode) {
+            self.mode = constraint.modes()[0];
+        }
+    }
+
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.commit();
+        self.max_length = max_length;
+        self.state.text.truncate(max_length);
+        self.state.cursor = self.state.cursor.min(self.state.text.len());
+    }
+
+    // replaces whole text, placing cursor at the end
+    pub fn set_text(&mut self, text: &str) {
+        self.commit();
+        self.state.text = text.chars().take(self.max_length).collect();
+        self.state.cursor = self.state.text.len();
+    }
+
+    // moves caret to the character index, committing text being composed
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.commit();
+        self.state.cursor = cursor.min(self.state.text.len());
+    }
+
+    // inserts text at the cursor as much as it fits, dropping characters not allowed by constraint
+    pub fn insert_text(&mut self, text: &str) -> bool {
+        self.commit();
+
+        let room = self.max_length.saturating_sub(self.state.text.len());
+        let chars = text.chars().filter(|&x| self.constraint.accepts(x)).take(room).collect::<Vec<_>>();
+        if chars.is_empty() {
+            return false;
+        }
+
+        let cursor = self.state.cursor;
+        self.state.cursor += chars.len();
+        self.state.text.splice(cursor..cursor, chars);
+
+        true
+    }
+
+    pub fn commit(&mut self) {
+        self.finish_composition();
+
+        self.history.clear();
+        self.last_key = None;
+        self.last_jamo = None;
+        self.strokes.clear();
+        self.vowel_start = None;
+    }
+
+    // returns false if key is not used, so the caller may handle it
+    pub fn handle_key(&mut self, key: InputKey, now: Instant) -> bool {
+        let repeated = matches!(self.last_key, Some((last, time)) if last == key && now.raw().saturating_sub(time.raw()) < MULTITAP_TIMEOUT);
+
+        let handled = match key {
+            InputKey::Left => self.move_left(),
+            InputKey::Right => self.move_right(),
+            InputKey::Clear => self.clear(),
+            _ => match self.mode {
+                InputMode::Numeric => self.numeric_key(key),
+                InputMode::EnglishLower | InputMode::EnglishUpper => self.english_key(key, repeated),
+                InputMode::Cheonjiin => self.cheonjiin_key(key, repeated),
+                InputMode::Naragul => self.naragul_key(key),
+            },
+        };
+
+        if !matches!(key, InputKey::Left | InputKey::Right | InputKey::Clear) {
+            self.last_key = Some((key, now));
+        }
+
+        handled
+    }
+
+    fn is_composing(&self) -> bool {
+        !matches!(self.state.composition, Composition::None)
+    }
+
+    fn move_left(&mut self) -> bool {
+        if self.is_composing() {
+            self.commit();
+            return true;
+        }
+        if self.state.cursor == 0 {
+            return false;
+        }
+
+        self.commit();
+        self.state.cursor -= 1;
+
+        true
+    }
+
+    // moving right at the end of text inserts space, as on korean handsets
+    fn move_right(&mut self) -> bool {
+        if self.is_composing() {
+            self.commit();
+            return true;
+        }
+        if self.state.cursor < self.state.text.len() {
+            self.commit();
+            self.state.cursor += 1;
+            return true;
+        }
+        if self.constraint == InputConstraint::Any {
+            return self.insert_char(' ');
+        }
+
+        false
+    }
+
+    fn clear(&mut self) -> bool {
+        if self.is_composing() {
+            match self.history.pop() {
+                Some(x) => self.state = x,
+                None => self.state.composition = Composition::None,
+            }
+            self.last_key = None;
+            self.last_jamo = None;
+            self.strokes.clear();
+            self.vowel_start = None;
+
+            return true;
+        }
+        if self.state.cursor == 0 {
+            return false;
+        }
+
+        self.commit();
+        self.state.cursor -= 1;
+        self.state.text.remove(self.state.cursor);
+
+        true
+    }
+
+    fn switch_mode(&mut self) -> bool {
+        let modes = self.constraint.modes();
+        if modes.len() < 2 {
+            return false;
+        }
+
+        let index = modes.iter().position(|&x| x == self.mode).unwrap_or(0);
+        self.set_mode(modes[(index + 1) % modes.len()])
+    }
+
+    fn insert_char(&mut self, char: char) -> bool {
+        self.commit();
+        self.insert_text(char.encode_utf8(&mut [0; 4]))
+    }
+
+    fn numeric_key(&mut self, key: InputKey) -> bool {
+        match (key, self.constraint) {
+            (InputKey::Digit(x), _) => self.insert_char((b'0' + x) as char),
+            (InputKey::Star, InputConstraint::PhoneNumber) => self.insert_char('*'),
+            (InputKey::Pound, InputConstraint::PhoneNumber) => self.insert_char('#'),
+            (InputKey::Star, InputConstraint::Decimal) if !self.state.text.contains(&'.') => self.insert_char('.'),
+            (InputKey::Star, _) => self.switch_mode(),
+            (InputKey::Pound, InputConstraint::Any) => self.insert_char(' '),
+            _ => false,
+        }
+    }
+
+    fn english_key(&mut self, key: InputKey, repeated: bool) -> bool {
+        let digit = match key {
+            InputKey::Digit(x) => x,
+            InputKey::Star => return self.switch_mode(),
+            InputKey::Pound => return self.insert_char(' '),
+            _ => return false,
+        };
+
+        let letters = match digit {
+            1 => ".,?!'-@1",
+            2 => "abc2",
+            3 => "def3",
+            4 => "ghi4",
+            5 => "jkl5",
+            6 => "mno6",
+            7 => "pqrs7",
+            8 => "tuv8",
+            9 => "wxyz9",
+            _ => "0",
+        };
+        let letters = letters
+            .chars()
+            .map(|x| {
+                if self.mode == InputMode::EnglishUpper {
+                    x.to_ascii_uppercase()
+                } else {
+                    x
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if repeated && let Composition::Letter(current) = self.state.composition {
+            let index = letters.iter().position(|&x| x == current).map(|x| x + 1).unwrap_or(0);
+            self.state.composition = Composition::Letter(letters[index % letters.len()]);
+
+            return true;
+        }
+
+        self.commit();
+        if !self.has_room() {
+            return false;
+        }
+
+        self.history.push(self.state.clone());
+        self.state.composition = Composition::Letter(letters[0]);
+
+        true
+    }
+
+    fn cheonjiin_key(&mut self, key: InputKey, repeated: bool) -> bool {
+        let digit = match key {
+            InputKey::Digit(x) => x,
+            InputKey::Star => return self.switch_mode(),
+            InputKey::Pound => return self.insert_char(' '),
+            _ => return false,
+        };
+
+        let consonants: &[char] = match digit {
+            1 => return self.cheonjiin_stroke('ㅣ'),
+            2 => return self.cheonjiin_stroke(ARAEA),
+            3 => return self.cheonjiin_stroke('ㅡ'),
+            4 => &['ㄱ', 'ㅋ', 'ㄲ'],
+            5 => &['ㄴ', 'ㄹ'],
+            6 => &['ㄷ', 'ㅌ', 'ㄸ'],
+            7 => &['ㅂ', 'ㅍ', 'ㅃ'],
+            8 => &['ㅅ', 'ㅎ', 'ㅆ'],
+            9 => &['ㅈ', 'ㅊ', 'ㅉ'],
+            _ => &['ㅇ', 'ㅁ'],
+        };
+
+        self.strokes.clear();
+        self.vowel_start = None;
+
+        if repeated
+            && let Some(last) = self.last_jamo
+            && let Some(index) = consonants.iter().position(|&x| x == last)
+        {
+            return self.replace_last_jamo(consonants[(index + 1) % consonants.len()]);
+        }
+
+        self.input_jamo(consonants[0])
+    }
+
+    // cheonjiin vowels are built from strokes of ㅣ, ㆍ and ㅡ
+    fn cheonjiin_stroke(&mut self, stroke: char) -> bool {
+        self.strokes.push(stroke);
+        if let Some(vowel) = cheonjiin_vowel(&self.strokes)
+            && let Some((state, history_length)) = self.vowel_start.clone()
+        {
+            self.state = state;
+            self.history.truncate(history_length);
+
+            return self.input_jamo(vowel);
+        }
+
+        self.strokes.clear();
+        self.strokes.push(stroke);
+        self.vowel_start = Some((self.state.clone(), self.history.len()));
+
+        self.input_jamo(stroke)
+    }
+
+    fn naragul_key(&mut self, key: InputKey) -> bool {
+        let last_key = self.last_key.map(|x| x.0);
+        match key {
+            // adds a stroke to the last jamo
+            InputKey::Star => {
+                if let Some(x) = self.last_jamo.and_then(naragul_stroke) {
+                    return self.replace_last_jamo(x);
+                }
+                if self.is_composing() {
+                    return true;
+                }
+
+                self.switch_mode()
+            }
+            // makes the last consonant double
+            InputKey::Pound => {
+                if let Some(x) = self.last_jamo.and_then(naragul_double) {
+                    return self.replace_last_jamo(x);
+                }
+                if self.is_composing() {
+                    return true;
+                }
+
+                self.insert_char(' ')
+            }
+            InputKey::Digit(digit) => {
+                let (jamo, alternate) = match digit {
+                    1 => ('ㄱ', None),
+                    2 => ('ㄴ', None),
+                    3 => ('ㅏ', Some('ㅓ')),
+                    4 => ('ㄹ', None),
+                    5 => ('ㅁ', None),
+                    6 => ('ㅗ', Some('ㅜ')),
+                    7 => ('ㅅ', None),
+                    8 => ('ㅇ', None),
+                    9 => ('ㅣ', None),
+                    _ => ('ㅡ', None),
+                };
+
+                // repeating vowel key toggles between the pair
+                if let Some(alternate) = alternate
+                    && last_key == Some(key)
+                    && let Some(last) = self.last_jamo
+                {
+                    let next = if last == jamo { alternate } else { jamo };
+                    return self.replace_last_jamo(next);
+                }
+
+                self.input_jamo(jamo)
+            }
+            _ => false,
+        }
+    }
+
+    fn replace_last_jamo(&mut self, jamo: char) -> bool {
+        let Some(previous) = self.history.pop() else {
+            return false;
+        };
+
+        let current = core::mem::replace(&mut self.state, previous);
+        if self.input_jamo(jamo) {
+            return true;
+        }
+
+        self.state = current;
+        false
+    }
+
+    fn input_jamo(&mut self, jamo: char) -> bool {
+        let previous = self.state.clone();
+
+        let applied = if hangul::is_medial(jamo) || jamo == ARAEA || jamo == DOUBLE_ARAEA {
+            self.apply_vowel(jamo)
+        } else {
+            self.apply_consonant(jamo)
+        };
+        if !applied {
+            return false;
+        }
+
+        self.history.push(previous);
+        if self.history.len() > MAX_HISTORY {
+            self.history.remove(0);
+        }
+        self.last_jamo = Some(jamo);
+
+        true
+    }
+
+    fn apply_consonant(&mut self, consonant: char) -> bool {
+        if let Composition::Hangul(mut syllable) = self.state.composition
+            && syllable.initial.is_some()
+            && syllable.medial.is_some_and(hangul::is_medial)
+        {
+            let r#final = match syllable.r#final {
+                None if hangul::can_be_final(consonant) => Some(consonant),
+                Some(x) => hangul::combine_final(x, consonant),
+                None => None,
+            };
+
+            if r#final.is_some() {
+                syllable.r#final = r#final;
+                self.state.composition = Composition::Hangul(syllable);
+
+                return true;
+            }
+        }
+
+        self.start_syllable(Syllable {
+            initial: Some(consonant),
+            ..Default::default()
+        })
+    }
+
+    fn apply_vowel(&mut self, vowel: char) -> bool {
+        if let Composition::Hangul(mut syllable) = self.state.composition {
+            match (syllable.initial, syllable.medial, syllable.r#final) {
+                (Some(_), None, _) => {
+                    syllable.medial = Some(vowel);
+                    self.state.composition = Composition::Hangul(syllable);
+
+                    return true;
+                }
+                // final consonant moves to the next syllable
+                (Some(_), Some(_), Some(x)) if hangul::is_medial(vowel) => {
+                    if !self.has_room() {
+                        return false;
+                    }
+
+                    let (remaining, moved) = hangul::split_final(x);
+                    syllable.r#final = remaining;
+                    self.state.composition = Composition::Hangul(syllable);
+
+                    return self.start_syllable(Syllable {
+                        initial: Some(moved),
+                        medial: Some(vowel),
+                        r#final: None,
+                    });
+                }
+                (_, Some(x), None) => {
+                    if let Some(compound) = hangul::combine_medial(x, vowel) {
+                        syllable.medial = Some(compound);
+                        self.state.composition = Composition::Hangul(syllable);
+
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.start_syllable(Syllable {
+            medial: Some(vowel),
+            ..Default::default()
+        })
+    }
+
+    fn start_syllable(&mut self, syllable: Syllable) -> bool {
+        if !self.has_room() {
+            return false;
+        }
+
+        self.finish_composition();
+        self.state.composition = Composition::Hangul(syllable);
+
+        true
+    }
+
+    fn finish_composition(&mut self) {
+        let composing = self.state.composition.render();
+        self.state.composition = Composition::None;
+
+        let cursor = self.state.cursor;
+        self.state.cursor += composing.len();
+        self.state.text.splice(cursor..cursor, composing);
+    }
+
+    // whether a new character fits after the characters being composed
+    fn has_room(&self) -> bool {
+        let composing = self.state.composition.render().len();
+
+        self.state.text.len() + composing < self.max_length
+    }
+}
+
+fn cheonjiin_vowel(strokes: &[char]) -> Option<char> {
+    const I: char = 'ㅣ';
+    const D: char = ARAEA;
+    const E: char = 'ㅡ';
+
+    Some(match strokes {
+        [I] => 'ㅣ',
+        [D] => ARAEA,
+        [D, D] => DOUBLE_ARAEA,
+        [E] => 'ㅡ',
+        [I, D] => 'ㅏ',
+        [I, D, D] => 'ㅑ',
+        [D, I] => 'ㅓ',
+        [D, D, I] => 'ㅕ',
+        [D, E] => 'ㅗ',
+        [D, D, E] => 'ㅛ',
+        [E, D] => 'ㅜ',
+        [E, D, D] => 'ㅠ',
+        [E, I] => 'ㅢ',
+        [I, D, I] => 'ㅐ',
+        [I, D, D, I] => 'ㅒ',
+        [D, I, I] => 'ㅔ',
+        [D, D, I, I] => 'ㅖ',
+        [D, E, I] => 'ㅚ',
+        [D, E, I, D] => 'ㅘ',
+        [D, E, I, D, I] => 'ㅙ',
+        [E, D, I] => 'ㅟ',
+        [E, D, D, I] => 'ㅝ',
+        [E, D, D, I, I] => 'ㅞ',
+        _ => return None,
+    })
+}
+
+fn naragul_stroke(jamo: char) -> Option<char> {
+    Some(match jamo {
+        'ㄱ' => 'ㅋ',
+        'ㅋ' => 'ㄱ',
+        'ㄴ' => 'ㄷ',
+        'ㄷ' => 'ㅌ',
+        'ㅌ' => 'ㄴ',
+        'ㅁ' => 'ㅂ',
+        'ㅂ' => 'ㅍ',
+        'ㅍ' => 'ㅁ',
+        'ㅅ' => 'ㅈ',
+        'ㅈ' => 'ㅊ',
+        'ㅊ' => 'ㅅ',
+        'ㅇ' => 'ㅎ',
+        'ㅎ' => 'ㅇ',
+        'ㅏ' => 'ㅑ',
+        'ㅑ' => 'ㅏ',
+        'ㅓ' => 'ㅕ',
+        'ㅕ' => 'ㅓ',
+        'ㅗ' => 'ㅛ',
+        'ㅛ' => 'ㅗ',
+        'ㅜ' => 'ㅠ',
+        'ㅠ' => 'ㅜ',
+        _ => return None,
+    })
+}
+
+fn naragul_double(jamo: char) -> Option<char> {
+    Some(match jamo {
+        'ㄱ' => 'ㄲ',
+        'ㄲ' => 'ㄱ',
+        'ㄷ' => 'ㄸ',
+        'ㄸ' => 'ㄷ',
+        'ㅂ' => 'ㅃ',
+        'ㅃ' => 'ㅂ',
+        'ㅅ' => 'ㅆ',
+        'ㅆ' => 'ㅅ',
+        'ㅈ' => 'ㅉ',
+        'ㅉ' => 'ㅈ',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::time::Instant;
+
+    use super::{InputConstraint, InputKey, InputMethod, InputMode};
+
+    fn type_keys(input_method: &mut InputMethod, keys: &[InputKey], now: &mut u64) {
+        for &key in keys {
+            *now += 100;
+            input_method.handle_key(key, Instant::from_epoch_millis(*now));
+        }
+    }
+
+    fn digits(digits: &[u8]) -> impl Iterator<Item = InputKey> + '_ {
+        digits.iter().map(|&x| InputKey::Digit(x))
+    }
+
+    #[test]
+    fn test_cheonjiin() {
+        let mut input_method = InputMethod::new(InputConstraint::Any, 10);
+        let mut now = 0;
+
+        let keys = digits(&[8, 8, 1, 2, 5, 4, 3, 5, 5]).collect::<Vec<_>>();
+        type_keys(&mut input_method, &keys, &mut now);
+        assert_eq!(input_method.text(), "한글");
+        assert_eq!(input_method.composing(), Some(1..2));
+
+        // final consonant moves to the next syllable
+        input_method.set_text("");
+        type_keys(
+            &mut input_method,
+            &[InputKey::Digit(4), InputKey::Digit(1), InputKey::Digit(2), InputKey::Digit(4)],
+            &mut now,
+        );
+        assert_eq!(input_method.text(), "각");
+        type_keys(&mut input_method, &[InputKey::Digit(1)], &mut now);
+        assert_eq!(input_method.text(), "가기");
+
+        // clear undoes the last jamo
+        type_keys(&mut input_method, &[InputKey::Clear], &mut now);
+        assert_eq!(input_method.text(), "각");
+        type_keys(&mut input_method, &[InputKey::Clear, InputKey::Right, InputKey::Clear], &mut now);
+        assert_eq!(input_method.text(), "");
+    }
+
+    #[test]
+    fn test_naragul() {
+        let mut input_method = InputMethod::new(InputConstraint::Any, 10);
+        assert!(input_method.set_mode(InputMode::Naragul));
+        let mut now = 0;
+
+        let keys = [
+            InputKey::Digit(8),
+            InputKey::Star,
+            InputKey::Digit(3),
+            InputKey::Digit(2),
+            InputKey::Digit(1),
+            InputKey::Digit(0),
+            InputKey::Digit(4),
+        ];
+        type_keys(&mut input_method, &keys, &mut now);
+        assert_eq!(input_method.text(), "한글");
+
+        input_method.set_text("");
+        type_keys(
+            &mut input_method,
+            &[InputKey::Digit(1), InputKey::Pound, InputKey::Digit(3), InputKey::Digit(3)],
+            &mut now,
+        );
+        assert_eq!(input_method.text(), "꺼");
+    }
+
+    #[test]
+    fn test_english() {
+        let mut input_method = InputMethod::new(InputConstraint::Any, 10);
+        assert!(input_method.set_mode(InputMode::EnglishLower));
+        let mut now = 0;
+
+        type_keys(&mut input_method, &[InputKey::Digit(4), InputKey::Digit(4)], &mut now);
+        assert_eq!(input_method.text(), "h");
+
+        // same key after timeout starts a new letter
+        now += 2000;
+        type_keys(&mut input_method, &[InputKey::Digit(4), InputKey::Digit(4), InputKey::Digit(4)], &mut now);
+        assert_eq!(input_method.text(), "hi");
+        assert_eq!(input_method.cursor(), 2);
+
+        type_keys(&mut input_method, &[InputKey::Star, InputKey::Digit(2), InputKey::Pound], &mut now);
+        assert_eq!(input_method.mode(), InputMode::EnglishUpper);
+        assert_eq!(input_method.text(), "hiA ");
+    }
+
+    #[test]
+    fn test_constraint() {
+        let mut input_method = InputMethod::new(InputConstraint::Numeric, 3);
+        assert_eq!(input_method.mode(), InputMode::Numeric);
+        let mut now = 0;
+
+        let keys = digits(&[1, 2, 3, 4]).collect::<Vec<_>>();
+        type_keys(&mut input_method, &keys, &mut now);
+        assert_eq!(input_method.text(), "123");
+
+        assert!(!input_method.handle_key(InputKey::Star, Instant::from_epoch_millis(now)));
+        assert!(!input_method.set_mode(InputMode::Cheonjiin));
+
+        type_keys(&mut input_method, &[InputKey::Left, InputKey::Clear], &mut now);
+        assert_eq!(input_method.text(), "13");
+        assert_eq!(input_method.cursor(), 1);
+    }
+
+    #[test]
+    fn test_insert_text() {
+        let mut input_method = InputMethod::new(InputConstraint::Any, 5);
+        let mut now = 0;
+
+        type_keys(&mut input_method, &digits(&[8, 8]).collect::<Vec<_>>(), &mut now);
+        assert!(input_method.insert_text("한글이다"));
+        assert_eq!(input_method.text(), "ㅎ한글이다");
+        assert!(!input_method.insert_text("x"));
+
+        let mut input_method = InputMethod::new(InputConstraint::Numeric, 10);
+        assert!(input_method.insert_text("a1b2 3"));
+        assert_eq!(input_method.text(), "123");
+        assert!(!input_method.insert_text("abc"));
+    }
+
+    #[test]
+    fn test_set_cursor() {
+        let mut input_method = InputMethod::new(InputConstraint::Any, 10);
+
+        input_method.set_text("abcd");
+        input_method.set_cursor(1);
+        assert!(input_method.insert_text("xy"));
+        assert_eq!(input_method.text(), "axybcd");
+        assert_eq!(input_method.cursor(), 3);
+
+        input_method.set_cursor(100);
+        assert_eq!(input_method.cursor(), 6);
+    }
+}
@@ -0,0 +1,82 @@
+// hangul compatibility jamo tables and syllable composition
+
+// intermediate vowels of cheonjiin layout, which aren't a valid medial by themselves
+pub const ARAEA: char = 'ㆍ';
+pub const DOUBLE_ARAEA: char = 'ᆢ';
+
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+const MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ', 'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+// index 0 of syllable final is no final
+const FINALS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+const COMPOUND_FINALS: [(char, char, char); 11] = [
+    ('ㄱ', 'ㅅ', 'ㄳ'),
+    ('ㄴ', 'ㅈ', 'ㄵ'),
+    ('ㄴ', 'ㅎ', 'ㄶ'),
+    ('ㄹ', 'ㄱ', 'ㄺ'),
+    ('ㄹ', 'ㅁ', 'ㄻ'),
+    ('ㄹ', 'ㅂ', 'ㄼ'),
+    ('ㄹ', 'ㅅ', 'ㄽ'),
+    ('ㄹ', 'ㅌ', 'ㄾ'),
+    ('ㄹ', 'ㅍ', 'ㄿ'),
+    ('ㄹ', 'ㅎ', 'ㅀ'),
+    ('ㅂ', 'ㅅ', 'ㅄ'),
+];
+
+const COMPOUND_MEDIALS: [(char, char, char); 13] = [
+    ('ㅏ', 'ㅣ', 'ㅐ'),
+    ('ㅑ', 'ㅣ', 'ㅒ'),
+    ('ㅓ', 'ㅣ', 'ㅔ'),
+    ('ㅕ', 'ㅣ', 'ㅖ'),
+    ('ㅗ', 'ㅏ', 'ㅘ'),
+    ('ㅘ', 'ㅣ', 'ㅙ'),
+    ('ㅗ', 'ㅐ', 'ㅙ'),
+    ('ㅗ', 'ㅣ', 'ㅚ'),
+    ('ㅜ', 'ㅓ', 'ㅝ'),
+    ('ㅝ', 'ㅣ', 'ㅞ'),
+    ('ㅜ', 'ㅔ', 'ㅞ'),
+    ('ㅜ', 'ㅣ', 'ㅟ'),
+    ('ㅡ', 'ㅣ', 'ㅢ'),
+];
+
+pub fn is_medial(jamo: char) -> bool {
+    MEDIALS.contains(&jamo)
+}
+
+pub fn can_be_final(jamo: char) -> bool {
+    FINALS.contains(&jamo)
+}
+
+pub fn combine_final(first: char, second: char) -> Option<char> {
+    COMPOUND_FINALS.iter().find(|x| x.0 == first && x.1 == second).map(|x| x.2)
+}
+
+// splits compound final into remaining final and the consonant moving to next syllable
+pub fn split_final(jamo: char) -> (Option<char>, char) {
+    match COMPOUND_FINALS.iter().find(|x| x.2 == jamo) {
+        Some(x) => (Some(x.0), x.1),
+        None => (None, jamo),
+    }
+}
+
+pub fn combine_medial(first: char, second: char) -> Option<char> {
+    COMPOUND_MEDIALS.iter().find(|x| x.0 == first && x.1 == second).map(|x| x.2)
+}
+
+pub fn compose(initial: char, medial: char, r#final: Option<char>) -> Option<char> {
+    let initial = INITIALS.iter().position(|&x| x == initial)?;
+    let medial = MEDIALS.iter().position(|&x| x == medial)?;
+    let r#final = match r#final {
+        Some(x) => FINALS.iter().position(|&y| y == x)? + 1,
+        None => 0,
+    };
+
+    char::from_u32(0xac00 + ((initial * 21 + medial) * 28 + r#final) as u32)
+}
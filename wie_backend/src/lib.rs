@@ -5,6 +5,9 @@ mod audio_sink;
 pub mod canvas;
 mod database;
 mod executor;
+mod http;
+mod input_method;
+mod network;
 mod platform;
 mod screen;
 mod system;
@@ -14,11 +17,17 @@ mod time;
 
 pub use self::{
     audio_sink::AudioSink,
-    database::{Database, DatabaseRepository, RecordId},
+    database::{Database, DatabaseRepository, RecordId, storage_available},
     executor::{AsyncCallable, AsyncCallableResult},
-    platform::Platform,
+    http::{HttpError, HttpRequest, HttpResponse, HttpResponseHeader, HttpResult},
+    input_method::{InputConstraint, InputKey, InputMethod, InputMode, MULTITAP_TIMEOUT},
+    network::{Network, NetworkError, NetworkResult, TcpSocket, UdpSocket},
+    platform::{DeviceProfile, Platform},
     screen::Screen,
-    system::{Event, KeyCode, System},
+    system::{
+        Event, InputMethodHandle, InputMethods, KeyCode, MAX_DATAGRAM_SIZE, PrivateAreaKind, Program, ProgramId, ProgramKind, ProgramState, Programs,
+        SharedBuffer, SocketError, SocketEvent, SocketHandle, SocketStatus, SocketType, Sockets, System, TimerCallback, TimerId,
+    },
     task_runner::{DefaultTaskRunner, TaskRunner},
     time::Instant,
 };
@@ -38,6 +47,8 @@ pub trait Emulator {
 
 pub struct Options {
     pub enable_gdbserver: bool,
+    // 1-based `MIDlet-n` to launch without showing selection screen, for suites with multiple midlets
+    pub midlet: Option<usize>,
 }
 
 pub fn extract_zip(zip: &[u8]) -> Result<BTreeMap<String, Vec<u8>>> {
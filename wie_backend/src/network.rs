@@ -0,0 +1,40 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::net::{Ipv4Addr, SocketAddrV4};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NetworkError {
+    HostNotFound,
+    ConnectionRefused,
+    ConnectionReset,
+    TimedOut,
+    AddressInUse,
+    Other,
+}
+
+pub type NetworkResult<T> = core::result::Result<T, NetworkError>;
+
+// wipi and midp only have ipv4 networking, so we don't expose ipv6 here
+#[async_trait::async_trait]
+pub trait Network: Send + Sync {
+    async fn resolve(&self, host: &str) -> NetworkResult<Vec<Ipv4Addr>>;
+    async fn connect_tcp(&self, addr: SocketAddrV4) -> NetworkResult<Box<dyn TcpSocket>>;
+    // binding local address doesn't block, unlike others
+    fn bind_udp(&self, addr: SocketAddrV4) -> NetworkResult<Box<dyn UdpSocket>>;
+}
+
+// socket methods take `&self`, so that socket can be read and written from different tasks at once
+#[async_trait::async_trait]
+pub trait TcpSocket: Send + Sync {
+    // returns 0 on end of stream
+    async fn read(&self, buf: &mut [u8]) -> NetworkResult<usize>;
+    async fn write(&self, buf: &[u8]) -> NetworkResult<usize>;
+    fn shutdown(&self);
+}
+
+#[async_trait::async_trait]
+pub trait UdpSocket: Send + Sync {
+    async fn send_to(&self, buf: &[u8], addr: SocketAddrV4) -> NetworkResult<usize>;
+    // datagram is truncated if buffer is smaller than it
+    async fn recv_from(&self, buf: &mut [u8]) -> NetworkResult<(usize, SocketAddrV4)>;
+    fn local_addr(&self) -> SocketAddrV4;
+}
@@ -1,12 +1,28 @@
 use alloc::boxed::Box;
 
-use crate::{audio_sink::AudioSink, database::DatabaseRepository, screen::Screen, time::Instant};
+use crate::{audio_sink::AudioSink, database::DatabaseRepository, network::Network, screen::Screen, time::Instant};
+
+// characteristics of emulated handset
+#[derive(Clone, Debug)]
+pub struct DeviceProfile {
+    // maximum total size of databases per app, in bytes
+    pub storage_quota: usize,
+}
+
+impl Default for DeviceProfile {
+    // as on most wipi handsets
+    fn default() -> Self {
+        Self { storage_quota: 1024 * 1024 }
+    }
+}
 
 pub trait Platform: Send + Sync {
     fn screen(&self) -> &dyn Screen;
+    fn device_profile(&self) -> &DeviceProfile;
     fn now(&self) -> Instant;
     fn database_repository(&self) -> &dyn DatabaseRepository;
     fn audio_sink(&self) -> Box<dyn AudioSink>;
+    fn network(&self) -> &dyn Network;
     fn write_stdout(&self, buf: &[u8]);
     fn write_stderr(&self, buf: &[u8]);
     fn exit(&self);
@@ -7,4 +7,6 @@ pub trait Screen: Send + Sync {
     fn paint(&self, image: &dyn Image);
     fn width(&self) -> u32;
     fn height(&self) -> u32;
+    // enabled while app has a text field focused, so that host can send text input
+    fn set_text_input(&self, enabled: bool);
 }
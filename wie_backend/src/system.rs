@@ -1,8 +1,12 @@
 mod audio;
 mod event_queue;
 mod file_system;
+mod input_methods;
+mod programs;
+mod sockets;
 
-use alloc::{borrow::ToOwned, boxed::Box, string::String, sync::Arc};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::net::Ipv4Addr;
 
 use spin::{Mutex, MutexGuard, RwLock, RwLockWriteGuard};
 
@@ -11,6 +15,7 @@ use wie_util::Result;
 use crate::{
     AsyncCallable,
     executor::Executor,
+    network::NetworkResult,
     platform::Platform,
     task::{SleepFuture, YieldFuture},
     task_runner::TaskRunner,
@@ -18,7 +23,12 @@ use crate::{
 
 use self::{audio::Audio, event_queue::EventQueue, file_system::Filesystem};
 
-pub use self::event_queue::{Event, KeyCode};
+pub use self::{
+    event_queue::{Event, KeyCode, TimerCallback, TimerId},
+    input_methods::{InputMethodHandle, InputMethods},
+    programs::{PrivateAreaKind, Program, ProgramId, ProgramKind, ProgramState, Programs, SharedBuffer},
+    sockets::{MAX_DATAGRAM_SIZE, SocketError, SocketEvent, SocketHandle, SocketStatus, SocketType, Sockets},
+};
 
 #[derive(Clone)]
 pub struct System {
@@ -29,6 +39,9 @@ pub struct System {
     filesystem: Arc<Mutex<Filesystem>>,
     event_queue: Arc<RwLock<EventQueue>>,
     audio: Arc<RwLock<Audio>>,
+    sockets: Arc<Mutex<Sockets>>,
+    input_methods: Arc<Mutex<InputMethods>>,
+    programs: Arc<Mutex<Programs>>,
     task_runner: Arc<dyn TaskRunner>,
 }
 
@@ -47,6 +60,9 @@ impl System {
             filesystem: Arc::new(Mutex::new(Filesystem::new())),
             event_queue: Arc::new(RwLock::new(EventQueue::new())),
             audio: Arc::new(RwLock::new(Audio::new(audio_sink))),
+            sockets: Arc::new(Mutex::new(Sockets::new())),
+            input_methods: Arc::new(Mutex::new(InputMethods::new())),
+            programs: Arc::new(Mutex::new(Programs::new(aid))),
             task_runner: Arc::new(task_runner),
         }
     }
@@ -92,10 +108,38 @@ impl System {
         self.platform.as_ref().as_ref()
     }
 
+    // lookup is polled with backoff like other platform socket operations
+    pub async fn resolve(&self, host: &str) -> NetworkResult<Vec<Ipv4Addr>> {
+        sockets::poll_with_backoff(self, self.platform().network().resolve(host)).await
+    }
+
     pub fn audio(&self) -> RwLockWriteGuard<'_, Audio> {
         self.audio.as_ref().write()
     }
 
+    pub fn sockets(&self) -> MutexGuard<'_, Sockets> {
+        self.sockets.lock()
+    }
+
+    pub fn input_methods(&self) -> MutexGuard<'_, InputMethods> {
+        self.input_methods.lock()
+    }
+
+    pub fn programs(&self) -> MutexGuard<'_, Programs> {
+        self.programs.lock()
+    }
+
+    // updates text field focus, turning host text input on while any text field is focused
+    pub fn set_input_method_focus(&self, handle: InputMethodHandle, focused: bool) {
+        let enabled = {
+            let mut input_methods = self.input_methods();
+            input_methods.set_focus(handle, focused);
+            input_methods.focused().is_some()
+        };
+
+        self.platform().screen().set_text_input(enabled);
+    }
+
     pub fn event_queue(&self) -> RwLockWriteGuard<'_, EventQueue> {
         self.event_queue.write()
     }
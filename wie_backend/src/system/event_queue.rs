@@ -1,4 +1,8 @@
-use alloc::{boxed::Box, collections::VecDeque};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    string::String,
+};
 use core::pin::Pin;
 
 use wie_util::Result;
@@ -62,38 +66,91 @@ impl KeyCode {
     }
 }
 
-type TimerCallback = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Result<()>> + Send>> + Send + Sync>;
+pub type TimerCallback = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = Result<()>> + Send>> + Send + Sync>;
+
+// wipi timers are identified by address of timer struct on app memory
+pub type TimerId = u32;
 
 pub enum Event {
     Redraw,
     Keydown(KeyCode),
     Keyup(KeyCode),
     Keyrepeat(KeyCode),
-    Timer { due: Instant, callback: TimerCallback },
+    // text typed on host keyboard, entered into the focused text field
+    Text(String),
+    // pointer positions are in screen coordinates
+    PointerDown { x: i32, y: i32 },
+    PointerUp { x: i32, y: i32 },
+    PointerMove { x: i32, y: i32 },
     Notify { r#type: i32, param1: i32, param2: i32 }, // wipi notifyEvent
+    // host lost focus, app should pause
+    Suspend,
+    Resume,
+    // host is closing, app should clean up and exit
+    Terminate,
 }
 
-impl Event {
-    pub fn timer<F, Fut>(due: Instant, callback: F) -> Self
-    where
-        F: FnOnce() -> Fut + Send + Sync + 'static,
-        Fut: Future<Output = Result<()>> + Send + 'static,
-    {
-        Event::Timer {
-            due,
-            callback: Box::new(move || Box::pin(callback())),
-        }
-    }
+struct Timer {
+    due: Instant,
+    sequence: u64, // timers with same due are fired in order they were set
+    callback: TimerCallback,
 }
 
 #[derive(Default)]
 pub struct EventQueue {
     events: VecDeque<Event>,
+    timers: BTreeMap<TimerId, Timer>,
+    last_sequence: u64,
 }
 
 impl EventQueue {
     pub fn new() -> Self {
-        Self { events: VecDeque::new() }
+        Self {
+            events: VecDeque::new(),
+            timers: BTreeMap::new(),
+            last_sequence: 0,
+        }
+    }
+
+    // setting timer with same id replaces pending one
+    pub fn set_timer<F, Fut>(&mut self, id: TimerId, due: Instant, callback: F)
+    where
+        F: FnOnce() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.last_sequence += 1;
+        self.timers.insert(
+            id,
+            Timer {
+                due,
+                sequence: self.last_sequence,
+                callback: Box::new(move || Box::pin(callback())),
+            },
+        );
+    }
+
+    // returns false if timer is not pending
+    pub fn cancel_timer(&mut self, id: TimerId) -> bool {
+        self.timers.remove(&id).is_some()
+    }
+
+    pub fn is_timer_pending(&self, id: TimerId) -> bool {
+        self.timers.contains_key(&id)
+    }
+
+    pub fn next_timer_due(&self) -> Option<Instant> {
+        self.timers.values().map(|x| x.due).min()
+    }
+
+    // removes earliest timer which is due on `now`
+    pub fn pop_due_timer(&mut self, now: Instant) -> Option<TimerCallback> {
+        let (&id, _) = self
+            .timers
+            .iter()
+            .filter(|(_, x)| x.due <= now)
+            .min_by_key(|(_, x)| (x.due, x.sequence))?;
+
+        self.timers.remove(&id).map(|x| x.callback)
     }
 
     pub fn push(&mut self, event: Event) {
@@ -104,3 +161,37 @@ impl EventQueue {
         self.events.pop_front()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::Instant;
+
+    use super::EventQueue;
+
+    #[test]
+    fn test_timers() {
+        let mut queue = EventQueue::new();
+        let at = Instant::from_epoch_millis;
+
+        queue.set_timer(0x100, at(30), || async { Ok(()) });
+        queue.set_timer(0x200, at(20), || async { Ok(()) });
+        queue.set_timer(0x300, at(20), || async { Ok(()) });
+        assert_eq!(queue.next_timer_due().map(|x| x.raw()), Some(20));
+
+        // re-arm replaces pending instance
+        queue.set_timer(0x200, at(40), || async { Ok(()) });
+        assert!(queue.is_timer_pending(0x200));
+
+        assert!(queue.pop_due_timer(at(10)).is_none());
+        assert!(queue.pop_due_timer(at(25)).is_some());
+        assert!(!queue.is_timer_pending(0x300));
+        assert!(queue.pop_due_timer(at(25)).is_none());
+
+        assert!(queue.cancel_timer(0x100));
+        assert!(!queue.cancel_timer(0x100));
+        assert_eq!(queue.next_timer_due().map(|x| x.raw()), Some(40));
+
+        assert!(queue.pop_due_timer(at(40)).is_some());
+        assert!(queue.next_timer_due().is_none());
+    }
+}
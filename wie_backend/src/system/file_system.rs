@@ -72,6 +72,12 @@ impl Filesystem {
         }
     }
 
+    pub fn remove(&mut self, path: &str) -> bool {
+        let normalized_path = Self::normalize_path(path);
+
+        self.virtual_files.remove(normalized_path).is_some()
+    }
+
     pub fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
         self.virtual_files.iter().map(|(k, v)| (k.as_str(), v.as_slice()))
     }
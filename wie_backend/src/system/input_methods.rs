@@ -0,0 +1,52 @@
+use alloc::collections::BTreeMap;
+
+use crate::input_method::{InputConstraint, InputMethod};
+
+pub type InputMethodHandle = u32;
+
+// handle table of input method states of app text fields, as text field objects live on the app side
+#[derive(Default)]
+pub struct InputMethods {
+    input_methods: BTreeMap<InputMethodHandle, InputMethod>,
+    last_handle: InputMethodHandle,
+    focused: Option<InputMethodHandle>,
+}
+
+impl InputMethods {
+    pub fn new() -> Self {
+        Self {
+            input_methods: BTreeMap::new(),
+            last_handle: 0,
+            focused: None,
+        }
+    }
+
+    pub fn create(&mut self, constraint: InputConstraint, max_length: usize) -> InputMethodHandle {
+        self.last_handle += 1;
+        self.input_methods.insert(self.last_handle, InputMethod::new(constraint, max_length));
+
+        self.last_handle
+    }
+
+    pub fn get(&mut self, handle: InputMethodHandle) -> Option<&mut InputMethod> {
+        self.input_methods.get_mut(&handle)
+    }
+
+    pub fn destroy(&mut self, handle: InputMethodHandle) {
+        self.input_methods.remove(&handle);
+        self.set_focus(handle, false);
+    }
+
+    // only one text field can have focus, focusing another one replaces it
+    pub fn set_focus(&mut self, handle: InputMethodHandle, focused: bool) {
+        if focused {
+            self.focused = Some(handle);
+        } else if self.focused == Some(handle) {
+            self.focused = None;
+        }
+    }
+
+    pub fn focused(&mut self) -> Option<&mut InputMethod> {
+        self.input_methods.get_mut(&self.focused?)
+    }
+}
@@ -0,0 +1,249 @@
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+pub type ProgramId = u32;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramKind {
+    Application,
+    Library,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramState {
+    Idle,
+    Running,
+    Paused,
+    Stopped,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum PrivateAreaKind {
+    Application,
+    Library,
+}
+
+pub struct Program {
+    pub name: String,
+    pub path: String, // resource path of executable, empty for main program
+    pub kind: ProgramKind,
+    pub state: ProgramState,
+    pub parent: Option<ProgramId>,
+    pub access_level: u32,
+    pub entry: Option<u32>, // set after executable is loaded
+}
+
+// buffer is allocated on app memory, we keep handle only
+#[derive(Clone, Copy)]
+pub struct SharedBuffer {
+    pub handle: u32,
+    pub size: u32,
+}
+
+// memory region occupied by loaded executable
+#[derive(Clone, Copy)]
+struct ImageRegion {
+    program: ProgramId,
+    base: u32,
+    size: u32,
+}
+
+// executables of package and states shared between them. main program is registered on creation with id 1
+pub struct Programs {
+    programs: BTreeMap<ProgramId, Program>,
+    last_id: ProgramId,
+    current: ProgramId,
+    shared_buffers: BTreeMap<String, SharedBuffer>,
+    private_areas: BTreeMap<(ProgramId, PrivateAreaKind), u32>,
+    image_regions: Vec<ImageRegion>,
+}
+
+impl Programs {
+    pub fn new(main_name: &str) -> Self {
+        let mut result = Self {
+            programs: BTreeMap::new(),
+            last_id: 0,
+            current: 0,
+            shared_buffers: BTreeMap::new(),
+            private_areas: BTreeMap::new(),
+            image_regions: Vec::new(),
+        };
+
+        result.current = result.register(main_name, "");
+        result.programs.get_mut(&result.current).unwrap().state = ProgramState::Running;
+
+        result
+    }
+
+    pub fn register(&mut self, name: &str, path: &str) -> ProgramId {
+        self.last_id += 1;
+        self.programs.insert(
+            self.last_id,
+            Program {
+                name: name.to_owned(),
+                path: path.to_owned(),
+                kind: ProgramKind::Application,
+                state: ProgramState::Idle,
+                parent: None,
+                access_level: 0,
+                entry: None,
+            },
+        );
+
+        self.last_id
+    }
+
+    pub fn find(&self, name: &str) -> Option<ProgramId> {
+        self.programs.iter().find(|(_, x)| x.name == name).map(|(&id, _)| id)
+    }
+
+    pub fn get(&self, id: ProgramId) -> Option<&Program> {
+        self.programs.get(&id)
+    }
+
+    pub fn get_mut(&mut self, id: ProgramId) -> Option<&mut Program> {
+        self.programs.get_mut(&id)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.programs.values().map(|x| x.name.as_str())
+    }
+
+    pub fn current(&self) -> ProgramId {
+        self.current
+    }
+
+    // exclusive start pauses parent until child stops, otherwise both of them keep running
+    pub fn start(&mut self, id: ProgramId, exclusive: bool) {
+        let parent = self.current;
+
+        if exclusive && let Some(x) = self.programs.get_mut(&parent) {
+            x.state = ProgramState::Paused;
+        }
+
+        if let Some(x) = self.programs.get_mut(&id) {
+            x.kind = ProgramKind::Application;
+            x.state = ProgramState::Running;
+            x.parent = Some(parent);
+        }
+
+        self.current = id;
+    }
+
+    // returns false if program doesn't exist
+    pub fn stop(&mut self, id: ProgramId) -> bool {
+        let Some(program) = self.programs.get_mut(&id) else {
+            return false;
+        };
+        program.state = ProgramState::Stopped;
+        let parent = program.parent;
+
+        self.private_areas.retain(|(x, _), _| *x != id);
+
+        if let Some(parent) = parent {
+            if let Some(x) = self.programs.get_mut(&parent)
+                && x.state == ProgramState::Paused
+            {
+                x.state = ProgramState::Running;
+            }
+
+            if self.current == id {
+                self.current = parent;
+            }
+        }
+
+        true
+    }
+
+    pub fn create_shared_buffer(&mut self, name: &str, handle: u32, size: u32) {
+        self.shared_buffers.insert(name.to_string(), SharedBuffer { handle, size });
+    }
+
+    pub fn shared_buffer(&self, name: &str) -> Option<SharedBuffer> {
+        self.shared_buffers.get(name).copied()
+    }
+
+    pub fn shared_buffer_name(&self, handle: u32) -> Option<String> {
+        self.shared_buffers.iter().find(|(_, x)| x.handle == handle).map(|(name, _)| name.clone())
+    }
+
+    pub fn remove_shared_buffer(&mut self, name: &str) -> Option<SharedBuffer> {
+        self.shared_buffers.remove(name)
+    }
+
+    pub fn add_image_region(&mut self, id: ProgramId, base: u32, size: u32) {
+        self.image_regions.push(ImageRegion { program: id, base, size });
+    }
+
+    // returns program whose image overlaps given region, images of same program are not checked
+    pub fn overlapping_image(&self, id: ProgramId, base: u32, size: u32) -> Option<ProgramId> {
+        let end = base as u64 + size as u64;
+
+        self.image_regions
+            .iter()
+            .find(|x| x.program != id && (x.base as u64) < end && (base as u64) < x.base as u64 + x.size as u64)
+            .map(|x| x.program)
+    }
+
+    // end address of highest loaded image, new images can be placed after it
+    pub fn image_end(&self) -> Option<u32> {
+        self.image_regions.iter().map(|x| x.base + x.size).max()
+    }
+
+    pub fn private_area(&self, id: ProgramId, kind: PrivateAreaKind) -> Option<u32> {
+        self.private_areas.get(&(id, kind)).copied()
+    }
+
+    pub fn set_private_area(&mut self, id: ProgramId, kind: PrivateAreaKind, address: u32) {
+        self.private_areas.insert((id, kind), address);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProgramState, Programs};
+
+    #[test]
+    fn test_programs() {
+        let mut programs = Programs::new("main");
+        assert_eq!(programs.current(), 1);
+
+        let child = programs.register("child", "child.bin");
+        assert_eq!(programs.find("child"), Some(child));
+
+        programs.start(child, true);
+        assert_eq!(programs.current(), child);
+        assert_eq!(programs.get(child).unwrap().parent, Some(1));
+        assert_eq!(programs.get(1).unwrap().state, ProgramState::Paused);
+
+        assert!(programs.stop(child));
+        assert_eq!(programs.current(), 1);
+        assert_eq!(programs.get(1).unwrap().state, ProgramState::Running);
+        assert_eq!(programs.get(child).unwrap().state, ProgramState::Stopped);
+
+        assert!(!programs.stop(100));
+    }
+
+    #[test]
+    fn test_image_regions() {
+        let mut programs = Programs::new("main");
+        assert_eq!(programs.image_end(), None);
+
+        programs.add_image_region(1, 0x100000, 0x2000);
+        programs.add_image_region(1, 0x102000, 0x1000);
+        assert_eq!(programs.image_end(), Some(0x103000));
+        assert_eq!(programs.overlapping_image(1, 0x101000, 0x1000), None);
+
+        let child = programs.register("child", "child.bin");
+        assert_eq!(programs.overlapping_image(child, 0x101000, 0x1000), Some(1));
+        assert_eq!(programs.overlapping_image(child, 0xff000, 0x2000), Some(1));
+        assert_eq!(programs.overlapping_image(child, 0x103000, 0x1000), None);
+
+        programs.add_image_region(child, 0x103000, 0x1000);
+        assert_eq!(programs.image_end(), Some(0x104000));
+    }
+}
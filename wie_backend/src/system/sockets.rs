@@ -0,0 +1,724 @@
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
+    future::{Future, poll_fn},
+    net::{Ipv4Addr, SocketAddrV4},
+    pin::pin,
+    task::Poll,
+};
+
+use spin::Mutex;
+
+use crate::{
+    System,
+    http::{HttpError, HttpRequest, HttpResponse, HttpResponseHeader},
+    network::{NetworkError, TcpSocket, UdpSocket},
+};
+
+pub type SocketHandle = u32;
+
+// sockets are non-blocking from the app side. platform sockets are driven by tasks, which fill receive buffers and drain send buffers.
+const READ_CHUNK_SIZE: usize = 4096;
+const RECEIVE_BUFFER_SIZE: usize = 65536;
+const SEND_BUFFER_SIZE: usize = 16384;
+const MAX_PENDING_DATAGRAMS: usize = 32;
+pub const MAX_DATAGRAM_SIZE: usize = 1472; // 1500 byte mtu minus ip and udp headers
+
+// interval to wait before reading again when receive buffer is full, or before polling platform socket again
+const BACKOFF_INTERVAL: u64 = 10;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SocketType {
+    Stream,
+    Datagram,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SocketError {
+    InvalidHandle,
+    WouldBlock,
+    NotConnected,
+    AlreadyConnected,
+    InvalidOperation,
+    Network(NetworkError),
+}
+
+impl From<NetworkError> for SocketError {
+    fn from(err: NetworkError) -> Self {
+        Self::Network(err)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SocketEvent {
+    Readable,
+    Writable,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SocketStatus {
+    pub connecting: bool,
+    pub connected: bool,
+    // there's data to read, or reading would return end of stream or error
+    pub readable: bool,
+    pub writable: bool,
+    // total bytes (or datagrams) received so far, to detect new arrivals
+    pub received: u64,
+    // bytes (or datagrams) which can be read without waiting
+    pub available: usize,
+    // bytes (or datagrams) queued but not yet passed to platform socket
+    pub pending: usize,
+    pub error: Option<NetworkError>,
+}
+
+#[derive(Clone)]
+enum Connection {
+    None,
+    Connecting,
+    Tcp(Arc<dyn TcpSocket>),
+    Udp(Arc<dyn UdpSocket>),
+    // response body is being read by a task
+    Http,
+}
+
+// http request is built before connecting, and response body is read like stream socket
+struct HttpExchange {
+    request: HttpRequest,
+    response: Option<HttpResponseHeader>,
+}
+
+struct Socket {
+    socket_type: SocketType,
+    connection: Connection,
+    closed: bool,
+    error: Option<NetworkError>,
+    received: VecDeque<u8>,
+    received_datagrams: VecDeque<(Vec<u8>, SocketAddrV4)>,
+    received_count: u64,
+    end_of_stream: bool,
+    sending: VecDeque<u8>,
+    sending_datagrams: VecDeque<(Vec<u8>, SocketAddrV4)>,
+    writer_running: bool,
+    subscriptions: [u32; 2],
+    http: Option<HttpExchange>,
+}
+
+impl Socket {
+    fn new(socket_type: SocketType) -> Self {
+        Self {
+            socket_type,
+            connection: Connection::None,
+            closed: false,
+            error: None,
+            received: VecDeque::new(),
+            received_datagrams: VecDeque::new(),
+            received_count: 0,
+            end_of_stream: false,
+            sending: VecDeque::new(),
+            sending_datagrams: VecDeque::new(),
+            writer_running: false,
+            subscriptions: [0; 2],
+            http: None,
+        }
+    }
+
+    fn status(&self) -> SocketStatus {
+        let connected = matches!(self.connection, Connection::Tcp(_) | Connection::Udp(_) | Connection::Http);
+
+        let (available, pending) = match self.socket_type {
+            SocketType::Stream => (self.received.len(), self.sending.len()),
+            SocketType::Datagram => (self.received_datagrams.len(), self.sending_datagrams.len()),
+        };
+
+        let (readable, writable) = match self.socket_type {
+            SocketType::Stream => (
+                !self.received.is_empty() || self.end_of_stream || self.error.is_some(),
+                match self.connection {
+                    Connection::None => self.http.is_some(), // request body can be written before connecting
+                    Connection::Tcp(_) => self.sending.len() < SEND_BUFFER_SIZE,
+                    _ => false,
+                },
+            ),
+            SocketType::Datagram => (
+                !self.received_datagrams.is_empty() || self.error.is_some(),
+                self.sending_datagrams.len() < MAX_PENDING_DATAGRAMS,
+            ),
+        };
+
+        SocketStatus {
+            connecting: matches!(self.connection, Connection::Connecting),
+            connected,
+            readable,
+            writable,
+            received: self.received_count,
+            available,
+            pending,
+            error: self.error,
+        }
+    }
+}
+
+// handle table of app sockets, shared by wipi and midp networking apis
+#[derive(Default)]
+pub struct Sockets {
+    sockets: BTreeMap<SocketHandle, Arc<Mutex<Socket>>>,
+    last_handle: SocketHandle,
+}
+
+impl Sockets {
+    pub fn new() -> Self {
+        Self {
+            sockets: BTreeMap::new(),
+            last_handle: 0,
+        }
+    }
+
+    pub fn create(&mut self, socket_type: SocketType) -> SocketHandle {
+        self.last_handle += 1;
+        self.sockets.insert(self.last_handle, Arc::new(Mutex::new(Socket::new(socket_type))));
+
+        self.last_handle
+    }
+
+    // connection result can be checked with `status`
+    pub fn connect(&mut self, system: &System, handle: SocketHandle, addr: SocketAddrV4) -> Result<(), SocketError> {
+        let socket = self.get(handle)?;
+
+        {
+            let mut socket = socket.lock();
+            if socket.socket_type != SocketType::Stream || socket.http.is_some() {
+                return Err(SocketError::InvalidOperation);
+            }
+            if !matches!(socket.connection, Connection::None) {
+                return Err(SocketError::AlreadyConnected);
+            }
+            socket.connection = Connection::Connecting;
+        }
+
+        tracing::debug!("Connecting socket {} to {}", handle, addr);
+
+        let mut system_clone = system.clone();
+        system.spawn(async move || {
+            let result = poll_with_backoff(&system_clone, system_clone.platform().network().connect_tcp(addr)).await;
+
+            let tcp_socket: Arc<dyn TcpSocket> = match result {
+                Ok(x) => x.into(),
+                Err(err) => {
+                    tracing::debug!("Failed to connect socket {} to {}: {:?}", handle, addr, err);
+
+                    let mut socket = socket.lock();
+                    socket.connection = Connection::None;
+                    socket.error = Some(err);
+
+                    return Ok(());
+                }
+            };
+
+            {
+                let mut socket = socket.lock();
+                if socket.closed {
+                    tcp_socket.shutdown();
+
+                    return Ok(());
+                }
+                socket.connection = Connection::Tcp(tcp_socket.clone());
+            }
+
+            read_stream(&mut system_clone, &socket, &*tcp_socket).await;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    pub fn bind(&mut self, system: &System, handle: SocketHandle, addr: SocketAddrV4) -> Result<(), SocketError> {
+        let socket = self.get(handle)?;
+
+        let mut socket_guard = socket.lock();
+        if socket_guard.socket_type != SocketType::Datagram {
+            return Err(SocketError::InvalidOperation);
+        }
+        if !matches!(socket_guard.connection, Connection::None) {
+            return Err(SocketError::AlreadyConnected);
+        }
+
+        let udp_socket: Arc<dyn UdpSocket> = system.platform().network().bind_udp(addr)?.into();
+        socket_guard.connection = Connection::Udp(udp_socket.clone());
+        drop(socket_guard);
+
+        let mut system_clone = system.clone();
+        system.spawn(async move || {
+            read_datagrams(&mut system_clone, &socket, &*udp_socket).await;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    // returns 0 on end of stream
+    pub fn read(&mut self, handle: SocketHandle, buf: &mut [u8]) -> Result<usize, SocketError> {
+        let socket = self.get(handle)?;
+        let mut socket = socket.lock();
+
+        if socket.socket_type != SocketType::Stream {
+            return Err(SocketError::InvalidOperation);
+        }
+
+        if socket.received.is_empty() {
+            return if let Some(err) = socket.error {
+                Err(err.into())
+            } else if socket.end_of_stream {
+                Ok(0)
+            } else if matches!(socket.connection, Connection::None) {
+                Err(SocketError::NotConnected)
+            } else {
+                Err(SocketError::WouldBlock)
+            };
+        }
+
+        let length = buf.len().min(socket.received.len());
+        for (dest, src) in buf.iter_mut().zip(socket.received.drain(..length)) {
+            *dest = src;
+        }
+
+        Ok(length)
+    }
+
+    // data is written in background, returns the number of bytes queued
+    pub fn write(&mut self, system: &System, handle: SocketHandle, buf: &[u8]) -> Result<usize, SocketError> {
+        let socket = self.get(handle)?;
+        let mut socket_guard = socket.lock();
+
+        if socket_guard.socket_type != SocketType::Stream {
+            return Err(SocketError::InvalidOperation);
+        }
+        if let Some(err) = socket_guard.error {
+            return Err(err.into());
+        }
+        if socket_guard.http.is_some() {
+            if !matches!(socket_guard.connection, Connection::None) {
+                return Err(SocketError::InvalidOperation);
+            }
+            socket_guard.http.as_mut().unwrap().request.body.extend_from_slice(buf);
+
+            return Ok(buf.len());
+        }
+        let Connection::Tcp(tcp_socket) = socket_guard.connection.clone() else {
+            return Err(SocketError::NotConnected);
+        };
+
+        let length = buf.len().min(SEND_BUFFER_SIZE - socket_guard.sending.len());
+        if length == 0 && !buf.is_empty() {
+            return Err(SocketError::WouldBlock);
+        }
+        socket_guard.sending.extend(&buf[..length]);
+
+        if !socket_guard.writer_running {
+            socket_guard.writer_running = true;
+            drop(socket_guard);
+
+            let system_clone = system.clone();
+            system.spawn(async move || {
+                write_stream(&system_clone, &socket, &*tcp_socket).await;
+
+                Ok(())
+            });
+        }
+
+        Ok(length)
+    }
+
+    // unbound socket is bound to any local address first
+    pub fn send_to(&mut self, system: &System, handle: SocketHandle, buf: &[u8], addr: SocketAddrV4) -> Result<usize, SocketError> {
+        let socket = self.get(handle)?;
+
+        {
+            let socket = socket.lock();
+            if socket.socket_type != SocketType::Datagram {
+                return Err(SocketError::InvalidOperation);
+            }
+            if buf.len() > MAX_DATAGRAM_SIZE {
+                return Err(SocketError::InvalidOperation);
+            }
+            if socket.sending_datagrams.len() >= MAX_PENDING_DATAGRAMS {
+                return Err(SocketError::WouldBlock);
+            }
+        }
+
+        if matches!(socket.lock().connection, Connection::None) {
+            self.bind(system, handle, SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0))?;
+        }
+
+        let mut socket_guard = socket.lock();
+        let Connection::Udp(udp_socket) = socket_guard.connection.clone() else {
+            return Err(SocketError::NotConnected);
+        };
+
+        socket_guard.sending_datagrams.push_back((buf.to_vec(), addr));
+
+        if !socket_guard.writer_running {
+            socket_guard.writer_running = true;
+            drop(socket_guard);
+
+            let system_clone = system.clone();
+            system.spawn(async move || {
+                write_datagrams(&system_clone, &socket, &*udp_socket).await;
+
+                Ok(())
+            });
+        }
+
+        Ok(buf.len())
+    }
+
+    // remaining part of datagram is discarded if buffer is too small
+    pub fn recv_from(&mut self, handle: SocketHandle, buf: &mut [u8]) -> Result<(usize, SocketAddrV4), SocketError> {
+        let socket = self.get(handle)?;
+        let mut socket = socket.lock();
+
+        if socket.socket_type != SocketType::Datagram {
+            return Err(SocketError::InvalidOperation);
+        }
+
+        let Some((data, addr)) = socket.received_datagrams.pop_front() else {
+            return if let Some(err) = socket.error {
+                Err(err.into())
+            } else if matches!(socket.connection, Connection::None) {
+                Err(SocketError::NotConnected)
+            } else {
+                Err(SocketError::WouldBlock)
+            };
+        };
+
+        let length = buf.len().min(data.len());
+        buf[..length].copy_from_slice(&data[..length]);
+
+        Ok((length, addr))
+    }
+
+    pub fn status(&self, handle: SocketHandle) -> Result<SocketStatus, SocketError> {
+        Ok(self.get(handle)?.lock().status())
+    }
+
+    pub fn local_addr(&self, handle: SocketHandle) -> Result<SocketAddrV4, SocketError> {
+        match &self.get(handle)?.lock().connection {
+            Connection::Udp(x) => Ok(x.local_addr()),
+            _ => Err(SocketError::NotConnected),
+        }
+    }
+
+    // creates stream socket for http request. data written before `connect_http` is sent as request body,
+    // and response body can be read after connecting.
+    pub fn open_http(&mut self, url: &str) -> SocketHandle {
+        let handle = self.create(SocketType::Stream);
+        self.sockets[&handle].lock().http = Some(HttpExchange {
+            request: HttpRequest::new(url),
+            response: None,
+        });
+
+        handle
+    }
+
+    pub fn http_request(&self, handle: SocketHandle) -> Result<HttpRequest, SocketError> {
+        let socket = self.get(handle)?;
+        let socket = socket.lock();
+
+        Ok(socket.http.as_ref().ok_or(SocketError::InvalidOperation)?.request.clone())
+    }
+
+    pub fn set_http_request(&mut self, handle: SocketHandle, request: HttpRequest) -> Result<(), SocketError> {
+        let socket = self.get(handle)?;
+        let mut socket = socket.lock();
+
+        if !matches!(socket.connection, Connection::None) {
+            return Err(SocketError::AlreadyConnected);
+        }
+        socket.http.as_mut().ok_or(SocketError::InvalidOperation)?.request = request;
+
+        Ok(())
+    }
+
+    // available after connected
+    pub fn http_response(&self, handle: SocketHandle) -> Result<Option<HttpResponseHeader>, SocketError> {
+        let socket = self.get(handle)?;
+        let socket = socket.lock();
+
+        Ok(socket.http.as_ref().ok_or(SocketError::InvalidOperation)?.response.clone())
+    }
+
+    // sends request and receives response header in background. result can be checked with `status`.
+    pub fn connect_http(&mut self, system: &System, handle: SocketHandle) -> Result<(), SocketError> {
+        let socket = self.get(handle)?;
+
+        let request = {
+            let mut socket = socket.lock();
+            let request = socket.http.as_ref().ok_or(SocketError::InvalidOperation)?.request.clone();
+            if !matches!(socket.connection, Connection::None) {
+                return Err(SocketError::AlreadyConnected);
+            }
+            socket.connection = Connection::Connecting;
+
+            request
+        };
+
+        let mut system_clone = system.clone();
+        system.spawn(async move || {
+            let result = poll_with_backoff(&system_clone, request.send(system_clone.platform().network())).await;
+
+            let mut response = match result {
+                Ok(x) => x,
+                Err(err) => {
+                    tracing::debug!("Http request to {} failed: {:?}", request.url, err);
+
+                    let mut socket = socket.lock();
+                    socket.connection = Connection::None;
+                    socket.error = Some(to_network_error(err));
+
+                    return Ok(());
+                }
+            };
+
+            {
+                let mut socket = socket.lock();
+                if socket.closed {
+                    return Ok(());
+                }
+                socket.connection = Connection::Http;
+                socket.http.as_mut().unwrap().response = Some(response.header.clone());
+            }
+
+            read_http_body(&mut system_clone, &socket, &mut response).await;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    // pending writes are flushed before shutting down the connection
+    pub fn close(&mut self, handle: SocketHandle) -> Result<(), SocketError> {
+        let socket = self.sockets.remove(&handle).ok_or(SocketError::InvalidHandle)?;
+        let mut socket = socket.lock();
+
+        socket.closed = true;
+        if let Connection::Tcp(tcp_socket) = &socket.connection
+            && !socket.writer_running
+        {
+            tcp_socket.shutdown();
+        }
+
+        Ok(())
+    }
+
+    // returns subscription id, which stays valid until next subscription to the same event or close.
+    // it lets callers waiting on socket events cancel previous waiters.
+    pub fn subscribe(&mut self, handle: SocketHandle, event: SocketEvent) -> Result<u32, SocketError> {
+        let socket = self.get(handle)?;
+        let mut socket = socket.lock();
+
+        let subscription = &mut socket.subscriptions[event as usize];
+        *subscription += 1;
+
+        Ok(*subscription)
+    }
+
+    pub fn is_subscribed(&self, handle: SocketHandle, event: SocketEvent, subscription: u32) -> bool {
+        self.get(handle).is_ok_and(|x| x.lock().subscriptions[event as usize] == subscription)
+    }
+
+    fn get(&self, handle: SocketHandle) -> Result<Arc<Mutex<Socket>>, SocketError> {
+        self.sockets.get(&handle).cloned().ok_or(SocketError::InvalidHandle)
+    }
+}
+
+async fn read_stream(system: &mut System, socket: &Mutex<Socket>, tcp_socket: &dyn TcpSocket) {
+    let mut buf = vec![0; READ_CHUNK_SIZE];
+
+    loop {
+        if socket.lock().received.len() >= RECEIVE_BUFFER_SIZE {
+            system.sleep(BACKOFF_INTERVAL).await;
+            continue;
+        }
+
+        let Some(result) = unless_closed(socket, poll_with_backoff(system, tcp_socket.read(&mut buf))).await else {
+            break;
+        };
+
+        let mut socket = socket.lock();
+
+        match result {
+            Ok(0) => {
+                socket.end_of_stream = true;
+                break;
+            }
+            Ok(length) => {
+                socket.received.extend(&buf[..length]);
+                socket.received_count += length as u64;
+            }
+            Err(err) => {
+                socket.error = Some(err);
+                break;
+            }
+        }
+    }
+}
+
+async fn read_http_body(system: &mut System, socket: &Mutex<Socket>, response: &mut HttpResponse) {
+    let mut buf = vec![0; READ_CHUNK_SIZE];
+
+    loop {
+        if socket.lock().received.len() >= RECEIVE_BUFFER_SIZE {
+            system.sleep(BACKOFF_INTERVAL).await;
+            continue;
+        }
+
+        let Some(result) = unless_closed(socket, poll_with_backoff(system, response.read(&mut buf))).await else {
+            break;
+        };
+
+        let mut socket = socket.lock();
+        match result {
+            Ok(0) => {
+                socket.end_of_stream = true;
+                break;
+            }
+            Ok(length) => {
+                socket.received.extend(&buf[..length]);
+                socket.received_count += length as u64;
+            }
+            Err(err) => {
+                socket.error = Some(to_network_error(err));
+                break;
+            }
+        }
+    }
+}
+
+async fn write_stream(system: &System, socket: &Mutex<Socket>, tcp_socket: &dyn TcpSocket) {
+    loop {
+        let chunk = {
+            let mut socket = socket.lock();
+            if socket.sending.is_empty() || socket.error.is_some() {
+                socket.writer_running = false;
+                if socket.closed {
+                    tcp_socket.shutdown();
+                }
+
+                return;
+            }
+
+            socket.sending.iter().take(READ_CHUNK_SIZE).copied().collect::<Vec<_>>()
+        };
+
+        let result = poll_with_backoff(system, tcp_socket.write(&chunk)).await;
+
+        let mut socket = socket.lock();
+        match result {
+            Ok(length) => {
+                socket.sending.drain(..length);
+            }
+            Err(err) => {
+                socket.error = Some(err);
+                socket.sending.clear();
+            }
+        }
+    }
+}
+
+async fn read_datagrams(system: &mut System, socket: &Mutex<Socket>, udp_socket: &dyn UdpSocket) {
+    let mut buf = vec![0; MAX_DATAGRAM_SIZE];
+
+    loop {
+        if socket.lock().received_datagrams.len() >= MAX_PENDING_DATAGRAMS {
+            system.sleep(BACKOFF_INTERVAL).await;
+            continue;
+        }
+
+        let Some(result) = unless_closed(socket, poll_with_backoff(system, udp_socket.recv_from(&mut buf))).await else {
+            break;
+        };
+
+        let mut socket = socket.lock();
+
+        match result {
+            Ok((length, addr)) => {
+                socket.received_datagrams.push_back((buf[..length].to_vec(), addr));
+                socket.received_count += 1;
+            }
+            Err(err) => {
+                socket.error = Some(err);
+                break;
+            }
+        }
+    }
+}
+
+async fn write_datagrams(system: &System, socket: &Mutex<Socket>, udp_socket: &dyn UdpSocket) {
+    loop {
+        let datagram = {
+            let mut socket = socket.lock();
+            let datagram = if socket.closed { None } else { socket.sending_datagrams.pop_front() };
+            if datagram.is_none() {
+                socket.writer_running = false;
+            }
+
+            datagram
+        };
+        let Some((data, addr)) = datagram else {
+            return;
+        };
+
+        if let Err(err) = poll_with_backoff(system, udp_socket.send_to(&data, addr)).await {
+            // datagrams are unreliable anyway, so we just drop it
+            tracing::debug!("Failed to send datagram to {}: {:?}", addr, err);
+        }
+    }
+}
+
+// http protocol errors are reported as generic network error to apps
+fn to_network_error(err: HttpError) -> NetworkError {
+    match err {
+        HttpError::Network(x) => x,
+        _ => NetworkError::Other,
+    }
+}
+
+// stops waiting on platform socket when the socket is closed by app
+async fn unless_closed<F>(socket: &Mutex<Socket>, future: F) -> Option<F::Output>
+where
+    F: Future,
+{
+    let mut future = pin!(future);
+
+    poll_fn(|cx| {
+        if socket.lock().closed {
+            Poll::Ready(None)
+        } else {
+            future.as_mut().poll(cx).map(Some)
+        }
+    })
+    .await
+}
+
+// platform sockets don't have a reactor to wake us up, so pending platform futures are polled again after an interval
+// instead of being polled in a busy loop by the executor
+pub(crate) async fn poll_with_backoff<F>(system: &System, future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut future = pin!(future);
+
+    loop {
+        if let Poll::Ready(x) = poll_fn(|cx| Poll::Ready(future.as_mut().poll(cx))).await {
+            return x;
+        }
+
+        system.sleep(BACKOFF_INTERVAL).await;
+    }
+}
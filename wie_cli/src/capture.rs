@@ -0,0 +1,418 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use flate2::{Compression, write::ZlibEncoder};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RecordingFormat {
+    Gif,
+    Apng,
+}
+
+impl RecordingFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "gif" => Some(Self::Gif),
+            "png" | "apng" => Some(Self::Apng),
+            _ => None,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Apng => "apng",
+        }
+    }
+}
+
+struct Frame {
+    width: u32,
+    height: u32,
+    data: Vec<u32>,
+}
+
+/// Frames are encoded as they arrive, only the latest one is kept as its duration is not known until the next frame.
+struct Recording {
+    path: PathBuf,
+    format: RecordingFormat,
+    encoder: Option<FrameEncoder>,
+    pending: Option<Frame>,
+    pending_since: Instant,
+    error: Option<anyhow::Error>,
+}
+
+impl Recording {
+    fn push(&mut self, width: u32, height: u32, data: &[u32]) {
+        if self.error.is_some() {
+            return;
+        }
+
+        if let Err(x) = self.try_push(width, height, data) {
+            tracing::error!("Failed to record frame: {x}");
+            self.error = Some(x);
+        }
+    }
+
+    fn try_push(&mut self, width: u32, height: u32, data: &[u32]) -> anyhow::Result<()> {
+        if let Some(pending) = &self.pending {
+            // all frames should have the same size, first frame wins
+            if pending.width != width || pending.height != height {
+                return Ok(());
+            }
+
+            // merge identical consecutive frames, as apps tend to repaint unchanged screen
+            if pending.data == data {
+                return Ok(());
+            }
+        }
+
+        let now = Instant::now();
+        self.flush(now)?;
+
+        self.pending = Some(Frame {
+            width,
+            height,
+            data: data.to_vec(),
+        });
+        self.pending_since = now;
+
+        Ok(())
+    }
+
+    fn flush(&mut self, now: Instant) -> anyhow::Result<()> {
+        let Some(frame) = self.pending.take() else {
+            return Ok(());
+        };
+
+        let encoder = match &mut self.encoder {
+            Some(x) => x,
+            None => self
+                .encoder
+                .insert(FrameEncoder::new(&self.path, self.format, frame.width, frame.height)?),
+        };
+
+        encoder.write_frame(&frame, now - self.pending_since)
+    }
+
+    fn save(mut self) -> anyhow::Result<PathBuf> {
+        if let Some(x) = self.error.take() {
+            return Err(x);
+        }
+
+        self.flush(Instant::now())?;
+
+        match self.encoder.take() {
+            Some(x) => x.finish()?,
+            None => anyhow::bail!("No frames recorded"),
+        }
+
+        Ok(self.path)
+    }
+}
+
+/// Captures frames painted by the emulator at native resolution, before scaling.
+pub struct Capture {
+    output_dir: PathBuf,
+    last_frame: Option<(u32, u32, Vec<u32>)>,
+    recording: Option<Recording>,
+}
+
+impl Capture {
+    pub fn new(output_dir: PathBuf) -> Self {
+        Self {
+            output_dir,
+            last_frame: None,
+            recording: None,
+        }
+    }
+
+    pub fn on_paint(&mut self, width: u32, height: u32, data: &[u32]) {
+        if let Some(recording) = &mut self.recording {
+            recording.push(width, height, data);
+        }
+
+        self.last_frame = Some((width, height, data.to_vec()));
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn screenshot(&self, path: Option<&Path>) -> anyhow::Result<PathBuf> {
+        let Some((width, height, data)) = &self.last_frame else {
+            anyhow::bail!("Nothing painted yet");
+        };
+
+        let path = path.map(|x| x.to_owned()).unwrap_or_else(|| self.default_path("png"));
+
+        let file = BufWriter::new(File::create(&path)?);
+        let mut encoder = png::Encoder::new(file, *width, *height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&to_rgba(data))?;
+        writer.finish()?;
+
+        Ok(path)
+    }
+
+    pub fn start_recording(&mut self, path: Option<&Path>, format: RecordingFormat) {
+        let path = path.map(|x| x.to_owned()).unwrap_or_else(|| self.default_path(format.extension()));
+
+        let mut recording = Recording {
+            path,
+            format,
+            encoder: None,
+            pending: None,
+            pending_since: Instant::now(),
+            error: None,
+        };
+
+        // start with current screen, as the app may not repaint for a while
+        if let Some((width, height, data)) = &self.last_frame {
+            recording.push(*width, *height, data);
+        }
+
+        self.recording = Some(recording);
+    }
+
+    pub fn stop_recording(&mut self) -> anyhow::Result<Option<PathBuf>> {
+        match self.recording.take() {
+            Some(recording) => Ok(Some(recording.save()?)),
+            None => Ok(None),
+        }
+    }
+
+    fn default_path(&self, extension: &str) -> PathBuf {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+
+        self.output_dir.join(format!("wie-{timestamp}.{extension}"))
+    }
+}
+
+fn to_rgba(data: &[u32]) -> Vec<u8> {
+    // screen is always opaque
+    data.iter().flat_map(|x| [(x >> 16) as u8, (x >> 8) as u8, *x as u8, 0xff]).collect()
+}
+
+enum FrameEncoder {
+    Gif(gif::Encoder<BufWriter<File>>),
+    Apng(ApngEncoder),
+}
+
+impl FrameEncoder {
+    fn new(path: &Path, format: RecordingFormat, width: u32, height: u32) -> anyhow::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+
+        Ok(match format {
+            RecordingFormat::Gif => {
+                let mut encoder = gif::Encoder::new(file, width as _, height as _, &[])?;
+                encoder.set_repeat(gif::Repeat::Infinite)?;
+
+                Self::Gif(encoder)
+            }
+            RecordingFormat::Apng => Self::Apng(ApngEncoder::new(file, width, height)?),
+        })
+    }
+
+    fn write_frame(&mut self, frame: &Frame, duration: Duration) -> anyhow::Result<()> {
+        match self {
+            Self::Gif(encoder) => {
+                let mut rgba = to_rgba(&frame.data);
+
+                let mut gif_frame = gif::Frame::from_rgba_speed(frame.width as _, frame.height as _, &mut rgba, 10);
+                // gif delay is in 10ms units
+                gif_frame.delay = (duration.as_millis() / 10).clamp(1, u16::MAX as _) as _;
+
+                encoder.write_frame(&gif_frame)?;
+            }
+            Self::Apng(encoder) => encoder.write_frame(&frame.data, duration)?,
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> anyhow::Result<()> {
+        match self {
+            Self::Gif(encoder) => encoder.into_inner()?.flush()?,
+            Self::Apng(encoder) => encoder.finish()?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Minimal APNG writer. `png` wants the frame count before the first frame, so we write a placeholder `acTL` and patch it on finish.
+struct ApngEncoder {
+    file: BufWriter<File>,
+    width: u32,
+    height: u32,
+    num_frames: u32,
+    sequence: u32,
+}
+
+impl ApngEncoder {
+    const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+    // acTL comes right after signature and IHDR chunk
+    const ACTL_OFFSET: u64 = 8 + 12 + 13;
+
+    fn new(mut file: BufWriter<File>, width: u32, height: u32) -> io::Result<Self> {
+        file.write_all(&Self::SIGNATURE)?;
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&width.to_be_bytes());
+        ihdr.extend_from_slice(&height.to_be_bytes());
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]); // 8bit rgba, deflate, no interlace
+        write_chunk(&mut file, b"IHDR", &ihdr)?;
+
+        write_chunk(&mut file, b"acTL", &Self::actl(0))?;
+
+        Ok(Self {
+            file,
+            width,
+            height,
+            num_frames: 0,
+            sequence: 0,
+        })
+    }
+
+    fn write_frame(&mut self, data: &[u32], duration: Duration) -> io::Result<()> {
+        let delay = duration.as_millis().clamp(1, u16::MAX as _) as u16;
+
+        let mut fctl = Vec::with_capacity(26);
+        fctl.extend_from_slice(&self.next_sequence().to_be_bytes());
+        fctl.extend_from_slice(&self.width.to_be_bytes());
+        fctl.extend_from_slice(&self.height.to_be_bytes());
+        fctl.extend_from_slice(&0u32.to_be_bytes()); // x offset
+        fctl.extend_from_slice(&0u32.to_be_bytes()); // y offset
+        fctl.extend_from_slice(&delay.to_be_bytes());
+        fctl.extend_from_slice(&1000u16.to_be_bytes());
+        fctl.extend_from_slice(&[0, 0]); // APNG_DISPOSE_OP_NONE, APNG_BLEND_OP_SOURCE
+        write_chunk(&mut self.file, b"fcTL", &fctl)?;
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        for row in data.chunks(self.width as _) {
+            encoder.write_all(&[0])?; // filter type none
+            encoder.write_all(&to_rgba(row))?;
+        }
+        let compressed = encoder.finish()?;
+
+        // first frame is the default image
+        if self.num_frames == 0 {
+            write_chunk(&mut self.file, b"IDAT", &compressed)?;
+        } else {
+            let mut fdat = Vec::with_capacity(compressed.len() + 4);
+            fdat.extend_from_slice(&self.next_sequence().to_be_bytes());
+            fdat.extend_from_slice(&compressed);
+            write_chunk(&mut self.file, b"fdAT", &fdat)?;
+        }
+        self.num_frames += 1;
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        write_chunk(&mut self.file, b"IEND", &[])?;
+
+        let mut file = self.file.into_inner().map_err(|x| x.into_error())?;
+        file.seek(SeekFrom::Start(Self::ACTL_OFFSET))?;
+        write_chunk(&mut file, b"acTL", &Self::actl(self.num_frames))?;
+
+        Ok(())
+    }
+
+    fn next_sequence(&mut self) -> u32 {
+        self.sequence += 1;
+
+        self.sequence - 1
+    }
+
+    fn actl(num_frames: u32) -> [u8; 8] {
+        let mut result = [0; 8];
+        result[..4].copy_from_slice(&num_frames.to_be_bytes());
+        // num_plays 0 is infinite loop
+
+        result
+    }
+}
+
+fn write_chunk<W: Write>(writer: &mut W, r#type: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(r#type);
+    hasher.update(data);
+
+    writer.write_all(&(data.len() as u32).to_be_bytes())?;
+    writer.write_all(r#type)?;
+    writer.write_all(data)?;
+    writer.write_all(&hasher.finalize().to_be_bytes())
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, fs::File, io::BufReader, path::PathBuf, process};
+
+    use super::{Capture, RecordingFormat};
+
+    const RED: u32 = 0xff0000;
+    const BLUE: u32 = 0x0000ff;
+
+    fn record(format: RecordingFormat, extension: &str) -> anyhow::Result<PathBuf> {
+        let dir = env::temp_dir();
+        let path = dir.join(format!("wie_capture_test_{}.{extension}", process::id()));
+
+        let mut capture = Capture::new(dir);
+        capture.start_recording(Some(&path), format);
+        capture.on_paint(2, 2, &[RED; 4]);
+        capture.on_paint(2, 2, &[RED; 4]); // merged
+        capture.on_paint(3, 3, &[BLUE; 9]); // size mismatch, dropped
+        capture.on_paint(2, 2, &[BLUE; 4]);
+
+        assert_eq!(capture.stop_recording()?, Some(path.clone()));
+
+        Ok(path)
+    }
+
+    #[test]
+    fn test_record_apng() -> anyhow::Result<()> {
+        let path = record(RecordingFormat::Apng, "apng")?;
+
+        let decoder = png::Decoder::new(BufReader::new(File::open(&path)?));
+        let mut reader = decoder.read_info()?;
+        assert_eq!(reader.info().animation_control.map(|x| x.num_frames), Some(2));
+
+        let mut buf = vec![0; 2 * 2 * 4];
+        reader.next_frame(&mut buf)?;
+        assert_eq!(&buf[..4], &[0xff, 0, 0, 0xff]);
+        reader.next_frame(&mut buf)?;
+        assert_eq!(&buf[..4], &[0, 0, 0xff, 0xff]);
+
+        fs::remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_gif() -> anyhow::Result<()> {
+        let path = record(RecordingFormat::Gif, "gif")?;
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(File::open(&path)?)?;
+
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame()? {
+            frames.push(frame.buffer[..4].to_vec());
+        }
+        assert_eq!(frames, [[0xff, 0, 0, 0xff], [0, 0, 0xff, 0xff]]);
+
+        fs::remove_file(&path)?;
+
+        Ok(())
+    }
+}
@@ -1,119 +1,94 @@
-use std::{fs, path::PathBuf};
+mod archive;
+mod directory;
+mod journal;
 
-use directories::ProjectDirs;
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-use wie_backend::{RecordId, System};
+use directories::ProjectDirs;
 
-pub struct DatabaseRepository {
-    base_path: PathBuf,
+use wie_backend::RecordId;
+
+pub use self::{
+    archive::{export_archive, import_archive},
+    directory::DirectoryRepository,
+    journal::JournalRepository,
+};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum StorageKind {
+    /// One file per record, under a directory per record store
+    #[default]
+    Directory,
+    /// All record stores of an app in one journaled file
+    Journal,
 }
 
-impl DatabaseRepository {
-    pub fn new() -> Self {
-        let base_dir = ProjectDirs::from("net", "dlunch", "wie").unwrap();
-
-        let base_path = base_dir.data_dir().to_owned();
-
-        Self { base_path }
-    }
-
-    fn get_path_for_database(&self, name: &str, app_id: &str) -> PathBuf {
-        self.base_path.join(app_id).join(name)
-    }
+// backend-independent snapshot of an app's record stores, used for import, export and migration
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AppData {
+    pub stores: BTreeMap<String, StoreData>,
 }
 
-#[async_trait::async_trait]
-impl wie_backend::DatabaseRepository for DatabaseRepository {
-    async fn open(&self, _system: &System, name: &str, app_id: &str) -> Box<dyn wie_backend::Database> {
-        let path = self.get_path_for_database(name, app_id);
-
-        Box::new(Database::new(path).unwrap())
-    }
-
-    async fn exists(&self, _system: &System, name: &str, app_id: &str) -> bool {
-        let path = self.get_path_for_database(name, app_id);
-
-        path.exists()
-    }
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StoreData {
+    pub version: u32,
+    pub last_modified: u64,
+    pub records: BTreeMap<RecordId, Vec<u8>>,
 }
 
-pub struct Database {
-    base_path: PathBuf,
+pub trait Storage: wie_backend::DatabaseRepository + Send + Sync {
+    fn app_ids(&self) -> anyhow::Result<Vec<String>>;
+    fn export(&self, app_id: &str) -> anyhow::Result<AppData>;
+    // replaces all existing record stores of the app
+    fn import(&self, app_id: &str, data: &AppData) -> anyhow::Result<()>;
 }
 
-impl Database {
-    pub fn new(base_path: PathBuf) -> anyhow::Result<Self> {
-        tracing::trace!("Opening database at {:?}", base_path);
-
-        fs::create_dir_all(&base_path)?;
-
-        Ok(Self { base_path })
-    }
-
-    fn find_empty_record_id(&self) -> RecordId {
-        let mut record_id = 1; // XXX midp requires first record to be 1
-
-        loop {
-            let path = self.base_path.join(record_id.to_string());
+pub fn open_storage(kind: StorageKind) -> Box<dyn Storage> {
+    let base_path = data_dir();
 
-            if !path.exists() {
-                return record_id;
-            }
-
-            record_id += 1;
-        }
-    }
-    fn get_path_for_record(&self, id: RecordId) -> PathBuf {
-        self.base_path.join(id.to_string())
+    match kind {
+        StorageKind::Directory => Box::new(DirectoryRepository::new(base_path)),
+        StorageKind::Journal => Box::new(JournalRepository::new(base_path)),
     }
 }
 
-#[async_trait::async_trait]
-impl wie_backend::Database for Database {
-    async fn next_id(&self) -> RecordId {
-        self.find_empty_record_id()
-    }
-
-    async fn add(&mut self, data: &[u8]) -> RecordId {
-        let id = self.find_empty_record_id();
-
-        tracing::trace!("Adding record {id} to database {:?}", &self.base_path);
-
-        let path = self.get_path_for_record(id);
-        fs::write(path, data).unwrap();
-
-        id
-    }
+pub fn data_dir() -> PathBuf {
+    let base_dir = ProjectDirs::from("net", "dlunch", "wie").unwrap();
 
-    async fn get(&self, id: RecordId) -> Option<Vec<u8>> {
-        let path = self.get_path_for_record(id);
+    base_dir.data_dir().to_owned()
+}
 
-        tracing::trace!("Read record {id} from database {:?}", &self.base_path);
+// app ids and record store names may come from an archive or an app, and are used as path components.
+// reject anything that could escape the data directory
+pub fn validate_name(name: &str) -> anyhow::Result<()> {
+    let is_single_component = matches!(Path::new(name).components().collect::<Vec<_>>().as_slice(), [Component::Normal(x)] if *x == name);
 
-        fs::read(path).ok()
+    if name.is_empty() || name.contains(['/', '\\', '\0']) || !is_single_component {
+        anyhow::bail!("Invalid name {name:?}");
     }
 
-    async fn set(&mut self, id: RecordId, data: &[u8]) -> bool {
-        let path = self.get_path_for_record(id);
-
-        tracing::trace!("Set record {id} to database {:?}", &self.base_path);
-
-        fs::write(path, data).is_ok()
-    }
+    Ok(())
+}
 
-    async fn delete(&mut self, id: RecordId) -> bool {
-        let path = self.get_path_for_record(id);
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as _
+}
 
-        tracing::trace!("Delete record {id} from database {:?}", &self.base_path);
+#[cfg(test)]
+mod test {
+    use super::validate_name;
 
-        fs::remove_file(path).is_ok()
-    }
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("app").is_ok());
+        assert!(validate_name("save slot..1").is_ok());
 
-    async fn get_record_ids(&self) -> Vec<RecordId> {
-        fs::read_dir(&self.base_path)
-            .unwrap()
-            .filter(|x| x.as_ref().unwrap().path().is_file())
-            .map(|x| x.unwrap().file_name().to_str().unwrap().parse().unwrap())
-            .collect()
+        for name in ["", ".", "..", "../../home/user", "/etc", "a/b", "a\\b", "C:\\Windows", "a\0b"] {
+            assert!(validate_name(name).is_err(), "{name:?} should be rejected");
+        }
     }
 }
@@ -0,0 +1,144 @@
+use std::{
+    collections::BTreeMap,
+    io::{Cursor, Read, Write},
+};
+
+use zip::{CompressionMethod, ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+use super::{AppData, StoreData, validate_name};
+
+// save data archive is a zip file with following entries, independent of storage backend:
+// `app_id`, and for each record store `stores/<n>/name`, `stores/<n>/metadata` (`<version> <last_modified>`) and `stores/<n>/records/<id>`.
+// record store names are stored in a file, as they may contain characters not allowed in zip entry names.
+// app id and record store names are used in host paths by storage backends, so they are validated on import.
+const APP_ID_ENTRY: &str = "app_id";
+
+pub fn export_archive(app_id: &str, data: &AppData) -> anyhow::Result<Vec<u8>> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    writer.start_file(APP_ID_ENTRY, options)?;
+    writer.write_all(app_id.as_bytes())?;
+
+    for (index, (name, store)) in data.stores.iter().enumerate() {
+        writer.start_file(format!("stores/{index}/name"), options)?;
+        writer.write_all(name.as_bytes())?;
+
+        writer.start_file(format!("stores/{index}/metadata"), options)?;
+        writer.write_all(format!("{} {}", store.version, store.last_modified).as_bytes())?;
+
+        for (id, record) in &store.records {
+            writer.start_file(format!("stores/{index}/records/{id}"), options)?;
+            writer.write_all(record)?;
+        }
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+pub fn import_archive(archive: &[u8]) -> anyhow::Result<(String, AppData)> {
+    let mut archive = ZipArchive::new(Cursor::new(archive))?;
+
+    let mut app_id = None;
+    let mut names = BTreeMap::new();
+    let mut stores: BTreeMap<usize, StoreData> = BTreeMap::new();
+
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        if file.is_dir() {
+            continue;
+        }
+
+        let path = file.name().to_owned();
+        let mut content = Vec::new();
+        file.read_to_end(&mut content)?;
+
+        if path == APP_ID_ENTRY {
+            app_id = Some(String::from_utf8(content)?);
+            continue;
+        }
+
+        let parts = path.split('/').collect::<Vec<_>>();
+        let (index, rest) = match parts.as_slice() {
+            ["stores", index, rest @ ..] => (index.parse::<usize>()?, rest),
+            _ => {
+                tracing::warn!("Skipping unknown entry {path} in save data archive");
+                continue;
+            }
+        };
+
+        match rest {
+            ["name"] => {
+                names.insert(index, String::from_utf8(content)?);
+            }
+            ["metadata"] => {
+                let metadata = String::from_utf8(content)?;
+                let (version, last_modified) = metadata.split_once(' ').ok_or_else(|| anyhow::anyhow!("Invalid metadata in {path}"))?;
+
+                let store = stores.entry(index).or_default();
+                store.version = version.parse()?;
+                store.last_modified = last_modified.trim().parse()?;
+            }
+            ["records", id] => {
+                stores.entry(index).or_default().records.insert(id.parse()?, content);
+            }
+            _ => tracing::warn!("Skipping unknown entry {path} in save data archive"),
+        }
+    }
+
+    let app_id = app_id.ok_or_else(|| anyhow::anyhow!("Save data archive doesn't have app id"))?;
+    validate_name(&app_id)?;
+
+    let mut result = AppData::default();
+    for (index, name) in names {
+        validate_name(&name)?;
+        result.stores.insert(name, stores.remove(&index).unwrap_or_default());
+    }
+
+    if !stores.is_empty() {
+        anyhow::bail!("Save data archive has record stores without name");
+    }
+
+    Ok((app_id, result))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AppData, StoreData, export_archive, import_archive};
+
+    fn archive_with(app_id: &str, store_name: &str) -> Vec<u8> {
+        let mut data = AppData::default();
+        data.stores.insert(store_name.into(), StoreData::default());
+
+        export_archive(app_id, &data).unwrap()
+    }
+
+    #[test]
+    fn test_archive_round_trip() {
+        let mut data = AppData::default();
+        data.stores.insert(
+            "save slot 1".into(),
+            StoreData {
+                version: 3,
+                last_modified: 1234,
+                records: [(1, vec![1, 2, 3]), (3, vec![])].into_iter().collect(),
+            },
+        );
+        data.stores.insert("empty".into(), StoreData::default());
+
+        let archive = export_archive("app", &data).unwrap();
+        let (app_id, imported) = import_archive(&archive).unwrap();
+
+        assert_eq!(app_id, "app");
+        assert_eq!(imported, data);
+    }
+
+    #[test]
+    fn test_archive_rejects_unsafe_names() {
+        assert!(import_archive(&archive_with("../../home/user", "store")).is_err());
+        assert!(import_archive(&archive_with("/tmp/app", "store")).is_err());
+        assert!(import_archive(&archive_with("app", "..")).is_err());
+        assert!(import_archive(&archive_with("app", "a/b")).is_err());
+        assert!(import_archive(&archive_with("", "store")).is_err());
+    }
+}
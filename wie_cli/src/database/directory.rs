@@ -0,0 +1,358 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use wie_backend::{Instant, RecordId, System};
+
+use super::{AppData, Storage, StoreData, now, validate_name};
+
+// stored alongside records, filename can't be parsed as record id
+const METADATA_FILE: &str = ".metadata";
+
+// one directory per record store, and one file per record in it
+pub struct DirectoryRepository {
+    base_path: PathBuf,
+}
+
+impl DirectoryRepository {
+    pub fn new(base_path: PathBuf) -> Self {
+        Self { base_path }
+    }
+
+    fn get_path_for_app(&self, app_id: &str) -> anyhow::Result<PathBuf> {
+        validate_name(app_id)?;
+
+        Ok(self.base_path.join(app_id))
+    }
+
+    fn get_path_for_database(&self, name: &str, app_id: &str) -> anyhow::Result<PathBuf> {
+        validate_name(name)?;
+
+        Ok(self.get_path_for_app(app_id)?.join(name))
+    }
+}
+
+#[async_trait::async_trait]
+impl wie_backend::DatabaseRepository for DirectoryRepository {
+    async fn open(&self, _system: &System, name: &str, app_id: &str) -> Option<Box<dyn wie_backend::Database>> {
+        let database = self.get_path_for_database(name, app_id).and_then(DirectoryDatabase::new);
+
+        match database {
+            Ok(x) => Some(Box::new(x)),
+            Err(e) => {
+                tracing::error!("Failed to open database {name:?} of {app_id:?}: {e}");
+                None
+            }
+        }
+    }
+
+    async fn exists(&self, _system: &System, name: &str, app_id: &str) -> bool {
+        self.get_path_for_database(name, app_id).is_ok_and(|x| x.exists())
+    }
+
+    async fn list(&self, _system: &System, app_id: &str) -> Vec<String> {
+        let Ok(entries) = self.get_path_for_app(app_id).and_then(|x| Ok(fs::read_dir(x)?)) else {
+            return Vec::new();
+        };
+
+        entries
+            .filter_map(|x| x.ok())
+            .filter(|x| x.path().is_dir())
+            .filter_map(|x| x.file_name().into_string().ok())
+            .collect()
+    }
+
+    async fn delete(&self, _system: &System, name: &str, app_id: &str) -> bool {
+        let Ok(path) = self.get_path_for_database(name, app_id) else {
+            return false;
+        };
+
+        tracing::trace!("Deleting database at {:?}", path);
+
+        fs::remove_dir_all(path).is_ok()
+    }
+
+    async fn rename(&self, _system: &System, name: &str, new_name: &str, app_id: &str) -> bool {
+        let (Ok(path), Ok(new_path)) = (self.get_path_for_database(name, app_id), self.get_path_for_database(new_name, app_id)) else {
+            return false;
+        };
+
+        tracing::trace!("Renaming database at {:?} to {:?}", path, new_path);
+
+        if new_path.exists() {
+            return false;
+        }
+
+        fs::rename(path, new_path).is_ok()
+    }
+}
+
+struct DirectoryDatabase {
+    base_path: PathBuf,
+    version: u32,
+    last_modified: u64,
+}
+
+impl DirectoryDatabase {
+    pub fn new(base_path: PathBuf) -> anyhow::Result<Self> {
+        tracing::trace!("Opening database at {:?}", base_path);
+
+        fs::create_dir_all(&base_path)?;
+
+        let metadata = fs::read_to_string(base_path.join(METADATA_FILE)).ok();
+        let (version, last_modified) = metadata.as_deref().and_then(parse_metadata).unwrap_or((0, now()));
+
+        let result = Self {
+            base_path,
+            version,
+            last_modified,
+        };
+        if metadata.is_none() {
+            result.write_metadata()?;
+        }
+
+        Ok(result)
+    }
+
+    fn find_empty_record_id(&self) -> RecordId {
+        let mut record_id = 1; // XXX midp requires first record to be 1
+
+        loop {
+            let path = self.base_path.join(record_id.to_string());
+
+            if !path.exists() {
+                return record_id;
+            }
+
+            record_id += 1;
+        }
+    }
+    fn get_path_for_record(&self, id: RecordId) -> PathBuf {
+        self.base_path.join(id.to_string())
+    }
+
+    fn touch(&mut self) -> std::io::Result<()> {
+        self.version += 1;
+        self.last_modified = now();
+
+        self.write_metadata()
+    }
+
+    fn write_metadata(&self) -> std::io::Result<()> {
+        write_metadata(&self.base_path, self.version, self.last_modified)
+    }
+
+    fn add_record(&mut self, data: &[u8]) -> std::io::Result<RecordId> {
+        let id = self.find_empty_record_id();
+
+        tracing::trace!("Adding record {id} to database {:?}", &self.base_path);
+
+        fs::write(self.get_path_for_record(id), data)?;
+        self.touch()?;
+
+        Ok(id)
+    }
+}
+
+#[async_trait::async_trait]
+impl wie_backend::Database for DirectoryDatabase {
+    async fn next_id(&self) -> RecordId {
+        self.find_empty_record_id()
+    }
+
+    async fn add(&mut self, data: &[u8]) -> Option<RecordId> {
+        self.add_record(data)
+            .inspect_err(|e| tracing::error!("Failed to add record to database {:?}: {e}", &self.base_path))
+            .ok()
+    }
+
+    async fn get(&self, id: RecordId) -> Option<Vec<u8>> {
+        let path = self.get_path_for_record(id);
+
+        tracing::trace!("Read record {id} from database {:?}", &self.base_path);
+
+        fs::read(path).ok()
+    }
+
+    async fn set(&mut self, id: RecordId, data: &[u8]) -> bool {
+        let path = self.get_path_for_record(id);
+
+        tracing::trace!("Set record {id} to database {:?}", &self.base_path);
+
+        fs::write(path, data).and_then(|_| self.touch()).is_ok()
+    }
+
+    async fn delete(&mut self, id: RecordId) -> bool {
+        let path = self.get_path_for_record(id);
+
+        tracing::trace!("Delete record {id} from database {:?}", &self.base_path);
+
+        fs::remove_file(path).and_then(|_| self.touch()).is_ok()
+    }
+
+    async fn get_record_ids(&self) -> Vec<RecordId> {
+        fs::read_dir(&self.base_path)
+            .unwrap()
+            .filter(|x| x.as_ref().unwrap().path().is_file())
+            .filter_map(|x| x.unwrap().file_name().to_str()?.parse().ok())
+            .collect()
+    }
+
+    async fn get_record_size(&self, id: RecordId) -> Option<usize> {
+        let path = self.get_path_for_record(id);
+
+        fs::metadata(path).ok().map(|x| x.len() as _)
+    }
+
+    async fn count(&self) -> usize {
+        self.get_record_ids().await.len()
+    }
+
+    async fn size(&self) -> usize {
+        let mut size = 0;
+        for id in self.get_record_ids().await {
+            size += self.get_record_size(id).await.unwrap_or(0);
+        }
+
+        size
+    }
+
+    async fn version(&self) -> u32 {
+        self.version
+    }
+
+    async fn last_modified(&self) -> Instant {
+        Instant::from_epoch_millis(self.last_modified)
+    }
+}
+
+impl Storage for DirectoryRepository {
+    fn app_ids(&self) -> anyhow::Result<Vec<String>> {
+        let Ok(entries) = fs::read_dir(&self.base_path) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(entries
+            .filter_map(|x| x.ok())
+            .filter(|x| x.path().is_dir())
+            .filter_map(|x| x.file_name().into_string().ok())
+            .collect())
+    }
+
+    fn export(&self, app_id: &str) -> anyhow::Result<AppData> {
+        let mut result = AppData::default();
+
+        let Ok(entries) = fs::read_dir(self.get_path_for_app(app_id)?) else {
+            return Ok(result);
+        };
+
+        for entry in entries {
+            let entry = entry?;
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let Ok(name) = entry.file_name().into_string() else {
+                tracing::warn!("Skipping record store with invalid name {:?}", entry.file_name());
+                continue;
+            };
+
+            let metadata = fs::read_to_string(entry.path().join(METADATA_FILE)).ok();
+            let (version, last_modified) = metadata.as_deref().and_then(parse_metadata).unwrap_or((0, now()));
+
+            let mut records = BTreeMap::new();
+            for record in fs::read_dir(entry.path())? {
+                let record = record?;
+                let Some(id) = record.file_name().to_str().and_then(|x| x.parse().ok()) else {
+                    continue;
+                };
+
+                records.insert(id, fs::read(record.path())?);
+            }
+
+            result.stores.insert(
+                name,
+                StoreData {
+                    version,
+                    last_modified,
+                    records,
+                },
+            );
+        }
+
+        Ok(result)
+    }
+
+    fn import(&self, app_id: &str, data: &AppData) -> anyhow::Result<()> {
+        let app_path = self.get_path_for_app(app_id)?;
+        for name in data.stores.keys() {
+            validate_name(name)?;
+        }
+
+        if app_path.exists() {
+            fs::remove_dir_all(&app_path)?;
+        }
+
+        for (name, store) in &data.stores {
+            let path = app_path.join(name);
+            fs::create_dir_all(&path)?;
+
+            for (id, record) in &store.records {
+                fs::write(path.join(id.to_string()), record)?;
+            }
+            write_metadata(&path, store.version, store.last_modified)?;
+        }
+
+        Ok(())
+    }
+}
+
+// metadata is stored as text `<version> <last_modified>`
+fn parse_metadata(metadata: &str) -> Option<(u32, u64)> {
+    let (version, last_modified) = metadata.split_once(' ')?;
+
+    Some((version.parse().ok()?, last_modified.trim().parse().ok()?))
+}
+
+fn write_metadata(base_path: &Path, version: u32, last_modified: u64) -> std::io::Result<()> {
+    fs::write(base_path.join(METADATA_FILE), format!("{version} {last_modified}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, fs, process};
+
+    use super::{DirectoryDatabase, METADATA_FILE, parse_metadata, write_metadata};
+
+    #[test]
+    fn test_metadata_round_trip() -> anyhow::Result<()> {
+        let path = env::temp_dir().join(format!("wie_directory_test_{}", process::id()));
+        let _ = fs::remove_dir_all(&path);
+
+        // new database writes initial metadata
+        let mut database = DirectoryDatabase::new(path.clone())?;
+        assert_eq!(database.version, 0);
+        assert!(path.join(METADATA_FILE).exists());
+
+        database.add_record(&[1, 2, 3])?;
+        database.add_record(&[4, 5])?;
+        let (version, last_modified) = (database.version, database.last_modified);
+        assert_eq!(version, 2);
+
+        let database = DirectoryDatabase::new(path.clone())?;
+        assert_eq!((database.version, database.last_modified), (version, last_modified));
+
+        write_metadata(&path, u32::MAX, u64::MAX)?;
+        let metadata = fs::read_to_string(path.join(METADATA_FILE))?;
+        assert_eq!(parse_metadata(&metadata), Some((u32::MAX, u64::MAX)));
+
+        assert_eq!(parse_metadata(""), None);
+        assert_eq!(parse_metadata("1"), None);
+        assert_eq!(parse_metadata("a 1"), None);
+
+        fs::remove_dir_all(&path)?;
+
+        Ok(())
+    }
+}
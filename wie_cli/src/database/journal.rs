@@ -0,0 +1,802 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use wie_backend::{Instant, RecordId, System};
+
+use super::{AppData, Storage, StoreData, now, validate_name};
+
+// journal file layout: MAGIC, then entries of `[payload length: u32][crc32 of payload: u32][payload]`, little endian.
+// each entry is written and synced at once, so a crash can only leave an incomplete entry at the end, which is discarded on load.
+const MAGIC: &[u8; 8] = b"WIEDB\x00\x00\x01";
+const EXTENSION: &str = "wiedb";
+// rewrite journal as single snapshot entry once it has this many entries
+const COMPACT_THRESHOLD: usize = 1024;
+
+// all record stores of an app in a single journaled file
+pub struct JournalRepository {
+    base_path: PathBuf,
+    journals: Mutex<HashMap<String, Arc<Mutex<Journal>>>>,
+}
+
+impl JournalRepository {
+    pub fn new(base_path: PathBuf) -> Self {
+        Self {
+            base_path,
+            journals: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // journal is loaded once and shared by all open databases of the app
+    fn journal(&self, app_id: &str) -> anyhow::Result<Arc<Mutex<Journal>>> {
+        validate_name(app_id)?;
+
+        let mut journals = self.journals.lock().unwrap();
+
+        Ok(journals
+            .entry(app_id.to_owned())
+            .or_insert_with(|| {
+                let path = self.base_path.join(format!("{app_id}.{EXTENSION}"));
+
+                Arc::new(Mutex::new(Journal::load(path)))
+            })
+            .clone())
+    }
+
+    // store names are not used in paths here, but are validated as well to keep the backends interchangeable
+    fn journal_for_store(&self, name: &str, app_id: &str) -> anyhow::Result<Arc<Mutex<Journal>>> {
+        validate_name(name)?;
+
+        self.journal(app_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl wie_backend::DatabaseRepository for JournalRepository {
+    async fn open(&self, _system: &System, name: &str, app_id: &str) -> Option<Box<dyn wie_backend::Database>> {
+        let journal = self
+            .journal_for_store(name, app_id)
+            .inspect_err(|e| tracing::error!("Failed to open database {name:?} of {app_id:?}: {e}"))
+            .ok()?;
+
+        {
+            let mut journal = journal.lock().unwrap();
+            if !journal.stores.contains_key(name)
+                && !journal.commit(Entry::Create {
+                    store: name.to_owned(),
+                    timestamp: now(),
+                })
+            {
+                return None;
+            }
+        }
+
+        Some(Box::new(JournalDatabase {
+            journal,
+            name: name.to_owned(),
+        }))
+    }
+
+    async fn exists(&self, _system: &System, name: &str, app_id: &str) -> bool {
+        self.journal_for_store(name, app_id)
+            .is_ok_and(|x| x.lock().unwrap().stores.contains_key(name))
+    }
+
+    async fn list(&self, _system: &System, app_id: &str) -> Vec<String> {
+        let Ok(journal) = self.journal(app_id) else {
+            return Vec::new();
+        };
+
+        journal.lock().unwrap().stores.keys().cloned().collect()
+    }
+
+    async fn delete(&self, _system: &System, name: &str, app_id: &str) -> bool {
+        let Ok(journal) = self.journal_for_store(name, app_id) else {
+            return false;
+        };
+        let mut journal = journal.lock().unwrap();
+
+        if !journal.stores.contains_key(name) {
+            return false;
+        }
+
+        journal.commit(Entry::DeleteStore { store: name.to_owned() })
+    }
+
+    async fn rename(&self, _system: &System, name: &str, new_name: &str, app_id: &str) -> bool {
+        let Ok(journal) = validate_name(new_name).and_then(|_| self.journal_for_store(name, app_id)) else {
+            return false;
+        };
+        let mut journal = journal.lock().unwrap();
+
+        if !journal.stores.contains_key(name) || journal.stores.contains_key(new_name) {
+            return false;
+        }
+
+        journal.commit(Entry::Rename {
+            from: name.to_owned(),
+            to: new_name.to_owned(),
+        })
+    }
+}
+
+impl Storage for JournalRepository {
+    fn app_ids(&self) -> anyhow::Result<Vec<String>> {
+        let Ok(entries) = fs::read_dir(&self.base_path) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(entries
+            .filter_map(|x| x.ok())
+            .map(|x| x.path())
+            .filter(|x| x.is_file() && x.extension().is_some_and(|x| x == EXTENSION))
+            .filter_map(|x| Some(x.file_stem()?.to_str()?.to_owned()))
+            .collect())
+    }
+
+    fn export(&self, app_id: &str) -> anyhow::Result<AppData> {
+        let journal = self.journal(app_id)?;
+        let journal = journal.lock().unwrap();
+
+        let stores = journal
+            .stores
+            .iter()
+            .map(|(name, store)| {
+                (
+                    name.clone(),
+                    StoreData {
+                        version: store.version,
+                        last_modified: store.last_modified,
+                        records: store.records.clone(),
+                    },
+                )
+            })
+            .collect();
+
+        Ok(AppData { stores })
+    }
+
+    fn import(&self, app_id: &str, data: &AppData) -> anyhow::Result<()> {
+        for name in data.stores.keys() {
+            validate_name(name)?;
+        }
+
+        let stores = data
+            .stores
+            .iter()
+            .map(|(name, store)| {
+                (
+                    name.clone(),
+                    JournalStore {
+                        next_id: store.records.keys().max().map(|x| x + 1).unwrap_or(1),
+                        version: store.version,
+                        last_modified: store.last_modified,
+                        records: store.records.clone(),
+                    },
+                )
+            })
+            .collect();
+
+        let journal = self.journal(app_id)?;
+        let mut journal = journal.lock().unwrap();
+
+        Ok(journal.replace(stores)?)
+    }
+}
+
+struct JournalDatabase {
+    journal: Arc<Mutex<Journal>>,
+    name: String,
+}
+
+impl JournalDatabase {
+    fn with_store<T>(&self, f: impl FnOnce(&JournalStore) -> T) -> T {
+        let journal = self.journal.lock().unwrap();
+
+        // store may be deleted while it's open
+        match journal.stores.get(&self.name) {
+            Some(store) => f(store),
+            None => f(&JournalStore::default()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl wie_backend::Database for JournalDatabase {
+    async fn next_id(&self) -> RecordId {
+        self.with_store(|x| x.next_id)
+    }
+
+    async fn add(&mut self, data: &[u8]) -> Option<RecordId> {
+        let mut journal = self.journal.lock().unwrap();
+
+        let id = journal.stores.get(&self.name).map(|x| x.next_id).unwrap_or(1);
+        journal
+            .commit(Entry::Put {
+                store: self.name.clone(),
+                id,
+                timestamp: now(),
+                data: data.to_vec(),
+            })
+            .then_some(id)
+    }
+
+    async fn get(&self, id: RecordId) -> Option<Vec<u8>> {
+        self.with_store(|x| x.records.get(&id).cloned())
+    }
+
+    async fn set(&mut self, id: RecordId, data: &[u8]) -> bool {
+        self.journal.lock().unwrap().commit(Entry::Put {
+            store: self.name.clone(),
+            id,
+            timestamp: now(),
+            data: data.to_vec(),
+        })
+    }
+
+    async fn delete(&mut self, id: RecordId) -> bool {
+        let mut journal = self.journal.lock().unwrap();
+
+        if !journal.stores.get(&self.name).is_some_and(|x| x.records.contains_key(&id)) {
+            return false;
+        }
+
+        journal.commit(Entry::Delete {
+            store: self.name.clone(),
+            id,
+            timestamp: now(),
+        })
+    }
+
+    async fn get_record_ids(&self) -> Vec<RecordId> {
+        self.with_store(|x| x.records.keys().cloned().collect())
+    }
+
+    async fn get_record_size(&self, id: RecordId) -> Option<usize> {
+        self.with_store(|x| x.records.get(&id).map(|x| x.len()))
+    }
+
+    async fn count(&self) -> usize {
+        self.with_store(|x| x.records.len())
+    }
+
+    async fn size(&self) -> usize {
+        self.with_store(|x| x.records.values().map(|x| x.len()).sum())
+    }
+
+    async fn version(&self) -> u32 {
+        self.with_store(|x| x.version)
+    }
+
+    async fn last_modified(&self) -> Instant {
+        Instant::from_epoch_millis(self.with_store(|x| x.last_modified))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct JournalStore {
+    // record ids are not reused, as midp requires
+    next_id: RecordId,
+    version: u32,
+    last_modified: u64,
+    records: BTreeMap<RecordId, Vec<u8>>,
+}
+
+impl Default for JournalStore {
+    fn default() -> Self {
+        Self {
+            next_id: 1, // XXX midp requires first record to be 1
+            version: 0,
+            last_modified: 0,
+            records: BTreeMap::new(),
+        }
+    }
+}
+
+enum Entry {
+    Snapshot(BTreeMap<String, JournalStore>),
+    Create {
+        store: String,
+        timestamp: u64,
+    },
+    Put {
+        store: String,
+        id: RecordId,
+        timestamp: u64,
+        data: Vec<u8>,
+    },
+    Delete {
+        store: String,
+        id: RecordId,
+        timestamp: u64,
+    },
+    DeleteStore {
+        store: String,
+    },
+    Rename {
+        from: String,
+        to: String,
+    },
+}
+
+impl Entry {
+    fn encode(&self) -> Vec<u8> {
+        let mut writer = EntryWriter(Vec::new());
+
+        match self {
+            Self::Snapshot(stores) => {
+                writer.u8(0);
+                writer.u32(stores.len() as _);
+                for (name, store) in stores {
+                    writer.string(name);
+                    writer.u32(store.next_id);
+                    writer.u32(store.version);
+                    writer.u64(store.last_modified);
+                    writer.u32(store.records.len() as _);
+                    for (id, data) in &store.records {
+                        writer.u32(*id);
+                        writer.bytes(data);
+                    }
+                }
+            }
+            Self::Create { store, timestamp } => {
+                writer.u8(1);
+                writer.string(store);
+                writer.u64(*timestamp);
+            }
+            Self::Put { store, id, timestamp, data } => {
+                writer.u8(2);
+                writer.string(store);
+                writer.u32(*id);
+                writer.u64(*timestamp);
+                writer.bytes(data);
+            }
+            Self::Delete { store, id, timestamp } => {
+                writer.u8(3);
+                writer.string(store);
+                writer.u32(*id);
+                writer.u64(*timestamp);
+            }
+            Self::DeleteStore { store } => {
+                writer.u8(4);
+                writer.string(store);
+            }
+            Self::Rename { from, to } => {
+                writer.u8(5);
+                writer.string(from);
+                writer.string(to);
+            }
+        }
+
+        writer.0
+    }
+
+    fn decode(payload: &[u8]) -> Option<Self> {
+        let mut reader = EntryReader(payload);
+
+        let entry = match reader.u8()? {
+            0 => {
+                let mut stores = BTreeMap::new();
+                for _ in 0..reader.u32()? {
+                    let name = reader.string()?;
+                    let next_id = reader.u32()?;
+                    let version = reader.u32()?;
+                    let last_modified = reader.u64()?;
+
+                    let mut records = BTreeMap::new();
+                    for _ in 0..reader.u32()? {
+                        let id = reader.u32()?;
+                        records.insert(id, reader.bytes()?);
+                    }
+
+                    stores.insert(
+                        name,
+                        JournalStore {
+                            next_id,
+                            version,
+                            last_modified,
+                            records,
+                        },
+                    );
+                }
+
+                Self::Snapshot(stores)
+            }
+            1 => Self::Create {
+                store: reader.string()?,
+                timestamp: reader.u64()?,
+            },
+            2 => Self::Put {
+                store: reader.string()?,
+                id: reader.u32()?,
+                timestamp: reader.u64()?,
+                data: reader.bytes()?,
+            },
+            3 => Self::Delete {
+                store: reader.string()?,
+                id: reader.u32()?,
+                timestamp: reader.u64()?,
+            },
+            4 => Self::DeleteStore { store: reader.string()? },
+            5 => Self::Rename {
+                from: reader.string()?,
+                to: reader.string()?,
+            },
+            _ => return None,
+        };
+
+        reader.0.is_empty().then_some(entry)
+    }
+}
+
+struct Journal {
+    path: PathBuf,
+    file: Option<File>,
+    stores: BTreeMap<String, JournalStore>,
+    entry_count: usize,
+}
+
+impl Journal {
+    fn load(path: PathBuf) -> Self {
+        tracing::trace!("Loading journal at {:?}", path);
+
+        let mut result = Self {
+            path,
+            file: None,
+            stores: BTreeMap::new(),
+            entry_count: 0,
+        };
+
+        let data = match fs::read(&result.path) {
+            Ok(x) => x,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return result,
+            Err(e) => {
+                tracing::error!("Failed to read journal {:?}: {e}", result.path);
+                return result;
+            }
+        };
+
+        // crashed while creating the journal
+        if MAGIC.starts_with(&data) && data.len() < MAGIC.len() {
+            if let Err(e) = fs::remove_file(&result.path) {
+                tracing::error!("Failed to remove incomplete journal {:?}: {e}", result.path);
+            }
+
+            return result;
+        }
+
+        if !data.starts_with(MAGIC) {
+            // keep unknown file for inspection instead of overwriting it
+            let backup_path = result.path.with_extension("corrupt");
+            tracing::error!("Invalid journal {:?}, moving it to {:?}", result.path, backup_path);
+            if let Err(e) = fs::rename(&result.path, &backup_path) {
+                tracing::error!("Failed to move invalid journal: {e}");
+            }
+
+            return result;
+        }
+
+        let mut cursor = MAGIC.len();
+        while let Some((entry, length)) = Self::read_entry(&data[cursor..]) {
+            result.apply(entry);
+            result.entry_count += 1;
+            cursor += length;
+        }
+
+        if cursor != data.len() {
+            tracing::warn!(
+                "Discarding {} bytes of incomplete journal entry in {:?}",
+                data.len() - cursor,
+                result.path
+            );
+        }
+
+        // truncate incomplete entry, so that next entries are appended after the valid ones
+        let file = OpenOptions::new()
+            .append(true)
+            .open(&result.path)
+            .and_then(|file| file.set_len(cursor as _).map(|_| file));
+        match file {
+            Ok(x) => result.file = Some(x),
+            Err(e) => tracing::error!("Failed to open journal {:?}: {e}", result.path),
+        }
+
+        if result.entry_count > COMPACT_THRESHOLD
+            && let Err(e) = result.compact()
+        {
+            tracing::error!("Failed to compact journal {:?}: {e}", result.path);
+        }
+
+        result
+    }
+
+    fn read_entry(data: &[u8]) -> Option<(Entry, usize)> {
+        let length = u32::from_le_bytes(data.get(0..4)?.try_into().unwrap()) as usize;
+        let checksum = u32::from_le_bytes(data.get(4..8)?.try_into().unwrap());
+        let payload = data.get(8..8 + length)?;
+
+        if crc32fast::hash(payload) != checksum {
+            return None;
+        }
+
+        Some((Entry::decode(payload)?, 8 + length))
+    }
+
+    fn frame(entry: &Entry) -> Vec<u8> {
+        let payload = entry.encode();
+
+        let mut result = Vec::with_capacity(payload.len() + 8);
+        result.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        result.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+        result.extend_from_slice(&payload);
+
+        result
+    }
+
+    // entry is applied only after it's durably written
+    fn commit(&mut self, entry: Entry) -> bool {
+        if let Err(e) = self.append(&entry) {
+            tracing::error!("Failed to write journal {:?}: {e}", self.path);
+            return false;
+        }
+
+        self.apply(entry);
+        self.entry_count += 1;
+
+        if self.entry_count > COMPACT_THRESHOLD
+            && let Err(e) = self.compact()
+        {
+            tracing::error!("Failed to compact journal {:?}: {e}", self.path);
+        }
+
+        true
+    }
+
+    fn append(&mut self, entry: &Entry) -> io::Result<()> {
+        if self.file.is_none() {
+            if let Some(parent) = self.path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            let mut file = OpenOptions::new().append(true).create_new(true).open(&self.path)?;
+            file.write_all(MAGIC)?;
+            file.sync_all()?;
+
+            self.file = Some(file);
+        }
+
+        let file = self.file.as_mut().unwrap();
+        let offset = file.metadata()?.len();
+
+        let result = file.write_all(&Self::frame(entry)).and_then(|_| file.sync_data());
+        if result.is_err() {
+            // cut partially written entry, as entries appended after it would be discarded on load
+            if let Err(e) = file.set_len(offset) {
+                tracing::error!("Failed to truncate journal {:?}: {e}", self.path);
+                self.file = None;
+            }
+        }
+
+        result
+    }
+
+    // atomically replaces journal with a single snapshot of current state
+    fn compact(&mut self) -> io::Result<()> {
+        self.file = Some(Self::write_snapshot(&self.path, &self.stores)?);
+        self.entry_count = 1;
+
+        Ok(())
+    }
+
+    // current state is kept if the new state couldn't be written
+    fn replace(&mut self, stores: BTreeMap<String, JournalStore>) -> io::Result<()> {
+        self.file = Some(Self::write_snapshot(&self.path, &stores)?);
+        self.stores = stores;
+        self.entry_count = 1;
+
+        Ok(())
+    }
+
+    fn write_snapshot(path: &Path, stores: &BTreeMap<String, JournalStore>) -> io::Result<File> {
+        tracing::trace!("Writing journal snapshot at {:?}", path);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let temp_path = path.with_extension("tmp");
+        {
+            let mut file = File::create(&temp_path)?;
+            file.write_all(MAGIC)?;
+            file.write_all(&Self::frame(&Entry::Snapshot(stores.clone())))?;
+            file.sync_all()?;
+        }
+        fs::rename(&temp_path, path)?;
+
+        OpenOptions::new().append(true).open(path)
+    }
+
+    fn apply(&mut self, entry: Entry) {
+        match entry {
+            Entry::Snapshot(stores) => self.stores = stores,
+            Entry::Create { store, timestamp } => {
+                self.stores.entry(store).or_insert_with(|| JournalStore {
+                    last_modified: timestamp,
+                    ..Default::default()
+                });
+            }
+            Entry::Put { store, id, timestamp, data } => {
+                let store = self.stores.entry(store).or_default();
+                store.records.insert(id, data);
+                store.next_id = store.next_id.max(id + 1);
+                store.version += 1;
+                store.last_modified = timestamp;
+            }
+            Entry::Delete { store, id, timestamp } => {
+                let store = self.stores.entry(store).or_default();
+                store.records.remove(&id);
+                store.version += 1;
+                store.last_modified = timestamp;
+            }
+            Entry::DeleteStore { store } => {
+                self.stores.remove(&store);
+            }
+            Entry::Rename { from, to } => {
+                if let Some(store) = self.stores.remove(&from) {
+                    self.stores.insert(to, store);
+                }
+            }
+        }
+    }
+}
+
+struct EntryWriter(Vec<u8>);
+
+impl EntryWriter {
+    fn u8(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.0.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn bytes(&mut self, value: &[u8]) {
+        self.u32(value.len() as _);
+        self.0.extend_from_slice(value);
+    }
+
+    fn string(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+}
+
+struct EntryReader<'a>(&'a [u8]);
+
+impl EntryReader<'_> {
+    fn take(&mut self, length: usize) -> Option<&[u8]> {
+        if self.0.len() < length {
+            return None;
+        }
+
+        let (result, rest) = self.0.split_at(length);
+        self.0 = rest;
+
+        Some(result)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn bytes(&mut self) -> Option<Vec<u8>> {
+        let length = self.u32()? as usize;
+
+        Some(self.take(length)?.to_vec())
+    }
+
+    fn string(&mut self) -> Option<String> {
+        String::from_utf8(self.bytes()?).ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeMap, env, fs, process};
+
+    use super::{Entry, Journal};
+
+    #[test]
+    fn test_journal_discards_incomplete_entry() {
+        let path = env::temp_dir().join(format!("wie_journal_test_{}.wiedb", process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut journal = Journal::load(path.clone());
+        assert!(journal.commit(Entry::Create {
+            store: "test".into(),
+            timestamp: 1,
+        }));
+        assert!(journal.commit(Entry::Put {
+            store: "test".into(),
+            id: 1,
+            timestamp: 2,
+            data: vec![1, 2, 3],
+        }));
+        assert!(journal.commit(Entry::Put {
+            store: "test".into(),
+            id: 2,
+            timestamp: 3,
+            data: vec![4, 5, 6],
+        }));
+        drop(journal);
+
+        // simulate crash in the middle of writing last entry
+        let data = fs::read(&path).unwrap();
+        fs::write(&path, &data[..data.len() - 2]).unwrap();
+
+        let journal = Journal::load(path.clone());
+        let store = &journal.stores["test"];
+        assert_eq!(store.records.len(), 1);
+        assert_eq!(store.records[&1], [1, 2, 3]);
+        assert_eq!(store.version, 1);
+        assert_eq!(store.last_modified, 2);
+        assert_eq!(
+            fs::metadata(&path).unwrap().len() as usize,
+            data.len()
+                - Journal::frame(&Entry::Put {
+                    store: "test".into(),
+                    id: 2,
+                    timestamp: 3,
+                    data: vec![4, 5, 6],
+                })
+                .len()
+        );
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_journal_keeps_state_on_failed_replace() {
+        let path = env::temp_dir().join(format!("wie_journal_replace_test_{}.wiedb", process::id()));
+        let temp_path = path.with_extension("tmp");
+        let _ = fs::remove_file(&path);
+        let _ = fs::remove_dir(&temp_path);
+
+        let mut journal = Journal::load(path.clone());
+        assert!(journal.commit(Entry::Put {
+            store: "test".into(),
+            id: 1,
+            timestamp: 1,
+            data: vec![1, 2, 3],
+        }));
+
+        // snapshot can't be written over a directory
+        fs::create_dir(&temp_path).unwrap();
+        assert!(journal.replace(BTreeMap::new()).is_err());
+        assert_eq!(journal.stores["test"].records[&1], [1, 2, 3]);
+
+        fs::remove_dir(&temp_path).unwrap();
+        drop(journal);
+
+        let journal = Journal::load(path.clone());
+        assert_eq!(journal.stores["test"].records[&1], [1, 2, 3]);
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -1,7 +1,9 @@
 extern crate alloc;
 
 mod audio_sink;
+mod capture;
 mod database;
+mod network;
 mod window;
 
 use core::str;
@@ -10,9 +12,13 @@ use std::{
     error::Error,
     fs,
     io::stderr,
-    sync::mpsc::{Receiver, Sender, channel},
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        mpsc::{Receiver, Sender, channel},
+    },
     thread,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use clap::Parser;
@@ -20,7 +26,7 @@ use midir::MidiOutput;
 use rodio::{OutputStreamBuilder, Sink, buffer::SamplesBuffer, conversions::SampleTypeConverter};
 use winit::keyboard::{KeyCode as WinitKeyCode, PhysicalKey};
 
-use wie_backend::{Emulator, Event, Instant, KeyCode, Options, Platform, Screen, extract_zip};
+use wie_backend::{DeviceProfile, Emulator, Event, Instant, KeyCode, Options, Platform, Screen, extract_zip};
 use wie_j2me::J2MEEmulator;
 use wie_ktf::KtfEmulator;
 use wie_lgt::LgtEmulator;
@@ -28,24 +34,30 @@ use wie_skt::SktEmulator;
 
 use self::{
     audio_sink::AudioSink,
-    database::DatabaseRepository,
+    capture::{Capture, RecordingFormat},
+    database::{DirectoryRepository, JournalRepository, Storage, StorageKind, export_archive, import_archive, open_storage},
+    network::Network,
     window::{WindowCallbackEvent, WindowHandle, WindowImpl},
 };
 
 struct WieCliPlatform {
     audio_thread_tx: Sender<(u8, u32, Vec<i16>)>,
-    database_repository: DatabaseRepository,
+    device_profile: DeviceProfile,
+    database_repository: Box<dyn Storage>,
+    network: Network,
     window: WindowHandle,
 }
 
 impl WieCliPlatform {
-    fn new(window: WindowHandle) -> Self {
+    fn new(window: WindowHandle, storage: StorageKind) -> Self {
         let (tx, rx) = channel();
         thread::spawn(|| Self::audio_thread(rx));
 
         Self {
             audio_thread_tx: tx,
-            database_repository: DatabaseRepository::new(),
+            device_profile: DeviceProfile::default(),
+            database_repository: open_storage(storage),
+            network: Network,
             window,
         }
     }
@@ -86,6 +98,10 @@ impl Platform for WieCliPlatform {
         &self.window
     }
 
+    fn device_profile(&self) -> &DeviceProfile {
+        &self.device_profile
+    }
+
     fn now(&self) -> Instant {
         let now = SystemTime::now();
         let since_the_epoch = now.duration_since(UNIX_EPOCH).unwrap();
@@ -94,7 +110,7 @@ impl Platform for WieCliPlatform {
     }
 
     fn database_repository(&self) -> &dyn wie_backend::DatabaseRepository {
-        &self.database_repository
+        &*self.database_repository
     }
 
     fn audio_sink(&self) -> Box<dyn wie_backend::AudioSink> {
@@ -110,6 +126,10 @@ impl Platform for WieCliPlatform {
         Box::new(AudioSink::new(midi_out, self.audio_thread_tx.clone()))
     }
 
+    fn network(&self) -> &dyn wie_backend::Network {
+        &self.network
+    }
+
     fn write_stdout(&self, buf: &[u8]) {
         let str = str::from_utf8(buf).unwrap();
 
@@ -127,11 +147,71 @@ impl Platform for WieCliPlatform {
     }
 }
 
+// time to wait for app to exit after close is requested
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(1);
+
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    filename: String,
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(required = true)]
+    filename: Option<String>,
     #[arg(long, default_value_t = false)]
     debug: bool,
+    /// MIDlet to launch from a suite with multiple MIDlets, 1-based as in `MIDlet-n`. Selection screen is shown if not given
+    #[arg(long)]
+    midlet: Option<usize>,
+    /// Storage backend for save data
+    #[arg(long, value_enum, default_value_t, global = true)]
+    storage: StorageKind,
+    #[command(flatten)]
+    capture: CaptureArgs,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Manage save data of apps
+    #[command(subcommand)]
+    Save(SaveCommand),
+}
+
+#[derive(clap::Subcommand)]
+enum SaveCommand {
+    /// List apps having save data
+    List,
+    /// Export save data of an app into a zip archive
+    Export { app_id: String, output: PathBuf },
+    /// Import save data from a zip archive, replacing existing save data of the app
+    Import {
+        input: PathBuf,
+        /// Import into given app instead of the one recorded in the archive
+        #[arg(long)]
+        app_id: Option<String>,
+    },
+    /// Copy save data from directory storage into journal storage. All apps are migrated if none is given
+    Migrate {
+        app_ids: Vec<String>,
+        /// Replace save data already in journal storage
+        #[arg(long, default_value_t = false)]
+        overwrite: bool,
+    },
+}
+
+#[derive(clap::Args)]
+pub struct CaptureArgs {
+    /// Directory to save screenshots(F9) and recordings(F10: gif, F11: apng) taken with hotkeys
+    #[arg(long)]
+    capture_dir: Option<PathBuf>,
+    /// Save png screenshot of the last frame on exit
+    #[arg(long)]
+    screenshot: Option<PathBuf>,
+    /// Record screen from start into given gif or apng file, saved on exit
+    #[arg(long)]
+    record: Option<PathBuf>,
+    /// Exit after given milliseconds, for unattended capture
+    #[arg(long)]
+    exit_after: Option<u64>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -142,16 +222,79 @@ fn main() -> anyhow::Result<()> {
 
     let args = Args::parse();
 
+    if let Some(Command::Save(command)) = args.command {
+        return run_save_command(command, args.storage);
+    }
+
     let options = Options {
         enable_gdbserver: args.debug,
+        midlet: args.midlet,
     };
 
-    start(&args.filename, options)
+    start(&args.filename.unwrap(), options, args.storage, args.capture)
+}
+
+fn run_save_command(command: SaveCommand, storage: StorageKind) -> anyhow::Result<()> {
+    match command {
+        SaveCommand::List => {
+            for app_id in open_storage(storage).app_ids()? {
+                println!("{app_id}");
+            }
+        }
+        SaveCommand::Export { app_id, output } => {
+            let storage = open_storage(storage);
+            if !storage.app_ids()?.contains(&app_id) {
+                anyhow::bail!("No save data for {app_id}");
+            }
+
+            let data = storage.export(&app_id)?;
+            fs::write(&output, export_archive(&app_id, &data)?)?;
+
+            println!("Exported {} record stores of {app_id} to {}", data.stores.len(), output.display());
+        }
+        SaveCommand::Import { input, app_id } => {
+            let (archive_app_id, data) = import_archive(&fs::read(&input)?)?;
+            let app_id = app_id.unwrap_or(archive_app_id);
+
+            open_storage(storage).import(&app_id, &data)?;
+
+            println!("Imported {} record stores into {app_id}", data.stores.len());
+        }
+        SaveCommand::Migrate { app_ids, overwrite } => {
+            let base_path = database::data_dir();
+            let source = DirectoryRepository::new(base_path.clone());
+            let target = JournalRepository::new(base_path);
+
+            let app_ids = if app_ids.is_empty() { source.app_ids()? } else { app_ids };
+            let existing = target.app_ids()?;
+
+            for app_id in app_ids {
+                if existing.contains(&app_id) && !overwrite {
+                    eprintln!("Skipping {app_id}, which already has journal storage. Use --overwrite to replace it");
+                    continue;
+                }
+
+                let data = source.export(&app_id)?;
+                target.import(&app_id, &data)?;
+
+                println!("Migrated {} record stores of {app_id}", data.stores.len());
+            }
+        }
+    }
+
+    Ok(())
 }
 
-pub fn start(filename: &str, options: Options) -> anyhow::Result<()> {
-    let window = WindowImpl::new(240, 320).unwrap(); // TODO hardcoded size
-    let platform = Box::new(WieCliPlatform::new(window.handle()));
+pub fn start(filename: &str, options: Options, storage: StorageKind, capture_args: CaptureArgs) -> anyhow::Result<()> {
+    let capture = Arc::new(Mutex::new(Capture::new(capture_args.capture_dir.unwrap_or_else(|| PathBuf::from(".")))));
+    if let Some(path) = &capture_args.record {
+        let format = RecordingFormat::from_path(path).ok_or_else(|| anyhow::anyhow!("Unsupported recording format: {}", path.display()))?;
+        capture.lock().unwrap().start_recording(Some(path), format);
+    }
+
+    let window = WindowImpl::new(240, 320, capture.clone()).unwrap(); // TODO hardcoded size
+    let quit_handle = window.handle();
+    let platform = Box::new(WieCliPlatform::new(window.handle(), storage));
 
     let buf = fs::read(filename)?;
     let mut emulator: Box<dyn Emulator> = if filename.ends_with("zip") {
@@ -162,7 +305,7 @@ pub fn start(filename: &str, options: Options) -> anyhow::Result<()> {
         } else if LgtEmulator::loadable_archive(&files) {
             Box::new(LgtEmulator::from_archive(platform, files, options)?)
         } else if SktEmulator::loadable_archive(&files) {
-            Box::new(SktEmulator::from_archive(platform, files)?)
+            Box::new(SktEmulator::from_archive(platform, files, options)?)
         } else {
             anyhow::bail!("Unknown archive format");
         }
@@ -172,7 +315,7 @@ pub fn start(filename: &str, options: Options) -> anyhow::Result<()> {
 
         let jar_filename = jar_filename[jar_filename.rfind('/').unwrap_or(0) + 1..].to_owned();
 
-        Box::new(J2MEEmulator::from_jad_jar(platform, buf, jar_filename, jar)?)
+        Box::new(J2MEEmulator::from_jad_jar(platform, buf, jar_filename, jar, options)?)
     } else if filename.ends_with("jar") {
         let filename_without_path = filename[filename.rfind('/').unwrap_or(0) + 1..].to_owned();
         let filename_without_ext = filename_without_path.trim_end_matches(".jar");
@@ -198,20 +341,43 @@ pub fn start(filename: &str, options: Options) -> anyhow::Result<()> {
                 options,
             )?)
         } else if SktEmulator::loadable_jar(&buf) {
-            Box::new(SktEmulator::from_jar(platform, &filename_without_path, buf, filename_without_ext, None)?)
+            Box::new(SktEmulator::from_jar(
+                platform,
+                &filename_without_path,
+                buf,
+                filename_without_ext,
+                None,
+                options,
+            )?)
         } else {
-            Box::new(J2MEEmulator::from_jar(platform, &filename_without_path, buf)?)
+            Box::new(J2MEEmulator::from_jar(platform, &filename_without_path, buf, options)?)
         }
     } else {
         anyhow::bail!("Unknown file format");
     };
 
+    let started = SystemTime::now();
+    let mut exit_after = capture_args.exit_after.map(Duration::from_millis);
+    let mut close_requested: Option<SystemTime> = None;
+
     let mut key_events = HashMap::new();
-    window.run(move |event| {
+    let hotkey_capture = capture.clone();
+    let result = window.run(move |event| {
         match event {
             WindowCallbackEvent::Update => {
                 let now = SystemTime::now();
 
+                if exit_after.is_some_and(|x| now.duration_since(started).unwrap() >= x) {
+                    exit_after = None;
+                    quit_handle.send_quit_event();
+                }
+
+                // app didn't exit by itself after Event::Terminate
+                if close_requested.is_some_and(|x| now.duration_since(x).unwrap() >= CLOSE_TIMEOUT) {
+                    close_requested = None;
+                    quit_handle.send_quit_event();
+                }
+
                 for entry in key_events.iter_mut() {
                     let (keycode, time) = entry;
 
@@ -225,7 +391,14 @@ pub fn start(filename: &str, options: Options) -> anyhow::Result<()> {
                 emulator.tick()?
             }
             WindowCallbackEvent::Redraw => emulator.handle_event(Event::Redraw),
+            WindowCallbackEvent::PointerDown { x, y } => emulator.handle_event(Event::PointerDown { x, y }),
+            WindowCallbackEvent::PointerUp { x, y } => emulator.handle_event(Event::PointerUp { x, y }),
+            WindowCallbackEvent::PointerMove { x, y } => emulator.handle_event(Event::PointerMove { x, y }),
             WindowCallbackEvent::Keydown(x) => {
+                if handle_capture_hotkey(&hotkey_capture, x) {
+                    return Ok(());
+                }
+
                 if let Some(keycode) = convert_key(x) {
                     let entry = key_events.entry(keycode);
                     if let Entry::Vacant(entry) = entry {
@@ -245,10 +418,71 @@ pub fn start(filename: &str, options: Options) -> anyhow::Result<()> {
                     emulator.handle_event(Event::Keyup(keycode));
                 }
             }
+            // keys mapped to keypad are sent as keys only, so that text isn't typed along with keypad input
+            WindowCallbackEvent::KeyText(key, text) => {
+                if convert_key(key).is_none() {
+                    emulator.handle_event(Event::Text(text));
+                }
+            }
+            WindowCallbackEvent::Text(x) => emulator.handle_event(Event::Text(x)),
+            WindowCallbackEvent::Focused(true) => emulator.handle_event(Event::Resume),
+            WindowCallbackEvent::Focused(false) => {
+                // we won't get keyup events while unfocused
+                for (keycode, _) in key_events.drain() {
+                    emulator.handle_event(Event::Keyup(keycode));
+                }
+
+                emulator.handle_event(Event::Suspend);
+            }
+            WindowCallbackEvent::CloseRequested => {
+                if close_requested.is_some() {
+                    // closed twice, don't wait for app anymore
+                    quit_handle.send_quit_event();
+                } else {
+                    close_requested = Some(SystemTime::now());
+                    emulator.handle_event(Event::Terminate);
+                }
+            }
         }
 
         Ok(())
-    })
+    });
+
+    let mut capture = capture.lock().unwrap();
+    if let Some(path) = capture.stop_recording()? {
+        tracing::info!("Saved recording to {}", path.display());
+    }
+    if let Some(path) = &capture_args.screenshot {
+        capture.screenshot(Some(path))?;
+    }
+
+    result
+}
+
+fn handle_capture_hotkey(capture: &Mutex<Capture>, key: PhysicalKey) -> bool {
+    let mut capture = capture.lock().unwrap();
+
+    let result = match key {
+        PhysicalKey::Code(WinitKeyCode::F9) => capture.screenshot(None).map(Some),
+        PhysicalKey::Code(WinitKeyCode::F10) | PhysicalKey::Code(WinitKeyCode::F11) if capture.is_recording() => capture.stop_recording(),
+        PhysicalKey::Code(WinitKeyCode::F10) => {
+            capture.start_recording(None, RecordingFormat::Gif);
+            Ok(None)
+        }
+        PhysicalKey::Code(WinitKeyCode::F11) => {
+            capture.start_recording(None, RecordingFormat::Apng);
+            Ok(None)
+        }
+        _ => return false,
+    };
+
+    match result {
+        Ok(Some(path)) => tracing::info!("Saved {}", path.display()),
+        Ok(None) => tracing::info!("Recording started"),
+        Err(x) => tracing::error!("Capture failed: {x}"),
+    }
+
+    true
 }
 
 fn convert_key(key: PhysicalKey) -> Option<KeyCode> {
@@ -0,0 +1,141 @@
+use std::{
+    future::poll_fn,
+    io::{self, ErrorKind, Read, Write},
+    net::{Ipv4Addr, Shutdown, SocketAddr, SocketAddrV4, TcpStream, ToSocketAddrs, UdpSocket as StdUdpSocket},
+    sync::mpsc::{TryRecvError, channel},
+    task::Poll,
+    thread,
+    time::Duration,
+};
+
+use wie_backend::{NetworkError, NetworkResult};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+// executor doesn't have an i/o reactor, so we use non-blocking sockets and return pending without waking.
+// socket tasks in backend poll pending operations again after a backoff interval.
+// blocking calls like connect and dns lookup are done on a separate thread.
+pub struct Network;
+
+#[async_trait::async_trait]
+impl wie_backend::Network for Network {
+    async fn resolve(&self, host: &str) -> NetworkResult<Vec<Ipv4Addr>> {
+        if let Ok(addr) = host.parse() {
+            return Ok(vec![addr]);
+        }
+
+        let host = host.to_owned();
+        let addrs = spawn_blocking(move || (host.as_str(), 0).to_socket_addrs())
+            .await
+            .map_err(|_| NetworkError::HostNotFound)?
+            .filter_map(|x| match x {
+                SocketAddr::V4(x) => Some(*x.ip()),
+                SocketAddr::V6(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        if addrs.is_empty() {
+            return Err(NetworkError::HostNotFound);
+        }
+
+        Ok(addrs)
+    }
+
+    async fn connect_tcp(&self, addr: SocketAddrV4) -> NetworkResult<Box<dyn wie_backend::TcpSocket>> {
+        tracing::debug!("Connecting to {}", addr);
+
+        let stream = spawn_blocking(move || TcpStream::connect_timeout(&addr.into(), CONNECT_TIMEOUT))
+            .await
+            .map_err(convert_error)?;
+        stream.set_nonblocking(true).map_err(convert_error)?;
+        stream.set_nodelay(true).map_err(convert_error)?;
+
+        Ok(Box::new(TcpSocket { stream }))
+    }
+
+    fn bind_udp(&self, addr: SocketAddrV4) -> NetworkResult<Box<dyn wie_backend::UdpSocket>> {
+        let socket = StdUdpSocket::bind(addr).map_err(convert_error)?;
+        socket.set_nonblocking(true).map_err(convert_error)?;
+
+        Ok(Box::new(UdpSocket { socket }))
+    }
+}
+
+struct TcpSocket {
+    stream: TcpStream,
+}
+
+#[async_trait::async_trait]
+impl wie_backend::TcpSocket for TcpSocket {
+    async fn read(&self, buf: &mut [u8]) -> NetworkResult<usize> {
+        poll_fn(|_| poll_io((&self.stream).read(buf))).await
+    }
+
+    async fn write(&self, buf: &[u8]) -> NetworkResult<usize> {
+        poll_fn(|_| poll_io((&self.stream).write(buf))).await
+    }
+
+    fn shutdown(&self) {
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
+
+struct UdpSocket {
+    socket: StdUdpSocket,
+}
+
+#[async_trait::async_trait]
+impl wie_backend::UdpSocket for UdpSocket {
+    async fn send_to(&self, buf: &[u8], addr: SocketAddrV4) -> NetworkResult<usize> {
+        poll_fn(|_| poll_io(self.socket.send_to(buf, addr))).await
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> NetworkResult<(usize, SocketAddrV4)> {
+        let (length, addr) = poll_fn(|_| poll_io(self.socket.recv_from(buf))).await?;
+
+        match addr {
+            SocketAddr::V4(addr) => Ok((length, addr)),
+            SocketAddr::V6(_) => Err(NetworkError::Other),
+        }
+    }
+
+    fn local_addr(&self) -> SocketAddrV4 {
+        match self.socket.local_addr() {
+            Ok(SocketAddr::V4(addr)) => addr,
+            _ => SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0),
+        }
+    }
+}
+
+fn poll_io<T>(result: io::Result<T>) -> Poll<NetworkResult<T>> {
+    match result {
+        Err(err) if err.kind() == ErrorKind::WouldBlock => Poll::Pending,
+        result => Poll::Ready(result.map_err(convert_error)),
+    }
+}
+
+async fn spawn_blocking<F, T>(f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = channel();
+    thread::spawn(move || tx.send(f()));
+
+    poll_fn(move |_| match rx.try_recv() {
+        Ok(x) => Poll::Ready(x),
+        Err(TryRecvError::Empty) => Poll::Pending,
+        Err(TryRecvError::Disconnected) => panic!("Blocking task panicked"),
+    })
+    .await
+}
+
+fn convert_error(err: io::Error) -> NetworkError {
+    match err.kind() {
+        ErrorKind::ConnectionRefused => NetworkError::ConnectionRefused,
+        ErrorKind::ConnectionReset | ErrorKind::ConnectionAborted | ErrorKind::BrokenPipe | ErrorKind::UnexpectedEof => NetworkError::ConnectionReset,
+        ErrorKind::TimedOut => NetworkError::TimedOut,
+        ErrorKind::AddrInUse => NetworkError::AddressInUse,
+        _ => NetworkError::Other,
+    }
+}
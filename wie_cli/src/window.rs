@@ -1,14 +1,14 @@
 use alloc::sync::Arc;
 use core::{fmt::Debug, fmt::Formatter, num::NonZeroU32};
-use std::fmt;
+use std::{fmt, sync::Mutex};
 
 use fast_image_resize::ResizeAlg;
 use fast_image_resize::{PixelType, ResizeOptions, SrcCropping};
 use softbuffer::{Context, Surface};
 use winit::{
     application::ApplicationHandler,
-    dpi::{LogicalSize, PhysicalSize},
-    event::{ElementState, KeyEvent, StartCause, WindowEvent},
+    dpi::{LogicalSize, PhysicalPosition, PhysicalSize},
+    event::{ElementState, Ime, KeyEvent, MouseButton, StartCause, Touch, TouchPhase, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop, EventLoopProxy},
     keyboard::PhysicalKey,
     window::{Window as WinitWindow, WindowId},
@@ -16,10 +16,13 @@ use winit::{
 
 use wie_backend::{Screen, canvas::Image};
 
+use crate::capture::Capture;
+
 #[derive(Debug)]
 pub enum WindowInternalEvent {
     RequestRedraw,
     Paint(Vec<u32>),
+    SetTextInput(bool),
     Quit,
 }
 
@@ -28,12 +31,23 @@ pub enum WindowCallbackEvent {
     Redraw,
     Keydown(PhysicalKey),
     Keyup(PhysicalKey),
+    // text typed by a key while text input is enabled. it comes along with keydown of the same key
+    KeyText(PhysicalKey, String),
+    // text committed by input method
+    Text(String),
+    // pointer positions are in content coordinates
+    PointerDown { x: i32, y: i32 },
+    PointerUp { x: i32, y: i32 },
+    PointerMove { x: i32, y: i32 },
+    Focused(bool),
+    CloseRequested,
 }
 
 pub struct WindowHandle {
     width: u32,
     height: u32,
     event_loop_proxy: EventLoopProxy<WindowInternalEvent>,
+    capture: Arc<Mutex<Capture>>,
 }
 
 impl WindowHandle {
@@ -60,6 +74,9 @@ impl Screen for WindowHandle {
             .map(|x| ((x.a as u32) << 24) | ((x.r as u32) << 16) | ((x.g as u32) << 8) | (x.b as u32))
             .collect::<Vec<_>>();
 
+        // capture before scaling, to keep native resolution
+        self.capture.lock().unwrap().on_paint(image.width(), image.height(), &data);
+
         self.send_event(WindowInternalEvent::Paint(data)).unwrap()
     }
 
@@ -70,19 +87,29 @@ impl Screen for WindowHandle {
     fn height(&self) -> u32 {
         self.height
     }
+
+    fn set_text_input(&self, enabled: bool) {
+        self.send_event(WindowInternalEvent::SetTextInput(enabled)).unwrap()
+    }
 }
 
 pub struct WindowImpl {
     width: u32,
     height: u32,
     event_loop: EventLoop<WindowInternalEvent>,
+    capture: Arc<Mutex<Capture>>,
 }
 
 impl WindowImpl {
-    pub fn new(width: u32, height: u32) -> anyhow::Result<Self> {
+    pub fn new(width: u32, height: u32, capture: Arc<Mutex<Capture>>) -> anyhow::Result<Self> {
         let event_loop = EventLoop::<WindowInternalEvent>::with_user_event().build()?;
 
-        Ok(Self { width, height, event_loop })
+        Ok(Self {
+            width,
+            height,
+            event_loop,
+            capture,
+        })
     }
 
     pub fn handle(&self) -> WindowHandle {
@@ -90,6 +117,7 @@ impl WindowImpl {
             width: self.width,
             height: self.height,
             event_loop_proxy: self.event_loop.create_proxy(),
+            capture: self.capture.clone(),
         }
     }
 
@@ -114,6 +142,9 @@ impl WindowImpl {
             surface: None,
             callback: Box::new(callback),
             last_frame: None,
+            cursor_position: None,
+            pointer_pressed: false,
+            text_input: false,
         };
 
         Ok(self.event_loop.run_app(&mut handler)?)
@@ -234,6 +265,12 @@ where
     window_size: PhysicalSize<u32>,
     /// Last content screen image data.
     last_frame: Option<Vec<u32>>,
+    /// Last cursor position in the OS window.
+    cursor_position: Option<PhysicalPosition<f64>>,
+    /// Whether the left mouse button is being held after pressed on content.
+    pointer_pressed: bool,
+    /// Whether the app has a text field focused, sending typed text instead of keypad keys.
+    text_input: bool,
 
     window: Option<Arc<WinitWindow>>,
     context: Option<Context<Arc<WinitWindow>>>,
@@ -311,6 +348,45 @@ where
         self.paint_last_frame();
     }
 
+    /// Converts a position in the OS window to content coordinates.
+    /// Content is drawn scaled at the top left corner of the window, so we just need to undo the scaling.
+    fn to_content_position(&self, position: PhysicalPosition<f64>) -> (i32, i32) {
+        let x = position.x * self.content_size.width as f64 / self.scaled_size.width as f64;
+        let y = position.y * self.content_size.height as f64 / self.scaled_size.height as f64;
+
+        (x.floor() as i32, y.floor() as i32)
+    }
+
+    fn is_in_content(&self, (x, y): (i32, i32)) -> bool {
+        x >= 0 && y >= 0 && (x as u32) < self.content_size.width && (y as u32) < self.content_size.height
+    }
+
+    /// Handles mouse and touch input as a single pointer.
+    fn handle_pointer(&mut self, phase: TouchPhase, position: PhysicalPosition<f64>, event_loop: &ActiveEventLoop) {
+        let (x, y) = self.to_content_position(position);
+
+        match phase {
+            TouchPhase::Started if self.is_in_content((x, y)) => {
+                self.pointer_pressed = true;
+                self.callback(WindowCallbackEvent::PointerDown { x, y }, event_loop);
+            }
+            TouchPhase::Moved if self.pointer_pressed => {
+                // keep drags in bounds, as real screens can't report outside positions
+                let x = x.clamp(0, self.content_size.width as i32 - 1);
+                let y = y.clamp(0, self.content_size.height as i32 - 1);
+                self.callback(WindowCallbackEvent::PointerMove { x, y }, event_loop);
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled if self.pointer_pressed => {
+                self.pointer_pressed = false;
+
+                let x = x.clamp(0, self.content_size.width as i32 - 1);
+                let y = y.clamp(0, self.content_size.height as i32 - 1);
+                self.callback(WindowCallbackEvent::PointerUp { x, y }, event_loop);
+            }
+            _ => {}
+        }
+    }
+
     /// Displays the last content frame to the window.
     fn paint_last_frame(&mut self) -> Option<()> {
         let data = self.last_frame.as_ref()?;
@@ -359,6 +435,7 @@ where
             .with_title("WIE");
         let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
         let context = Context::new(window.clone()).unwrap();
+        window.set_ime_allowed(self.text_input);
         self.window = Some(window.clone());
         self.context = Some(context);
         self.window_size = window.inner_size();
@@ -380,6 +457,12 @@ where
                 self.last_frame = Some(data);
                 self.paint_last_frame();
             }
+            WindowInternalEvent::SetTextInput(enabled) => {
+                self.text_input = enabled;
+                if let Some(window) = &self.window {
+                    window.set_ime_allowed(enabled);
+                }
+            }
             WindowInternalEvent::Quit => {
                 event_loop.exit();
             }
@@ -388,24 +471,70 @@ where
 
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
         match event {
-            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::CloseRequested => {
+                // emulator decides when to quit, so that app can clean up
+                self.callback(WindowCallbackEvent::CloseRequested, event_loop);
+            }
+            WindowEvent::Focused(focused) => {
+                // we won't get button release while unfocused
+                if !focused && let Some(position) = self.cursor_position {
+                    self.handle_pointer(TouchPhase::Cancelled, position, event_loop);
+                }
+                self.callback(WindowCallbackEvent::Focused(focused), event_loop);
+            }
             WindowEvent::KeyboardInput {
                 event:
                     KeyEvent {
                         physical_key,
                         state,
-                        repeat: false,
+                        repeat,
+                        text,
                         ..
                     },
                 ..
-            } => match state {
-                ElementState::Pressed => {
-                    self.callback(WindowCallbackEvent::Keydown(physical_key), event_loop);
+            } => {
+                if !repeat {
+                    match state {
+                        ElementState::Pressed => {
+                            self.callback(WindowCallbackEvent::Keydown(physical_key), event_loop);
+                        }
+                        ElementState::Released => {
+                            self.callback(WindowCallbackEvent::Keyup(physical_key), event_loop);
+                        }
+                    }
                 }
-                ElementState::Released => {
-                    self.callback(WindowCallbackEvent::Keyup(physical_key), event_loop);
+
+                // while app has a text field focused, printable keys also type text. caller decides which keys emulate keypad
+                if self.text_input
+                    && state.is_pressed()
+                    && let Some(text) = text.filter(|x| !x.chars().any(char::is_control))
+                {
+                    self.callback(WindowCallbackEvent::KeyText(physical_key, text.to_string()), event_loop);
                 }
-            },
+            }
+            WindowEvent::Ime(Ime::Commit(text)) if self.text_input => {
+                self.callback(WindowCallbackEvent::Text(text), event_loop);
+            }
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor_position = Some(position);
+                self.handle_pointer(TouchPhase::Moved, position, event_loop);
+            }
+            WindowEvent::MouseInput {
+                state,
+                button: MouseButton::Left,
+                ..
+            } => {
+                if let Some(position) = self.cursor_position {
+                    let phase = match state {
+                        ElementState::Pressed => TouchPhase::Started,
+                        ElementState::Released => TouchPhase::Ended,
+                    };
+                    self.handle_pointer(phase, position, event_loop);
+                }
+            }
+            WindowEvent::Touch(Touch { phase, location, .. }) => {
+                self.handle_pointer(phase, location, event_loop);
+            }
             WindowEvent::RedrawRequested => {
                 self.callback(WindowCallbackEvent::Redraw, event_loop);
             }
@@ -1,19 +1,10 @@
-use alloc::{
-    borrow::ToOwned,
-    boxed::Box,
-    collections::btree_map::BTreeMap,
-    format, str,
-    string::{String, ToString},
-    vec::Vec,
-};
-
-use jvm::{
-    ClassInstance, Result as JvmResult,
-    runtime::{JavaIoInputStream, JavaLangString},
-};
-
-use wie_backend::{DefaultTaskRunner, Emulator, Event, Platform, System};
+use alloc::{borrow::ToOwned, boxed::Box, collections::btree_map::BTreeMap, format, string::String, vec::Vec};
+
+use jvm::{Result as JvmResult, runtime::JavaLangString};
+
+use wie_backend::{DefaultTaskRunner, Emulator, Event, Options, Platform, System, extract_zip};
 use wie_jvm_support::{JvmSupport, RustJavaJvmImplementation};
+use wie_midp::suite::{MIDletSuite, select_midlet};
 use wie_util::{Result, WieError};
 
 pub struct J2MEEmulator {
@@ -21,44 +12,37 @@ pub struct J2MEEmulator {
 }
 
 impl J2MEEmulator {
-    pub fn from_jad_jar(platform: Box<dyn Platform>, jad: Vec<u8>, jar_filename: String, jar: Vec<u8>) -> Result<Self> {
-        let descriptor = J2MEDescriptor::parse(&jad);
-
-        let files = [(jar_filename.to_owned(), jar)].into_iter().collect();
-        Self::load(
-            platform,
-            &jar_filename,
-            &descriptor.name,
-            Some(descriptor.main_class_name),
-            descriptor.properties,
-            &files,
-        )
-    }
+    pub fn from_jad_jar(platform: Box<dyn Platform>, jad: Vec<u8>, jar_filename: String, jar: Vec<u8>, options: Options) -> Result<Self> {
+        let jad = MIDletSuite::parse(&jad);
+        let id = jad.name().unwrap_or(&jar_filename).to_owned();
 
-    pub fn from_jar(platform: Box<dyn Platform>, jar_filename: &str, jar: Vec<u8>) -> Result<Self> {
-        let files = [(jar_filename.to_owned(), jar)].into_iter().collect();
+        Self::load(platform, &jar_filename, &id, Some(jad), jar, options)
+    }
 
-        Self::load(platform, jar_filename, jar_filename, None, BTreeMap::new(), &files)
+    pub fn from_jar(platform: Box<dyn Platform>, jar_filename: &str, jar: Vec<u8>, options: Options) -> Result<Self> {
+        Self::load(platform, jar_filename, jar_filename, None, jar, options)
     }
 
-    fn load(
-        platform: Box<dyn Platform>,
-        jar_filename: &str,
-        id: &str,
-        main_class_name: Option<String>,
-        properties: BTreeMap<String, String>,
-        files: &BTreeMap<String, Vec<u8>>,
-    ) -> Result<Self> {
-        let system = System::new(platform, id, id, DefaultTaskRunner);
+    fn load(platform: Box<dyn Platform>, jar_filename: &str, id: &str, jad: Option<MIDletSuite>, jar: Vec<u8>, options: Options) -> Result<Self> {
+        let resources = extract_zip(&jar)?;
+
+        let manifest = resources.get("META-INF/MANIFEST.MF").map(|x| MIDletSuite::parse(x)).unwrap_or_default();
+        let suite = match jad {
+            Some(jad) => MIDletSuite::merge(manifest, jad),
+            None => manifest,
+        };
 
-        for (path, data) in files {
-            system.filesystem().add(path, data.clone());
+        if suite.midlets().is_empty() {
+            return Err(WieError::FatalError("Main class not found".into()));
         }
 
+        let system = System::new(platform, id, id, DefaultTaskRunner);
+        system.filesystem().add(jar_filename, jar);
+
         let mut system_clone = system.clone();
         let jar_filename = jar_filename.to_owned();
 
-        system.spawn(async move || Self::do_start(&mut system_clone, jar_filename, properties, main_class_name).await);
+        system.spawn(async move || Self::do_start(&mut system_clone, jar_filename, suite, resources, options).await);
 
         Ok(J2MEEmulator { system })
     }
@@ -67,10 +51,24 @@ impl J2MEEmulator {
     async fn do_start(
         system: &mut System,
         jar_filename: String,
-        properties: BTreeMap<String, String>,
-        main_class_name: Option<String>,
+        suite: MIDletSuite,
+        resources: BTreeMap<String, Vec<u8>>,
+        options: Options,
     ) -> Result<()> {
-        let properties = properties
+        let midlets = suite.midlets();
+        let title = suite.name().unwrap_or(&jar_filename);
+
+        let Some(index) = select_midlet(system, title, &midlets, options.midlet, &resources).await else {
+            system.platform().exit();
+            return Ok(());
+        };
+        drop(resources);
+
+        let main_class_name = midlets[index].class_name.replace('.', "/");
+        tracing::info!("Launching {} ({main_class_name})", midlets[index].name);
+
+        let properties = suite
+            .into_attributes()
             .into_iter()
             .map(|(k, v)| (format!("wie.appProperty.{k}"), v))
             .collect::<Vec<_>>();
@@ -79,51 +77,6 @@ impl J2MEEmulator {
         let protos = [wie_midp::get_protos().into()];
         let jvm = JvmSupport::new_jvm(system, Some(&jar_filename), Box::new(protos), &properties, RustJavaJvmImplementation).await?;
 
-        let main_class_name = if let Some(x) = main_class_name {
-            x.replace('.', "/")
-        } else {
-            let class_loader = jvm
-                .invoke_static("java/lang/ClassLoader", "getSystemClassLoader", "()Ljava/lang/ClassLoader;", ())
-                .await
-                .unwrap();
-
-            // TODO Use JarFile::getManifest
-            let resource_name = JavaLangString::from_rust_string(&jvm, "META-INF/MANIFEST.MF").await.unwrap();
-            let resource_stream = jvm
-                .invoke_virtual(
-                    &class_loader,
-                    "getResourceAsStream",
-                    "(Ljava/lang/String;)Ljava/io/InputStream;",
-                    (resource_name.clone(),),
-                )
-                .await
-                .unwrap();
-            let data = JavaIoInputStream::read_until_end(&jvm, &resource_stream).await.unwrap();
-
-            let descriptor = J2MEDescriptor::parse(&data);
-
-            for (k, v) in descriptor.properties {
-                let property_key = format!("wie.appProperty.{k}");
-                let property_key = JavaLangString::from_rust_string(&jvm, &property_key).await.unwrap();
-                let property_value = JavaLangString::from_rust_string(&jvm, &v).await.unwrap();
-
-                let _: Option<Box<dyn ClassInstance>> = jvm
-                    .invoke_static(
-                        "java/lang/System",
-                        "setProperty",
-                        "(Ljava/lang/String;Ljava/lang/String;)Ljava/lang/Object;",
-                        (property_key, property_value),
-                    )
-                    .await
-                    .unwrap();
-            }
-
-            if descriptor.main_class_name.is_empty() {
-                return Err(WieError::FatalError("Main class not found".into()));
-            }
-            descriptor.main_class_name.replace('.', "/")
-        };
-
         let main_class_java = JavaLangString::from_rust_string(&jvm, &main_class_name).await.unwrap();
 
         let result: JvmResult<()> = jvm
@@ -147,45 +100,3 @@ impl Emulator for J2MEEmulator {
         self.system.tick()
     }
 }
-
-struct J2MEDescriptor {
-    name: String,
-    main_class_name: String,
-    properties: BTreeMap<String, String>,
-}
-
-impl J2MEDescriptor {
-    pub fn parse(data: &[u8]) -> Self {
-        let lines = data.split(|x| *x == b'\n');
-
-        let mut name = String::new();
-        let mut main_class_name = String::new();
-        let mut properties = BTreeMap::new();
-
-        for line in lines {
-            let line = str::from_utf8(line).unwrap().trim();
-
-            if line.is_empty() {
-                continue;
-            }
-
-            let mut parts = line.splitn(2, ':');
-
-            let key = parts.next().unwrap().trim();
-            let value = parts.next().unwrap().trim();
-            properties.insert(key.trim().to_string(), value.trim().to_string());
-
-            match key {
-                "MIDlet-Name" => name = value.to_string(),
-                "MIDlet-1" => main_class_name = value.split(',').nth(2).unwrap().trim().to_string(),
-                _ => {}
-            }
-        }
-
-        Self {
-            name,
-            main_class_name,
-            properties,
-        }
-    }
-}
@@ -2,7 +2,7 @@ use alloc::{format, string::String};
 use core::mem::size_of;
 use jvm::Jvm;
 
-use wie_backend::System;
+use wie_backend::{ProgramId, System};
 use wie_core_arm::{Allocator, ArmCore};
 use wie_util::{Result, WieError, read_generic, read_null_terminated_string_bytes, write_generic};
 
@@ -11,10 +11,23 @@ use wipi_types::ktf::{ExeInterface, ExeInterfaceFunctions, InitParam0, InitParam
 use crate::{
     emulator::IMAGE_BASE,
     runtime::{
-        java::interface::{get_wipi_jb_interface, java_array_new, java_check_type, java_class_load, java_new, java_throw},
+        java::{
+            interface::{get_wipi_jb_interface, java_array_new, java_check_type, java_class_load, java_new, java_throw},
+            jvm_support::KtfJvmSupport,
+        },
         wipi_c::interface::get_wipic_knl_interface,
     },
 };
+
+// loaded images are page aligned
+const IMAGE_ALIGNMENT: u32 = 0x1000;
+
+fn parse_bss_size(filename: &str) -> Option<u32> {
+    let bss_start = filename.find("client.bin")? + 10;
+
+    filename[bss_start..].parse::<u32>().ok()
+}
+
 pub async fn load_native(
     core: &mut ArmCore,
     system: &mut System,
@@ -24,14 +37,83 @@ pub async fn load_native(
     ptr_jvm_context: u32,
     ptr_jvm_exception_context: u32,
 ) -> Result<ExeInterfaceFunctions> {
-    let bss_start = filename.find("client.bin").unwrap() + 10;
-    let bss_size = filename[bss_start..].parse::<u32>().unwrap();
+    let bss_size = parse_bss_size(filename).unwrap();
+    let program = system.programs().current();
+
+    let (wipi_exe, exe_interface_functions) = load_image(
+        core,
+        system,
+        jvm,
+        program,
+        IMAGE_BASE,
+        data,
+        bss_size,
+        ptr_jvm_context,
+        ptr_jvm_exception_context,
+    )
+    .await?;
+
+    // call init
+    let result = core.run_function::<u32>(wipi_exe.fn_init, &[]).await?;
+    if result != 0 {
+        return Err(WieError::FatalError(format!("wipi init failed with code {result:#x}")));
+    }
 
-    core.load(data, IMAGE_BASE, data.len() + bss_size as usize)?;
+    Ok(exe_interface_functions)
+}
 
-    tracing::debug!("Loaded at {:#x}, size {:#x}, bss {:#x}", IMAGE_BASE, data.len(), bss_size);
+// loads executable of other program in package after already loaded images, and returns its init function
+pub async fn load_module(core: &mut ArmCore, system: &mut System, jvm: &Jvm, program: ProgramId, filename: &str, data: &[u8]) -> Result<u32> {
+    let bss_size = parse_bss_size(filename).unwrap_or(0);
+    let base = system.programs().image_end().unwrap_or(IMAGE_BASE).next_multiple_of(IMAGE_ALIGNMENT);
+    let (ptr_jvm_context, ptr_jvm_exception_context) = KtfJvmSupport::jvm_context(core)?;
+
+    let (wipi_exe, _) = load_image(
+        core,
+        system,
+        jvm,
+        program,
+        base,
+        data,
+        bss_size,
+        ptr_jvm_context,
+        ptr_jvm_exception_context,
+    )
+    .await?;
+
+    Ok(wipi_exe.fn_init)
+}
 
-    let wipi_exe = core.run_function(IMAGE_BASE + 1, &[bss_size]).await?;
+// loads image and initializes its exe interface, which gives the image access to wipi apis
+#[allow(clippy::too_many_arguments)]
+async fn load_image(
+    core: &mut ArmCore,
+    system: &mut System,
+    jvm: &Jvm,
+    program: ProgramId,
+    base: u32,
+    data: &[u8],
+    bss_size: u32,
+    ptr_jvm_context: u32,
+    ptr_jvm_exception_context: u32,
+) -> Result<(WipiExe, ExeInterfaceFunctions)> {
+    let size = (data.len() as u32)
+        .checked_add(bss_size)
+        .ok_or_else(|| WieError::FatalError(format!("Invalid image size {:#x}, bss {:#x}", data.len(), bss_size)))?;
+
+    let region_size = size.next_multiple_of(IMAGE_ALIGNMENT);
+    if let Some(x) = system.programs().overlapping_image(program, base, region_size) {
+        return Err(WieError::FatalError(format!(
+            "Image of program {program} at {base:#x}, size {size:#x} overlaps image of program {x}"
+        )));
+    }
+    system.programs().add_image_region(program, base, region_size);
+
+    core.load(data, base, size as usize)?;
+
+    tracing::debug!("Loaded at {:#x}, size {:#x}, bss {:#x}", base, data.len(), bss_size);
+
+    let wipi_exe = core.run_function(base + 1, &[bss_size]).await?;
     tracing::debug!("Got wipi_exe {:#x}", wipi_exe);
 
     let ptr_param_0 = Allocator::alloc(core, size_of::<InitParam0>() as u32)?;
@@ -92,13 +174,7 @@ pub async fn load_native(
         return Err(WieError::FatalError(format!("Init failed with code {result:#x}")));
     }
 
-    // call init
-    let result = core.run_function::<u32>(wipi_exe.fn_init, &[]).await?;
-    if result != 0 {
-        return Err(WieError::FatalError(format!("wipi init failed with code {result:#x}")));
-    }
-
-    Ok(exe_interface_functions)
+    Ok((wipi_exe, exe_interface_functions))
 }
 
 async fn get_interface(core: &mut ArmCore, (system, jvm): &mut (System, Jvm), ptr_name: u32) -> Result<u32> {
@@ -54,6 +54,7 @@ struct KtfJvmExceptionContext {
 struct KtfJvmSupportContext {
     ptr_vtables_base: u32,
     ptr_jvm_exception_context: u32,
+    ptr_jvm_context: u32,
 }
 
 const SUPPORT_CONTEXT_BASE: u32 = 0x7fff0000;
@@ -81,6 +82,7 @@ impl KtfJvmSupport {
         let context_data = KtfJvmSupportContext {
             ptr_vtables_base: ptr_jvm_context + 12,
             ptr_jvm_exception_context,
+            ptr_jvm_context,
         };
         write_generic(core, SUPPORT_CONTEXT_BASE, context_data)?;
 
@@ -193,6 +195,13 @@ impl KtfJvmSupport {
         Ok(index as _)
     }
 
+    // returns (ptr_jvm_context, ptr_jvm_exception_context), which are passed to native executables on init
+    pub fn jvm_context(core: &ArmCore) -> Result<(u32, u32)> {
+        let context_data: KtfJvmSupportContext = read_generic(core, SUPPORT_CONTEXT_BASE)?;
+
+        Ok((context_data.ptr_jvm_context, context_data.ptr_jvm_exception_context))
+    }
+
     pub fn current_java_exception_handler(core: &mut ArmCore) -> Result<u32> {
         let context_data: KtfJvmSupportContext = read_generic(core, SUPPORT_CONTEXT_BASE)?;
         let exception_context: KtfJvmExceptionContext = read_generic(core, context_data.ptr_jvm_exception_context)?;
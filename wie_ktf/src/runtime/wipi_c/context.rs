@@ -5,11 +5,14 @@ use jvm::{
     runtime::{JavaIoInputStream, JavaLangClassLoader},
 };
 
-use wie_backend::{AsyncCallable, Event, Instant, System};
+use wie_backend::{AsyncCallable, Instant, ProgramId, System, TimerId};
 use wie_core_arm::{Allocator, ArmCore, EmulatedFunction, EmulatedFunctionParam, ResultWriter};
+use wie_libc::printf::{CoreArgs, VarArgs};
 use wie_util::{ByteRead, ByteWrite, Result, read_generic, write_generic};
 use wie_wipi_c::{WIPICContext, WIPICMemoryId, WIPICMethodBody, WIPICResult, WIPICWord};
 
+use crate::runtime::init::load_module;
+
 #[derive(Clone)]
 pub struct KtfWIPICContext {
     core: ArmCore,
@@ -110,6 +113,10 @@ impl WIPICContext for KtfWIPICContext {
         self.core.run_function(address, args).await
     }
 
+    fn var_args(&self, first_slot: usize) -> Box<dyn VarArgs + '_> {
+        Box::new(CoreArgs::new(&self.core, first_slot))
+    }
+
     fn spawn(&mut self, callback: WIPICMethodBody) -> Result<()> {
         struct SpawnProxy {
             context: KtfWIPICContext,
@@ -157,17 +164,23 @@ impl WIPICContext for KtfWIPICContext {
         Ok(JavaIoInputStream::read_until_end(&self.jvm, &stream).await.unwrap())
     }
 
-    fn set_timer(&mut self, due: Instant, callback: WIPICMethodBody) {
+    fn set_timer(&mut self, id: TimerId, due: Instant, callback: WIPICMethodBody) {
         let context = self.clone();
 
-        self.system().event_queue().push(Event::timer(due, move || {
+        self.system().event_queue().set_timer(id, due, move || {
             let mut context = context.clone();
 
             async move {
                 callback.call(&mut context, Box::new([])).await?;
                 Ok(())
             }
-        }))
+        })
+    }
+
+    async fn load_executable(&mut self, program: ProgramId, path: &str) -> Result<WIPICWord> {
+        let data = self.read_resource(path).await?;
+
+        load_module(&mut self.core, &mut self.system, &self.jvm, program, path, &data).await
     }
 }
 
@@ -1,12 +1,14 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
 use core::mem::{size_of, size_of_val};
 use jvm::Jvm;
 
 use bytemuck::{Pod, Zeroable};
+use spin::Mutex;
 
 use wie_backend::System;
 use wie_core_arm::{Allocator, ArmCore};
-use wie_util::{Result, write_generic};
+use wie_libc::RandSeed;
+use wie_util::{Result, read_null_terminated_string_bytes, write_generic};
 use wie_wipi_c::{WIPICContext, WIPICMethodBody};
 
 use crate::runtime::wipi_c::{context::KtfWIPICContext, method_table};
@@ -49,7 +51,8 @@ fn write_methods(context: &mut dyn WIPICContext, methods: Vec<WIPICMethodBody>)
 
 pub fn get_wipic_knl_interface(core: &mut ArmCore, system: &mut System, jvm: &Jvm) -> Result<u32> {
     let mut context = KtfWIPICContext::new(core.clone(), system.clone(), jvm.clone());
-    let kernel_interface = method_table::get_kernel_interface(&mut context, get_wipic_interfaces)?;
+    let mut kernel_interface = method_table::get_kernel_interface(&mut context, get_wipic_interfaces)?;
+    kernel_interface.oemc_get_proc_address = core.register_function(get_proc_address, &(context, RandSeed::default(), ProcAddresses::default()))?;
 
     let address = Allocator::alloc(core, size_of_val(&kernel_interface) as u32)?;
     write_generic(core, address, kernel_interface)?;
@@ -57,6 +60,37 @@ pub fn get_wipic_knl_interface(core: &mut ArmCore, system: &mut System, jvm: &Jv
     Ok(address)
 }
 
+// addresses of procedures already looked up, so that repeated lookups don't register new functions
+type ProcAddresses = Arc<Mutex<BTreeMap<String, u32>>>;
+
+// c runtime functions and wie extensions are provided by name, as they are not part of wipi interfaces
+async fn get_proc_address(
+    core: &mut ArmCore,
+    (context, rand_seed, addresses): &mut (KtfWIPICContext, RandSeed, ProcAddresses),
+    id: u32,
+    ptr_name: u32,
+) -> Result<u32> {
+    let name = String::from_utf8(read_null_terminated_string_bytes(core, ptr_name)?).unwrap_or_default();
+    tracing::debug!("OEMC_knlGetProcAddress({:#x}, {})", id, name);
+
+    if let Some(&address) = addresses.lock().get(&name) {
+        return Ok(address);
+    }
+
+    let address = if let Some(method) = method_table::get_extension_method(&name) {
+        context.register_function(method)?
+    } else if let Some(address) = wie_libc::get_function(core, rand_seed, &name)? {
+        address
+    } else {
+        tracing::warn!("Unknown procedure {}", name);
+        return Ok(0);
+    };
+
+    addresses.lock().insert(name, address);
+
+    Ok(address)
+}
+
 pub async fn get_wipic_interfaces(context: &mut dyn WIPICContext) -> Result<u32> {
     tracing::trace!("get_wipic_interfaces");
 
@@ -21,24 +21,24 @@ where
     Ok(WIPICKnlInterface {
         printk: context.register_function(kernel::printk.into_body())?,
         sprintk: context.register_function(kernel::sprintk.into_body())?,
-        get_exec_names: context.register_function(gen_stub(2, "MC_knlGetExecNames"))?,
-        execute: context.register_function(gen_stub(3, "MC_knlExecute"))?,
-        mexecute: context.register_function(gen_stub(4, "MC_knlMExecute"))?,
-        load: context.register_function(gen_stub(5, "MC_knlLoad"))?,
+        get_exec_names: context.register_function(kernel::get_exec_names.into_body())?,
+        execute: context.register_function(kernel::execute.into_body())?,
+        mexecute: context.register_function(kernel::mexecute.into_body())?,
+        load: context.register_function(kernel::load.into_body())?,
         mload: context.register_function(gen_stub(6, "MC_knlMLoad"))?,
         exit: context.register_function(kernel::exit.into_body())?,
-        program_stop: context.register_function(gen_stub(8, "MC_knlProgramStop"))?,
+        program_stop: context.register_function(kernel::program_stop.into_body())?,
         get_cur_program_id: context.register_function(kernel::get_cur_program_id.into_body())?,
-        get_parent_program_id: context.register_function(gen_stub(10, "MC_knlGetParentProgramID"))?,
+        get_parent_program_id: context.register_function(kernel::get_parent_program_id.into_body())?,
         get_app_manager_id: context.register_function(gen_stub(11, "MC_knlGetAppManagerID"))?,
-        get_program_info: context.register_function(gen_stub(12, "MC_knlGetProgramInfo"))?,
-        get_access_level: context.register_function(gen_stub(13, "MC_knlGetAccessLevel"))?,
+        get_program_info: context.register_function(kernel::get_program_info.into_body())?,
+        get_access_level: context.register_function(kernel::get_access_level.into_body())?,
         get_program_name: context.register_function(kernel::get_program_name.into_body())?,
-        create_shared_buf: context.register_function(gen_stub(15, "MC_knlCreateSharedBuf"))?,
-        destroy_shared_buf: context.register_function(gen_stub(16, "MC_knlDestroySharedBuf"))?,
-        get_shared_buf: context.register_function(gen_stub(17, "MC_knlGetSharedBuf"))?,
-        get_shared_buf_size: context.register_function(gen_stub(18, "MC_knlGetSharedBufSize"))?,
-        resize_shared_buf: context.register_function(gen_stub(19, "MC_knlResizeSharedBuf"))?,
+        create_shared_buf: context.register_function(kernel::create_shared_buf.into_body())?,
+        destroy_shared_buf: context.register_function(kernel::destroy_shared_buf.into_body())?,
+        get_shared_buf: context.register_function(kernel::get_shared_buf.into_body())?,
+        get_shared_buf_size: context.register_function(kernel::get_shared_buf_size.into_body())?,
+        resize_shared_buf: context.register_function(kernel::resize_shared_buf.into_body())?,
         alloc: context.register_function(kernel::alloc.into_body())?,
         calloc: context.register_function(kernel::calloc.into_body())?,
         free: context.register_function(kernel::free.into_body())?,
@@ -64,7 +64,7 @@ where
         reserved10: context.register_function(gen_stub(42, "MC_knlReserved10"))?,
         reserved11: context.register_function(gen_stub(43, "MC_knlReserved11"))?,
         oemc_send_message: context.register_function(gen_stub(44, "OEMC_knlSendMessage"))?,
-        oemc_set_timer_ex: context.register_function(gen_stub(45, "OEMC_knlSetTimerEx"))?,
+        oemc_set_timer_ex: context.register_function(kernel::set_timer_ex.into_body())?,
         oemc_get_system_state: context.register_function(gen_stub(46, "OEMC_knlGetSystemState"))?,
         oemc_create_system_progress_bar: context.register_function(gen_stub(47, "OEMC_knlCreateSystemProgressBar"))?,
         oemc_set_system_progress_bar: context.register_function(gen_stub(48, "OEMC_knlSetSystemProgressBar"))?,
@@ -78,10 +78,10 @@ where
         oemc_get_program_info: context.register_function(gen_stub(56, "OEMC_knlGetProgramInfo"))?,
         reserved12: context.register_function(gen_stub(57, "MC_knlReserved12"))?,
         reserved13: context.register_function(gen_stub(58, "MC_knlReserved13"))?,
-        oemc_create_app_private_area: context.register_function(gen_stub(59, "OEMC_knlCreateAppPrivateArea"))?,
-        oemc_get_app_private_area: context.register_function(gen_stub(60, "OEMC_knlGetAppPrivateArea"))?,
-        oemc_create_lib_private_area: context.register_function(gen_stub(61, "OEMC_knlCreateLibPrivateArea"))?,
-        oemc_get_lib_private_area: context.register_function(gen_stub(62, "OEMC_knlGetLibPrivateArea"))?,
+        oemc_create_app_private_area: context.register_function(kernel::create_app_private_area.into_body())?,
+        oemc_get_app_private_area: context.register_function(kernel::get_app_private_area.into_body())?,
+        oemc_create_lib_private_area: context.register_function(kernel::create_lib_private_area.into_body())?,
+        oemc_get_lib_private_area: context.register_function(kernel::get_lib_private_area.into_body())?,
         oemc_get_platform_version: context.register_function(gen_stub(63, "OEMC_knlGetPlatformVersion"))?,
         oemc_get_token: context.register_function(gen_stub(64, "OEMC_knlGetToken"))?,
     })
@@ -90,13 +90,13 @@ where
 pub fn get_graphics_method_table() -> Vec<WIPICMethodBody> {
     vec![
         graphics::get_image_property.into_body(),
-        gen_stub(1, "MC_grpGetImageFrameBuffer"),
+        graphics::get_image_framebuffer.into_body(),
         graphics::get_screen_framebuffer.into_body(),
         graphics::destroy_offscreen_framebuffer.into_body(),
         graphics::create_offscreen_framebuffer.into_body(),
         graphics::init_context.into_body(),
         graphics::set_context.into_body(),
-        gen_stub(7, "MC_grpGetContext"),
+        graphics::get_context.into_body(),
         graphics::put_pixel.into_body(),
         graphics::draw_line.into_body(),
         graphics::draw_rect.into_body(),
@@ -104,12 +104,12 @@ pub fn get_graphics_method_table() -> Vec<WIPICMethodBody> {
         graphics::copy_frame_buffer.into_body(),
         graphics::draw_image.into_body(),
         graphics::copy_area.into_body(),
-        gen_stub(15, "MC_grpDrawArc"),
-        gen_stub(16, "MC_grpFillArc"),
+        graphics::draw_arc.into_body(),
+        graphics::fill_arc.into_body(),
         graphics::draw_string.into_body(),
-        gen_stub(18, "MC_grpDrawUnicodeString"),
-        gen_stub(19, "MC_grpGetRGBPixels"),
-        gen_stub(20, "MC_grpSetRGBPixels"),
+        graphics::draw_unicode_string.into_body(),
+        graphics::get_rgb_pixels.into_body(),
+        graphics::set_rgb_pixels.into_body(),
         graphics::flush.into_body(),
         graphics::get_pixel_from_rgb.into_body(),
         graphics::get_rgb_from_pixel.into_body(),
@@ -117,13 +117,13 @@ pub fn get_graphics_method_table() -> Vec<WIPICMethodBody> {
         graphics::repaint.into_body(),
         graphics::get_font.into_body(),
         graphics::get_font_height.into_body(),
-        gen_stub(28, "MC_grpGetFontAscent"),
-        gen_stub(29, "MC_grpGetFontDescent"),
+        graphics::get_font_ascent.into_body(),
+        graphics::get_font_descent.into_body(),
         graphics::get_string_width.into_body(),
-        gen_stub(31, "MC_grpGetUnicodeStringWidth"),
+        graphics::get_unicode_string_width.into_body(),
         graphics::create_image.into_body(),
-        gen_stub(33, "MC_grpDestroyImage"),
-        gen_stub(34, "MC_grpDecodeNextImage"),
+        graphics::destroy_image.into_body(),
+        graphics::decode_next_image.into_body(),
         gen_stub(35, "MC_grpEncodeImage"),
         graphics::post_event.into_body(),
         gen_stub(37, "MC_imHandleInput"),
@@ -131,8 +131,8 @@ pub fn get_graphics_method_table() -> Vec<WIPICMethodBody> {
         gen_stub(39, "MC_imGetCurrentMode"),
         gen_stub(40, "MC_imGetSupportModeCount"),
         gen_stub(41, "MC_imGetSupportedModes"),
-        gen_stub(42, "MC_grpFillPolygon"),
-        gen_stub(43, "MC_grpDrawPolygon"),
+        graphics::fill_polygon.into_body(),
+        graphics::draw_polygon.into_body(),
         gen_stub(44, "OEMC_grpShowAnnunciator"),
         gen_stub(45, "OEMC_grpGetAnnunciatorInfo"),
         gen_stub(46, "OEMC_grpSetAnnunciatorIcon"),
@@ -172,6 +172,16 @@ pub fn get_graphics_method_table() -> Vec<WIPICMethodBody> {
     ]
 }
 
+// wie extensions without slot in wipi-c interface tables, provided by name through OEMC_knlGetProcAddress
+pub fn get_extension_method(name: &str) -> Option<WIPICMethodBody> {
+    Some(match name {
+        "MC_grpGetImagePalette" => graphics::get_image_palette.into_body(),
+        "MC_grpSetImagePalette" => graphics::set_image_palette.into_body(),
+        "MC_grpSetImageTransparentIndex" => graphics::set_image_transparent_index.into_body(),
+        _ => return None,
+    })
+}
+
 pub fn get_util_method_table() -> Vec<WIPICMethodBody> {
     vec![
         gen_stub(0, "MC_utilHtonl"),
@@ -201,14 +211,14 @@ pub fn get_database_method_table() -> Vec<WIPICMethodBody> {
         database::write_record_single.into_body(),
         database::close_database.into_body(),
         database::select_record.into_body(),
-        gen_stub(5, "MC_dbUpdateRecord"),
+        database::update_record.into_body(),
         database::delete_record.into_body(),
         database::list_record.into_body(),
-        gen_stub(8, "MC_dbSortRecords"),
+        database::sort_records.into_body(),
         gen_stub(9, "MC_dbGetAccessMode"),
-        gen_stub(10, "MC_dbGetNumberOfRecords"),
-        gen_stub(11, "MC_dbGetRecordSize"),
-        gen_stub(12, "MC_dbListDataBases"),
+        database::get_number_of_records.into_body(),
+        database::get_record_size.into_body(),
+        database::list_databases.into_body(),
         gen_stub(13, ""),
         gen_stub(14, ""),
         gen_stub(15, ""),
@@ -219,50 +229,50 @@ pub fn get_database_method_table() -> Vec<WIPICMethodBody> {
 pub fn get_uic_method_table() -> Vec<WIPICMethodBody> {
     vec![
         uic::create_application_context.into_body(),
-        gen_stub(1, "MC_uicGetClass"),
-        gen_stub(2, "MC_uicCreate"),
-        gen_stub(3, "MC_uicDestroy"),
-        gen_stub(4, "MC_uicRepaint"),
-        gen_stub(5, "MC_uicPaint"),
-        gen_stub(6, "MC_uicGetClassName"),
-        gen_stub(7, "MC_uicIsInstance"),
-        gen_stub(8, "MC_uicHandleEvent"),
-        gen_stub(9, "MC_uicConfigure"),
-        gen_stub(10, "MC_uicGetGeometry"),
-        gen_stub(11, "MC_uicSetEnable"),
-        gen_stub(12, "MC_uicSetCallback"),
-        gen_stub(13, "MC_uicSetEventHandler"),
-        gen_stub(14, "MC_uicSetFont"),
-        gen_stub(15, "MC_uicGetFont"),
-        gen_stub(16, "MC_uicSetFgColor"),
-        gen_stub(17, "MC_uicSetBgColor"),
-        gen_stub(18, "MC_uicSetLabel"),
-        gen_stub(19, "MC_uicGetLabel"),
-        gen_stub(20, "MC_uicSetLabelAlignment"),
-        gen_stub(21, "MC_uicSetTimeMask"),
-        gen_stub(22, "MC_uicSetTime"),
-        gen_stub(23, "MC_uicSetTimeLong"),
-        gen_stub(24, "MC_uicGetTime"),
-        gen_stub(25, "MC_uicAddMenuItem"),
-        gen_stub(26, "MC_uicGetMenuItem"),
-        gen_stub(27, "MC_uicRemoveMenuItem"),
-        gen_stub(28, "MC_uicSetActiveMenuItem"),
-        gen_stub(29, "MC_uicGetActiveMenuItem"),
-        gen_stub(30, "MC_uicInsertText"),
-        gen_stub(31, "MC_uicDeleteText"),
-        gen_stub(32, "MC_uicGetMaxTextSize"),
-        gen_stub(33, "MC_uicSetMaxTextSize"),
-        gen_stub(34, "MC_uicGetTextSize"),
-        gen_stub(35, "MC_uicGetText"),
-        gen_stub(36, "MC_uicAddListItem"),
-        gen_stub(37, "MC_uicGetListItem"),
-        gen_stub(38, "MC_uicRemoveListItem"),
-        gen_stub(39, "MC_uicSetActiveListItem"),
-        gen_stub(40, "MC_uicGetActiveListItem"),
-        gen_stub(41, "OEMC_uicGetCursorPosition"),
-        gen_stub(42, "OEMC_uicSetCursorPosition"),
-        gen_stub(43, "OEMC_uicSetLineGap"),
-        gen_stub(44, "OEMC_uicGetLineGap"),
+        uic::get_class.into_body(),
+        uic::create.into_body(),
+        uic::destroy.into_body(),
+        uic::repaint.into_body(),
+        uic::paint.into_body(),
+        uic::get_class_name.into_body(),
+        uic::is_instance.into_body(),
+        uic::handle_event.into_body(),
+        uic::configure.into_body(),
+        uic::get_geometry.into_body(),
+        uic::set_enable.into_body(),
+        uic::set_callback.into_body(),
+        uic::set_event_handler.into_body(),
+        uic::set_font.into_body(),
+        uic::get_font.into_body(),
+        uic::set_fg_color.into_body(),
+        uic::set_bg_color.into_body(),
+        uic::set_label.into_body(),
+        uic::get_label.into_body(),
+        uic::set_label_alignment.into_body(),
+        uic::set_time_mask.into_body(),
+        uic::set_time.into_body(),
+        uic::set_time_long.into_body(),
+        uic::get_time.into_body(),
+        uic::add_menu_item.into_body(),
+        uic::get_menu_item.into_body(),
+        uic::remove_menu_item.into_body(),
+        uic::set_active_menu_item.into_body(),
+        uic::get_active_menu_item.into_body(),
+        uic::insert_text.into_body(),
+        uic::delete_text.into_body(),
+        uic::get_max_text_size.into_body(),
+        uic::set_max_text_size.into_body(),
+        uic::get_text_size.into_body(),
+        uic::get_text.into_body(),
+        uic::add_list_item.into_body(),
+        uic::get_list_item.into_body(),
+        uic::remove_list_item.into_body(),
+        uic::set_active_list_item.into_body(),
+        uic::get_active_list_item.into_body(),
+        uic::get_cursor_position.into_body(),
+        uic::set_cursor_position.into_body(),
+        uic::set_line_gap.into_body(),
+        uic::get_line_gap.into_body(),
     ]
 }
 
@@ -302,34 +312,34 @@ pub fn get_net_method_table() -> Vec<WIPICMethodBody> {
     vec![
         net::connect.into_body(),
         net::close.into_body(),
-        gen_stub(2, "MC_netSocket"),
-        gen_stub(3, "MC_netSocketConnect"),
-        gen_stub(4, "MC_netSocketWrite"),
-        gen_stub(5, "MC_netSocketRead"),
+        net::socket.into_body(),
+        net::socket_connect.into_body(),
+        net::socket_write.into_body(),
+        net::socket_read.into_body(),
         net::socket_close.into_body(),
-        gen_stub(7, "MC_netSocketBind"),
-        gen_stub(8, "MC_netGetMaxPacketLength"),
-        gen_stub(9, "MC_netSocketSendTo"),
-        gen_stub(10, "MC_netSocketRcvFrom"),
-        gen_stub(11, "MC_netGetHostAddr"),
+        net::socket_bind.into_body(),
+        net::get_max_packet_length.into_body(),
+        net::socket_send_to.into_body(),
+        net::socket_rcv_from.into_body(),
+        net::get_host_addr.into_body(),
         gen_stub(12, "MC_netSocketAccept"),
-        gen_stub(13, "MC_netSetReadCB"),
-        gen_stub(14, "MC_netSetWriteCB"),
-        gen_stub(15, "MC_netHttpOpen"),
-        gen_stub(16, "MC_netHttpConnect"),
-        gen_stub(17, "MC_netHttpSetRequestMethod"),
-        gen_stub(18, "MC_netHttpGetRequestMethod"),
-        gen_stub(19, "MC_netHttpSetRequestProperty"),
-        gen_stub(20, "MC_netHttpGetRequestProperty"),
-        gen_stub(21, "MC_netHttpSetProxy"),
-        gen_stub(22, "MC_netHttpGetProxy"),
-        gen_stub(23, "MC_netHttpGetResponseCode"),
-        gen_stub(24, "MC_netHttpGetResponseMessage"),
-        gen_stub(25, "MC_netHttpGetHeaderField"),
-        gen_stub(26, "MC_netHttpGetLength"),
-        gen_stub(27, "MC_netHttpGetType"),
-        gen_stub(28, "MC_netHttpGetEncoding"),
-        gen_stub(29, "MC_netHttpClose"),
+        net::set_read_cb.into_body(),
+        net::set_write_cb.into_body(),
+        net::http_open.into_body(),
+        net::http_connect.into_body(),
+        net::http_set_request_method.into_body(),
+        net::http_get_request_method.into_body(),
+        net::http_set_request_property.into_body(),
+        net::http_get_request_property.into_body(),
+        net::http_set_proxy.into_body(),
+        net::http_get_proxy.into_body(),
+        net::http_get_response_code.into_body(),
+        net::http_get_response_message.into_body(),
+        net::http_get_header_field.into_body(),
+        net::http_get_length.into_body(),
+        net::http_get_type.into_body(),
+        net::http_get_encoding.into_body(),
+        net::http_close.into_body(),
     ]
 }
 
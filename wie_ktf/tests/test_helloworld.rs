@@ -30,7 +30,14 @@ pub fn test_helloworld() -> Result<()> {
     let platform = Box::new(TestPlatform::with_event_handler(event_handler));
 
     let archive = extract_zip(include_bytes!("../../test_data/helloworld_ktf.zip"))?;
-    let mut emulator = KtfEmulator::from_archive(platform, archive, Options { enable_gdbserver: false })?;
+    let mut emulator = KtfEmulator::from_archive(
+        platform,
+        archive,
+        Options {
+            enable_gdbserver: false,
+            midlet: None,
+        },
+    )?;
 
     while !exited.load(Ordering::SeqCst) {
         emulator.tick()?;
@@ -1,4 +1,4 @@
-use alloc::format;
+use alloc::{format, vec::Vec};
 use core::mem::size_of;
 
 use elf::{ElfBytes, endian::AnyEndian};
@@ -6,14 +6,26 @@ use elf::{ElfBytes, endian::AnyEndian};
 use jvm::Jvm;
 use wipi_types::lgt::{InitParam1, InitParam2, InitStruct};
 
-use wie_backend::System;
+use wie_backend::{ProgramId, System};
 use wie_core_arm::{Allocator, ArmCore};
+use wie_libc::RandSeed;
 use wie_util::{Result, WieError, read_generic, write_generic};
 
 use super::{java::get_java_interface_method, stdlib::get_stdlib_method, wipi_c::get_wipi_c_method};
 
 pub async fn load_native(core: &mut ArmCore, system: &mut System, jvm: &Jvm, data: &[u8]) -> Result<()> {
-    let entrypoint = load_executable(core, data)?;
+    let program = system.programs().current();
+    let fn_init = load_module(core, system, jvm, program, data).await?;
+
+    tracing::debug!("Calling initializer at {:#x}", fn_init);
+    let _: () = core.run_function(fn_init, &[]).await?;
+
+    Ok(())
+}
+
+// loads executable and runs its entrypoint, returns initializer
+pub async fn load_module(core: &mut ArmCore, system: &mut System, jvm: &Jvm, program: ProgramId, data: &[u8]) -> Result<u32> {
+    let entrypoint = load_executable(core, system, program, data)?;
 
     let ptr_init_param_1 = Allocator::alloc(core, size_of::<InitParam1>() as u32)?;
     let ptr_init_param_2 = Allocator::alloc(core, size_of::<InitParam2>() as u32)?;
@@ -28,7 +40,7 @@ pub async fn load_native(core: &mut ArmCore, system: &mut System, jvm: &Jvm, dat
 
     let init_param_2 = InitParam2 {
         fn_get_import_table: core.register_function(get_import_table, &())?,
-        fn_get_import_function: core.register_function(get_import_function, &(system.clone(), jvm.clone()))?,
+        fn_get_import_function: core.register_function(get_import_function, &(system.clone(), jvm.clone(), RandSeed::default()))?,
         fn_unk3: 0,
         fn_unk4: 0,
     };
@@ -46,10 +58,7 @@ pub async fn load_native(core: &mut ArmCore, system: &mut System, jvm: &Jvm, dat
     tracing::debug!("InitStruct: {:#x?}", init_param_1.ptr_init_struct);
     let init_struct: InitStruct = read_generic(core, init_param_1.ptr_init_struct)?;
 
-    tracing::debug!("Calling initializer at {:#x}", init_struct.fn_init);
-    let _: () = core.run_function(init_struct.fn_init, &[]).await?;
-
-    Ok(())
+    Ok(init_struct.fn_init)
 }
 
 async fn get_import_table(_core: &mut ArmCore, _: &mut (), import_table: u32) -> Result<u32> {
@@ -58,7 +67,12 @@ async fn get_import_table(_core: &mut ArmCore, _: &mut (), import_table: u32) ->
     Ok(import_table)
 }
 
-async fn get_import_function(core: &mut ArmCore, (system, jvm): &mut (System, Jvm), import_table: u32, function_index: u32) -> Result<u32> {
+async fn get_import_function(
+    core: &mut ArmCore,
+    (system, jvm, rand_seed): &mut (System, Jvm, RandSeed),
+    import_table: u32,
+    function_index: u32,
+) -> Result<u32> {
     tracing::debug!("get_import_function({:#x}, {})", import_table, function_index);
 
     if import_table == 0x1fb {
@@ -66,7 +80,7 @@ async fn get_import_function(core: &mut ArmCore, (system, jvm): &mut (System, Jv
     } else if import_table == 0x64 {
         return get_java_interface_method(core, function_index);
     } else if import_table == 1 {
-        return get_stdlib_method(core, function_index);
+        return get_stdlib_method(core, rand_seed, function_index);
     }
 
     Ok(match (import_table, function_index) {
@@ -83,7 +97,8 @@ async fn get_import_function(core: &mut ArmCore, (system, jvm): &mut (System, Jv
     })
 }
 
-fn load_executable(core: &mut ArmCore, data: &[u8]) -> Result<u32> {
+// elf executables have fixed load address, so we can't relocate the ones which collide with already loaded images
+fn load_executable(core: &mut ArmCore, system: &mut System, program: ProgramId, data: &[u8]) -> Result<u32> {
     let elf = ElfBytes::<AnyEndian>::minimal_parse(data).unwrap();
 
     assert_eq!(elf.ehdr.e_machine, elf::abi::EM_ARM, "Invalid machine type");
@@ -96,16 +111,26 @@ fn load_executable(core: &mut ArmCore, data: &[u8]) -> Result<u32> {
     let (shdrs_opt, strtab_opt) = elf.section_headers_with_strtab().unwrap();
     let (shdrs, strtab) = (shdrs_opt.unwrap(), strtab_opt.unwrap());
 
-    for shdr in shdrs {
+    let sections = shdrs.iter().filter(|x| x.sh_addr != 0).collect::<Vec<_>>();
+
+    for shdr in &sections {
+        if let Some(x) = system.programs().overlapping_image(program, shdr.sh_addr as u32, shdr.sh_size as u32) {
+            return Err(WieError::FatalError(format!(
+                "Section at {:#x}, size {:#x} of program {program} overlaps image of program {x}",
+                shdr.sh_addr, shdr.sh_size
+            )));
+        }
+    }
+
+    for shdr in sections {
         let section_name = strtab.get(shdr.sh_name as usize).unwrap();
 
-        if shdr.sh_addr != 0 {
-            tracing::debug!("Section {} at {:x}", section_name, shdr.sh_addr);
+        tracing::debug!("Section {} at {:x}", section_name, shdr.sh_addr);
 
-            let data = elf.section_data(&shdr).unwrap().0;
+        let data = elf.section_data(&shdr).unwrap().0;
 
-            core.load(data, shdr.sh_addr as u32, shdr.sh_size as usize)?;
-        }
+        core.load(data, shdr.sh_addr as u32, shdr.sh_size as usize)?;
+        system.programs().add_image_region(program, shdr.sh_addr as u32, shdr.sh_size as u32);
     }
 
     tracing::debug!("Entrypoint: {:#x}", elf.ehdr.e_entry);
@@ -9,6 +9,11 @@ use wie_wipi_java::classes::org::kwis::msp::lcdui::Display;
 
 use super::CletWrapperContext;
 
+// WIPI-C event types delivered to handleCletEvent
+// TODO verify constants, these follow key event types which starts from 501
+const MH_PAUSE: i32 = 511;
+const MH_RESUME: i32 = 512;
+
 // class net.wie.CletWrapper
 pub struct CletWrapper;
 
@@ -21,6 +26,9 @@ impl CletWrapper {
             methods: vec![
                 JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
                 JavaMethodProto::new("startApp", "([Ljava/lang/String;)V", Self::start_app, Default::default()),
+                JavaMethodProto::new("pauseApp", "()V", Self::pause_app, Default::default()),
+                JavaMethodProto::new("resumeApp", "()V", Self::resume_app, Default::default()),
+                JavaMethodProto::new("destroyApp", "(Z)V", Self::destroy_app, Default::default()),
             ],
             fields: vec![
                 JavaFieldProto::new("startClet", "I", FieldAccessFlags::STATIC),
@@ -69,4 +77,123 @@ impl CletWrapper {
 
         Ok(())
     }
+
+    async fn pause_app(jvm: &Jvm, context: &mut CletWrapperContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.CletWrapper::pauseApp({:?})", &this);
+
+        Self::handle_clet_event(jvm, context, MH_PAUSE).await?;
+        Self::run_clet_function(jvm, context, "pauseClet", &[]).await
+    }
+
+    async fn resume_app(jvm: &Jvm, context: &mut CletWrapperContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.CletWrapper::resumeApp({:?})", &this);
+
+        Self::run_clet_function(jvm, context, "resumeClet", &[]).await?;
+        Self::handle_clet_event(jvm, context, MH_RESUME).await
+    }
+
+    async fn destroy_app(jvm: &Jvm, context: &mut CletWrapperContext, this: ClassInstanceRef<Self>, unconditional: bool) -> JvmResult<()> {
+        tracing::debug!("net.wie.CletWrapper::destroyApp({:?}, {})", &this, unconditional);
+
+        Self::run_clet_function(jvm, context, "destroyClet", &[]).await
+    }
+
+    async fn handle_clet_event(jvm: &Jvm, context: &mut CletWrapperContext, r#type: i32) -> JvmResult<()> {
+        Self::run_clet_function(jvm, context, "handleCletEvent", &[r#type as _, 0, 0]).await
+    }
+
+    async fn run_clet_function(jvm: &Jvm, context: &mut CletWrapperContext, name: &str, args: &[u32]) -> JvmResult<()> {
+        let function: i32 = jvm.get_static_field("net/wie/CletWrapper", name, "I").await?;
+        if function == 0 {
+            return Ok(());
+        }
+
+        context.core.run_function(function as _, args).await.map_err(|x| match x {
+            WieError::FatalError(x) => JavaError::FatalError(x),
+            _ => JavaError::FatalError(format!("{x}")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, sync::Arc};
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+    use jvm_rust::ClassDefinitionImpl;
+
+    use test_utils::run_jvm_test;
+    use wie_core_arm::{Allocator, ArmCore};
+    use wie_midp::classes::javax::microedition::midlet::MIDlet;
+    use wie_util::Result;
+
+    use crate::runtime::java::classes::net::wie::{CletWrapper, CletWrapperContext};
+
+    use super::{MH_PAUSE, MH_RESUME};
+
+    async fn count_call(_: &mut ArmCore, count: &mut Arc<AtomicU32>) -> Result<()> {
+        count.fetch_add(1, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    async fn record_event(_: &mut ArmCore, last_event: &mut Arc<AtomicU32>, r#type: u32, _: u32, _: u32) -> Result<()> {
+        last_event.store(r#type, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    async fn register_clet_function(jvm: &Jvm, core: &mut ArmCore, name: &str) -> JvmResult<Arc<AtomicU32>> {
+        let count = Arc::new(AtomicU32::new(0));
+        let function = core.register_function(count_call, &count).unwrap();
+        jvm.put_static_field("net/wie/CletWrapper", name, "I", function as i32).await?;
+
+        Ok(count)
+    }
+
+    #[test]
+    fn test_pause_resume() -> Result<()> {
+        let protos = Box::new([wie_midp::get_protos().into(), wie_wipi_java::get_protos().into()]);
+
+        run_jvm_test(protos, |jvm| async move {
+            let mut core = ArmCore::new(false).unwrap();
+            Allocator::init(&mut core).unwrap();
+
+            let mut context = core.save_context();
+            let stack = Allocator::alloc(&mut core, 0x1000).unwrap();
+            context.sp = stack + 0x1000;
+            core.restore_context(&context);
+
+            let context = CletWrapperContext { core: core.clone() };
+            let class = ClassDefinitionImpl::from_class_proto(CletWrapper::as_proto(), Box::new(context) as Box<_>);
+            jvm.register_class(Box::new(class), None).await?;
+
+            let pause = register_clet_function(&jvm, &mut core, "pauseClet").await?;
+            let resume = register_clet_function(&jvm, &mut core, "resumeClet").await?;
+            let last_event = Arc::new(AtomicU32::new(0));
+            let handle_event = core.register_function(record_event, &last_event).unwrap();
+            jvm.put_static_field("net/wie/CletWrapper", "handleCletEvent", "I", handle_event as i32)
+                .await?;
+
+            let clet: ClassInstanceRef<CletWrapper> = jvm.instantiate_class("net/wie/CletWrapper").await?.into();
+
+            // host suspend and resume events reach the clet through the wrapping midlet
+            let mut midlet: ClassInstanceRef<MIDlet> = jvm.new_class("net/wie/WIPIMIDlet", "()V", ()).await?.into();
+            jvm.put_field(&mut midlet, "jlet", "Lorg/kwis/msp/lcdui/Jlet;", clet).await?;
+            jvm.put_field(&mut midlet, "started", "Z", true).await?;
+
+            MIDlet::suspend(&jvm, midlet.clone()).await?;
+            assert_eq!(pause.load(Ordering::Relaxed), 1);
+            assert_eq!(resume.load(Ordering::Relaxed), 0);
+            assert_eq!(last_event.load(Ordering::Relaxed), MH_PAUSE as u32);
+
+            MIDlet::resume(&jvm, midlet).await?;
+            assert_eq!(pause.load(Ordering::Relaxed), 1);
+            assert_eq!(resume.load(Ordering::Relaxed), 1);
+            assert_eq!(last_event.load(Ordering::Relaxed), MH_RESUME as u32);
+
+            Ok(())
+        })
+    }
 }
@@ -3,11 +3,17 @@ use alloc::{format, vec};
 use java_class_proto::{JavaClassProto, JavaFieldProto, JavaMethodProto};
 use jvm::{ClassInstanceRef, JavaError, Jvm, Result as JvmResult};
 
-use wie_midp::classes::javax::microedition::lcdui::Graphics;
+use wie_midp::classes::{javax::microedition::lcdui::Graphics, net::wie::PointerEventType};
 use wie_util::WieError;
 
 use super::CletWrapperContext;
 
+// WIPI-C pointer event types, x and y are passed as params
+// TODO verify constants, these follow key event types which starts from 501
+const MH_POINTER_PRESSEVENT: i32 = 504;
+const MH_POINTER_RELEASEEVENT: i32 = 505;
+const MH_POINTER_MOVEEVENT: i32 = 506;
+
 // class net.wie.CletWrapperCard
 pub struct CletWrapperCard;
 
@@ -21,6 +27,7 @@ impl CletWrapperCard {
                 JavaMethodProto::new("<init>", "(II)V", Self::init, Default::default()),
                 JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
                 JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("pointerNotify", "(III)Z", Self::pointer_notify, Default::default()),
                 JavaMethodProto::new("notifyEvent", "(III)V", Self::notify_event, Default::default()),
             ],
             fields: vec![
@@ -84,6 +91,35 @@ impl CletWrapperCard {
         Ok(true)
     }
 
+    async fn pointer_notify(
+        jvm: &Jvm,
+        context: &mut CletWrapperContext,
+        this: ClassInstanceRef<Self>,
+        r#type: i32,
+        x: i32,
+        y: i32,
+    ) -> JvmResult<bool> {
+        tracing::debug!("net.wie.CletWrapperCard::pointerNotify({:?}, {}, {}, {})", &this, r#type, x, y);
+
+        let r#type = match PointerEventType::from_raw(r#type) {
+            PointerEventType::PointerPressed => MH_POINTER_PRESSEVENT,
+            PointerEventType::PointerReleased => MH_POINTER_RELEASEEVENT,
+            PointerEventType::PointerDragged => MH_POINTER_MOVEEVENT,
+        };
+
+        let handle_clet_event: i32 = jvm.get_field(&this, "handleCletEvent", "I").await?;
+        let _: () = context
+            .core
+            .run_function(handle_clet_event as _, &[r#type as _, x as _, y as _])
+            .await
+            .map_err(|x| match x {
+                WieError::FatalError(x) => JavaError::FatalError(x),
+                _ => JavaError::FatalError(format!("{x}")),
+            })?;
+
+        Ok(true)
+    }
+
     async fn notify_event(
         jvm: &Jvm,
         context: &mut CletWrapperContext,
@@ -107,3 +143,84 @@ impl CletWrapperCard {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, sync::Arc};
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    use jvm::ClassInstanceRef;
+    use jvm_rust::ClassDefinitionImpl;
+
+    use test_utils::run_jvm_test;
+    use wie_core_arm::{Allocator, ArmCore};
+    use wie_util::Result;
+
+    use crate::runtime::java::classes::net::wie::{CletWrapperCard, CletWrapperContext};
+
+    use super::{MH_POINTER_MOVEEVENT, MH_POINTER_PRESSEVENT};
+
+    type LastEvent = Arc<[AtomicU32; 3]>;
+
+    async fn record_event(_: &mut ArmCore, last_event: &mut LastEvent, r#type: u32, param1: u32, param2: u32) -> Result<()> {
+        for (slot, value) in last_event.iter().zip([r#type, param1, param2]) {
+            slot.store(value, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    fn load(last_event: &LastEvent) -> (u32, u32, u32) {
+        (
+            last_event[0].load(Ordering::Relaxed),
+            last_event[1].load(Ordering::Relaxed),
+            last_event[2].load(Ordering::Relaxed),
+        )
+    }
+
+    #[test]
+    fn test_pointer_routing() -> Result<()> {
+        let protos = Box::new([wie_midp::get_protos().into(), wie_wipi_java::get_protos().into()]);
+
+        run_jvm_test(protos, |jvm| async move {
+            let mut core = ArmCore::new(false).unwrap();
+            Allocator::init(&mut core).unwrap();
+
+            let mut context = core.save_context();
+            let stack = Allocator::alloc(&mut core, 0x1000).unwrap();
+            context.sp = stack + 0x1000;
+            core.restore_context(&context);
+
+            let context = CletWrapperContext { core: core.clone() };
+            let class = ClassDefinitionImpl::from_class_proto(CletWrapperCard::as_proto(), Box::new(context) as Box<_>);
+            jvm.register_class(Box::new(class), None).await?;
+
+            let last_event: LastEvent = Arc::new([AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0)]);
+            let handle_event = core.register_function(record_event, &last_event).unwrap();
+
+            // Card() requires running jlet, so we bypass it
+            let mut card: ClassInstanceRef<CletWrapperCard> = jvm.instantiate_class("net/wie/CletWrapperCard").await?.into();
+            jvm.put_field(&mut card, "handleCletEvent", "I", handle_event as i32).await?;
+            jvm.put_field(&mut card, "x", "I", 10).await?;
+            jvm.put_field(&mut card, "y", "I", 20).await?;
+            jvm.put_field(&mut card, "w", "I", 100).await?;
+            jvm.put_field(&mut card, "h", "I", 100).await?;
+
+            let canvas = jvm.new_class("net/wie/CardCanvas", "()V", ()).await?;
+            let _: () = jvm.invoke_virtual(&canvas, "pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", (card,)).await?;
+
+            // pointer position is relative to the card
+            let _: () = jvm.invoke_virtual(&canvas, "pointerPressed", "(II)V", (15, 25)).await?;
+            assert_eq!(load(&last_event), (MH_POINTER_PRESSEVENT as u32, 5, 5));
+
+            let _: () = jvm.invoke_virtual(&canvas, "pointerDragged", "(II)V", (40, 60)).await?;
+            assert_eq!(load(&last_event), (MH_POINTER_MOVEEVENT as u32, 30, 40));
+
+            // events outside of the card are not delivered
+            let _: () = jvm.invoke_virtual(&canvas, "pointerReleased", "(II)V", (5, 5)).await?;
+            assert_eq!(load(&last_event), (MH_POINTER_MOVEEVENT as u32, 30, 40));
+
+            Ok(())
+        })
+    }
+}
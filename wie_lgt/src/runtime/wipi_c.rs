@@ -12,7 +12,7 @@ use wie_jvm_support::JvmSupport;
 use wie_util::{Result, WieError, read_generic, write_null_terminated_string_bytes};
 use wie_wipi_c::{
     MethodImpl, WIPICContext,
-    api::{database, graphics, kernel, media, misc, net},
+    api::{database, graphics, kernel, media, misc, net, uic},
 };
 
 use context::LgtWIPICContext;
@@ -45,28 +45,87 @@ pub fn get_wipi_c_method(core: &mut ArmCore, system: &mut System, jvm: &Jvm, fun
         0x81 => kernel::get_resource.into_body(),
         0x97 => unk2.into_body(),
         0xc8 => graphics::get_image_property.into_body(),
+        0xc9 => graphics::get_image_framebuffer.into_body(),
         0xca => graphics::get_screen_framebuffer.into_body(),
         0xcb => graphics::destroy_offscreen_framebuffer.into_body(),
         0xcc => graphics::create_offscreen_framebuffer.into_body(),
         0xcd => graphics::init_context.into_body(),
         0xce => graphics::set_context.into_body(),
+        0xcf => graphics::get_context.into_body(),
         0xd0 => graphics::put_pixel.into_body(),
+        0xd1 => graphics::draw_line.into_body(),
+        0xd2 => graphics::draw_rect.into_body(),
         0xd3 => graphics::fill_rect.into_body(),
+        0xd4 => graphics::copy_frame_buffer.into_body(),
         0xd5 => graphics::draw_image.into_body(),
+        0xd6 => graphics::copy_area.into_body(),
+        0xd7 => graphics::draw_arc.into_body(),
+        0xd8 => graphics::fill_arc.into_body(),
+        // lgt has one more graphics function than ktf before MC_grpDrawString, 0xd9 is unknown
         0xda => graphics::draw_string.into_body(),
+        0xdb => graphics::draw_unicode_string.into_body(),
+        0xdc => graphics::get_rgb_pixels.into_body(),
+        0xdd => graphics::set_rgb_pixels.into_body(),
         0xde => graphics::flush.into_body(),
         0xdf => graphics::get_pixel_from_rgb.into_body(),
         0xe0 => graphics::get_rgb_from_pixel.into_body(),
         0xe1 => graphics::get_display_info.into_body(),
+        0xe2 => graphics::repaint.into_body(),
         0xe3 => graphics::get_font.into_body(),
         0xe4 => graphics::get_font_height.into_body(),
+        0xe5 => graphics::get_font_ascent.into_body(),
+        0xe6 => graphics::get_font_descent.into_body(),
+        0xe7 => graphics::get_string_width.into_body(),
+        0xe8 => graphics::get_unicode_string_width.into_body(),
         0xe9 => graphics::create_image.into_body(),
-        0xeb => unk0.into_body(),
+        0xea => graphics::destroy_image.into_body(),
+        0xeb => graphics::decode_next_image.into_body(),
+        0xed => graphics::post_event.into_body(),
         0xee => unk11.into_body(),
-        0x12c => unk3.into_body(),
-        0x12d => unk4.into_body(),
-        0x12e => unk7.into_body(),
-        0x12f => unk6.into_body(),
+        0xf3 => graphics::fill_polygon.into_body(),
+        0xf4 => graphics::draw_polygon.into_body(),
+        // palette extensions(MC_grpGetImagePalette and others) are not mapped, as lgt imports only by index and they have no index
+        0x12c => uic::create_application_context.into_body(),
+        0x12d => uic::get_class.into_body(),
+        0x12e => uic::create.into_body(),
+        0x12f => uic::destroy.into_body(),
+        0x130 => uic::repaint.into_body(),
+        0x131 => uic::paint.into_body(),
+        0x132 => uic::get_class_name.into_body(),
+        0x133 => uic::is_instance.into_body(),
+        0x134 => uic::handle_event.into_body(),
+        0x135 => uic::configure.into_body(),
+        0x136 => uic::get_geometry.into_body(),
+        0x137 => uic::set_enable.into_body(),
+        0x138 => uic::set_callback.into_body(),
+        0x139 => uic::set_event_handler.into_body(),
+        0x13a => uic::set_font.into_body(),
+        0x13b => uic::get_font.into_body(),
+        0x13c => uic::set_fg_color.into_body(),
+        0x13d => uic::set_bg_color.into_body(),
+        0x13e => uic::set_label.into_body(),
+        0x13f => uic::get_label.into_body(),
+        0x140 => uic::set_label_alignment.into_body(),
+        0x141 => uic::set_time_mask.into_body(),
+        0x142 => uic::set_time.into_body(),
+        0x143 => uic::set_time_long.into_body(),
+        0x144 => uic::get_time.into_body(),
+        0x145 => uic::add_menu_item.into_body(),
+        0x146 => uic::get_menu_item.into_body(),
+        0x147 => uic::remove_menu_item.into_body(),
+        0x148 => uic::set_active_menu_item.into_body(),
+        0x149 => uic::get_active_menu_item.into_body(),
+        0x14a => uic::insert_text.into_body(),
+        0x14b => uic::delete_text.into_body(),
+        0x14c => uic::get_max_text_size.into_body(),
+        0x14d => uic::set_max_text_size.into_body(),
+        0x14e => uic::get_text_size.into_body(),
+        0x14f => uic::get_text.into_body(),
+        0x150 => uic::add_list_item.into_body(),
+        0x151 => uic::get_list_item.into_body(),
+        0x152 => uic::remove_list_item.into_body(),
+        0x153 => uic::set_active_list_item.into_body(),
+        0x154 => uic::get_active_list_item.into_body(),
         0x190 => database::open_database.into_body(),
         0x191 => database::read_record_single.into_body(),
         0x192 => database::write_record_single.into_body(),
@@ -147,14 +206,6 @@ async fn clet_register(core: &mut ArmCore, jvm: &mut Jvm, function_table: u32, a
     Ok(())
 }
 
-async fn unk0(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
-    tracing::warn!("stub unk0({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
-
-    // graphics
-
-    Ok(0)
-}
-
 async fn unk1(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
     tracing::warn!("stub unk1({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
 
@@ -174,18 +225,6 @@ async fn unk2(context: &mut dyn WIPICContext) -> Result<u32> {
     Ok(result)
 }
 
-async fn unk3(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
-    tracing::warn!("stub unk3({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
-
-    Ok(0)
-}
-
-async fn unk4(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
-    tracing::warn!("stub unk4({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
-
-    Ok(0)
-}
-
 async fn unk5(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
     tracing::warn!("stub unk5({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
 
@@ -194,18 +233,6 @@ async fn unk5(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u3
     Ok(0)
 }
 
-async fn unk6(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
-    tracing::warn!("stub unk6({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
-
-    Ok(0)
-}
-
-async fn unk7(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
-    tracing::warn!("stub unk7({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
-
-    Ok(0)
-}
-
 async fn unk8(_context: &mut dyn WIPICContext, a0: u32, a1: u32, a2: u32, a3: u32) -> Result<u32> {
     tracing::warn!("stub unk8({:#x}, {:#x}, {:#x}, {:#x})", a0, a1, a2, a3);
 
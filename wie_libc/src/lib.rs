@@ -0,0 +1,78 @@
+#![no_std]
+extern crate alloc;
+
+mod malloc;
+pub mod printf;
+mod stdio;
+mod stdlib;
+mod string;
+
+use wie_core_arm::ArmCore;
+use wie_util::Result;
+
+pub use self::{
+    malloc::{calloc, free, malloc, realloc},
+    stdio::{snprintf, sprintf, vsnprintf, vsprintf},
+    stdlib::{RandSeed, abs, atoi, atol, bsearch, itoa, labs, qsort, rand, srand, strtol, strtoul},
+    string::{memchr, memcmp, memcpy, memmove, memset, strcat, strchr, strcmp, strcpy, strdup, strlen, strncat, strncmp, strncpy, strrchr, strstr},
+};
+
+/// Registers c runtime function by its c name, returns None if function is not implemented
+pub fn get_function(core: &mut ArmCore, rand_seed: &RandSeed, name: &str) -> Result<Option<u32>> {
+    Ok(Some(match name {
+        "malloc" => core.register_function(malloc, &())?,
+        "free" => core.register_function(free, &())?,
+        "calloc" => core.register_function(calloc, &())?,
+        "realloc" => core.register_function(realloc, &())?,
+        "sprintf" => core.register_function(sprintf, &())?,
+        "snprintf" => core.register_function(snprintf, &())?,
+        "vsprintf" => core.register_function(vsprintf, &())?,
+        "vsnprintf" => core.register_function(vsnprintf, &())?,
+        "abs" => core.register_function(abs, &())?,
+        "labs" => core.register_function(labs, &())?,
+        "atoi" => core.register_function(atoi, &())?,
+        "atol" => core.register_function(atol, &())?,
+        "strtol" => core.register_function(strtol, &())?,
+        "strtoul" => core.register_function(strtoul, &())?,
+        "itoa" => core.register_function(itoa, &())?,
+        "rand" => core.register_function(rand, rand_seed)?,
+        "srand" => core.register_function(srand, rand_seed)?,
+        "qsort" => core.register_function(qsort, &())?,
+        "bsearch" => core.register_function(bsearch, &())?,
+        "memcpy" => core.register_function(memcpy, &())?,
+        "memmove" => core.register_function(memmove, &())?,
+        "memset" => core.register_function(memset, &())?,
+        "memcmp" => core.register_function(memcmp, &())?,
+        "memchr" => core.register_function(memchr, &())?,
+        "strcpy" => core.register_function(strcpy, &())?,
+        "strncpy" => core.register_function(strncpy, &())?,
+        "strcat" => core.register_function(strcat, &())?,
+        "strncat" => core.register_function(strncat, &())?,
+        "strcmp" => core.register_function(strcmp, &())?,
+        "strncmp" => core.register_function(strncmp, &())?,
+        "strchr" => core.register_function(strchr, &())?,
+        "strrchr" => core.register_function(strrchr, &())?,
+        "strstr" => core.register_function(strstr, &())?,
+        "strlen" => core.register_function(strlen, &())?,
+        "strdup" => core.register_function(strdup, &())?,
+        _ => return Ok(None),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use wie_core_arm::{Allocator, ArmCore};
+    use wie_util::Result;
+
+    pub fn init_core() -> Result<ArmCore> {
+        let mut core = ArmCore::new(false)?;
+        Allocator::init(&mut core)?;
+
+        let mut context = core.save_context();
+        let stack = Allocator::alloc(&mut core, 0x1000)?;
+        context.sp = stack + 0x1000;
+        core.restore_context(&context);
+
+        Ok(core)
+    }
+}
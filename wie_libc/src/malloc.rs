@@ -0,0 +1,115 @@
+use alloc::vec;
+use core::cmp::min;
+
+use wie_core_arm::{Allocator, ArmCore};
+use wie_util::{ByteRead, ByteWrite, Result, WieError, read_generic, write_generic};
+
+// allocator requires size on free, so we keep it in front of returned pointer. 8 bytes to keep alignment of double.
+const HEADER_SIZE: u32 = 8;
+
+fn allocation_base(ptr: u32) -> Result<u32> {
+    ptr.checked_sub(HEADER_SIZE).ok_or(WieError::InvalidMemoryAccess(ptr))
+}
+
+fn allocation_size(core: &ArmCore, ptr: u32) -> Result<u32> {
+    read_generic(core, allocation_base(ptr)?)
+}
+
+pub async fn malloc(core: &mut ArmCore, _: &mut (), size: u32) -> Result<u32> {
+    tracing::debug!("malloc({:#x})", size);
+
+    // c malloc returns null on failure instead of aborting
+    let base = match size.checked_add(HEADER_SIZE).map(|x| Allocator::alloc(core, x)) {
+        Some(Ok(base)) => base,
+        None | Some(Err(WieError::AllocationFailure)) => return Ok(0),
+        Some(Err(err)) => return Err(err),
+    };
+    write_generic(core, base, size)?;
+
+    Ok(base + HEADER_SIZE)
+}
+
+pub async fn free(core: &mut ArmCore, _: &mut (), ptr: u32) -> Result<()> {
+    tracing::debug!("free({:#x})", ptr);
+
+    if ptr == 0 {
+        return Ok(());
+    }
+
+    let size = allocation_size(core, ptr)?;
+    Allocator::free(core, allocation_base(ptr)?, size.saturating_add(HEADER_SIZE))
+}
+
+pub async fn calloc(core: &mut ArmCore, _: &mut (), count: u32, size: u32) -> Result<u32> {
+    tracing::debug!("calloc({:#x}, {:#x})", count, size);
+
+    let Some(total) = count.checked_mul(size) else {
+        return Ok(0);
+    };
+
+    let ptr = malloc(core, &mut (), total).await?;
+    if ptr == 0 {
+        return Ok(0);
+    }
+    core.write_bytes(ptr, &vec![0; total as usize])?;
+
+    Ok(ptr)
+}
+
+pub async fn realloc(core: &mut ArmCore, _: &mut (), ptr: u32, size: u32) -> Result<u32> {
+    tracing::debug!("realloc({:#x}, {:#x})", ptr, size);
+
+    if ptr == 0 {
+        return malloc(core, &mut (), size).await;
+    }
+    if size == 0 {
+        free(core, &mut (), ptr).await?;
+        return Ok(0);
+    }
+
+    let old_size = allocation_size(core, ptr)?;
+    let new_ptr = malloc(core, &mut (), size).await?;
+    if new_ptr == 0 {
+        return Ok(0);
+    }
+
+    let mut data = vec![0; min(old_size, size) as usize];
+    core.read_bytes(ptr, &mut data)?;
+    core.write_bytes(new_ptr, &data)?;
+
+    free(core, &mut (), ptr).await?;
+
+    Ok(new_ptr)
+}
+
+#[cfg(test)]
+mod test {
+    use wie_util::{Result, read_generic, write_generic};
+
+    use crate::test::init_core;
+
+    use super::{calloc, free, malloc, realloc};
+
+    #[futures_test::test]
+    async fn test_realloc() -> Result<()> {
+        let mut core = init_core()?;
+
+        let ptr = malloc(&mut core, &mut (), 8).await?;
+        write_generic(&mut core, ptr, 0x12345678u32)?;
+
+        let new_ptr = realloc(&mut core, &mut (), ptr, 0x100).await?;
+        assert_eq!(read_generic::<u32, _>(&core, new_ptr)?, 0x12345678);
+
+        free(&mut core, &mut (), new_ptr).await?;
+
+        let ptr = calloc(&mut core, &mut (), 4, 4).await?;
+        assert_eq!(read_generic::<u32, _>(&core, ptr + 12)?, 0);
+
+        assert_eq!(malloc(&mut core, &mut (), u32::MAX - 4).await?, 0);
+        assert_eq!(calloc(&mut core, &mut (), 0x10000, 0x10001).await?, 0);
+        assert_eq!(malloc(&mut core, &mut (), 0x80000000).await?, 0);
+        assert!(free(&mut core, &mut (), 4).await.is_err());
+
+        Ok(())
+    }
+}
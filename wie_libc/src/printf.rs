@@ -0,0 +1,511 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use wie_core_arm::ArmCore;
+use wie_util::{ByteRead, Result, read_null_terminated_string_bytes};
+
+// width and precision are clamped, so that garbage argument doesn't make us allocate huge buffer
+const MAX_WIDTH: usize = 1024;
+
+/// Variadic arguments of native call, read one argument slot at a time
+pub trait VarArgs {
+    fn next_word(&mut self) -> Result<u32>;
+
+    /// argument slot of next word, counted from first register argument
+    fn slot(&self) -> usize;
+
+    // 64bit arguments(long long, double) are aligned to even slots on arm
+    fn next_dword(&mut self) -> Result<u64> {
+        if self.slot() % 2 == 1 {
+            self.next_word()?;
+        }
+
+        let low = self.next_word()?;
+        let high = self.next_word()?;
+
+        Ok(((high as u64) << 32) | low as u64)
+    }
+}
+
+/// Variadic arguments already read into words, words[0] is at `first_slot`
+pub struct WordArgs<'a> {
+    words: &'a [u32],
+    first_slot: usize,
+    index: usize,
+}
+
+impl<'a> WordArgs<'a> {
+    pub fn new(words: &'a [u32], first_slot: usize) -> Self {
+        Self { words, first_slot, index: 0 }
+    }
+}
+
+impl VarArgs for WordArgs<'_> {
+    fn next_word(&mut self) -> Result<u32> {
+        let word = self.words.get(self.index).copied().unwrap_or_else(|| {
+            tracing::warn!("Not enough arguments for format string");
+            0
+        });
+        self.index += 1;
+
+        Ok(word)
+    }
+
+    fn slot(&self) -> usize {
+        self.first_slot + self.index
+    }
+}
+
+/// Variadic arguments read from registers and stack of current native call
+pub struct CoreArgs<'a> {
+    core: &'a ArmCore,
+    slot: usize,
+}
+
+impl<'a> CoreArgs<'a> {
+    pub fn new(core: &'a ArmCore, first_slot: usize) -> Self {
+        Self { core, slot: first_slot }
+    }
+}
+
+impl VarArgs for CoreArgs<'_> {
+    fn next_word(&mut self) -> Result<u32> {
+        let word = self.core.read_param(self.slot)?;
+        self.slot += 1;
+
+        Ok(word)
+    }
+
+    fn slot(&self) -> usize {
+        self.slot
+    }
+}
+
+#[derive(Default)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    space: bool,
+    alt: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+#[derive(Clone, Copy)]
+enum Length {
+    Default,
+    Char,
+    Short,
+    LongLong,
+}
+
+/// Formats c printf style format string. Strings are handled as raw bytes, so multibyte(euc-kr) strings are kept as is.
+pub fn sprintf<R, A>(reader: &R, format: &[u8], args: &mut A) -> Result<Vec<u8>>
+where
+    R: ?Sized + ByteRead,
+    A: ?Sized + VarArgs,
+{
+    let mut result = Vec::with_capacity(format.len());
+    let mut i = 0;
+
+    while i < format.len() {
+        let start = i;
+        i += 1;
+
+        if format[start] != b'%' {
+            result.push(format[start]);
+            continue;
+        }
+
+        let mut spec = Spec::default();
+        while let Some(&c) = format.get(i) {
+            match c {
+                b'-' => spec.left = true,
+                b'+' => spec.plus = true,
+                b' ' => spec.space = true,
+                b'#' => spec.alt = true,
+                b'0' => spec.zero = true,
+                _ => break,
+            }
+            i += 1;
+        }
+
+        if format.get(i) == Some(&b'*') {
+            i += 1;
+
+            // negative width means left alignment
+            let width = args.next_word()? as i32;
+            spec.left |= width < 0;
+            spec.width = (width.unsigned_abs() as usize).min(MAX_WIDTH);
+        } else {
+            spec.width = parse_number(format, &mut i);
+        }
+
+        if format.get(i) == Some(&b'.') {
+            i += 1;
+
+            if format.get(i) == Some(&b'*') {
+                i += 1;
+
+                // negative precision is taken as if precision were omitted
+                let precision = args.next_word()? as i32;
+                spec.precision = (precision >= 0).then_some((precision as usize).min(MAX_WIDTH));
+            } else {
+                spec.precision = Some(parse_number(format, &mut i));
+            }
+        }
+
+        let length = parse_length(format, &mut i);
+
+        let Some(&conversion) = format.get(i) else {
+            // broken format, print as is
+            result.extend_from_slice(&format[start..]);
+            break;
+        };
+        i += 1;
+
+        match conversion {
+            b'%' => result.push(b'%'),
+            b'd' | b'i' => {
+                let value = match length {
+                    Length::Default => args.next_word()? as i32 as i64,
+                    Length::Char => args.next_word()? as i8 as i64,
+                    Length::Short => args.next_word()? as i16 as i64,
+                    Length::LongLong => args.next_dword()? as i64,
+                };
+
+                let sign = if value < 0 {
+                    "-"
+                } else if spec.plus {
+                    "+"
+                } else if spec.space {
+                    " "
+                } else {
+                    ""
+                };
+                let digits = format_integer(value.unsigned_abs(), b'd', spec.precision);
+
+                pad(&mut result, &spec, sign, digits.as_bytes(), spec.precision.is_none());
+            }
+            b'u' | b'x' | b'X' | b'o' => {
+                let value = match length {
+                    Length::Default => args.next_word()? as u64,
+                    Length::Char => args.next_word()? as u8 as u64,
+                    Length::Short => args.next_word()? as u16 as u64,
+                    Length::LongLong => args.next_dword()?,
+                };
+
+                let mut digits = format_integer(value, conversion, spec.precision);
+                let prefix = match conversion {
+                    b'x' if spec.alt && value != 0 => "0x",
+                    b'X' if spec.alt && value != 0 => "0X",
+                    b'o' if spec.alt && !digits.starts_with('0') => {
+                        digits.insert(0, '0');
+                        ""
+                    }
+                    _ => "",
+                };
+
+                pad(&mut result, &spec, prefix, digits.as_bytes(), spec.precision.is_none());
+            }
+            b'p' => {
+                let value = args.next_word()?;
+
+                pad(&mut result, &spec, "0x", format!("{value:x}").as_bytes(), false);
+            }
+            b'c' => {
+                let value = args.next_word()? as u8;
+
+                pad(&mut result, &spec, "", &[value], false);
+            }
+            b's' => {
+                let ptr = args.next_word()?;
+                let mut bytes = if ptr == 0 {
+                    b"(null)".to_vec()
+                } else {
+                    read_null_terminated_string_bytes(reader, ptr)?
+                };
+                if let Some(precision) = spec.precision {
+                    bytes.truncate(precision);
+                }
+
+                pad(&mut result, &spec, "", &bytes, false);
+            }
+            b'f' | b'F' | b'e' | b'E' | b'g' | b'G' => {
+                // soft float abi passes double in two core registers
+                let value = f64::from_bits(args.next_dword()?);
+
+                let sign = if value.is_sign_negative() && !value.is_nan() {
+                    "-"
+                } else if spec.plus {
+                    "+"
+                } else if spec.space {
+                    " "
+                } else {
+                    ""
+                };
+                let body = format_float(value, conversion, &spec);
+
+                pad(&mut result, &spec, sign, body.as_bytes(), value.is_finite());
+            }
+            _ => {
+                tracing::warn!("Unknown format conversion: {}", conversion as char);
+
+                result.extend_from_slice(&format[start..i]);
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_number(format: &[u8], i: &mut usize) -> usize {
+    let mut result = 0;
+    while let Some(c) = format.get(*i).filter(|x| x.is_ascii_digit()) {
+        result = (result * 10 + (c - b'0') as usize).min(MAX_WIDTH);
+        *i += 1;
+    }
+
+    result
+}
+
+fn parse_length(format: &[u8], i: &mut usize) -> Length {
+    let (length, size) = match (format.get(*i), format.get(*i + 1)) {
+        (Some(b'h'), Some(b'h')) => (Length::Char, 2),
+        (Some(b'l'), Some(b'l')) => (Length::LongLong, 2),
+        (Some(b'h'), _) => (Length::Short, 1),
+        (Some(b'L' | b'q' | b'j'), _) => (Length::LongLong, 1),
+        (Some(b'l' | b'z' | b't'), _) => (Length::Default, 1), // long and size_t are 32bit
+        _ => (Length::Default, 0),
+    };
+    *i += size;
+
+    length
+}
+
+fn format_integer(value: u64, conversion: u8, precision: Option<usize>) -> String {
+    let mut digits = match conversion {
+        b'x' => format!("{value:x}"),
+        b'X' => format!("{value:X}"),
+        b'o' => format!("{value:o}"),
+        _ => value.to_string(),
+    };
+
+    match precision {
+        Some(0) if value == 0 => digits.clear(),
+        Some(precision) if digits.len() < precision => digits.insert_str(0, &"0".repeat(precision - digits.len())),
+        _ => {}
+    }
+
+    digits
+}
+
+// formats absolute value of `value`, sign is handled by caller
+fn format_float(value: f64, conversion: u8, spec: &Spec) -> String {
+    let upper = conversion.is_ascii_uppercase();
+    let value = if value.is_sign_negative() { -value } else { value };
+
+    if !value.is_finite() {
+        let body = if value.is_nan() { "nan" } else { "inf" };
+
+        return if upper { body.to_ascii_uppercase() } else { body.into() };
+    }
+
+    let precision = spec.precision.unwrap_or(6);
+    let (mut body, exponent) = match conversion {
+        b'f' | b'F' => (format!("{value:.precision$}"), None),
+        b'e' | b'E' => {
+            let (mantissa, exponent) = split_exponent(value, precision);
+
+            (mantissa, Some(exponent))
+        }
+        _ => {
+            // %g uses %e style if exponent is less than -4 or greater than or equal to precision
+            let precision = precision.max(1);
+            let (mantissa, exponent) = split_exponent(value, precision - 1);
+
+            let mut body = if exponent < -4 || exponent >= precision as i32 {
+                (mantissa, Some(exponent))
+            } else {
+                (format!("{value:.0$}", (precision as i32 - 1 - exponent) as usize), None)
+            };
+
+            if !spec.alt && body.0.contains('.') {
+                let trimmed = body.0.trim_end_matches('0').trim_end_matches('.').len();
+                body.0.truncate(trimmed);
+            }
+
+            body
+        }
+    };
+
+    if spec.alt && !body.contains('.') {
+        body.push('.');
+    }
+
+    if let Some(exponent) = exponent {
+        body += &format!(
+            "{}{}{:02}",
+            if upper { 'E' } else { 'e' },
+            if exponent < 0 { '-' } else { '+' },
+            exponent.unsigned_abs()
+        );
+    }
+
+    body
+}
+
+fn split_exponent(value: f64, precision: usize) -> (String, i32) {
+    let formatted = format!("{value:.precision$e}");
+    let (mantissa, exponent) = formatted.split_once('e').unwrap();
+
+    (mantissa.into(), exponent.parse().unwrap())
+}
+
+// zero padding goes between prefix(sign, 0x) and body
+fn pad(result: &mut Vec<u8>, spec: &Spec, prefix: &str, body: &[u8], zero_allowed: bool) {
+    let padding = spec.width.saturating_sub(prefix.len() + body.len());
+
+    if spec.left {
+        result.extend_from_slice(prefix.as_bytes());
+        result.extend_from_slice(body);
+        result.resize(result.len() + padding, b' ');
+    } else if spec.zero && zero_allowed {
+        result.extend_from_slice(prefix.as_bytes());
+        result.resize(result.len() + padding, b'0');
+        result.extend_from_slice(body);
+    } else {
+        result.resize(result.len() + padding, b' ');
+        result.extend_from_slice(prefix.as_bytes());
+        result.extend_from_slice(body);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec::Vec};
+
+    use wie_core_arm::{Allocator, ArmCore};
+    use wie_util::{Result, write_null_terminated_string_bytes};
+
+    use crate::test::init_core;
+
+    use super::{WordArgs, sprintf};
+
+    fn format(core: &ArmCore, format: &str, args: &[u32]) -> Result<String> {
+        let result = sprintf(core, format.as_bytes(), &mut WordArgs::new(args, 0))?;
+
+        Ok(String::from_utf8(result).unwrap())
+    }
+
+    fn double(value: f64) -> [u32; 2] {
+        let bits = value.to_bits();
+
+        [bits as u32, (bits >> 32) as u32]
+    }
+
+    #[test]
+    fn test_integer() -> Result<()> {
+        let core = init_core()?;
+
+        assert_eq!(format(&core, "%d %i %u", &[-1i32 as u32, 42, -1i32 as u32])?, "-1 42 4294967295");
+        assert_eq!(
+            format(&core, "[%5d] [%-5d] [%05d] [%+d] [% d]", &[42, 42, -42, 42, 42])?,
+            "[   42] [42   ] [-0042] [+42] [ 42]"
+        );
+        assert_eq!(format(&core, "%x %X %#x %o %#o", &[255, 255, 255, 8, 8])?, "ff FF 0xff 10 010");
+        assert_eq!(format(&core, "%.3d %.0d|%8.3x", &[7, 0, 0xa])?, "007 |     00a");
+        assert_eq!(format(&core, "%hd %hhu %ld", &[0x12345, 0x1ff, 7])?, "9029 255 7");
+        assert_eq!(format(&core, "%*d %-*d|%.*d", &[4, 1, 3, 2, 2, 3])?, "   1 2  |03");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_width_clamp() -> Result<()> {
+        let core = init_core()?;
+
+        assert_eq!(format(&core, "%*d", &[0x7fffffff, 1])?.len(), 1024);
+        assert_eq!(format(&core, "%-*d", &[-0x80000000i32 as u32, 1])?.len(), 1024);
+        assert_eq!(format(&core, "%.*d", &[0x7fffffff, 1])?.len(), 1024);
+        assert_eq!(format(&core, "%99999999999999999999d", &[1])?.len(), 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_long() -> Result<()> {
+        let core = init_core()?;
+
+        // 64bit argument skips odd slot
+        let value = -5_000_000_000i64 as u64;
+        assert_eq!(
+            format(&core, "%d %lld", &[1, 0xdead, value as u32, (value >> 32) as u32])?,
+            "1 -5000000000"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut core = init_core()?;
+
+        let string = Allocator::alloc(&mut core, 10)?;
+        write_null_terminated_string_bytes(&mut core, string, b"hello")?;
+
+        assert_eq!(format(&core, "%s %c%c", &[string, b'o' as u32, b'k' as u32])?, "hello ok");
+        assert_eq!(
+            format(&core, "[%8s] [%-8s] [%.2s]", &[string, string, string])?,
+            "[   hello] [hello   ] [he]"
+        );
+        assert_eq!(format(&core, "%s %p", &[0, 0x1234])?, "(null) 0x1234");
+        assert_eq!(format(&core, "100%% %y", &[])?, "100% %y");
+        assert_eq!(format(&core, "broken %", &[])?, "broken %");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_float() -> Result<()> {
+        let core = init_core()?;
+
+        // double after single word argument skips odd slot
+        let args = [0, 0xdead, double(3.14159)[0], double(3.14159)[1]];
+        assert_eq!(format(&core, "%d %f", &args)?, "0 3.141590");
+        assert_eq!(format(&core, "%.2f", &double(-2.005))?, "-2.00");
+        assert_eq!(
+            format(&core, "%8.3f|%-8.1f|%08.2f", &[double(1.5), double(1.5), double(-1.5)].concat())?,
+            "   1.500|1.5     |-0001.50"
+        );
+        assert_eq!(
+            format(&core, "%e %E", &[double(1234.5), double(0.00012)].concat())?,
+            "1.234500e+03 1.200000E-04"
+        );
+        assert_eq!(
+            format(
+                &core,
+                "%g %g %g %g",
+                &[double(100000.0), double(1e6), double(0.0001), double(1.5e-5)].concat()
+            )?,
+            "100000 1e+06 0.0001 1.5e-05"
+        );
+        assert_eq!(
+            format(&core, "%G %#g %g", &[double(1e-10), double(1.0), double(0.0)].concat())?,
+            "1E-10 1.00000 0"
+        );
+        assert_eq!(
+            format(&core, "%f %5.1f", &[double(f64::INFINITY), double(f64::NAN)].concat())?,
+            "inf   nan"
+        );
+
+        let args: Vec<u32> = [double(1.0), double(2.0)].concat();
+        assert_eq!(format(&core, "%.0f %#.0f", &args)?, "1 2.");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use core::cmp::min;
+
+use wie_core_arm::ArmCore;
+use wie_util::{Result, read_generic, read_null_terminated_string_bytes, write_null_terminated_string_bytes};
+
+use crate::printf::{self, CoreArgs, VarArgs};
+
+// reads variadic arguments from va_list, which is pointer to argument area on arm
+struct VaListArgs<'a> {
+    core: &'a ArmCore,
+    address: u32,
+}
+
+impl VarArgs for VaListArgs<'_> {
+    fn next_word(&mut self) -> Result<u32> {
+        let word = read_generic(self.core, self.address)?;
+        self.address += 4;
+
+        Ok(word)
+    }
+
+    // 64bit arguments are aligned to 8 bytes in memory, so address is used as slot
+    fn slot(&self) -> usize {
+        (self.address / 4) as usize
+    }
+}
+
+fn write_truncated(core: &mut ArmCore, dst: u32, size: u32, result: &[u8]) -> Result<()> {
+    if size > 0 {
+        let length = min(result.len(), size as usize - 1);
+        write_null_terminated_string_bytes(core, dst, &result[..length])?;
+    }
+
+    Ok(())
+}
+
+pub async fn sprintf(core: &mut ArmCore, _: &mut (), dst: u32, ptr_format: u32) -> Result<u32> {
+    tracing::debug!("sprintf({:#x}, {:#x}, ...)", dst, ptr_format);
+
+    let format_string = read_null_terminated_string_bytes(core, ptr_format)?;
+    let result = printf::sprintf(core, &format_string, &mut CoreArgs::new(core, 2))?;
+
+    write_null_terminated_string_bytes(core, dst, &result)?;
+
+    Ok(result.len() as _)
+}
+
+// returns length of full result, even if it was truncated
+pub async fn snprintf(core: &mut ArmCore, _: &mut (), dst: u32, size: u32, ptr_format: u32) -> Result<u32> {
+    tracing::debug!("snprintf({:#x}, {}, {:#x}, ...)", dst, size, ptr_format);
+
+    let format_string = read_null_terminated_string_bytes(core, ptr_format)?;
+    let result = printf::sprintf(core, &format_string, &mut CoreArgs::new(core, 3))?;
+
+    write_truncated(core, dst, size, &result)?;
+
+    Ok(result.len() as _)
+}
+
+pub async fn vsprintf(core: &mut ArmCore, _: &mut (), dst: u32, ptr_format: u32, va_list: u32) -> Result<u32> {
+    tracing::debug!("vsprintf({:#x}, {:#x}, {:#x})", dst, ptr_format, va_list);
+
+    let format_string = read_null_terminated_string_bytes(core, ptr_format)?;
+    let result = printf::sprintf(core, &format_string, &mut VaListArgs { core, address: va_list })?;
+
+    write_null_terminated_string_bytes(core, dst, &result)?;
+
+    Ok(result.len() as _)
+}
+
+pub async fn vsnprintf(core: &mut ArmCore, _: &mut (), dst: u32, size: u32, ptr_format: u32, va_list: u32) -> Result<u32> {
+    tracing::debug!("vsnprintf({:#x}, {}, {:#x}, {:#x})", dst, size, ptr_format, va_list);
+
+    let format_string = read_null_terminated_string_bytes(core, ptr_format)?;
+    let result = printf::sprintf(core, &format_string, &mut VaListArgs { core, address: va_list })?;
+
+    write_truncated(core, dst, size, &result)?;
+
+    Ok(result.len() as _)
+}
+
+#[cfg(test)]
+mod test {
+    use wie_core_arm::Allocator;
+    use wie_util::{Result, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
+
+    use crate::test::init_core;
+
+    use super::vsnprintf;
+
+    #[futures_test::test]
+    async fn test_vsnprintf() -> Result<()> {
+        let mut core = init_core()?;
+
+        let dst = Allocator::alloc(&mut core, 0x20)?;
+        let format = Allocator::alloc(&mut core, 0x20)?;
+        let va_list = Allocator::alloc(&mut core, 0x10)?;
+
+        write_null_terminated_string_bytes(&mut core, format, b"%d-%x")?;
+        write_generic(&mut core, va_list, -12i32)?;
+        write_generic(&mut core, va_list + 4, 0xabcu32)?;
+
+        assert_eq!(vsnprintf(&mut core, &mut (), dst, 6, format, va_list).await?, 7);
+        assert_eq!(read_null_terminated_string_bytes(&core, dst)?, b"-12-a");
+
+        Ok(())
+    }
+}
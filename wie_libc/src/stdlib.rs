@@ -0,0 +1,365 @@
+use alloc::{sync::Arc, vec, vec::Vec};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use wie_core_arm::ArmCore;
+use wie_util::{ByteRead, ByteWrite, Result, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
+
+/// State of rand(), one per guest runtime so that srand() of one core doesn't affect others
+#[derive(Clone)]
+pub struct RandSeed(Arc<AtomicU32>);
+
+impl Default for RandSeed {
+    fn default() -> Self {
+        Self(Arc::new(AtomicU32::new(1)))
+    }
+}
+
+struct ParsedInteger {
+    negative: bool,
+    value: u64, // saturated on overflow
+    length: usize,
+}
+
+// parses integer like c strtol family, length is 0 if there's no digit
+fn parse_integer(string: &[u8], base: u32) -> ParsedInteger {
+    let invalid = ParsedInteger {
+        negative: false,
+        value: 0,
+        length: 0,
+    };
+    if base == 1 || base > 36 {
+        return invalid;
+    }
+
+    let mut index = string.iter().take_while(|x| b" \t\n\x0b\x0c\r".contains(x)).count();
+    let negative = match string.get(index) {
+        Some(b'-') => {
+            index += 1;
+            true
+        }
+        Some(b'+') => {
+            index += 1;
+            false
+        }
+        _ => false,
+    };
+
+    let has_hex_prefix = string.get(index) == Some(&b'0')
+        && matches!(string.get(index + 1), Some(b'x' | b'X'))
+        && string.get(index + 2).is_some_and(|x| x.is_ascii_hexdigit());
+
+    let base = if (base == 0 || base == 16) && has_hex_prefix {
+        index += 2;
+        16
+    } else if base == 0 {
+        if string.get(index) == Some(&b'0') { 8 } else { 10 }
+    } else {
+        base
+    };
+
+    let start = index;
+    let mut value = 0u64;
+    while let Some(digit) = string.get(index).and_then(|&x| (x as char).to_digit(base)) {
+        value = value.saturating_mul(base as u64).saturating_add(digit as u64);
+        index += 1;
+    }
+
+    if index == start {
+        return invalid;
+    }
+
+    ParsedInteger {
+        negative,
+        value,
+        length: index,
+    }
+}
+
+fn to_long(parsed: &ParsedInteger) -> i32 {
+    if parsed.negative {
+        (parsed.value.min(i32::MAX as u64 + 1) as i64).wrapping_neg() as i32
+    } else {
+        parsed.value.min(i32::MAX as u64) as i32
+    }
+}
+
+fn write_end_pointer(core: &mut ArmCore, ptr_end: u32, ptr_str: u32, parsed: &ParsedInteger) -> Result<()> {
+    if ptr_end != 0 {
+        write_generic(core, ptr_end, ptr_str + parsed.length as u32)?;
+    }
+
+    Ok(())
+}
+
+pub async fn atoi(core: &mut ArmCore, _: &mut (), ptr_str: u32) -> Result<u32> {
+    tracing::debug!("atoi({:#x})", ptr_str);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+
+    Ok(to_long(&parse_integer(&string, 10)) as u32)
+}
+
+pub async fn atol(core: &mut ArmCore, _: &mut (), ptr_str: u32) -> Result<u32> {
+    tracing::debug!("atol({:#x})", ptr_str);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+
+    Ok(to_long(&parse_integer(&string, 10)) as u32)
+}
+
+pub async fn strtol(core: &mut ArmCore, _: &mut (), ptr_str: u32, ptr_end: u32, base: u32) -> Result<u32> {
+    tracing::debug!("strtol({:#x}, {:#x}, {})", ptr_str, ptr_end, base);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+    let parsed = parse_integer(&string, base);
+    write_end_pointer(core, ptr_end, ptr_str, &parsed)?;
+
+    Ok(to_long(&parsed) as u32)
+}
+
+pub async fn strtoul(core: &mut ArmCore, _: &mut (), ptr_str: u32, ptr_end: u32, base: u32) -> Result<u32> {
+    tracing::debug!("strtoul({:#x}, {:#x}, {})", ptr_str, ptr_end, base);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+    let parsed = parse_integer(&string, base);
+    write_end_pointer(core, ptr_end, ptr_str, &parsed)?;
+
+    let value = parsed.value.min(u32::MAX as u64) as u32;
+
+    Ok(if parsed.negative { value.wrapping_neg() } else { value })
+}
+
+// value is treated as signed only on radix 10, like most of the c runtimes
+pub async fn itoa(core: &mut ArmCore, _: &mut (), value: u32, dst: u32, radix: u32) -> Result<u32> {
+    tracing::debug!("itoa({:#x}, {:#x}, {})", value, dst, radix);
+
+    if !(2..=36).contains(&radix) {
+        write_null_terminated_string_bytes(core, dst, b"")?;
+        return Ok(dst);
+    }
+
+    let negative = radix == 10 && (value as i32) < 0;
+    let mut magnitude = if negative { (value as i32).unsigned_abs() } else { value };
+
+    let mut result = Vec::new();
+    loop {
+        result.push(char::from_digit(magnitude % radix, radix).unwrap() as u8);
+        magnitude /= radix;
+
+        if magnitude == 0 {
+            break;
+        }
+    }
+    if negative {
+        result.push(b'-');
+    }
+    result.reverse();
+
+    write_null_terminated_string_bytes(core, dst, &result)?;
+
+    Ok(dst)
+}
+
+pub async fn abs(_: &mut ArmCore, _: &mut (), value: u32) -> Result<u32> {
+    tracing::debug!("abs({})", value as i32);
+
+    Ok((value as i32).wrapping_abs() as u32)
+}
+
+pub async fn labs(_: &mut ArmCore, _: &mut (), value: u32) -> Result<u32> {
+    tracing::debug!("labs({})", value as i32);
+
+    Ok((value as i32).wrapping_abs() as u32)
+}
+
+// same lcg as c standard's sample implementation, RAND_MAX is 0x7fff
+pub async fn rand(_: &mut ArmCore, seed: &mut RandSeed) -> Result<u32> {
+    tracing::debug!("rand()");
+
+    let next = |x: u32| x.wrapping_mul(1103515245).wrapping_add(12345);
+    let seed = seed.0.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| Some(next(x))).unwrap();
+
+    Ok((next(seed) >> 16) & 0x7fff)
+}
+
+pub async fn srand(_: &mut ArmCore, state: &mut RandSeed, seed: u32) -> Result<()> {
+    tracing::debug!("srand({:#x})", seed);
+
+    state.0.store(seed, Ordering::Relaxed);
+
+    Ok(())
+}
+
+async fn call_compare(core: &mut ArmCore, fn_compare: u32, lhs: u32, rhs: u32) -> Result<i32> {
+    let result: u32 = core.run_function(fn_compare, &[lhs, rhs]).await?;
+
+    Ok(result as i32)
+}
+
+fn swap(core: &mut ArmCore, address1: u32, address2: u32, size: u32) -> Result<()> {
+    let mut data1 = vec![0; size as usize];
+    let mut data2 = vec![0; size as usize];
+    core.read_bytes(address1, &mut data1)?;
+    core.read_bytes(address2, &mut data2)?;
+
+    core.write_bytes(address1, &data2)?;
+    core.write_bytes(address2, &data1)?;
+
+    Ok(())
+}
+
+async fn sift_down(core: &mut ArmCore, base: u32, size: u32, fn_compare: u32, mut root: u32, end: u32) -> Result<()> {
+    let element = |index: u32| base + index * size;
+
+    loop {
+        let mut child = root * 2 + 1;
+        if child >= end {
+            break;
+        }
+
+        if child + 1 < end && call_compare(core, fn_compare, element(child), element(child + 1)).await? < 0 {
+            child += 1;
+        }
+        if call_compare(core, fn_compare, element(root), element(child)).await? >= 0 {
+            break;
+        }
+
+        swap(core, element(root), element(child), size)?;
+        root = child;
+    }
+
+    Ok(())
+}
+
+// comparator is guest function taking pointers, so we sort elements in place using heap sort
+pub async fn qsort(core: &mut ArmCore, _: &mut (), base: u32, count: u32, size: u32, fn_compare: u32) -> Result<()> {
+    tracing::debug!("qsort({:#x}, {}, {}, {:#x})", base, count, size, fn_compare);
+
+    if count < 2 {
+        return Ok(());
+    }
+
+    for start in (0..count / 2).rev() {
+        sift_down(core, base, size, fn_compare, start, count).await?;
+    }
+    for end in (1..count).rev() {
+        swap(core, base, base + end * size, size)?;
+        sift_down(core, base, size, fn_compare, 0, end).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn bsearch(core: &mut ArmCore, _: &mut (), key: u32, base: u32, count: u32, size: u32, fn_compare: u32) -> Result<u32> {
+    tracing::debug!("bsearch({:#x}, {:#x}, {}, {}, {:#x})", key, base, count, size, fn_compare);
+
+    let (mut low, mut high) = (0, count);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let element = base + mid * size;
+
+        match call_compare(core, fn_compare, key, element).await? {
+            0 => return Ok(element),
+            x if x < 0 => high = mid,
+            _ => low = mid + 1,
+        }
+    }
+
+    Ok(0)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use wie_core_arm::{Allocator, ArmCore};
+    use wie_util::{Result, read_generic, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
+
+    use crate::test::init_core;
+
+    use super::{RandSeed, bsearch, itoa, qsort, rand, srand, strtol};
+
+    async fn compare_word(core: &mut ArmCore, _: &mut (), lhs: u32, rhs: u32) -> Result<u32> {
+        let lhs: u32 = read_generic(core, lhs)?;
+        let rhs: u32 = read_generic(core, rhs)?;
+
+        Ok((lhs as i32 - rhs as i32) as u32)
+    }
+
+    #[futures_test::test]
+    async fn test_strtol() -> Result<()> {
+        let mut core = init_core()?;
+
+        let ptr_str = Allocator::alloc(&mut core, 0x20)?;
+        let ptr_end = Allocator::alloc(&mut core, 4)?;
+
+        write_null_terminated_string_bytes(&mut core, ptr_str, b"  -0x1fz")?;
+        assert_eq!(strtol(&mut core, &mut (), ptr_str, ptr_end, 0).await? as i32, -31);
+        assert_eq!(read_generic::<u32, _>(&core, ptr_end)?, ptr_str + 7);
+
+        write_null_terminated_string_bytes(&mut core, ptr_str, b"99999999999")?;
+        assert_eq!(strtol(&mut core, &mut (), ptr_str, 0, 10).await? as i32, i32::MAX);
+
+        write_null_terminated_string_bytes(&mut core, ptr_str, b"abc")?;
+        assert_eq!(strtol(&mut core, &mut (), ptr_str, ptr_end, 10).await?, 0);
+        assert_eq!(read_generic::<u32, _>(&core, ptr_end)?, ptr_str);
+
+        itoa(&mut core, &mut (), -123i32 as u32, ptr_str, 10).await?;
+        assert_eq!(read_null_terminated_string_bytes(&core, ptr_str)?, b"-123");
+
+        itoa(&mut core, &mut (), 0xff, ptr_str, 16).await?;
+        assert_eq!(read_null_terminated_string_bytes(&core, ptr_str)?, b"ff");
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_qsort() -> Result<()> {
+        let mut core = init_core()?;
+        let fn_compare = core.register_function(compare_word, &())?;
+
+        let values = [5u32, 3, 9, 1, 7, 3, 0, 8];
+        let base = Allocator::alloc(&mut core, (values.len() * 4) as u32)?;
+        for (i, value) in values.iter().enumerate() {
+            write_generic(&mut core, base + (i * 4) as u32, *value)?;
+        }
+
+        qsort(&mut core, &mut (), base, values.len() as u32, 4, fn_compare).await?;
+
+        let sorted = (0..values.len())
+            .map(|i| read_generic(&core, base + (i * 4) as u32))
+            .collect::<Result<Vec<u32>>>()?;
+        assert_eq!(sorted, [0, 1, 3, 3, 5, 7, 8, 9]);
+
+        let key = Allocator::alloc(&mut core, 4)?;
+        write_generic(&mut core, key, 7u32)?;
+        assert_eq!(bsearch(&mut core, &mut (), key, base, 8, 4, fn_compare).await?, base + 5 * 4);
+
+        write_generic(&mut core, key, 4u32)?;
+        assert_eq!(bsearch(&mut core, &mut (), key, base, 8, 4, fn_compare).await?, 0);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_rand_seed() -> Result<()> {
+        let mut core = init_core()?;
+
+        let mut reference = RandSeed::default();
+        let expected = [rand(&mut core, &mut reference).await?, rand(&mut core, &mut reference).await?];
+
+        // srand on one seed doesn't affect the other
+        let (mut seed1, mut seed2) = (RandSeed::default(), RandSeed::default());
+        assert_eq!(rand(&mut core, &mut seed1).await?, expected[0]);
+        srand(&mut core, &mut seed1, 42).await?;
+        let seeded = rand(&mut core, &mut seed1).await?;
+
+        assert_eq!(rand(&mut core, &mut seed2).await?, expected[0]);
+        assert_eq!(rand(&mut core, &mut seed2).await?, expected[1]);
+
+        srand(&mut core, &mut seed2, 42).await?;
+        assert_eq!(rand(&mut core, &mut seed2).await?, seeded);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::min;
+
+use wie_core_arm::ArmCore;
+use wie_util::{ByteRead, ByteWrite, Result, read_null_terminated_string_bytes, write_null_terminated_string_bytes};
+
+use crate::malloc::malloc;
+
+// c compares bytes as unsigned char, which is same as lexicographical order of byte slices
+fn compare(lhs: &[u8], rhs: &[u8]) -> u32 {
+    lhs.cmp(rhs) as i32 as u32
+}
+
+fn read_bytes(core: &ArmCore, address: u32, size: u32) -> Result<Vec<u8>> {
+    let mut data = vec![0; size as usize];
+    core.read_bytes(address, &mut data)?;
+
+    Ok(data)
+}
+
+pub async fn memcpy(core: &mut ArmCore, _: &mut (), dst: u32, src: u32, size: u32) -> Result<u32> {
+    tracing::debug!("memcpy({:#x}, {:#x}, {:#x})", dst, src, size);
+
+    let data = read_bytes(core, src, size)?;
+    core.write_bytes(dst, &data)?;
+
+    Ok(dst)
+}
+
+// we read whole source before writing, so overlapping region is handled correctly
+pub async fn memmove(core: &mut ArmCore, _: &mut (), dst: u32, src: u32, size: u32) -> Result<u32> {
+    tracing::debug!("memmove({:#x}, {:#x}, {:#x})", dst, src, size);
+
+    let data = read_bytes(core, src, size)?;
+    core.write_bytes(dst, &data)?;
+
+    Ok(dst)
+}
+
+pub async fn memset(core: &mut ArmCore, _: &mut (), dst: u32, value: u32, size: u32) -> Result<u32> {
+    tracing::debug!("memset({:#x}, {:#x}, {:#x})", dst, value, size);
+
+    core.write_bytes(dst, &vec![value as u8; size as usize])?;
+
+    Ok(dst)
+}
+
+pub async fn memcmp(core: &mut ArmCore, _: &mut (), ptr1: u32, ptr2: u32, size: u32) -> Result<u32> {
+    tracing::debug!("memcmp({:#x}, {:#x}, {:#x})", ptr1, ptr2, size);
+
+    let data1 = read_bytes(core, ptr1, size)?;
+    let data2 = read_bytes(core, ptr2, size)?;
+
+    Ok(compare(&data1, &data2))
+}
+
+pub async fn memchr(core: &mut ArmCore, _: &mut (), ptr: u32, value: u32, size: u32) -> Result<u32> {
+    tracing::debug!("memchr({:#x}, {:#x}, {:#x})", ptr, value, size);
+
+    let data = read_bytes(core, ptr, size)?;
+
+    Ok(data.iter().position(|&x| x == value as u8).map(|x| ptr + x as u32).unwrap_or(0))
+}
+
+pub async fn strcpy(core: &mut ArmCore, _: &mut (), dst: u32, ptr_src: u32) -> Result<u32> {
+    tracing::debug!("strcpy({:#x}, {:#x})", dst, ptr_src);
+
+    let src = read_null_terminated_string_bytes(core, ptr_src)?;
+    write_null_terminated_string_bytes(core, dst, &src)?;
+
+    Ok(dst)
+}
+
+// remaining space is padded with zero, and result is not null terminated if source is longer than size
+pub async fn strncpy(core: &mut ArmCore, _: &mut (), dst: u32, ptr_src: u32, size: u32) -> Result<u32> {
+    tracing::debug!("strncpy({:#x}, {:#x}, {:#x})", dst, ptr_src, size);
+
+    let mut src = read_null_terminated_string_bytes(core, ptr_src)?;
+    src.resize(size as usize, 0);
+
+    core.write_bytes(dst, &src)?;
+
+    Ok(dst)
+}
+
+pub async fn strcat(core: &mut ArmCore, _: &mut (), dst: u32, ptr_src: u32) -> Result<u32> {
+    tracing::debug!("strcat({:#x}, {:#x})", dst, ptr_src);
+
+    let length = read_null_terminated_string_bytes(core, dst)?.len() as u32;
+    let src = read_null_terminated_string_bytes(core, ptr_src)?;
+    write_null_terminated_string_bytes(core, dst + length, &src)?;
+
+    Ok(dst)
+}
+
+pub async fn strncat(core: &mut ArmCore, _: &mut (), dst: u32, ptr_src: u32, size: u32) -> Result<u32> {
+    tracing::debug!("strncat({:#x}, {:#x}, {:#x})", dst, ptr_src, size);
+
+    let length = read_null_terminated_string_bytes(core, dst)?.len() as u32;
+    let src = read_null_terminated_string_bytes(core, ptr_src)?;
+    write_null_terminated_string_bytes(core, dst + length, &src[..min(src.len(), size as usize)])?;
+
+    Ok(dst)
+}
+
+pub async fn strcmp(core: &mut ArmCore, _: &mut (), ptr_str1: u32, ptr_str2: u32) -> Result<u32> {
+    tracing::debug!("strcmp({:#x}, {:#x})", ptr_str1, ptr_str2);
+
+    let str1 = read_null_terminated_string_bytes(core, ptr_str1)?;
+    let str2 = read_null_terminated_string_bytes(core, ptr_str2)?;
+
+    Ok(compare(&str1, &str2))
+}
+
+pub async fn strncmp(core: &mut ArmCore, _: &mut (), ptr_str1: u32, ptr_str2: u32, size: u32) -> Result<u32> {
+    tracing::debug!("strncmp({:#x}, {:#x}, {:#x})", ptr_str1, ptr_str2, size);
+
+    let str1 = read_null_terminated_string_bytes(core, ptr_str1)?;
+    let str2 = read_null_terminated_string_bytes(core, ptr_str2)?;
+
+    Ok(compare(&str1[..min(str1.len(), size as usize)], &str2[..min(str2.len(), size as usize)]))
+}
+
+// terminating null is considered part of string, so strchr(s, 0) returns pointer to terminator
+pub async fn strchr(core: &mut ArmCore, _: &mut (), ptr_str: u32, value: u32) -> Result<u32> {
+    tracing::debug!("strchr({:#x}, {:#x})", ptr_str, value);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+    let value = value as u8;
+
+    if value == 0 {
+        return Ok(ptr_str + string.len() as u32);
+    }
+
+    Ok(string.iter().position(|&x| x == value).map(|x| ptr_str + x as u32).unwrap_or(0))
+}
+
+pub async fn strrchr(core: &mut ArmCore, _: &mut (), ptr_str: u32, value: u32) -> Result<u32> {
+    tracing::debug!("strrchr({:#x}, {:#x})", ptr_str, value);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+    let value = value as u8;
+
+    if value == 0 {
+        return Ok(ptr_str + string.len() as u32);
+    }
+
+    Ok(string.iter().rposition(|&x| x == value).map(|x| ptr_str + x as u32).unwrap_or(0))
+}
+
+pub async fn strstr(core: &mut ArmCore, _: &mut (), ptr_str: u32, ptr_substr: u32) -> Result<u32> {
+    tracing::debug!("strstr({:#x}, {:#x})", ptr_str, ptr_substr);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+    let substr = read_null_terminated_string_bytes(core, ptr_substr)?;
+
+    if substr.is_empty() {
+        return Ok(ptr_str);
+    }
+
+    Ok(string
+        .windows(substr.len())
+        .position(|x| x == substr.as_slice())
+        .map(|x| ptr_str + x as u32)
+        .unwrap_or(0))
+}
+
+pub async fn strlen(core: &mut ArmCore, _: &mut (), ptr_str: u32) -> Result<u32> {
+    tracing::debug!("strlen({:#x})", ptr_str);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+
+    Ok(string.len() as u32)
+}
+
+pub async fn strdup(core: &mut ArmCore, _: &mut (), ptr_str: u32) -> Result<u32> {
+    tracing::debug!("strdup({:#x})", ptr_str);
+
+    let string = read_null_terminated_string_bytes(core, ptr_str)?;
+
+    let ptr = malloc(core, &mut (), string.len() as u32 + 1).await?;
+    write_null_terminated_string_bytes(core, ptr, &string)?;
+
+    Ok(ptr)
+}
+
+#[cfg(test)]
+mod test {
+    use wie_core_arm::Allocator;
+    use wie_util::{Result, read_null_terminated_string_bytes, write_null_terminated_string_bytes};
+
+    use crate::test::init_core;
+
+    use super::{memmove, strcat, strchr, strcmp, strncmp, strncpy, strrchr, strstr};
+
+    #[futures_test::test]
+    async fn test_string() -> Result<()> {
+        let mut core = init_core()?;
+
+        let str1 = Allocator::alloc(&mut core, 0x20)?;
+        let str2 = Allocator::alloc(&mut core, 0x20)?;
+        write_null_terminated_string_bytes(&mut core, str1, b"hello")?;
+        write_null_terminated_string_bytes(&mut core, str2, b", world")?;
+
+        strcat(&mut core, &mut (), str1, str2).await?;
+        assert_eq!(read_null_terminated_string_bytes(&core, str1)?, b"hello, world");
+
+        assert_eq!(strchr(&mut core, &mut (), str1, b'o' as _).await?, str1 + 4);
+        assert_eq!(strrchr(&mut core, &mut (), str1, b'o' as _).await?, str1 + 8);
+        assert_eq!(strchr(&mut core, &mut (), str1, b'z' as _).await?, 0);
+        assert_eq!(strchr(&mut core, &mut (), str1, 0).await?, str1 + 12);
+        assert_eq!(strstr(&mut core, &mut (), str1, str2 + 2).await?, str1 + 7);
+
+        assert_eq!(strcmp(&mut core, &mut (), str1, str2).await? as i32, 1);
+        assert_eq!(strcmp(&mut core, &mut (), str2, str1).await? as i32, -1);
+        assert_eq!(strncmp(&mut core, &mut (), str1 + 5, str2, 3).await?, 0);
+
+        strncpy(&mut core, &mut (), str2, str1, 3).await?;
+        assert_eq!(read_null_terminated_string_bytes(&core, str2)?, b"helworld");
+
+        memmove(&mut core, &mut (), str1 + 1, str1, 4).await?;
+        assert_eq!(read_null_terminated_string_bytes(&core, str1)?, b"hhell, world");
+
+        Ok(())
+    }
+}
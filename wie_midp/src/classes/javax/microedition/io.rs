@@ -0,0 +1,19 @@
+pub mod file;
+
+mod connection;
+mod connection_not_found_exception;
+pub mod connector;
+mod content_connection;
+mod datagram;
+mod datagram_connection;
+pub mod http_connection;
+mod input_connection;
+mod output_connection;
+pub mod socket_connection;
+mod stream_connection;
+
+pub use self::{
+    connection::Connection, connection_not_found_exception::ConnectionNotFoundException, connector::Connector, content_connection::ContentConnection,
+    datagram::Datagram, datagram_connection::DatagramConnection, http_connection::HttpConnection, input_connection::InputConnection,
+    output_connection::OutputConnection, socket_connection::SocketConnection, stream_connection::StreamConnection,
+};
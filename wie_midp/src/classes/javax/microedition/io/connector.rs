@@ -0,0 +1,531 @@
+use alloc::{format, vec};
+use core::net::Ipv4Addr;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::MethodAccessFlags;
+use java_runtime::classes::java::{
+    io::{DataInputStream, InputStream, OutputStream},
+    lang::String,
+};
+use jvm::{ClassInstanceRef, JavaError, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_backend::{NetworkError, SocketError};
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::io::Connection;
+
+pub const READ: i32 = 1;
+pub const WRITE: i32 = 2;
+pub const READ_WRITE: i32 = 3;
+
+// backend sockets are non-blocking, so blocking calls poll them with this interval
+pub const POLL_INTERVAL: u64 = 16;
+
+// class javax.microedition.io.Connector
+pub struct Connector;
+
+impl Connector {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/Connector",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "open",
+                    "(Ljava/lang/String;)Ljavax/microedition/io/Connection;",
+                    Self::open,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "open",
+                    "(Ljava/lang/String;I)Ljavax/microedition/io/Connection;",
+                    Self::open_with_mode,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "open",
+                    "(Ljava/lang/String;IZ)Ljavax/microedition/io/Connection;",
+                    Self::open_with_mode_timeouts,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openInputStream",
+                    "(Ljava/lang/String;)Ljava/io/InputStream;",
+                    Self::open_input_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openDataInputStream",
+                    "(Ljava/lang/String;)Ljava/io/DataInputStream;",
+                    Self::open_data_input_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openOutputStream",
+                    "(Ljava/lang/String;)Ljava/io/OutputStream;",
+                    Self::open_output_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openDataOutputStream",
+                    "(Ljava/lang/String;)Ljava/io/DataOutputStream;",
+                    Self::open_data_output_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn open(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<Connection>> {
+        tracing::debug!("javax.microedition.io.Connector::open({:?})", &name);
+
+        jvm.invoke_static(
+            "javax/microedition/io/Connector",
+            "open",
+            "(Ljava/lang/String;IZ)Ljavax/microedition/io/Connection;",
+            (name, READ_WRITE, false),
+        )
+        .await
+    }
+
+    async fn open_with_mode(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>, mode: i32) -> JvmResult<ClassInstanceRef<Connection>> {
+        tracing::debug!("javax.microedition.io.Connector::open({:?}, {})", &name, mode);
+
+        jvm.invoke_static(
+            "javax/microedition/io/Connector",
+            "open",
+            "(Ljava/lang/String;IZ)Ljavax/microedition/io/Connection;",
+            (name, mode, false),
+        )
+        .await
+    }
+
+    // access mode is not enforced, and we don't have timeouts on connecting
+    async fn open_with_mode_timeouts(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        name: ClassInstanceRef<String>,
+        mode: i32,
+        timeouts: bool,
+    ) -> JvmResult<ClassInstanceRef<Connection>> {
+        tracing::debug!("javax.microedition.io.Connector::open({:?}, {}, {})", &name, mode, timeouts);
+
+        if name.is_null() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Null url").await);
+        }
+        if !(READ..=READ_WRITE).contains(&mode) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid mode").await);
+        }
+
+        let url = JavaLangString::to_rust_string(jvm, &name).await?;
+        let Some((scheme, _)) = url.split_once(':') else {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid url").await);
+        };
+
+        let class_name = match scheme.to_ascii_lowercase().as_str() {
+            "http" => "net/wie/HttpConnectionImpl",
+            "socket" => "net/wie/SocketConnectionImpl",
+            "datagram" => "net/wie/DatagramConnectionImpl",
+            "file" => "net/wie/FileConnectionImpl",
+            _ => {
+                return Err(jvm
+                    .exception(
+                        "javax/microedition/io/ConnectionNotFoundException",
+                        &format!("Unsupported scheme {scheme}"),
+                    )
+                    .await);
+            }
+        };
+
+        let connection = jvm.new_class(class_name, "(Ljava/lang/String;)V", (name,)).await?;
+
+        Ok(connection.into())
+    }
+
+    async fn open_input_stream(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<InputStream>> {
+        tracing::debug!("javax.microedition.io.Connector::openInputStream({:?})", &name);
+
+        let connection = Self::open_connection(jvm, name, READ).await?;
+        let input_stream = jvm.invoke_virtual(&connection, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+
+        // connection is kept open until the stream is closed
+        let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+        Ok(input_stream)
+    }
+
+    async fn open_data_input_stream(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        name: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<DataInputStream>> {
+        tracing::debug!("javax.microedition.io.Connector::openDataInputStream({:?})", &name);
+
+        let input_stream: ClassInstanceRef<InputStream> = jvm
+            .invoke_static(
+                "javax/microedition/io/Connector",
+                "openInputStream",
+                "(Ljava/lang/String;)Ljava/io/InputStream;",
+                (name,),
+            )
+            .await?;
+        let data_input_stream = jvm
+            .new_class("java/io/DataInputStream", "(Ljava/io/InputStream;)V", (input_stream,))
+            .await?;
+
+        Ok(data_input_stream.into())
+    }
+
+    async fn open_output_stream(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("javax.microedition.io.Connector::openOutputStream({:?})", &name);
+
+        let connection = Self::open_connection(jvm, name, WRITE).await?;
+        let output_stream = jvm
+            .invoke_virtual(&connection, "openOutputStream", "()Ljava/io/OutputStream;", ())
+            .await?;
+
+        let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+        Ok(output_stream)
+    }
+
+    async fn open_data_output_stream(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("javax.microedition.io.Connector::openDataOutputStream({:?})", &name);
+
+        let output_stream: ClassInstanceRef<OutputStream> = jvm
+            .invoke_static(
+                "javax/microedition/io/Connector",
+                "openOutputStream",
+                "(Ljava/lang/String;)Ljava/io/OutputStream;",
+                (name,),
+            )
+            .await?;
+        let data_output_stream = jvm
+            .new_class("java/io/DataOutputStream", "(Ljava/io/OutputStream;)V", (output_stream,))
+            .await?;
+
+        Ok(data_output_stream.into())
+    }
+
+    async fn open_connection(jvm: &Jvm, name: ClassInstanceRef<String>, mode: i32) -> JvmResult<ClassInstanceRef<Connection>> {
+        jvm.invoke_static(
+            "javax/microedition/io/Connector",
+            "open",
+            "(Ljava/lang/String;I)Ljavax/microedition/io/Connection;",
+            (name, mode),
+        )
+        .await
+    }
+
+    // splits `//host:port` part of socket and datagram urls
+    pub fn parse_host_port(url: &str) -> Option<(&str, u16)> {
+        let (_, rest) = url.split_once("://")?;
+        let (host, port) = rest.rsplit_once(':')?;
+
+        Some((host, port.parse().ok()?))
+    }
+
+    pub async fn resolve(jvm: &Jvm, context: &mut WieJvmContext, host: &str) -> JvmResult<Ipv4Addr> {
+        let result = context.system().resolve(host).await;
+
+        match result.as_deref() {
+            Ok([addr, ..]) => Ok(*addr),
+            Ok([]) => Err(Self::io_exception(jvm, NetworkError::HostNotFound.into()).await),
+            Err(err) => Err(Self::io_exception(jvm, (*err).into()).await),
+        }
+    }
+
+    pub async fn io_exception(jvm: &Jvm, err: SocketError) -> JavaError {
+        match err {
+            SocketError::Network(NetworkError::HostNotFound | NetworkError::ConnectionRefused) => {
+                jvm.exception("javax/microedition/io/ConnectionNotFoundException", &format!("{err:?}"))
+                    .await
+            }
+            SocketError::InvalidHandle => jvm.exception("java/io/IOException", "Connection closed").await,
+            _ => jvm.exception("java/io/IOException", &format!("{err:?}")).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, string::String as RustString, vec, vec::Vec};
+    use core::net::{Ipv4Addr, SocketAddrV4};
+
+    use bytemuck::cast_vec;
+    use java_runtime::classes::java::io::{InputStream, OutputStream};
+    use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::{TestPlatform, run_jvm_test, run_jvm_test_with_platform, serve};
+    use wie_backend::{Network, TcpSocket};
+    use wie_util::Result;
+
+    use crate::{
+        classes::javax::microedition::io::{Connection, Datagram},
+        get_protos,
+    };
+
+    const SERVER_ADDR: Ipv4Addr = Ipv4Addr::new(10, 0, 0, 2);
+
+    async fn open(jvm: &Jvm, url: &str) -> JvmResult<ClassInstanceRef<Connection>> {
+        let url = JavaLangString::from_rust_string(jvm, url).await?;
+
+        jvm.invoke_static(
+            "javax/microedition/io/Connector",
+            "open",
+            "(Ljava/lang/String;)Ljavax/microedition/io/Connection;",
+            (url,),
+        )
+        .await
+    }
+
+    async fn read_all(jvm: &Jvm, connection: &ClassInstanceRef<Connection>) -> JvmResult<Vec<u8>> {
+        let input_stream: ClassInstanceRef<InputStream> = jvm.invoke_virtual(connection, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+
+        let mut result = Vec::new();
+        loop {
+            let read: i32 = jvm.invoke_virtual(&input_stream, "read", "()I", ()).await?;
+            if read == -1 {
+                break;
+            }
+            result.push(read as u8);
+        }
+        let _: () = jvm.invoke_virtual(&input_stream, "close", "()V", ()).await?;
+
+        Ok(result)
+    }
+
+    async fn byte_array(jvm: &Jvm, data: &[u8]) -> JvmResult<ClassInstanceRef<Array<i8>>> {
+        let mut array = jvm.instantiate_array("B", data.len()).await?;
+        jvm.store_array(&mut array, 0, cast_vec::<u8, i8>(data.to_vec())).await?;
+
+        Ok(array.into())
+    }
+
+    // reads from server side socket until `predicate` matches received data
+    async fn read_until(socket: &dyn TcpSocket, predicate: impl Fn(&[u8]) -> bool) -> Vec<u8> {
+        let mut result = Vec::new();
+        while !predicate(&result) {
+            let mut buf = [0; 256];
+            let read = socket.read(&mut buf).await.unwrap();
+            assert_ne!(read, 0, "connection closed");
+            result.extend_from_slice(&buf[..read]);
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_file_connection() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            let url = JavaLangString::from_rust_string(&jvm, "file:///data/test.txt").await?;
+            let connection: ClassInstanceRef<Connection> = jvm
+                .invoke_static(
+                    "javax/microedition/io/Connector",
+                    "open",
+                    "(Ljava/lang/String;)Ljavax/microedition/io/Connection;",
+                    (url,),
+                )
+                .await?;
+
+            let exists: bool = jvm.invoke_virtual(&connection, "exists", "()Z", ()).await?;
+            assert!(!exists);
+
+            let _: () = jvm.invoke_virtual(&connection, "create", "()V", ()).await?;
+            let exists: bool = jvm.invoke_virtual(&connection, "exists", "()Z", ()).await?;
+            assert!(exists);
+
+            let mut data = jvm.instantiate_array("B", 3).await?;
+            jvm.store_array(&mut data, 0, vec![1i8, 2, 3]).await?;
+
+            let output_stream: ClassInstanceRef<OutputStream> = jvm
+                .invoke_virtual(&connection, "openOutputStream", "()Ljava/io/OutputStream;", ())
+                .await?;
+            let _: () = jvm.invoke_virtual(&output_stream, "write", "([B)V", (data,)).await?;
+            let _: () = jvm.invoke_virtual(&output_stream, "close", "()V", ()).await?;
+
+            let size: i64 = jvm.invoke_virtual(&connection, "fileSize", "()J", ()).await?;
+            assert_eq!(size, 3);
+
+            let input_stream: ClassInstanceRef<InputStream> =
+                jvm.invoke_virtual(&connection, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+            for expected in [1, 2, 3, -1] {
+                let read: i32 = jvm.invoke_virtual(&input_stream, "read", "()I", ()).await?;
+                assert_eq!(read, expected);
+            }
+            let _: () = jvm.invoke_virtual(&input_stream, "close", "()V", ()).await?;
+
+            let new_name = JavaLangString::from_rust_string(&jvm, "renamed.txt").await?;
+            let _: () = jvm.invoke_virtual(&connection, "rename", "(Ljava/lang/String;)V", (new_name,)).await?;
+            let name = jvm.invoke_virtual(&connection, "getName", "()Ljava/lang/String;", ()).await?;
+            assert_eq!(JavaLangString::to_rust_string(&jvm, &name).await?, "renamed.txt");
+
+            let _: () = jvm.invoke_virtual(&connection, "delete", "()V", ()).await?;
+            let exists: bool = jvm.invoke_virtual(&connection, "exists", "()Z", ()).await?;
+            assert!(!exists);
+
+            let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_unsupported_scheme() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            let url = JavaLangString::from_rust_string(&jvm, "https://example.com/").await?;
+            let result: JvmResult<ClassInstanceRef<Connection>> = jvm
+                .invoke_static(
+                    "javax/microedition/io/Connector",
+                    "open",
+                    "(Ljava/lang/String;)Ljavax/microedition/io/Connection;",
+                    (url,),
+                )
+                .await;
+            assert!(result.is_err());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_http_connection() -> Result<()> {
+        let platform = TestPlatform::new();
+        let network = platform.test_network();
+        network.add_host("example.com", SERVER_ADDR);
+        let listener = network.listen(SocketAddrV4::new(SERVER_ADDR, 80));
+
+        run_jvm_test_with_platform(platform, Box::new([get_protos().into()]), |jvm| async move {
+            let server = async {
+                let socket = listener.accept_async().await;
+
+                let request = read_until(&socket, |x| x.ends_with(b"\r\n\r\n")).await;
+                let request = RustString::from_utf8(request).unwrap();
+                assert!(request.starts_with("GET /notice?id=1 HTTP/1.1\r\n"));
+                assert!(request.contains("User-Agent: wie\r\n"));
+
+                socket
+                    .write(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 11\r\n\r\nhello world")
+                    .await
+                    .unwrap();
+                socket.shutdown();
+            };
+
+            let client = async {
+                let connection = open(&jvm, "http://example.com/notice?id=1").await?;
+
+                let key = JavaLangString::from_rust_string(&jvm, "User-Agent").await?;
+                let value = JavaLangString::from_rust_string(&jvm, "wie").await?;
+                let _: () = jvm
+                    .invoke_virtual(&connection, "setRequestProperty", "(Ljava/lang/String;Ljava/lang/String;)V", (key, value))
+                    .await?;
+
+                let response_code: i32 = jvm.invoke_virtual(&connection, "getResponseCode", "()I", ()).await?;
+                assert_eq!(response_code, 200);
+
+                let r#type = jvm.invoke_virtual(&connection, "getType", "()Ljava/lang/String;", ()).await?;
+                assert_eq!(JavaLangString::to_rust_string(&jvm, &r#type).await?, "text/plain");
+
+                assert_eq!(read_all(&jvm, &connection).await?, b"hello world");
+
+                let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+                Ok::<_, JavaError>(())
+            };
+
+            serve(client, server).await
+        })
+    }
+
+    #[test]
+    fn test_socket_connection() -> Result<()> {
+        let platform = TestPlatform::new();
+        let listener = platform.test_network().listen(SocketAddrV4::new(SERVER_ADDR, 8080));
+
+        run_jvm_test_with_platform(platform, Box::new([get_protos().into()]), |jvm| async move {
+            let server = async {
+                let socket = listener.accept_async().await;
+
+                assert_eq!(read_until(&socket, |x| x.len() >= 5).await, b"hello");
+
+                socket.write(b"world!").await.unwrap();
+                socket.shutdown();
+            };
+
+            let client = async {
+                let connection = open(&jvm, "socket://10.0.0.2:8080").await?;
+
+                let port: i32 = jvm.invoke_virtual(&connection, "getPort", "()I", ()).await?;
+                assert_eq!(port, 8080);
+
+                let output_stream: ClassInstanceRef<OutputStream> = jvm
+                    .invoke_virtual(&connection, "openOutputStream", "()Ljava/io/OutputStream;", ())
+                    .await?;
+                let data = byte_array(&jvm, b"hello").await?;
+                let _: () = jvm.invoke_virtual(&output_stream, "write", "([B)V", (data,)).await?;
+                let _: () = jvm.invoke_virtual(&output_stream, "flush", "()V", ()).await?;
+
+                assert_eq!(read_all(&jvm, &connection).await?, b"world!");
+
+                let _: () = jvm.invoke_virtual(&output_stream, "close", "()V", ()).await?;
+                let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+                Ok::<_, JavaError>(())
+            };
+
+            serve(client, server).await
+        })
+    }
+
+    #[test]
+    fn test_datagram_connection() -> Result<()> {
+        let platform = TestPlatform::new();
+        let server_socket = platform.test_network().bind_udp(SocketAddrV4::new(SERVER_ADDR, 9000)).unwrap();
+
+        run_jvm_test_with_platform(platform, Box::new([get_protos().into()]), |jvm| async move {
+            let server = async {
+                let mut buf = [0; 16];
+                let (read, addr) = server_socket.recv_from(&mut buf).await.unwrap();
+                assert_eq!(&buf[..read], b"ping");
+
+                server_socket.send_to(b"pong", addr).await.unwrap();
+            };
+
+            let client = async {
+                let connection = open(&jvm, "datagram://10.0.0.2:9000").await?;
+
+                let data = byte_array(&jvm, b"ping").await?;
+                let datagram: ClassInstanceRef<Datagram> = jvm
+                    .invoke_virtual(&connection, "newDatagram", "([BI)Ljavax/microedition/io/Datagram;", (data, 4))
+                    .await?;
+                let _: () = jvm
+                    .invoke_virtual(&connection, "send", "(Ljavax/microedition/io/Datagram;)V", (datagram,))
+                    .await?;
+
+                let datagram: ClassInstanceRef<Datagram> = jvm
+                    .invoke_virtual(&connection, "newDatagram", "(I)Ljavax/microedition/io/Datagram;", (16,))
+                    .await?;
+                let _: () = jvm
+                    .invoke_virtual(&connection, "receive", "(Ljavax/microedition/io/Datagram;)V", (datagram.clone(),))
+                    .await?;
+
+                let length: i32 = jvm.invoke_virtual(&datagram, "getLength", "()I", ()).await?;
+                let data: ClassInstanceRef<Array<i8>> = jvm.invoke_virtual(&datagram, "getData", "()[B", ()).await?;
+                let data = cast_vec::<i8, u8>(jvm.load_array(&data, 0, length as _).await?);
+                assert_eq!(data, b"pong");
+
+                let address = jvm.invoke_virtual(&datagram, "getAddress", "()Ljava/lang/String;", ()).await?;
+                assert_eq!(JavaLangString::to_rust_string(&jvm, &address).await?, "datagram://10.0.0.2:9000");
+
+                let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+                Ok::<_, JavaError>(())
+            };
+
+            serve(client, server).await
+        })
+    }
+}
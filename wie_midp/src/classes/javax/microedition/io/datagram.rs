@@ -0,0 +1,33 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+// interface javax.microedition.io.Datagram
+// TODO java.io.DataInput and java.io.DataOutput methods
+pub struct Datagram;
+
+impl Datagram {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/Datagram",
+            parent_class: None,
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new_abstract("getAddress", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getData", "()[B", Default::default()),
+                JavaMethodProto::new_abstract("getLength", "()I", Default::default()),
+                JavaMethodProto::new_abstract("getOffset", "()I", Default::default()),
+                JavaMethodProto::new_abstract("setAddress", "(Ljava/lang/String;)V", Default::default()),
+                JavaMethodProto::new_abstract("setAddress", "(Ljavax/microedition/io/Datagram;)V", Default::default()),
+                JavaMethodProto::new_abstract("setLength", "(I)V", Default::default()),
+                JavaMethodProto::new_abstract("setData", "([BII)V", Default::default()),
+                JavaMethodProto::new_abstract("reset", "()V", Default::default()),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+// interface javax.microedition.io.DatagramConnection
+pub struct DatagramConnection;
+
+impl DatagramConnection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/DatagramConnection",
+            parent_class: None,
+            interfaces: vec!["javax/microedition/io/Connection"],
+            methods: vec![
+                JavaMethodProto::new_abstract("getMaximumLength", "()I", Default::default()),
+                JavaMethodProto::new_abstract("getNominalLength", "()I", Default::default()),
+                JavaMethodProto::new_abstract("send", "(Ljavax/microedition/io/Datagram;)V", Default::default()),
+                JavaMethodProto::new_abstract("receive", "(Ljavax/microedition/io/Datagram;)V", Default::default()),
+                JavaMethodProto::new_abstract("newDatagram", "(I)Ljavax/microedition/io/Datagram;", Default::default()),
+                JavaMethodProto::new_abstract("newDatagram", "(ILjava/lang/String;)Ljavax/microedition/io/Datagram;", Default::default()),
+                JavaMethodProto::new_abstract("newDatagram", "([BI)Ljavax/microedition/io/Datagram;", Default::default()),
+                JavaMethodProto::new_abstract(
+                    "newDatagram",
+                    "([BILjava/lang/String;)Ljavax/microedition/io/Datagram;",
+                    Default::default(),
+                ),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod file_connection;
+
+pub use self::file_connection::FileConnection;
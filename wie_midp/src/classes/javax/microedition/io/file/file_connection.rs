@@ -0,0 +1,50 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+// interface javax.microedition.io.file.FileConnection
+pub struct FileConnection;
+
+impl FileConnection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/file/FileConnection",
+            parent_class: None,
+            interfaces: vec!["javax/microedition/io/StreamConnection"],
+            methods: vec![
+                JavaMethodProto::new_abstract("isOpen", "()Z", Default::default()),
+                JavaMethodProto::new_abstract("openOutputStream", "(J)Ljava/io/OutputStream;", Default::default()),
+                JavaMethodProto::new_abstract("totalSize", "()J", Default::default()),
+                JavaMethodProto::new_abstract("availableSize", "()J", Default::default()),
+                JavaMethodProto::new_abstract("usedSize", "()J", Default::default()),
+                JavaMethodProto::new_abstract("directorySize", "(Z)J", Default::default()),
+                JavaMethodProto::new_abstract("fileSize", "()J", Default::default()),
+                JavaMethodProto::new_abstract("canRead", "()Z", Default::default()),
+                JavaMethodProto::new_abstract("canWrite", "()Z", Default::default()),
+                JavaMethodProto::new_abstract("isHidden", "()Z", Default::default()),
+                JavaMethodProto::new_abstract("setReadable", "(Z)V", Default::default()),
+                JavaMethodProto::new_abstract("setWritable", "(Z)V", Default::default()),
+                JavaMethodProto::new_abstract("setHidden", "(Z)V", Default::default()),
+                JavaMethodProto::new_abstract("list", "()Ljava/util/Enumeration;", Default::default()),
+                JavaMethodProto::new_abstract("list", "(Ljava/lang/String;Z)Ljava/util/Enumeration;", Default::default()),
+                JavaMethodProto::new_abstract("create", "()V", Default::default()),
+                JavaMethodProto::new_abstract("mkdir", "()V", Default::default()),
+                JavaMethodProto::new_abstract("exists", "()Z", Default::default()),
+                JavaMethodProto::new_abstract("isDirectory", "()Z", Default::default()),
+                JavaMethodProto::new_abstract("delete", "()V", Default::default()),
+                JavaMethodProto::new_abstract("rename", "(Ljava/lang/String;)V", Default::default()),
+                JavaMethodProto::new_abstract("truncate", "(J)V", Default::default()),
+                JavaMethodProto::new_abstract("setFileConnection", "(Ljava/lang/String;)V", Default::default()),
+                JavaMethodProto::new_abstract("getName", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getPath", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getURL", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("lastModified", "()J", Default::default()),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
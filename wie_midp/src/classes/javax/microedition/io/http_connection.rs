@@ -0,0 +1,49 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+pub const HEAD: &str = "HEAD";
+pub const GET: &str = "GET";
+pub const POST: &str = "POST";
+
+// interface javax.microedition.io.HttpConnection
+// HTTP_* status code constants are inlined by compiler, so they're not declared here
+pub struct HttpConnection;
+
+impl HttpConnection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/HttpConnection",
+            parent_class: None,
+            interfaces: vec!["javax/microedition/io/ContentConnection"],
+            methods: vec![
+                JavaMethodProto::new_abstract("getURL", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getProtocol", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getHost", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getFile", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getRef", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getQuery", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getPort", "()I", Default::default()),
+                JavaMethodProto::new_abstract("getRequestMethod", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("setRequestMethod", "(Ljava/lang/String;)V", Default::default()),
+                JavaMethodProto::new_abstract("getRequestProperty", "(Ljava/lang/String;)Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("setRequestProperty", "(Ljava/lang/String;Ljava/lang/String;)V", Default::default()),
+                JavaMethodProto::new_abstract("getResponseCode", "()I", Default::default()),
+                JavaMethodProto::new_abstract("getResponseMessage", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getExpiration", "()J", Default::default()),
+                JavaMethodProto::new_abstract("getDate", "()J", Default::default()),
+                JavaMethodProto::new_abstract("getLastModified", "()J", Default::default()),
+                JavaMethodProto::new_abstract("getHeaderField", "(Ljava/lang/String;)Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getHeaderFieldInt", "(Ljava/lang/String;I)I", Default::default()),
+                JavaMethodProto::new_abstract("getHeaderFieldDate", "(Ljava/lang/String;J)J", Default::default()),
+                JavaMethodProto::new_abstract("getHeaderField", "(I)Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getHeaderFieldKey", "(I)Ljava/lang/String;", Default::default()),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+// interface javax.microedition.io.OutputConnection
+pub struct OutputConnection;
+
+impl OutputConnection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/OutputConnection",
+            parent_class: None,
+            interfaces: vec!["javax/microedition/io/Connection"],
+            methods: vec![
+                JavaMethodProto::new_abstract("openOutputStream", "()Ljava/io/OutputStream;", Default::default()),
+                JavaMethodProto::new_abstract("openDataOutputStream", "()Ljava/io/DataOutputStream;", Default::default()),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+pub const DELAY: i8 = 0;
+pub const LINGER: i8 = 1;
+pub const KEEPALIVE: i8 = 2;
+pub const RCVBUF: i8 = 3;
+pub const SNDBUF: i8 = 4;
+
+// interface javax.microedition.io.SocketConnection
+pub struct SocketConnection;
+
+impl SocketConnection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/io/SocketConnection",
+            parent_class: None,
+            interfaces: vec!["javax/microedition/io/StreamConnection"],
+            methods: vec![
+                JavaMethodProto::new_abstract("setSocketOption", "(BI)V", Default::default()),
+                JavaMethodProto::new_abstract("getSocketOption", "(B)I", Default::default()),
+                JavaMethodProto::new_abstract("getLocalAddress", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getLocalPort", "()I", Default::default()),
+                JavaMethodProto::new_abstract("getAddress", "()Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getPort", "()I", Default::default()),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
@@ -1,22 +1,32 @@
 mod alert;
 mod alert_type;
 mod canvas;
+mod choice;
 mod choice_group;
 mod command;
+mod command_bar;
 mod command_listener;
 mod display;
 mod displayable;
 mod font;
 mod form;
 pub mod game;
+mod gauge;
 mod graphics;
 mod image;
+mod image_item;
 mod item;
+mod item_state_listener;
+mod list;
+pub mod renderer;
 mod screen;
+mod string_item;
 mod text_box;
+mod ticker;
 
 pub use {
-    alert::Alert, alert_type::AlertType, canvas::Canvas, choice_group::ChoiceGroup, command::Command, command_listener::CommandListener,
-    display::Display, displayable::Displayable, font::Font, form::Form, graphics::Graphics, image::Image, item::Item, screen::Screen,
-    text_box::TextBox,
+    alert::Alert, alert_type::AlertType, canvas::Canvas, choice::Choice, choice_group::ChoiceGroup, command::Command,
+    command_listener::CommandListener, display::Display, displayable::Displayable, font::Font, form::Form, gauge::Gauge, graphics::Graphics,
+    image::Image, image_item::ImageItem, item::Item, item_state_listener::ItemStateListener, list::List, screen::Screen, string_item::StringItem,
+    text_box::TextBox, ticker::Ticker,
 };
@@ -1,12 +1,23 @@
 use alloc::vec;
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::canvas::TextAlignment;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
-use crate::classes::javax::microedition::lcdui::AlertType;
+use crate::classes::{
+    javax::microedition::lcdui::{
+        AlertType, Display, Graphics, Image,
+        renderer::{self, BACKGROUND, BORDER, FOREGROUND, LINE_HEIGHT, PADDING, Renderer, TITLE_HEIGHT},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
+
+const FOREVER: i32 = -2;
+const DEFAULT_TIMEOUT: i32 = 2000;
+const MARGIN: i32 = 8;
 
 // class javax.microedition.lcdui.Alert
 pub struct Alert;
@@ -19,11 +30,36 @@ impl Alert {
             interfaces: vec![],
             methods: vec![
                 JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/String;Ljava/lang/String;Ljavax/microedition/lcdui/Image;Ljavax/microedition/lcdui/AlertType;)V",
+                    Self::init_with_text,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getType", "()Ljavax/microedition/lcdui/AlertType;", Self::get_type, Default::default()),
                 JavaMethodProto::new("setType", "(Ljavax/microedition/lcdui/AlertType;)V", Self::set_type, Default::default()),
+                JavaMethodProto::new("getDefaultTimeout", "()I", Self::get_default_timeout, Default::default()),
+                JavaMethodProto::new("getTimeout", "()I", Self::get_timeout, Default::default()),
                 JavaMethodProto::new("setTimeout", "(I)V", Self::set_timeout, Default::default()),
+                JavaMethodProto::new("getString", "()Ljava/lang/String;", Self::get_string, Default::default()),
                 JavaMethodProto::new("setString", "(Ljava/lang/String;)V", Self::set_string, Default::default()),
+                JavaMethodProto::new("getImage", "()Ljavax/microedition/lcdui/Image;", Self::get_image, Default::default()),
+                JavaMethodProto::new("setImage", "(Ljavax/microedition/lcdui/Image;)V", Self::set_image, Default::default()),
+                // wie private methods
+                JavaMethodProto::new(
+                    "handlePaintEvent",
+                    "(Ljavax/microedition/lcdui/Graphics;)V",
+                    Self::handle_paint_event,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("text", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("image", "Ljavax/microedition/lcdui/Image;", Default::default()),
+                JavaFieldProto::new("alertType", "Ljavax/microedition/lcdui/AlertType;", Default::default()),
+                JavaFieldProto::new("timeout", "I", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
@@ -31,30 +67,176 @@ impl Alert {
     async fn init(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, title: ClassInstanceRef<String>) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Alert::<init>({this:?}, {title:?})");
 
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/Alert",
+                "<init>",
+                "(Ljava/lang/String;Ljava/lang/String;Ljavax/microedition/lcdui/Image;Ljavax/microedition/lcdui/AlertType;)V",
+                (title, None, None, None),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_text(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        title: ClassInstanceRef<String>,
+        text: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+        alert_type: ClassInstanceRef<AlertType>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Alert::<init>({this:?}, {title:?}, {text:?}, {image:?}, {alert_type:?})");
+
         let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Screen", "<init>", "()V", ()).await?;
 
+        jvm.put_field(&mut this, "title", "Ljava/lang/String;", title).await?;
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+        jvm.put_field(&mut this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+        jvm.put_field(&mut this, "alertType", "Ljavax/microedition/lcdui/AlertType;", alert_type)
+            .await?;
+        jvm.put_field(&mut this, "timeout", "I", DEFAULT_TIMEOUT).await?;
+
         Ok(())
     }
 
+    async fn get_type(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<AlertType>> {
+        tracing::debug!("javax.microedition.lcdui.Alert::getType({this:?})");
+
+        jvm.get_field(&this, "alertType", "Ljavax/microedition/lcdui/AlertType;").await
+    }
+
     async fn set_type(
-        _jvm: &Jvm,
+        jvm: &Jvm,
         _context: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+        mut this: ClassInstanceRef<Self>,
         alert_type: ClassInstanceRef<AlertType>,
     ) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.lcdui.Alert::setType({this:?}, {alert_type:?})");
+        tracing::debug!("javax.microedition.lcdui.Alert::setType({this:?}, {alert_type:?})");
+
+        jvm.put_field(&mut this, "alertType", "Ljavax/microedition/lcdui/AlertType;", alert_type)
+            .await
+    }
+
+    async fn get_default_timeout(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Alert::getDefaultTimeout({this:?})");
+
+        Ok(DEFAULT_TIMEOUT)
+    }
+
+    async fn get_timeout(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Alert::getTimeout({this:?})");
+
+        jvm.get_field(&this, "timeout", "I").await
+    }
+
+    async fn set_timeout(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, timeout: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Alert::setTimeout({this:?}, {timeout})");
+
+        if timeout <= 0 && timeout != FOREVER {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid timeout").await);
+        }
+
+        jvm.put_field(&mut this, "timeout", "I", timeout).await
+    }
+
+    async fn get_string(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.Alert::getString({this:?})");
+
+        jvm.get_field(&this, "text", "Ljava/lang/String;").await
+    }
+
+    async fn set_string(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Alert::setString({this:?}, {text:?})");
+
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+        renderer::request_redraw(context);
 
         Ok(())
     }
 
-    async fn set_timeout(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, timeout: i32) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.lcdui.Alert::setTimeout({this:?}, {timeout})");
+    async fn get_image(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Image>> {
+        tracing::debug!("javax.microedition.lcdui.Alert::getImage({this:?})");
+
+        jvm.get_field(&this, "image", "Ljavax/microedition/lcdui/Image;").await
+    }
+
+    async fn set_image(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, image: ClassInstanceRef<Image>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Alert::setImage({this:?}, {image:?})");
+
+        jvm.put_field(&mut this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+        renderer::request_redraw(context);
 
         Ok(())
     }
 
-    async fn set_string(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.lcdui.Alert::setString({this:?}, {text:?})");
+    // alert is painted as a dialog over the displayable it returns to
+    async fn handle_paint_event(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Alert::handlePaintEvent({this:?}, {graphics:?})");
+
+        let title = jvm.get_field(&this, "title", "Ljava/lang/String;").await?;
+        let title = renderer::rust_string(jvm, &title).await?;
+        let text = jvm.get_field(&this, "text", "Ljava/lang/String;").await?;
+        let text = renderer::rust_string(jvm, &text).await?;
+        let image: ClassInstanceRef<Image> = jvm.get_field(&this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        let image = if image.is_null() { None } else { Some(Image::image(jvm, &image).await?) };
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+
+        let width = renderer.width() - MARGIN * 2;
+        let lines = renderer::wrap_text(&text, width - PADDING * 4);
+        let image_height = image.as_ref().map(|x| x.height() as i32 + PADDING).unwrap_or(0);
+        let height = (TITLE_HEIGHT + image_height + lines.len() as i32 * LINE_HEIGHT + PADDING * 4).min(renderer.height() - MARGIN * 2);
+
+        let x = MARGIN;
+        let y = (renderer.height() - height) / 2;
+
+        renderer.fill_rect(x, y, width, height, BACKGROUND);
+        renderer.draw_rect(x, y, width - 1, height - 1, BORDER);
+
+        renderer.set_clip(x, y, width, height);
+        renderer.draw_bar(y, TITLE_HEIGHT, &title, TextAlignment::Center);
+        // draw_bar fills whole width, so restore the border
+        renderer.draw_rect(x, y, width - 1, height - 1, BORDER);
+
+        let mut content_y = y + TITLE_HEIGHT + PADDING * 2;
+        if let Some(image) = image {
+            renderer.draw_image(&*image, x + (width - image.width() as i32) / 2, content_y);
+            content_y += image.height() as i32 + PADDING;
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            renderer.draw_text(line, x + width / 2, content_y + i as i32 * LINE_HEIGHT, TextAlignment::Center, FOREGROUND);
+        }
+
+        Ok(())
+    }
+
+    async fn handle_key_event(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Alert::handleKeyEvent({this:?}, {event_type}, {code})");
+
+        if !matches!(KeyboardEventType::from_raw(event_type), KeyboardEventType::KeyPressed) {
+            return Ok(());
+        }
+
+        if matches!(
+            MIDPKeyCode::from_raw(code),
+            MIDPKeyCode::FIRE | MIDPKeyCode::LEFT_SOFT_KEY | MIDPKeyCode::RIGHT_SOFT_KEY
+        ) {
+            let display: ClassInstanceRef<Display> = jvm.get_field(&this, "currentDisplay", "Ljavax/microedition/lcdui/Display;").await?;
+            if !display.is_null() {
+                let serial: i32 = jvm.get_field(&display, "alertSerial", "I").await?;
+                let _: () = jvm.invoke_virtual(&display, "dismissAlert", "(I)V", (serial,)).await?;
+            }
+        }
 
         Ok(())
     }
@@ -16,7 +16,7 @@ impl AlertType {
             parent_class: Some("java/lang/Object"),
             interfaces: vec![],
             methods: vec![
-                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("<init>", "(I)V", Self::init, Default::default()),
                 JavaMethodProto::new("<clinit>", "()V", Self::cl_init, Default::default()),
             ],
             fields: vec![
@@ -25,16 +25,19 @@ impl AlertType {
                 JavaFieldProto::new("ERROR", "Ljavax/microedition/lcdui/AlertType;", FieldAccessFlags::STATIC),
                 JavaFieldProto::new("INFO", "Ljavax/microedition/lcdui/AlertType;", FieldAccessFlags::STATIC),
                 JavaFieldProto::new("WARNING", "Ljavax/microedition/lcdui/AlertType;", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("type", "I", Default::default()),
             ],
             access_flags: Default::default(),
         }
     }
 
-    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32) -> JvmResult<()> {
-        tracing::debug!("javax.microedition.lcdui.Alert::<init>({this:?}, {})", r#type);
+    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, r#type: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.AlertType::<init>({this:?}, {})", r#type);
 
         let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
 
+        jvm.put_field(&mut this, "type", "I", r#type).await?;
+
         Ok(())
     }
 
@@ -45,7 +48,7 @@ impl AlertType {
             "javax/microedition/lcdui/AlertType",
             "ALARM",
             "Ljavax/microedition/lcdui/AlertType;",
-            jvm.new_class("javax/microedition/lcdui/AlertType", "(Ljava/lang/String;)V", (0,)).await?,
+            jvm.new_class("javax/microedition/lcdui/AlertType", "(I)V", (0,)).await?,
         )
         .await?;
 
@@ -53,7 +56,7 @@ impl AlertType {
             "javax/microedition/lcdui/AlertType",
             "CONFIRMATION",
             "Ljavax/microedition/lcdui/AlertType;",
-            jvm.new_class("javax/microedition/lcdui/AlertType", "(Ljava/lang/String;)V", (1,)).await?,
+            jvm.new_class("javax/microedition/lcdui/AlertType", "(I)V", (1,)).await?,
         )
         .await?;
 
@@ -61,7 +64,7 @@ impl AlertType {
             "javax/microedition/lcdui/AlertType",
             "ERROR",
             "Ljavax/microedition/lcdui/AlertType;",
-            jvm.new_class("javax/microedition/lcdui/AlertType", "(Ljava/lang/String;)V", (2,)).await?,
+            jvm.new_class("javax/microedition/lcdui/AlertType", "(I)V", (2,)).await?,
         )
         .await?;
 
@@ -69,7 +72,7 @@ impl AlertType {
             "javax/microedition/lcdui/AlertType",
             "INFO",
             "Ljavax/microedition/lcdui/AlertType;",
-            jvm.new_class("javax/microedition/lcdui/AlertType", "(Ljava/lang/String;)V", (3,)).await?,
+            jvm.new_class("javax/microedition/lcdui/AlertType", "(I)V", (3,)).await?,
         )
         .await?;
 
@@ -77,7 +80,7 @@ impl AlertType {
             "javax/microedition/lcdui/AlertType",
             "WARNING",
             "Ljavax/microedition/lcdui/AlertType;",
-            jvm.new_class("javax/microedition/lcdui/AlertType", "(Ljava/lang/String;)V", (4,)).await?,
+            jvm.new_class("javax/microedition/lcdui/AlertType", "(I)V", (4,)).await?,
         )
         .await?;
 
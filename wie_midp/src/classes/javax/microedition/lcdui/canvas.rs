@@ -8,7 +8,7 @@ use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
 use crate::classes::{
     javax::microedition::lcdui::{Display, Graphics},
-    net::wie::{KeyboardEventType, MIDPKeyCode},
+    net::wie::{KeyboardEventType, MIDPKeyCode, PointerEventType},
 };
 
 // abstract class javax.microedition.lcdui.Canvas
@@ -30,15 +30,25 @@ impl Canvas {
                 JavaMethodProto::new("keyPressed", "(I)V", Self::key_pressed, Default::default()),
                 JavaMethodProto::new("keyRepeated", "(I)V", Self::key_repeated, Default::default()),
                 JavaMethodProto::new("keyReleased", "(I)V", Self::key_released, Default::default()),
+                JavaMethodProto::new("pointerPressed", "(II)V", Self::pointer_pressed, Default::default()),
+                JavaMethodProto::new("pointerReleased", "(II)V", Self::pointer_released, Default::default()),
+                JavaMethodProto::new("pointerDragged", "(II)V", Self::pointer_dragged, Default::default()),
+                JavaMethodProto::new("hasPointerEvents", "()Z", Self::has_pointer_events, Default::default()),
+                JavaMethodProto::new("hasPointerMotionEvents", "()Z", Self::has_pointer_motion_events, Default::default()),
                 JavaMethodProto::new("setFullScreenMode", "(Z)V", Self::set_full_screen_mode, Default::default()),
+                JavaMethodProto::new("showNotify", "()V", Self::show_notify, Default::default()),
+                JavaMethodProto::new("hideNotify", "()V", Self::hide_notify, Default::default()),
                 // wie private methods
                 JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+                JavaMethodProto::new("handlePointerEvent", "(III)V", Self::handle_pointer_event, Default::default()),
                 JavaMethodProto::new(
                     "handlePaintEvent",
                     "(Ljavax/microedition/lcdui/Graphics;)V",
                     Self::handle_paint_event,
                     Default::default(),
                 ),
+                JavaMethodProto::new("handleHideEvent", "()V", Self::handle_hide_event, Default::default()),
+                JavaMethodProto::new("handleShowEvent", "()V", Self::handle_show_event, Default::default()),
             ],
             fields: vec![],
             access_flags: ClassAccessFlags::ABSTRACT,
@@ -96,11 +106,15 @@ impl Canvas {
         tracing::debug!("javax.microedition.lcdui.Canvas::getGameAction({:?}, {})", &this, key);
 
         let action = match MIDPKeyCode::from_raw(key) {
-            MIDPKeyCode::UP => 1,    // UP
-            MIDPKeyCode::DOWN => 6,  // DOWN
-            MIDPKeyCode::LEFT => 2,  // LEFT
-            MIDPKeyCode::RIGHT => 5, // RIGHT
-            MIDPKeyCode::FIRE => 8,  // FIRE,
+            MIDPKeyCode::UP => 1,        // UP
+            MIDPKeyCode::DOWN => 6,      // DOWN
+            MIDPKeyCode::LEFT => 2,      // LEFT
+            MIDPKeyCode::RIGHT => 5,     // RIGHT
+            MIDPKeyCode::FIRE => 8,      // FIRE,
+            MIDPKeyCode::KEY_NUM1 => 9,  // GAME_A
+            MIDPKeyCode::KEY_NUM3 => 10, // GAME_B
+            MIDPKeyCode::KEY_NUM7 => 11, // GAME_C
+            MIDPKeyCode::KEY_NUM9 => 12, // GAME_D
             _ => 0,
         };
 
@@ -125,6 +139,48 @@ impl Canvas {
         Ok(())
     }
 
+    async fn pointer_pressed(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::pointerPressed({this:?}, {x}, {y})");
+
+        Ok(())
+    }
+
+    async fn pointer_released(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::pointerReleased({this:?}, {x}, {y})");
+
+        Ok(())
+    }
+
+    async fn pointer_dragged(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::pointerDragged({this:?}, {x}, {y})");
+
+        Ok(())
+    }
+
+    async fn has_pointer_events(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::hasPointerEvents({this:?})");
+
+        Ok(true)
+    }
+
+    async fn has_pointer_motion_events(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::hasPointerMotionEvents({this:?})");
+
+        Ok(true)
+    }
+
+    async fn show_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::showNotify({this:?})");
+
+        Ok(())
+    }
+
+    async fn hide_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::hideNotify({this:?})");
+
+        Ok(())
+    }
+
     async fn set_full_screen_mode(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, mode: bool) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Canvas::setFullScreenMode({this:?}, {mode})");
 
@@ -158,6 +214,23 @@ impl Canvas {
         Ok(())
     }
 
+    async fn handle_pointer_event(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        event_type: i32,
+        x: i32,
+        y: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::handlePointerEvent({this:?}, {event_type}, {x}, {y})");
+
+        match PointerEventType::from_raw(event_type) {
+            PointerEventType::PointerPressed => jvm.invoke_virtual(&this, "pointerPressed", "(II)V", (x, y)).await,
+            PointerEventType::PointerReleased => jvm.invoke_virtual(&this, "pointerReleased", "(II)V", (x, y)).await,
+            PointerEventType::PointerDragged => jvm.invoke_virtual(&this, "pointerDragged", "(II)V", (x, y)).await,
+        }
+    }
+
     async fn handle_paint_event(
         jvm: &Jvm,
         _context: &mut WieJvmContext,
@@ -172,4 +245,16 @@ impl Canvas {
 
         Ok(())
     }
+
+    async fn handle_hide_event(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::handleHideEvent({this:?})");
+
+        jvm.invoke_virtual(&this, "hideNotify", "()V", ()).await
+    }
+
+    async fn handle_show_event(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Canvas::handleShowEvent({this:?})");
+
+        jvm.invoke_virtual(&this, "showNotify", "()V", ()).await
+    }
 }
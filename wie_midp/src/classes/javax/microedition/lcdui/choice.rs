@@ -0,0 +1,425 @@
+use alloc::{vec, vec::Vec};
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+use java_runtime::classes::java::lang::String;
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::WieJavaClassProto;
+
+use crate::classes::javax::microedition::lcdui::{
+    Graphics, Image,
+    renderer::{self, FOREGROUND, HIGHLIGHT, HIGHLIGHT_TEXT, LINE_HEIGHT, PADDING, Renderer},
+};
+
+pub const EXCLUSIVE: i32 = 1;
+pub const MULTIPLE: i32 = 2;
+pub const IMPLICIT: i32 = 3;
+pub const POPUP: i32 = 4;
+
+// interface javax.microedition.lcdui.Choice
+// implementors (ChoiceGroup, List) share element storage in fields `choiceType`, `strings`, `images`, `selected` and `cursor`
+pub struct Choice;
+
+impl Choice {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/Choice",
+            parent_class: None,
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new_abstract("append", "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;)I", Default::default()),
+                JavaMethodProto::new_abstract("insert", "(ILjava/lang/String;Ljavax/microedition/lcdui/Image;)V", Default::default()),
+                JavaMethodProto::new_abstract("set", "(ILjava/lang/String;Ljavax/microedition/lcdui/Image;)V", Default::default()),
+                JavaMethodProto::new_abstract("delete", "(I)V", Default::default()),
+                JavaMethodProto::new_abstract("deleteAll", "()V", Default::default()),
+                JavaMethodProto::new_abstract("getString", "(I)Ljava/lang/String;", Default::default()),
+                JavaMethodProto::new_abstract("getImage", "(I)Ljavax/microedition/lcdui/Image;", Default::default()),
+                JavaMethodProto::new_abstract("size", "()I", Default::default()),
+                JavaMethodProto::new_abstract("isSelected", "(I)Z", Default::default()),
+                JavaMethodProto::new_abstract("getSelectedIndex", "()I", Default::default()),
+                JavaMethodProto::new_abstract("setSelectedIndex", "(IZ)V", Default::default()),
+                JavaMethodProto::new_abstract("getSelectedFlags", "([Z)I", Default::default()),
+                JavaMethodProto::new_abstract("setSelectedFlags", "([Z)V", Default::default()),
+            ],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+
+    pub async fn init_elements<T>(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<T>,
+        choice_type: i32,
+        strings: ClassInstanceRef<Array<ClassInstanceRef<String>>>,
+        images: ClassInstanceRef<Array<ClassInstanceRef<Image>>>,
+    ) -> JvmResult<()> {
+        if !(EXCLUSIVE..=POPUP).contains(&choice_type) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid choiceType").await);
+        }
+        if strings.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "stringElements is null").await);
+        }
+
+        let length = jvm.array_length(&strings).await?;
+        if !images.is_null() && jvm.array_length(&images).await? != length {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "length mismatch").await);
+        }
+
+        let mut this = this.clone();
+        jvm.put_field(&mut this, "choiceType", "I", choice_type).await?;
+
+        let string_vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "strings", "Ljava/util/Vector;", string_vector).await?;
+        let image_vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "images", "Ljava/util/Vector;", image_vector).await?;
+        Self::store_selected(jvm, &this, Vec::new()).await?;
+
+        let strings: Vec<ClassInstanceRef<String>> = jvm.load_array(&strings, 0, length).await?;
+        let images: Vec<ClassInstanceRef<Image>> = if images.is_null() {
+            (0..length).map(|_| None.into()).collect()
+        } else {
+            jvm.load_array(&images, 0, length).await?
+        };
+
+        for (string, image) in strings.into_iter().zip(images) {
+            Self::append(jvm, &this, string, image).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn choice_type<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<i32> {
+        jvm.get_field(this, "choiceType", "I").await
+    }
+
+    pub async fn size<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<i32> {
+        let strings = jvm.get_field(this, "strings", "Ljava/util/Vector;").await?;
+
+        jvm.invoke_virtual(&strings, "size", "()I", ()).await
+    }
+
+    pub async fn append<T>(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<T>,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<i32> {
+        let size = Self::size(jvm, this).await?;
+
+        Self::insert(jvm, this, size, string, image).await?;
+
+        Ok(size)
+    }
+
+    pub async fn insert<T>(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<T>,
+        index: i32,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<()> {
+        let size = Self::size(jvm, this).await?;
+        if index < 0 || index > size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "index out of range").await);
+        }
+        if string.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "stringPart is null").await);
+        }
+
+        let strings = jvm.get_field(this, "strings", "Ljava/util/Vector;").await?;
+        let _: () = jvm
+            .invoke_virtual(&strings, "insertElementAt", "(Ljava/lang/Object;I)V", (string, index))
+            .await?;
+        let images = jvm.get_field(this, "images", "Ljava/util/Vector;").await?;
+        let _: () = jvm
+            .invoke_virtual(&images, "insertElementAt", "(Ljava/lang/Object;I)V", (image, index))
+            .await?;
+
+        let mut selected = Self::load_selected(jvm, this).await?;
+        // exclusive choices always have one selected element
+        let select = Self::choice_type(jvm, this).await? != MULTIPLE && size == 0;
+        selected.insert(index as usize, select);
+        Self::store_selected(jvm, this, selected).await?;
+
+        let cursor: i32 = jvm.get_field(this, "cursor", "I").await?;
+        if index <= cursor && size != 0 {
+            jvm.put_field(&mut this.clone(), "cursor", "I", cursor + 1).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn set<T>(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<T>,
+        index: i32,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<()> {
+        Self::check_index(jvm, this, index).await?;
+        if string.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "stringPart is null").await);
+        }
+
+        let strings = jvm.get_field(this, "strings", "Ljava/util/Vector;").await?;
+        let _: () = jvm
+            .invoke_virtual(&strings, "setElementAt", "(Ljava/lang/Object;I)V", (string, index))
+            .await?;
+        let images = jvm.get_field(this, "images", "Ljava/util/Vector;").await?;
+        let _: () = jvm
+            .invoke_virtual(&images, "setElementAt", "(Ljava/lang/Object;I)V", (image, index))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32) -> JvmResult<()> {
+        Self::check_index(jvm, this, index).await?;
+
+        let strings = jvm.get_field(this, "strings", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&strings, "removeElementAt", "(I)V", (index,)).await?;
+        let images = jvm.get_field(this, "images", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&images, "removeElementAt", "(I)V", (index,)).await?;
+
+        let mut selected = Self::load_selected(jvm, this).await?;
+        let was_selected = selected.remove(index as usize);
+        if was_selected && !selected.is_empty() && Self::choice_type(jvm, this).await? != MULTIPLE {
+            selected[(index as usize).min(selected.len() - 1)] = true;
+        }
+        let size = selected.len() as i32;
+        Self::store_selected(jvm, this, selected).await?;
+
+        let cursor: i32 = jvm.get_field(this, "cursor", "I").await?;
+        let cursor = if index < cursor { cursor - 1 } else { cursor };
+        jvm.put_field(&mut this.clone(), "cursor", "I", cursor.min(size - 1).max(0)).await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_all<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<()> {
+        let strings = jvm.get_field(this, "strings", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&strings, "removeAllElements", "()V", ()).await?;
+        let images = jvm.get_field(this, "images", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&images, "removeAllElements", "()V", ()).await?;
+
+        Self::store_selected(jvm, this, Vec::new()).await?;
+        jvm.put_field(&mut this.clone(), "cursor", "I", 0).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_string<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32) -> JvmResult<ClassInstanceRef<String>> {
+        Self::check_index(jvm, this, index).await?;
+
+        let strings = jvm.get_field(this, "strings", "Ljava/util/Vector;").await?;
+
+        jvm.invoke_virtual(&strings, "elementAt", "(I)Ljava/lang/Object;", (index,)).await
+    }
+
+    pub async fn get_image<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32) -> JvmResult<ClassInstanceRef<Image>> {
+        Self::check_index(jvm, this, index).await?;
+
+        let images = jvm.get_field(this, "images", "Ljava/util/Vector;").await?;
+
+        jvm.invoke_virtual(&images, "elementAt", "(I)Ljava/lang/Object;", (index,)).await
+    }
+
+    pub async fn is_selected<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32) -> JvmResult<bool> {
+        Self::check_index(jvm, this, index).await?;
+
+        let selected = Self::load_selected(jvm, this).await?;
+
+        Ok(selected[index as usize])
+    }
+
+    pub async fn get_selected_index<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<i32> {
+        if Self::choice_type(jvm, this).await? == MULTIPLE {
+            return Ok(-1);
+        }
+
+        let selected = Self::load_selected(jvm, this).await?;
+
+        Ok(selected.iter().position(|&x| x).map(|x| x as i32).unwrap_or(-1))
+    }
+
+    pub async fn set_selected_index<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32, selected: bool) -> JvmResult<()> {
+        Self::check_index(jvm, this, index).await?;
+
+        let mut flags = Self::load_selected(jvm, this).await?;
+        if Self::choice_type(jvm, this).await? == MULTIPLE {
+            flags[index as usize] = selected;
+        } else if selected {
+            // deselecting on exclusive choice has no effect
+            flags.iter_mut().enumerate().for_each(|(i, x)| *x = i == index as usize);
+        }
+        Self::store_selected(jvm, this, flags).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_selected_flags<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, mut flags: ClassInstanceRef<Array<bool>>) -> JvmResult<i32> {
+        if flags.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "selectedArray is null").await);
+        }
+
+        let selected = Self::load_selected(jvm, this).await?;
+        let length = jvm.array_length(&flags).await?;
+        if length < selected.len() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "selectedArray is too short").await);
+        }
+
+        let count = selected.iter().filter(|&&x| x).count();
+        let mut result = selected;
+        result.resize(length, false);
+        jvm.store_array(&mut flags, 0, result).await?;
+
+        Ok(count as _)
+    }
+
+    pub async fn set_selected_flags<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, flags: ClassInstanceRef<Array<bool>>) -> JvmResult<()> {
+        if flags.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "selectedArray is null").await);
+        }
+
+        let size = Self::size(jvm, this).await? as usize;
+        if jvm.array_length(&flags).await? < size {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "selectedArray is too short").await);
+        }
+
+        let mut selected: Vec<bool> = jvm.load_array(&flags, 0, size).await?;
+        if Self::choice_type(jvm, this).await? != MULTIPLE && size != 0 {
+            let first = selected.iter().position(|&x| x).unwrap_or(0);
+            selected.iter_mut().enumerate().for_each(|(i, x)| *x = i == first);
+        }
+        Self::store_selected(jvm, this, selected).await?;
+
+        Ok(())
+    }
+
+    pub async fn cursor<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<i32> {
+        jvm.get_field(this, "cursor", "I").await
+    }
+
+    // returns false if cursor is already at the end
+    pub async fn move_cursor<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, delta: i32) -> JvmResult<bool> {
+        let size = Self::size(jvm, this).await?;
+        let cursor = Self::cursor(jvm, this).await?;
+
+        let new_cursor = cursor + delta;
+        if new_cursor < 0 || new_cursor >= size {
+            return Ok(false);
+        }
+
+        jvm.put_field(&mut this.clone(), "cursor", "I", new_cursor).await?;
+
+        // selection follows cursor on implicit list
+        if Self::choice_type(jvm, this).await? == IMPLICIT {
+            Self::set_selected_index(jvm, this, new_cursor, true).await?;
+        }
+
+        Ok(true)
+    }
+
+    pub async fn select_cursor<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<()> {
+        let size = Self::size(jvm, this).await?;
+        let cursor = Self::cursor(jvm, this).await?;
+        if cursor >= size {
+            return Ok(());
+        }
+
+        let selected = Self::is_selected(jvm, this, cursor).await?;
+        if Self::choice_type(jvm, this).await? == MULTIPLE {
+            Self::set_selected_index(jvm, this, cursor, !selected).await
+        } else {
+            Self::set_selected_index(jvm, this, cursor, true).await
+        }
+    }
+
+    pub async fn element_height<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32) -> JvmResult<i32> {
+        let image = Self::get_image(jvm, this, index).await?;
+        if image.is_null() {
+            return Ok(LINE_HEIGHT);
+        }
+
+        let image_height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+
+        Ok(image_height.max(LINE_HEIGHT))
+    }
+
+    pub async fn elements_height<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<i32> {
+        let mut height = 0;
+        for i in 0..Self::size(jvm, this).await? {
+            height += Self::element_height(jvm, this, i).await?;
+        }
+
+        Ok(height)
+    }
+
+    // paints element with choice mark and highlight if it's under cursor
+    pub async fn paint_element<T>(
+        jvm: &Jvm,
+        this: &ClassInstanceRef<T>,
+        graphics: &ClassInstanceRef<Graphics>,
+        index: i32,
+        x: i32,
+        y: i32,
+        width: i32,
+        highlight: bool,
+    ) -> JvmResult<i32> {
+        let height = Self::element_height(jvm, this, index).await?;
+        let choice_type = Self::choice_type(jvm, this).await?;
+        let selected = Self::is_selected(jvm, this, index).await?;
+
+        let string = Self::get_string(jvm, this, index).await?;
+        let string = renderer::rust_string(jvm, &string).await?;
+
+        let image = Self::get_image(jvm, this, index).await?;
+        let image = if image.is_null() { None } else { Some(Image::image(jvm, &image).await?) };
+
+        let mut renderer = Renderer::new(jvm, graphics).await?;
+        let color = if highlight {
+            renderer.fill_rect(x, y, width, height, HIGHLIGHT);
+            HIGHLIGHT_TEXT
+        } else {
+            FOREGROUND
+        };
+
+        let mut text_x = x + PADDING;
+        if choice_type == EXCLUSIVE || choice_type == MULTIPLE {
+            renderer.draw_choice_mark(text_x, y, choice_type == EXCLUSIVE, selected, color);
+            text_x += LINE_HEIGHT;
+        }
+        if let Some(image) = image {
+            renderer.draw_image(&*image, text_x, y);
+            text_x += image.width() as i32 + PADDING;
+        }
+        renderer.draw_text(&string, text_x, y + (height - LINE_HEIGHT) / 2, TextAlignment::Left, color);
+
+        Ok(height)
+    }
+
+    async fn check_index<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, index: i32) -> JvmResult<()> {
+        let size = Self::size(jvm, this).await?;
+        if index < 0 || index >= size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "index out of range").await);
+        }
+
+        Ok(())
+    }
+
+    async fn load_selected<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<Vec<bool>> {
+        let selected: ClassInstanceRef<Array<bool>> = jvm.get_field(this, "selected", "[Z").await?;
+        let length = jvm.array_length(&selected).await?;
+
+        jvm.load_array(&selected, 0, length).await
+    }
+
+    async fn store_selected<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, selected: Vec<bool>) -> JvmResult<()> {
+        let mut array = jvm.instantiate_array("Z", selected.len()).await?;
+        jvm.store_array(&mut array, 0, selected).await?;
+
+        jvm.put_field(&mut this.clone(), "selected", "[Z", array).await
+    }
+}
@@ -1,12 +1,20 @@
 use alloc::vec;
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_runtime::classes::java::lang::String;
 use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::canvas::TextAlignment;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
-use crate::classes::javax::microedition::lcdui::Image;
+use crate::classes::{
+    javax::microedition::lcdui::{
+        Graphics, Image, Item,
+        choice::{self, Choice},
+        renderer::{self, BORDER, LINE_HEIGHT, PADDING, Renderer},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
 
 // class javax.microedition.lcdui.ChoiceGroup
 pub struct ChoiceGroup;
@@ -16,7 +24,7 @@ impl ChoiceGroup {
         WieJavaClassProto {
             name: "javax/microedition/lcdui/ChoiceGroup",
             parent_class: Some("javax/microedition/lcdui/Item"),
-            interfaces: vec![],
+            interfaces: vec!["javax/microedition/lcdui/Choice"],
             methods: vec![
                 JavaMethodProto::new("<init>", "(Ljava/lang/String;I)V", Self::init, Default::default()),
                 JavaMethodProto::new(
@@ -25,8 +33,53 @@ impl ChoiceGroup {
                     Self::init_with_elements,
                     Default::default(),
                 ),
+                JavaMethodProto::new(
+                    "append",
+                    "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;)I",
+                    Self::append,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "insert",
+                    "(ILjava/lang/String;Ljavax/microedition/lcdui/Image;)V",
+                    Self::insert,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "set",
+                    "(ILjava/lang/String;Ljavax/microedition/lcdui/Image;)V",
+                    Self::set,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("delete", "(I)V", Self::delete, Default::default()),
+                JavaMethodProto::new("deleteAll", "()V", Self::delete_all, Default::default()),
+                JavaMethodProto::new("getString", "(I)Ljava/lang/String;", Self::get_string, Default::default()),
+                JavaMethodProto::new("getImage", "(I)Ljavax/microedition/lcdui/Image;", Self::get_image, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("isSelected", "(I)Z", Self::is_selected, Default::default()),
+                JavaMethodProto::new("getSelectedIndex", "()I", Self::get_selected_index, Default::default()),
+                JavaMethodProto::new("setSelectedIndex", "(IZ)V", Self::set_selected_index, Default::default()),
+                JavaMethodProto::new("getSelectedFlags", "([Z)I", Self::get_selected_flags, Default::default()),
+                JavaMethodProto::new("setSelectedFlags", "([Z)V", Self::set_selected_flags, Default::default()),
+                // wie private methods
+                JavaMethodProto::new("getItemHeight", "(I)I", Self::get_item_height, Default::default()),
+                JavaMethodProto::new(
+                    "paintItem",
+                    "(Ljavax/microedition/lcdui/Graphics;IIIZ)V",
+                    Self::paint_item,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("isItemFocusable", "()Z", Self::is_item_focusable, Default::default()),
+                JavaMethodProto::new("handleItemKey", "(II)Z", Self::handle_item_key, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("choiceType", "I", Default::default()),
+                JavaFieldProto::new("strings", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("images", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("selected", "[Z", Default::default()),
+                JavaFieldProto::new("cursor", "I", Default::default()),
+                JavaFieldProto::new("popupOpen", "Z", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
@@ -40,7 +93,7 @@ impl ChoiceGroup {
     ) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.ChoiceGroup::<init>({this:?}, {label:?}, {choice_type})");
 
-        let string_elements = jvm.instantiate_array("[Ljava/lang/String;", 0).await?;
+        let string_elements = jvm.instantiate_array("Ljava/lang/String;", 0).await?;
 
         let _: () = jvm
             .invoke_special(
@@ -58,7 +111,7 @@ impl ChoiceGroup {
     async fn init_with_elements(
         jvm: &Jvm,
         _context: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+        mut this: ClassInstanceRef<Self>,
         label: ClassInstanceRef<String>,
         choice_type: i32,
         string_elements: ClassInstanceRef<Array<ClassInstanceRef<String>>>,
@@ -68,6 +121,310 @@ impl ChoiceGroup {
 
         let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Item", "<init>", "()V", ()).await?;
 
+        // implicit is only allowed on List
+        if choice_type == choice::IMPLICIT {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid choiceType").await);
+        }
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        Choice::init_elements(jvm, &this, choice_type, string_elements, image_elements).await?;
+
+        Ok(())
+    }
+
+    async fn append(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::append({this:?}, {string:?}, {image:?})");
+
+        let index = Choice::append(jvm, &this, string, image).await?;
+        renderer::request_redraw(context);
+
+        Ok(index)
+    }
+
+    async fn insert(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        index: i32,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::insert({this:?}, {index}, {string:?}, {image:?})");
+
+        Choice::insert(jvm, &this, index, string, image).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn set(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        index: i32,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::set({this:?}, {index}, {string:?}, {image:?})");
+
+        Choice::set(jvm, &this, index, string, image).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::delete({this:?}, {index})");
+
+        Choice::delete(jvm, &this, index).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete_all(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::deleteAll({this:?})");
+
+        Choice::delete_all(jvm, &this).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_string(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::getString({this:?}, {index})");
+
+        Choice::get_string(jvm, &this, index).await
+    }
+
+    async fn get_image(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<Image>> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::getImage({this:?}, {index})");
+
+        Choice::get_image(jvm, &this, index).await
+    }
+
+    async fn size(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::size({this:?})");
+
+        Choice::size(jvm, &this).await
+    }
+
+    async fn is_selected(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::isSelected({this:?}, {index})");
+
+        Choice::is_selected(jvm, &this, index).await
+    }
+
+    async fn get_selected_index(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::getSelectedIndex({this:?})");
+
+        Choice::get_selected_index(jvm, &this).await
+    }
+
+    async fn set_selected_index(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32, selected: bool) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::setSelectedIndex({this:?}, {index}, {selected})");
+
+        Choice::set_selected_index(jvm, &this, index, selected).await?;
+        renderer::request_redraw(context);
+
         Ok(())
     }
+
+    async fn get_selected_flags(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        flags: ClassInstanceRef<Array<bool>>,
+    ) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::getSelectedFlags({this:?}, {flags:?})");
+
+        Choice::get_selected_flags(jvm, &this, flags).await
+    }
+
+    async fn set_selected_flags(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        flags: ClassInstanceRef<Array<bool>>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::setSelectedFlags({this:?}, {flags:?})");
+
+        Choice::set_selected_flags(jvm, &this, flags).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_item_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, width: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::getItemHeight({this:?}, {width})");
+
+        let label_height = Item::label_height(jvm, &this, width).await?;
+
+        let elements_height = if Self::is_popup_closed(jvm, &this).await? {
+            LINE_HEIGHT
+        } else {
+            Choice::elements_height(jvm, &this).await?
+        };
+
+        Ok(label_height + elements_height)
+    }
+
+    async fn paint_item(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        focused: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::paintItem({this:?}, {graphics:?}, {x}, {y}, {width}, {focused})");
+
+        let label_height = {
+            let mut renderer = Renderer::new(jvm, &graphics).await?;
+            Item::paint_label(jvm, &this, &mut renderer, x, y, width).await?
+        };
+        let mut y = y + label_height;
+
+        // closed popup only shows selected element with drop down marker
+        if Self::is_popup_closed(jvm, &this).await? {
+            let selected = Choice::get_selected_index(jvm, &this).await?;
+            if selected >= 0 {
+                Choice::paint_element(jvm, &this, &graphics, selected, x, y, width - LINE_HEIGHT, focused).await?;
+            }
+
+            let mut renderer = Renderer::new(jvm, &graphics).await?;
+            renderer.draw_rect(x, y, width - 1, LINE_HEIGHT - 1, BORDER);
+            renderer.draw_text("v", x + width - PADDING * 2, y, TextAlignment::Right, renderer::FOREGROUND);
+
+            return Ok(());
+        }
+
+        let cursor = Choice::cursor(jvm, &this).await?;
+        for i in 0..Choice::size(jvm, &this).await? {
+            y += Choice::paint_element(jvm, &this, &graphics, i, x, y, width, focused && i == cursor).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn is_item_focusable(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::isItemFocusable({this:?})");
+
+        Ok(true)
+    }
+
+    async fn handle_item_key(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        event_type: i32,
+        code: i32,
+    ) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.ChoiceGroup::handleItemKey({this:?}, {event_type}, {code})");
+
+        if matches!(KeyboardEventType::from_raw(event_type), KeyboardEventType::KeyReleased) {
+            return Ok(false);
+        }
+
+        let is_popup = Choice::choice_type(jvm, &this).await? == choice::POPUP;
+        let popup_open: bool = jvm.get_field(&this, "popupOpen", "Z").await?;
+
+        let consumed = match MIDPKeyCode::from_raw(code) {
+            MIDPKeyCode::FIRE if is_popup && !popup_open => {
+                let selected = Choice::get_selected_index(jvm, &this).await?;
+                jvm.put_field(&mut this, "cursor", "I", selected.max(0)).await?;
+                jvm.put_field(&mut this, "popupOpen", "Z", true).await?;
+
+                true
+            }
+            MIDPKeyCode::FIRE => {
+                Choice::select_cursor(jvm, &this).await?;
+                if is_popup {
+                    jvm.put_field(&mut this, "popupOpen", "Z", false).await?;
+                }
+                Item::notify_state_changed(jvm, &this).await?;
+
+                true
+            }
+            // open popup captures cursor
+            MIDPKeyCode::UP => Choice::move_cursor(jvm, &this, -1).await? || popup_open,
+            MIDPKeyCode::DOWN => Choice::move_cursor(jvm, &this, 1).await? || popup_open,
+            _ => false,
+        };
+
+        if consumed {
+            renderer::request_redraw(context);
+        }
+
+        Ok(consumed)
+    }
+
+    async fn is_popup_closed(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<bool> {
+        let popup_open: bool = jvm.get_field(this, "popupOpen", "Z").await?;
+
+        Ok(Choice::choice_type(jvm, this).await? == choice::POPUP && !popup_open)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::runtime::JavaLangString;
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    use crate::{classes::javax::microedition::lcdui::choice, get_protos};
+
+    #[test]
+    fn test_choice_group_selection() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            let label = JavaLangString::from_rust_string(&jvm, "label").await?;
+            let choice_group = jvm
+                .new_class(
+                    "javax/microedition/lcdui/ChoiceGroup",
+                    "(Ljava/lang/String;I)V",
+                    (label, choice::EXCLUSIVE),
+                )
+                .await?;
+
+            for text in ["a", "b", "c"] {
+                let text = JavaLangString::from_rust_string(&jvm, text).await?;
+                let _: i32 = jvm
+                    .invoke_virtual(
+                        &choice_group,
+                        "append",
+                        "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;)I",
+                        (text, None),
+                    )
+                    .await?;
+            }
+
+            // first element of exclusive choice is selected by default
+            let selected: i32 = jvm.invoke_virtual(&choice_group, "getSelectedIndex", "()I", ()).await?;
+            assert_eq!(selected, 0);
+
+            let _: () = jvm.invoke_virtual(&choice_group, "setSelectedIndex", "(IZ)V", (2, true)).await?;
+            let selected: i32 = jvm.invoke_virtual(&choice_group, "getSelectedIndex", "()I", ()).await?;
+            assert_eq!(selected, 2);
+
+            let is_selected: bool = jvm.invoke_virtual(&choice_group, "isSelected", "(I)Z", (0,)).await?;
+            assert!(!is_selected);
+
+            let _: () = jvm.invoke_virtual(&choice_group, "delete", "(I)V", (2,)).await?;
+            let size: i32 = jvm.invoke_virtual(&choice_group, "size", "()I", ()).await?;
+            assert_eq!(size, 2);
+
+            Ok(())
+        })
+    }
 }
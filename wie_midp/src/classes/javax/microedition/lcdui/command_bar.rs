@@ -0,0 +1,314 @@
+use alloc::{string::String as RustString, vec::Vec};
+
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::WieJvmContext;
+
+use crate::classes::{
+    javax::microedition::lcdui::{
+        Command, CommandListener, Graphics,
+        renderer::{self, BAR_BACKGROUND, BAR_TEXT, BORDER, FOREGROUND, HIGHLIGHT, HIGHLIGHT_TEXT, LINE_HEIGHT, PADDING, Renderer, TITLE_HEIGHT},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
+
+// soft key bar and overflow menu for commands added to displayable
+
+pub const SOFT_BAR_HEIGHT: i32 = TITLE_HEIGHT;
+
+const BACK: i32 = 2;
+const CANCEL: i32 = 3;
+const STOP: i32 = 6;
+const EXIT: i32 = 7;
+
+pub struct SoftKeys {
+    pub left: Option<ClassInstanceRef<Command>>,
+    pub right: Option<ClassInstanceRef<Command>>,
+    // commands in overflow menu, opened by left soft key if not empty
+    pub menu: Vec<ClassInstanceRef<Command>>,
+}
+
+pub async fn commands<T>(jvm: &Jvm, displayable: &ClassInstanceRef<T>) -> JvmResult<Vec<ClassInstanceRef<Command>>> {
+    let commands = jvm.get_field(displayable, "commands", "Ljava/util/Vector;").await?;
+    let size: i32 = jvm.invoke_virtual(&commands, "size", "()I", ()).await?;
+
+    let mut result = Vec::with_capacity(size as _);
+    for i in 0..size {
+        let command: ClassInstanceRef<Command> = jvm.invoke_virtual(&commands, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
+        result.push(command);
+    }
+
+    Ok(result)
+}
+
+// negative commands go to right soft key, and the rest goes to left soft key or to the menu if there are many
+pub async fn soft_keys<T>(jvm: &Jvm, displayable: &ClassInstanceRef<T>) -> JvmResult<SoftKeys> {
+    let mut commands_with_key = Vec::new();
+    for command in commands(jvm, displayable).await? {
+        let command_type: i32 = jvm.get_field(&command, "commandType", "I").await?;
+        let priority: i32 = jvm.get_field(&command, "priority", "I").await?;
+        let negative = matches!(command_type, BACK | CANCEL | STOP | EXIT);
+
+        commands_with_key.push((negative, priority, command));
+    }
+    // stable sort keeps the order of addition for same priority
+    commands_with_key.sort_by_key(|(_, priority, _)| *priority);
+
+    let right_index = commands_with_key.iter().position(|(negative, _, _)| *negative);
+    let mut right = right_index.map(|x| commands_with_key.remove(x).2);
+    let mut rest = commands_with_key.into_iter().map(|(_, _, command)| command).collect::<Vec<_>>();
+
+    if right.is_none() && rest.len() == 2 {
+        right = rest.pop();
+    }
+
+    Ok(if rest.len() > 1 {
+        SoftKeys {
+            left: None,
+            right,
+            menu: rest,
+        }
+    } else {
+        SoftKeys {
+            left: rest.pop(),
+            right,
+            menu: Vec::new(),
+        }
+    })
+}
+
+pub async fn bar_height<T>(jvm: &Jvm, displayable: &ClassInstanceRef<T>) -> JvmResult<i32> {
+    let fullscreen: bool = jvm.get_field(displayable, "isInFullScreenMode", "Z").await?;
+    if fullscreen || commands(jvm, displayable).await?.is_empty() {
+        return Ok(0);
+    }
+
+    Ok(SOFT_BAR_HEIGHT)
+}
+
+// screens reserve space for the bar, but canvas is painted under it to keep its size unchanged.
+// menu is still shown in full screen mode, as soft keys work without the bar
+pub async fn paint<T>(jvm: &Jvm, displayable: &ClassInstanceRef<T>, graphics: &ClassInstanceRef<Graphics>) -> JvmResult<()> {
+    let soft_keys = soft_keys(jvm, displayable).await?;
+    let bar_height = bar_height(jvm, displayable).await?;
+
+    let mut renderer = Renderer::new(jvm, graphics).await?;
+    let y = renderer.height() - bar_height;
+
+    if bar_height != 0 {
+        let left_label = if !soft_keys.menu.is_empty() {
+            "Menu".into()
+        } else {
+            label(jvm, &soft_keys.left).await?
+        };
+        let right_label = label(jvm, &soft_keys.right).await?;
+        let text_y = y + (bar_height - LINE_HEIGHT) / 2;
+
+        renderer.fill_rect(0, y, renderer.width(), bar_height, BAR_BACKGROUND);
+        renderer.draw_text(&left_label, PADDING * 2, text_y, TextAlignment::Left, BAR_TEXT);
+        renderer.draw_text(&right_label, renderer.width() - PADDING * 2, text_y, TextAlignment::Right, BAR_TEXT);
+    }
+
+    let menu_open: bool = jvm.get_field(displayable, "commandMenuOpen", "Z").await?;
+    if !menu_open || soft_keys.menu.is_empty() {
+        return Ok(());
+    }
+
+    let cursor: i32 = jvm.get_field(displayable, "commandMenuCursor", "I").await?;
+    let mut labels = Vec::with_capacity(soft_keys.menu.len());
+    for command in soft_keys.menu {
+        labels.push(label(jvm, &Some(command)).await?);
+    }
+
+    let width = labels.iter().map(|x| renderer::text_width(x)).max().unwrap_or(0) + PADDING * 4;
+    let height = labels.len() as i32 * LINE_HEIGHT + PADDING * 2;
+    let menu_y = y - height;
+
+    renderer.fill_rect(0, menu_y, width, height, renderer::BACKGROUND);
+    renderer.draw_rect(0, menu_y, width - 1, height - 1, BORDER);
+    for (i, label) in labels.iter().enumerate() {
+        let item_y = menu_y + PADDING + i as i32 * LINE_HEIGHT;
+        let color = if i as i32 == cursor {
+            renderer.fill_rect(1, item_y, width - 2, LINE_HEIGHT, HIGHLIGHT);
+            HIGHLIGHT_TEXT
+        } else {
+            FOREGROUND
+        };
+
+        renderer.draw_text(label, PADDING * 2, item_y, TextAlignment::Left, color);
+    }
+
+    Ok(())
+}
+
+// returns true if key is consumed by soft keys or menu
+pub async fn handle_key<T>(jvm: &Jvm, context: &mut WieJvmContext, displayable: &ClassInstanceRef<T>, event_type: i32, code: i32) -> JvmResult<bool> {
+    let soft_keys = soft_keys(jvm, displayable).await?;
+    let pressed = matches!(KeyboardEventType::from_raw(event_type), KeyboardEventType::KeyPressed);
+    let mut displayable = displayable.clone();
+
+    let menu_open: bool = jvm.get_field(&displayable, "commandMenuOpen", "Z").await?;
+    if menu_open && !soft_keys.menu.is_empty() {
+        if !pressed {
+            return Ok(true);
+        }
+
+        let count = soft_keys.menu.len() as i32;
+        let cursor: i32 = jvm.get_field(&displayable, "commandMenuCursor", "I").await?;
+
+        match MIDPKeyCode::from_raw(code) {
+            MIDPKeyCode::UP => {
+                jvm.put_field(&mut displayable, "commandMenuCursor", "I", (cursor + count - 1) % count)
+                    .await?
+            }
+            MIDPKeyCode::DOWN => jvm.put_field(&mut displayable, "commandMenuCursor", "I", (cursor + 1) % count).await?,
+            MIDPKeyCode::FIRE | MIDPKeyCode::LEFT_SOFT_KEY => {
+                jvm.put_field(&mut displayable, "commandMenuOpen", "Z", false).await?;
+
+                let command = soft_keys.menu[cursor.clamp(0, count - 1) as usize].clone();
+                dispatch(jvm, &displayable, command).await?;
+            }
+            MIDPKeyCode::RIGHT_SOFT_KEY | MIDPKeyCode::CLEAR => jvm.put_field(&mut displayable, "commandMenuOpen", "Z", false).await?,
+            _ => {}
+        }
+        renderer::request_redraw(context);
+
+        return Ok(true);
+    }
+
+    match MIDPKeyCode::from_raw(code) {
+        MIDPKeyCode::LEFT_SOFT_KEY if !soft_keys.menu.is_empty() => {
+            if pressed {
+                jvm.put_field(&mut displayable, "commandMenuOpen", "Z", true).await?;
+                jvm.put_field(&mut displayable, "commandMenuCursor", "I", 0).await?;
+                renderer::request_redraw(context);
+            }
+        }
+        MIDPKeyCode::LEFT_SOFT_KEY if soft_keys.left.is_some() => {
+            if pressed {
+                dispatch(jvm, &displayable, soft_keys.left.unwrap()).await?;
+            }
+        }
+        MIDPKeyCode::RIGHT_SOFT_KEY if soft_keys.right.is_some() => {
+            if pressed {
+                dispatch(jvm, &displayable, soft_keys.right.unwrap()).await?;
+            }
+        }
+        _ => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+// commandAction is called from event queue, not inside of key event handler
+pub async fn dispatch<T>(jvm: &Jvm, displayable: &ClassInstanceRef<T>, command: ClassInstanceRef<Command>) -> JvmResult<()> {
+    let listener: ClassInstanceRef<CommandListener> = jvm
+        .get_field(displayable, "commandListener", "Ljavax/microedition/lcdui/CommandListener;")
+        .await?;
+    if listener.is_null() {
+        return Ok(());
+    }
+
+    let event = jvm
+        .new_class(
+            "net/wie/CommandEvent",
+            "(Ljavax/microedition/lcdui/CommandListener;Ljavax/microedition/lcdui/Command;Ljavax/microedition/lcdui/Displayable;)V",
+            (listener, command, displayable.clone()),
+        )
+        .await?;
+
+    let event_queue = jvm
+        .invoke_static("net/wie/EventQueue", "getEventQueue", "()Lnet/wie/EventQueue;", ())
+        .await?;
+    let _: () = jvm
+        .invoke_virtual(&event_queue, "callSerially", "(Ljava/lang/Runnable;)V", (event,))
+        .await?;
+
+    Ok(())
+}
+
+async fn label(jvm: &Jvm, command: &Option<ClassInstanceRef<Command>>) -> JvmResult<RustString> {
+    match command {
+        Some(command) => {
+            let label = jvm.get_field(command, "label", "Ljava/lang/String;").await?;
+
+            renderer::rust_string(jvm, &label).await
+        }
+        None => Ok(RustString::new()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    use crate::{
+        classes::javax::microedition::lcdui::{Command, Form},
+        get_protos,
+    };
+
+    async fn add_command(
+        jvm: &Jvm,
+        form: &ClassInstanceRef<Form>,
+        label: &str,
+        command_type: i32,
+        priority: i32,
+    ) -> JvmResult<ClassInstanceRef<Command>> {
+        let label = JavaLangString::from_rust_string(jvm, label).await?;
+        let command: ClassInstanceRef<Command> = jvm
+            .new_class(
+                "javax/microedition/lcdui/Command",
+                "(Ljava/lang/String;II)V",
+                (label, command_type, priority),
+            )
+            .await?
+            .into();
+        let _: () = jvm
+            .invoke_virtual(form, "addCommand", "(Ljavax/microedition/lcdui/Command;)V", (command.clone(),))
+            .await?;
+
+        Ok(command)
+    }
+
+    #[test]
+    fn test_soft_keys() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            let title = JavaLangString::from_rust_string(&jvm, "title").await?;
+            let form: ClassInstanceRef<Form> = jvm
+                .new_class("javax/microedition/lcdui/Form", "(Ljava/lang/String;)V", (title,))
+                .await?
+                .into();
+
+            // EXIT goes to right soft key even if added first
+            let exit = add_command(&jvm, &form, "Exit", 7, 1).await?;
+            let ok = add_command(&jvm, &form, "OK", 4, 1).await?;
+
+            let soft_keys = super::soft_keys(&jvm, &form).await?;
+            assert!(soft_keys.menu.is_empty());
+
+            let left: bool = jvm
+                .invoke_virtual(&soft_keys.left.unwrap(), "equals", "(Ljava/lang/Object;)Z", (ok,))
+                .await?;
+            let right: bool = jvm
+                .invoke_virtual(&soft_keys.right.unwrap(), "equals", "(Ljava/lang/Object;)Z", (exit,))
+                .await?;
+            assert!(left && right);
+
+            // more positive commands than left soft key can hold go to menu
+            add_command(&jvm, &form, "Help", 5, 2).await?;
+
+            let soft_keys = super::soft_keys(&jvm, &form).await?;
+            assert!(soft_keys.left.is_none());
+            assert!(soft_keys.right.is_some());
+            assert_eq!(soft_keys.menu.len(), 2);
+
+            Ok(())
+        })
+    }
+}
@@ -1,14 +1,14 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec};
 
-use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_class_proto::{JavaFieldProto, JavaMethodProto, MethodBody};
 use java_constants::MethodAccessFlags;
 use java_runtime::classes::java::lang::Runnable;
-use jvm::{ClassInstanceRef, JavaError, Jvm, Result as JvmResult, runtime::JavaLangString};
+use jvm::{ClassInstanceRef, JavaError, JavaValue, Jvm, Result as JvmResult, runtime::JavaLangString};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
 use crate::classes::javax::microedition::{
-    lcdui::{Displayable, Graphics, Image},
+    lcdui::{Alert, Displayable, Graphics, Image, command_bar, renderer},
     midlet::MIDlet,
 };
 
@@ -29,6 +29,12 @@ impl Display {
                     Self::set_current,
                     Default::default(),
                 ),
+                JavaMethodProto::new(
+                    "setCurrent",
+                    "(Ljavax/microedition/lcdui/Alert;Ljavax/microedition/lcdui/Displayable;)V",
+                    Self::set_current_alert,
+                    Default::default(),
+                ),
                 JavaMethodProto::new(
                     "getCurrent",
                     "()Ljavax/microedition/lcdui/Displayable;",
@@ -48,8 +54,12 @@ impl Display {
                 // wie private methods...
                 JavaMethodProto::new("handlePaintEvent", "()V", Self::handle_paint_event, Default::default()),
                 JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+                JavaMethodProto::new("handlePointerEvent", "(III)V", Self::handle_pointer_event, Default::default()),
                 JavaMethodProto::new("handleNotifyEvent", "(III)V", Self::handle_notify_event, Default::default()),
+                JavaMethodProto::new("handleHideEvent", "()V", Self::handle_hide_event, Default::default()),
+                JavaMethodProto::new("handleShowEvent", "()V", Self::handle_show_event, Default::default()),
                 JavaMethodProto::new("setFullscreen", "(Z)V", Self::set_fullscreen, Default::default()),
+                JavaMethodProto::new("dismissAlert", "(I)V", Self::dismiss_alert, Default::default()),
             ],
             fields: vec![
                 JavaFieldProto::new("isInFullScreenMode", "Z", Default::default()),
@@ -58,6 +68,8 @@ impl Display {
                 JavaFieldProto::new("screenGraphics", "Ljavax/microedition/lcdui/Graphics;", Default::default()),
                 JavaFieldProto::new("width", "I", Default::default()),
                 JavaFieldProto::new("height", "I", Default::default()),
+                JavaFieldProto::new("alertReturn", "Ljavax/microedition/lcdui/Displayable;", Default::default()),
+                JavaFieldProto::new("alertSerial", "I", Default::default()),
             ],
             access_flags: Default::default(),
         }
@@ -133,20 +145,88 @@ impl Display {
 
     async fn set_current(
         jvm: &Jvm,
-        _context: &mut WieJvmContext,
+        context: &mut WieJvmContext,
         mut this: ClassInstanceRef<Self>,
         displayable: ClassInstanceRef<Displayable>,
     ) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Display::setCurrent({:?}, {:?})", &this, displayable);
 
-        let old_displayable: ClassInstanceRef<Displayable> = jvm
+        if !displayable.is_null() && jvm.is_instance(&**displayable, "javax/microedition/lcdui/Alert") {
+            // alert returns to the screen below it, which is the previous alert's return if alerts are stacked
+            let mut next: ClassInstanceRef<Displayable> = jvm.get_field(&this, "alertReturn", "Ljavax/microedition/lcdui/Displayable;").await?;
+            if next.is_null() {
+                next = jvm
+                    .get_field(&this, "currentDisplayable", "Ljavax/microedition/lcdui/Displayable;")
+                    .await?;
+            }
+
+            return Self::show_alert(jvm, context, this, displayable, next).await;
+        }
+
+        jvm.put_field(&mut this, "alertReturn", "Ljavax/microedition/lcdui/Displayable;", None)
+            .await?;
+        Self::replace_current(jvm, context, this, displayable).await
+    }
+
+    async fn set_current_alert(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        alert: ClassInstanceRef<Alert>,
+        next: ClassInstanceRef<Displayable>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Display::setCurrent({this:?}, {alert:?}, {next:?})");
+
+        if alert.is_null() || next.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "alert or nextDisplayable is null").await);
+        }
+        if jvm.is_instance(&**next, "javax/microedition/lcdui/Alert") {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "nextDisplayable is an Alert").await);
+        }
+
+        Self::show_alert(jvm, context, this, alert, next).await
+    }
+
+    async fn show_alert<T>(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        alert: ClassInstanceRef<T>,
+        next: ClassInstanceRef<Displayable>,
+    ) -> JvmResult<()> {
+        let serial: i32 = jvm.get_field(&this, "alertSerial", "I").await?;
+        let serial = serial.wrapping_add(1);
+
+        jvm.put_field(&mut this, "alertSerial", "I", serial).await?;
+        jvm.put_field(&mut this, "alertReturn", "Ljavax/microedition/lcdui/Displayable;", next)
+            .await?;
+
+        Self::replace_current(jvm, context, this, alert.clone()).await?;
+
+        let timeout: i32 = jvm.invoke_virtual(&alert, "getTimeout", "()I", ()).await?;
+        if timeout > 0 {
+            context.spawn(jvm, Box::new(AlertTimeoutRunner { serial, timeout }))?;
+        }
+
+        Ok(())
+    }
+
+    async fn replace_current<T>(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        displayable: ClassInstanceRef<T>,
+    ) -> JvmResult<()> {
+        let mut old_displayable: ClassInstanceRef<Displayable> = jvm
             .get_field(&this, "currentDisplayable", "Ljavax/microedition/lcdui/Displayable;")
             .await?;
 
         if !old_displayable.is_null() {
+            let _: () = jvm.invoke_virtual(&old_displayable, "handleHideEvent", "()V", ()).await?;
             let _: () = jvm
                 .invoke_virtual(&old_displayable, "setDisplay", "(Ljavax/microedition/lcdui/Display;)V", (None,))
                 .await?;
+            jvm.put_field(&mut old_displayable, "commandMenuOpen", "Z", false).await?;
         }
 
         jvm.put_field(
@@ -157,6 +237,10 @@ impl Display {
         )
         .await?;
 
+        if displayable.is_null() {
+            return Ok(());
+        }
+
         let _: () = jvm
             .invoke_virtual(&displayable, "setDisplay", "(Ljavax/microedition/lcdui/Display;)V", (this.clone(),))
             .await?;
@@ -164,9 +248,27 @@ impl Display {
         let fullscreen_mode: bool = jvm.get_field(&displayable, "isInFullScreenMode", "Z").await?;
         jvm.put_field(&mut this, "isInFullScreenMode", "Z", fullscreen_mode).await?;
 
+        let _: () = jvm.invoke_virtual(&displayable, "handleShowEvent", "()V", ()).await?;
+        renderer::request_redraw(context);
+
         Ok(())
     }
 
+    async fn dismiss_alert(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, serial: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Display::dismissAlert({this:?}, {serial})");
+
+        // alert may have been dismissed or replaced already
+        let current_serial: i32 = jvm.get_field(&this, "alertSerial", "I").await?;
+        let next: ClassInstanceRef<Displayable> = jvm.get_field(&this, "alertReturn", "Ljavax/microedition/lcdui/Displayable;").await?;
+        if current_serial != serial || next.is_null() {
+            return Ok(());
+        }
+
+        jvm.put_field(&mut this, "alertReturn", "Ljavax/microedition/lcdui/Displayable;", None)
+            .await?;
+        Self::replace_current(jvm, context, this, next).await
+    }
+
     async fn get_current(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Displayable>> {
         tracing::debug!("javax.microedition.lcdui.Display::getCurrent({this:?})");
 
@@ -191,7 +293,7 @@ impl Display {
         Ok(false)
     }
 
-    async fn handle_key_event(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
+    async fn handle_key_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
         tracing::debug!(
             "javax.microedition.lcdui.Display::handleKeyEvent({:?}, {:?}, {})",
             &this,
@@ -204,6 +306,11 @@ impl Display {
             .await?;
 
         if !current_displayable.is_null() {
+            // soft keys mapped to commands don't reach displayable
+            if command_bar::handle_key(jvm, context, &current_displayable, event_type, code).await? {
+                return Ok(());
+            }
+
             let result: JvmResult<()> = jvm
                 .invoke_virtual(&current_displayable, "handleKeyEvent", "(II)V", (event_type, code))
                 .await;
@@ -216,6 +323,33 @@ impl Display {
         Ok(())
     }
 
+    async fn handle_pointer_event(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        event_type: i32,
+        x: i32,
+        y: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Display::handlePointerEvent({this:?}, {event_type}, {x}, {y})");
+
+        let current_displayable: ClassInstanceRef<Displayable> = jvm
+            .get_field(&this, "currentDisplayable", "Ljavax/microedition/lcdui/Displayable;")
+            .await?;
+
+        if !current_displayable.is_null() {
+            let result: JvmResult<()> = jvm
+                .invoke_virtual(&current_displayable, "handlePointerEvent", "(III)V", (event_type, x, y))
+                .await;
+
+            if let Err(x) = result {
+                Self::handle_exception(jvm, x).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn handle_paint_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Display::handlePaintEvent({:?})", &this);
 
@@ -226,7 +360,23 @@ impl Display {
         if !current_displayable.is_null() {
             let screen_graphics: ClassInstanceRef<Graphics> = jvm.get_field(&this, "screenGraphics", "Ljavax/microedition/lcdui/Graphics;").await?;
 
-            // TODO draw title and bottom soft bar if not fullscreen
+            // alert is drawn over the displayable it returns to
+            let alert_return: ClassInstanceRef<Displayable> = jvm.get_field(&this, "alertReturn", "Ljavax/microedition/lcdui/Displayable;").await?;
+            if !alert_return.is_null() {
+                let result: JvmResult<()> = jvm
+                    .invoke_virtual(
+                        &alert_return,
+                        "handlePaintEvent",
+                        "(Ljavax/microedition/lcdui/Graphics;)V",
+                        (screen_graphics.clone(),),
+                    )
+                    .await;
+                if let Err(x) = result {
+                    Self::handle_exception(jvm, x).await?;
+                }
+
+                let _: () = jvm.invoke_virtual(&screen_graphics, "reset", "()V", ()).await?;
+            }
 
             let result: JvmResult<()> = jvm
                 .invoke_virtual(
@@ -242,6 +392,8 @@ impl Display {
 
             let _: () = jvm.invoke_virtual(&screen_graphics, "reset", "()V", ()).await?;
 
+            command_bar::paint(jvm, &current_displayable, &screen_graphics).await?;
+
             let screen_image: ClassInstanceRef<Image> = jvm.get_field(&this, "screenImage", "Ljavax/microedition/lcdui/Image;").await?;
             let image = Image::image(jvm, &screen_image).await?;
 
@@ -285,6 +437,45 @@ impl Display {
         Ok(())
     }
 
+    async fn handle_hide_event(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Display::handleHideEvent({this:?})");
+
+        let current_displayable: ClassInstanceRef<Displayable> = jvm
+            .get_field(&this, "currentDisplayable", "Ljavax/microedition/lcdui/Displayable;")
+            .await?;
+
+        if !current_displayable.is_null() {
+            let result: JvmResult<()> = jvm.invoke_virtual(&current_displayable, "handleHideEvent", "()V", ()).await;
+
+            if let Err(x) = result {
+                Self::handle_exception(jvm, x).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_show_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Display::handleShowEvent({this:?})");
+
+        let current_displayable: ClassInstanceRef<Displayable> = jvm
+            .get_field(&this, "currentDisplayable", "Ljavax/microedition/lcdui/Displayable;")
+            .await?;
+
+        if !current_displayable.is_null() {
+            let result: JvmResult<()> = jvm.invoke_virtual(&current_displayable, "handleShowEvent", "()V", ()).await;
+
+            if let Err(x) = result {
+                Self::handle_exception(jvm, x).await?;
+            }
+        }
+
+        // screen may have been overwritten while we were in background
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
     pub async fn screen_graphics(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Graphics>> {
         jvm.get_field(this, "screenGraphics", "Ljavax/microedition/lcdui/Graphics;").await
     }
@@ -327,3 +518,27 @@ impl Display {
         Ok(())
     }
 }
+
+struct AlertTimeoutRunner {
+    serial: i32,
+    timeout: i32,
+}
+
+#[async_trait::async_trait]
+impl MethodBody<JavaError, WieJvmContext> for AlertTimeoutRunner {
+    async fn call(&self, jvm: &Jvm, context: &mut WieJvmContext, _args: Box<[JavaValue]>) -> Result<JavaValue, JavaError> {
+        jvm.attach_thread()?;
+
+        context.system().sleep(self.timeout as _).await;
+
+        let midlet: ClassInstanceRef<MIDlet> = jvm
+            .get_static_field("javax/microedition/midlet/MIDlet", "currentMIDlet", "Ljavax/microedition/midlet/MIDlet;")
+            .await?;
+        let display = MIDlet::display(jvm, &midlet).await?;
+        let _: () = jvm.invoke_virtual(&display, "dismissAlert", "(I)V", (self.serial,)).await?;
+
+        jvm.detach_thread()?;
+
+        Ok(JavaValue::Void)
+    }
+}
@@ -1,11 +1,12 @@
 use alloc::{format, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
-use crate::classes::javax::microedition::lcdui::{Command, CommandListener, Display, Graphics};
+use crate::classes::javax::microedition::lcdui::{Command, CommandListener, Display, Graphics, Ticker, command_bar, renderer};
 
 // class javax.microedition.lcdui.Displayable
 pub struct Displayable;
@@ -24,6 +25,12 @@ impl Displayable {
                     Self::add_command,
                     Default::default(),
                 ),
+                JavaMethodProto::new(
+                    "removeCommand",
+                    "(Ljavax/microedition/lcdui/Command;)V",
+                    Self::remove_command,
+                    Default::default(),
+                ),
                 JavaMethodProto::new(
                     "setCommandListener",
                     "(Ljavax/microedition/lcdui/CommandListener;)V",
@@ -32,6 +39,11 @@ impl Displayable {
                 ),
                 JavaMethodProto::new("getWidth", "()I", Self::get_width, Default::default()),
                 JavaMethodProto::new("getHeight", "()I", Self::get_height, Default::default()),
+                JavaMethodProto::new("getTitle", "()Ljava/lang/String;", Self::get_title, Default::default()),
+                JavaMethodProto::new("setTitle", "(Ljava/lang/String;)V", Self::set_title, Default::default()),
+                JavaMethodProto::new("getTicker", "()Ljavax/microedition/lcdui/Ticker;", Self::get_ticker, Default::default()),
+                JavaMethodProto::new("setTicker", "(Ljavax/microedition/lcdui/Ticker;)V", Self::set_ticker, Default::default()),
+                JavaMethodProto::new("isShown", "()Z", Self::is_shown, Default::default()),
                 // wie private methods...
                 JavaMethodProto::new(
                     "setDisplay",
@@ -40,6 +52,7 @@ impl Displayable {
                     Default::default(),
                 ),
                 JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+                JavaMethodProto::new("handlePointerEvent", "(III)V", Self::handle_pointer_event, Default::default()),
                 JavaMethodProto::new(
                     "handlePaintEvent",
                     "(Ljavax/microedition/lcdui/Graphics;)V",
@@ -47,43 +60,97 @@ impl Displayable {
                     Default::default(),
                 ),
                 JavaMethodProto::new("handleNotifyEvent", "(III)V", Self::handle_notify_event, Default::default()),
+                JavaMethodProto::new("handleHideEvent", "()V", Self::handle_hide_event, Default::default()),
+                JavaMethodProto::new("handleShowEvent", "()V", Self::handle_show_event, Default::default()),
             ],
             fields: vec![
                 JavaFieldProto::new("currentDisplay", "Ljavax/microedition/lcdui/Display;", Default::default()),
                 JavaFieldProto::new("isInFullScreenMode", "Z", Default::default()),
+                JavaFieldProto::new("title", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("ticker", "Ljavax/microedition/lcdui/Ticker;", Default::default()),
+                JavaFieldProto::new("commands", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("commandListener", "Ljavax/microedition/lcdui/CommandListener;", Default::default()),
+                JavaFieldProto::new("commandMenuOpen", "Z", Default::default()),
+                JavaFieldProto::new("commandMenuCursor", "I", Default::default()),
             ],
             access_flags: Default::default(),
         }
     }
 
-    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Displayable::<init>({:?})", &this);
 
         let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
 
+        let commands = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "commands", "Ljava/util/Vector;", commands).await?;
+
         Ok(())
     }
 
-    async fn add_command(
-        _jvm: &Jvm,
-        _context: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+    async fn add_command(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, command: ClassInstanceRef<Command>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::addCommand({this:?}, {command:?})");
+
+        if command.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "command is null").await);
+        }
+
+        if Self::find_command(jvm, &this, &command).await?.is_some() {
+            return Ok(());
+        }
+
+        let commands = jvm.get_field(&this, "commands", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&commands, "addElement", "(Ljava/lang/Object;)V", (command,)).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn remove_command(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
         command: ClassInstanceRef<Command>,
     ) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.lcdui.Displayable::addCommand({this:?}, {command:?})");
+        tracing::debug!("javax.microedition.lcdui.Displayable::removeCommand({this:?}, {command:?})");
+
+        if command.is_null() {
+            return Ok(());
+        }
+
+        if let Some(index) = Self::find_command(jvm, &this, &command).await? {
+            let commands = jvm.get_field(&this, "commands", "Ljava/util/Vector;").await?;
+            let _: () = jvm.invoke_virtual(&commands, "removeElementAt", "(I)V", (index,)).await?;
+
+            jvm.put_field(&mut this, "commandMenuOpen", "Z", false).await?;
+            renderer::request_redraw(context);
+        }
 
         Ok(())
     }
 
     async fn set_command_listener(
-        _jvm: &Jvm,
+        jvm: &Jvm,
         _context: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+        mut this: ClassInstanceRef<Self>,
         listener: ClassInstanceRef<CommandListener>,
     ) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.lcdui.Displayable::setCommandListener({this:?}, {listener:?})");
+        tracing::debug!("javax.microedition.lcdui.Displayable::setCommandListener({this:?}, {listener:?})");
 
-        Ok(())
+        jvm.put_field(&mut this, "commandListener", "Ljavax/microedition/lcdui/CommandListener;", listener)
+            .await
+    }
+
+    async fn find_command(jvm: &Jvm, this: &ClassInstanceRef<Self>, command: &ClassInstanceRef<Command>) -> JvmResult<Option<i32>> {
+        let commands = command_bar::commands(jvm, this).await?;
+        for (i, x) in commands.iter().enumerate() {
+            let equals: bool = jvm.invoke_virtual(x, "equals", "(Ljava/lang/Object;)Z", (command.clone(),)).await?;
+            if equals {
+                return Ok(Some(i as _));
+            }
+        }
+
+        Ok(None)
     }
 
     async fn set_display(
@@ -128,12 +195,64 @@ impl Displayable {
         Ok(height)
     }
 
+    async fn get_title(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::getTitle({this:?})");
+
+        jvm.get_field(&this, "title", "Ljava/lang/String;").await
+    }
+
+    async fn set_title(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, title: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::setTitle({this:?}, {title:?})");
+
+        jvm.put_field(&mut this, "title", "Ljava/lang/String;", title).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_ticker(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Ticker>> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::getTicker({this:?})");
+
+        jvm.get_field(&this, "ticker", "Ljavax/microedition/lcdui/Ticker;").await
+    }
+
+    async fn set_ticker(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, ticker: ClassInstanceRef<Ticker>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::setTicker({this:?}, {ticker:?})");
+
+        jvm.put_field(&mut this, "ticker", "Ljavax/microedition/lcdui/Ticker;", ticker).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn is_shown(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::isShown({this:?})");
+
+        // display is only set while we're current displayable
+        let display: ClassInstanceRef<Display> = jvm.get_field(&this, "currentDisplay", "Ljavax/microedition/lcdui/Display;").await?;
+
+        Ok(!display.is_null())
+    }
+
     async fn handle_key_event(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Displayable::handleKeyEvent({this:?}, {event_type}, {code})");
 
         Ok(())
     }
 
+    async fn handle_pointer_event(
+        _jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        event_type: i32,
+        x: i32,
+        y: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::handlePointerEvent({this:?}, {event_type}, {x}, {y})");
+
+        Ok(())
+    }
+
     async fn handle_paint_event(
         _jvm: &Jvm,
         _context: &mut WieJvmContext,
@@ -160,4 +279,16 @@ impl Displayable {
 
         Ok(())
     }
+
+    async fn handle_hide_event(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::handleHideEvent({this:?})");
+
+        Ok(())
+    }
+
+    async fn handle_show_event(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Displayable::handleShowEvent({this:?})");
+
+        Ok(())
+    }
 }
@@ -1,12 +1,18 @@
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
-use java_class_proto::JavaMethodProto;
-use java_runtime::classes::java::lang::String;
-use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::{Object, String};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
-use crate::classes::javax::microedition::lcdui::Item;
+use crate::classes::{
+    javax::microedition::lcdui::{
+        Graphics, Image, Item, ItemStateListener,
+        renderer::{self, HIGHLIGHT, LINE_HEIGHT, PADDING, Renderer},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
 
 // class javax.microedition.lcdui.Form
 pub struct Form;
@@ -19,10 +25,48 @@ impl Form {
             interfaces: vec![],
             methods: vec![
                 JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/String;[Ljavax/microedition/lcdui/Item;)V",
+                    Self::init_with_items,
+                    Default::default(),
+                ),
                 JavaMethodProto::new("append", "(Ljavax/microedition/lcdui/Item;)I", Self::append, Default::default()),
                 JavaMethodProto::new("append", "(Ljava/lang/String;)I", Self::append_string, Default::default()),
+                JavaMethodProto::new("append", "(Ljavax/microedition/lcdui/Image;)I", Self::append_image, Default::default()),
+                JavaMethodProto::new("insert", "(ILjavax/microedition/lcdui/Item;)V", Self::insert, Default::default()),
+                JavaMethodProto::new("set", "(ILjavax/microedition/lcdui/Item;)V", Self::set, Default::default()),
+                JavaMethodProto::new("get", "(I)Ljavax/microedition/lcdui/Item;", Self::get, Default::default()),
+                JavaMethodProto::new("delete", "(I)V", Self::delete, Default::default()),
+                JavaMethodProto::new("deleteAll", "()V", Self::delete_all, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new(
+                    "setItemStateListener",
+                    "(Ljavax/microedition/lcdui/ItemStateListener;)V",
+                    Self::set_item_state_listener,
+                    Default::default(),
+                ),
+                // wie private methods
+                JavaMethodProto::new(
+                    "paintContent",
+                    "(Ljavax/microedition/lcdui/Graphics;IIII)V",
+                    Self::paint_content,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+                JavaMethodProto::new(
+                    "itemStateChanged",
+                    "(Ljavax/microedition/lcdui/Item;)V",
+                    Self::item_state_changed,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("items", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("focusedIndex", "I", Default::default()),
+                JavaFieldProto::new("scrollY", "I", Default::default()),
+                JavaFieldProto::new("itemStateListener", "Ljavax/microedition/lcdui/ItemStateListener;", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
@@ -30,20 +74,408 @@ impl Form {
     async fn init(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, title: ClassInstanceRef<String>) -> JvmResult<()> {
         tracing::debug!("javax.microedition.lcdui.Form::<init>({this:?}, {title:?})");
 
+        let items = jvm.instantiate_array("Ljavax/microedition/lcdui/Item;", 0).await?;
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/Form",
+                "<init>",
+                "(Ljava/lang/String;[Ljavax/microedition/lcdui/Item;)V",
+                (title, items),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_items(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        title: ClassInstanceRef<String>,
+        items: ClassInstanceRef<Array<ClassInstanceRef<Item>>>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::<init>({this:?}, {title:?}, {items:?})");
+
         let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Screen", "<init>", "()V", ()).await?;
 
+        jvm.put_field(&mut this, "title", "Ljava/lang/String;", title).await?;
+        let item_vector = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "items", "Ljava/util/Vector;", item_vector).await?;
+        jvm.put_field(&mut this, "focusedIndex", "I", -1).await?;
+
+        if !items.is_null() {
+            let length = jvm.array_length(&items).await?;
+            let items: Vec<ClassInstanceRef<Item>> = jvm.load_array(&items, 0, length).await?;
+            for item in items {
+                Self::append(jvm, context, this.clone(), item).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn append(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, item: ClassInstanceRef<Item>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Form::append({this:?}, {item:?})");
+
+        let size = Self::item_count(jvm, &this).await?;
+        Self::insert(jvm, context, this, size, item).await?;
+
+        Ok(size)
+    }
+
+    async fn append_string(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, str: ClassInstanceRef<String>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Form::append({this:?}, {str:?})");
+
+        let item = jvm
+            .new_class(
+                "javax/microedition/lcdui/StringItem",
+                "(Ljava/lang/String;Ljava/lang/String;)V",
+                (None, str),
+            )
+            .await?;
+
+        Self::append(jvm, context, this, item.into()).await
+    }
+
+    async fn append_image(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, image: ClassInstanceRef<Image>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Form::append({this:?}, {image:?})");
+
+        let item = jvm
+            .new_class(
+                "javax/microedition/lcdui/ImageItem",
+                "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;ILjava/lang/String;)V",
+                (None, image, 0, None),
+            )
+            .await?;
+
+        Self::append(jvm, context, this, item.into()).await
+    }
+
+    async fn insert(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        index: i32,
+        item: ClassInstanceRef<Item>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::insert({this:?}, {index}, {item:?})");
+
+        let size = Self::item_count(jvm, &this).await?;
+        if index < 0 || index > size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "index out of range").await);
+        }
+        Self::adopt(jvm, &this, &item).await?;
+
+        let items = jvm.get_field(&this, "items", "Ljava/util/Vector;").await?;
+        let _: () = jvm
+            .invoke_virtual(&items, "insertElementAt", "(Ljava/lang/Object;I)V", (item.clone(), index))
+            .await?;
+
+        let focused_index: i32 = jvm.get_field(&this, "focusedIndex", "I").await?;
+        if focused_index >= index {
+            jvm.put_field(&mut this, "focusedIndex", "I", focused_index + 1).await?;
+        } else if focused_index == -1 && jvm.invoke_virtual(&item, "isItemFocusable", "()Z", ()).await? {
+            jvm.put_field(&mut this, "focusedIndex", "I", index).await?;
+        }
+
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn set(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        index: i32,
+        item: ClassInstanceRef<Item>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::set({this:?}, {index}, {item:?})");
+
+        let old_item = Self::item_at(jvm, &this, index).await?;
+        Self::adopt(jvm, &this, &item).await?;
+
+        let items = jvm.get_field(&this, "items", "Ljava/util/Vector;").await?;
+        let _: () = jvm
+            .invoke_virtual(&items, "setElementAt", "(Ljava/lang/Object;I)V", (item.clone(), index))
+            .await?;
+        let _: () = jvm
+            .invoke_virtual(&old_item, "setOwner", "(Ljavax/microedition/lcdui/Form;)V", (None,))
+            .await?;
+
+        let focused_index: i32 = jvm.get_field(&this, "focusedIndex", "I").await?;
+        if focused_index == index && !jvm.invoke_virtual(&item, "isItemFocusable", "()Z", ()).await? {
+            let next = Self::find_focusable(jvm, &this, index, 1).await?;
+            jvm.put_field(&mut this, "focusedIndex", "I", next).await?;
+        }
+
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<Item>> {
+        tracing::debug!("javax.microedition.lcdui.Form::get({this:?}, {index})");
+
+        Self::item_at(jvm, &this, index).await
+    }
+
+    async fn delete(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::delete({this:?}, {index})");
+
+        let item = Self::item_at(jvm, &this, index).await?;
+
+        let items = jvm.get_field(&this, "items", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&items, "removeElementAt", "(I)V", (index,)).await?;
+        let _: () = jvm
+            .invoke_virtual(&item, "setOwner", "(Ljavax/microedition/lcdui/Form;)V", (None,))
+            .await?;
+
+        let focused_index: i32 = jvm.get_field(&this, "focusedIndex", "I").await?;
+        if focused_index > index {
+            jvm.put_field(&mut this, "focusedIndex", "I", focused_index - 1).await?;
+        } else if focused_index == index {
+            let mut next = Self::find_focusable(jvm, &this, index - 1, 1).await?;
+            if next == -1 {
+                next = Self::find_focusable(jvm, &this, index, -1).await?;
+            }
+            jvm.put_field(&mut this, "focusedIndex", "I", next).await?;
+        }
+
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete_all(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::deleteAll({this:?})");
+
+        for i in 0..Self::item_count(jvm, &this).await? {
+            let item = Self::item_at(jvm, &this, i).await?;
+            let _: () = jvm
+                .invoke_virtual(&item, "setOwner", "(Ljavax/microedition/lcdui/Form;)V", (None,))
+                .await?;
+        }
+
+        let items = jvm.get_field(&this, "items", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&items, "removeAllElements", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "focusedIndex", "I", -1).await?;
+        jvm.put_field(&mut this, "scrollY", "I", 0).await?;
+
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn size(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Form::size({this:?})");
+
+        Self::item_count(jvm, &this).await
+    }
+
+    async fn set_item_state_listener(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        listener: ClassInstanceRef<ItemStateListener>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::setItemStateListener({this:?}, {listener:?})");
+
+        jvm.put_field(&mut this, "itemStateListener", "Ljavax/microedition/lcdui/ItemStateListener;", listener)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn paint_content(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::paintContent({this:?}, {graphics:?}, {x}, {y}, {width}, {height})");
+
+        let item_width = width - PADDING * 4;
+        let count = Self::item_count(jvm, &this).await?;
+
+        // layout items vertically
+        let mut item_tops = Vec::with_capacity(count as _);
+        let mut item_heights = Vec::with_capacity(count as _);
+        let mut content_height = PADDING;
+        for i in 0..count {
+            let item = Self::item_at(jvm, &this, i).await?;
+            let item_height: i32 = jvm.invoke_virtual(&item, "getItemHeight", "(I)I", (item_width,)).await?;
+
+            item_tops.push(content_height);
+            item_heights.push(item_height);
+            content_height += item_height + PADDING * 2;
+        }
+
+        // scroll to make focused item visible, while keeping scroll made by user
+        let focused_index: i32 = jvm.get_field(&this, "focusedIndex", "I").await?;
+        let mut scroll_y: i32 = jvm.get_field(&this, "scrollY", "I").await?;
+        if focused_index >= 0 {
+            let top = item_tops[focused_index as usize] - PADDING;
+            let bottom = top + item_heights[focused_index as usize] + PADDING * 2;
+
+            if top < scroll_y {
+                scroll_y = top;
+            } else if bottom > scroll_y + height {
+                scroll_y = (bottom - height).min(top);
+            }
+        }
+        scroll_y = scroll_y.min(content_height - height).max(0);
+        jvm.put_field(&mut this, "scrollY", "I", scroll_y).await?;
+
+        for i in 0..count {
+            let item_y = y + item_tops[i as usize] - scroll_y;
+            let item_height = item_heights[i as usize];
+            if item_y + item_height < y || item_y >= y + height {
+                continue;
+            }
+
+            let focused = i == focused_index;
+            let item = Self::item_at(jvm, &this, i).await?;
+            let _: () = jvm
+                .invoke_virtual(
+                    &item,
+                    "paintItem",
+                    "(Ljavax/microedition/lcdui/Graphics;IIIZ)V",
+                    (graphics.clone(), x + PADDING * 2, item_y, item_width, focused),
+                )
+                .await?;
+
+            if focused {
+                let mut renderer = Renderer::new(jvm, &graphics).await?;
+                renderer.draw_rect(
+                    x + PADDING,
+                    item_y - PADDING,
+                    width - PADDING * 2 - 1,
+                    item_height + PADDING * 2 - 1,
+                    HIGHLIGHT,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_key_event(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::handleKeyEvent({this:?}, {event_type}, {code})");
+
+        // focused item gets the key first
+        let focused_index: i32 = jvm.get_field(&this, "focusedIndex", "I").await?;
+        if focused_index >= 0 {
+            let item = Self::item_at(jvm, &this, focused_index).await?;
+            let consumed: bool = jvm.invoke_virtual(&item, "handleItemKey", "(II)Z", (event_type, code)).await?;
+            if consumed {
+                return Ok(());
+            }
+        }
+
+        if matches!(KeyboardEventType::from_raw(event_type), KeyboardEventType::KeyReleased) {
+            return Ok(());
+        }
+
+        let direction = match MIDPKeyCode::from_raw(code) {
+            MIDPKeyCode::UP => -1,
+            MIDPKeyCode::DOWN => 1,
+            _ => return Ok(()),
+        };
+
+        let next = Self::find_focusable(jvm, &this, focused_index, direction).await?;
+        if next != -1 {
+            jvm.put_field(&mut this, "focusedIndex", "I", next).await?;
+        } else {
+            // nothing to focus, scroll instead. paint will clamp it
+            let scroll_y: i32 = jvm.get_field(&this, "scrollY", "I").await?;
+            jvm.put_field(&mut this, "scrollY", "I", (scroll_y + direction * LINE_HEIGHT).max(0))
+                .await?;
+        }
+
+        renderer::request_redraw(context);
+
         Ok(())
     }
 
-    async fn append(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, item: ClassInstanceRef<Item>) -> JvmResult<i32> {
-        tracing::warn!("stub javax.microedition.lcdui.Form::append({this:?}, {item:?})");
+    async fn item_state_changed(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        item: ClassInstanceRef<Item>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Form::itemStateChanged({this:?}, {item:?})");
+
+        let listener: ClassInstanceRef<ItemStateListener> = jvm
+            .get_field(&this, "itemStateListener", "Ljavax/microedition/lcdui/ItemStateListener;")
+            .await?;
+        if listener.is_null() {
+            return Ok(());
+        }
+
+        let _: () = jvm
+            .invoke_virtual(&listener, "itemStateChanged", "(Ljavax/microedition/lcdui/Item;)V", (item,))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn item_count(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<i32> {
+        let items = jvm.get_field(this, "items", "Ljava/util/Vector;").await?;
+
+        jvm.invoke_virtual(&items, "size", "()I", ()).await
+    }
+
+    async fn item_at(jvm: &Jvm, this: &ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<Item>> {
+        let count = Self::item_count(jvm, this).await?;
+        if index < 0 || index >= count {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "index out of range").await);
+        }
+
+        let items = jvm.get_field(this, "items", "Ljava/util/Vector;").await?;
 
-        Ok(0)
+        jvm.invoke_virtual(&items, "elementAt", "(I)Ljava/lang/Object;", (index,)).await
     }
 
-    async fn append_string(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, str: ClassInstanceRef<String>) -> JvmResult<i32> {
-        tracing::warn!("stub javax.microedition.lcdui.Form::append({this:?}, {str:?})");
+    // item can be owned by only one form
+    async fn adopt(jvm: &Jvm, this: &ClassInstanceRef<Self>, item: &ClassInstanceRef<Item>) -> JvmResult<()> {
+        if item.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "item is null").await);
+        }
+
+        let owner: ClassInstanceRef<Object> = jvm.get_field(item, "owner", "Ljavax/microedition/lcdui/Form;").await?;
+        if !owner.is_null() {
+            return Err(jvm.exception("java/lang/IllegalStateException", "item is already owned").await);
+        }
+
+        let _: () = jvm
+            .invoke_virtual(item, "setOwner", "(Ljavax/microedition/lcdui/Form;)V", (this.clone(),))
+            .await?;
+
+        Ok(())
+    }
+
+    // returns -1 if there's no focusable item in direction
+    async fn find_focusable(jvm: &Jvm, this: &ClassInstanceRef<Self>, from: i32, direction: i32) -> JvmResult<i32> {
+        let count = Self::item_count(jvm, this).await?;
+
+        let mut index = from + direction;
+        while index >= 0 && index < count {
+            let item = Self::item_at(jvm, this, index).await?;
+            if jvm.invoke_virtual(&item, "isItemFocusable", "()Z", ()).await? {
+                return Ok(index);
+            }
+
+            index += direction;
+        }
 
-        Ok(0)
+        Ok(-1)
     }
 }
@@ -1,3 +1,8 @@
 mod game_canvas;
+mod layer;
+mod layer_manager;
+mod sprite;
+mod tiled_layer;
+pub(crate) mod transform;
 
-pub use game_canvas::GameCanvas;
+pub use self::{game_canvas::GameCanvas, layer::Layer, layer_manager::LayerManager, sprite::Sprite, tiled_layer::TiledLayer};
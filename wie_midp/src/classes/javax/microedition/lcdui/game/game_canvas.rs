@@ -5,7 +5,10 @@ use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
-use crate::classes::javax::microedition::lcdui::{Graphics, Image};
+use crate::classes::{
+    javax::microedition::lcdui::{Graphics, Image},
+    net::wie::KeyboardEventType,
+};
 
 // class javax.microedition.lcdui.game.GameCanvas
 pub struct GameCanvas;
@@ -26,12 +29,15 @@ impl GameCanvas {
                 ),
                 JavaMethodProto::new("flushGraphics", "()V", Self::flush_graphics, Default::default()),
                 JavaMethodProto::new("paint", "(Ljavax/microedition/lcdui/Graphics;)V", Self::paint, Default::default()),
+                JavaMethodProto::new("getKeyStates", "()I", Self::get_key_states, Default::default()),
+                JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("offscreenImage", "Ljavax/microedition/lcdui/Image;", Default::default()),
+                JavaFieldProto::new("suppressKeyEvents", "Z", Default::default()),
+                JavaFieldProto::new("keyStates", "I", Default::default()),
+                JavaFieldProto::new("latchedKeyStates", "I", Default::default()),
             ],
-            fields: vec![JavaFieldProto::new(
-                "offscreenImage",
-                "Ljavax/microedition/lcdui/Image;",
-                Default::default(),
-            )],
             access_flags: Default::default(),
         }
     }
@@ -55,6 +61,7 @@ impl GameCanvas {
 
         jvm.put_field(&mut this, "offscreenImage", "Ljavax/microedition/lcdui/Image;", image)
             .await?;
+        jvm.put_field(&mut this, "suppressKeyEvents", "Z", suppress_key_events).await?;
 
         Ok(())
     }
@@ -93,4 +100,52 @@ impl GameCanvas {
 
         Ok(())
     }
+
+    async fn get_key_states(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.GameCanvas::getKeyStates({this:?})");
+
+        // keys pressed and released since last call are reported once
+        let key_states: i32 = jvm.get_field(&this, "keyStates", "I").await?;
+        let latched_key_states: i32 = jvm.get_field(&this, "latchedKeyStates", "I").await?;
+        jvm.put_field(&mut this, "latchedKeyStates", "I", 0).await?;
+
+        Ok(key_states | latched_key_states)
+    }
+
+    async fn handle_key_event(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        event_type: i32,
+        code: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.GameCanvas::handleKeyEvent({this:?}, {event_type}, {code})");
+
+        let game_action: i32 = jvm.invoke_virtual(&this, "getGameAction", "(I)I", (code,)).await?;
+        let bit = if game_action != 0 { 1 << game_action } else { 0 };
+
+        if bit != 0 {
+            let key_states: i32 = jvm.get_field(&this, "keyStates", "I").await?;
+            match KeyboardEventType::from_raw(event_type) {
+                KeyboardEventType::KeyPressed => {
+                    let latched_key_states: i32 = jvm.get_field(&this, "latchedKeyStates", "I").await?;
+                    jvm.put_field(&mut this, "keyStates", "I", key_states | bit).await?;
+                    jvm.put_field(&mut this, "latchedKeyStates", "I", latched_key_states | bit).await?;
+                }
+                KeyboardEventType::KeyReleased => {
+                    jvm.put_field(&mut this, "keyStates", "I", key_states & !bit).await?;
+                }
+                _ => {}
+            }
+        }
+
+        // game keys are not delivered to keyPressed and friends when suppressed
+        let suppress_key_events: bool = jvm.get_field(&this, "suppressKeyEvents", "Z").await?;
+        if suppress_key_events && bit != 0 {
+            return Ok(());
+        }
+
+        jvm.invoke_special(&this, "javax/microedition/lcdui/Canvas", "handleKeyEvent", "(II)V", (event_type, code))
+            .await
+    }
 }
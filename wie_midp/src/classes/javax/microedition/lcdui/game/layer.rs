@@ -0,0 +1,119 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::ClassAccessFlags;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+// abstract class javax.microedition.lcdui.game.Layer
+pub struct Layer;
+
+impl Layer {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/game/Layer",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(II)V", Self::init, Default::default()),
+                JavaMethodProto::new("getX", "()I", Self::get_x, Default::default()),
+                JavaMethodProto::new("getY", "()I", Self::get_y, Default::default()),
+                JavaMethodProto::new("getWidth", "()I", Self::get_width, Default::default()),
+                JavaMethodProto::new("getHeight", "()I", Self::get_height, Default::default()),
+                JavaMethodProto::new("setPosition", "(II)V", Self::set_position, Default::default()),
+                JavaMethodProto::new("move", "(II)V", Self::r#move, Default::default()),
+                JavaMethodProto::new("isVisible", "()Z", Self::is_visible, Default::default()),
+                JavaMethodProto::new("setVisible", "(Z)V", Self::set_visible, Default::default()),
+                JavaMethodProto::new_abstract("paint", "(Ljavax/microedition/lcdui/Graphics;)V", Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("x", "I", Default::default()),
+                JavaFieldProto::new("y", "I", Default::default()),
+                JavaFieldProto::new("width", "I", Default::default()),
+                JavaFieldProto::new("height", "I", Default::default()),
+                JavaFieldProto::new("visible", "Z", Default::default()),
+            ],
+            access_flags: ClassAccessFlags::ABSTRACT,
+        }
+    }
+
+    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, width: i32, height: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::<init>({this:?}, {width}, {height})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "width", "I", width).await?;
+        jvm.put_field(&mut this, "height", "I", height).await?;
+        jvm.put_field(&mut this, "visible", "Z", true).await?;
+
+        Ok(())
+    }
+
+    async fn get_x(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::getX({this:?})");
+
+        jvm.get_field(&this, "x", "I").await
+    }
+
+    async fn get_y(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::getY({this:?})");
+
+        jvm.get_field(&this, "y", "I").await
+    }
+
+    async fn get_width(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::getWidth({this:?})");
+
+        jvm.get_field(&this, "width", "I").await
+    }
+
+    async fn get_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::getHeight({this:?})");
+
+        jvm.get_field(&this, "height", "I").await
+    }
+
+    async fn set_position(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::setPosition({this:?}, {x}, {y})");
+
+        jvm.put_field(&mut this, "x", "I", x).await?;
+        jvm.put_field(&mut this, "y", "I", y).await?;
+
+        Ok(())
+    }
+
+    async fn r#move(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, dx: i32, dy: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::move({this:?}, {dx}, {dy})");
+
+        let x: i32 = jvm.get_field(&this, "x", "I").await?;
+        let y: i32 = jvm.get_field(&this, "y", "I").await?;
+
+        jvm.put_field(&mut this, "x", "I", x.wrapping_add(dx)).await?;
+        jvm.put_field(&mut this, "y", "I", y.wrapping_add(dy)).await?;
+
+        Ok(())
+    }
+
+    async fn is_visible(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::isVisible({this:?})");
+
+        jvm.get_field(&this, "visible", "Z").await
+    }
+
+    async fn set_visible(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, visible: bool) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Layer::setVisible({this:?}, {visible})");
+
+        jvm.put_field(&mut this, "visible", "Z", visible).await
+    }
+
+    // layer's rectangle in painter's coordinate
+    pub async fn bounds<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<(i32, i32, i32, i32)> {
+        let x: i32 = jvm.get_field(this, "x", "I").await?;
+        let y: i32 = jvm.get_field(this, "y", "I").await?;
+        let width: i32 = jvm.get_field(this, "width", "I").await?;
+        let height: i32 = jvm.get_field(this, "height", "I").await?;
+
+        Ok((x, y, width, height))
+    }
+}
@@ -0,0 +1,350 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::util::Vector;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::{Graphics, game::Layer};
+
+// class javax.microedition.lcdui.game.LayerManager
+pub struct LayerManager;
+
+impl LayerManager {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/game/LayerManager",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("append", "(Ljavax/microedition/lcdui/game/Layer;)V", Self::append, Default::default()),
+                JavaMethodProto::new("insert", "(Ljavax/microedition/lcdui/game/Layer;I)V", Self::insert, Default::default()),
+                JavaMethodProto::new("remove", "(Ljavax/microedition/lcdui/game/Layer;)V", Self::remove, Default::default()),
+                JavaMethodProto::new(
+                    "getLayerAt",
+                    "(I)Ljavax/microedition/lcdui/game/Layer;",
+                    Self::get_layer_at,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getSize", "()I", Self::get_size, Default::default()),
+                JavaMethodProto::new("setViewWindow", "(IIII)V", Self::set_view_window, Default::default()),
+                JavaMethodProto::new("paint", "(Ljavax/microedition/lcdui/Graphics;II)V", Self::paint, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("layers", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("viewX", "I", Default::default()),
+                JavaFieldProto::new("viewY", "I", Default::default()),
+                JavaFieldProto::new("viewWidth", "I", Default::default()),
+                JavaFieldProto::new("viewHeight", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        let layers = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "layers", "Ljava/util/Vector;", layers).await?;
+
+        jvm.put_field(&mut this, "viewWidth", "I", i32::MAX).await?;
+        jvm.put_field(&mut this, "viewHeight", "I", i32::MAX).await?;
+
+        Ok(())
+    }
+
+    async fn append(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, layer: ClassInstanceRef<Layer>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::append({this:?}, {layer:?})");
+
+        if layer.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "layer is null").await);
+        }
+
+        Self::remove_layer(jvm, &this, &layer).await?;
+
+        let layers: ClassInstanceRef<Vector> = jvm.get_field(&this, "layers", "Ljava/util/Vector;").await?;
+        let _: () = jvm.invoke_virtual(&layers, "addElement", "(Ljava/lang/Object;)V", (layer,)).await?;
+
+        Ok(())
+    }
+
+    async fn insert(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        layer: ClassInstanceRef<Layer>,
+        index: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::insert({this:?}, {layer:?}, {index})");
+
+        if layer.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "layer is null").await);
+        }
+
+        // index is checked against the size after existing layer is removed
+        Self::remove_layer(jvm, &this, &layer).await?;
+
+        let layers: ClassInstanceRef<Vector> = jvm.get_field(&this, "layers", "Ljava/util/Vector;").await?;
+        let size: i32 = jvm.invoke_virtual(&layers, "size", "()I", ()).await?;
+        if index < 0 || index > size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid index").await);
+        }
+
+        let _: () = jvm
+            .invoke_virtual(&layers, "insertElementAt", "(Ljava/lang/Object;I)V", (layer, index))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, layer: ClassInstanceRef<Layer>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::remove({this:?}, {layer:?})");
+
+        if layer.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "layer is null").await);
+        }
+
+        Self::remove_layer(jvm, &this, &layer).await
+    }
+
+    async fn get_layer_at(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<Layer>> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::getLayerAt({this:?}, {index})");
+
+        let layers: ClassInstanceRef<Vector> = jvm.get_field(&this, "layers", "Ljava/util/Vector;").await?;
+        let size: i32 = jvm.invoke_virtual(&layers, "size", "()I", ()).await?;
+        if index < 0 || index >= size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid index").await);
+        }
+
+        jvm.invoke_virtual(&layers, "elementAt", "(I)Ljava/lang/Object;", (index,)).await
+    }
+
+    async fn get_size(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::getSize({this:?})");
+
+        let layers: ClassInstanceRef<Vector> = jvm.get_field(&this, "layers", "Ljava/util/Vector;").await?;
+
+        jvm.invoke_virtual(&layers, "size", "()I", ()).await
+    }
+
+    async fn set_view_window(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::setViewWindow({this:?}, {x}, {y}, {width}, {height})");
+
+        if width < 0 || height < 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid size").await);
+        }
+
+        jvm.put_field(&mut this, "viewX", "I", x).await?;
+        jvm.put_field(&mut this, "viewY", "I", y).await?;
+        jvm.put_field(&mut this, "viewWidth", "I", width).await?;
+        jvm.put_field(&mut this, "viewHeight", "I", height).await?;
+
+        Ok(())
+    }
+
+    async fn paint(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        g: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.LayerManager::paint({this:?}, {g:?}, {x}, {y})");
+
+        if g.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "g is null").await);
+        }
+
+        let view_x: i32 = jvm.get_field(&this, "viewX", "I").await?;
+        let view_y: i32 = jvm.get_field(&this, "viewY", "I").await?;
+        let view_width: i32 = jvm.get_field(&this, "viewWidth", "I").await?;
+        let view_height: i32 = jvm.get_field(&this, "viewHeight", "I").await?;
+
+        // save graphics state to restore after painting
+        let clip_x: i32 = jvm.invoke_virtual(&g, "getClipX", "()I", ()).await?;
+        let clip_y: i32 = jvm.invoke_virtual(&g, "getClipY", "()I", ()).await?;
+        let clip_width: i32 = jvm.invoke_virtual(&g, "getClipWidth", "()I", ()).await?;
+        let clip_height: i32 = jvm.invoke_virtual(&g, "getClipHeight", "()I", ()).await?;
+
+        let _: () = jvm.invoke_virtual(&g, "clipRect", "(IIII)V", (x, y, view_width, view_height)).await?;
+        let _: () = jvm.invoke_virtual(&g, "translate", "(II)V", (x - view_x, y - view_y)).await?;
+
+        // layer at index 0 is closest to the user, so it's painted last
+        let layers: ClassInstanceRef<Vector> = jvm.get_field(&this, "layers", "Ljava/util/Vector;").await?;
+        let size: i32 = jvm.invoke_virtual(&layers, "size", "()I", ()).await?;
+        for index in (0..size).rev() {
+            let layer: ClassInstanceRef<Layer> = jvm.invoke_virtual(&layers, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let visible: bool = jvm.get_field(&layer, "visible", "Z").await?;
+            if !visible {
+                continue;
+            }
+
+            let _: () = jvm
+                .invoke_virtual(&layer, "paint", "(Ljavax/microedition/lcdui/Graphics;)V", (g.clone(),))
+                .await?;
+        }
+
+        let _: () = jvm.invoke_virtual(&g, "translate", "(II)V", (view_x - x, view_y - y)).await?;
+        let _: () = jvm
+            .invoke_virtual(&g, "setClip", "(IIII)V", (clip_x, clip_y, clip_width, clip_height))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_layer(jvm: &Jvm, this: &ClassInstanceRef<Self>, layer: &ClassInstanceRef<Layer>) -> JvmResult<()> {
+        let layers: ClassInstanceRef<Vector> = jvm.get_field(this, "layers", "Ljava/util/Vector;").await?;
+        let size: i32 = jvm.invoke_virtual(&layers, "size", "()I", ()).await?;
+
+        for index in 0..size {
+            let item: ClassInstanceRef<Layer> = jvm.invoke_virtual(&layers, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let same: bool = jvm.invoke_virtual(&item, "equals", "(Ljava/lang/Object;)Z", (layer.clone(),)).await?;
+            if same {
+                let _: () = jvm.invoke_virtual(&layers, "removeElementAt", "(I)V", (index,)).await?;
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+    use test_utils::run_jvm_test;
+    use wie_backend::canvas::Image as BackendImage;
+    use wie_util::Result;
+
+    use crate::{classes::javax::microedition::lcdui::Image, get_protos};
+
+    async fn create_image(jvm: &Jvm, width: i32, height: i32, color: i32) -> JvmResult<ClassInstanceRef<Image>> {
+        let image: ClassInstanceRef<Image> = jvm
+            .invoke_static(
+                "javax/microedition/lcdui/Image",
+                "createImage",
+                "(II)Ljavax/microedition/lcdui/Image;",
+                (width, height),
+            )
+            .await?;
+
+        if color != 0 {
+            let graphics = jvm
+                .new_class(
+                    "javax/microedition/lcdui/Graphics",
+                    "(Ljavax/microedition/lcdui/Image;)V",
+                    (image.clone(),),
+                )
+                .await?;
+            let _: () = jvm.invoke_virtual(&graphics, "setColor", "(I)V", (color,)).await?;
+            let _: () = jvm.invoke_virtual(&graphics, "fillRect", "(IIII)V", (0, 0, width, height)).await?;
+        }
+
+        Ok(image)
+    }
+
+    async fn paint(jvm: &Jvm, layer_manager: &ClassInstanceRef<super::LayerManager>, x: i32, y: i32) -> JvmResult<Box<dyn BackendImage>> {
+        let target = create_image(jvm, 4, 4, 0).await?;
+        let graphics = jvm
+            .new_class(
+                "javax/microedition/lcdui/Graphics",
+                "(Ljavax/microedition/lcdui/Image;)V",
+                (target.clone(),),
+            )
+            .await?;
+        let _: () = jvm
+            .invoke_virtual(layer_manager, "paint", "(Ljavax/microedition/lcdui/Graphics;II)V", (graphics, x, y))
+            .await?;
+
+        Image::image(jvm, &target).await
+    }
+
+    #[test]
+    fn test_layer_manager() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            let red = create_image(&jvm, 4, 4, 0xff0000).await?;
+            let green = create_image(&jvm, 2, 2, 0x00ff00).await?;
+
+            let background = jvm
+                .new_class("javax/microedition/lcdui/game/Sprite", "(Ljavax/microedition/lcdui/Image;)V", (red,))
+                .await?;
+            let foreground = jvm
+                .new_class("javax/microedition/lcdui/game/Sprite", "(Ljavax/microedition/lcdui/Image;)V", (green,))
+                .await?;
+            let _: () = jvm.invoke_virtual(&foreground, "setPosition", "(II)V", (1, 1)).await?;
+
+            let layer_manager: ClassInstanceRef<super::LayerManager> =
+                jvm.new_class("javax/microedition/lcdui/game/LayerManager", "()V", ()).await?.into();
+            let _: () = jvm
+                .invoke_virtual(
+                    &layer_manager,
+                    "append",
+                    "(Ljavax/microedition/lcdui/game/Layer;)V",
+                    (foreground.clone(),),
+                )
+                .await?;
+            let _: () = jvm
+                .invoke_virtual(
+                    &layer_manager,
+                    "append",
+                    "(Ljavax/microedition/lcdui/game/Layer;)V",
+                    (background.clone(),),
+                )
+                .await?;
+
+            let size: i32 = jvm.invoke_virtual(&layer_manager, "getSize", "()I", ()).await?;
+            assert_eq!(size, 2);
+
+            // layer 0 is painted on top
+            let image = paint(&jvm, &layer_manager, 0, 0).await?;
+            assert_eq!(image.get_pixel(0, 0).r, 0xff);
+            assert_eq!(image.get_pixel(1, 1).g, 0xff);
+            assert_eq!(image.get_pixel(1, 1).r, 0x00);
+
+            // inserting an existing layer moves it
+            let _: () = jvm
+                .invoke_virtual(
+                    &layer_manager,
+                    "insert",
+                    "(Ljavax/microedition/lcdui/game/Layer;I)V",
+                    (background.clone(), 0),
+                )
+                .await?;
+            let size: i32 = jvm.invoke_virtual(&layer_manager, "getSize", "()I", ()).await?;
+            assert_eq!(size, 2);
+
+            let image = paint(&jvm, &layer_manager, 0, 0).await?;
+            assert_eq!(image.get_pixel(1, 1).r, 0xff);
+            assert_eq!(image.get_pixel(1, 1).g, 0x00);
+
+            // view window is placed at the paint position and clips to its size
+            let _: () = jvm
+                .invoke_virtual(&layer_manager, "remove", "(Ljavax/microedition/lcdui/game/Layer;)V", (background,))
+                .await?;
+            let _: () = jvm.invoke_virtual(&layer_manager, "setViewWindow", "(IIII)V", (0, 0, 2, 2)).await?;
+
+            let image = paint(&jvm, &layer_manager, 1, 1).await?;
+            assert_eq!(image.get_pixel(1, 1).g, 0x00);
+            assert_eq!(image.get_pixel(2, 2).g, 0xff);
+            assert_eq!(image.get_pixel(3, 3).g, 0x00);
+
+            Ok(())
+        })
+    }
+}
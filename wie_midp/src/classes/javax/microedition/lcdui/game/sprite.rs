@@ -0,0 +1,815 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::Image as BackendImage;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::{
+    Graphics, Image,
+    game::{
+        Layer, TiledLayer,
+        tiled_layer::TileMap,
+        transform::{self, TRANS_NONE},
+    },
+};
+
+// Graphics.TOP | Graphics.LEFT
+const ANCHOR_TOP_LEFT: i32 = 20;
+
+// class javax.microedition.lcdui.game.Sprite
+pub struct Sprite;
+
+impl Sprite {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/game/Sprite",
+            parent_class: Some("javax/microedition/lcdui/game/Layer"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljavax/microedition/lcdui/Image;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljavax/microedition/lcdui/Image;II)V",
+                    Self::init_with_frame_size,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljavax/microedition/lcdui/game/Sprite;)V",
+                    Self::init_with_sprite,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("setImage", "(Ljavax/microedition/lcdui/Image;II)V", Self::set_image, Default::default()),
+                JavaMethodProto::new("getRawFrameCount", "()I", Self::get_raw_frame_count, Default::default()),
+                JavaMethodProto::new("getFrameSequenceLength", "()I", Self::get_frame_sequence_length, Default::default()),
+                JavaMethodProto::new("getFrame", "()I", Self::get_frame, Default::default()),
+                JavaMethodProto::new("setFrame", "(I)V", Self::set_frame, Default::default()),
+                JavaMethodProto::new("nextFrame", "()V", Self::next_frame, Default::default()),
+                JavaMethodProto::new("prevFrame", "()V", Self::prev_frame, Default::default()),
+                JavaMethodProto::new("setFrameSequence", "([I)V", Self::set_frame_sequence, Default::default()),
+                JavaMethodProto::new("defineReferencePixel", "(II)V", Self::define_reference_pixel, Default::default()),
+                JavaMethodProto::new("getRefPixelX", "()I", Self::get_ref_pixel_x, Default::default()),
+                JavaMethodProto::new("getRefPixelY", "()I", Self::get_ref_pixel_y, Default::default()),
+                JavaMethodProto::new("setRefPixelPosition", "(II)V", Self::set_ref_pixel_position, Default::default()),
+                JavaMethodProto::new("setTransform", "(I)V", Self::set_transform, Default::default()),
+                JavaMethodProto::new(
+                    "defineCollisionRectangle",
+                    "(IIII)V",
+                    Self::define_collision_rectangle,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "collidesWith",
+                    "(Ljavax/microedition/lcdui/game/Sprite;Z)Z",
+                    Self::collides_with_sprite,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "collidesWith",
+                    "(Ljavax/microedition/lcdui/game/TiledLayer;Z)Z",
+                    Self::collides_with_tiled_layer,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "collidesWith",
+                    "(Ljavax/microedition/lcdui/Image;IIZ)Z",
+                    Self::collides_with_image,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("paint", "(Ljavax/microedition/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("image", "Ljavax/microedition/lcdui/Image;", Default::default()),
+                JavaFieldProto::new("frameWidth", "I", Default::default()),
+                JavaFieldProto::new("frameHeight", "I", Default::default()),
+                JavaFieldProto::new("frameSequence", "[I", Default::default()),
+                JavaFieldProto::new("sequenceIndex", "I", Default::default()),
+                JavaFieldProto::new("refX", "I", Default::default()),
+                JavaFieldProto::new("refY", "I", Default::default()),
+                JavaFieldProto::new("transform", "I", Default::default()),
+                JavaFieldProto::new("collisionX", "I", Default::default()),
+                JavaFieldProto::new("collisionY", "I", Default::default()),
+                JavaFieldProto::new("collisionWidth", "I", Default::default()),
+                JavaFieldProto::new("collisionHeight", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, image: ClassInstanceRef<Image>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::<init>({this:?}, {image:?})");
+
+        if image.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "image is null").await);
+        }
+
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/game/Sprite",
+                "<init>",
+                "(Ljavax/microedition/lcdui/Image;II)V",
+                (image, width, height),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_frame_size(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        image: ClassInstanceRef<Image>,
+        frame_width: i32,
+        frame_height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::<init>({this:?}, {image:?}, {frame_width}, {frame_height})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/game/Layer",
+                "<init>",
+                "(II)V",
+                (frame_width, frame_height),
+            )
+            .await?;
+
+        Self::load_frames(jvm, &mut this, image, frame_width, frame_height).await?;
+        Self::reset_frame_sequence(jvm, &mut this).await?;
+        Self::reset_collision_rectangle(jvm, &mut this).await?;
+
+        Ok(())
+    }
+
+    async fn init_with_sprite(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        sprite: ClassInstanceRef<Self>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::<init>({this:?}, {sprite:?})");
+
+        if sprite.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "sprite is null").await);
+        }
+
+        let (x, y, width, height) = Layer::bounds(jvm, &sprite).await?;
+        let _: () = jvm
+            .invoke_special(&this, "javax/microedition/lcdui/game/Layer", "<init>", "(II)V", (width, height))
+            .await?;
+        jvm.put_field(&mut this, "x", "I", x).await?;
+        jvm.put_field(&mut this, "y", "I", y).await?;
+        let visible: bool = jvm.get_field(&sprite, "visible", "Z").await?;
+        jvm.put_field(&mut this, "visible", "Z", visible).await?;
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(&sprite, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        jvm.put_field(&mut this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+
+        for field in [
+            "frameWidth",
+            "frameHeight",
+            "sequenceIndex",
+            "refX",
+            "refY",
+            "transform",
+            "collisionX",
+            "collisionY",
+            "collisionWidth",
+            "collisionHeight",
+        ] {
+            let value: i32 = jvm.get_field(&sprite, field, "I").await?;
+            jvm.put_field(&mut this, field, "I", value).await?;
+        }
+
+        let frame_sequence = Self::frame_sequence(jvm, &sprite).await?;
+        Self::store_frame_sequence(jvm, &mut this, frame_sequence).await?;
+
+        Ok(())
+    }
+
+    async fn set_image(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        image: ClassInstanceRef<Image>,
+        frame_width: i32,
+        frame_height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::setImage({this:?}, {image:?}, {frame_width}, {frame_height})");
+
+        let old_frame_count = Self::raw_frame_count(jvm, &this).await?;
+        let (ref_x, ref_y) = Self::ref_pixel_position(jvm, &this).await?;
+
+        Self::load_frames(jvm, &mut this, image, frame_width, frame_height).await?;
+
+        // frame sequence is kept only if there are enough frames
+        if Self::raw_frame_count(jvm, &this).await? < old_frame_count {
+            Self::reset_frame_sequence(jvm, &mut this).await?;
+        }
+        Self::reset_collision_rectangle(jvm, &mut this).await?;
+
+        let transform: i32 = jvm.get_field(&this, "transform", "I").await?;
+        let (width, height) = transform::transformed_size(transform, frame_width, frame_height);
+        jvm.put_field(&mut this, "width", "I", width).await?;
+        jvm.put_field(&mut this, "height", "I", height).await?;
+
+        // reference pixel stays at the same position
+        Self::move_ref_pixel(jvm, &mut this, ref_x, ref_y).await?;
+
+        Ok(())
+    }
+
+    async fn get_raw_frame_count(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::getRawFrameCount({this:?})");
+
+        Self::raw_frame_count(jvm, &this).await
+    }
+
+    async fn get_frame_sequence_length(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::getFrameSequenceLength({this:?})");
+
+        let frame_sequence: ClassInstanceRef<Array<i32>> = jvm.get_field(&this, "frameSequence", "[I").await?;
+
+        Ok(jvm.array_length(&frame_sequence).await? as _)
+    }
+
+    async fn get_frame(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::getFrame({this:?})");
+
+        jvm.get_field(&this, "sequenceIndex", "I").await
+    }
+
+    async fn set_frame(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, sequence_index: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::setFrame({this:?}, {sequence_index})");
+
+        let length = Self::frame_sequence(jvm, &this).await?.len() as i32;
+        if sequence_index < 0 || sequence_index >= length {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid sequence index").await);
+        }
+
+        jvm.put_field(&mut this, "sequenceIndex", "I", sequence_index).await
+    }
+
+    async fn next_frame(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::nextFrame({this:?})");
+
+        let length = Self::frame_sequence(jvm, &this).await?.len() as i32;
+        let sequence_index: i32 = jvm.get_field(&this, "sequenceIndex", "I").await?;
+
+        jvm.put_field(&mut this, "sequenceIndex", "I", (sequence_index + 1) % length).await
+    }
+
+    async fn prev_frame(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::prevFrame({this:?})");
+
+        let length = Self::frame_sequence(jvm, &this).await?.len() as i32;
+        let sequence_index: i32 = jvm.get_field(&this, "sequenceIndex", "I").await?;
+
+        jvm.put_field(&mut this, "sequenceIndex", "I", (sequence_index + length - 1) % length)
+            .await
+    }
+
+    async fn set_frame_sequence(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        sequence: ClassInstanceRef<Array<i32>>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::setFrameSequence({this:?}, {sequence:?})");
+
+        if sequence.is_null() {
+            return Self::reset_frame_sequence(jvm, &mut this).await;
+        }
+
+        let length = jvm.array_length(&sequence).await?;
+        let sequence: Vec<i32> = jvm.load_array(&sequence, 0, length).await?;
+
+        let raw_frame_count = Self::raw_frame_count(jvm, &this).await?;
+        if sequence.is_empty() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "sequence is empty").await);
+        }
+        if sequence.iter().any(|&x| x < 0 || x >= raw_frame_count) {
+            return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", "invalid frame index").await);
+        }
+
+        Self::store_frame_sequence(jvm, &mut this, sequence).await?;
+        jvm.put_field(&mut this, "sequenceIndex", "I", 0).await
+    }
+
+    async fn define_reference_pixel(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::defineReferencePixel({this:?}, {x}, {y})");
+
+        jvm.put_field(&mut this, "refX", "I", x).await?;
+        jvm.put_field(&mut this, "refY", "I", y).await?;
+
+        Ok(())
+    }
+
+    async fn get_ref_pixel_x(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::getRefPixelX({this:?})");
+
+        Ok(Self::ref_pixel_position(jvm, &this).await?.0)
+    }
+
+    async fn get_ref_pixel_y(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::getRefPixelY({this:?})");
+
+        Ok(Self::ref_pixel_position(jvm, &this).await?.1)
+    }
+
+    async fn set_ref_pixel_position(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::setRefPixelPosition({this:?}, {x}, {y})");
+
+        Self::move_ref_pixel(jvm, &mut this, x, y).await
+    }
+
+    async fn set_transform(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, transform: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::setTransform({this:?}, {transform})");
+
+        if !transform::is_valid(transform) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid transform").await);
+        }
+
+        // sprite is moved so that reference pixel stays at the same position
+        let (ref_x, ref_y) = Self::ref_pixel_position(jvm, &this).await?;
+
+        let frame_width: i32 = jvm.get_field(&this, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(&this, "frameHeight", "I").await?;
+        let (width, height) = transform::transformed_size(transform, frame_width, frame_height);
+
+        jvm.put_field(&mut this, "transform", "I", transform).await?;
+        jvm.put_field(&mut this, "width", "I", width).await?;
+        jvm.put_field(&mut this, "height", "I", height).await?;
+
+        Self::move_ref_pixel(jvm, &mut this, ref_x, ref_y).await
+    }
+
+    async fn define_collision_rectangle(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::defineCollisionRectangle({this:?}, {x}, {y}, {width}, {height})");
+
+        if width < 0 || height < 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid size").await);
+        }
+
+        jvm.put_field(&mut this, "collisionX", "I", x).await?;
+        jvm.put_field(&mut this, "collisionY", "I", y).await?;
+        jvm.put_field(&mut this, "collisionWidth", "I", width).await?;
+        jvm.put_field(&mut this, "collisionHeight", "I", height).await?;
+
+        Ok(())
+    }
+
+    async fn collides_with_sprite(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        sprite: ClassInstanceRef<Self>,
+        pixel_level: bool,
+    ) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::collidesWith({this:?}, {sprite:?}, {pixel_level})");
+
+        if sprite.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "sprite is null").await);
+        }
+
+        let a = SpriteFrame::load(jvm, &this).await?;
+        let b = SpriteFrame::load(jvm, &sprite).await?;
+        if !a.visible || !b.visible {
+            return Ok(false);
+        }
+
+        let Some(area) = intersect(a.collision_rect(), b.collision_rect()) else {
+            return Ok(false);
+        };
+        if !pixel_level {
+            return Ok(true);
+        }
+
+        Ok(any_pixel(area, |x, y| a.is_opaque_at(x, y) && b.is_opaque_at(x, y)))
+    }
+
+    async fn collides_with_tiled_layer(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        tiled_layer: ClassInstanceRef<TiledLayer>,
+        pixel_level: bool,
+    ) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::collidesWith({this:?}, {tiled_layer:?}, {pixel_level})");
+
+        if tiled_layer.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "tiledLayer is null").await);
+        }
+
+        let sprite = SpriteFrame::load(jvm, &this).await?;
+        let visible: bool = jvm.get_field(&tiled_layer, "visible", "Z").await?;
+        if !sprite.visible || !visible {
+            return Ok(false);
+        }
+
+        let Some(area) = intersect(sprite.collision_rect(), Layer::bounds(jvm, &tiled_layer).await?) else {
+            return Ok(false);
+        };
+
+        let tile_map = TileMap::load(jvm, &tiled_layer).await?;
+        if pixel_level {
+            Ok(any_pixel(area, |x, y| sprite.is_opaque_at(x, y) && tile_map.is_opaque_at(x, y)))
+        } else {
+            Ok(any_pixel(area, |x, y| tile_map.tile_at(x, y) != 0))
+        }
+    }
+
+    async fn collides_with_image(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        image: ClassInstanceRef<Image>,
+        x: i32,
+        y: i32,
+        pixel_level: bool,
+    ) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::collidesWith({this:?}, {image:?}, {x}, {y}, {pixel_level})");
+
+        if image.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "image is null").await);
+        }
+
+        let sprite = SpriteFrame::load(jvm, &this).await?;
+        if !sprite.visible {
+            return Ok(false);
+        }
+
+        let image = Image::image(jvm, &image).await?;
+        let Some(area) = intersect(sprite.collision_rect(), (x, y, image.width() as _, image.height() as _)) else {
+            return Ok(false);
+        };
+        if !pixel_level {
+            return Ok(true);
+        }
+
+        Ok(any_pixel(area, |x1, y1| {
+            sprite.is_opaque_at(x1, y1) && image.get_pixel(x1 - x, y1 - y).a != 0
+        }))
+    }
+
+    async fn paint(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, g: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.Sprite::paint({this:?}, {g:?})");
+
+        if g.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "g is null").await);
+        }
+
+        let visible: bool = jvm.get_field(&this, "visible", "Z").await?;
+        if !visible {
+            return Ok(());
+        }
+
+        let (x, y, _, _) = Layer::bounds(jvm, &this).await?;
+        let (frame_x, frame_y) = Self::frame_position(jvm, &this).await?;
+        let frame_width: i32 = jvm.get_field(&this, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(&this, "frameHeight", "I").await?;
+        let transform: i32 = jvm.get_field(&this, "transform", "I").await?;
+        let image: ClassInstanceRef<Image> = jvm.get_field(&this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+
+        jvm.invoke_virtual(
+            &g,
+            "drawRegion",
+            "(Ljavax/microedition/lcdui/Image;IIIIIIII)V",
+            (image, frame_x, frame_y, frame_width, frame_height, transform, x, y, ANCHOR_TOP_LEFT),
+        )
+        .await
+    }
+
+    async fn load_frames(
+        jvm: &Jvm,
+        this: &mut ClassInstanceRef<Self>,
+        image: ClassInstanceRef<Image>,
+        frame_width: i32,
+        frame_height: i32,
+    ) -> JvmResult<()> {
+        if image.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "image is null").await);
+        }
+
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+        if frame_width < 1 || frame_height < 1 || width % frame_width != 0 || height % frame_height != 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid frame size").await);
+        }
+
+        jvm.put_field(this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+        jvm.put_field(this, "frameWidth", "I", frame_width).await?;
+        jvm.put_field(this, "frameHeight", "I", frame_height).await?;
+
+        Ok(())
+    }
+
+    async fn reset_frame_sequence(jvm: &Jvm, this: &mut ClassInstanceRef<Self>) -> JvmResult<()> {
+        let raw_frame_count = Self::raw_frame_count(jvm, this).await?;
+
+        Self::store_frame_sequence(jvm, this, (0..raw_frame_count).collect()).await?;
+        jvm.put_field(this, "sequenceIndex", "I", 0).await
+    }
+
+    async fn reset_collision_rectangle(jvm: &Jvm, this: &mut ClassInstanceRef<Self>) -> JvmResult<()> {
+        let frame_width: i32 = jvm.get_field(this, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(this, "frameHeight", "I").await?;
+
+        jvm.put_field(this, "collisionX", "I", 0).await?;
+        jvm.put_field(this, "collisionY", "I", 0).await?;
+        jvm.put_field(this, "collisionWidth", "I", frame_width).await?;
+        jvm.put_field(this, "collisionHeight", "I", frame_height).await?;
+
+        Ok(())
+    }
+
+    async fn raw_frame_count(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<i32> {
+        let image: ClassInstanceRef<Image> = jvm.get_field(this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        if image.is_null() {
+            return Ok(0);
+        }
+
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+        let frame_width: i32 = jvm.get_field(this, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(this, "frameHeight", "I").await?;
+
+        Ok((width / frame_width) * (height / frame_height))
+    }
+
+    async fn frame_sequence(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<Vec<i32>> {
+        let frame_sequence: ClassInstanceRef<Array<i32>> = jvm.get_field(this, "frameSequence", "[I").await?;
+        let length = jvm.array_length(&frame_sequence).await?;
+
+        jvm.load_array(&frame_sequence, 0, length).await
+    }
+
+    async fn store_frame_sequence(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, frame_sequence: Vec<i32>) -> JvmResult<()> {
+        let mut array = jvm.instantiate_array("I", frame_sequence.len()).await?;
+        jvm.store_array(&mut array, 0, frame_sequence).await?;
+
+        jvm.put_field(this, "frameSequence", "[I", array).await
+    }
+
+    // top left position of current frame in image
+    async fn frame_position(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<(i32, i32)> {
+        let sequence_index: i32 = jvm.get_field(this, "sequenceIndex", "I").await?;
+        let frame = Self::frame_sequence(jvm, this).await?[sequence_index as usize];
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let frame_width: i32 = jvm.get_field(this, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(this, "frameHeight", "I").await?;
+        let columns = width / frame_width;
+
+        Ok(((frame % columns) * frame_width, (frame / columns) * frame_height))
+    }
+
+    // reference pixel position in painter's coordinate
+    async fn ref_pixel_position(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<(i32, i32)> {
+        let (x, y, _, _) = Layer::bounds(jvm, this).await?;
+        let (offset_x, offset_y) = Self::transformed_ref_pixel(jvm, this).await?;
+
+        Ok((x + offset_x, y + offset_y))
+    }
+
+    async fn move_ref_pixel(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        let (offset_x, offset_y) = Self::transformed_ref_pixel(jvm, this).await?;
+
+        jvm.put_field(this, "x", "I", x - offset_x).await?;
+        jvm.put_field(this, "y", "I", y - offset_y).await?;
+
+        Ok(())
+    }
+
+    async fn transformed_ref_pixel(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<(i32, i32)> {
+        let ref_x: i32 = jvm.get_field(this, "refX", "I").await?;
+        let ref_y: i32 = jvm.get_field(this, "refY", "I").await?;
+        let frame_width: i32 = jvm.get_field(this, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(this, "frameHeight", "I").await?;
+        let transform: i32 = jvm.get_field(this, "transform", "I").await?;
+
+        Ok(transform::transform_point(transform, ref_x, ref_y, frame_width, frame_height))
+    }
+}
+
+// snapshot of sprite's current frame for collision detection
+struct SpriteFrame {
+    x: i32,
+    y: i32,
+    visible: bool,
+    frame_x: i32,
+    frame_y: i32,
+    frame_width: i32,
+    frame_height: i32,
+    transform: i32,
+    collision: (i32, i32, i32, i32),
+    image: Box<dyn BackendImage>,
+}
+
+impl SpriteFrame {
+    async fn load(jvm: &Jvm, sprite: &ClassInstanceRef<Sprite>) -> JvmResult<Self> {
+        let (x, y, _, _) = Layer::bounds(jvm, sprite).await?;
+        let visible: bool = jvm.get_field(sprite, "visible", "Z").await?;
+        let (frame_x, frame_y) = Sprite::frame_position(jvm, sprite).await?;
+        let frame_width: i32 = jvm.get_field(sprite, "frameWidth", "I").await?;
+        let frame_height: i32 = jvm.get_field(sprite, "frameHeight", "I").await?;
+        let transform: i32 = jvm.get_field(sprite, "transform", "I").await?;
+
+        let collision = (
+            jvm.get_field(sprite, "collisionX", "I").await?,
+            jvm.get_field(sprite, "collisionY", "I").await?,
+            jvm.get_field(sprite, "collisionWidth", "I").await?,
+            jvm.get_field(sprite, "collisionHeight", "I").await?,
+        );
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(sprite, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        let image = Image::image(jvm, &image).await?;
+
+        Ok(Self {
+            x,
+            y,
+            visible,
+            frame_x,
+            frame_y,
+            frame_width,
+            frame_height,
+            transform,
+            collision,
+            image,
+        })
+    }
+
+    // collision rectangle in painter's coordinate, with transform applied
+    fn collision_rect(&self) -> (i32, i32, i32, i32) {
+        let (x, y, width, height) = self.collision;
+        if width == 0 || height == 0 {
+            return (self.x, self.y, 0, 0);
+        }
+
+        let (x1, y1) = transform::transform_point(self.transform, x, y, self.frame_width, self.frame_height);
+        let (x2, y2) = transform::transform_point(self.transform, x + width - 1, y + height - 1, self.frame_width, self.frame_height);
+
+        (self.x + x1.min(x2), self.y + y1.min(y2), (x1 - x2).abs() + 1, (y1 - y2).abs() + 1)
+    }
+
+    fn is_opaque_at(&self, x: i32, y: i32) -> bool {
+        let (width, height) = transform::transformed_size(self.transform, self.frame_width, self.frame_height);
+        let (x, y) = (x - self.x, y - self.y);
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return false;
+        }
+
+        let (x, y) = if self.transform == TRANS_NONE {
+            (x, y)
+        } else {
+            transform::source_point(self.transform, x, y, self.frame_width, self.frame_height)
+        };
+
+        self.image.get_pixel(self.frame_x + x, self.frame_y + y).a != 0
+    }
+}
+
+fn intersect(a: (i32, i32, i32, i32), b: (i32, i32, i32, i32)) -> Option<(i32, i32, i32, i32)> {
+    let x1 = a.0.max(b.0);
+    let y1 = a.1.max(b.1);
+    let x2 = (a.0 + a.2).min(b.0 + b.2);
+    let y2 = (a.1 + a.3).min(b.1 + b.3);
+
+    if x1 >= x2 || y1 >= y2 {
+        return None;
+    }
+
+    Some((x1, y1, x2 - x1, y2 - y1))
+}
+
+fn any_pixel(area: (i32, i32, i32, i32), mut predicate: impl FnMut(i32, i32) -> bool) -> bool {
+    let (x, y, width, height) = area;
+
+    (y..y + height).any(|y| (x..x + width).any(|x| predicate(x, y)))
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    use crate::{classes::javax::microedition::lcdui::Image, get_protos};
+
+    // mutable images start fully transparent, so only the filled area is opaque
+    async fn create_image(jvm: &Jvm, width: i32, height: i32, fill: (i32, i32, i32, i32)) -> JvmResult<ClassInstanceRef<Image>> {
+        let image: ClassInstanceRef<Image> = jvm
+            .invoke_static(
+                "javax/microedition/lcdui/Image",
+                "createImage",
+                "(II)Ljavax/microedition/lcdui/Image;",
+                (width, height),
+            )
+            .await?;
+
+        let graphics = jvm
+            .new_class(
+                "javax/microedition/lcdui/Graphics",
+                "(Ljavax/microedition/lcdui/Image;)V",
+                (image.clone(),),
+            )
+            .await?;
+        let _: () = jvm.invoke_virtual(&graphics, "setColor", "(I)V", (0xff0000,)).await?;
+        let _: () = jvm.invoke_virtual(&graphics, "fillRect", "(IIII)V", fill).await?;
+
+        Ok(image)
+    }
+
+    #[test]
+    fn test_sprite_collision() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            // 4x4 image with only the left half opaque
+            let image = create_image(&jvm, 4, 4, (0, 0, 2, 4)).await?;
+
+            let a = jvm
+                .new_class(
+                    "javax/microedition/lcdui/game/Sprite",
+                    "(Ljavax/microedition/lcdui/Image;)V",
+                    (image.clone(),),
+                )
+                .await?;
+            let b = jvm
+                .new_class(
+                    "javax/microedition/lcdui/game/Sprite",
+                    "(Ljavax/microedition/lcdui/Image;)V",
+                    (image.clone(),),
+                )
+                .await?;
+
+            // bounds overlap only where a is transparent
+            let _: () = jvm.invoke_virtual(&b, "setPosition", "(II)V", (2, 0)).await?;
+            let rect: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/game/Sprite;Z)Z", (b.clone(), false))
+                .await?;
+            let pixel: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/game/Sprite;Z)Z", (b.clone(), true))
+                .await?;
+            assert!(rect);
+            assert!(!pixel);
+
+            // column 1 is opaque in both sprites
+            let _: () = jvm.invoke_virtual(&b, "setPosition", "(II)V", (1, 0)).await?;
+            let pixel: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/game/Sprite;Z)Z", (b.clone(), true))
+                .await?;
+            assert!(pixel);
+
+            // collision rectangle shrinks the rect level test
+            let _: () = jvm.invoke_virtual(&a, "defineCollisionRectangle", "(IIII)V", (0, 0, 1, 4)).await?;
+            let rect: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/game/Sprite;Z)Z", (b.clone(), false))
+                .await?;
+            assert!(!rect);
+
+            // invisible sprites never collide
+            let _: () = jvm.invoke_virtual(&a, "defineCollisionRectangle", "(IIII)V", (0, 0, 4, 4)).await?;
+            let _: () = jvm.invoke_virtual(&b, "setVisible", "(Z)V", (false,)).await?;
+            let rect: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/game/Sprite;Z)Z", (b.clone(), false))
+                .await?;
+            assert!(!rect);
+
+            // image collision uses the image's own alpha
+            let other = create_image(&jvm, 4, 4, (2, 0, 2, 4)).await?;
+            let rect: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/Image;IIZ)Z", (other.clone(), 0, 0, false))
+                .await?;
+            let pixel: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/Image;IIZ)Z", (other.clone(), 0, 0, true))
+                .await?;
+            assert!(rect);
+            assert!(!pixel);
+
+            let pixel: bool = jvm
+                .invoke_virtual(&a, "collidesWith", "(Ljavax/microedition/lcdui/Image;IIZ)Z", (other, -2, 0, true))
+                .await?;
+            assert!(pixel);
+
+            Ok(())
+        })
+    }
+}
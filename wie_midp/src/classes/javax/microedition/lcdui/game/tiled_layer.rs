@@ -0,0 +1,555 @@
+use alloc::{boxed::Box, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::Image as BackendImage;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::{Graphics, Image, game::Layer};
+
+// class javax.microedition.lcdui.game.TiledLayer
+pub struct TiledLayer;
+
+impl TiledLayer {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/game/TiledLayer",
+            parent_class: Some("javax/microedition/lcdui/game/Layer"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(IILjavax/microedition/lcdui/Image;II)V", Self::init, Default::default()),
+                JavaMethodProto::new("createAnimatedTile", "(I)I", Self::create_animated_tile, Default::default()),
+                JavaMethodProto::new("setAnimatedTile", "(II)V", Self::set_animated_tile, Default::default()),
+                JavaMethodProto::new("getAnimatedTile", "(I)I", Self::get_animated_tile, Default::default()),
+                JavaMethodProto::new("setCell", "(III)V", Self::set_cell, Default::default()),
+                JavaMethodProto::new("getCell", "(II)I", Self::get_cell, Default::default()),
+                JavaMethodProto::new("fillCells", "(IIIII)V", Self::fill_cells, Default::default()),
+                JavaMethodProto::new("getCellWidth", "()I", Self::get_cell_width, Default::default()),
+                JavaMethodProto::new("getCellHeight", "()I", Self::get_cell_height, Default::default()),
+                JavaMethodProto::new("getColumns", "()I", Self::get_columns, Default::default()),
+                JavaMethodProto::new("getRows", "()I", Self::get_rows, Default::default()),
+                JavaMethodProto::new(
+                    "setStaticTileSet",
+                    "(Ljavax/microedition/lcdui/Image;II)V",
+                    Self::set_static_tile_set,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("paint", "(Ljavax/microedition/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("image", "Ljavax/microedition/lcdui/Image;", Default::default()),
+                JavaFieldProto::new("columns", "I", Default::default()),
+                JavaFieldProto::new("rows", "I", Default::default()),
+                JavaFieldProto::new("cellWidth", "I", Default::default()),
+                JavaFieldProto::new("cellHeight", "I", Default::default()),
+                JavaFieldProto::new("cells", "[I", Default::default()),
+                JavaFieldProto::new("animatedTiles", "[I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        columns: i32,
+        rows: i32,
+        image: ClassInstanceRef<Image>,
+        tile_width: i32,
+        tile_height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::<init>({this:?}, {columns}, {rows}, {image:?}, {tile_width}, {tile_height})");
+
+        if columns < 1 || rows < 1 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid columns or rows").await);
+        }
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/game/Layer",
+                "<init>",
+                "(II)V",
+                (columns * tile_width, rows * tile_height),
+            )
+            .await?;
+
+        jvm.put_field(&mut this, "columns", "I", columns).await?;
+        jvm.put_field(&mut this, "rows", "I", rows).await?;
+
+        let cells = jvm.instantiate_array("I", (columns * rows) as _).await?;
+        jvm.put_field(&mut this, "cells", "[I", cells).await?;
+        let animated_tiles = jvm.instantiate_array("I", 0).await?;
+        jvm.put_field(&mut this, "animatedTiles", "[I", animated_tiles).await?;
+
+        Self::set_tile_set(jvm, &mut this, image, tile_width, tile_height).await?;
+
+        Ok(())
+    }
+
+    async fn create_animated_tile(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        static_tile_index: i32,
+    ) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::createAnimatedTile({this:?}, {static_tile_index})");
+
+        Self::check_static_tile(jvm, &this, static_tile_index).await?;
+
+        let mut animated_tiles = Self::animated_tiles(jvm, &this).await?;
+        animated_tiles.push(static_tile_index);
+
+        let count = animated_tiles.len();
+        let mut array = jvm.instantiate_array("I", count).await?;
+        jvm.store_array(&mut array, 0, animated_tiles).await?;
+        jvm.put_field(&mut this, "animatedTiles", "[I", array).await?;
+
+        // animated tiles are referenced by negative index starting from -1
+        Ok(-(count as i32))
+    }
+
+    async fn set_animated_tile(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        animated_tile_index: i32,
+        static_tile_index: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::setAnimatedTile({this:?}, {animated_tile_index}, {static_tile_index})");
+
+        Self::check_static_tile(jvm, &this, static_tile_index).await?;
+        let index = Self::check_animated_tile(jvm, &this, animated_tile_index).await?;
+
+        let mut animated_tiles: ClassInstanceRef<Array<i32>> = jvm.get_field(&this, "animatedTiles", "[I").await?;
+        jvm.store_array(&mut animated_tiles, index, vec![static_tile_index]).await?;
+
+        Ok(())
+    }
+
+    async fn get_animated_tile(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, animated_tile_index: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::getAnimatedTile({this:?}, {animated_tile_index})");
+
+        let index = Self::check_animated_tile(jvm, &this, animated_tile_index).await?;
+
+        Ok(Self::animated_tiles(jvm, &this).await?[index])
+    }
+
+    async fn set_cell(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, col: i32, row: i32, tile_index: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::setCell({this:?}, {col}, {row}, {tile_index})");
+
+        jvm.invoke_virtual(&this, "fillCells", "(IIIII)V", (col, row, 1, 1, tile_index)).await
+    }
+
+    async fn get_cell(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, col: i32, row: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::getCell({this:?}, {col}, {row})");
+
+        let columns: i32 = jvm.get_field(&this, "columns", "I").await?;
+        let rows: i32 = jvm.get_field(&this, "rows", "I").await?;
+        if col < 0 || row < 0 || col >= columns || row >= rows {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid cell").await);
+        }
+
+        let cells: ClassInstanceRef<Array<i32>> = jvm.get_field(&this, "cells", "[I").await?;
+
+        Ok(jvm.load_array(&cells, (row * columns + col) as _, 1).await?[0])
+    }
+
+    async fn fill_cells(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        col: i32,
+        row: i32,
+        num_cols: i32,
+        num_rows: i32,
+        tile_index: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::fillCells({this:?}, {col}, {row}, {num_cols}, {num_rows}, {tile_index})");
+
+        let columns: i32 = jvm.get_field(&this, "columns", "I").await?;
+        let rows: i32 = jvm.get_field(&this, "rows", "I").await?;
+        if num_cols < 0 || num_rows < 0 || col < 0 || row < 0 || col + num_cols > columns || row + num_rows > rows {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid region").await);
+        }
+
+        if tile_index < 0 {
+            Self::check_animated_tile(jvm, &this, tile_index).await?;
+        } else if tile_index > 0 {
+            Self::check_static_tile(jvm, &this, tile_index).await?;
+        }
+
+        let mut cells: ClassInstanceRef<Array<i32>> = jvm.get_field(&this, "cells", "[I").await?;
+        for y in row..row + num_rows {
+            jvm.store_array(&mut cells, (y * columns + col) as _, vec![tile_index; num_cols as _])
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_cell_width(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::getCellWidth({this:?})");
+
+        jvm.get_field(&this, "cellWidth", "I").await
+    }
+
+    async fn get_cell_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::getCellHeight({this:?})");
+
+        jvm.get_field(&this, "cellHeight", "I").await
+    }
+
+    async fn get_columns(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::getColumns({this:?})");
+
+        jvm.get_field(&this, "columns", "I").await
+    }
+
+    async fn get_rows(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::getRows({this:?})");
+
+        jvm.get_field(&this, "rows", "I").await
+    }
+
+    async fn set_static_tile_set(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        image: ClassInstanceRef<Image>,
+        tile_width: i32,
+        tile_height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::setStaticTileSet({this:?}, {image:?}, {tile_width}, {tile_height})");
+
+        let old_tile_count = Self::static_tile_count(jvm, &this).await?;
+        Self::set_tile_set(jvm, &mut this, image, tile_width, tile_height).await?;
+
+        let columns: i32 = jvm.get_field(&this, "columns", "I").await?;
+        let rows: i32 = jvm.get_field(&this, "rows", "I").await?;
+        jvm.put_field(&mut this, "width", "I", columns * tile_width).await?;
+        jvm.put_field(&mut this, "height", "I", rows * tile_height).await?;
+
+        // cells and animated tiles are reset if new tile set has fewer tiles
+        if Self::static_tile_count(jvm, &this).await? < old_tile_count {
+            let cells = jvm.instantiate_array("I", (columns * rows) as _).await?;
+            jvm.put_field(&mut this, "cells", "[I", cells).await?;
+            let animated_tiles = jvm.instantiate_array("I", 0).await?;
+            jvm.put_field(&mut this, "animatedTiles", "[I", animated_tiles).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn paint(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, mut g: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.game.TiledLayer::paint({this:?}, {g:?})");
+
+        let visible: bool = jvm.get_field(&this, "visible", "Z").await?;
+        if !visible {
+            return Ok(());
+        }
+
+        let tile_map = TileMap::load(jvm, &this).await?;
+
+        let translate_x: i32 = jvm.invoke_virtual(&g, "getTranslateX", "()I", ()).await?;
+        let translate_y: i32 = jvm.invoke_virtual(&g, "getTranslateY", "()I", ()).await?;
+        let clip = Graphics::clip(jvm, &g).await?;
+
+        let target = Graphics::image(jvm, &mut g).await?;
+        let mut canvas = Image::canvas(jvm, &target).await?;
+
+        for row in 0..tile_map.rows {
+            for col in 0..tile_map.columns {
+                let tile = tile_map.tile(col, row);
+                if tile == 0 {
+                    continue;
+                }
+
+                let (src_x, src_y) = tile_map.tile_position(tile);
+                let x = translate_x + tile_map.x + col * tile_map.cell_width;
+                let y = translate_y + tile_map.y + row * tile_map.cell_height;
+                if x + tile_map.cell_width <= clip.x
+                    || y + tile_map.cell_height <= clip.y
+                    || x >= clip.x + clip.width as i32
+                    || y >= clip.y + clip.height as i32
+                {
+                    continue;
+                }
+
+                canvas.draw(
+                    x,
+                    y,
+                    tile_map.cell_width as _,
+                    tile_map.cell_height as _,
+                    &*tile_map.image,
+                    src_x,
+                    src_y,
+                    clip,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_tile_set(
+        jvm: &Jvm,
+        this: &mut ClassInstanceRef<Self>,
+        image: ClassInstanceRef<Image>,
+        tile_width: i32,
+        tile_height: i32,
+    ) -> JvmResult<()> {
+        if image.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "image is null").await);
+        }
+
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+        if tile_width < 1 || tile_height < 1 || width % tile_width != 0 || height % tile_height != 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid tile size").await);
+        }
+
+        jvm.put_field(this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+        jvm.put_field(this, "cellWidth", "I", tile_width).await?;
+        jvm.put_field(this, "cellHeight", "I", tile_height).await?;
+
+        Ok(())
+    }
+
+    async fn static_tile_count(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<i32> {
+        let image: ClassInstanceRef<Image> = jvm.get_field(this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        if image.is_null() {
+            return Ok(0);
+        }
+
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+        let cell_width: i32 = jvm.get_field(this, "cellWidth", "I").await?;
+        let cell_height: i32 = jvm.get_field(this, "cellHeight", "I").await?;
+
+        Ok((width / cell_width) * (height / cell_height))
+    }
+
+    async fn check_static_tile(jvm: &Jvm, this: &ClassInstanceRef<Self>, static_tile_index: i32) -> JvmResult<()> {
+        if static_tile_index < 0 || static_tile_index > Self::static_tile_count(jvm, this).await? {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid static tile index").await);
+        }
+
+        Ok(())
+    }
+
+    async fn check_animated_tile(jvm: &Jvm, this: &ClassInstanceRef<Self>, animated_tile_index: i32) -> JvmResult<usize> {
+        let count = Self::animated_tiles(jvm, this).await?.len() as i32;
+        if animated_tile_index >= 0 || -animated_tile_index > count {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid animated tile index").await);
+        }
+
+        Ok((-animated_tile_index - 1) as _)
+    }
+
+    async fn animated_tiles(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<Vec<i32>> {
+        let animated_tiles: ClassInstanceRef<Array<i32>> = jvm.get_field(this, "animatedTiles", "[I").await?;
+        let length = jvm.array_length(&animated_tiles).await?;
+
+        jvm.load_array(&animated_tiles, 0, length).await
+    }
+}
+
+// snapshot of tiled layer for painting and collision detection
+pub struct TileMap {
+    pub x: i32,
+    pub y: i32,
+    pub columns: i32,
+    pub rows: i32,
+    pub cell_width: i32,
+    pub cell_height: i32,
+    cells: Vec<i32>,
+    animated_tiles: Vec<i32>,
+    image: Box<dyn BackendImage>,
+}
+
+impl TileMap {
+    pub async fn load(jvm: &Jvm, this: &ClassInstanceRef<TiledLayer>) -> JvmResult<Self> {
+        let (x, y, _, _) = Layer::bounds(jvm, this).await?;
+        let columns: i32 = jvm.get_field(this, "columns", "I").await?;
+        let rows: i32 = jvm.get_field(this, "rows", "I").await?;
+        let cell_width: i32 = jvm.get_field(this, "cellWidth", "I").await?;
+        let cell_height: i32 = jvm.get_field(this, "cellHeight", "I").await?;
+
+        let cells: ClassInstanceRef<Array<i32>> = jvm.get_field(this, "cells", "[I").await?;
+        let cells = jvm.load_array(&cells, 0, (columns * rows) as _).await?;
+        let animated_tiles = TiledLayer::animated_tiles(jvm, this).await?;
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        let image = Image::image(jvm, &image).await?;
+
+        Ok(Self {
+            x,
+            y,
+            columns,
+            rows,
+            cell_width,
+            cell_height,
+            cells,
+            animated_tiles,
+            image,
+        })
+    }
+
+    // static tile index of cell, animated tiles resolved. 0 if cell is empty
+    pub fn tile(&self, col: i32, row: i32) -> i32 {
+        let tile = self.cells[(row * self.columns + col) as usize];
+        if tile < 0 {
+            self.animated_tiles.get((-tile - 1) as usize).copied().unwrap_or(0)
+        } else {
+            tile
+        }
+    }
+
+    // returns static tile index of cell containing given point, 0 if there's none
+    pub fn tile_at(&self, x: i32, y: i32) -> i32 {
+        let (col, row) = ((x - self.x).div_euclid(self.cell_width), (y - self.y).div_euclid(self.cell_height));
+        if x < self.x || y < self.y || col >= self.columns || row >= self.rows {
+            return 0;
+        }
+
+        self.tile(col, row)
+    }
+
+    pub fn is_opaque_at(&self, x: i32, y: i32) -> bool {
+        let tile = self.tile_at(x, y);
+        if tile == 0 {
+            return false;
+        }
+
+        let (src_x, src_y) = self.tile_position(tile);
+        let pixel = self.image.get_pixel(
+            src_x + (x - self.x).rem_euclid(self.cell_width),
+            src_y + (y - self.y).rem_euclid(self.cell_height),
+        );
+
+        pixel.a != 0
+    }
+
+    fn tile_position(&self, tile: i32) -> (i32, i32) {
+        let tiles_per_row = self.image.width() as i32 / self.cell_width;
+
+        (
+            ((tile - 1) % tiles_per_row) * self.cell_width,
+            ((tile - 1) / tiles_per_row) * self.cell_height,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::{ClassInstanceRef, Result as JvmResult};
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    use crate::{classes::javax::microedition::lcdui::Image, get_protos};
+
+    #[test]
+    fn test_tiled_layer() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            // two 2x2 tiles: tile 1 is red, tile 2 is green
+            let tiles: ClassInstanceRef<Image> = jvm
+                .invoke_static(
+                    "javax/microedition/lcdui/Image",
+                    "createImage",
+                    "(II)Ljavax/microedition/lcdui/Image;",
+                    (4, 2),
+                )
+                .await?;
+            let graphics = jvm
+                .new_class(
+                    "javax/microedition/lcdui/Graphics",
+                    "(Ljavax/microedition/lcdui/Image;)V",
+                    (tiles.clone(),),
+                )
+                .await?;
+            let _: () = jvm.invoke_virtual(&graphics, "setColor", "(I)V", (0xff0000,)).await?;
+            let _: () = jvm.invoke_virtual(&graphics, "fillRect", "(IIII)V", (0, 0, 2, 2)).await?;
+            let _: () = jvm.invoke_virtual(&graphics, "setColor", "(I)V", (0x00ff00,)).await?;
+            let _: () = jvm.invoke_virtual(&graphics, "fillRect", "(IIII)V", (2, 0, 2, 2)).await?;
+
+            let layer = jvm
+                .new_class(
+                    "javax/microedition/lcdui/game/TiledLayer",
+                    "(IILjavax/microedition/lcdui/Image;II)V",
+                    (3, 2, tiles, 2, 2),
+                )
+                .await?;
+
+            let cell: i32 = jvm.invoke_virtual(&layer, "getCell", "(II)I", (0, 0)).await?;
+            assert_eq!(cell, 0);
+
+            let _: () = jvm.invoke_virtual(&layer, "setCell", "(III)V", (1, 0, 2)).await?;
+            let _: () = jvm.invoke_virtual(&layer, "fillCells", "(IIIII)V", (0, 1, 3, 1, 1)).await?;
+            let cell: i32 = jvm.invoke_virtual(&layer, "getCell", "(II)I", (1, 0)).await?;
+            assert_eq!(cell, 2);
+            let cell: i32 = jvm.invoke_virtual(&layer, "getCell", "(II)I", (2, 1)).await?;
+            assert_eq!(cell, 1);
+
+            let result: JvmResult<()> = jvm.invoke_virtual(&layer, "setCell", "(III)V", (3, 0, 1)).await;
+            assert!(result.is_err());
+            let result: JvmResult<()> = jvm.invoke_virtual(&layer, "setCell", "(III)V", (0, 0, 3)).await;
+            assert!(result.is_err());
+
+            // animated tiles are numbered from -1 downwards
+            let first: i32 = jvm.invoke_virtual(&layer, "createAnimatedTile", "(I)I", (1,)).await?;
+            let second: i32 = jvm.invoke_virtual(&layer, "createAnimatedTile", "(I)I", (2,)).await?;
+            assert_eq!(first, -1);
+            assert_eq!(second, -2);
+
+            let _: () = jvm.invoke_virtual(&layer, "setAnimatedTile", "(II)V", (-1, 2)).await?;
+            let tile: i32 = jvm.invoke_virtual(&layer, "getAnimatedTile", "(I)I", (-1,)).await?;
+            assert_eq!(tile, 2);
+
+            let result: JvmResult<i32> = jvm.invoke_virtual(&layer, "getAnimatedTile", "(I)I", (-3,)).await;
+            assert!(result.is_err());
+
+            let _: () = jvm.invoke_virtual(&layer, "setCell", "(III)V", (0, 0, -1)).await?;
+            let cell: i32 = jvm.invoke_virtual(&layer, "getCell", "(II)I", (0, 0)).await?;
+            assert_eq!(cell, -1);
+
+            let target: ClassInstanceRef<Image> = jvm
+                .invoke_static(
+                    "javax/microedition/lcdui/Image",
+                    "createImage",
+                    "(II)Ljavax/microedition/lcdui/Image;",
+                    (6, 4),
+                )
+                .await?;
+            let graphics = jvm
+                .new_class(
+                    "javax/microedition/lcdui/Graphics",
+                    "(Ljavax/microedition/lcdui/Image;)V",
+                    (target.clone(),),
+                )
+                .await?;
+            let _: () = jvm
+                .invoke_virtual(&layer, "paint", "(Ljavax/microedition/lcdui/Graphics;)V", (graphics,))
+                .await?;
+
+            let image = Image::image(&jvm, &target).await?;
+            let rgb = |x, y| {
+                let color = image.get_pixel(x, y);
+                (color.r, color.g, color.b)
+            };
+
+            // animated cell resolves to its current static tile
+            assert_eq!(rgb(0, 0), (0x00, 0xff, 0x00));
+            assert_eq!(rgb(2, 0), (0x00, 0xff, 0x00));
+            assert_eq!(rgb(4, 0), (0x00, 0x00, 0x00));
+            assert_eq!(rgb(5, 3), (0xff, 0x00, 0x00));
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use alloc::boxed::Box;
+
+use wie_backend::canvas::{ArgbPixel, Image as BackendImage, ImageBuffer, VecImageBuffer};
+
+// Sprite.TRANS_* constants, also used by Graphics.drawRegion
+pub const TRANS_NONE: i32 = 0;
+pub const TRANS_MIRROR_ROT180: i32 = 1;
+pub const TRANS_MIRROR: i32 = 2;
+pub const TRANS_ROT180: i32 = 3;
+pub const TRANS_MIRROR_ROT270: i32 = 4;
+pub const TRANS_ROT90: i32 = 5;
+pub const TRANS_ROT270: i32 = 6;
+pub const TRANS_MIRROR_ROT90: i32 = 7;
+
+pub fn is_valid(transform: i32) -> bool {
+    (TRANS_NONE..=TRANS_MIRROR_ROT90).contains(&transform)
+}
+
+// rotations by 90 or 270 degrees swap width and height
+pub fn transformed_size(transform: i32, width: i32, height: i32) -> (i32, i32) {
+    if transform & 4 != 0 { (height, width) } else { (width, height) }
+}
+
+// maps pixel in untransformed width x height region to pixel in transformed region
+pub fn transform_point(transform: i32, x: i32, y: i32, width: i32, height: i32) -> (i32, i32) {
+    match transform {
+        TRANS_MIRROR_ROT180 => (x, height - 1 - y),
+        TRANS_MIRROR => (width - 1 - x, y),
+        TRANS_ROT180 => (width - 1 - x, height - 1 - y),
+        TRANS_MIRROR_ROT270 => (y, x),
+        TRANS_ROT90 => (height - 1 - y, x),
+        TRANS_ROT270 => (y, width - 1 - x),
+        TRANS_MIRROR_ROT90 => (height - 1 - y, width - 1 - x),
+        _ => (x, y),
+    }
+}
+
+// maps pixel in transformed region back to pixel in untransformed width x height region
+pub fn source_point(transform: i32, x: i32, y: i32, width: i32, height: i32) -> (i32, i32) {
+    match transform {
+        TRANS_MIRROR_ROT180 => (x, height - 1 - y),
+        TRANS_MIRROR => (width - 1 - x, y),
+        TRANS_ROT180 => (width - 1 - x, height - 1 - y),
+        TRANS_MIRROR_ROT270 => (y, x),
+        TRANS_ROT90 => (y, height - 1 - x),
+        TRANS_ROT270 => (width - 1 - y, x),
+        TRANS_MIRROR_ROT90 => (width - 1 - y, height - 1 - x),
+        _ => (x, y),
+    }
+}
+
+// copies region of src into new image with transform applied
+pub fn transform_region(src: &dyn BackendImage, src_x: i32, src_y: i32, width: i32, height: i32, transform: i32) -> Box<dyn BackendImage> {
+    let (dst_width, dst_height) = transformed_size(transform, width, height);
+    let mut result = VecImageBuffer::<ArgbPixel>::new(dst_width.max(0) as _, dst_height.max(0) as _);
+
+    for y in 0..dst_height {
+        for x in 0..dst_width {
+            let (x1, y1) = source_point(transform, x, y, width, height);
+            let (x1, y1) = (src_x + x1, src_y + y1);
+            if x1 < 0 || y1 < 0 || x1 >= src.width() as i32 || y1 >= src.height() as i32 {
+                continue;
+            }
+
+            result.put_pixel(x, y, src.get_pixel(x1, y1));
+        }
+    }
+
+    Box::new(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_transform_roundtrip() {
+        for transform in TRANS_NONE..=TRANS_MIRROR_ROT90 {
+            let (width, height) = (3, 5);
+            let (dst_width, dst_height) = transformed_size(transform, width, height);
+
+            for y in 0..height {
+                for x in 0..width {
+                    let (tx, ty) = transform_point(transform, x, y, width, height);
+                    assert!(tx >= 0 && ty >= 0 && tx < dst_width && ty < dst_height);
+                    assert_eq!(source_point(transform, tx, ty, width, height), (x, y));
+                }
+            }
+        }
+
+        // rotating clockwise moves top left pixel to top right
+        assert_eq!(transform_point(TRANS_ROT90, 0, 0, 3, 5), (4, 0));
+    }
+}
@@ -0,0 +1,204 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::{
+    javax::microedition::lcdui::{
+        Graphics, Item,
+        renderer::{self, BORDER, HIGHLIGHT, LINE_HEIGHT, Renderer},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
+
+const INDEFINITE: i32 = -1;
+
+// class javax.microedition.lcdui.Gauge
+pub struct Gauge;
+
+impl Gauge {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/Gauge",
+            parent_class: Some("javax/microedition/lcdui/Item"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;ZII)V", Self::init, Default::default()),
+                JavaMethodProto::new("getValue", "()I", Self::get_value, Default::default()),
+                JavaMethodProto::new("setValue", "(I)V", Self::set_value, Default::default()),
+                JavaMethodProto::new("getMaxValue", "()I", Self::get_max_value, Default::default()),
+                JavaMethodProto::new("setMaxValue", "(I)V", Self::set_max_value, Default::default()),
+                JavaMethodProto::new("isInteractive", "()Z", Self::is_interactive, Default::default()),
+                // wie private methods
+                JavaMethodProto::new("getItemHeight", "(I)I", Self::get_item_height, Default::default()),
+                JavaMethodProto::new(
+                    "paintItem",
+                    "(Ljavax/microedition/lcdui/Graphics;IIIZ)V",
+                    Self::paint_item,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("isItemFocusable", "()Z", Self::is_item_focusable, Default::default()),
+                JavaMethodProto::new("handleItemKey", "(II)Z", Self::handle_item_key, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("interactive", "Z", Default::default()),
+                JavaFieldProto::new("maxValue", "I", Default::default()),
+                JavaFieldProto::new("value", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        label: ClassInstanceRef<String>,
+        interactive: bool,
+        max_value: i32,
+        initial_value: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::<init>({this:?}, {label:?}, {interactive}, {max_value}, {initial_value})");
+
+        let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Item", "<init>", "()V", ()).await?;
+
+        if max_value <= 0 && !(max_value == INDEFINITE && !interactive) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid maxValue").await);
+        }
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        jvm.put_field(&mut this, "interactive", "Z", interactive).await?;
+        jvm.put_field(&mut this, "maxValue", "I", max_value).await?;
+        jvm.put_field(&mut this, "value", "I", Self::clamp_value(initial_value, max_value))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_value(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::getValue({this:?})");
+
+        jvm.get_field(&this, "value", "I").await
+    }
+
+    async fn set_value(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, value: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::setValue({this:?}, {value})");
+
+        let max_value: i32 = jvm.get_field(&this, "maxValue", "I").await?;
+        jvm.put_field(&mut this, "value", "I", Self::clamp_value(value, max_value)).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_max_value(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::getMaxValue({this:?})");
+
+        jvm.get_field(&this, "maxValue", "I").await
+    }
+
+    async fn set_max_value(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, max_value: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::setMaxValue({this:?}, {max_value})");
+
+        let interactive: bool = jvm.get_field(&this, "interactive", "Z").await?;
+        if max_value <= 0 && !(max_value == INDEFINITE && !interactive) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid maxValue").await);
+        }
+
+        let value: i32 = jvm.get_field(&this, "value", "I").await?;
+        jvm.put_field(&mut this, "maxValue", "I", max_value).await?;
+        jvm.put_field(&mut this, "value", "I", Self::clamp_value(value, max_value)).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn is_interactive(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::isInteractive({this:?})");
+
+        jvm.get_field(&this, "interactive", "Z").await
+    }
+
+    async fn get_item_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, width: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::getItemHeight({this:?}, {width})");
+
+        let label_height = Item::label_height(jvm, &this, width).await?;
+
+        Ok(label_height + LINE_HEIGHT)
+    }
+
+    async fn paint_item(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        focused: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::paintItem({this:?}, {graphics:?}, {x}, {y}, {width}, {focused})");
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+        let label_height = Item::paint_label(jvm, &this, &mut renderer, x, y, width).await?;
+
+        let max_value: i32 = jvm.get_field(&this, "maxValue", "I").await?;
+        let value: i32 = jvm.get_field(&this, "value", "I").await?;
+
+        let bar_y = y + label_height + 2;
+        let bar_height = LINE_HEIGHT - 4;
+        renderer.draw_rect(x, bar_y, width - 1, bar_height, BORDER);
+
+        let filled = if max_value > 0 { (width - 4) * value / max_value } else { 0 };
+        renderer.fill_rect(x + 2, bar_y + 2, filled, bar_height - 3, HIGHLIGHT);
+
+        Ok(())
+    }
+
+    async fn is_item_focusable(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::isItemFocusable({this:?})");
+
+        jvm.get_field(&this, "interactive", "Z").await
+    }
+
+    async fn handle_item_key(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        event_type: i32,
+        code: i32,
+    ) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Gauge::handleItemKey({this:?}, {event_type}, {code})");
+
+        let interactive: bool = jvm.get_field(&this, "interactive", "Z").await?;
+        if !interactive || matches!(KeyboardEventType::from_raw(event_type), KeyboardEventType::KeyReleased) {
+            return Ok(false);
+        }
+
+        let delta = match MIDPKeyCode::from_raw(code) {
+            MIDPKeyCode::LEFT => -1,
+            MIDPKeyCode::RIGHT => 1,
+            _ => return Ok(false),
+        };
+
+        let max_value: i32 = jvm.get_field(&this, "maxValue", "I").await?;
+        let value: i32 = jvm.get_field(&this, "value", "I").await?;
+        let new_value = Self::clamp_value(value + delta, max_value);
+
+        if new_value != value {
+            jvm.put_field(&mut this, "value", "I", new_value).await?;
+            renderer::request_redraw(context);
+
+            Item::notify_state_changed(jvm, &this).await?;
+        }
+
+        Ok(true)
+    }
+
+    fn clamp_value(value: i32, max_value: i32) -> i32 {
+        if max_value == INDEFINITE { value } else { value.clamp(0, max_value) }
+    }
+}
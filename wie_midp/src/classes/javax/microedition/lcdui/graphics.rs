@@ -10,7 +10,7 @@ use java_runtime::classes::java::lang::String;
 use wie_backend::canvas::{Clip, PixelType, Rgb8Pixel, TextAlignment, VecImageBuffer};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
-use crate::classes::javax::microedition::lcdui::{Font, Image};
+use crate::classes::javax::microedition::lcdui::{Font, Image, game::transform};
 
 bitflags::bitflags! {
     struct Anchor: i32 {
@@ -663,7 +663,17 @@ impl Graphics {
             return Err(jvm.exception("java/lang/NullPointerException", "img is null").await);
         }
 
+        if !transform::is_valid(transform) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid transform").await);
+        }
+
         let src_image = Image::image(jvm, &img).await?;
+        let (src_image, src_x, src_y) = if transform == transform::TRANS_NONE {
+            (src_image, src_x, src_y)
+        } else {
+            (transform::transform_region(&*src_image, src_x, src_y, width, height, transform), 0, 0)
+        };
+        let (width, height) = transform::transformed_size(transform, width, height);
 
         let image = Self::image(jvm, &mut this).await?;
         let mut canvas = Image::canvas(jvm, &image).await?;
@@ -671,7 +681,7 @@ impl Graphics {
         let x_delta = if anchor.contains(Anchor::HCENTER) {
             -width / 2
         } else if anchor.contains(Anchor::RIGHT) {
-            -height
+            -width
         } else {
             0
         };
@@ -12,7 +12,8 @@ use jvm::{
 };
 
 use wie_backend::canvas::{
-    ArgbPixel, Canvas, Color, Image as BackendImage, ImageBuffer, ImageBufferCanvas, PixelType, Rgb332Pixel, Rgb565Pixel, decode_image,
+    ArgbPixel, Canvas, Color, Image as BackendImage, ImageBuffer, ImageBufferCanvas, IndexedImage, PixelType, Rgb332Pixel, Rgb565Pixel, decode_image,
+    decode_indexed_image,
 };
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
@@ -67,6 +68,9 @@ impl Image {
                 JavaFieldProto::new("h", "I", Default::default()),
                 JavaFieldProto::new("imgData", "[B", Default::default()),
                 JavaFieldProto::new("bpl", "I", Default::default()),
+                // wie private fields: palette of indexed images, null otherwise
+                JavaFieldProto::new("palette", "[I", Default::default()),
+                JavaFieldProto::new("transparentIndex", "I", Default::default()),
             ],
             access_flags: Default::default(),
         }
@@ -149,9 +153,15 @@ impl Image {
 
         let mut image_data = vec![0; image_length as usize];
         jvm.array_raw_buffer(&data).await?.read(image_offset as _, &mut image_data)?;
+        let image_data: Vec<u8> = cast_vec(image_data);
+
+        // keep palette images indexed so that palette can be swapped later
+        if let Ok(Some(indexed)) = decode_indexed_image(&image_data) {
+            return Self::create_indexed_image_instance(jvm, &indexed).await;
+        }
 
         let image = {
-            let result = decode_image(&cast_vec(image_data));
+            let result = decode_image(&image_data);
             if let Ok(image) = result {
                 image
             } else {
@@ -169,6 +179,9 @@ impl Image {
         tracing::debug!("javax.microedition.lcdui.Image::createImage({image:?})");
 
         let src_image = Image::image(jvm, &image).await?;
+        if let Some(indexed) = src_image.as_indexed() {
+            return Self::create_indexed_image_instance(jvm, indexed).await;
+        }
 
         Self::create_image_instance(jvm, src_image.width(), src_image.height(), &src_image.raw(), src_image.bytes_per_pixel()).await
     }
@@ -200,6 +213,10 @@ impl Image {
     }
 
     pub async fn image(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<Box<dyn BackendImage>> {
+        if Self::is_indexed(jvm, this).await? {
+            return Ok(Box::new(JavaIndexedImageBuffer::new(jvm, this).await?));
+        }
+
         let width: i32 = jvm.get_field(this, "w", "I").await?;
         let bpl: i32 = jvm.get_field(this, "bpl", "I").await?;
 
@@ -214,6 +231,10 @@ impl Image {
     }
 
     pub async fn canvas(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<Box<dyn Canvas>> {
+        if Self::is_indexed(jvm, this).await? {
+            return Ok(Box::new(ImageBufferCanvas::new(JavaIndexedImageBuffer::new(jvm, this).await?)));
+        }
+
         let width: i32 = jvm.get_field(this, "w", "I").await?;
         let bpl: i32 = jvm.get_field(this, "bpl", "I").await?;
 
@@ -227,6 +248,48 @@ impl Image {
         })
     }
 
+    async fn is_indexed(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<bool> {
+        let palette: ClassInstanceRef<Array<i32>> = jvm.get_field(this, "palette", "[I").await?;
+
+        Ok(!palette.is_null())
+    }
+
+    // returns palette in argb, None if image is not indexed
+    async fn palette(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<Option<Vec<i32>>> {
+        let palette: ClassInstanceRef<Array<i32>> = jvm.get_field(this, "palette", "[I").await?;
+        if palette.is_null() {
+            return Ok(None);
+        }
+
+        let length = jvm.array_length(&palette).await?;
+
+        Ok(Some(jvm.load_array(&palette, 0, length).await?))
+    }
+
+    // -1 if there's no transparent index
+    async fn transparent_index(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<i32> {
+        jvm.get_field(this, "transparentIndex", "I").await
+    }
+
+    async fn create_indexed_image_instance(jvm: &Jvm, image: &IndexedImage) -> JvmResult<ClassInstanceRef<Image>> {
+        let mut instance = Self::create_image_instance(jvm, image.width(), image.height(), image.indices(), 1).await?;
+
+        let colors = image.palette().iter().map(|&x| ArgbPixel::from_color(x) as i32).collect::<Vec<_>>();
+        let mut palette = jvm.instantiate_array("I", colors.len()).await?;
+        jvm.store_array(&mut palette, 0, colors).await?;
+
+        jvm.put_field(&mut instance, "palette", "[I", palette).await?;
+        jvm.put_field(
+            &mut instance,
+            "transparentIndex",
+            "I",
+            image.transparent_index().map(|x| x as i32).unwrap_or(-1),
+        )
+        .await?;
+
+        Ok(instance)
+    }
+
     async fn create_image_instance(jvm: &Jvm, width: u32, height: u32, data: &[u8], bytes_per_pixel: u32) -> JvmResult<ClassInstanceRef<Image>> {
         let mut data_array = jvm.instantiate_array("B", data.len() as _).await?;
         jvm.array_raw_buffer_mut(&mut data_array).await?.write(0, data)?;
@@ -347,3 +410,85 @@ where
         self.raw_buffer.write(offset as _, &raw_bytes).unwrap();
     }
 }
+
+// palette is resolved at draw time, so palette updates are visible on next draw
+struct JavaIndexedImageBuffer {
+    image: IndexedImage,
+    raw_buffer: Box<dyn ArrayRawBufferMut>,
+}
+
+impl JavaIndexedImageBuffer {
+    pub async fn new(jvm: &Jvm, this: &ClassInstanceRef<Image>) -> JvmResult<Self> {
+        let mut java_img_data = jvm.get_field(this, "imgData", "[B").await?;
+        let raw_buffer = jvm.array_raw_buffer_mut(&mut java_img_data).await?;
+
+        let width: i32 = jvm.get_field(this, "w", "I").await?;
+        let height: i32 = jvm.get_field(this, "h", "I").await?;
+
+        let mut data = vec![0; (width * height) as usize];
+        raw_buffer.read(0, &mut data)?;
+
+        let palette = Image::palette(jvm, this)
+            .await?
+            .unwrap()
+            .into_iter()
+            .map(|x| ArgbPixel::to_color(x as _))
+            .collect();
+        let transparent_index = Image::transparent_index(jvm, this).await?;
+        let transparent_index = if transparent_index >= 0 { Some(transparent_index as u8) } else { None };
+
+        Ok(Self {
+            image: IndexedImage::new(width as _, height as _, data, palette, transparent_index),
+            raw_buffer,
+        })
+    }
+}
+
+impl BackendImage for JavaIndexedImageBuffer {
+    fn width(&self) -> u32 {
+        self.image.width()
+    }
+
+    fn height(&self) -> u32 {
+        self.image.height()
+    }
+
+    fn bytes_per_pixel(&self) -> u32 {
+        1
+    }
+
+    fn get_pixel(&self, x: i32, y: i32) -> Color {
+        self.image.get_pixel(x, y)
+    }
+
+    fn raw(&self) -> Cow<'_, [u8]> {
+        self.image.raw()
+    }
+
+    fn colors(&self) -> Vec<Color> {
+        self.image.colors()
+    }
+
+    fn as_indexed(&self) -> Option<&IndexedImage> {
+        Some(&self.image)
+    }
+}
+
+impl ImageBuffer for JavaIndexedImageBuffer {
+    fn put_pixel(&mut self, x: i32, y: i32, color: Color) {
+        if x < 0 || y < 0 || x as u32 >= self.image.width() || y as u32 >= self.image.height() {
+            return;
+        }
+
+        self.image.put_pixel(x, y, color);
+
+        let offset = (y as u32) * self.image.width() + (x as u32);
+        self.raw_buffer.write(offset as _, &[self.image.index(x, y)]).unwrap();
+    }
+
+    fn put_pixels(&mut self, x: i32, y: i32, width: u32, colors: &[Color]) {
+        for (i, color) in colors.iter().enumerate() {
+            self.put_pixel(x + (i as i32 % width as i32), y + (i as i32 / width as i32), *color);
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::{
+    Graphics, Image, Item,
+    renderer::{self, FOREGROUND, LINE_HEIGHT, Renderer},
+};
+
+// class javax.microedition.lcdui.ImageItem
+pub struct ImageItem;
+
+impl ImageItem {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/ImageItem",
+            parent_class: Some("javax/microedition/lcdui/Item"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;ILjava/lang/String;)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;ILjava/lang/String;I)V",
+                    Self::init_with_appearance_mode,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getImage", "()Ljavax/microedition/lcdui/Image;", Self::get_image, Default::default()),
+                JavaMethodProto::new("setImage", "(Ljavax/microedition/lcdui/Image;)V", Self::set_image, Default::default()),
+                JavaMethodProto::new("getAltText", "()Ljava/lang/String;", Self::get_alt_text, Default::default()),
+                JavaMethodProto::new("setAltText", "(Ljava/lang/String;)V", Self::set_alt_text, Default::default()),
+                JavaMethodProto::new("getLayout", "()I", Self::get_layout, Default::default()),
+                JavaMethodProto::new("setLayout", "(I)V", Self::set_layout, Default::default()),
+                JavaMethodProto::new("getAppearanceMode", "()I", Self::get_appearance_mode, Default::default()),
+                // wie private methods
+                JavaMethodProto::new("getItemHeight", "(I)I", Self::get_item_height, Default::default()),
+                JavaMethodProto::new(
+                    "paintItem",
+                    "(Ljavax/microedition/lcdui/Graphics;IIIZ)V",
+                    Self::paint_item,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("image", "Ljavax/microedition/lcdui/Image;", Default::default()),
+                JavaFieldProto::new("altText", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("layout", "I", Default::default()),
+                JavaFieldProto::new("appearanceMode", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        label: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+        layout: i32,
+        alt_text: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::<init>({this:?}, {label:?}, {image:?}, {layout}, {alt_text:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/ImageItem",
+                "<init>",
+                "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;ILjava/lang/String;I)V",
+                (label, image, layout, alt_text, 0),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_appearance_mode(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        label: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+        layout: i32,
+        alt_text: ClassInstanceRef<String>,
+        appearance_mode: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::<init>({this:?}, {label:?}, {image:?}, {layout}, {alt_text:?}, {appearance_mode})");
+
+        let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Item", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        jvm.put_field(&mut this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+        jvm.put_field(&mut this, "layout", "I", layout).await?;
+        jvm.put_field(&mut this, "altText", "Ljava/lang/String;", alt_text).await?;
+        jvm.put_field(&mut this, "appearanceMode", "I", appearance_mode).await?;
+
+        Ok(())
+    }
+
+    async fn get_image(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Image>> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::getImage({this:?})");
+
+        jvm.get_field(&this, "image", "Ljavax/microedition/lcdui/Image;").await
+    }
+
+    async fn set_image(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, image: ClassInstanceRef<Image>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::setImage({this:?}, {image:?})");
+
+        jvm.put_field(&mut this, "image", "Ljavax/microedition/lcdui/Image;", image).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_alt_text(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::getAltText({this:?})");
+
+        jvm.get_field(&this, "altText", "Ljava/lang/String;").await
+    }
+
+    async fn set_alt_text(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        alt_text: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::setAltText({this:?}, {alt_text:?})");
+
+        jvm.put_field(&mut this, "altText", "Ljava/lang/String;", alt_text).await?;
+
+        Ok(())
+    }
+
+    async fn get_layout(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::getLayout({this:?})");
+
+        jvm.get_field(&this, "layout", "I").await
+    }
+
+    async fn set_layout(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, layout: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::setLayout({this:?}, {layout})");
+
+        jvm.put_field(&mut this, "layout", "I", layout).await?;
+
+        Ok(())
+    }
+
+    async fn get_appearance_mode(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::getAppearanceMode({this:?})");
+
+        jvm.get_field(&this, "appearanceMode", "I").await
+    }
+
+    async fn get_item_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, width: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::getItemHeight({this:?}, {width})");
+
+        let label_height = Item::label_height(jvm, &this, width).await?;
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(&this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        let image_height = if image.is_null() {
+            LINE_HEIGHT
+        } else {
+            jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?
+        };
+
+        Ok(label_height + image_height)
+    }
+
+    async fn paint_item(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        focused: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.ImageItem::paintItem({this:?}, {graphics:?}, {x}, {y}, {width}, {focused})");
+
+        let label_height = {
+            let mut renderer = Renderer::new(jvm, &graphics).await?;
+            Item::paint_label(jvm, &this, &mut renderer, x, y, width).await?
+        };
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(&this, "image", "Ljavax/microedition/lcdui/Image;").await?;
+        if image.is_null() {
+            let alt_text = jvm.get_field(&this, "altText", "Ljava/lang/String;").await?;
+            let alt_text = renderer::rust_string(jvm, &alt_text).await?;
+
+            let mut renderer = Renderer::new(jvm, &graphics).await?;
+            renderer.draw_text(&alt_text, x, y + label_height, TextAlignment::Left, FOREGROUND);
+        } else {
+            let image = Image::image(jvm, &image).await?;
+
+            let mut renderer = Renderer::new(jvm, &graphics).await?;
+            let image_x = x + (width - image.width() as i32).max(0) / 2;
+            renderer.draw_image(&*image, image_x, y + label_height);
+        }
+
+        Ok(())
+    }
+}
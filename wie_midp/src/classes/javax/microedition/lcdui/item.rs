@@ -1,10 +1,17 @@
-use alloc::vec;
+use alloc::{string::String as RustString, vec, vec::Vec};
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::canvas::TextAlignment;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
+use crate::classes::javax::microedition::lcdui::{
+    Form, Graphics,
+    renderer::{self, FOREGROUND, LINE_HEIGHT, Renderer},
+};
+
 // class javax.microedition.lcdui.Item
 pub struct Item;
 
@@ -14,8 +21,26 @@ impl Item {
             name: "javax/microedition/lcdui/Item",
             parent_class: Some("java/lang/Object"),
             interfaces: vec![],
-            methods: vec![JavaMethodProto::new("<init>", "()V", Self::init, Default::default())],
-            fields: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("getLabel", "()Ljava/lang/String;", Self::get_label, Default::default()),
+                JavaMethodProto::new("setLabel", "(Ljava/lang/String;)V", Self::set_label, Default::default()),
+                // wie private methods
+                JavaMethodProto::new("setOwner", "(Ljavax/microedition/lcdui/Form;)V", Self::set_owner, Default::default()),
+                JavaMethodProto::new("getItemHeight", "(I)I", Self::get_item_height, Default::default()),
+                JavaMethodProto::new(
+                    "paintItem",
+                    "(Ljavax/microedition/lcdui/Graphics;IIIZ)V",
+                    Self::paint_item,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("isItemFocusable", "()Z", Self::is_item_focusable, Default::default()),
+                JavaMethodProto::new("handleItemKey", "(II)Z", Self::handle_item_key, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("label", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("owner", "Ljavax/microedition/lcdui/Form;", Default::default()),
+            ],
             access_flags: Default::default(),
         }
     }
@@ -27,4 +52,105 @@ impl Item {
 
         Ok(())
     }
+
+    async fn get_label(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.Item::getLabel({this:?})");
+
+        jvm.get_field(&this, "label", "Ljava/lang/String;").await
+    }
+
+    async fn set_label(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, label: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Item::setLabel({this:?}, {label:?})");
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn set_owner(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, owner: ClassInstanceRef<Form>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Item::setOwner({this:?}, {owner:?})");
+
+        jvm.put_field(&mut this, "owner", "Ljavax/microedition/lcdui/Form;", owner).await?;
+
+        Ok(())
+    }
+
+    async fn get_item_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, width: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.Item::getItemHeight({this:?}, {width})");
+
+        Self::label_height(jvm, &this, width).await
+    }
+
+    async fn paint_item(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        focused: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Item::paintItem({this:?}, {graphics:?}, {x}, {y}, {width}, {focused})");
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+        Self::paint_label(jvm, &this, &mut renderer, x, y, width).await?;
+
+        Ok(())
+    }
+
+    async fn is_item_focusable(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Item::isItemFocusable({this:?})");
+
+        Ok(false)
+    }
+
+    async fn handle_item_key(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.Item::handleItemKey({this:?}, {event_type}, {code})");
+
+        Ok(false)
+    }
+
+    pub async fn label_lines<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, width: i32) -> JvmResult<Vec<RustString>> {
+        let label: ClassInstanceRef<String> = jvm.get_field(this, "label", "Ljava/lang/String;").await?;
+        if label.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let label = renderer::rust_string(jvm, &label).await?;
+
+        Ok(renderer::wrap_text(&label, width))
+    }
+
+    pub async fn label_height<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, width: i32) -> JvmResult<i32> {
+        let lines = Self::label_lines(jvm, this, width).await?;
+
+        Ok(lines.len() as i32 * LINE_HEIGHT)
+    }
+
+    // returns height of painted label
+    pub async fn paint_label<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, renderer: &mut Renderer, x: i32, y: i32, width: i32) -> JvmResult<i32> {
+        let lines = Self::label_lines(jvm, this, width).await?;
+
+        for (i, line) in lines.iter().enumerate() {
+            renderer.draw_text(line, x, y + i as i32 * LINE_HEIGHT, TextAlignment::Left, FOREGROUND);
+        }
+
+        Ok(lines.len() as i32 * LINE_HEIGHT)
+    }
+
+    // notifies ItemStateListener of owning form, for changes made by user
+    pub async fn notify_state_changed<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<()> {
+        let owner: ClassInstanceRef<Form> = jvm.get_field(this, "owner", "Ljavax/microedition/lcdui/Form;").await?;
+        if owner.is_null() {
+            return Ok(());
+        }
+
+        let _: () = jvm
+            .invoke_virtual(&owner, "itemStateChanged", "(Ljavax/microedition/lcdui/Item;)V", (this.clone(),))
+            .await?;
+
+        Ok(())
+    }
 }
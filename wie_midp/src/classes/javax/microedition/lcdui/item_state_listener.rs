@@ -0,0 +1,26 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+
+use java_constants::ClassAccessFlags;
+use wie_jvm_support::WieJavaClassProto;
+
+// interface javax.microedition.lcdui.ItemStateListener
+pub struct ItemStateListener;
+
+impl ItemStateListener {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/ItemStateListener",
+            parent_class: None,
+            interfaces: vec![],
+            methods: vec![JavaMethodProto::new_abstract(
+                "itemStateChanged",
+                "(Ljavax/microedition/lcdui/Item;)V",
+                Default::default(),
+            )],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
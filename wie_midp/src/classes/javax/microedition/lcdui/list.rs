@@ -0,0 +1,409 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::FieldAccessFlags;
+use java_runtime::classes::java::lang::String;
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::{
+    javax::microedition::lcdui::{
+        Command, Graphics, Image,
+        choice::{self, Choice},
+        command_bar, renderer,
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
+
+// class javax.microedition.lcdui.List
+pub struct List;
+
+impl List {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/List",
+            parent_class: Some("javax/microedition/lcdui/Screen"),
+            interfaces: vec!["javax/microedition/lcdui/Choice"],
+            methods: vec![
+                JavaMethodProto::new("<clinit>", "()V", Self::cl_init, Default::default()),
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;I)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/String;I[Ljava/lang/String;[Ljavax/microedition/lcdui/Image;)V",
+                    Self::init_with_elements,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "append",
+                    "(Ljava/lang/String;Ljavax/microedition/lcdui/Image;)I",
+                    Self::append,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "insert",
+                    "(ILjava/lang/String;Ljavax/microedition/lcdui/Image;)V",
+                    Self::insert,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "set",
+                    "(ILjava/lang/String;Ljavax/microedition/lcdui/Image;)V",
+                    Self::set,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("delete", "(I)V", Self::delete, Default::default()),
+                JavaMethodProto::new("deleteAll", "()V", Self::delete_all, Default::default()),
+                JavaMethodProto::new("getString", "(I)Ljava/lang/String;", Self::get_string, Default::default()),
+                JavaMethodProto::new("getImage", "(I)Ljavax/microedition/lcdui/Image;", Self::get_image, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("isSelected", "(I)Z", Self::is_selected, Default::default()),
+                JavaMethodProto::new("getSelectedIndex", "()I", Self::get_selected_index, Default::default()),
+                JavaMethodProto::new("setSelectedIndex", "(IZ)V", Self::set_selected_index, Default::default()),
+                JavaMethodProto::new("getSelectedFlags", "([Z)I", Self::get_selected_flags, Default::default()),
+                JavaMethodProto::new("setSelectedFlags", "([Z)V", Self::set_selected_flags, Default::default()),
+                JavaMethodProto::new(
+                    "setSelectCommand",
+                    "(Ljavax/microedition/lcdui/Command;)V",
+                    Self::set_select_command,
+                    Default::default(),
+                ),
+                // wie private methods
+                JavaMethodProto::new(
+                    "paintContent",
+                    "(Ljavax/microedition/lcdui/Graphics;IIII)V",
+                    Self::paint_content,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("SELECT_COMMAND", "Ljavax/microedition/lcdui/Command;", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("choiceType", "I", Default::default()),
+                JavaFieldProto::new("strings", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("images", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("selected", "[Z", Default::default()),
+                JavaFieldProto::new("cursor", "I", Default::default()),
+                JavaFieldProto::new("scrollY", "I", Default::default()),
+                JavaFieldProto::new("selectCommand", "Ljavax/microedition/lcdui/Command;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn cl_init(jvm: &Jvm, _context: &mut WieJvmContext) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::<clinit>()");
+
+        // Command.SCREEN with empty label
+        let label = JavaLangString::from_rust_string(jvm, "").await?;
+        let select_command = jvm
+            .new_class("javax/microedition/lcdui/Command", "(Ljava/lang/String;II)V", (label, 1, 0))
+            .await?;
+
+        jvm.put_static_field(
+            "javax/microedition/lcdui/List",
+            "SELECT_COMMAND",
+            "Ljavax/microedition/lcdui/Command;",
+            select_command,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        title: ClassInstanceRef<String>,
+        list_type: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::<init>({this:?}, {title:?}, {list_type})");
+
+        let string_elements = jvm.instantiate_array("Ljava/lang/String;", 0).await?;
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/List",
+                "<init>",
+                "(Ljava/lang/String;I[Ljava/lang/String;[Ljavax/microedition/lcdui/Image;)V",
+                (title, list_type, string_elements, None),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_elements(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        title: ClassInstanceRef<String>,
+        list_type: i32,
+        string_elements: ClassInstanceRef<Array<ClassInstanceRef<String>>>,
+        image_elements: ClassInstanceRef<Array<ClassInstanceRef<Image>>>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::<init>({this:?}, {title:?}, {list_type}, {string_elements:?}, {image_elements:?})");
+
+        let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Screen", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "title", "Ljava/lang/String;", title).await?;
+
+        let select_command: ClassInstanceRef<Command> = jvm
+            .get_static_field("javax/microedition/lcdui/List", "SELECT_COMMAND", "Ljavax/microedition/lcdui/Command;")
+            .await?;
+        jvm.put_field(&mut this, "selectCommand", "Ljavax/microedition/lcdui/Command;", select_command)
+            .await?;
+
+        Choice::init_elements(jvm, &this, list_type, string_elements, image_elements).await?;
+
+        Ok(())
+    }
+
+    async fn append(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.List::append({this:?}, {string:?}, {image:?})");
+
+        let index = Choice::append(jvm, &this, string, image).await?;
+        renderer::request_redraw(context);
+
+        Ok(index)
+    }
+
+    async fn insert(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        index: i32,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::insert({this:?}, {index}, {string:?}, {image:?})");
+
+        Choice::insert(jvm, &this, index, string, image).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn set(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        index: i32,
+        string: ClassInstanceRef<String>,
+        image: ClassInstanceRef<Image>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::set({this:?}, {index}, {string:?}, {image:?})");
+
+        Choice::set(jvm, &this, index, string, image).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::delete({this:?}, {index})");
+
+        Choice::delete(jvm, &this, index).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete_all(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::deleteAll({this:?})");
+
+        Choice::delete_all(jvm, &this).await?;
+        jvm.put_field(&mut this, "scrollY", "I", 0).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_string(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.List::getString({this:?}, {index})");
+
+        Choice::get_string(jvm, &this, index).await
+    }
+
+    async fn get_image(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<Image>> {
+        tracing::debug!("javax.microedition.lcdui.List::getImage({this:?}, {index})");
+
+        Choice::get_image(jvm, &this, index).await
+    }
+
+    async fn size(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.List::size({this:?})");
+
+        Choice::size(jvm, &this).await
+    }
+
+    async fn is_selected(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<bool> {
+        tracing::debug!("javax.microedition.lcdui.List::isSelected({this:?}, {index})");
+
+        Choice::is_selected(jvm, &this, index).await
+    }
+
+    async fn get_selected_index(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.List::getSelectedIndex({this:?})");
+
+        Choice::get_selected_index(jvm, &this).await
+    }
+
+    async fn set_selected_index(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        index: i32,
+        selected: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::setSelectedIndex({this:?}, {index}, {selected})");
+
+        Choice::set_selected_index(jvm, &this, index, selected).await?;
+        if selected {
+            jvm.put_field(&mut this, "cursor", "I", index).await?;
+        }
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_selected_flags(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        flags: ClassInstanceRef<Array<bool>>,
+    ) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.List::getSelectedFlags({this:?}, {flags:?})");
+
+        Choice::get_selected_flags(jvm, &this, flags).await
+    }
+
+    async fn set_selected_flags(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        flags: ClassInstanceRef<Array<bool>>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::setSelectedFlags({this:?}, {flags:?})");
+
+        Choice::set_selected_flags(jvm, &this, flags).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn set_select_command(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        command: ClassInstanceRef<Command>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::setSelectCommand({this:?}, {command:?})");
+
+        // select command is only used by implicit list
+        if Choice::choice_type(jvm, &this).await? != choice::IMPLICIT {
+            return Ok(());
+        }
+
+        let default_command: ClassInstanceRef<Command> = jvm
+            .get_static_field("javax/microedition/lcdui/List", "SELECT_COMMAND", "Ljavax/microedition/lcdui/Command;")
+            .await?;
+        let is_default: bool = !command.is_null()
+            && jvm
+                .invoke_virtual(&command, "equals", "(Ljava/lang/Object;)Z", (default_command,))
+                .await?;
+        if !command.is_null() && !is_default {
+            let _: () = jvm
+                .invoke_virtual(&this, "addCommand", "(Ljavax/microedition/lcdui/Command;)V", (command.clone(),))
+                .await?;
+        }
+
+        jvm.put_field(&mut this, "selectCommand", "Ljavax/microedition/lcdui/Command;", command)
+            .await
+    }
+
+    async fn paint_content(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::paintContent({this:?}, {graphics:?}, {x}, {y}, {width}, {height})");
+
+        let size = Choice::size(jvm, &this).await?;
+        let cursor = Choice::cursor(jvm, &this).await?;
+
+        // scroll to make cursor visible
+        let mut cursor_top = 0;
+        for i in 0..cursor.min(size) {
+            cursor_top += Choice::element_height(jvm, &this, i).await?;
+        }
+        let cursor_bottom = if cursor < size {
+            cursor_top + Choice::element_height(jvm, &this, cursor).await?
+        } else {
+            cursor_top
+        };
+
+        let mut scroll_y: i32 = jvm.get_field(&this, "scrollY", "I").await?;
+        if cursor_top < scroll_y {
+            scroll_y = cursor_top;
+        } else if cursor_bottom > scroll_y + height {
+            scroll_y = cursor_bottom - height;
+        }
+        jvm.put_field(&mut this, "scrollY", "I", scroll_y).await?;
+
+        let mut element_y = y - scroll_y;
+        for i in 0..size {
+            if element_y >= y + height {
+                break;
+            }
+
+            element_y += Choice::paint_element(jvm, &this, &graphics, i, x, element_y, width, i == cursor).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn handle_key_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.List::handleKeyEvent({this:?}, {event_type}, {code})");
+
+        if matches!(KeyboardEventType::from_raw(event_type), KeyboardEventType::KeyReleased) {
+            return Ok(());
+        }
+
+        match MIDPKeyCode::from_raw(code) {
+            MIDPKeyCode::UP => {
+                Choice::move_cursor(jvm, &this, -1).await?;
+            }
+            MIDPKeyCode::DOWN => {
+                Choice::move_cursor(jvm, &this, 1).await?;
+            }
+            MIDPKeyCode::FIRE => {
+                Choice::select_cursor(jvm, &this).await?;
+
+                // implicit list notifies selection through its select command
+                let select_command: ClassInstanceRef<Command> = jvm.get_field(&this, "selectCommand", "Ljavax/microedition/lcdui/Command;").await?;
+                if Choice::choice_type(jvm, &this).await? == choice::IMPLICIT && !select_command.is_null() {
+                    command_bar::dispatch(jvm, &this, select_command).await?;
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,308 @@
+use alloc::{
+    boxed::Box,
+    string::{String as RustString, ToString},
+    vec::Vec,
+};
+use core::ops::Range;
+
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_backend::{
+    InputMethod,
+    canvas::{Canvas, Clip, Color, Image as BackendImage, TextAlignment, string_width},
+};
+use wie_jvm_support::WieJvmContext;
+
+use crate::classes::javax::microedition::lcdui::{Graphics, Image};
+
+// renderer for high-level lcdui screens, drawing directly on graphics' target image with graphics' clip
+
+pub const FONT_SIZE: f32 = 10.0;
+pub const LINE_HEIGHT: i32 = 14;
+pub const TITLE_HEIGHT: i32 = 18;
+pub const PADDING: i32 = 2;
+
+pub const BACKGROUND: Color = Color {
+    a: 0xff,
+    r: 0xff,
+    g: 0xff,
+    b: 0xff,
+};
+pub const FOREGROUND: Color = Color { a: 0xff, r: 0, g: 0, b: 0 };
+pub const BORDER: Color = Color {
+    a: 0xff,
+    r: 0x80,
+    g: 0x80,
+    b: 0x80,
+};
+pub const HIGHLIGHT: Color = Color {
+    a: 0xff,
+    r: 0x31,
+    g: 0x6a,
+    b: 0xc5,
+};
+pub const HIGHLIGHT_TEXT: Color = BACKGROUND;
+pub const BAR_BACKGROUND: Color = Color {
+    a: 0xff,
+    r: 0x20,
+    g: 0x40,
+    b: 0x80,
+};
+pub const BAR_TEXT: Color = BACKGROUND;
+
+pub struct Renderer {
+    canvas: Box<dyn Canvas>,
+    width: i32,
+    height: i32,
+    clip: (i32, i32, i32, i32),
+}
+
+impl Renderer {
+    pub async fn new(jvm: &Jvm, graphics: &ClassInstanceRef<Graphics>) -> JvmResult<Self> {
+        let mut graphics = graphics.clone();
+        let image = Graphics::image(jvm, &mut graphics).await?;
+
+        let width: i32 = jvm.invoke_virtual(&image, "getWidth", "()I", ()).await?;
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+        let canvas = Image::canvas(jvm, &image).await?;
+        let clip = Graphics::clip(jvm, &graphics).await?;
+
+        Ok(Self {
+            canvas,
+            width,
+            height,
+            clip: (clip.x, clip.y, clip.width as _, clip.height as _),
+        })
+    }
+
+    // renders on a standalone canvas, outside of java
+    pub fn from_canvas(canvas: Box<dyn Canvas>) -> Self {
+        let width = canvas.image().width() as i32;
+        let height = canvas.image().height() as i32;
+
+        Self {
+            canvas,
+            width,
+            height,
+            clip: (0, 0, width, height),
+        }
+    }
+
+    pub fn image(&self) -> &dyn BackendImage {
+        self.canvas.image()
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn set_clip(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        self.clip = (x, y, width, height);
+    }
+
+    fn clip(&self) -> Clip {
+        Clip {
+            x: self.clip.0,
+            y: self.clip.1,
+            width: self.clip.2.max(0) as _,
+            height: self.clip.3.max(0) as _,
+        }
+    }
+
+    fn is_line_visible(&self, y: i32) -> bool {
+        y + LINE_HEIGHT > self.clip.1 && y < self.clip.1 + self.clip.3
+    }
+
+    pub fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        let clip = self.clip();
+        self.canvas.fill_rect(x, y, width as _, height as _, color, clip);
+    }
+
+    pub fn draw_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        let clip = self.clip();
+        self.canvas.draw_rect(x, y, width as _, height as _, color, clip);
+    }
+
+    // canvas doesn't clip text, so we skip lines outside of clip
+    pub fn draw_text(&mut self, text: &str, x: i32, y: i32, alignment: TextAlignment, color: Color) {
+        if !self.is_line_visible(y) {
+            return;
+        }
+
+        self.canvas.draw_text(text, x, y, alignment, color);
+    }
+
+    pub fn draw_image(&mut self, image: &dyn BackendImage, x: i32, y: i32) {
+        let clip = self.clip();
+        self.canvas.draw(x, y, image.width(), image.height(), image, 0, 0, clip);
+    }
+
+    pub fn clear(&mut self, color: Color) {
+        let clip = self.clip();
+        self.canvas.fill_rect(0, 0, self.width as _, self.height as _, color, clip);
+    }
+
+    pub fn draw_bar(&mut self, y: i32, height: i32, text: &str, alignment: TextAlignment) {
+        self.fill_rect(0, y, self.width, height, BAR_BACKGROUND);
+
+        let x = match alignment {
+            TextAlignment::Left => PADDING * 2,
+            TextAlignment::Center => self.width / 2,
+            TextAlignment::Right => self.width - PADDING * 2,
+        };
+        self.draw_text(text, x, y + (height - LINE_HEIGHT) / 2, alignment, BAR_TEXT);
+    }
+
+    // draws check box or radio button sized to a text line
+    pub fn draw_choice_mark(&mut self, x: i32, y: i32, exclusive: bool, selected: bool, color: Color) {
+        let size = LINE_HEIGHT - 4;
+
+        self.draw_rect(x, y + 2, size, size, color);
+        if selected {
+            if exclusive {
+                self.fill_rect(x + 3, y + 5, size - 6, size - 6, color);
+            } else {
+                self.fill_rect(x + 2, y + 4, size - 4, size - 4, color);
+            }
+        }
+    }
+
+    // draws text of the input method with caret, highlighting characters being composed and showing input mode when focused
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_editor(&mut self, x: i32, y: i32, width: i32, height: i32, input_method: &InputMethod, password: bool, focused: bool) {
+        self.fill_rect(x, y, width, height, BACKGROUND);
+        self.draw_rect(x, y, width - 1, height - 1, if focused { HIGHLIGHT } else { BORDER });
+
+        let composing = input_method.composing().unwrap_or(0..0);
+        let cursor = input_method.cursor();
+        let chars = input_method
+            .text()
+            .chars()
+            .enumerate()
+            .map(|(i, x)| if password && !composing.contains(&i) { '*' } else { x })
+            .collect::<Vec<_>>();
+
+        let mut text_area_width = width - PADDING * 4;
+        if focused {
+            let label = input_method.mode().label();
+            let label_width = text_width(label) + PADDING * 2;
+            let label_x = x + width - PADDING - label_width;
+
+            self.fill_rect(label_x, y + PADDING, label_width, LINE_HEIGHT, BAR_BACKGROUND);
+            self.draw_text(label, label_x + PADDING, y + PADDING, TextAlignment::Left, BAR_TEXT);
+            text_area_width -= label_width + PADDING;
+        }
+
+        let lines = layout_chars(&chars, text_area_width);
+        let visible_lines = ((height - PADDING * 2) / LINE_HEIGHT).max(1) as usize;
+
+        // keep the line with caret visible
+        let caret_line = lines.iter().rposition(|x| x.start <= cursor).unwrap_or(0);
+        let first_line = (caret_line + 1).saturating_sub(visible_lines);
+
+        for (i, line) in lines.iter().enumerate().skip(first_line).take(visible_lines) {
+            let line_x = x + PADDING * 2;
+            let line_y = y + PADDING + (i - first_line) as i32 * LINE_HEIGHT;
+            let offset = |index: usize| line_x + text_width(&chars[line.start..index.clamp(line.start, line.end)].iter().collect::<RustString>());
+
+            let text = chars[line.clone()].iter().collect::<RustString>();
+            self.draw_text(&text, line_x, line_y, TextAlignment::Left, FOREGROUND);
+
+            let start = composing.start.max(line.start);
+            let end = composing.end.min(line.end);
+            if start < end {
+                let composing_x = offset(start);
+                let composing_text = chars[start..end].iter().collect::<RustString>();
+
+                self.fill_rect(composing_x, line_y, offset(end) - composing_x, LINE_HEIGHT, HIGHLIGHT);
+                self.draw_text(&composing_text, composing_x, line_y, TextAlignment::Left, HIGHLIGHT_TEXT);
+            }
+
+            if focused && i == caret_line {
+                self.fill_rect(offset(cursor), line_y + 1, 1, LINE_HEIGHT - 2, FOREGROUND);
+            }
+        }
+    }
+}
+
+// breaks characters into lines which fit in width, keeping character indices for caret and highlight
+fn layout_chars(chars: &[char], width: i32) -> Vec<Range<usize>> {
+    let mut result = Vec::new();
+    let mut start = 0;
+
+    for (i, &char) in chars.iter().enumerate() {
+        if char == '\n' {
+            result.push(start..i);
+            start = i + 1;
+            continue;
+        }
+
+        if i > start && text_width(&chars[start..=i].iter().collect::<RustString>()) > width {
+            result.push(start..i);
+            start = i;
+        }
+    }
+    result.push(start..chars.len());
+
+    result
+}
+
+pub fn text_width(text: &str) -> i32 {
+    string_width(text, FONT_SIZE) as _
+}
+
+// word wrap text into lines which fit in width
+pub fn wrap_text(text: &str, width: i32) -> Vec<RustString> {
+    let mut result = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut line = RustString::new();
+        for word in paragraph.split_inclusive(' ') {
+            if !line.is_empty() && text_width((line.clone() + word).trim_end()) > width {
+                result.push(line.trim_end().to_string());
+                line.clear();
+            }
+
+            // break words longer than a line
+            for char in word.chars() {
+                line.push(char);
+                if line.chars().count() > 1 && text_width(line.trim_end()) > width {
+                    line.pop();
+                    result.push(line.clone());
+                    line.clear();
+                    line.push(char);
+                }
+            }
+        }
+        result.push(line.trim_end().to_string());
+    }
+
+    result
+}
+
+pub async fn rust_string(jvm: &Jvm, string: &ClassInstanceRef<String>) -> JvmResult<RustString> {
+    if string.is_null() {
+        return Ok(RustString::new());
+    }
+
+    JavaLangString::to_rust_string(jvm, string).await
+}
+
+pub fn request_redraw(context: &mut WieJvmContext) {
+    let platform = context.system().platform();
+    let screen = platform.screen();
+    screen.request_redraw().unwrap();
+}
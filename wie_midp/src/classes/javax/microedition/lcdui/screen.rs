@@ -1,10 +1,17 @@
 use alloc::vec;
 
 use java_class_proto::JavaMethodProto;
+use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::canvas::TextAlignment;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
+use crate::classes::javax::microedition::lcdui::{
+    Graphics, Ticker, command_bar,
+    renderer::{self, BACKGROUND, LINE_HEIGHT, Renderer, TITLE_HEIGHT},
+};
+
 // class javax.microedition.lcdui.Screen
 pub struct Screen;
 
@@ -14,7 +21,22 @@ impl Screen {
             name: "javax/microedition/lcdui/Screen",
             parent_class: Some("javax/microedition/lcdui/Displayable"),
             interfaces: vec![],
-            methods: vec![JavaMethodProto::new("<init>", "()V", Self::init, Default::default())],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                // wie private methods
+                JavaMethodProto::new(
+                    "handlePaintEvent",
+                    "(Ljavax/microedition/lcdui/Graphics;)V",
+                    Self::handle_paint_event,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "paintContent",
+                    "(Ljavax/microedition/lcdui/Graphics;IIII)V",
+                    Self::paint_content,
+                    Default::default(),
+                ),
+            ],
             fields: vec![],
             access_flags: Default::default(),
         }
@@ -29,4 +51,79 @@ impl Screen {
 
         Ok(())
     }
+
+    async fn handle_paint_event(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Screen::handlePaintEvent({this:?}, {graphics:?})");
+
+        let (width, height) = {
+            let mut renderer = Renderer::new(jvm, &graphics).await?;
+            renderer.clear(BACKGROUND);
+
+            (renderer.width(), renderer.height())
+        };
+
+        let title: ClassInstanceRef<String> = jvm.get_field(&this, "title", "Ljava/lang/String;").await?;
+        let ticker: ClassInstanceRef<Ticker> = jvm.get_field(&this, "ticker", "Ljavax/microedition/lcdui/Ticker;").await?;
+
+        let title_height = if title.is_null() { 0 } else { TITLE_HEIGHT };
+        let ticker_height = if ticker.is_null() { 0 } else { LINE_HEIGHT };
+        let top = title_height + ticker_height;
+        let content_height = height - top - command_bar::bar_height(jvm, &this).await?;
+
+        // content is painted first, as it may overflow clip when drawing text
+        let _: () = jvm
+            .invoke_virtual(&graphics, "setClip", "(IIII)V", (0, top, width, content_height))
+            .await?;
+        let _: () = jvm
+            .invoke_virtual(
+                &this,
+                "paintContent",
+                "(Ljavax/microedition/lcdui/Graphics;IIII)V",
+                (graphics.clone(), 0, top, width, content_height),
+            )
+            .await?;
+        let _: () = jvm.invoke_virtual(&graphics, "setClip", "(IIII)V", (0, 0, width, height)).await?;
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+        if !title.is_null() {
+            let title = renderer::rust_string(jvm, &title).await?;
+            renderer.draw_bar(0, TITLE_HEIGHT, &title, TextAlignment::Left);
+        }
+
+        if !ticker.is_null() {
+            let text = jvm.invoke_virtual(&ticker, "getString", "()Ljava/lang/String;", ()).await?;
+            let text = renderer::rust_string(jvm, &text).await?;
+
+            // scroll 50 pixels per second from right to left
+            let text_width = renderer::text_width(&text);
+            let offset = (context.system().platform().now().raw() / 20 % (width + text_width) as u64) as i32;
+
+            renderer.fill_rect(0, title_height, width, ticker_height, BACKGROUND);
+            renderer.draw_text(&text, width - offset, title_height, TextAlignment::Left, renderer::FOREGROUND);
+
+            renderer::request_redraw(context);
+        }
+
+        Ok(())
+    }
+
+    async fn paint_content(
+        _jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Screen::paintContent({this:?}, {graphics:?}, {x}, {y}, {width}, {height})");
+
+        Ok(())
+    }
 }
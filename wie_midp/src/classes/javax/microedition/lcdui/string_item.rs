@@ -0,0 +1,156 @@
+use alloc::{string::String as RustString, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::{
+    Graphics, Item,
+    renderer::{self, FOREGROUND, LINE_HEIGHT, Renderer},
+};
+
+// class javax.microedition.lcdui.StringItem
+pub struct StringItem;
+
+impl StringItem {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/StringItem",
+            parent_class: Some("javax/microedition/lcdui/Item"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljava/lang/String;Ljava/lang/String;I)V",
+                    Self::init_with_appearance_mode,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getText", "()Ljava/lang/String;", Self::get_text, Default::default()),
+                JavaMethodProto::new("setText", "(Ljava/lang/String;)V", Self::set_text, Default::default()),
+                JavaMethodProto::new("getAppearanceMode", "()I", Self::get_appearance_mode, Default::default()),
+                // wie private methods
+                JavaMethodProto::new("getItemHeight", "(I)I", Self::get_item_height, Default::default()),
+                JavaMethodProto::new(
+                    "paintItem",
+                    "(Ljavax/microedition/lcdui/Graphics;IIIZ)V",
+                    Self::paint_item,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("text", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("appearanceMode", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        label: ClassInstanceRef<String>,
+        text: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::<init>({this:?}, {label:?}, {text:?})");
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/lcdui/StringItem",
+                "<init>",
+                "(Ljava/lang/String;Ljava/lang/String;I)V",
+                (label, text, 0),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_appearance_mode(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        label: ClassInstanceRef<String>,
+        text: ClassInstanceRef<String>,
+        appearance_mode: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::<init>({this:?}, {label:?}, {text:?}, {appearance_mode})");
+
+        let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Item", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+        jvm.put_field(&mut this, "appearanceMode", "I", appearance_mode).await?;
+
+        Ok(())
+    }
+
+    async fn get_text(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::getText({this:?})");
+
+        jvm.get_field(&this, "text", "Ljava/lang/String;").await
+    }
+
+    async fn set_text(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::setText({this:?}, {text:?})");
+
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_appearance_mode(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::getAppearanceMode({this:?})");
+
+        jvm.get_field(&this, "appearanceMode", "I").await
+    }
+
+    async fn get_item_height(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, width: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::getItemHeight({this:?}, {width})");
+
+        let label_height = Item::label_height(jvm, &this, width).await?;
+        let lines = Self::text_lines(jvm, &this, width).await?;
+
+        Ok(label_height + lines.len() as i32 * LINE_HEIGHT)
+    }
+
+    async fn paint_item(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        focused: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.StringItem::paintItem({this:?}, {graphics:?}, {x}, {y}, {width}, {focused})");
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+        let label_height = Item::paint_label(jvm, &this, &mut renderer, x, y, width).await?;
+
+        let lines = Self::text_lines(jvm, &this, width).await?;
+        for (i, line) in lines.iter().enumerate() {
+            renderer.draw_text(line, x, y + label_height + i as i32 * LINE_HEIGHT, TextAlignment::Left, FOREGROUND);
+        }
+
+        Ok(())
+    }
+
+    async fn text_lines(jvm: &Jvm, this: &ClassInstanceRef<Self>, width: i32) -> JvmResult<Vec<RustString>> {
+        let text: ClassInstanceRef<String> = jvm.get_field(this, "text", "Ljava/lang/String;").await?;
+        if text.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let text = renderer::rust_string(jvm, &text).await?;
+
+        Ok(renderer::wrap_text(&text, width))
+    }
+}
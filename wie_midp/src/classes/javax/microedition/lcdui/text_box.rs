@@ -1,11 +1,22 @@
-use alloc::vec;
+use alloc::{string::String as RustString, vec, vec::Vec};
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_runtime::classes::java::lang::String;
-use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
 
+use wie_backend::{InputConstraint, InputKey, InputMethodHandle};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
+use crate::classes::{
+    javax::microedition::lcdui::{
+        Graphics,
+        renderer::{self, PADDING, Renderer},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
+
+const PASSWORD: i32 = 0x10000;
+
 // class javax.microedition.lcdui.TextBox
 pub struct TextBox;
 
@@ -15,21 +26,44 @@ impl TextBox {
             name: "javax/microedition/lcdui/TextBox",
             parent_class: Some("javax/microedition/lcdui/Screen"),
             interfaces: vec![],
-            methods: vec![JavaMethodProto::new(
-                "<init>",
-                "(Ljava/lang/String;Ljava/lang/String;II)V",
-                Self::init,
-                Default::default(),
-            )],
-            fields: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;Ljava/lang/String;II)V", Self::init, Default::default()),
+                JavaMethodProto::new("getString", "()Ljava/lang/String;", Self::get_string, Default::default()),
+                JavaMethodProto::new("setString", "(Ljava/lang/String;)V", Self::set_string, Default::default()),
+                JavaMethodProto::new("getChars", "([C)I", Self::get_chars, Default::default()),
+                JavaMethodProto::new("setChars", "([CII)V", Self::set_chars, Default::default()),
+                JavaMethodProto::new("insert", "(Ljava/lang/String;I)V", Self::insert, Default::default()),
+                JavaMethodProto::new("delete", "(II)V", Self::delete, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("getMaxSize", "()I", Self::get_max_size, Default::default()),
+                JavaMethodProto::new("setMaxSize", "(I)I", Self::set_max_size, Default::default()),
+                JavaMethodProto::new("getConstraints", "()I", Self::get_constraints, Default::default()),
+                JavaMethodProto::new("setConstraints", "(I)V", Self::set_constraints, Default::default()),
+                JavaMethodProto::new("getCaretPosition", "()I", Self::get_caret_position, Default::default()),
+                // wie private methods
+                JavaMethodProto::new(
+                    "paintContent",
+                    "(Ljavax/microedition/lcdui/Graphics;IIII)V",
+                    Self::paint_content,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("handleKeyEvent", "(II)V", Self::handle_key_event, Default::default()),
+                JavaMethodProto::new("handleShowEvent", "()V", Self::handle_show_event, Default::default()),
+                JavaMethodProto::new("handleHideEvent", "()V", Self::handle_hide_event, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("inputMethod", "I", Default::default()),
+                JavaFieldProto::new("maxSize", "I", Default::default()),
+                JavaFieldProto::new("constraints", "I", Default::default()),
+            ],
             access_flags: Default::default(),
         }
     }
 
     async fn init(
         jvm: &Jvm,
-        _context: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
         title: ClassInstanceRef<String>,
         text: ClassInstanceRef<String>,
         max_size: i32,
@@ -39,6 +73,281 @@ impl TextBox {
 
         let _: () = jvm.invoke_special(&this, "javax/microedition/lcdui/Screen", "<init>", "()V", ()).await?;
 
+        if max_size <= 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "maxSize is zero or less").await);
+        }
+
+        jvm.put_field(&mut this, "title", "Ljava/lang/String;", title).await?;
+        jvm.put_field(&mut this, "maxSize", "I", max_size).await?;
+        jvm.put_field(&mut this, "constraints", "I", constraints).await?;
+
+        let input_method = context
+            .system()
+            .input_methods()
+            .create(InputConstraint::from_raw(constraints), max_size as _);
+        jvm.put_field(&mut this, "inputMethod", "I", input_method as i32).await?;
+
+        let text = renderer::rust_string(jvm, &text).await?;
+        Self::store_text(jvm, context, &this, &text).await?;
+
+        Ok(())
+    }
+
+    async fn get_string(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::getString({this:?})");
+
+        let text = Self::text(jvm, context, &this).await?;
+
+        Ok(JavaLangString::from_rust_string(jvm, &text).await?.into())
+    }
+
+    async fn set_string(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::setString({this:?}, {text:?})");
+
+        let text = renderer::rust_string(jvm, &text).await?;
+        Self::store_text(jvm, context, &this, &text).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_chars(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        mut data: ClassInstanceRef<Array<u16>>,
+    ) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::getChars({this:?}, {data:?})");
+
+        let chars = Self::text(jvm, context, &this).await?.encode_utf16().collect::<Vec<_>>();
+        if jvm.array_length(&data).await? < chars.len() {
+            return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", "data is too short").await);
+        }
+
+        let length = chars.len();
+        jvm.store_array(&mut data, 0, chars).await?;
+
+        Ok(length as _)
+    }
+
+    async fn set_chars(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        data: ClassInstanceRef<Array<u16>>,
+        offset: i32,
+        length: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::setChars({this:?}, {data:?}, {offset}, {length})");
+
+        let text = if data.is_null() {
+            RustString::new()
+        } else {
+            let chars: Vec<u16> = jvm.load_array(&data, offset as _, length as _).await?;
+            RustString::from_utf16_lossy(&chars)
+        };
+
+        Self::store_text(jvm, context, &this, &text).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn insert(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        src: ClassInstanceRef<String>,
+        position: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::insert({this:?}, {src:?}, {position})");
+
+        let src = renderer::rust_string(jvm, &src).await?;
+        let mut chars = Self::text(jvm, context, &this).await?.chars().collect::<Vec<_>>();
+
+        let position = (position.max(0) as usize).min(chars.len());
+        chars.splice(position..position, src.chars());
+
+        Self::store_text(jvm, context, &this, &chars.into_iter().collect::<RustString>()).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, offset: i32, length: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::delete({this:?}, {offset}, {length})");
+
+        let mut chars = Self::text(jvm, context, &this).await?.chars().collect::<Vec<_>>();
+        if offset < 0 || length < 0 || (offset + length) as usize > chars.len() {
+            return Err(jvm.exception("java/lang/StringIndexOutOfBoundsException", "invalid range").await);
+        }
+
+        chars.drain(offset as usize..(offset + length) as usize);
+
+        Self::store_text(jvm, context, &this, &chars.into_iter().collect::<RustString>()).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn size(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::size({this:?})");
+
+        Ok(Self::text(jvm, context, &this).await?.chars().count() as _)
+    }
+
+    async fn get_max_size(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::getMaxSize({this:?})");
+
+        jvm.get_field(&this, "maxSize", "I").await
+    }
+
+    async fn set_max_size(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, max_size: i32) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::setMaxSize({this:?}, {max_size})");
+
+        if max_size <= 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "maxSize is zero or less").await);
+        }
+
+        // existing text is truncated to fit new size
+        jvm.put_field(&mut this, "maxSize", "I", max_size).await?;
+        let input_method = Self::input_method(jvm, &this).await?;
+        if let Some(x) = context.system().input_methods().get(input_method) {
+            x.set_max_length(max_size as _);
+        }
+        renderer::request_redraw(context);
+
+        Ok(max_size)
+    }
+
+    async fn get_constraints(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::getConstraints({this:?})");
+
+        jvm.get_field(&this, "constraints", "I").await
+    }
+
+    async fn set_constraints(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, constraints: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::setConstraints({this:?}, {constraints})");
+
+        jvm.put_field(&mut this, "constraints", "I", constraints).await?;
+        let input_method = Self::input_method(jvm, &this).await?;
+        if let Some(x) = context.system().input_methods().get(input_method) {
+            x.set_constraint(InputConstraint::from_raw(constraints));
+        }
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_caret_position(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::getCaretPosition({this:?})");
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let cursor = context.system().input_methods().get(input_method).map(|x| x.cursor()).unwrap_or(0);
+
+        Ok(cursor as _)
+    }
+
+    async fn paint_content(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        graphics: ClassInstanceRef<Graphics>,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::paintContent({this:?}, {graphics:?}, {x}, {y}, {width}, {height})");
+
+        let constraints: i32 = jvm.get_field(&this, "constraints", "I").await?;
+        let input_method = Self::input_method(jvm, &this).await?;
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+        if let Some(input_method) = context.system().input_methods().get(input_method) {
+            renderer.draw_text_editor(
+                x + PADDING,
+                y + PADDING,
+                width - PADDING * 2,
+                height - PADDING * 2,
+                input_method,
+                constraints & PASSWORD != 0,
+                true,
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn handle_key_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, event_type: i32, code: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::handleKeyEvent({this:?}, {event_type}, {code})");
+
+        let Some(key) = MIDPKeyCode::from_raw(code).to_input_key() else {
+            return Ok(());
+        };
+
+        // holding a key repeats only editing keys, as repeated keypad keys would cycle multi-tap letters
+        match KeyboardEventType::from_raw(event_type) {
+            KeyboardEventType::KeyPressed => {}
+            KeyboardEventType::KeyRepeated if matches!(key, InputKey::Left | InputKey::Right | InputKey::Clear) => {}
+            _ => return Ok(()),
+        }
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let now = context.system().platform().now();
+        let handled = context.system().input_methods().get(input_method).is_some_and(|x| x.handle_key(key, now));
+
+        if handled {
+            renderer::request_redraw(context);
+        }
+
+        Ok(())
+    }
+
+    async fn handle_show_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::handleShowEvent({this:?})");
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        context.system().set_input_method_focus(input_method, true);
+
+        Ok(())
+    }
+
+    async fn handle_hide_event(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.TextBox::handleHideEvent({this:?})");
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        if let Some(x) = context.system().input_methods().get(input_method) {
+            x.commit();
+        }
+        context.system().set_input_method_focus(input_method, false);
+
+        Ok(())
+    }
+
+    async fn input_method(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<InputMethodHandle> {
+        let input_method: i32 = jvm.get_field(this, "inputMethod", "I").await?;
+
+        Ok(input_method as _)
+    }
+
+    async fn text(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<RustString> {
+        let input_method = Self::input_method(jvm, this).await?;
+
+        Ok(context.system().input_methods().get(input_method).map(|x| x.text()).unwrap_or_default())
+    }
+
+    async fn store_text(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>, text: &str) -> JvmResult<()> {
+        let max_size: i32 = jvm.get_field(this, "maxSize", "I").await?;
+        if text.chars().count() > max_size as usize {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "text exceeds maxSize").await);
+        }
+
+        let input_method = Self::input_method(jvm, this).await?;
+        if let Some(x) = context.system().input_methods().get(input_method) {
+            x.set_text(text);
+        }
+
         Ok(())
     }
 }
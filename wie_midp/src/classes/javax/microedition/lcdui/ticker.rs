@@ -0,0 +1,62 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::renderer;
+
+// class javax.microedition.lcdui.Ticker
+pub struct Ticker;
+
+impl Ticker {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/lcdui/Ticker",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("getString", "()Ljava/lang/String;", Self::get_string, Default::default()),
+                JavaMethodProto::new("setString", "(Ljava/lang/String;)V", Self::set_string, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("text", "Ljava/lang/String;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Ticker::<init>({this:?}, {text:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        if text.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "text is null").await);
+        }
+
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+
+        Ok(())
+    }
+
+    async fn get_string(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.lcdui.Ticker::getString({this:?})");
+
+        jvm.get_field(&this, "text", "Ljava/lang/String;").await
+    }
+
+    async fn set_string(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.lcdui.Ticker::setString({this:?}, {text:?})");
+
+        if text.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "text is null").await);
+        }
+
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+}
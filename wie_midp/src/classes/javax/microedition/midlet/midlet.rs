@@ -5,10 +5,16 @@ use java_constants::{ClassAccessFlags, FieldAccessFlags};
 use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
 
+use wie_backend::Event;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
 use crate::classes::javax::microedition::lcdui::Display;
 
+// MIDlet states. midlet is considered active as soon as it's created, as startApp is called right after
+const STATE_ACTIVE: i32 = 0;
+const STATE_PAUSED: i32 = 1;
+const STATE_DESTROYED: i32 = 2;
+
 // abstract class javax.microedition.midlet.MIDlet
 pub struct MIDlet;
 
@@ -27,11 +33,16 @@ impl MIDlet {
                     Default::default(),
                 ),
                 JavaMethodProto::new_abstract("startApp", "([Ljava/lang/String;)V", Default::default()),
+                JavaMethodProto::new_abstract("pauseApp", "()V", Default::default()),
+                JavaMethodProto::new_abstract("destroyApp", "(Z)V", Default::default()),
                 JavaMethodProto::new("notifyDestroyed", "()V", Self::notify_destroyed, Default::default()),
+                JavaMethodProto::new("notifyPaused", "()V", Self::notify_paused, Default::default()),
+                JavaMethodProto::new("resumeRequest", "()V", Self::resume_request, Default::default()),
             ],
             fields: vec![
                 JavaFieldProto::new("currentMIDlet", "Ljavax/microedition/midlet/MIDlet;", FieldAccessFlags::STATIC),
                 JavaFieldProto::new("display", "Ljavax/microedition/lcdui/Display;", Default::default()),
+                JavaFieldProto::new("state", "I", Default::default()),
             ],
             access_flags: ClassAccessFlags::ABSTRACT,
         }
@@ -73,8 +84,40 @@ impl MIDlet {
             .await
     }
 
-    async fn notify_destroyed(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.midlet.MIDlet::notifyDestroyed({:?})", &this);
+    async fn notify_destroyed(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.midlet.MIDlet::notifyDestroyed({:?})", &this);
+
+        if Self::is_destroyed(jvm, &this).await? {
+            return Ok(());
+        }
+
+        jvm.put_field(&mut this, "state", "I", STATE_DESTROYED).await?;
+        context.system().platform().exit();
+
+        Ok(())
+    }
+
+    async fn notify_paused(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.midlet.MIDlet::notifyPaused({:?})", &this);
+
+        let state: i32 = jvm.get_field(&this, "state", "I").await?;
+        if state == STATE_ACTIVE {
+            let display = Self::display(jvm, &this).await?;
+            let _: () = jvm.invoke_virtual(&display, "handleHideEvent", "()V", ()).await?;
+
+            jvm.put_field(&mut this, "state", "I", STATE_PAUSED).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn resume_request(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.midlet.MIDlet::resumeRequest({:?})", &this);
+
+        let state: i32 = jvm.get_field(&this, "state", "I").await?;
+        if state == STATE_PAUSED {
+            context.system().event_queue().push(Event::Resume);
+        }
 
         Ok(())
     }
@@ -82,4 +125,67 @@ impl MIDlet {
     pub async fn display(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Display>> {
         jvm.get_field(this, "display", "Ljavax/microedition/lcdui/Display;").await
     }
+
+    pub async fn is_destroyed(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<bool> {
+        let state: i32 = jvm.get_field(this, "state", "I").await?;
+
+        Ok(state == STATE_DESTROYED)
+    }
+
+    // called on Event::Suspend
+    pub async fn suspend(jvm: &Jvm, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        let state: i32 = jvm.get_field(&this, "state", "I").await?;
+        if state != STATE_ACTIVE {
+            return Ok(());
+        }
+
+        let display = Self::display(jvm, &this).await?;
+        let _: () = jvm.invoke_virtual(&display, "handleHideEvent", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "state", "I", STATE_PAUSED).await?;
+        jvm.invoke_virtual(&this, "pauseApp", "()V", ()).await
+    }
+
+    // called on Event::Resume
+    pub async fn resume(jvm: &Jvm, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        let state: i32 = jvm.get_field(&this, "state", "I").await?;
+        if state != STATE_PAUSED {
+            return Ok(());
+        }
+
+        jvm.put_field(&mut this, "state", "I", STATE_ACTIVE).await?;
+        let _: () = jvm.invoke_virtual(&this, "startApp", "()V", ()).await?;
+
+        // startApp may have destroyed or paused the midlet again
+        let state: i32 = jvm.get_field(&this, "state", "I").await?;
+        if state == STATE_ACTIVE {
+            let display = Self::display(jvm, &this).await?;
+            let _: () = jvm.invoke_virtual(&display, "handleShowEvent", "()V", ()).await?;
+        }
+
+        Ok(())
+    }
+
+    // called on Event::Terminate
+    pub async fn terminate(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        let state: i32 = jvm.get_field(&this, "state", "I").await?;
+        if state == STATE_DESTROYED {
+            return Ok(());
+        }
+
+        if state == STATE_ACTIVE {
+            let display = Self::display(jvm, &this).await?;
+            let _: () = jvm.invoke_virtual(&display, "handleHideEvent", "()V", ()).await?;
+        }
+
+        let _: () = jvm.invoke_virtual(&this, "destroyApp", "(Z)V", (true,)).await?;
+
+        // destroyApp(true) is unconditional, so exit even if app didn't call notifyDestroyed
+        if !Self::is_destroyed(jvm, &this).await? {
+            jvm.put_field(&mut this, "state", "I", STATE_DESTROYED).await?;
+            context.system().platform().exit();
+        }
+
+        Ok(())
+    }
 }
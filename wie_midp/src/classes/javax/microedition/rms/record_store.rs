@@ -1,15 +1,19 @@
-use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
+use alloc::{borrow::ToOwned, boxed::Box, string::String as RustString, vec, vec::Vec};
 
 use bytemuck::cast_vec;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
-use java_constants::MethodAccessFlags;
-use java_runtime::classes::java::lang::String;
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
+use java_runtime::classes::java::{lang::String, util::Vector};
 use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
 
-use wie_backend::Database;
+use wie_backend::{Database, storage_available};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
+use crate::classes::javax::microedition::rms::{RecordComparator, RecordEnumeration, RecordFilter, RecordListener};
+
+const MAX_NAME_LENGTH: usize = 32;
+
 // class javax.microedition.rms.RecordStore
 pub struct RecordStore;
 
@@ -22,13 +26,37 @@ impl RecordStore {
             methods: vec![
                 JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
                 JavaMethodProto::new("addRecord", "([BII)I", Self::add_record, Default::default()),
+                JavaMethodProto::new("deleteRecord", "(I)V", Self::delete_record, Default::default()),
                 JavaMethodProto::new("getSizeAvailable", "()I", Self::get_size_available, Default::default()),
+                JavaMethodProto::new("getSize", "()I", Self::get_size, Default::default()),
+                JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, Default::default()),
+                JavaMethodProto::new("getVersion", "()I", Self::get_version, Default::default()),
+                JavaMethodProto::new("getLastModified", "()J", Self::get_last_modified, Default::default()),
                 JavaMethodProto::new("getNextRecordID", "()I", Self::get_next_record_id, Default::default()),
                 JavaMethodProto::new("getRecord", "(I)[B", Self::get_record, Default::default()),
                 JavaMethodProto::new("getRecord", "(I[BI)I", Self::get_record_array, Default::default()),
                 JavaMethodProto::new("getRecordSize", "(I)I", Self::get_record_size, Default::default()),
                 JavaMethodProto::new("setRecord", "(I[BII)V", Self::set_record, Default::default()),
                 JavaMethodProto::new("getNumRecords", "()I", Self::get_num_records, Default::default()),
+                JavaMethodProto::new(
+                    "enumerateRecords",
+                    "(Ljavax/microedition/rms/RecordFilter;Ljavax/microedition/rms/RecordComparator;Z)Ljavax/microedition/rms/RecordEnumeration;",
+                    Self::enumerate_records,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "addRecordListener",
+                    "(Ljavax/microedition/rms/RecordListener;)V",
+                    Self::add_record_listener,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "removeRecordListener",
+                    "(Ljavax/microedition/rms/RecordListener;)V",
+                    Self::remove_record_listener,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("setMode", "(IZ)V", Self::set_mode, Default::default()),
                 JavaMethodProto::new("closeRecordStore", "()V", Self::close_record_store, Default::default()),
                 JavaMethodProto::new(
                     "openRecordStore",
@@ -36,6 +64,18 @@ impl RecordStore {
                     Self::open_record_store,
                     MethodAccessFlags::STATIC,
                 ),
+                JavaMethodProto::new(
+                    "openRecordStore",
+                    "(Ljava/lang/String;ZIZ)Ljavax/microedition/rms/RecordStore;",
+                    Self::open_record_store_with_mode,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openRecordStore",
+                    "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)Ljavax/microedition/rms/RecordStore;",
+                    Self::open_record_store_shared,
+                    MethodAccessFlags::STATIC,
+                ),
                 JavaMethodProto::new(
                     "deleteRecordStore",
                     "(Ljava/lang/String;)V",
@@ -49,7 +89,13 @@ impl RecordStore {
                     MethodAccessFlags::STATIC,
                 ),
             ],
-            fields: vec![JavaFieldProto::new("dbName", "Ljava/lang/String;", Default::default())],
+            fields: vec![
+                JavaFieldProto::new("dbName", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("openCount", "I", Default::default()),
+                JavaFieldProto::new("listeners", "Ljava/util/Vector;", Default::default()),
+                // opened stores, so that opening same store returns same instance
+                JavaFieldProto::new("openStores", "Ljava/util/Vector;", FieldAccessFlags::STATIC),
+            ],
             access_flags: Default::default(),
         }
     }
@@ -61,6 +107,9 @@ impl RecordStore {
 
         jvm.put_field(&mut this, "dbName", "Ljava/lang/String;", db_name).await?;
 
+        let listeners = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "listeners", "Ljava/util/Vector;", listeners).await?;
+
         Ok(())
     }
 
@@ -82,17 +131,83 @@ impl RecordStore {
 
         let mut database = Self::get_database(jvm, context, &this).await?;
 
-        let data: Vec<i8> = jvm.load_array(&data, offset as _, length as _).await?;
+        // data can be null for empty record
+        let data: Vec<i8> = if data.is_null() {
+            Vec::new()
+        } else {
+            jvm.load_array(&data, offset as _, length as _).await?
+        };
+
+        let size_available: i32 = jvm.invoke_virtual(&this, "getSizeAvailable", "()I", ()).await?;
+        if data.len() > size_available as usize {
+            return Err(jvm.exception("javax/microedition/rms/RecordStoreFullException", "Not enough space").await);
+        }
+
+        let Some(id) = database.add(&cast_vec(data)).await else {
+            return Err(jvm
+                .exception("javax/microedition/rms/RecordStoreException", "Failed to write record")
+                .await);
+        };
 
-        let id = database.add(&cast_vec(data)).await;
+        Self::notify_listeners(jvm, &this, "recordAdded", id as _).await?;
 
         Ok(id as _)
     }
 
-    async fn get_size_available(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
-        tracing::warn!("stub javax.microedition.rms.RecordStore::getSizeAvailable({:?})", &this);
+    async fn delete_record(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, record_id: i32) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.rms.RecordStore::deleteRecord({:?}, {})", &this, record_id);
+
+        let mut database = Self::get_database(jvm, context, &this).await?;
+
+        if !database.delete(record_id as _).await {
+            return Err(jvm.exception("javax/microedition/rms/InvalidRecordIDException", "Record not found").await);
+        }
+
+        Self::notify_listeners(jvm, &this, "recordDeleted", record_id).await
+    }
+
+    async fn get_size_available(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.rms.RecordStore::getSizeAvailable({:?})", &this);
+
+        Self::check_open(jvm, context, &this).await?;
+
+        let system = context.system();
+        let pid = system.pid().to_owned();
+
+        // quota is shared by all record stores of the suite
+        Ok(storage_available(system, &pid).await.min(i32::MAX as _) as _)
+    }
+
+    async fn get_size(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.rms.RecordStore::getSize({:?})", &this);
+
+        let database = Self::get_database(jvm, context, &this).await?;
 
-        Ok(1000000 as _)
+        Ok(database.size().await as _)
+    }
+
+    async fn get_name(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("javax.microedition.rms.RecordStore::getName({:?})", &this);
+
+        Self::check_open(jvm, context, &this).await?;
+
+        jvm.get_field(&this, "dbName", "Ljava/lang/String;").await
+    }
+
+    async fn get_version(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("javax.microedition.rms.RecordStore::getVersion({:?})", &this);
+
+        let database = Self::get_database(jvm, context, &this).await?;
+
+        Ok(database.version().await as _)
+    }
+
+    async fn get_last_modified(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("javax.microedition.rms.RecordStore::getLastModified({:?})", &this);
+
+        let database = Self::get_database(jvm, context, &this).await?;
+
+        Ok(database.last_modified().await.raw() as _)
     }
 
     async fn get_next_record_id(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
@@ -122,6 +237,11 @@ impl RecordStore {
 
         let data = result.unwrap();
 
+        // spec requires null for empty record
+        if data.is_empty() {
+            return Ok(None.into());
+        }
+
         let mut array = jvm.instantiate_array("B", data.len() as _).await?;
         jvm.store_array(&mut array, 0, cast_vec::<u8, i8>(data)).await?;
 
@@ -153,6 +273,12 @@ impl RecordStore {
 
         let data = result.unwrap();
         let data_length = data.len();
+
+        let buffer_length = jvm.array_length(&buffer).await?;
+        if offset < 0 || offset as usize + data_length > buffer_length {
+            return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", "Buffer too small").await);
+        }
+
         jvm.store_array(&mut buffer, offset as _, cast_vec::<u8, i8>(data)).await?;
 
         Ok(data_length as _)
@@ -163,14 +289,11 @@ impl RecordStore {
 
         let database = Self::get_database(jvm, context, &this).await?;
 
-        let result = database.get(record_id as _).await;
-        if result.is_none() {
+        let Some(size) = database.get_record_size(record_id as _).await else {
             return Err(jvm.exception("javax/microedition/rms/InvalidRecordIDException", "Record not found").await);
-        }
-
-        let data = result.unwrap();
+        };
 
-        Ok(data.len() as _)
+        Ok(size as _)
     }
 
     async fn set_record(
@@ -191,12 +314,25 @@ impl RecordStore {
             length
         );
 
-        let data: Vec<i8> = jvm.load_array(&data, offset as _, length as _).await?;
+        let data: Vec<i8> = if data.is_null() {
+            Vec::new()
+        } else {
+            jvm.load_array(&data, offset as _, length as _).await?
+        };
 
         let mut database = Self::get_database(jvm, context, &this).await?;
+        let Some(old_size) = database.get_record_size(record_id as _).await else {
+            return Err(jvm.exception("javax/microedition/rms/InvalidRecordIDException", "Record not found").await);
+        };
+
+        let size_available: i32 = jvm.invoke_virtual(&this, "getSizeAvailable", "()I", ()).await?;
+        if data.len() > old_size + size_available as usize {
+            return Err(jvm.exception("javax/microedition/rms/RecordStoreFullException", "Not enough space").await);
+        }
+
         database.set(record_id as _, &cast_vec(data)).await;
 
-        Ok(())
+        Self::notify_listeners(jvm, &this, "recordChanged", record_id).await
     }
 
     async fn get_num_records(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
@@ -204,53 +340,463 @@ impl RecordStore {
 
         let database = Self::get_database(jvm, context, &this).await?;
 
-        let count = database.get_record_ids().await.len();
+        Ok(database.count().await as _)
+    }
 
-        Ok(count as _)
+    async fn enumerate_records(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        filter: ClassInstanceRef<RecordFilter>,
+        comparator: ClassInstanceRef<RecordComparator>,
+        keep_updated: bool,
+    ) -> JvmResult<ClassInstanceRef<RecordEnumeration>> {
+        tracing::debug!(
+            "javax.microedition.rms.RecordStore::enumerateRecords({:?}, {:?}, {:?}, {})",
+            &this,
+            &filter,
+            &comparator,
+            keep_updated
+        );
+
+        Self::check_open(jvm, context, &this).await?;
+
+        let enumeration = jvm
+            .new_class(
+                "net/wie/RecordEnumerationImpl",
+                "(Ljavax/microedition/rms/RecordStore;Ljavax/microedition/rms/RecordFilter;Ljavax/microedition/rms/RecordComparator;Z)V",
+                (this, filter, comparator, keep_updated),
+            )
+            .await?;
+
+        Ok(enumeration.into())
     }
 
-    async fn close_record_store(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.rms.RecordStore::closeRecordStore({:?})", &this);
+    async fn add_record_listener(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        listener: ClassInstanceRef<RecordListener>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.rms.RecordStore::addRecordListener({:?}, {:?})", &this, &listener);
+
+        Self::check_open(jvm, context, &this).await?;
+
+        let listeners: ClassInstanceRef<Vector> = jvm.get_field(&this, "listeners", "Ljava/util/Vector;").await?;
+        let contains: bool = jvm
+            .invoke_virtual(&listeners, "contains", "(Ljava/lang/Object;)Z", (listener.clone(),))
+            .await?;
+        if !contains {
+            let _: () = jvm.invoke_virtual(&listeners, "addElement", "(Ljava/lang/Object;)V", (listener,)).await?;
+        }
 
         Ok(())
     }
 
-    async fn open_record_store(
+    async fn remove_record_listener(
         jvm: &Jvm,
         _context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        listener: ClassInstanceRef<RecordListener>,
+    ) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.rms.RecordStore::removeRecordListener({:?}, {:?})", &this, &listener);
+
+        let listeners: ClassInstanceRef<Vector> = jvm.get_field(&this, "listeners", "Ljava/util/Vector;").await?;
+        let _: bool = jvm
+            .invoke_virtual(&listeners, "removeElement", "(Ljava/lang/Object;)Z", (listener,))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_mode(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, auth_mode: i32, writable: bool) -> JvmResult<()> {
+        tracing::warn!(
+            "stub javax.microedition.rms.RecordStore::setMode({:?}, {}, {})",
+            &this,
+            auth_mode,
+            writable
+        );
+
+        Ok(())
+    }
+
+    async fn close_record_store(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.rms.RecordStore::closeRecordStore({:?})", &this);
+
+        Self::check_open(jvm, context, &this).await?;
+
+        let open_count: i32 = jvm.get_field(&this, "openCount", "I").await?;
+        jvm.put_field(&mut this, "openCount", "I", open_count - 1).await?;
+
+        // store is closed when all opens are balanced by closes
+        if open_count == 1 {
+            let listeners: ClassInstanceRef<Vector> = jvm.get_field(&this, "listeners", "Ljava/util/Vector;").await?;
+            let _: () = jvm.invoke_virtual(&listeners, "removeAllElements", "()V", ()).await?;
+
+            let open_stores = Self::open_stores(jvm).await?;
+            let _: bool = jvm
+                .invoke_virtual(&open_stores, "removeElement", "(Ljava/lang/Object;)Z", (this,))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn open_record_store(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
         name: ClassInstanceRef<String>,
         create: bool,
     ) -> JvmResult<ClassInstanceRef<Self>> {
         tracing::debug!("javax.microedition.rms.RecordStore::openRecordStore({:?}, {:?})", name, create);
 
-        let store = jvm
+        let name_str = Self::validate_name(jvm, &name).await?;
+
+        if let Some(mut store) = Self::find_open_store(jvm, &name_str).await? {
+            let open_count: i32 = jvm.get_field(&store, "openCount", "I").await?;
+            jvm.put_field(&mut store, "openCount", "I", open_count + 1).await?;
+
+            return Ok(store);
+        }
+
+        let system = context.system();
+        let pid = system.pid().to_owned();
+        let exists = system.platform().database_repository().exists(system, &name_str, &pid).await;
+        if !exists && !create {
+            return Err(jvm.exception("javax/microedition/rms/RecordStoreNotFoundException", &name_str).await);
+        }
+
+        let mut store: ClassInstanceRef<Self> = jvm
             .new_class("javax/microedition/rms/RecordStore", "(Ljava/lang/String;)V", (name,))
+            .await?
+            .into();
+        jvm.put_field(&mut store, "openCount", "I", 1).await?;
+
+        // make sure store exists even if no record is added
+        let _ = Self::get_database(jvm, context, &store).await?;
+
+        let open_stores = Self::open_stores(jvm).await?;
+        let _: () = jvm
+            .invoke_virtual(&open_stores, "addElement", "(Ljava/lang/Object;)V", (store.clone(),))
             .await?;
 
-        Ok(store.into())
+        Ok(store)
+    }
+
+    async fn open_record_store_with_mode(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        name: ClassInstanceRef<String>,
+        create: bool,
+        auth_mode: i32,
+        writable: bool,
+    ) -> JvmResult<ClassInstanceRef<Self>> {
+        tracing::debug!(
+            "javax.microedition.rms.RecordStore::openRecordStore({:?}, {}, {}, {})",
+            name,
+            create,
+            auth_mode,
+            writable
+        );
+
+        // we don't share record stores between suites, so access mode doesn't matter
+        jvm.invoke_static(
+            "javax/microedition/rms/RecordStore",
+            "openRecordStore",
+            "(Ljava/lang/String;Z)Ljavax/microedition/rms/RecordStore;",
+            (name, create),
+        )
+        .await
+    }
+
+    async fn open_record_store_shared(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        name: ClassInstanceRef<String>,
+        vendor_name: ClassInstanceRef<String>,
+        suite_name: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<Self>> {
+        tracing::debug!(
+            "javax.microedition.rms.RecordStore::openRecordStore({:?}, {:?}, {:?})",
+            name,
+            vendor_name,
+            suite_name
+        );
+
+        if vendor_name.is_null() || suite_name.is_null() {
+            return Err(jvm
+                .exception("java/lang/IllegalArgumentException", "vendor and suite name is required")
+                .await);
+        }
+
+        // TODO we don't have other suites, assume it's ours
+        jvm.invoke_static(
+            "javax/microedition/rms/RecordStore",
+            "openRecordStore",
+            "(Ljava/lang/String;Z)Ljavax/microedition/rms/RecordStore;",
+            (name, false),
+        )
+        .await
     }
 
-    async fn delete_record_store(_jvm: &Jvm, _context: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<()> {
-        tracing::warn!("stub javax.microedition.rms.RecordStore::deleteRecordStore({name:?})");
+    async fn delete_record_store(jvm: &Jvm, context: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("javax.microedition.rms.RecordStore::deleteRecordStore({name:?})");
+
+        let name_str = Self::validate_name(jvm, &name).await?;
+
+        if Self::find_open_store(jvm, &name_str).await?.is_some() {
+            return Err(jvm.exception("javax/microedition/rms/RecordStoreException", "Record store is open").await);
+        }
+
+        let system = context.system();
+        let pid = system.pid().to_owned();
+        let repository = system.platform().database_repository();
+        if !repository.exists(system, &name_str, &pid).await {
+            return Err(jvm.exception("javax/microedition/rms/RecordStoreNotFoundException", &name_str).await);
+        }
+
+        if !repository.delete(system, &name_str, &pid).await {
+            return Err(jvm.exception("javax/microedition/rms/RecordStoreException", "Failed to delete").await);
+        }
 
         Ok(())
     }
 
-    async fn list_record_stores(jvm: &Jvm, _context: &mut WieJvmContext) -> JvmResult<ClassInstanceRef<Array<String>>> {
-        tracing::warn!("stub javax.microedition.rms.RecordStore::listRecordStores()");
+    async fn list_record_stores(jvm: &Jvm, context: &mut WieJvmContext) -> JvmResult<ClassInstanceRef<Array<String>>> {
+        tracing::debug!("javax.microedition.rms.RecordStore::listRecordStores()");
 
-        let result = jvm.instantiate_array("Ljava/lang/String;", 0).await?;
+        let system = context.system();
+        let pid = system.pid().to_owned();
+        let names = system.platform().database_repository().list(system, &pid).await;
+
+        // spec requires null if there's no record store
+        if names.is_empty() {
+            return Ok(None.into());
+        }
+
+        let mut java_names = Vec::with_capacity(names.len());
+        for name in names {
+            java_names.push(JavaLangString::from_rust_string(jvm, &name).await?);
+        }
+
+        let mut result = jvm.instantiate_array("Ljava/lang/String;", java_names.len()).await?;
+        jvm.store_array(&mut result, 0, java_names).await?;
 
         Ok(result.into())
     }
 
+    // wie private methods
+
+    // record ids in ascending order
+    pub async fn record_ids(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<Vec<i32>> {
+        let database = Self::get_database(jvm, context, this).await?;
+
+        let mut ids = database.get_record_ids().await.into_iter().map(|x| x as i32).collect::<Vec<_>>();
+        ids.sort_unstable();
+
+        Ok(ids)
+    }
+
+    async fn check_open(jvm: &Jvm, _context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<()> {
+        let open_count: i32 = jvm.get_field(this, "openCount", "I").await?;
+        if open_count <= 0 {
+            return Err(jvm
+                .exception("javax/microedition/rms/RecordStoreNotOpenException", "Record store is closed")
+                .await);
+        }
+
+        Ok(())
+    }
+
+    async fn validate_name(jvm: &Jvm, name: &ClassInstanceRef<String>) -> JvmResult<RustString> {
+        if name.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "name is null").await);
+        }
+
+        let name = JavaLangString::to_rust_string(jvm, name).await?;
+        let length = name.chars().count();
+        if length == 0 || length > MAX_NAME_LENGTH {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid record store name").await);
+        }
+
+        Ok(name)
+    }
+
+    async fn open_stores(jvm: &Jvm) -> JvmResult<ClassInstanceRef<Vector>> {
+        let open_stores: ClassInstanceRef<Vector> = jvm
+            .get_static_field("javax/microedition/rms/RecordStore", "openStores", "Ljava/util/Vector;")
+            .await?;
+        if !open_stores.is_null() {
+            return Ok(open_stores);
+        }
+
+        let open_stores = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_static_field(
+            "javax/microedition/rms/RecordStore",
+            "openStores",
+            "Ljava/util/Vector;",
+            open_stores.clone(),
+        )
+        .await?;
+
+        Ok(open_stores.into())
+    }
+
+    async fn find_open_store(jvm: &Jvm, name: &str) -> JvmResult<Option<ClassInstanceRef<Self>>> {
+        let open_stores = Self::open_stores(jvm).await?;
+        let size: i32 = jvm.invoke_virtual(&open_stores, "size", "()I", ()).await?;
+
+        for index in 0..size {
+            let store: ClassInstanceRef<Self> = jvm.invoke_virtual(&open_stores, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let store_name = jvm.get_field(&store, "dbName", "Ljava/lang/String;").await?;
+            if JavaLangString::to_rust_string(jvm, &store_name).await? == name {
+                return Ok(Some(store));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn notify_listeners(jvm: &Jvm, this: &ClassInstanceRef<Self>, method: &str, record_id: i32) -> JvmResult<()> {
+        let listeners: ClassInstanceRef<Vector> = jvm.get_field(this, "listeners", "Ljava/util/Vector;").await?;
+        let size: i32 = jvm.invoke_virtual(&listeners, "size", "()I", ()).await?;
+
+        // listeners may remove themselves while being notified
+        let mut targets: Vec<ClassInstanceRef<RecordListener>> = Vec::with_capacity(size as _);
+        for index in 0..size {
+            targets.push(jvm.invoke_virtual(&listeners, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?);
+        }
+
+        for listener in targets {
+            let _: () = jvm
+                .invoke_virtual(&listener, method, "(Ljavax/microedition/rms/RecordStore;I)V", (this.clone(), record_id))
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn get_database(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<Box<dyn Database>> {
+        Self::check_open(jvm, context, this).await?;
+
         let db_name = jvm.get_field(this, "dbName", "Ljava/lang/String;").await?;
         let db_name_str = JavaLangString::to_rust_string(jvm, &db_name).await?;
 
         let system = context.system();
         let pid = system.pid().to_owned();
 
-        Ok(system.platform().database_repository().open(system, &db_name_str, &pid).await)
+        let database = system.platform().database_repository().open(system, &db_name_str, &pid).await;
+
+        match database {
+            Some(x) => Ok(x),
+            None => Err(jvm
+                .exception("javax/microedition/rms/RecordStoreException", "Failed to open record store")
+                .await),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, vec};
+
+    use java_runtime::classes::java::lang::String;
+    use jvm::{Array, ClassInstanceRef, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    use crate::{classes::javax::microedition::rms::RecordStore, get_protos};
+
+    #[test]
+    fn test_record_store() -> Result<()> {
+        run_jvm_test(Box::new([get_protos().into()]), |jvm| async move {
+            let name = JavaLangString::from_rust_string(&jvm, "test").await?;
+
+            let result: JvmResult<ClassInstanceRef<RecordStore>> = jvm
+                .invoke_static(
+                    "javax/microedition/rms/RecordStore",
+                    "openRecordStore",
+                    "(Ljava/lang/String;Z)Ljavax/microedition/rms/RecordStore;",
+                    (name.clone(), false),
+                )
+                .await;
+            assert!(result.is_err());
+
+            let store: ClassInstanceRef<RecordStore> = jvm
+                .invoke_static(
+                    "javax/microedition/rms/RecordStore",
+                    "openRecordStore",
+                    "(Ljava/lang/String;Z)Ljavax/microedition/rms/RecordStore;",
+                    (name.clone(), true),
+                )
+                .await?;
+
+            for data in [vec![3i8, 3], vec![1], vec![2, 2, 2]] {
+                let mut array = jvm.instantiate_array("B", data.len()).await?;
+                jvm.store_array(&mut array, 0, data.clone()).await?;
+
+                let _: i32 = jvm.invoke_virtual(&store, "addRecord", "([BII)I", (array, 0, data.len() as i32)).await?;
+            }
+
+            let num_records: i32 = jvm.invoke_virtual(&store, "getNumRecords", "()I", ()).await?;
+            assert_eq!(num_records, 3);
+            let size: i32 = jvm.invoke_virtual(&store, "getSize", "()I", ()).await?;
+            assert_eq!(size, 6);
+            let version: i32 = jvm.invoke_virtual(&store, "getVersion", "()I", ()).await?;
+            assert_eq!(version, 3);
+
+            let _: () = jvm.invoke_virtual(&store, "deleteRecord", "(I)V", (2,)).await?;
+
+            let enumeration = jvm
+                .invoke_virtual(
+                    &store,
+                    "enumerateRecords",
+                    "(Ljavax/microedition/rms/RecordFilter;Ljavax/microedition/rms/RecordComparator;Z)Ljavax/microedition/rms/RecordEnumeration;",
+                    [None.into(), None.into(), false.into()],
+                )
+                .await?;
+            let num_records: i32 = jvm.invoke_virtual(&enumeration, "numRecords", "()I", ()).await?;
+            assert_eq!(num_records, 2);
+            let id: i32 = jvm.invoke_virtual(&enumeration, "nextRecordId", "()I", ()).await?;
+            assert_eq!(id, 1);
+            let id: i32 = jvm.invoke_virtual(&enumeration, "nextRecordId", "()I", ()).await?;
+            assert_eq!(id, 3);
+            let has_next: bool = jvm.invoke_virtual(&enumeration, "hasNextElement", "()Z", ()).await?;
+            assert!(!has_next);
+
+            let names: ClassInstanceRef<Array<String>> = jvm
+                .invoke_static("javax/microedition/rms/RecordStore", "listRecordStores", "()[Ljava/lang/String;", ())
+                .await?;
+            assert_eq!(jvm.array_length(&names).await?, 1);
+
+            // store can't be deleted while open
+            let result: JvmResult<()> = jvm
+                .invoke_static(
+                    "javax/microedition/rms/RecordStore",
+                    "deleteRecordStore",
+                    "(Ljava/lang/String;)V",
+                    (name.clone(),),
+                )
+                .await;
+            assert!(result.is_err());
+
+            let _: () = jvm.invoke_virtual(&store, "closeRecordStore", "()V", ()).await?;
+            let _: () = jvm
+                .invoke_static(
+                    "javax/microedition/rms/RecordStore",
+                    "deleteRecordStore",
+                    "(Ljava/lang/String;)V",
+                    (name,),
+                )
+                .await?;
+
+            let names: ClassInstanceRef<Array<String>> = jvm
+                .invoke_static("javax/microedition/rms/RecordStore", "listRecordStores", "()[Ljava/lang/String;", ())
+                .await?;
+            assert!(names.is_null());
+
+            Ok(())
+        })
     }
 }
@@ -0,0 +1,52 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+// class javax.microedition.rms.RecordStoreFullException
+pub struct RecordStoreFullException;
+
+impl RecordStoreFullException {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "javax/microedition/rms/RecordStoreFullException",
+            parent_class: Some("javax/microedition/rms/RecordStoreException"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init_with_message, Default::default()),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> Result<()> {
+        tracing::debug!("javax.microedition.rms.RecordStoreFullException::<init>({:?})", &this);
+
+        let _: () = jvm
+            .invoke_special(&this, "javax/microedition/rms/RecordStoreException", "<init>", "()V", ())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn init_with_message(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, message: ClassInstanceRef<String>) -> Result<()> {
+        tracing::debug!("javax.microedition.rms.RecordStoreFullException::<init>({:?}, {:?})", &this, &message);
+
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "javax/microedition/rms/RecordStoreException",
+                "<init>",
+                "(Ljava/lang/String;)V",
+                (message,),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,31 @@
+mod array_enumeration;
+mod command_event;
+mod datagram_connection_impl;
+mod datagram_impl;
 mod event_queue;
+mod file_connection_impl;
+mod http_connection_impl;
 mod launcher;
+mod record_enumeration_impl;
 mod smaf_player;
+mod socket_connection_impl;
+mod socket_input_stream;
+mod socket_output_stream;
+mod stream_connection_impl;
 
 pub use self::{
-    event_queue::{EventQueue, KeyboardEventType, MIDPKeyCode},
+    array_enumeration::ArrayEnumeration,
+    command_event::CommandEvent,
+    datagram_connection_impl::DatagramConnectionImpl,
+    datagram_impl::DatagramImpl,
+    event_queue::{EventQueue, KeyboardEventType, MIDPKeyCode, PointerEventType},
+    file_connection_impl::FileConnectionImpl,
+    http_connection_impl::HttpConnectionImpl,
     launcher::Launcher,
+    record_enumeration_impl::RecordEnumerationImpl,
     smaf_player::SmafPlayer,
+    socket_connection_impl::SocketConnectionImpl,
+    socket_input_stream::SocketInputStream,
+    socket_output_stream::SocketOutputStream,
+    stream_connection_impl::StreamConnectionImpl,
 };
@@ -0,0 +1,70 @@
+use alloc::{vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::Object;
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+// class net.wie.ArrayEnumeration
+pub struct ArrayEnumeration;
+
+impl ArrayEnumeration {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/ArrayEnumeration",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/util/Enumeration"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "([Ljava/lang/Object;)V", Self::init, Default::default()),
+                JavaMethodProto::new("hasMoreElements", "()Z", Self::has_more_elements, Default::default()),
+                JavaMethodProto::new("nextElement", "()Ljava/lang/Object;", Self::next_element, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("elements", "[Ljava/lang/Object;", Default::default()),
+                JavaFieldProto::new("index", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        elements: ClassInstanceRef<Array<ClassInstanceRef<Object>>>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.ArrayEnumeration::<init>({:?}, {:?})", &this, &elements);
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "elements", "[Ljava/lang/Object;", elements).await?;
+
+        Ok(())
+    }
+
+    async fn has_more_elements(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.ArrayEnumeration::hasMoreElements({:?})", &this);
+
+        let elements = jvm.get_field(&this, "elements", "[Ljava/lang/Object;").await?;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        Ok((index as usize) < jvm.array_length(&elements).await?)
+    }
+
+    async fn next_element(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Object>> {
+        tracing::debug!("net.wie.ArrayEnumeration::nextElement({:?})", &this);
+
+        let elements = jvm.get_field(&this, "elements", "[Ljava/lang/Object;").await?;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+        if index as usize >= jvm.array_length(&elements).await? {
+            return Err(jvm.exception("java/util/NoSuchElementException", "No more elements").await);
+        }
+
+        jvm.put_field(&mut this, "index", "I", index + 1).await?;
+
+        let mut element: Vec<ClassInstanceRef<Object>> = jvm.load_array(&elements, index as _, 1).await?;
+
+        Ok(element.remove(0))
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::lcdui::{Command, CommandListener, Displayable};
+
+// class net.wie.CommandEvent
+pub struct CommandEvent;
+
+impl CommandEvent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/CommandEvent",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["java/lang/Runnable"],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljavax/microedition/lcdui/CommandListener;Ljavax/microedition/lcdui/Command;Ljavax/microedition/lcdui/Displayable;)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("run", "()V", Self::run, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("listener", "Ljavax/microedition/lcdui/CommandListener;", Default::default()),
+                JavaFieldProto::new("command", "Ljavax/microedition/lcdui/Command;", Default::default()),
+                JavaFieldProto::new("displayable", "Ljavax/microedition/lcdui/Displayable;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        listener: ClassInstanceRef<CommandListener>,
+        command: ClassInstanceRef<Command>,
+        displayable: ClassInstanceRef<Displayable>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.CommandEvent::<init>({this:?}, {listener:?}, {command:?}, {displayable:?})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "listener", "Ljavax/microedition/lcdui/CommandListener;", listener)
+            .await?;
+        jvm.put_field(&mut this, "command", "Ljavax/microedition/lcdui/Command;", command).await?;
+        jvm.put_field(&mut this, "displayable", "Ljavax/microedition/lcdui/Displayable;", displayable)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn run(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.CommandEvent::run({this:?})");
+
+        let listener: ClassInstanceRef<CommandListener> = jvm.get_field(&this, "listener", "Ljavax/microedition/lcdui/CommandListener;").await?;
+        let command: ClassInstanceRef<Command> = jvm.get_field(&this, "command", "Ljavax/microedition/lcdui/Command;").await?;
+        let displayable: ClassInstanceRef<Displayable> = jvm.get_field(&this, "displayable", "Ljavax/microedition/lcdui/Displayable;").await?;
+
+        jvm.invoke_virtual(
+            &listener,
+            "commandAction",
+            "(Ljavax/microedition/lcdui/Command;Ljavax/microedition/lcdui/Displayable;)V",
+            (command, displayable),
+        )
+        .await
+    }
+}
@@ -0,0 +1,272 @@
+use alloc::{format, vec};
+use core::net::{Ipv4Addr, SocketAddrV4};
+
+use bytemuck::cast_vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_backend::{MAX_DATAGRAM_SIZE, SocketError, SocketHandle, SocketType};
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::io::{Connector, Datagram, connector::POLL_INTERVAL};
+
+// class net.wie.DatagramConnectionImpl
+// `datagram://host:port` sends to the host by default, and `datagram://:port` receives on the port
+pub struct DatagramConnectionImpl;
+
+impl DatagramConnectionImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/DatagramConnectionImpl",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["javax/microedition/io/DatagramConnection"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("getMaximumLength", "()I", Self::get_maximum_length, Default::default()),
+                JavaMethodProto::new("getNominalLength", "()I", Self::get_maximum_length, Default::default()),
+                JavaMethodProto::new("send", "(Ljavax/microedition/io/Datagram;)V", Self::send, Default::default()),
+                JavaMethodProto::new("receive", "(Ljavax/microedition/io/Datagram;)V", Self::receive, Default::default()),
+                JavaMethodProto::new(
+                    "newDatagram",
+                    "(I)Ljavax/microedition/io/Datagram;",
+                    Self::new_datagram,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "newDatagram",
+                    "(ILjava/lang/String;)Ljavax/microedition/io/Datagram;",
+                    Self::new_datagram_with_address,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "newDatagram",
+                    "([BI)Ljavax/microedition/io/Datagram;",
+                    Self::new_datagram_with_buffer,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "newDatagram",
+                    "([BILjava/lang/String;)Ljavax/microedition/io/Datagram;",
+                    Self::new_datagram_with_buffer_address,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("close", "()V", Self::close, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("handle", "I", Default::default()),
+                JavaFieldProto::new("address", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("closed", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, url: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::<init>({:?}, {:?})", &this, &url);
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        let url_string = JavaLangString::to_rust_string(jvm, &url).await?;
+        let Some((host, port)) = Connector::parse_host_port(&url_string) else {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid url").await);
+        };
+
+        // client connection binds to any port
+        let local_port = if host.is_empty() { port } else { 0 };
+
+        let system = context.system().clone();
+        let handle = system.sockets().create(SocketType::Datagram);
+        let result = system
+            .sockets()
+            .bind(&system, handle, SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, local_port));
+        if let Err(err) = result {
+            let _ = system.sockets().close(handle);
+
+            return Err(Connector::io_exception(jvm, err).await);
+        }
+
+        jvm.put_field(&mut this, "handle", "I", handle as i32).await?;
+        if !host.is_empty() {
+            jvm.put_field(&mut this, "address", "Ljava/lang/String;", url).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_maximum_length(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::getMaximumLength({:?})", &this);
+
+        Ok(MAX_DATAGRAM_SIZE as _)
+    }
+
+    async fn send(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, datagram: ClassInstanceRef<Datagram>) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::send({:?}, {:?})", &this, &datagram);
+
+        let handle = Self::handle(jvm, &this).await?;
+
+        let address: ClassInstanceRef<String> = jvm.invoke_virtual(&datagram, "getAddress", "()Ljava/lang/String;", ()).await?;
+        if address.is_null() {
+            return Err(jvm.exception("java/io/IOException", "No address").await);
+        }
+        let address = JavaLangString::to_rust_string(jvm, &address).await?;
+        let Some((host, port)) = Connector::parse_host_port(&address) else {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid address").await);
+        };
+        let addr = SocketAddrV4::new(Connector::resolve(jvm, context, host).await?, port);
+
+        let data: ClassInstanceRef<Array<i8>> = jvm.invoke_virtual(&datagram, "getData", "()[B", ()).await?;
+        let offset: i32 = jvm.invoke_virtual(&datagram, "getOffset", "()I", ()).await?;
+        let length: i32 = jvm.invoke_virtual(&datagram, "getLength", "()I", ()).await?;
+        let data = cast_vec::<i8, u8>(jvm.load_array(&data, offset as _, length as _).await?);
+
+        loop {
+            let system = context.system().clone();
+            let result = system.sockets().send_to(&system, handle, &data, addr);
+            match result {
+                Ok(_) => return Ok(()),
+                Err(SocketError::WouldBlock) => context.system().sleep(POLL_INTERVAL).await,
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            }
+        }
+    }
+
+    // blocks until a datagram arrives. it's truncated to the datagram length
+    async fn receive(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, datagram: ClassInstanceRef<Datagram>) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::receive({:?}, {:?})", &this, &datagram);
+
+        let handle = Self::handle(jvm, &this).await?;
+
+        let mut data: ClassInstanceRef<Array<i8>> = jvm.invoke_virtual(&datagram, "getData", "()[B", ()).await?;
+        let offset: i32 = jvm.invoke_virtual(&datagram, "getOffset", "()I", ()).await?;
+        let length: i32 = jvm.invoke_virtual(&datagram, "getLength", "()I", ()).await?;
+
+        let mut buf = vec![0; length as _];
+        let (received, addr) = loop {
+            let result = context.system().sockets().recv_from(handle, &mut buf);
+            match result {
+                Ok(x) => break x,
+                Err(SocketError::WouldBlock) => context.system().sleep(POLL_INTERVAL).await,
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            }
+        };
+        buf.truncate(received);
+
+        jvm.store_array(&mut data, offset as _, cast_vec::<u8, i8>(buf)).await?;
+        let _: () = jvm.invoke_virtual(&datagram, "setLength", "(I)V", (received as i32,)).await?;
+
+        let address = JavaLangString::from_rust_string(jvm, &format!("datagram://{addr}")).await?;
+        let _: () = jvm.invoke_virtual(&datagram, "setAddress", "(Ljava/lang/String;)V", (address,)).await?;
+
+        Ok(())
+    }
+
+    async fn new_datagram(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, size: i32) -> JvmResult<ClassInstanceRef<Datagram>> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::newDatagram({:?}, {})", &this, size);
+
+        let buf = Self::instantiate_buffer(jvm, size).await?;
+
+        jvm.invoke_virtual(&this, "newDatagram", "([BI)Ljavax/microedition/io/Datagram;", (buf, size))
+            .await
+    }
+
+    async fn new_datagram_with_address(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        size: i32,
+        address: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<Datagram>> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::newDatagram({:?}, {}, {:?})", &this, size, &address);
+
+        let buf = Self::instantiate_buffer(jvm, size).await?;
+
+        jvm.invoke_virtual(
+            &this,
+            "newDatagram",
+            "([BILjava/lang/String;)Ljavax/microedition/io/Datagram;",
+            (buf, size, address),
+        )
+        .await
+    }
+
+    async fn new_datagram_with_buffer(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        buf: ClassInstanceRef<Array<i8>>,
+        size: i32,
+    ) -> JvmResult<ClassInstanceRef<Datagram>> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::newDatagram({:?}, {:?}, {})", &this, &buf, size);
+
+        let address: ClassInstanceRef<String> = jvm.get_field(&this, "address", "Ljava/lang/String;").await?;
+
+        jvm.invoke_virtual(
+            &this,
+            "newDatagram",
+            "([BILjava/lang/String;)Ljavax/microedition/io/Datagram;",
+            (buf, size, address),
+        )
+        .await
+    }
+
+    async fn new_datagram_with_buffer_address(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        buf: ClassInstanceRef<Array<i8>>,
+        size: i32,
+        address: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<Datagram>> {
+        tracing::debug!(
+            "net.wie.DatagramConnectionImpl::newDatagram({:?}, {:?}, {}, {:?})",
+            &this,
+            &buf,
+            size,
+            &address
+        );
+
+        Self::handle(jvm, &this).await?;
+
+        let datagram = jvm
+            .new_class("net/wie/DatagramImpl", "([BILjava/lang/String;)V", (buf, size, address))
+            .await?;
+
+        Ok(datagram.into())
+    }
+
+    async fn close(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramConnectionImpl::close({:?})", &this);
+
+        let closed: bool = jvm.get_field(&this, "closed", "Z").await?;
+        if closed {
+            return Ok(());
+        }
+        jvm.put_field(&mut this, "closed", "Z", true).await?;
+
+        let handle: i32 = jvm.get_field(&this, "handle", "I").await?;
+        let _ = context.system().sockets().close(handle as _);
+
+        Ok(())
+    }
+
+    async fn instantiate_buffer(jvm: &Jvm, size: i32) -> JvmResult<ClassInstanceRef<Array<i8>>> {
+        if !(0..=MAX_DATAGRAM_SIZE as i32).contains(&size) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid size").await);
+        }
+
+        Ok(jvm.instantiate_array("B", size as _).await?.into())
+    }
+
+    async fn handle(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<SocketHandle> {
+        let closed: bool = jvm.get_field(this, "closed", "Z").await?;
+        if closed {
+            return Err(jvm.exception("java/io/IOException", "Connection closed").await);
+        }
+
+        let handle: i32 = jvm.get_field(this, "handle", "I").await?;
+
+        Ok(handle as _)
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::io::{Connector, Datagram};
+
+// class net.wie.DatagramImpl
+pub struct DatagramImpl;
+
+impl DatagramImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/DatagramImpl",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["javax/microedition/io/Datagram"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "([BILjava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("getAddress", "()Ljava/lang/String;", Self::get_address, Default::default()),
+                JavaMethodProto::new("getData", "()[B", Self::get_data, Default::default()),
+                JavaMethodProto::new("getLength", "()I", Self::get_length, Default::default()),
+                JavaMethodProto::new("getOffset", "()I", Self::get_offset, Default::default()),
+                JavaMethodProto::new("setAddress", "(Ljava/lang/String;)V", Self::set_address, Default::default()),
+                JavaMethodProto::new(
+                    "setAddress",
+                    "(Ljavax/microedition/io/Datagram;)V",
+                    Self::set_address_from_datagram,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("setLength", "(I)V", Self::set_length, Default::default()),
+                JavaMethodProto::new("setData", "([BII)V", Self::set_data, Default::default()),
+                JavaMethodProto::new("reset", "()V", Self::reset, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("data", "[B", Default::default()),
+                JavaFieldProto::new("offset", "I", Default::default()),
+                JavaFieldProto::new("length", "I", Default::default()),
+                JavaFieldProto::new("address", "Ljava/lang/String;", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        data: ClassInstanceRef<Array<i8>>,
+        length: i32,
+        address: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramImpl::<init>({:?}, {:?}, {}, {:?})", &this, &data, length, &address);
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        let _: () = jvm.invoke_virtual(&this, "setData", "([BII)V", (data, 0, length)).await?;
+        if !address.is_null() {
+            let _: () = jvm.invoke_virtual(&this, "setAddress", "(Ljava/lang/String;)V", (address,)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_address(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.DatagramImpl::getAddress({:?})", &this);
+
+        jvm.get_field(&this, "address", "Ljava/lang/String;").await
+    }
+
+    async fn get_data(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Array<i8>>> {
+        tracing::debug!("net.wie.DatagramImpl::getData({:?})", &this);
+
+        jvm.get_field(&this, "data", "[B").await
+    }
+
+    async fn get_length(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.DatagramImpl::getLength({:?})", &this);
+
+        jvm.get_field(&this, "length", "I").await
+    }
+
+    async fn get_offset(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.DatagramImpl::getOffset({:?})", &this);
+
+        jvm.get_field(&this, "offset", "I").await
+    }
+
+    async fn set_address(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, address: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramImpl::setAddress({:?}, {:?})", &this, &address);
+
+        let address_string = JavaLangString::to_rust_string(jvm, &address).await?;
+        if Connector::parse_host_port(&address_string).is_none_or(|(host, _)| host.is_empty()) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid address").await);
+        }
+
+        jvm.put_field(&mut this, "address", "Ljava/lang/String;", address).await?;
+
+        Ok(())
+    }
+
+    async fn set_address_from_datagram(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        datagram: ClassInstanceRef<Datagram>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramImpl::setAddress({:?}, {:?})", &this, &datagram);
+
+        let address: ClassInstanceRef<String> = jvm.invoke_virtual(&datagram, "getAddress", "()Ljava/lang/String;", ()).await?;
+        if address.is_null() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "No address").await);
+        }
+
+        jvm.put_field(&mut this, "address", "Ljava/lang/String;", address).await?;
+
+        Ok(())
+    }
+
+    async fn set_length(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, length: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramImpl::setLength({:?}, {})", &this, length);
+
+        let data = jvm.get_field(&this, "data", "[B").await?;
+        let offset: i32 = jvm.get_field(&this, "offset", "I").await?;
+        if length < 0 || (offset + length) as usize > jvm.array_length(&data).await? {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid length").await);
+        }
+
+        jvm.put_field(&mut this, "length", "I", length).await?;
+
+        Ok(())
+    }
+
+    async fn set_data(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        data: ClassInstanceRef<Array<i8>>,
+        offset: i32,
+        length: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramImpl::setData({:?}, {:?}, {}, {})", &this, &data, offset, length);
+
+        if offset < 0 || length < 0 || (offset + length) as usize > jvm.array_length(&data).await? {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid offset or length").await);
+        }
+
+        jvm.put_field(&mut this, "data", "[B", data).await?;
+        jvm.put_field(&mut this, "offset", "I", offset).await?;
+        jvm.put_field(&mut this, "length", "I", length).await?;
+
+        Ok(())
+    }
+
+    async fn reset(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.DatagramImpl::reset({:?})", &this);
+
+        jvm.put_field(&mut this, "offset", "I", 0).await?;
+        jvm.put_field(&mut this, "length", "I", 0).await?;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,12 @@
-use alloc::{string::ToString, vec, vec::Vec};
+use alloc::{string::ToString, vec};
+use core::cmp::min;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::{FieldAccessFlags, MethodAccessFlags};
 use java_runtime::classes::java::lang::Runnable;
 use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result as JvmResult};
 
-use wie_backend::{Event, KeyCode};
+use wie_backend::{Event, InputKey, KeyCode};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
 use crate::classes::javax::microedition::midlet::MIDlet;
@@ -15,8 +16,12 @@ use crate::classes::javax::microedition::midlet::MIDlet;
 enum EventQueueEvent {
     // TODO it's wipi event codes
     KeyEvent = 1,
+    PointerEvent = 2,
     RepaintEvent = 41,
     NotifyEvent = 1000,
+    SuspendEvent = 1001,
+    ResumeEvent = 1002,
+    TerminateEvent = 1003,
 }
 
 impl EventQueueEvent {
@@ -39,6 +44,32 @@ impl KeyboardEventType {
     pub fn from_raw(raw: i32) -> Self {
         unsafe { core::mem::transmute(raw) }
     }
+
+    // for event types from guest, which may not be one of the known types
+    pub fn try_from_raw(raw: i32) -> Option<Self> {
+        Some(match raw {
+            1 => Self::KeyPressed,
+            2 => Self::KeyReleased,
+            3 => Self::KeyRepeated,
+            4 => Self::KeyTyped,
+            _ => return None,
+        })
+    }
+}
+
+#[repr(i32)]
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum PointerEventType {
+    PointerPressed = 1,
+    PointerReleased = 2,
+    PointerDragged = 3,
+}
+
+impl PointerEventType {
+    pub fn from_raw(raw: i32) -> Self {
+        unsafe { core::mem::transmute(raw) }
+    }
 }
 
 #[repr(i32)]
@@ -78,6 +109,28 @@ impl MIDPKeyCode {
         unsafe { core::mem::transmute(raw) }
     }
 
+    // keys used for keypad text input
+    pub fn to_input_key(self) -> Option<InputKey> {
+        Some(match self {
+            Self::KEY_NUM0
+            | Self::KEY_NUM1
+            | Self::KEY_NUM2
+            | Self::KEY_NUM3
+            | Self::KEY_NUM4
+            | Self::KEY_NUM5
+            | Self::KEY_NUM6
+            | Self::KEY_NUM7
+            | Self::KEY_NUM8
+            | Self::KEY_NUM9 => InputKey::Digit((self as i32 - Self::KEY_NUM0 as i32) as u8),
+            Self::KEY_STAR => InputKey::Star,
+            Self::KEY_POUND => InputKey::Pound,
+            Self::LEFT => InputKey::Left,
+            Self::RIGHT => InputKey::Right,
+            Self::CLEAR => InputKey::Clear,
+            _ => return None,
+        })
+    }
+
     fn from_key_code(keycode: KeyCode) -> Self {
         match keycode {
             KeyCode::UP => Self::UP,
@@ -158,9 +211,17 @@ impl EventQueue {
     ) -> JvmResult<()> {
         tracing::debug!("net.wie.EventQueue::getNextEvent({:?}, {:?})", &this, &event);
 
-        let mut pending_timer_events = Vec::new();
         loop {
             let now = context.system().platform().now();
+
+            // due timers are fired first, earliest one at a time
+            let due_timer = context.system().event_queue().pop_due_timer(now);
+            if let Some(callback) = due_timer {
+                callback().await.map_err(|x| JavaError::FatalError(x.to_string()))?;
+
+                continue;
+            }
+
             let maybe_event = context.system().event_queue().pop();
 
             if let Some(x) = maybe_event {
@@ -184,19 +245,23 @@ impl EventQueue {
                         MIDPKeyCode::from_key_code(x) as _,
                         0,
                     ],
-                    Event::Timer { due, callback } => {
-                        // TODO we should wait for timer more efficiently
-                        if due < now {
-                            callback().await.map_err(|x| JavaError::FatalError(x.to_string()))?
-                        } else {
-                            // push it to event queue again
-                            pending_timer_events.push(Event::Timer { due, callback });
+                    Event::Text(text) => {
+                        // typed text goes directly into the focused text field, which only needs a repaint
+                        let inserted = context.system().input_methods().focused().is_some_and(|x| x.insert_text(&text));
+                        if !inserted {
+                            continue;
                         }
 
-                        continue;
+                        vec![EventQueueEvent::RepaintEvent as _, 0, 0, 0]
                     }
+                    Event::PointerDown { x, y } => vec![EventQueueEvent::PointerEvent as _, PointerEventType::PointerPressed as _, x, y],
+                    Event::PointerUp { x, y } => vec![EventQueueEvent::PointerEvent as _, PointerEventType::PointerReleased as _, x, y],
+                    Event::PointerMove { x, y } => vec![EventQueueEvent::PointerEvent as _, PointerEventType::PointerDragged as _, x, y],
                     // wipi notifyEvent
                     Event::Notify { r#type, param1, param2 } => vec![EventQueueEvent::NotifyEvent as i32, r#type, param1, param2],
+                    Event::Suspend => vec![EventQueueEvent::SuspendEvent as _, 0, 0, 0],
+                    Event::Resume => vec![EventQueueEvent::ResumeEvent as _, 0, 0, 0],
+                    Event::Terminate => vec![EventQueueEvent::TerminateEvent as _, 0, 0, 0],
                 };
 
                 jvm.store_array(&mut event, 0, event_data).await?;
@@ -210,24 +275,26 @@ impl EventQueue {
                     let _: () = jvm.invoke_virtual(&event, "run", "()V", ()).await?;
                 }
 
-                context.system().sleep(16).await; // TODO we need to wait for events
+                // wake up early if timer is due before next poll
+                let next_timer_due = context.system().event_queue().next_timer_due();
+                let timeout = match next_timer_due {
+                    Some(due) if due > now => min(due - now, 16),
+                    Some(_) => 0,
+                    None => 16,
+                };
 
-                for event in pending_timer_events.drain(..) {
-                    context.system().event_queue().push(event);
+                if timeout > 0 {
+                    context.system().sleep(timeout).await; // TODO we need to wait for events
                 }
             }
         }
 
-        for event in pending_timer_events {
-            context.system().event_queue().push(event);
-        }
-
         Ok(())
     }
 
     async fn dispatch_event(
         jvm: &Jvm,
-        _context: &mut WieJvmContext,
+        context: &mut WieJvmContext,
         this: ClassInstanceRef<Self>,
         event: ClassInstanceRef<Array<i32>>,
     ) -> JvmResult<()> {
@@ -242,7 +309,7 @@ impl EventQueue {
                 "javax/microedition/lcdui/Display",
                 "getDisplay",
                 "(Ljavax/microedition/midlet/MIDlet;)Ljavax/microedition/lcdui/Display;",
-                (current_midlet,),
+                (current_midlet.clone(),),
             )
             .await?;
 
@@ -257,6 +324,14 @@ impl EventQueue {
 
                 let _: () = jvm.invoke_virtual(&display, "handleKeyEvent", "(II)V", (event_type as i32, code)).await?;
             }
+            EventQueueEvent::PointerEvent => {
+                let event_type = PointerEventType::from_raw(event[1]);
+                let (x, y) = (event[2], event[3]);
+
+                let _: () = jvm
+                    .invoke_virtual(&display, "handlePointerEvent", "(III)V", (event_type as i32, x, y))
+                    .await?;
+            }
             EventQueueEvent::NotifyEvent => {
                 let r#type = event[1];
                 let param1 = event[2];
@@ -266,6 +341,9 @@ impl EventQueue {
                     .invoke_virtual(&display, "handleNotifyEvent", "(III)V", (r#type, param1, param2))
                     .await?;
             }
+            EventQueueEvent::SuspendEvent => MIDlet::suspend(jvm, current_midlet).await?,
+            EventQueueEvent::ResumeEvent => MIDlet::resume(jvm, current_midlet).await?,
+            EventQueueEvent::TerminateEvent => MIDlet::terminate(jvm, context, current_midlet).await?,
         }
 
         Ok(())
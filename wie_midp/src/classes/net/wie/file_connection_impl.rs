@@ -0,0 +1,621 @@
+use alloc::{
+    collections::BTreeSet,
+    format,
+    string::{String as RustString, ToString},
+    vec,
+    vec::Vec,
+};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::{
+    io::{DataInputStream, InputStream, OutputStream},
+    lang::String,
+    util::Enumeration,
+};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+// reported capacity of the app filesystem, it's not actually limited
+const TOTAL_SIZE: i64 = 16 * 1024 * 1024;
+
+// class net.wie.FileConnectionImpl
+// `file:///path` on app filesystem. filesystem has no directories, so directories exist only if they contain files.
+pub struct FileConnectionImpl;
+
+impl FileConnectionImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/FileConnectionImpl",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["javax/microedition/io/file/FileConnection"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("isOpen", "()Z", Self::is_open, Default::default()),
+                JavaMethodProto::new("openInputStream", "()Ljava/io/InputStream;", Self::open_input_stream, Default::default()),
+                JavaMethodProto::new(
+                    "openDataInputStream",
+                    "()Ljava/io/DataInputStream;",
+                    Self::open_data_input_stream,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "openOutputStream",
+                    "()Ljava/io/OutputStream;",
+                    Self::open_output_stream,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "openOutputStream",
+                    "(J)Ljava/io/OutputStream;",
+                    Self::open_output_stream_with_offset,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "openDataOutputStream",
+                    "()Ljava/io/DataOutputStream;",
+                    Self::open_data_output_stream,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("totalSize", "()J", Self::total_size, Default::default()),
+                JavaMethodProto::new("availableSize", "()J", Self::available_size, Default::default()),
+                JavaMethodProto::new("usedSize", "()J", Self::used_size, Default::default()),
+                JavaMethodProto::new("directorySize", "(Z)J", Self::directory_size, Default::default()),
+                JavaMethodProto::new("fileSize", "()J", Self::file_size, Default::default()),
+                JavaMethodProto::new("canRead", "()Z", Self::exists, Default::default()),
+                JavaMethodProto::new("canWrite", "()Z", Self::exists, Default::default()),
+                JavaMethodProto::new("isHidden", "()Z", Self::is_hidden, Default::default()),
+                JavaMethodProto::new("setReadable", "(Z)V", Self::set_attribute, Default::default()),
+                JavaMethodProto::new("setWritable", "(Z)V", Self::set_attribute, Default::default()),
+                JavaMethodProto::new("setHidden", "(Z)V", Self::set_attribute, Default::default()),
+                JavaMethodProto::new("list", "()Ljava/util/Enumeration;", Self::list, Default::default()),
+                JavaMethodProto::new(
+                    "list",
+                    "(Ljava/lang/String;Z)Ljava/util/Enumeration;",
+                    Self::list_with_filter,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("create", "()V", Self::create, Default::default()),
+                JavaMethodProto::new("mkdir", "()V", Self::mkdir, Default::default()),
+                JavaMethodProto::new("exists", "()Z", Self::exists, Default::default()),
+                JavaMethodProto::new("isDirectory", "()Z", Self::is_directory, Default::default()),
+                JavaMethodProto::new("delete", "()V", Self::delete, Default::default()),
+                JavaMethodProto::new("rename", "(Ljava/lang/String;)V", Self::rename, Default::default()),
+                JavaMethodProto::new("truncate", "(J)V", Self::truncate, Default::default()),
+                JavaMethodProto::new(
+                    "setFileConnection",
+                    "(Ljava/lang/String;)V",
+                    Self::set_file_connection,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getName", "()Ljava/lang/String;", Self::get_name, Default::default()),
+                JavaMethodProto::new("getPath", "()Ljava/lang/String;", Self::get_path, Default::default()),
+                JavaMethodProto::new("getURL", "()Ljava/lang/String;", Self::get_url, Default::default()),
+                JavaMethodProto::new("lastModified", "()J", Self::last_modified, Default::default()),
+                JavaMethodProto::new("close", "()V", Self::close, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("url", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("closed", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, url: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::<init>({:?}, {:?})", &this, &url);
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        let url_string = JavaLangString::to_rust_string(jvm, &url).await?;
+        if parse_path(&url_string).is_none() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid url").await);
+        }
+
+        jvm.put_field(&mut this, "url", "Ljava/lang/String;", url).await?;
+
+        Ok(())
+    }
+
+    async fn is_open(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.FileConnectionImpl::isOpen({:?})", &this);
+
+        let closed: bool = jvm.get_field(&this, "closed", "Z").await?;
+
+        Ok(!closed)
+    }
+
+    async fn open_input_stream(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<InputStream>> {
+        tracing::debug!("net.wie.FileConnectionImpl::openInputStream({:?})", &this);
+
+        let path = Self::file_path(jvm, context, &this).await?;
+
+        let path = JavaLangString::from_rust_string(jvm, &path).await?;
+        let file = jvm.new_class("java/io/File", "(Ljava/lang/String;)V", (path,)).await?;
+        let input_stream = jvm.new_class("java/io/FileInputStream", "(Ljava/io/File;)V", (file,)).await?;
+
+        Ok(input_stream.into())
+    }
+
+    async fn open_data_input_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<DataInputStream>> {
+        tracing::debug!("net.wie.FileConnectionImpl::openDataInputStream({:?})", &this);
+
+        let input_stream: ClassInstanceRef<InputStream> = jvm.invoke_virtual(&this, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+        let data_input_stream = jvm
+            .new_class("java/io/DataInputStream", "(Ljava/io/InputStream;)V", (input_stream,))
+            .await?;
+
+        Ok(data_input_stream.into())
+    }
+
+    async fn open_output_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("net.wie.FileConnectionImpl::openOutputStream({:?})", &this);
+
+        jvm.invoke_virtual(&this, "openOutputStream", "(J)Ljava/io/OutputStream;", (0i64,)).await
+    }
+
+    // existing content is overwritten from the offset, without truncating
+    async fn open_output_stream_with_offset(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        offset: i64,
+    ) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("net.wie.FileConnectionImpl::openOutputStream({:?}, {})", &this, offset);
+
+        if offset < 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid offset").await);
+        }
+
+        let path = Self::file_path(jvm, context, &this).await?;
+        let size = context.system().filesystem().size(&path).unwrap_or(0) as i64;
+
+        let path = JavaLangString::from_rust_string(jvm, &path).await?;
+        let mode = JavaLangString::from_rust_string(jvm, "rw").await?;
+        let file = jvm.new_class("java/io/File", "(Ljava/lang/String;)V", (path,)).await?;
+        let raf = jvm
+            .new_class("java/io/RandomAccessFile", "(Ljava/io/File;Ljava/lang/String;)V", (file, mode))
+            .await?;
+        let _: () = jvm.invoke_virtual(&raf, "seek", "(J)V", (offset.min(size),)).await?;
+
+        let fd = jvm.invoke_virtual(&raf, "getFD", "()Ljava/io/FileDescriptor;", ()).await?;
+        let output_stream = jvm.new_class("java/io/FileOutputStream", "(Ljava/io/FileDescriptor;)V", (fd,)).await?;
+
+        Ok(output_stream.into())
+    }
+
+    async fn open_data_output_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("net.wie.FileConnectionImpl::openDataOutputStream({:?})", &this);
+
+        let output_stream: ClassInstanceRef<OutputStream> = jvm.invoke_virtual(&this, "openOutputStream", "()Ljava/io/OutputStream;", ()).await?;
+        let data_output_stream = jvm
+            .new_class("java/io/DataOutputStream", "(Ljava/io/OutputStream;)V", (output_stream,))
+            .await?;
+
+        Ok(data_output_stream.into())
+    }
+
+    async fn total_size(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.FileConnectionImpl::totalSize({:?})", &this);
+
+        Self::path(jvm, &this).await?;
+
+        Ok(TOTAL_SIZE)
+    }
+
+    async fn available_size(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.FileConnectionImpl::availableSize({:?})", &this);
+
+        let used_size: i64 = jvm.invoke_virtual(&this, "usedSize", "()J", ()).await?;
+
+        Ok((TOTAL_SIZE - used_size).max(0))
+    }
+
+    async fn used_size(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.FileConnectionImpl::usedSize({:?})", &this);
+
+        Self::path(jvm, &this).await?;
+
+        Ok(context.system().filesystem().files().map(|(_, data)| data.len() as i64).sum())
+    }
+
+    async fn directory_size(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, include_sub_directories: bool) -> JvmResult<i64> {
+        tracing::debug!("net.wie.FileConnectionImpl::directorySize({:?}, {})", &this, include_sub_directories);
+
+        let path = Self::directory_path(jvm, context, &this).await?;
+        let prefix = path.trim_start_matches('/');
+
+        let size = context
+            .system()
+            .filesystem()
+            .files()
+            .filter_map(|(name, data)| Some((name.strip_prefix(prefix)?, data)))
+            .filter(|(name, _)| include_sub_directories || !name.contains('/'))
+            .map(|(_, data)| data.len() as i64)
+            .sum();
+
+        Ok(size)
+    }
+
+    async fn file_size(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.FileConnectionImpl::fileSize({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+        if path.ends_with('/') {
+            return Err(jvm.exception("java/io/IOException", "Not a file").await);
+        }
+
+        Ok(context.system().filesystem().size(&path).map(|x| x as i64).unwrap_or(-1))
+    }
+
+    async fn is_hidden(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.FileConnectionImpl::isHidden({:?})", &this);
+
+        Self::path(jvm, &this).await?;
+
+        Ok(false)
+    }
+
+    // we don't have file attributes
+    async fn set_attribute(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, value: bool) -> JvmResult<()> {
+        tracing::warn!("stub net.wie.FileConnectionImpl::setAttribute({:?}, {})", &this, value);
+
+        Self::path(jvm, &this).await?;
+
+        Ok(())
+    }
+
+    async fn list(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Enumeration>> {
+        tracing::debug!("net.wie.FileConnectionImpl::list({:?})", &this);
+
+        let filter = JavaLangString::from_rust_string(jvm, "*").await?;
+
+        jvm.invoke_virtual(&this, "list", "(Ljava/lang/String;Z)Ljava/util/Enumeration;", (filter, false))
+            .await
+    }
+
+    // subdirectories are listed with trailing slash
+    async fn list_with_filter(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        filter: ClassInstanceRef<String>,
+        include_hidden: bool,
+    ) -> JvmResult<ClassInstanceRef<Enumeration>> {
+        tracing::debug!("net.wie.FileConnectionImpl::list({:?}, {:?}, {})", &this, &filter, include_hidden);
+
+        let filter = JavaLangString::to_rust_string(jvm, &filter).await?;
+        if filter.contains('/') {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid filter").await);
+        }
+
+        let path = Self::directory_path(jvm, context, &this).await?;
+        let prefix = path.trim_start_matches('/');
+
+        let names = context
+            .system()
+            .filesystem()
+            .files()
+            .filter_map(|(name, _)| {
+                let name = name.strip_prefix(prefix)?;
+
+                Some(match name.find('/') {
+                    Some(index) => name[..=index].to_string(),
+                    None => name.to_string(),
+                })
+            })
+            .filter(|name| matches_filter(name.trim_end_matches('/'), &filter))
+            .collect::<BTreeSet<_>>();
+
+        let mut java_names = Vec::with_capacity(names.len());
+        for name in names {
+            java_names.push(JavaLangString::from_rust_string(jvm, &name).await?);
+        }
+
+        let mut array = jvm.instantiate_array("Ljava/lang/String;", java_names.len()).await?;
+        jvm.store_array(&mut array, 0, java_names).await?;
+
+        let enumeration = jvm.new_class("net/wie/ArrayEnumeration", "([Ljava/lang/Object;)V", (array,)).await?;
+
+        Ok(enumeration.into())
+    }
+
+    async fn create(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::create({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+        if path.ends_with('/') {
+            return Err(jvm.exception("java/io/IOException", "Not a file").await);
+        }
+
+        let mut filesystem = context.system().filesystem();
+        if filesystem.exists(&path) {
+            drop(filesystem);
+
+            return Err(jvm.exception("java/io/IOException", "File already exists").await);
+        }
+        filesystem.add(&path, Vec::new());
+
+        Ok(())
+    }
+
+    // TODO empty directories can't be represented on app filesystem
+    async fn mkdir(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::warn!("stub net.wie.FileConnectionImpl::mkdir({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+        if !path.ends_with('/') {
+            return Err(jvm.exception("java/io/IOException", "Not a directory").await);
+        }
+
+        Ok(())
+    }
+
+    async fn exists(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.FileConnectionImpl::exists({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+
+        Ok(Self::path_exists(context, &path))
+    }
+
+    async fn is_directory(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.FileConnectionImpl::isDirectory({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+
+        Ok(path.ends_with('/') && Self::path_exists(context, &path))
+    }
+
+    async fn delete(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::delete({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+        if path.ends_with('/') {
+            // directory which exists is not empty
+            return Err(jvm.exception("java/io/IOException", "Directory is not empty").await);
+        }
+
+        let removed = context.system().filesystem().remove(&path);
+        if !removed {
+            return Err(jvm.exception("java/io/IOException", "File does not exist").await);
+        }
+
+        Ok(())
+    }
+
+    async fn rename(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, new_name: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::rename({:?}, {:?})", &this, &new_name);
+
+        let new_name = JavaLangString::to_rust_string(jvm, &new_name).await?;
+        if new_name.is_empty() || new_name.trim_end_matches('/').contains('/') {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid name").await);
+        }
+
+        let path = Self::path(jvm, &this).await?;
+        if path == "/" {
+            return Err(jvm.exception("java/io/IOException", "Root directory can't be renamed").await);
+        }
+
+        let (parent, _) = split_name(&path);
+        let new_path = if path.ends_with('/') {
+            format!("{parent}{}/", new_name.trim_end_matches('/'))
+        } else {
+            format!("{parent}{new_name}")
+        };
+
+        if !Self::path_exists(context, &path) {
+            return Err(jvm.exception("java/io/IOException", "File does not exist").await);
+        }
+        if Self::path_exists(context, &new_path) {
+            return Err(jvm.exception("java/io/IOException", "File already exists").await);
+        }
+
+        // directory is renamed by moving all files in it
+        let mut filesystem = context.system().filesystem();
+        let (old_prefix, new_prefix) = (path.trim_start_matches('/'), new_path.trim_start_matches('/'));
+        let files = filesystem
+            .files()
+            .filter(|(name, _)| {
+                if path.ends_with('/') {
+                    name.starts_with(old_prefix)
+                } else {
+                    *name == old_prefix
+                }
+            })
+            .map(|(name, data)| (name.to_string(), data.to_vec()))
+            .collect::<Vec<_>>();
+        for (name, data) in files {
+            filesystem.remove(&name);
+            filesystem.add(&format!("{new_prefix}{}", &name[old_prefix.len()..]), data);
+        }
+        drop(filesystem);
+
+        Self::set_path(jvm, &mut this, &new_path).await
+    }
+
+    async fn truncate(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, byte_offset: i64) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::truncate({:?}, {})", &this, byte_offset);
+
+        if byte_offset < 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid offset").await);
+        }
+
+        let path = Self::file_path(jvm, context, &this).await?;
+
+        let mut filesystem = context.system().filesystem();
+        if filesystem.size(&path).is_some_and(|x| x as i64 > byte_offset) {
+            filesystem.truncate(&path, byte_offset as _);
+        }
+
+        Ok(())
+    }
+
+    async fn set_file_connection(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::setFileConnection({:?}, {:?})", &this, &name);
+
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let path = Self::directory_path(jvm, context, &this).await?;
+
+        let new_path = if name == ".." {
+            match split_name(&path).0 {
+                "" => return Err(jvm.exception("java/lang/IllegalArgumentException", "No parent directory").await),
+                parent => parent.to_string(),
+            }
+        } else if name.is_empty() || name.trim_end_matches('/').contains('/') {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid name").await);
+        } else {
+            let file_path = format!("{path}{name}");
+            let directory_path = format!("{path}{}/", name.trim_end_matches('/'));
+
+            if !name.ends_with('/') && Self::path_exists(context, &file_path) {
+                file_path
+            } else if Self::path_exists(context, &directory_path) {
+                directory_path
+            } else {
+                return Err(jvm.exception("java/lang/IllegalArgumentException", "File does not exist").await);
+            }
+        };
+
+        Self::set_path(jvm, &mut this, &new_path).await
+    }
+
+    async fn get_name(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.FileConnectionImpl::getName({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+
+        Ok(JavaLangString::from_rust_string(jvm, split_name(&path).1).await?.into())
+    }
+
+    async fn get_path(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.FileConnectionImpl::getPath({:?})", &this);
+
+        let path = Self::path(jvm, &this).await?;
+
+        Ok(JavaLangString::from_rust_string(jvm, split_name(&path).0).await?.into())
+    }
+
+    async fn get_url(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.FileConnectionImpl::getURL({:?})", &this);
+
+        jvm.get_field(&this, "url", "Ljava/lang/String;").await
+    }
+
+    async fn last_modified(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.FileConnectionImpl::lastModified({:?})", &this);
+
+        Self::path(jvm, &this).await?;
+
+        Ok(0)
+    }
+
+    async fn close(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.FileConnectionImpl::close({:?})", &this);
+
+        jvm.put_field(&mut this, "closed", "Z", true).await?;
+
+        Ok(())
+    }
+
+    // throws IOException if the connection is closed
+    async fn path(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<RustString> {
+        let closed: bool = jvm.get_field(this, "closed", "Z").await?;
+        if closed {
+            return Err(jvm.exception("java/io/IOException", "Connection closed").await);
+        }
+
+        let url = jvm.get_field(this, "url", "Ljava/lang/String;").await?;
+        let url = JavaLangString::to_rust_string(jvm, &url).await?;
+
+        Ok(parse_path(&url).unwrap().to_string())
+    }
+
+    // path of existing file
+    async fn file_path(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<RustString> {
+        let path = Self::path(jvm, this).await?;
+        if path.ends_with('/') {
+            return Err(jvm.exception("java/io/IOException", "Not a file").await);
+        }
+        if !context.system().filesystem().exists(&path) {
+            return Err(jvm.exception("java/io/IOException", "File does not exist").await);
+        }
+
+        Ok(path)
+    }
+
+    // path of existing directory
+    async fn directory_path(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<RustString> {
+        let path = Self::path(jvm, this).await?;
+        if !path.ends_with('/') || !Self::path_exists(context, &path) {
+            return Err(jvm.exception("java/io/IOException", "Not a directory").await);
+        }
+
+        Ok(path)
+    }
+
+    async fn set_path(jvm: &Jvm, this: &mut ClassInstanceRef<Self>, path: &str) -> JvmResult<()> {
+        let url = JavaLangString::from_rust_string(jvm, &format!("file://{path}")).await?;
+        jvm.put_field(this, "url", "Ljava/lang/String;", url).await?;
+
+        Ok(())
+    }
+
+    fn path_exists(context: &mut WieJvmContext, path: &str) -> bool {
+        let filesystem = context.system().filesystem();
+
+        if path.ends_with('/') {
+            let prefix = path.trim_start_matches('/');
+
+            prefix.is_empty() || filesystem.files().any(|(name, _)| name.starts_with(prefix))
+        } else {
+            filesystem.exists(path)
+        }
+    }
+}
+
+// `file:///path` or `file://localhost/path` to `/path`
+fn parse_path(url: &str) -> Option<&str> {
+    let scheme = url.get(..7)?;
+    if !scheme.eq_ignore_ascii_case("file://") {
+        return None;
+    }
+
+    let rest = &url[7..];
+    let index = rest.find('/')?;
+    let host = &rest[..index];
+    if !host.is_empty() && !host.eq_ignore_ascii_case("localhost") {
+        return None;
+    }
+
+    Some(&rest[index..])
+}
+
+// splits path into parent directory and name, like `/a/` and `b.txt`. directory name has trailing slash.
+fn split_name(path: &str) -> (&str, &str) {
+    let trimmed = path.strip_suffix('/').unwrap_or(path);
+    let index = trimmed.rfind('/').map(|x| x + 1).unwrap_or(0);
+
+    (&path[..index], &path[index..])
+}
+
+// `*` matches any sequence of characters
+fn matches_filter(name: &str, filter: &str) -> bool {
+    match filter.split_once('*') {
+        None => name == filter,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+
+            (0..=name.len())
+                .filter(|&x| name.is_char_boundary(x))
+                .any(|x| matches_filter(&name[x..], rest))
+        }
+    }
+}
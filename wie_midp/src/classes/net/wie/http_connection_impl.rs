@@ -0,0 +1,505 @@
+use alloc::{string::String as RustString, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::{io::InputStream, lang::String};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_backend::{HttpRequest, HttpResponseHeader, SocketError};
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::{
+    javax::microedition::io::{
+        Connector,
+        connector::POLL_INTERVAL,
+        http_connection::{GET, HEAD, POST},
+    },
+    net::wie::StreamConnectionImpl,
+};
+
+const DEFAULT_PORT: i32 = 80;
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+// class net.wie.HttpConnectionImpl
+// request is sent on the first call which needs response, and request body is written before that
+pub struct HttpConnectionImpl;
+
+impl HttpConnectionImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/HttpConnectionImpl",
+            parent_class: Some("net/wie/StreamConnectionImpl"),
+            interfaces: vec!["javax/microedition/io/HttpConnection"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("getURL", "()Ljava/lang/String;", Self::get_url, Default::default()),
+                JavaMethodProto::new("getProtocol", "()Ljava/lang/String;", Self::get_protocol, Default::default()),
+                JavaMethodProto::new("getHost", "()Ljava/lang/String;", Self::get_host, Default::default()),
+                JavaMethodProto::new("getFile", "()Ljava/lang/String;", Self::get_file, Default::default()),
+                JavaMethodProto::new("getRef", "()Ljava/lang/String;", Self::get_ref, Default::default()),
+                JavaMethodProto::new("getQuery", "()Ljava/lang/String;", Self::get_query, Default::default()),
+                JavaMethodProto::new("getPort", "()I", Self::get_port, Default::default()),
+                JavaMethodProto::new("getRequestMethod", "()Ljava/lang/String;", Self::get_request_method, Default::default()),
+                JavaMethodProto::new("setRequestMethod", "(Ljava/lang/String;)V", Self::set_request_method, Default::default()),
+                JavaMethodProto::new(
+                    "getRequestProperty",
+                    "(Ljava/lang/String;)Ljava/lang/String;",
+                    Self::get_request_property,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "setRequestProperty",
+                    "(Ljava/lang/String;Ljava/lang/String;)V",
+                    Self::set_request_property,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getResponseCode", "()I", Self::get_response_code, Default::default()),
+                JavaMethodProto::new(
+                    "getResponseMessage",
+                    "()Ljava/lang/String;",
+                    Self::get_response_message,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getExpiration", "()J", Self::get_expiration, Default::default()),
+                JavaMethodProto::new("getDate", "()J", Self::get_date, Default::default()),
+                JavaMethodProto::new("getLastModified", "()J", Self::get_last_modified, Default::default()),
+                JavaMethodProto::new(
+                    "getHeaderField",
+                    "(Ljava/lang/String;)Ljava/lang/String;",
+                    Self::get_header_field,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "getHeaderFieldInt",
+                    "(Ljava/lang/String;I)I",
+                    Self::get_header_field_int,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "getHeaderFieldDate",
+                    "(Ljava/lang/String;J)J",
+                    Self::get_header_field_date,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "getHeaderField",
+                    "(I)Ljava/lang/String;",
+                    Self::get_header_field_by_index,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "getHeaderFieldKey",
+                    "(I)Ljava/lang/String;",
+                    Self::get_header_field_key,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("getType", "()Ljava/lang/String;", Self::get_type, Default::default()),
+                JavaMethodProto::new("getEncoding", "()Ljava/lang/String;", Self::get_encoding, Default::default()),
+                JavaMethodProto::new("getLength", "()J", Self::get_length, Default::default()),
+                JavaMethodProto::new("openInputStream", "()Ljava/io/InputStream;", Self::open_input_stream, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("url", "Ljava/lang/String;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, url: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.HttpConnectionImpl::<init>({:?}, {:?})", &this, &url);
+
+        let url_string = JavaLangString::to_rust_string(jvm, &url).await?;
+        let handle = context.system().sockets().open_http(&url_string);
+
+        let _: () = jvm
+            .invoke_special(&this, "net/wie/StreamConnectionImpl", "<init>", "(I)V", (handle as i32,))
+            .await?;
+
+        jvm.put_field(&mut this, "url", "Ljava/lang/String;", url).await?;
+
+        Ok(())
+    }
+
+    async fn get_url(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getURL({:?})", &this);
+
+        jvm.get_field(&this, "url", "Ljava/lang/String;").await
+    }
+
+    async fn get_protocol(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getProtocol({:?})", &this);
+
+        Ok(JavaLangString::from_rust_string(jvm, "http").await?.into())
+    }
+
+    async fn get_host(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getHost({:?})", &this);
+
+        let url = Self::url(jvm, &this).await?;
+
+        Self::to_java_string(jvm, Some(UrlParts::parse(&url).host)).await
+    }
+
+    async fn get_file(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getFile({:?})", &this);
+
+        let url = Self::url(jvm, &this).await?;
+
+        Self::to_java_string(jvm, UrlParts::parse(&url).file).await
+    }
+
+    async fn get_ref(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getRef({:?})", &this);
+
+        let url = Self::url(jvm, &this).await?;
+
+        Self::to_java_string(jvm, UrlParts::parse(&url).fragment).await
+    }
+
+    async fn get_query(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getQuery({:?})", &this);
+
+        let url = Self::url(jvm, &this).await?;
+
+        Self::to_java_string(jvm, UrlParts::parse(&url).query).await
+    }
+
+    async fn get_port(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getPort({:?})", &this);
+
+        let url = Self::url(jvm, &this).await?;
+
+        Ok(UrlParts::parse(&url).port.map(|x| x as i32).unwrap_or(DEFAULT_PORT))
+    }
+
+    async fn get_request_method(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getRequestMethod({:?})", &this);
+
+        let request = Self::request(jvm, context, &this).await?;
+
+        Self::to_java_string(jvm, Some(request.method.as_str())).await
+    }
+
+    async fn set_request_method(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        method: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.HttpConnectionImpl::setRequestMethod({:?}, {:?})", &this, &method);
+
+        let method = JavaLangString::to_rust_string(jvm, &method).await?;
+        if ![GET, POST, HEAD].contains(&method.as_str()) {
+            return Err(jvm.exception("java/io/IOException", "Invalid request method").await);
+        }
+
+        let mut request = Self::request(jvm, context, &this).await?;
+        request.method = method;
+
+        Self::set_request(jvm, context, &this, request).await
+    }
+
+    async fn get_request_property(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        key: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getRequestProperty({:?}, {:?})", &this, &key);
+
+        let key = JavaLangString::to_rust_string(jvm, &key).await?;
+        let request = Self::request(jvm, context, &this).await?;
+
+        Self::to_java_string(jvm, request.header(&key)).await
+    }
+
+    async fn set_request_property(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        key: ClassInstanceRef<String>,
+        value: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.HttpConnectionImpl::setRequestProperty({:?}, {:?}, {:?})", &this, &key, &value);
+
+        let key = JavaLangString::to_rust_string(jvm, &key).await?;
+        let value = JavaLangString::to_rust_string(jvm, &value).await?;
+
+        let mut request = Self::request(jvm, context, &this).await?;
+        request.set_header(&key, &value);
+
+        Self::set_request(jvm, context, &this, request).await
+    }
+
+    async fn get_response_code(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getResponseCode({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.code as _)
+    }
+
+    async fn get_response_message(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getResponseMessage({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Self::to_java_string(jvm, Some(response.message.as_str())).await
+    }
+
+    async fn get_expiration(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getExpiration({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.header("Expires").and_then(parse_http_date).unwrap_or(0))
+    }
+
+    async fn get_date(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getDate({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.header("Date").and_then(parse_http_date).unwrap_or(0))
+    }
+
+    async fn get_last_modified(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getLastModified({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.header("Last-Modified").and_then(parse_http_date).unwrap_or(0))
+    }
+
+    async fn get_header_field(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getHeaderField({:?}, {:?})", &this, &name);
+
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let response = Self::response(jvm, context, &this).await?;
+
+        Self::to_java_string(jvm, response.header(&name)).await
+    }
+
+    async fn get_header_field_int(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+        default: i32,
+    ) -> JvmResult<i32> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getHeaderFieldInt({:?}, {:?}, {})", &this, &name, default);
+
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.header(&name).and_then(|x| x.trim().parse().ok()).unwrap_or(default))
+    }
+
+    async fn get_header_field_date(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        name: ClassInstanceRef<String>,
+        default: i64,
+    ) -> JvmResult<i64> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getHeaderFieldDate({:?}, {:?}, {})", &this, &name, default);
+
+        let name = JavaLangString::to_rust_string(jvm, &name).await?;
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.header(&name).and_then(parse_http_date).unwrap_or(default))
+    }
+
+    async fn get_header_field_by_index(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        index: i32,
+    ) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getHeaderField({:?}, {})", &this, index);
+
+        let response = Self::response(jvm, context, &this).await?;
+        let header = usize::try_from(index).ok().and_then(|x| response.headers.get(x));
+
+        Self::to_java_string(jvm, header.map(|(_, value)| value.as_str())).await
+    }
+
+    async fn get_header_field_key(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        index: i32,
+    ) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getHeaderFieldKey({:?}, {})", &this, index);
+
+        let response = Self::response(jvm, context, &this).await?;
+        let header = usize::try_from(index).ok().and_then(|x| response.headers.get(x));
+
+        Self::to_java_string(jvm, header.map(|(key, _)| key.as_str())).await
+    }
+
+    async fn get_type(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getType({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Self::to_java_string(jvm, response.content_type()).await
+    }
+
+    async fn get_encoding(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getEncoding({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Self::to_java_string(jvm, response.content_encoding()).await
+    }
+
+    async fn get_length(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i64> {
+        tracing::debug!("net.wie.HttpConnectionImpl::getLength({:?})", &this);
+
+        let response = Self::response(jvm, context, &this).await?;
+
+        Ok(response.content_length().map(|x| x as i64).unwrap_or(-1))
+    }
+
+    async fn open_input_stream(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<InputStream>> {
+        tracing::debug!("net.wie.HttpConnectionImpl::openInputStream({:?})", &this);
+
+        Self::response(jvm, context, &this).await?;
+
+        jvm.invoke_special(&this, "net/wie/StreamConnectionImpl", "openInputStream", "()Ljava/io/InputStream;", ())
+            .await
+    }
+
+    async fn url(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<RustString> {
+        let url = jvm.get_field(this, "url", "Ljava/lang/String;").await?;
+
+        JavaLangString::to_rust_string(jvm, &url).await
+    }
+
+    async fn request(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<HttpRequest> {
+        let handle = StreamConnectionImpl::handle(jvm, this).await?;
+
+        let result = context.system().sockets().http_request(handle);
+        match result {
+            Ok(x) => Ok(x),
+            Err(err) => Err(Connector::io_exception(jvm, err).await),
+        }
+    }
+
+    async fn set_request(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>, request: HttpRequest) -> JvmResult<()> {
+        let handle = StreamConnectionImpl::handle(jvm, this).await?;
+
+        let result = context.system().sockets().set_http_request(handle, request);
+        match result {
+            Ok(()) => Ok(()),
+            Err(SocketError::AlreadyConnected) => Err(jvm.exception("java/io/IOException", "Already connected").await),
+            Err(err) => Err(Connector::io_exception(jvm, err).await),
+        }
+    }
+
+    // sends request if not sent yet, and waits for response header
+    async fn response(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<HttpResponseHeader> {
+        let handle = StreamConnectionImpl::handle(jvm, this).await?;
+        let system = context.system().clone();
+
+        let status = system.sockets().status(handle);
+        match status {
+            Ok(status) if !status.connecting && !status.connected && status.error.is_none() => {
+                let result = system.sockets().connect_http(&system, handle);
+                if let Err(err) = result {
+                    return Err(Connector::io_exception(jvm, err).await);
+                }
+            }
+            Ok(_) => {}
+            Err(err) => return Err(Connector::io_exception(jvm, err).await),
+        }
+
+        loop {
+            let response = system.sockets().http_response(handle);
+            match response {
+                Ok(Some(x)) => return Ok(x),
+                Ok(None) => {}
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            }
+
+            let error = system.sockets().status(handle).ok().and_then(|x| x.error);
+            if let Some(err) = error {
+                return Err(Connector::io_exception(jvm, err.into()).await);
+            }
+
+            system.sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn to_java_string(jvm: &Jvm, value: Option<&str>) -> JvmResult<ClassInstanceRef<String>> {
+        match value {
+            Some(x) => Ok(JavaLangString::from_rust_string(jvm, x).await?.into()),
+            None => Ok(None.into()),
+        }
+    }
+}
+
+struct UrlParts<'a> {
+    host: &'a str,
+    port: Option<u16>,
+    file: Option<&'a str>,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> UrlParts<'a> {
+    // `http://host:port/file?query#fragment`
+    fn parse(url: &'a str) -> Self {
+        let rest = url.split_once("://").map(|x| x.1).unwrap_or(url);
+        let (rest, fragment) = match rest.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (rest, None),
+        };
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+        let (authority, file) = match rest.find('/') {
+            Some(index) => (&rest[..index], Some(&rest[index..])),
+            None => (rest, None),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().ok()),
+            None => (authority, None),
+        };
+
+        Self {
+            host,
+            port,
+            file,
+            query,
+            fragment,
+        }
+    }
+}
+
+// rfc 1123 format like `Sun, 06 Nov 1994 08:49:37 GMT`, returns milliseconds since epoch
+fn parse_http_date(value: &str) -> Option<i64> {
+    let mut parts = value.split_whitespace().skip(1);
+
+    let day: i64 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|x| x.eq_ignore_ascii_case(month))? as i64 + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+
+    let mut time = parts.next()?.split(':').map(|x| x.parse::<i64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+
+    // days from civil date, https://howardhinnant.github.io/date_algorithms.html
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    Some((((days * 24 + hour) * 60 + minute) * 60 + second) * 1000)
+}
@@ -0,0 +1,362 @@
+use alloc::{vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::rms::{RecordComparator, RecordFilter, RecordStore};
+
+// class net.wie.RecordEnumerationImpl
+// `index` points to the last returned element, -1 if none is returned yet
+pub struct RecordEnumerationImpl;
+
+impl RecordEnumerationImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/RecordEnumerationImpl",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["javax/microedition/rms/RecordEnumeration", "javax/microedition/rms/RecordListener"],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Ljavax/microedition/rms/RecordStore;Ljavax/microedition/rms/RecordFilter;Ljavax/microedition/rms/RecordComparator;Z)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("numRecords", "()I", Self::num_records, Default::default()),
+                JavaMethodProto::new("nextRecord", "()[B", Self::next_record, Default::default()),
+                JavaMethodProto::new("nextRecordId", "()I", Self::next_record_id, Default::default()),
+                JavaMethodProto::new("previousRecord", "()[B", Self::previous_record, Default::default()),
+                JavaMethodProto::new("previousRecordId", "()I", Self::previous_record_id, Default::default()),
+                JavaMethodProto::new("hasNextElement", "()Z", Self::has_next_element, Default::default()),
+                JavaMethodProto::new("hasPreviousElement", "()Z", Self::has_previous_element, Default::default()),
+                JavaMethodProto::new("reset", "()V", Self::reset, Default::default()),
+                JavaMethodProto::new("rebuild", "()V", Self::rebuild, Default::default()),
+                JavaMethodProto::new("keepUpdated", "(Z)V", Self::keep_updated, Default::default()),
+                JavaMethodProto::new("isKeptUpdated", "()Z", Self::is_kept_updated, Default::default()),
+                JavaMethodProto::new("destroy", "()V", Self::destroy, Default::default()),
+                JavaMethodProto::new(
+                    "recordAdded",
+                    "(Ljavax/microedition/rms/RecordStore;I)V",
+                    Self::record_updated,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "recordChanged",
+                    "(Ljavax/microedition/rms/RecordStore;I)V",
+                    Self::record_updated,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "recordDeleted",
+                    "(Ljavax/microedition/rms/RecordStore;I)V",
+                    Self::record_updated,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("store", "Ljavax/microedition/rms/RecordStore;", Default::default()),
+                JavaFieldProto::new("filter", "Ljavax/microedition/rms/RecordFilter;", Default::default()),
+                JavaFieldProto::new("comparator", "Ljavax/microedition/rms/RecordComparator;", Default::default()),
+                JavaFieldProto::new("keepUpdated", "Z", Default::default()),
+                JavaFieldProto::new("destroyed", "Z", Default::default()),
+                JavaFieldProto::new("recordIds", "[I", Default::default()),
+                JavaFieldProto::new("index", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        store: ClassInstanceRef<RecordStore>,
+        filter: ClassInstanceRef<RecordFilter>,
+        comparator: ClassInstanceRef<RecordComparator>,
+        keep_updated: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::<init>({this:?}, {store:?}, {filter:?}, {comparator:?}, {keep_updated})");
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "store", "Ljavax/microedition/rms/RecordStore;", store).await?;
+        jvm.put_field(&mut this, "filter", "Ljavax/microedition/rms/RecordFilter;", filter)
+            .await?;
+        jvm.put_field(&mut this, "comparator", "Ljavax/microedition/rms/RecordComparator;", comparator)
+            .await?;
+
+        Self::build(jvm, context, &mut this).await?;
+        jvm.put_field(&mut this, "index", "I", -1).await?;
+
+        let _: () = jvm.invoke_virtual(&this, "keepUpdated", "(Z)V", (keep_updated,)).await?;
+
+        Ok(())
+    }
+
+    async fn num_records(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::numRecords({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        Ok(Self::record_ids(jvm, &this).await?.len() as _)
+    }
+
+    async fn next_record(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Array<i8>>> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::nextRecord({this:?})");
+
+        let id: i32 = jvm.invoke_virtual(&this, "nextRecordId", "()I", ()).await?;
+
+        Self::get_record(jvm, &this, id).await
+    }
+
+    async fn next_record_id(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::nextRecordId({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        let ids = Self::record_ids(jvm, &this).await?;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        let next = index + 1;
+        if next as usize >= ids.len() {
+            return Err(jvm.exception("javax/microedition/rms/InvalidRecordIDException", "No next record").await);
+        }
+
+        jvm.put_field(&mut this, "index", "I", next).await?;
+
+        Ok(ids[next as usize])
+    }
+
+    async fn previous_record(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Array<i8>>> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::previousRecord({this:?})");
+
+        let id: i32 = jvm.invoke_virtual(&this, "previousRecordId", "()I", ()).await?;
+
+        Self::get_record(jvm, &this, id).await
+    }
+
+    async fn previous_record_id(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::previousRecordId({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        let ids = Self::record_ids(jvm, &this).await?;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        // going backward from the start wraps to the last element
+        let previous = if index < 0 { ids.len() as i32 - 1 } else { index - 1 };
+        if previous < 0 {
+            return Err(jvm
+                .exception("javax/microedition/rms/InvalidRecordIDException", "No previous record")
+                .await);
+        }
+
+        jvm.put_field(&mut this, "index", "I", previous).await?;
+
+        Ok(ids[previous as usize])
+    }
+
+    async fn has_next_element(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::hasNextElement({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        let length = Self::record_ids(jvm, &this).await?.len() as i32;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        Ok(length > 0 && index < length - 1)
+    }
+
+    async fn has_previous_element(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::hasPreviousElement({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        let length = Self::record_ids(jvm, &this).await?.len() as i32;
+        let index: i32 = jvm.get_field(&this, "index", "I").await?;
+
+        Ok(length > 0 && index != 0)
+    }
+
+    async fn reset(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::reset({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        jvm.put_field(&mut this, "index", "I", -1).await
+    }
+
+    async fn rebuild(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::rebuild({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        Self::build(jvm, context, &mut this).await?;
+
+        jvm.put_field(&mut this, "index", "I", -1).await
+    }
+
+    async fn keep_updated(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, keep_updated: bool) -> JvmResult<()> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::keepUpdated({this:?}, {keep_updated})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        let current: bool = jvm.get_field(&this, "keepUpdated", "Z").await?;
+        if current == keep_updated {
+            return Ok(());
+        }
+
+        let store: ClassInstanceRef<RecordStore> = jvm.get_field(&this, "store", "Ljavax/microedition/rms/RecordStore;").await?;
+        if keep_updated {
+            Self::rebuild_keeping_position(jvm, context, &mut this).await?;
+
+            let _: () = jvm
+                .invoke_virtual(&store, "addRecordListener", "(Ljavax/microedition/rms/RecordListener;)V", (this.clone(),))
+                .await?;
+        } else {
+            let _: () = jvm
+                .invoke_virtual(
+                    &store,
+                    "removeRecordListener",
+                    "(Ljavax/microedition/rms/RecordListener;)V",
+                    (this.clone(),),
+                )
+                .await?;
+        }
+
+        jvm.put_field(&mut this, "keepUpdated", "Z", keep_updated).await
+    }
+
+    async fn is_kept_updated(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::isKeptUpdated({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        jvm.get_field(&this, "keepUpdated", "Z").await
+    }
+
+    async fn destroy(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::destroy({this:?})");
+
+        Self::check_destroyed(jvm, &this).await?;
+
+        let keep_updated: bool = jvm.get_field(&this, "keepUpdated", "Z").await?;
+        if keep_updated {
+            let store: ClassInstanceRef<RecordStore> = jvm.get_field(&this, "store", "Ljavax/microedition/rms/RecordStore;").await?;
+            let _: () = jvm
+                .invoke_virtual(
+                    &store,
+                    "removeRecordListener",
+                    "(Ljavax/microedition/rms/RecordListener;)V",
+                    (this.clone(),),
+                )
+                .await?;
+        }
+
+        jvm.put_field(&mut this, "destroyed", "Z", true).await
+    }
+
+    async fn record_updated(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        store: ClassInstanceRef<RecordStore>,
+        record_id: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.RecordEnumerationImpl::recordUpdated({this:?}, {store:?}, {record_id})");
+
+        Self::rebuild_keeping_position(jvm, context, &mut this).await
+    }
+
+    // wie private methods
+
+    async fn check_destroyed(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<()> {
+        let destroyed: bool = jvm.get_field(this, "destroyed", "Z").await?;
+        if destroyed {
+            return Err(jvm.exception("java/lang/IllegalStateException", "RecordEnumeration is destroyed").await);
+        }
+
+        Ok(())
+    }
+
+    async fn record_ids(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<Vec<i32>> {
+        let record_ids: ClassInstanceRef<Array<i32>> = jvm.get_field(this, "recordIds", "[I").await?;
+        let length = jvm.array_length(&record_ids).await?;
+
+        jvm.load_array(&record_ids, 0, length).await
+    }
+
+    async fn get_record(jvm: &Jvm, this: &ClassInstanceRef<Self>, record_id: i32) -> JvmResult<ClassInstanceRef<Array<i8>>> {
+        let store: ClassInstanceRef<RecordStore> = jvm.get_field(this, "store", "Ljavax/microedition/rms/RecordStore;").await?;
+
+        jvm.invoke_virtual(&store, "getRecord", "(I)[B", (record_id,)).await
+    }
+
+    // keeps the current record selected if it still exists
+    async fn rebuild_keeping_position(jvm: &Jvm, context: &mut WieJvmContext, this: &mut ClassInstanceRef<Self>) -> JvmResult<()> {
+        let old_ids = Self::record_ids(jvm, this).await?;
+        let index: i32 = jvm.get_field(this, "index", "I").await?;
+
+        let new_ids = Self::build(jvm, context, this).await?;
+
+        let new_index = if index < 0 {
+            -1
+        } else if let Some(position) = new_ids.iter().position(|&x| x == old_ids[index as usize]) {
+            position as i32
+        } else {
+            // current record is gone, so that next element is the one after it
+            (index.min(new_ids.len() as i32)) - 1
+        };
+
+        jvm.put_field(this, "index", "I", new_index).await
+    }
+
+    // filters and sorts record ids, storing them to `recordIds`
+    async fn build(jvm: &Jvm, context: &mut WieJvmContext, this: &mut ClassInstanceRef<Self>) -> JvmResult<Vec<i32>> {
+        let store: ClassInstanceRef<RecordStore> = jvm.get_field(this, "store", "Ljavax/microedition/rms/RecordStore;").await?;
+        let filter: ClassInstanceRef<RecordFilter> = jvm.get_field(this, "filter", "Ljavax/microedition/rms/RecordFilter;").await?;
+        let comparator: ClassInstanceRef<RecordComparator> = jvm.get_field(this, "comparator", "Ljavax/microedition/rms/RecordComparator;").await?;
+
+        let mut entries: Vec<(i32, ClassInstanceRef<Array<i8>>)> = Vec::new();
+        for id in RecordStore::record_ids(jvm, context, &store).await? {
+            if filter.is_null() && comparator.is_null() {
+                entries.push((id, None.into()));
+                continue;
+            }
+
+            let data: ClassInstanceRef<Array<i8>> = jvm.invoke_virtual(&store, "getRecord", "(I)[B", (id,)).await?;
+            if !filter.is_null() {
+                let matches: bool = jvm.invoke_virtual(&filter, "matches", "([B)Z", (data.clone(),)).await?;
+                if !matches {
+                    continue;
+                }
+            }
+
+            // insertion sort, as comparator is a java method
+            let mut position = entries.len();
+            if !comparator.is_null() {
+                while position > 0 {
+                    let result: i32 = jvm
+                        .invoke_virtual(&comparator, "compare", "([B[B)I", (entries[position - 1].1.clone(), data.clone()))
+                        .await?;
+                    if result <= 0 {
+                        break;
+                    }
+                    position -= 1;
+                }
+            }
+
+            entries.insert(position, (id, data));
+        }
+
+        let ids = entries.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+
+        let mut record_ids = jvm.instantiate_array("I", ids.len()).await?;
+        jvm.store_array(&mut record_ids, 0, ids.clone()).await?;
+        jvm.put_field(this, "recordIds", "[I", record_ids).await?;
+
+        Ok(ids)
+    }
+}
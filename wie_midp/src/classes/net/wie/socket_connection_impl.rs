@@ -0,0 +1,156 @@
+use alloc::{format, vec};
+use core::net::SocketAddrV4;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_backend::{SocketHandle, SocketType};
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::javax::microedition::io::{
+    Connector,
+    connector::POLL_INTERVAL,
+    socket_connection::{DELAY, SNDBUF},
+};
+
+// class net.wie.SocketConnectionImpl
+// socket options are only stored, as platform sockets don't expose them
+pub struct SocketConnectionImpl;
+
+impl SocketConnectionImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/SocketConnectionImpl",
+            parent_class: Some("net/wie/StreamConnectionImpl"),
+            interfaces: vec!["javax/microedition/io/SocketConnection"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("setSocketOption", "(BI)V", Self::set_socket_option, Default::default()),
+                JavaMethodProto::new("getSocketOption", "(B)I", Self::get_socket_option, Default::default()),
+                JavaMethodProto::new("getLocalAddress", "()Ljava/lang/String;", Self::get_local_address, Default::default()),
+                JavaMethodProto::new("getLocalPort", "()I", Self::get_local_port, Default::default()),
+                JavaMethodProto::new("getAddress", "()Ljava/lang/String;", Self::get_address, Default::default()),
+                JavaMethodProto::new("getPort", "()I", Self::get_port, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("address", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("port", "I", Default::default()),
+                JavaFieldProto::new("options", "[I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    // connects to `socket://host:port`, blocking until connected
+    async fn init(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, url: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketConnectionImpl::<init>({:?}, {:?})", &this, &url);
+
+        let url = JavaLangString::to_rust_string(jvm, &url).await?;
+        let Some((host, port)) = Connector::parse_host_port(&url) else {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid url").await);
+        };
+        if host.is_empty() {
+            return Err(jvm
+                .exception("javax/microedition/io/ConnectionNotFoundException", "Server socket is not supported")
+                .await);
+        }
+
+        let addr = Connector::resolve(jvm, context, host).await?;
+
+        let system = context.system().clone();
+        let handle = system.sockets().create(SocketType::Stream);
+        let result = system.sockets().connect(&system, handle, SocketAddrV4::new(addr, port));
+        if let Err(err) = result {
+            let _ = system.sockets().close(handle);
+
+            return Err(Connector::io_exception(jvm, err).await);
+        }
+
+        Self::wait_connected(jvm, context, handle).await?;
+
+        let _: () = jvm
+            .invoke_special(&this, "net/wie/StreamConnectionImpl", "<init>", "(I)V", (handle as i32,))
+            .await?;
+
+        let address = JavaLangString::from_rust_string(jvm, &format!("{addr}")).await?;
+        jvm.put_field(&mut this, "address", "Ljava/lang/String;", address).await?;
+        jvm.put_field(&mut this, "port", "I", port as i32).await?;
+
+        let options = jvm.instantiate_array("I", (SNDBUF - DELAY + 1) as _).await?;
+        jvm.put_field(&mut this, "options", "[I", options).await?;
+
+        Ok(())
+    }
+
+    async fn set_socket_option(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, option: i8, value: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketConnectionImpl::setSocketOption({:?}, {}, {})", &this, option, value);
+
+        if !(DELAY..=SNDBUF).contains(&option) || value < 0 {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid socket option").await);
+        }
+
+        let mut options: ClassInstanceRef<Array<i32>> = jvm.get_field(&this, "options", "[I").await?;
+        jvm.store_array(&mut options, option as _, vec![value]).await?;
+
+        Ok(())
+    }
+
+    async fn get_socket_option(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, option: i8) -> JvmResult<i32> {
+        tracing::debug!("net.wie.SocketConnectionImpl::getSocketOption({:?}, {})", &this, option);
+
+        if !(DELAY..=SNDBUF).contains(&option) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid socket option").await);
+        }
+
+        let options: ClassInstanceRef<Array<i32>> = jvm.get_field(&this, "options", "[I").await?;
+
+        Ok(jvm.load_array(&options, option as _, 1).await?[0])
+    }
+
+    // TODO platform tcp socket doesn't expose its local address
+    async fn get_local_address(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::warn!("stub net.wie.SocketConnectionImpl::getLocalAddress({:?})", &this);
+
+        Ok(JavaLangString::from_rust_string(jvm, "0.0.0.0").await?.into())
+    }
+
+    async fn get_local_port(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::warn!("stub net.wie.SocketConnectionImpl::getLocalPort({:?})", &this);
+
+        Ok(0)
+    }
+
+    async fn get_address(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("net.wie.SocketConnectionImpl::getAddress({:?})", &this);
+
+        jvm.get_field(&this, "address", "Ljava/lang/String;").await
+    }
+
+    async fn get_port(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.SocketConnectionImpl::getPort({:?})", &this);
+
+        jvm.get_field(&this, "port", "I").await
+    }
+
+    async fn wait_connected(jvm: &Jvm, context: &mut WieJvmContext, handle: SocketHandle) -> JvmResult<()> {
+        loop {
+            let status = context.system().sockets().status(handle);
+            let status = match status {
+                Ok(x) => x,
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            };
+
+            if let Some(err) = status.error {
+                let _ = context.system().sockets().close(handle);
+
+                return Err(Connector::io_exception(jvm, err.into()).await);
+            }
+            if status.connected {
+                return Ok(());
+            }
+
+            context.system().sleep(POLL_INTERVAL).await;
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use alloc::{vec, vec::Vec};
+
+use bytemuck::cast_vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::{SocketError, SocketHandle};
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::{
+    javax::microedition::io::{Connector, connector::POLL_INTERVAL},
+    net::wie::StreamConnectionImpl,
+};
+
+// class net.wie.SocketInputStream
+// reading blocks the calling thread until data arrives
+pub struct SocketInputStream;
+
+impl SocketInputStream {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/SocketInputStream",
+            parent_class: Some("java/io/InputStream"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Lnet/wie/StreamConnectionImpl;I)V", Self::init, Default::default()),
+                JavaMethodProto::new("read", "()I", Self::read_byte, Default::default()),
+                JavaMethodProto::new("read", "([BII)I", Self::read_array, Default::default()),
+                JavaMethodProto::new("available", "()I", Self::available, Default::default()),
+                JavaMethodProto::new("close", "()V", Self::close, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("connection", "Lnet/wie/StreamConnectionImpl;", Default::default()),
+                JavaFieldProto::new("handle", "I", Default::default()),
+                JavaFieldProto::new("closed", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        connection: ClassInstanceRef<StreamConnectionImpl>,
+        handle: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketInputStream::<init>({:?}, {:?}, {})", &this, &connection, handle);
+
+        let _: () = jvm.invoke_special(&this, "java/io/InputStream", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "connection", "Lnet/wie/StreamConnectionImpl;", connection)
+            .await?;
+        jvm.put_field(&mut this, "handle", "I", handle).await?;
+
+        Ok(())
+    }
+
+    async fn read_byte(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.SocketInputStream::read({:?})", &this);
+
+        let data = Self::read(jvm, context, &this, 1).await?;
+
+        Ok(data.first().map(|&x| x as i32).unwrap_or(-1))
+    }
+
+    async fn read_array(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        mut buf: ClassInstanceRef<Array<i8>>,
+        offset: i32,
+        length: i32,
+    ) -> JvmResult<i32> {
+        tracing::debug!("net.wie.SocketInputStream::read({:?}, {:?}, {}, {})", &this, &buf, offset, length);
+
+        let buf_length = jvm.array_length(&buf).await?;
+        if offset < 0 || length < 0 || (offset + length) as usize > buf_length {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "Invalid offset or length").await);
+        }
+        if length == 0 {
+            return Ok(0);
+        }
+
+        let data = Self::read(jvm, context, &this, length as _).await?;
+        if data.is_empty() {
+            return Ok(-1);
+        }
+
+        let read = data.len();
+        jvm.store_array(&mut buf, offset as _, cast_vec::<u8, i8>(data)).await?;
+
+        Ok(read as _)
+    }
+
+    async fn available(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("net.wie.SocketInputStream::available({:?})", &this);
+
+        let handle = Self::handle(jvm, &this).await?;
+
+        let status = context.system().sockets().status(handle);
+        match status {
+            Ok(status) => Ok(status.available as _),
+            Err(err) => Err(Connector::io_exception(jvm, err).await),
+        }
+    }
+
+    async fn close(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketInputStream::close({:?})", &this);
+
+        let closed: bool = jvm.get_field(&this, "closed", "Z").await?;
+        if closed {
+            return Ok(());
+        }
+        jvm.put_field(&mut this, "closed", "Z", true).await?;
+
+        let connection = jvm.get_field(&this, "connection", "Lnet/wie/StreamConnectionImpl;").await?;
+        let _: () = jvm.invoke_virtual(&connection, "streamClosed", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    // returns empty vec on end of stream
+    async fn read(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>, length: usize) -> JvmResult<Vec<u8>> {
+        let handle = Self::handle(jvm, this).await?;
+
+        let mut buf = vec![0; length];
+        loop {
+            let result = context.system().sockets().read(handle, &mut buf);
+            match result {
+                Ok(read) => {
+                    buf.truncate(read);
+
+                    return Ok(buf);
+                }
+                Err(SocketError::WouldBlock) => context.system().sleep(POLL_INTERVAL).await,
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            }
+        }
+    }
+
+    async fn handle(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<SocketHandle> {
+        let closed: bool = jvm.get_field(this, "closed", "Z").await?;
+        if closed {
+            return Err(jvm.exception("java/io/IOException", "Stream closed").await);
+        }
+
+        let handle: i32 = jvm.get_field(this, "handle", "I").await?;
+
+        Ok(handle as _)
+    }
+}
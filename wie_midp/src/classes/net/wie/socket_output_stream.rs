@@ -0,0 +1,151 @@
+use alloc::{vec, vec::Vec};
+
+use bytemuck::cast_vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::{SocketError, SocketHandle};
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::{
+    javax::microedition::io::{Connector, connector::POLL_INTERVAL},
+    net::wie::StreamConnectionImpl,
+};
+
+// class net.wie.SocketOutputStream
+// data is queued on backend socket, writing blocks only if its send buffer is full
+pub struct SocketOutputStream;
+
+impl SocketOutputStream {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/SocketOutputStream",
+            parent_class: Some("java/io/OutputStream"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Lnet/wie/StreamConnectionImpl;I)V", Self::init, Default::default()),
+                JavaMethodProto::new("write", "(I)V", Self::write_byte, Default::default()),
+                JavaMethodProto::new("write", "([BII)V", Self::write_array, Default::default()),
+                JavaMethodProto::new("flush", "()V", Self::flush, Default::default()),
+                JavaMethodProto::new("close", "()V", Self::close, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("connection", "Lnet/wie/StreamConnectionImpl;", Default::default()),
+                JavaFieldProto::new("handle", "I", Default::default()),
+                JavaFieldProto::new("closed", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        connection: ClassInstanceRef<StreamConnectionImpl>,
+        handle: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketOutputStream::<init>({:?}, {:?}, {})", &this, &connection, handle);
+
+        let _: () = jvm.invoke_special(&this, "java/io/OutputStream", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "connection", "Lnet/wie/StreamConnectionImpl;", connection)
+            .await?;
+        jvm.put_field(&mut this, "handle", "I", handle).await?;
+
+        Ok(())
+    }
+
+    async fn write_byte(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, byte: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketOutputStream::write({:?}, {})", &this, byte);
+
+        Self::write(jvm, context, &this, &[byte as u8]).await
+    }
+
+    async fn write_array(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        buf: ClassInstanceRef<Array<i8>>,
+        offset: i32,
+        length: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketOutputStream::write({:?}, {:?}, {}, {})", &this, &buf, offset, length);
+
+        let buf_length = jvm.array_length(&buf).await?;
+        if offset < 0 || length < 0 || (offset + length) as usize > buf_length {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "Invalid offset or length").await);
+        }
+
+        let data: Vec<i8> = jvm.load_array(&buf, offset as _, length as _).await?;
+
+        Self::write(jvm, context, &this, &cast_vec::<i8, u8>(data)).await
+    }
+
+    // waits until queued data is passed to the platform
+    async fn flush(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketOutputStream::flush({:?})", &this);
+
+        let handle = Self::handle(jvm, &this).await?;
+
+        loop {
+            let status = context.system().sockets().status(handle);
+            let status = match status {
+                Ok(x) => x,
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            };
+
+            if let Some(err) = status.error {
+                return Err(Connector::io_exception(jvm, err.into()).await);
+            }
+            if status.pending == 0 {
+                return Ok(());
+            }
+            context.system().sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn close(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.SocketOutputStream::close({:?})", &this);
+
+        let closed: bool = jvm.get_field(&this, "closed", "Z").await?;
+        if closed {
+            return Ok(());
+        }
+        jvm.put_field(&mut this, "closed", "Z", true).await?;
+
+        // pending data is sent even after the socket is closed
+        let connection = jvm.get_field(&this, "connection", "Lnet/wie/StreamConnectionImpl;").await?;
+        let _: () = jvm.invoke_virtual(&connection, "streamClosed", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn write(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>, mut data: &[u8]) -> JvmResult<()> {
+        let handle = Self::handle(jvm, this).await?;
+
+        while !data.is_empty() {
+            let system = context.system().clone();
+            let result = system.sockets().write(&system, handle, data);
+            match result {
+                Ok(written) => data = &data[written..],
+                Err(SocketError::WouldBlock) => context.system().sleep(POLL_INTERVAL).await,
+                Err(err) => return Err(Connector::io_exception(jvm, err).await),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<SocketHandle> {
+        let closed: bool = jvm.get_field(this, "closed", "Z").await?;
+        if closed {
+            return Err(jvm.exception("java/io/IOException", "Stream closed").await);
+        }
+
+        let handle: i32 = jvm.get_field(this, "handle", "I").await?;
+
+        Ok(handle as _)
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::io::{DataInputStream, InputStream, OutputStream};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::SocketHandle;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+// class net.wie.StreamConnectionImpl
+// base of connections over backend stream socket. socket is closed after the connection and all of its streams are closed.
+pub struct StreamConnectionImpl;
+
+impl StreamConnectionImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/StreamConnectionImpl",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec!["javax/microedition/io/StreamConnection"],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(I)V", Self::init, Default::default()),
+                JavaMethodProto::new("openInputStream", "()Ljava/io/InputStream;", Self::open_input_stream, Default::default()),
+                JavaMethodProto::new(
+                    "openDataInputStream",
+                    "()Ljava/io/DataInputStream;",
+                    Self::open_data_input_stream,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "openOutputStream",
+                    "()Ljava/io/OutputStream;",
+                    Self::open_output_stream,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "openDataOutputStream",
+                    "()Ljava/io/DataOutputStream;",
+                    Self::open_data_output_stream,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("close", "()V", Self::close, Default::default()),
+                JavaMethodProto::new("streamClosed", "()V", Self::stream_closed, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("handle", "I", Default::default()),
+                JavaFieldProto::new("closed", "Z", Default::default()),
+                JavaFieldProto::new("openStreams", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, handle: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.StreamConnectionImpl::<init>({:?}, {})", &this, handle);
+
+        let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "handle", "I", handle).await?;
+
+        Ok(())
+    }
+
+    async fn open_input_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<InputStream>> {
+        tracing::debug!("net.wie.StreamConnectionImpl::openInputStream({:?})", &this);
+
+        let handle = Self::open_stream(jvm, this.clone()).await?;
+        let input_stream = jvm
+            .new_class("net/wie/SocketInputStream", "(Lnet/wie/StreamConnectionImpl;I)V", (this, handle as i32))
+            .await?;
+
+        Ok(input_stream.into())
+    }
+
+    async fn open_data_input_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<DataInputStream>> {
+        tracing::debug!("net.wie.StreamConnectionImpl::openDataInputStream({:?})", &this);
+
+        let input_stream: ClassInstanceRef<InputStream> = jvm.invoke_virtual(&this, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+        let data_input_stream = jvm
+            .new_class("java/io/DataInputStream", "(Ljava/io/InputStream;)V", (input_stream,))
+            .await?;
+
+        Ok(data_input_stream.into())
+    }
+
+    async fn open_output_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("net.wie.StreamConnectionImpl::openOutputStream({:?})", &this);
+
+        let handle = Self::open_stream(jvm, this.clone()).await?;
+        let output_stream = jvm
+            .new_class("net/wie/SocketOutputStream", "(Lnet/wie/StreamConnectionImpl;I)V", (this, handle as i32))
+            .await?;
+
+        Ok(output_stream.into())
+    }
+
+    async fn open_data_output_stream(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("net.wie.StreamConnectionImpl::openDataOutputStream({:?})", &this);
+
+        let output_stream: ClassInstanceRef<OutputStream> = jvm.invoke_virtual(&this, "openOutputStream", "()Ljava/io/OutputStream;", ()).await?;
+        let data_output_stream = jvm
+            .new_class("java/io/DataOutputStream", "(Ljava/io/OutputStream;)V", (output_stream,))
+            .await?;
+
+        Ok(data_output_stream.into())
+    }
+
+    async fn close(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.StreamConnectionImpl::close({:?})", &this);
+
+        let closed: bool = jvm.get_field(&this, "closed", "Z").await?;
+        if closed {
+            return Ok(());
+        }
+        jvm.put_field(&mut this, "closed", "Z", true).await?;
+
+        let open_streams: i32 = jvm.get_field(&this, "openStreams", "I").await?;
+        if open_streams == 0 {
+            Self::close_socket(jvm, context, &this).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn stream_closed(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.StreamConnectionImpl::streamClosed({:?})", &this);
+
+        let open_streams: i32 = jvm.get_field(&this, "openStreams", "I").await?;
+        jvm.put_field(&mut this, "openStreams", "I", open_streams - 1).await?;
+
+        let closed: bool = jvm.get_field(&this, "closed", "Z").await?;
+        if closed && open_streams == 1 {
+            Self::close_socket(jvm, context, &this).await?;
+        }
+
+        Ok(())
+    }
+
+    // throws IOException if the connection is closed
+    pub async fn handle<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<SocketHandle> {
+        let closed: bool = jvm.get_field(this, "closed", "Z").await?;
+        if closed {
+            return Err(jvm.exception("java/io/IOException", "Connection closed").await);
+        }
+
+        let handle: i32 = jvm.get_field(this, "handle", "I").await?;
+
+        Ok(handle as _)
+    }
+
+    async fn open_stream(jvm: &Jvm, mut this: ClassInstanceRef<Self>) -> JvmResult<SocketHandle> {
+        let handle = Self::handle(jvm, &this).await?;
+
+        let open_streams: i32 = jvm.get_field(&this, "openStreams", "I").await?;
+        jvm.put_field(&mut this, "openStreams", "I", open_streams + 1).await?;
+
+        Ok(handle)
+    }
+
+    async fn close_socket(jvm: &Jvm, context: &mut WieJvmContext, this: &ClassInstanceRef<Self>) -> JvmResult<()> {
+        let handle: i32 = jvm.get_field(this, "handle", "I").await?;
+
+        // socket is already gone if connecting has failed
+        let _ = context.system().sockets().close(handle as _);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+mod selector;
+
+use alloc::{
+    borrow::ToOwned,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str;
+
+pub use self::selector::select_midlet;
+
+// attributes identifying the suite, which the jad must not change
+const IDENTITY_ATTRIBUTES: [&str; 3] = ["MIDlet-Name", "MIDlet-Version", "MIDlet-Vendor"];
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MIDletEntry {
+    pub name: String,
+    pub icon: Option<String>,
+    pub class_name: String,
+}
+
+// attributes of a midlet suite, from jar manifest and/or jad
+#[derive(Clone, Debug, Default)]
+pub struct MIDletSuite {
+    attributes: BTreeMap<String, String>,
+}
+
+impl MIDletSuite {
+    pub fn parse(data: &[u8]) -> Self {
+        let data = match str::from_utf8(data) {
+            Ok(x) => x.to_owned(),
+            Err(_) => encoding_rs::EUC_KR.decode(data).0.into_owned(),
+        };
+
+        let mut attributes = BTreeMap::new();
+        let mut last_key: Option<String> = None;
+
+        for line in data.trim_start_matches('\u{feff}').split('\n') {
+            let line = line.trim_end_matches('\r');
+
+            // manifest wraps long lines, continuing them with a leading space
+            if let Some(continuation) = line.strip_prefix(' ') {
+                if let Some(value) = last_key.as_ref().and_then(|x| attributes.get_mut(x)) {
+                    value.push_str(continuation.trim_end());
+                }
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(':') else {
+                if !line.trim().is_empty() {
+                    tracing::warn!("Invalid descriptor line: {line}");
+                }
+                last_key = None;
+                continue;
+            };
+
+            let key = key.trim().to_string();
+            attributes.insert(key.clone(), value.trim().to_string());
+            last_key = Some(key);
+        }
+
+        Self { attributes }
+    }
+
+    // jad attributes override manifest ones, except for the ones identifying the suite
+    pub fn merge(manifest: Self, jad: Self) -> Self {
+        let mut attributes = manifest.attributes;
+
+        for (key, value) in jad.attributes {
+            if IDENTITY_ATTRIBUTES.contains(&key.as_str())
+                && let Some(manifest_value) = attributes.get(&key)
+            {
+                if *manifest_value != value {
+                    tracing::warn!("Mismatching {key} in jad: {value}, manifest: {manifest_value}");
+                }
+                continue;
+            }
+
+            attributes.insert(key, value);
+        }
+
+        Self { attributes }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(|x| x.as_str())
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.get("MIDlet-Name")
+    }
+
+    pub fn attributes(&self) -> &BTreeMap<String, String> {
+        &self.attributes
+    }
+
+    pub fn into_attributes(self) -> BTreeMap<String, String> {
+        self.attributes
+    }
+
+    // MIDlet-<n> entries are numbered consecutively from 1
+    pub fn midlets(&self) -> Vec<MIDletEntry> {
+        let mut result = Vec::new();
+
+        for index in 1.. {
+            let Some(value) = self.get(&format!("MIDlet-{index}")) else {
+                break;
+            };
+
+            let mut parts = value.splitn(3, ',').map(|x| x.trim());
+            let name = parts.next().unwrap_or_default();
+            let icon = parts.next().unwrap_or_default();
+            let class_name = parts.next().unwrap_or_default();
+
+            if class_name.is_empty() {
+                tracing::warn!("Invalid MIDlet-{index}: {value}");
+                continue;
+            }
+
+            result.push(MIDletEntry {
+                name: name.to_string(),
+                icon: (!icon.is_empty()).then(|| icon.trim_start_matches('/').to_string()),
+                class_name: class_name.to_string(),
+            });
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::string::ToString;
+
+    use super::{MIDletEntry, MIDletSuite};
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = b"Manifest-Version: 1.0\r\nMIDlet-Name: Suite\r\nMIDlet-1: First, /icon1.png, com.example.Fi\r\n rst\r\nMIDlet-2: Second,, Second\r\nMIDlet-4: Skipped,, Skipped\r\n\r\n";
+        let suite = MIDletSuite::parse(manifest);
+
+        assert_eq!(suite.name(), Some("Suite"));
+        assert_eq!(
+            suite.midlets(),
+            [
+                MIDletEntry {
+                    name: "First".to_string(),
+                    icon: Some("icon1.png".to_string()),
+                    class_name: "com.example.First".to_string(),
+                },
+                MIDletEntry {
+                    name: "Second".to_string(),
+                    icon: None,
+                    class_name: "Second".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let manifest = MIDletSuite::parse(b"MIDlet-Name: Suite\nMIDlet-Version: 1.0\nMIDlet-1: A,, A\nCustom: manifest\n");
+        let jad = MIDletSuite::parse(b"MIDlet-Name: Other\nMIDlet-Jar-URL: suite.jar\nCustom: jad\n");
+        let suite = MIDletSuite::merge(manifest, jad);
+
+        assert_eq!(suite.name(), Some("Suite"));
+        assert_eq!(suite.get("MIDlet-Version"), Some("1.0"));
+        assert_eq!(suite.get("MIDlet-Jar-URL"), Some("suite.jar"));
+        assert_eq!(suite.get("Custom"), Some("jad"));
+        assert_eq!(suite.midlets().len(), 1);
+    }
+}
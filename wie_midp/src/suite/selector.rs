@@ -0,0 +1,187 @@
+use alloc::{boxed::Box, collections::BTreeMap, format, string::String, vec::Vec};
+
+use wie_backend::{
+    Event, KeyCode, System,
+    canvas::{ArgbPixel, Image, ImageBufferCanvas, TextAlignment, VecImageBuffer, decode_image},
+};
+
+use crate::classes::javax::microedition::lcdui::renderer::{
+    BACKGROUND, FOREGROUND, HIGHLIGHT, HIGHLIGHT_TEXT, LINE_HEIGHT, PADDING, Renderer, TITLE_HEIGHT,
+};
+
+use super::MIDletEntry;
+
+const MAX_ICON_SIZE: i32 = 32;
+
+// picks a midlet to launch, showing built-in menu if there's more than one and none is preselected.
+// returns index in `midlets`, or None if we're terminated while waiting
+pub async fn select_midlet(
+    system: &System,
+    title: &str,
+    midlets: &[MIDletEntry],
+    preselected: Option<usize>,
+    resources: &BTreeMap<String, Vec<u8>>,
+) -> Option<usize> {
+    if midlets.len() == 1 {
+        return Some(0);
+    }
+
+    // preselection is 1-based, as in `MIDlet-n`
+    match preselected {
+        Some(x) if (1..=midlets.len()).contains(&x) => return Some(x - 1),
+        Some(x) => tracing::warn!("Invalid midlet index {x}, suite has {} midlets", midlets.len()),
+        None => {}
+    }
+
+    let icons = midlets
+        .iter()
+        .map(|x| {
+            let data = resources.get(x.icon.as_ref()?)?;
+            if data.len() < 4 {
+                return None;
+            }
+
+            decode_image(data)
+                .inspect_err(|e| tracing::warn!("Failed to decode icon {:?}: {e}", x.icon))
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
+    let mut selected = 0;
+    render(system, title, midlets, &icons, selected);
+
+    loop {
+        let event = system.event_queue().pop();
+        let Some(event) = event else {
+            system.sleep(16).await;
+            continue;
+        };
+
+        let key = match event {
+            Event::Redraw | Event::Resume => {
+                render(system, title, midlets, &icons, selected);
+                continue;
+            }
+            Event::Terminate => return None,
+            Event::PointerUp { x, y } => {
+                if let Some(index) = Layout::new(system, &icons, selected).row_at(x, y)
+                    && index < midlets.len()
+                {
+                    return Some(index);
+                }
+                continue;
+            }
+            Event::Keydown(x) | Event::Keyrepeat(x) => x,
+            _ => continue,
+        };
+
+        let number = match key {
+            KeyCode::NUM1 => Some(0),
+            KeyCode::NUM2 => Some(1),
+            KeyCode::NUM3 => Some(2),
+            KeyCode::NUM4 => Some(3),
+            KeyCode::NUM5 => Some(4),
+            KeyCode::NUM6 => Some(5),
+            KeyCode::NUM7 => Some(6),
+            KeyCode::NUM8 => Some(7),
+            KeyCode::NUM9 => Some(8),
+            _ => None,
+        };
+
+        match key {
+            KeyCode::UP => selected = (selected + midlets.len() - 1) % midlets.len(),
+            KeyCode::DOWN => selected = (selected + 1) % midlets.len(),
+            KeyCode::OK | KeyCode::LEFT_SOFT_KEY => return Some(selected),
+            _ if number.is_some_and(|x| x < midlets.len()) => return number,
+            _ => continue,
+        }
+
+        render(system, title, midlets, &icons, selected);
+    }
+}
+
+struct Layout {
+    icon_size: i32,
+    row_height: i32,
+    first: usize,
+    visible_rows: usize,
+}
+
+impl Layout {
+    fn new(system: &System, icons: &[Option<Box<dyn Image>>], selected: usize) -> Self {
+        let screen_height = system.platform().screen().height() as i32;
+
+        let icon_size = icons
+            .iter()
+            .flatten()
+            .map(|x| x.width().max(x.height()) as i32)
+            .max()
+            .unwrap_or(0)
+            .min(MAX_ICON_SIZE);
+        let row_height = (icon_size + PADDING * 2).max(LINE_HEIGHT + PADDING * 2);
+
+        // scroll so that selected item is always visible
+        let visible_rows = ((screen_height - TITLE_HEIGHT) / row_height).max(1) as usize;
+        let first = selected.saturating_sub(visible_rows - 1);
+
+        Self {
+            icon_size,
+            row_height,
+            first,
+            visible_rows,
+        }
+    }
+
+    fn row_y(&self, index: usize) -> i32 {
+        TITLE_HEIGHT + (index - self.first) as i32 * self.row_height
+    }
+
+    fn row_at(&self, x: i32, y: i32) -> Option<usize> {
+        if x < 0 || y < TITLE_HEIGHT {
+            return None;
+        }
+
+        let row = ((y - TITLE_HEIGHT) / self.row_height) as usize;
+
+        (row < self.visible_rows).then_some(self.first + row)
+    }
+}
+
+fn render(system: &System, title: &str, midlets: &[MIDletEntry], icons: &[Option<Box<dyn Image>>], selected: usize) {
+    let screen = system.platform().screen();
+
+    let canvas = ImageBufferCanvas::new(VecImageBuffer::<ArgbPixel>::new(screen.width(), screen.height()));
+    let mut renderer = Renderer::from_canvas(Box::new(canvas));
+
+    let layout = Layout::new(system, icons, selected);
+    let (icon_size, row_height) = (layout.icon_size, layout.row_height);
+    let text_x = PADDING * 2 + if icon_size > 0 { icon_size + PADDING * 2 } else { 0 };
+
+    renderer.clear(BACKGROUND);
+    renderer.draw_bar(0, TITLE_HEIGHT, title, TextAlignment::Center);
+
+    for (index, midlet) in midlets.iter().enumerate().skip(layout.first).take(layout.visible_rows) {
+        let y = layout.row_y(index);
+
+        let text_color = if index == selected {
+            renderer.fill_rect(0, y, renderer.width(), row_height, HIGHLIGHT);
+            HIGHLIGHT_TEXT
+        } else {
+            FOREGROUND
+        };
+
+        if let Some(icon) = &icons[index] {
+            let icon_x = PADDING * 2 + (icon_size - icon.width() as i32) / 2;
+            let icon_y = y + (row_height - icon.height() as i32) / 2;
+
+            renderer.set_clip(PADDING * 2, y, icon_size, row_height);
+            renderer.draw_image(&**icon, icon_x, icon_y);
+            renderer.set_clip(0, 0, renderer.width(), renderer.height());
+        }
+
+        let label = format!("{}. {}", index + 1, midlet.name);
+        renderer.draw_text(&label, text_x, y + (row_height - LINE_HEIGHT) / 2, TextAlignment::Left, text_color);
+    }
+
+    screen.paint(renderer.image());
+}
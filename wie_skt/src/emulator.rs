@@ -2,7 +2,7 @@ use alloc::{
     borrow::ToOwned,
     boxed::Box,
     collections::BTreeMap,
-    format, str,
+    format,
     string::{String, ToString},
     vec,
     vec::Vec,
@@ -10,8 +10,9 @@ use alloc::{
 
 use jvm::{Result as JvmResult, runtime::JavaLangString};
 
-use wie_backend::{DefaultTaskRunner, Emulator, Event, Platform, System};
+use wie_backend::{DefaultTaskRunner, Emulator, Event, Options, Platform, System};
 use wie_jvm_support::{JvmSupport, RustJavaJvmImplementation};
+use wie_midp::suite::{MIDletEntry, MIDletSuite, select_midlet};
 use wie_util::{Result, WieError};
 
 pub struct SktEmulator {
@@ -19,21 +20,36 @@ pub struct SktEmulator {
 }
 
 impl SktEmulator {
-    pub fn from_archive(platform: Box<dyn Platform>, files: BTreeMap<String, Vec<u8>>) -> Result<Self> {
+    pub fn from_archive(platform: Box<dyn Platform>, files: BTreeMap<String, Vec<u8>>, options: Options) -> Result<Self> {
         let msd_file = files.iter().find(|x| x.0.ends_with(".msd")).unwrap();
         let msd = SktMsd::parse(msd_file.0, msd_file.1);
 
-        tracing::info!("Loading app {}, mclass {}", msd.id, msd.main_class);
+        tracing::info!("Loading app {}, midlets {:?}", msd.id, msd.midlets);
 
         let jar_filename = msd_file.0.replace(".msd", ".jar");
 
-        Self::load(platform, &jar_filename, &msd.id, Some(msd.main_class), msd.properties, &files)
+        Self::load(platform, &jar_filename, &msd.id, msd.midlets, msd.properties, files, options)
     }
 
-    pub fn from_jar(platform: Box<dyn Platform>, jar_filename: &str, jar: Vec<u8>, id: &str, main_class_name: Option<String>) -> Result<Self> {
+    pub fn from_jar(
+        platform: Box<dyn Platform>,
+        jar_filename: &str,
+        jar: Vec<u8>,
+        id: &str,
+        main_class_name: Option<String>,
+        options: Options,
+    ) -> Result<Self> {
         let files = [(jar_filename.to_owned(), jar)].into_iter().collect();
-
-        Self::load(platform, jar_filename, id, main_class_name, BTreeMap::new(), &files)
+        let midlets = main_class_name
+            .into_iter()
+            .map(|x| MIDletEntry {
+                name: id.to_owned(),
+                icon: None,
+                class_name: x,
+            })
+            .collect();
+
+        Self::load(platform, jar_filename, id, midlets, BTreeMap::new(), files, options)
     }
 
     pub fn loadable_archive(files: &BTreeMap<String, Vec<u8>>) -> bool {
@@ -48,31 +64,40 @@ impl SktEmulator {
         platform: Box<dyn Platform>,
         jar_filename: &str,
         id: &str,
-        main_class_name: Option<String>,
+        midlets: Vec<MIDletEntry>,
         properties: BTreeMap<String, String>,
-        files: &BTreeMap<String, Vec<u8>>,
+        files: BTreeMap<String, Vec<u8>>,
+        options: Options,
     ) -> Result<Self> {
+        if midlets.is_empty() {
+            return Err(WieError::FatalError("Main class not found".into()));
+        }
+
         let system = System::new(platform, id, id, DefaultTaskRunner);
 
-        for (filename, data) in files {
+        for (filename, data) in &files {
             system.filesystem().add(filename, data.clone())
         }
 
         let mut system_clone = system.clone();
         let jar_filename_clone = jar_filename.to_owned();
+        let id = id.to_owned();
+
+        system.spawn(async move || {
+            // icons in msd refer to files in archive
+            let Some(index) = select_midlet(&system_clone, &id, &midlets, options.midlet, &files).await else {
+                system_clone.platform().exit();
+                return Ok(());
+            };
 
-        system.spawn(async move || Self::do_start(&mut system_clone, jar_filename_clone, properties, main_class_name).await);
+            Self::do_start(&mut system_clone, jar_filename_clone, properties, &midlets[index].class_name).await
+        });
 
         Ok(Self { system })
     }
 
     #[tracing::instrument(name = "start", skip_all)]
-    async fn do_start(
-        system: &mut System,
-        jar_filename: String,
-        properties: BTreeMap<String, String>,
-        main_class_name: Option<String>,
-    ) -> Result<()> {
+    async fn do_start(system: &mut System, jar_filename: String, properties: BTreeMap<String, String>, main_class_name: &str) -> Result<()> {
         let system_properties = [
             ("MIN", "01000000000"),
             ("m.MIN", "01000000000"),
@@ -98,11 +123,7 @@ impl SktEmulator {
         ];
         let jvm = JvmSupport::new_jvm(system, Some(&jar_filename), Box::new(protos), &properties, RustJavaJvmImplementation).await?;
 
-        let main_class_name = if let Some(x) = main_class_name {
-            x.replace('.', "/")
-        } else {
-            return Err(WieError::FatalError("Main class not found".into()))?;
-        };
+        let main_class_name = main_class_name.replace('.', "/");
 
         let main_class = jvm.resolve_class(&main_class_name).await.unwrap();
         let main_class_java = JavaLangString::from_rust_string(&jvm, &main_class_name).await.unwrap();
@@ -137,39 +158,28 @@ impl Emulator for SktEmulator {
 
 struct SktMsd {
     id: String,
-    main_class: String,
+    midlets: Vec<MIDletEntry>,
     properties: BTreeMap<String, String>,
 }
 
 impl SktMsd {
     pub fn parse(filename: &str, data: &[u8]) -> Self {
-        let mut main_class = String::new();
-        let mut id = filename[..filename.find('.').unwrap()].into();
-        let mut properties = BTreeMap::new();
-
-        let mut lines = data.split(|x| *x == b'\n');
-
-        for line in &mut lines {
-            if line.starts_with(b"MIDlet-1:") {
-                let value = line[10..].split(|x| *x == b',').collect::<Vec<_>>();
-                main_class = str::from_utf8(value[2]).unwrap().trim().to_string();
-            }
-            if line.starts_with(b"DD-ProgName") {
-                id = str::from_utf8(&line[12..]).unwrap().trim().to_string();
-            }
-
-            let sep = line.iter().position(|x| *x == b':');
-            if let Some(sep) = sep {
-                let key = &line[..sep];
-                let value = &line[sep + 1..];
-
-                if let (Ok(key), Ok(value)) = (str::from_utf8(key), str::from_utf8(value)) {
-                    tracing::info!("Adding property {}={}", key.trim(), value.trim());
-                    properties.insert(key.trim().to_string(), value.trim().to_string());
-                }
-            }
+        let suite = MIDletSuite::parse(data);
+
+        let id = suite
+            .get("DD-ProgName")
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| filename[..filename.find('.').unwrap()].into());
+        let midlets = suite.midlets();
+
+        for (key, value) in suite.attributes() {
+            tracing::info!("Adding property {key}={value}");
         }
 
-        Self { id, main_class, properties }
+        Self {
+            id,
+            midlets,
+            properties: suite.into_attributes(),
+        }
     }
 }
@@ -1,11 +1,20 @@
-use alloc::vec;
+use alloc::{string::String as RustString, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_runtime::classes::java::lang::String;
-use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
 
+use wie_backend::{InputConstraint, InputKey, InputMethodHandle};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
-use wie_midp::classes::javax::microedition::lcdui::{Canvas, Graphics};
+use wie_midp::classes::{
+    javax::microedition::lcdui::{
+        Canvas, Graphics,
+        renderer::{self, Renderer},
+    },
+    net::wie::{KeyboardEventType, MIDPKeyCode},
+};
+
+const PASSWORD: i32 = 0x10000;
 
 // class com.xce.lcdui.XTextField
 pub struct XTextField;
@@ -31,14 +40,22 @@ impl XTextField {
                 JavaMethodProto::new("paint", "(Ljavax/microedition/lcdui/Graphics;)V", Self::paint, Default::default()),
                 JavaMethodProto::new("getText", "()Ljava/lang/String;", Self::get_text, Default::default()),
             ],
-            fields: vec![JavaFieldProto::new("text", "Ljava/lang/String;", Default::default())],
+            fields: vec![
+                JavaFieldProto::new("inputMethod", "I", Default::default()),
+                JavaFieldProto::new("constraints", "I", Default::default()),
+                JavaFieldProto::new("focused", "Z", Default::default()),
+                JavaFieldProto::new("x", "I", Default::default()),
+                JavaFieldProto::new("y", "I", Default::default()),
+                JavaFieldProto::new("width", "I", Default::default()),
+                JavaFieldProto::new("height", "I", Default::default()),
+            ],
             access_flags: Default::default(),
         }
     }
 
     async fn init(
         jvm: &Jvm,
-        _context: &mut WieJvmContext,
+        context: &mut WieJvmContext,
         mut this: ClassInstanceRef<Self>,
         text2: ClassInstanceRef<String>,
         max_size: i32,
@@ -57,59 +74,153 @@ impl XTextField {
         // Call the parent constructor
         let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
 
-        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text2).await?;
+        let text = if text2.is_null() {
+            RustString::new()
+        } else {
+            JavaLangString::to_rust_string(jvm, &text2).await?
+        };
+
+        let mut input_methods = context.system().input_methods();
+        let input_method = input_methods.create(InputConstraint::from_raw(constraints), max_size.max(0) as _);
+        if let Some(x) = input_methods.get(input_method) {
+            x.set_text(&text);
+        }
+        drop(input_methods);
+
+        jvm.put_field(&mut this, "inputMethod", "I", input_method as i32).await?;
+        jvm.put_field(&mut this, "constraints", "I", constraints).await?;
 
         Ok(())
     }
 
-    async fn set_focus(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, focus: bool) -> JvmResult<()> {
-        tracing::warn!("stub com.xce.lcdui.XTextField::setFocus({this:?}, {focus})");
+    async fn set_focus(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, focus: bool) -> JvmResult<()> {
+        tracing::debug!("com.xce.lcdui.XTextField::setFocus({this:?}, {focus})");
+
+        // losing focus finishes the syllable being composed
+        let input_method = Self::input_method(jvm, &this).await?;
+        if !focus && let Some(x) = context.system().input_methods().get(input_method) {
+            x.commit();
+        }
+        context.system().set_input_method_focus(input_method, focus);
+
+        jvm.put_field(&mut this, "focused", "Z", focus).await?;
+        renderer::request_redraw(context);
 
         Ok(())
     }
 
     async fn set_bounds(
-        _jvm: &Jvm,
+        jvm: &Jvm,
         _context: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+        mut this: ClassInstanceRef<Self>,
         x: i32,
         y: i32,
         width: i32,
         height: i32,
     ) -> JvmResult<()> {
-        tracing::warn!("stub com.xce.lcdui.XTextField::setBounds({this:?}, {x}, {y}, {width}, {height})");
+        tracing::debug!("com.xce.lcdui.XTextField::setBounds({this:?}, {x}, {y}, {width}, {height})");
+
+        jvm.put_field(&mut this, "x", "I", x).await?;
+        jvm.put_field(&mut this, "y", "I", y).await?;
+        jvm.put_field(&mut this, "width", "I", width).await?;
+        jvm.put_field(&mut this, "height", "I", height).await?;
 
         Ok(())
     }
 
-    async fn key_pressed(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
-        tracing::warn!("stub com.xce.lcdui.XTextField::keyPressed({this:?}, {key_code})");
+    async fn key_pressed(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
+        tracing::debug!("com.xce.lcdui.XTextField::keyPressed({this:?}, {key_code})");
 
-        Ok(())
+        Self::handle_key_event(jvm, context, &this, KeyboardEventType::KeyPressed, key_code).await
     }
 
-    async fn key_repeated(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
-        tracing::warn!("stub com.xce.lcdui.XTextField::keyRepeated({this:?}, {key_code})");
+    async fn key_repeated(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
+        tracing::debug!("com.xce.lcdui.XTextField::keyRepeated({this:?}, {key_code})");
 
-        Ok(())
+        Self::handle_key_event(jvm, context, &this, KeyboardEventType::KeyRepeated, key_code).await
     }
 
     async fn key_released(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
-        tracing::warn!("stub com.xce.lcdui.XTextField::keyReleased({this:?}, {key_code})");
+        tracing::debug!("com.xce.lcdui.XTextField::keyReleased({this:?}, {key_code})");
 
         Ok(())
     }
 
-    async fn paint(_jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
-        tracing::warn!("stub com.xce.lcdui.XTextField::paint({this:?}, {graphics:?})");
+    async fn paint(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("com.xce.lcdui.XTextField::paint({this:?}, {graphics:?})");
+
+        let x: i32 = jvm.get_field(&this, "x", "I").await?;
+        let y: i32 = jvm.get_field(&this, "y", "I").await?;
+        let width: i32 = jvm.get_field(&this, "width", "I").await?;
+        let height: i32 = jvm.get_field(&this, "height", "I").await?;
+        let constraints: i32 = jvm.get_field(&this, "constraints", "I").await?;
+        let focused: bool = jvm.get_field(&this, "focused", "Z").await?;
+        let input_method = Self::input_method(jvm, &this).await?;
+
+        // renderer draws in untranslated coordinates
+        let translate_x: i32 = jvm.invoke_virtual(&graphics, "getTranslateX", "()I", ()).await?;
+        let translate_y: i32 = jvm.invoke_virtual(&graphics, "getTranslateY", "()I", ()).await?;
+
+        let mut renderer = Renderer::new(jvm, &graphics).await?;
+        if let Some(input_method) = context.system().input_methods().get(input_method) {
+            renderer.draw_text_editor(
+                translate_x + x,
+                translate_y + y,
+                width,
+                height,
+                input_method,
+                constraints & PASSWORD != 0,
+                focused,
+            );
+        }
 
         Ok(())
     }
 
-    async fn get_text(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+    async fn get_text(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
         tracing::debug!("com.xce.lcdui.XTextField::getText({this:?})");
 
-        let text = jvm.get_field(&this, "text", "Ljava/lang/String;").await?;
-        Ok(text)
+        let input_method = Self::input_method(jvm, &this).await?;
+        let text = context.system().input_methods().get(input_method).map(|x| x.text()).unwrap_or_default();
+
+        Ok(JavaLangString::from_rust_string(jvm, &text).await?.into())
+    }
+
+    async fn handle_key_event(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: &ClassInstanceRef<Self>,
+        event_type: KeyboardEventType,
+        key_code: i32,
+    ) -> JvmResult<()> {
+        let focused: bool = jvm.get_field(this, "focused", "Z").await?;
+        if !focused {
+            return Ok(());
+        }
+
+        let Some(key) = MIDPKeyCode::from_raw(key_code).to_input_key() else {
+            return Ok(());
+        };
+
+        // holding a key repeats only editing keys, as repeated keypad keys would cycle multi-tap letters
+        if matches!(event_type, KeyboardEventType::KeyRepeated) && !matches!(key, InputKey::Left | InputKey::Right | InputKey::Clear) {
+            return Ok(());
+        }
+
+        let input_method = Self::input_method(jvm, this).await?;
+        let now = context.system().platform().now();
+        let handled = context.system().input_methods().get(input_method).is_some_and(|x| x.handle_key(key, now));
+
+        if handled {
+            renderer::request_redraw(context);
+        }
+
+        Ok(())
+    }
+
+    async fn input_method(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<InputMethodHandle> {
+        let input_method: i32 = jvm.get_field(this, "inputMethod", "I").await?;
+
+        Ok(input_method as _)
     }
 }
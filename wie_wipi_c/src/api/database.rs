@@ -1,10 +1,10 @@
-use alloc::{borrow::ToOwned, boxed::Box, str, string::String, vec};
+use alloc::{borrow::ToOwned, boxed::Box, format, str, string::String, vec, vec::Vec};
 use core::mem::size_of;
 
 use bytemuck::{Pod, Zeroable};
 
-use wie_backend::Database;
-use wie_util::{Result, read_generic, read_null_terminated_string_bytes, write_generic};
+use wie_backend::{Database, storage_available};
+use wie_util::{Result, WieError, read_generic, read_null_terminated_string_bytes, write_generic};
 
 use crate::{WIPICWord, context::WIPICContext};
 
@@ -28,6 +28,11 @@ pub async fn open_database(context: &mut dyn WIPICContext, ptr_name: WIPICWord,
         return Ok(-12); // M_E_NOENT
     }
 
+    // database is created here if it doesn't exist, which fails if repository doesn't accept the name
+    if system.platform().database_repository().open(system, &name, &pid).await.is_none() {
+        return Ok(-9); // M_E_INVALID
+    }
+
     let name_bytes = name.as_bytes();
     let mut handle = DatabaseHandle { name: [0; 32] };
 
@@ -56,7 +61,7 @@ pub async fn close_database(context: &mut dyn WIPICContext, db_id: i32) -> Resul
 pub async fn list_record(context: &mut dyn WIPICContext, db_id: i32, buf_ptr: WIPICWord, buf_len: WIPICWord) -> Result<i32> {
     tracing::debug!("MC_dbListRecords({:#x}, {:#x}, {})", db_id, buf_ptr, buf_len);
 
-    let db = get_database_from_db_id(context, db_id).await;
+    let db = get_database_from_db_id(context, db_id).await?;
     let ids = db.get_record_ids().await;
 
     let mut cursor = 0;
@@ -75,11 +80,19 @@ pub async fn write_record_single(context: &mut dyn WIPICContext, db_id: i32, buf
         return Ok(-25); // M_E_INVALIDHANDLE
     }
 
+    let mut db = get_database_from_db_id(context, db_id).await?;
+
+    let old_size = db.get_record_size(1).await.unwrap_or(0);
+    if !fits_storage(context, old_size, buf_len).await {
+        return Ok(-1); // M_E_ERROR
+    }
+
     let mut buf = vec![0; buf_len as _];
     context.read_bytes(buf_ptr, &mut buf)?;
-    let mut db = get_database_from_db_id(context, db_id).await;
 
-    db.set(1, &buf).await;
+    if !db.set(1, &buf).await {
+        return Ok(-1); // M_E_ERROR
+    }
 
     Ok(buf_len as _)
 }
@@ -87,7 +100,7 @@ pub async fn write_record_single(context: &mut dyn WIPICContext, db_id: i32, buf
 pub async fn delete_record(context: &mut dyn WIPICContext, db_id: i32, rec_id: i32) -> Result<i32> {
     tracing::debug!("MC_dbDeleteRecord({:#x}, {})", db_id, rec_id);
 
-    let mut db = get_database_from_db_id(context, db_id).await;
+    let mut db = get_database_from_db_id(context, db_id).await?;
 
     let result = db.delete(rec_id as _).await;
 
@@ -98,6 +111,118 @@ pub async fn delete_record(context: &mut dyn WIPICContext, db_id: i32, rec_id: i
     }
 }
 
+pub async fn update_record(context: &mut dyn WIPICContext, db_id: i32, rec_id: i32, buf_ptr: WIPICWord, buf_len: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_dbUpdateRecord({:#x}, {}, {:#x}, {})", db_id, rec_id, buf_ptr, buf_len);
+
+    if db_id < 0x10000 {
+        return Ok(-25); // M_E_INVALIDHANDLE
+    }
+
+    let mut db = get_database_from_db_id(context, db_id).await?;
+
+    let Some(old_size) = db.get_record_size(rec_id as _).await else {
+        return Ok(-22); // M_E_BADRECID
+    };
+    if !fits_storage(context, old_size, buf_len).await {
+        return Ok(-1); // M_E_ERROR
+    }
+
+    let mut buf = vec![0; buf_len as _];
+    context.read_bytes(buf_ptr, &mut buf)?;
+
+    if !db.set(rec_id as _, &buf).await {
+        return Ok(-1); // M_E_ERROR
+    }
+
+    Ok(0) // success
+}
+
+pub async fn sort_records(context: &mut dyn WIPICContext, db_id: i32, buf_ptr: WIPICWord, buf_len: WIPICWord, fn_compare: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_dbSortRecords({:#x}, {:#x}, {}, {:#x})", db_id, buf_ptr, buf_len, fn_compare);
+
+    if db_id < 0x10000 {
+        return Ok(-25); // M_E_INVALIDHANDLE
+    }
+
+    let db = get_database_from_db_id(context, db_id).await?;
+    let mut ids = db.get_record_ids().await;
+    ids.sort_unstable();
+
+    if ids.len() * size_of::<WIPICWord>() > buf_len as usize {
+        return Ok(-18); // M_E_SHORTBUF
+    }
+
+    // insertion sort, as comparator is a guest function
+    // TODO verify comparator signature, we pass record ids
+    for i in 1..ids.len() {
+        let mut j = i;
+        while j > 0 {
+            let result = context.call_function(fn_compare, &[ids[j - 1], ids[j]]).await? as i32;
+            if result <= 0 {
+                break;
+            }
+            ids.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+
+    for (i, &id) in ids.iter().enumerate() {
+        write_generic(context, buf_ptr + (i * size_of::<WIPICWord>()) as WIPICWord, id)?;
+    }
+
+    Ok(ids.len() as _)
+}
+
+pub async fn get_number_of_records(context: &mut dyn WIPICContext, db_id: i32) -> Result<i32> {
+    tracing::debug!("MC_dbGetNumberOfRecords({:#x})", db_id);
+
+    if db_id < 0x10000 {
+        return Ok(-25); // M_E_INVALIDHANDLE
+    }
+
+    let db = get_database_from_db_id(context, db_id).await?;
+
+    Ok(db.count().await as _)
+}
+
+pub async fn get_record_size(context: &mut dyn WIPICContext, db_id: i32, rec_id: i32) -> Result<i32> {
+    tracing::debug!("MC_dbGetRecordSize({:#x}, {})", db_id, rec_id);
+
+    if db_id < 0x10000 {
+        return Ok(-25); // M_E_INVALIDHANDLE
+    }
+
+    let db = get_database_from_db_id(context, db_id).await?;
+
+    match db.get_record_size(rec_id as _).await {
+        Some(x) => Ok(x as _),
+        None => Ok(-22), // M_E_BADRECID
+    }
+}
+
+// writes null-terminated database names consecutively, returning number of databases
+pub async fn list_databases(context: &mut dyn WIPICContext, buf_ptr: WIPICWord, buf_len: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_dbListDataBases({:#x}, {})", buf_ptr, buf_len);
+
+    let system = context.system();
+    let pid = system.pid().to_owned();
+
+    let names = system.platform().database_repository().list(system, &pid).await;
+
+    let mut buf = Vec::new();
+    for name in &names {
+        buf.extend_from_slice(name.as_bytes());
+        buf.push(0);
+    }
+
+    if buf.len() > buf_len as usize {
+        return Ok(-18); // M_E_SHORTBUF
+    }
+    context.write_bytes(buf_ptr, &buf)?;
+
+    Ok(names.len() as _)
+}
+
 pub async fn read_record_single(context: &mut dyn WIPICContext, db_id: i32, buf_ptr: WIPICWord, buf_len: WIPICWord) -> Result<i32> {
     tracing::debug!("MC_db_read_record_single({:#x}, {:#x}, {})", db_id, buf_ptr, buf_len);
 
@@ -105,7 +230,7 @@ pub async fn read_record_single(context: &mut dyn WIPICContext, db_id: i32, buf_
         return Ok(-25); // M_E_INVALIDHANDLE
     }
 
-    let db = get_database_from_db_id(context, db_id).await;
+    let db = get_database_from_db_id(context, db_id).await?;
 
     if let Some(x) = db.get(1).await {
         if buf_len < x.len() as _ {
@@ -126,7 +251,7 @@ pub async fn select_record(context: &mut dyn WIPICContext, db_id: i32, rec_id: i
         return Ok(-25); // M_E_INVALIDHANDLE
     }
 
-    let db = get_database_from_db_id(context, db_id).await;
+    let db = get_database_from_db_id(context, db_id).await?;
 
     if let Some(x) = db.get(rec_id as _).await {
         if buf_len < x.len() as _ {
@@ -146,8 +271,16 @@ pub async fn unk16(_context: &mut dyn WIPICContext) -> Result<i32> {
     Ok(1)
 }
 
-async fn get_database_from_db_id(context: &mut dyn WIPICContext, db_id: i32) -> Box<dyn Database> {
-    let handle: DatabaseHandle = read_generic(context, db_id as _).unwrap();
+// replacing a record of `old_size` bytes with `new_size` bytes must stay within the app storage quota
+async fn fits_storage(context: &mut dyn WIPICContext, old_size: usize, new_size: WIPICWord) -> bool {
+    let system = context.system();
+    let pid = system.pid().to_owned();
+
+    new_size as usize <= old_size + storage_available(system, &pid).await
+}
+
+async fn get_database_from_db_id(context: &mut dyn WIPICContext, db_id: i32) -> Result<Box<dyn Database>> {
+    let handle: DatabaseHandle = read_generic(context, db_id as _)?;
 
     let name_length = handle.name.iter().position(|&c| c == 0).unwrap_or(handle.name.len());
     let db_name = str::from_utf8(&handle.name[..name_length]).unwrap();
@@ -155,5 +288,43 @@ async fn get_database_from_db_id(context: &mut dyn WIPICContext, db_id: i32) ->
     let system = context.system();
     let pid = system.pid().to_owned();
 
-    system.platform().database_repository().open(system, db_name, &pid).await
+    system
+        .platform()
+        .database_repository()
+        .open(system, db_name, &pid)
+        .await
+        .ok_or_else(|| WieError::FatalError(format!("Failed to open database {db_name}")))
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use test_utils::TestPlatform;
+    use wie_backend::{DefaultTaskRunner, DeviceProfile, System};
+
+    use crate::context::test::TestContext;
+
+    use super::fits_storage;
+
+    #[futures_test::test]
+    async fn test_storage_quota() {
+        let platform = TestPlatform::new().with_device_profile(DeviceProfile { storage_quota: 16 });
+        let system = System::new(Box::new(platform), "", "", DefaultTaskRunner);
+
+        let mut db = system.platform().database_repository().open(&system, "test", "").await.unwrap();
+        db.add(&[0; 10]).await.unwrap();
+
+        let mut context = TestContext::with_system(system);
+
+        assert!(fits_storage(&mut context, 0, 6).await);
+        assert!(!fits_storage(&mut context, 0, 7).await);
+
+        // replaced record is not counted against the quota
+        assert!(fits_storage(&mut context, 10, 16).await);
+        assert!(!fits_storage(&mut context, 10, 17).await);
+
+        // guest length is checked before it is allocated
+        assert!(!fits_storage(&mut context, 0, u32::MAX).await);
+    }
 }
@@ -1,16 +1,17 @@
-mod framebuffer;
+pub(crate) mod framebuffer;
 mod grp_context;
 mod image;
 
-use core::mem::size_of;
+use alloc::{string::String, vec, vec::Vec};
+use core::{mem::size_of, ops::Range};
 
-use bytemuck::Zeroable;
+use bytemuck::{Zeroable, cast_slice, pod_collect_to_vec};
 
 use wie_backend::{
     Event,
-    canvas::{Clip, Color, PixelType, Rgb8Pixel},
+    canvas::{Clip, Color, PixelType, Rgb8Pixel, TextAlignment, font_ascent, font_descent, string_width},
 };
-use wie_util::{Result, read_generic, write_generic};
+use wie_util::{Result, read_generic, read_null_terminated_string_bytes, write_generic};
 
 use crate::{WIPICMemoryId, WIPICWord, context::WIPICContext};
 
@@ -22,6 +23,8 @@ use self::{
 
 const FRAMEBUFFER_DEPTH: u32 = 16; // XXX hardcode to 16bpp as some game requires 16bpp framebuffer
 const SCREEN_FRAMEBUFFER_PTR: u32 = 0x7fff1000;
+const FONT_SIZE: f32 = 10.0; // XXX canvas only supports single font size
+const DEFAULT_FONT: i32 = 1;
 
 pub async fn get_screen_framebuffer(context: &mut dyn WIPICContext, a0: WIPICWord) -> Result<WIPICMemoryId> {
     tracing::debug!("MC_grpGetScreenFrameBuffer({:#x})", a0);
@@ -100,6 +103,29 @@ pub async fn set_context(context: &mut dyn WIPICContext, p_grp_ctx: WIPICWord, o
     Ok(())
 }
 
+pub async fn get_context(context: &mut dyn WIPICContext, p_grp_ctx: WIPICWord, op: WIPICGraphicsContextIdx, pv: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_grpGetContext({:#x}, {:?}, {:#x})", p_grp_ctx, op, pv);
+
+    let grp_ctx: WIPICGraphicsContext = read_generic(context, p_grp_ctx)?;
+    match op {
+        WIPICGraphicsContextIdx::ClipIdx => write_generic(context, pv, grp_ctx.clip)?,
+        WIPICGraphicsContextIdx::FgPixelIdx => write_generic(context, pv, grp_ctx.fgpxl)?,
+        WIPICGraphicsContextIdx::BgPixelIdx => write_generic(context, pv, grp_ctx.bgpxl)?,
+        WIPICGraphicsContextIdx::TransPixelIdx => write_generic(context, pv, grp_ctx.transpxl)?,
+        WIPICGraphicsContextIdx::AlphaIdx => write_generic(context, pv, grp_ctx.alpha)?,
+        WIPICGraphicsContextIdx::PixelopIdx => write_generic(context, pv, grp_ctx.pixel_op_func_ptr)?,
+        WIPICGraphicsContextIdx::PixelParam1Idx => write_generic(context, pv, grp_ctx.param1)?,
+        WIPICGraphicsContextIdx::FontIdx => write_generic(context, pv, grp_ctx.font)?,
+        WIPICGraphicsContextIdx::StyleIdx => write_generic(context, pv, grp_ctx.style)?,
+        WIPICGraphicsContextIdx::OffsetIdx => write_generic(context, pv, grp_ctx.offset)?,
+        _ => {
+            tracing::warn!("MC_grpGetContext({:#x}, {:?}, {:#x}): ignoring invalid op", p_grp_ctx, op, pv);
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn put_pixel(context: &mut dyn WIPICContext, dst_fb: WIPICMemoryId, x: i32, y: i32, p_gctx: WIPICWord) -> Result<()> {
     tracing::debug!("MC_grpPutPixel({:#x}, {}, {}, {:?})", dst_fb.0, x, y, p_gctx);
 
@@ -120,7 +146,7 @@ pub async fn fill_rect(context: &mut dyn WIPICContext, dst_fb: WIPICMemoryId, x:
 
     let clip = Clip {
         x: x as _,
-        y: x as _,
+        y: y as _,
         width: w as _,
         height: h as _,
     };
@@ -176,7 +202,7 @@ pub async fn draw_image(
     let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(framebuffer)?)?;
     let image: WIPICImage = read_generic(context, context.data_ptr(image)?)?;
 
-    let src_image = image.img.image(context)?;
+    let src_image = image.image(context)?;
     let mut canvas = framebuffer.canvas(context)?;
 
     let clip = Clip {
@@ -374,28 +400,85 @@ pub async fn copy_frame_buffer(
     Ok(())
 }
 
+// we have single builtin font, so every font handle resolves to it
 pub async fn get_font(_: &mut dyn WIPICContext, face: i32, size: i32, style: i32) -> Result<i32> {
-    tracing::warn!("stub MC_grpGetFont({}, {}, {})", face, size, style);
+    tracing::debug!("MC_grpGetFont({}, {}, {})", face, size, style);
 
-    Ok(0)
+    Ok(DEFAULT_FONT)
 }
 
 pub async fn get_font_height(_: &mut dyn WIPICContext, font: i32) -> Result<i32> {
-    tracing::warn!("stub MC_grpGetFontHeight({})", font);
+    tracing::debug!("MC_grpGetFontHeight({})", font);
 
-    Ok(10)
+    Ok((font_ascent(FONT_SIZE) + font_descent(FONT_SIZE)).ceil() as _)
 }
 
-pub async fn get_string_width(_: &mut dyn WIPICContext, font: i32, ptr_string: WIPICWord, length: i32) -> Result<i32> {
-    tracing::warn!("stub MC_grpGetStringWidth({}, {:#x}, {})", font, ptr_string, length);
+pub async fn get_font_ascent(_: &mut dyn WIPICContext, font: i32) -> Result<i32> {
+    tracing::debug!("MC_grpGetFontAscent({})", font);
 
-    Ok(10)
+    Ok(font_ascent(FONT_SIZE).ceil() as _)
 }
 
-pub async fn draw_string(_: &mut dyn WIPICContext, dst: WIPICMemoryId, x: i32, y: i32, string: WIPICWord, length: i32, pgc: WIPICWord) -> Result<()> {
-    tracing::warn!("stub MC_grpDrawString({:#x}, {}, {}, {:#x}, {}, {:#x})", dst.0, x, y, string, length, pgc);
+pub async fn get_font_descent(_: &mut dyn WIPICContext, font: i32) -> Result<i32> {
+    tracing::debug!("MC_grpGetFontDescent({})", font);
 
-    Ok(())
+    Ok(font_descent(FONT_SIZE).ceil() as _)
+}
+
+pub async fn get_string_width(context: &mut dyn WIPICContext, font: i32, ptr_string: WIPICWord, length: i32) -> Result<i32> {
+    tracing::debug!("MC_grpGetStringWidth({}, {:#x}, {})", font, ptr_string, length);
+
+    let string = read_string(context, ptr_string, length)?;
+
+    Ok(string_width(&string, FONT_SIZE) as _)
+}
+
+pub async fn get_unicode_string_width(context: &mut dyn WIPICContext, font: i32, ptr_string: WIPICWord, length: i32) -> Result<i32> {
+    tracing::debug!("MC_grpGetUnicodeStringWidth({}, {:#x}, {})", font, ptr_string, length);
+
+    let string = read_unicode_string(context, ptr_string, length)?;
+
+    Ok(string_width(&string, FONT_SIZE) as _)
+}
+
+pub async fn draw_string(
+    context: &mut dyn WIPICContext,
+    dst: WIPICMemoryId,
+    x: i32,
+    y: i32,
+    ptr_string: WIPICWord,
+    length: i32,
+    pgc: WIPICWord,
+) -> Result<()> {
+    tracing::debug!("MC_grpDrawString({:#x}, {}, {}, {:#x}, {}, {:#x})", dst.0, x, y, ptr_string, length, pgc);
+
+    let string = read_string(context, ptr_string, length)?;
+
+    draw_text(context, dst, x, y, &string, pgc)
+}
+
+pub async fn draw_unicode_string(
+    context: &mut dyn WIPICContext,
+    dst: WIPICMemoryId,
+    x: i32,
+    y: i32,
+    ptr_string: WIPICWord,
+    length: i32,
+    pgc: WIPICWord,
+) -> Result<()> {
+    tracing::debug!(
+        "MC_grpDrawUnicodeString({:#x}, {}, {}, {:#x}, {}, {:#x})",
+        dst.0,
+        x,
+        y,
+        ptr_string,
+        length,
+        pgc
+    );
+
+    let string = read_unicode_string(context, ptr_string, length)?;
+
+    draw_text(context, dst, x, y, &string, pgc)
 }
 
 pub async fn repaint(context: &mut dyn WIPICContext, lcd: i32, x: i32, y: i32, width: i32, height: i32) -> Result<()> {
@@ -423,6 +506,65 @@ pub async fn get_image_property(context: &mut dyn WIPICContext, image: WIPICMemo
     })
 }
 
+pub async fn get_image_palette(context: &mut dyn WIPICContext, image: WIPICMemoryId, start: i32, ptr_palette: WIPICWord, count: i32) -> Result<i32> {
+    tracing::debug!("MC_grpGetImagePalette({:#x}, {}, {:#x}, {})", image.0, start, ptr_palette, count);
+
+    let image: WIPICImage = read_generic(context, context.data_ptr(image)?)?;
+    let Some(range) = palette_range(start, count).filter(|_| image.is_indexed()) else {
+        return Ok(-9); // M_E_INVALID
+    };
+
+    let palette = image.palette(context)?;
+    for (i, color) in palette[range].iter().enumerate() {
+        write_generic(context, ptr_palette + (i * size_of::<WIPICWord>()) as WIPICWord, *color)?;
+    }
+
+    Ok(count)
+}
+
+pub async fn set_image_palette(context: &mut dyn WIPICContext, image: WIPICMemoryId, start: i32, ptr_palette: WIPICWord, count: i32) -> Result<i32> {
+    tracing::debug!("MC_grpSetImagePalette({:#x}, {}, {:#x}, {})", image.0, start, ptr_palette, count);
+
+    let ptr_image = context.data_ptr(image)?;
+    let mut image: WIPICImage = read_generic(context, ptr_image)?;
+    let Some(range) = palette_range(start, count).filter(|_| image.is_indexed()) else {
+        return Ok(-9); // M_E_INVALID
+    };
+
+    let colors = (0..range.len())
+        .map(|i| read_generic(context, ptr_palette + (i * size_of::<WIPICWord>()) as WIPICWord))
+        .collect::<Result<Vec<WIPICWord>>>()?;
+    image.set_palette(context, range.start as _, &colors)?;
+    write_generic(context, ptr_image, image)?;
+
+    Ok(0) // M_E_SUCCESS
+}
+
+// palette has 256 entries. None if guest supplied range is out of it
+fn palette_range(start: i32, count: i32) -> Option<Range<usize>> {
+    if start < 0 || count < 0 {
+        return None;
+    }
+    let end = start.checked_add(count).filter(|&x| x <= 256)?;
+
+    Some(start as usize..end as usize)
+}
+
+pub async fn set_image_transparent_index(context: &mut dyn WIPICContext, image: WIPICMemoryId, index: i32) -> Result<i32> {
+    tracing::debug!("MC_grpSetImageTransparentIndex({:#x}, {})", image.0, index);
+
+    let ptr_image = context.data_ptr(image)?;
+    let mut image: WIPICImage = read_generic(context, ptr_image)?;
+    if !image.is_indexed() {
+        return Ok(-9); // M_E_INVALID
+    }
+
+    image.set_transparent_index(index as _); // negative index disables transparency
+    write_generic(context, ptr_image, image)?;
+
+    Ok(0) // M_E_SUCCESS
+}
+
 pub async fn draw_rect(context: &mut dyn WIPICContext, dst: WIPICMemoryId, x: i32, y: i32, w: i32, h: i32, pgc: WIPICWord) -> Result<()> {
     tracing::debug!("MC_grpDrawRect({:#x}, {x}, {y}, {w}, {h}, {pgc:#x})", dst.0);
 
@@ -492,3 +634,468 @@ pub async fn get_framebuffer_bpp(context: &mut dyn WIPICContext, framebuffer: WI
 
     Ok(framebuffer.bpp as _)
 }
+
+pub async fn get_image_framebuffer(context: &mut dyn WIPICContext, image: WIPICMemoryId) -> Result<WIPICMemoryId> {
+    tracing::debug!("MC_grpGetImageFrameBuffer({:#x})", image.0);
+
+    let ptr_image = context.data_ptr(image)?;
+    let mut image: WIPICImage = read_generic(context, ptr_image)?;
+    if image.framebuffer.0 == 0 {
+        image.framebuffer = context.alloc(size_of::<WIPICFramebuffer>() as WIPICWord)?;
+    }
+    image.update_img(context)?;
+    write_generic(context, ptr_image, image)?;
+
+    // img changes on animated image, so we have to update framebuffer every time
+    write_generic(context, context.data_ptr(image.framebuffer)?, image.img)?;
+
+    Ok(image.framebuffer)
+}
+
+pub async fn destroy_image(context: &mut dyn WIPICContext, image: WIPICMemoryId) -> Result<()> {
+    tracing::debug!("MC_grpDestroyImage({:#x})", image.0);
+
+    let data: WIPICImage = read_generic(context, context.data_ptr(image)?)?;
+    data.destroy(context)?;
+
+    context.free(image)
+}
+
+pub async fn decode_next_image(context: &mut dyn WIPICContext, image: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("MC_grpDecodeNextImage({:#x})", image.0);
+
+    let ptr_image = context.data_ptr(image)?;
+    let mut image: WIPICImage = read_generic(context, ptr_image)?;
+    if !image.is_animated() {
+        return Ok(-9); // M_E_INVALID
+    }
+
+    image.next_frame(context)?;
+    write_generic(context, ptr_image, image)?;
+
+    Ok(0) // M_E_SUCCESS
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn draw_arc(
+    context: &mut dyn WIPICContext,
+    dst: WIPICMemoryId,
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    start_angle: i32,
+    arc_angle: i32,
+    pgc: WIPICWord,
+) -> Result<()> {
+    tracing::debug!("MC_grpDrawArc({:#x}, {x}, {y}, {w}, {h}, {start_angle}, {arc_angle}, {pgc:#x})", dst.0);
+
+    if w < 0 || h < 0 {
+        return Ok(());
+    }
+
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(dst)?)?;
+    let gctx: WIPICGraphicsContext = read_generic(context, pgc)?;
+    let clip = framebuffer_clip(&framebuffer);
+    let mut canvas = framebuffer.canvas(context)?;
+
+    canvas.draw_arc(
+        x,
+        y,
+        w as _,
+        h as _,
+        start_angle as _,
+        arc_angle as _,
+        Rgb8Pixel::to_color(gctx.fgpxl),
+        clip,
+    );
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn fill_arc(
+    context: &mut dyn WIPICContext,
+    dst: WIPICMemoryId,
+    x: i32,
+    y: i32,
+    w: i32,
+    h: i32,
+    start_angle: i32,
+    arc_angle: i32,
+    pgc: WIPICWord,
+) -> Result<()> {
+    tracing::debug!("MC_grpFillArc({:#x}, {x}, {y}, {w}, {h}, {start_angle}, {arc_angle}, {pgc:#x})", dst.0);
+
+    if w < 0 || h < 0 {
+        return Ok(());
+    }
+
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(dst)?)?;
+    let gctx: WIPICGraphicsContext = read_generic(context, pgc)?;
+    let clip = framebuffer_clip(&framebuffer);
+    let mut canvas = framebuffer.canvas(context)?;
+
+    canvas.fill_arc(
+        x,
+        y,
+        w as _,
+        h as _,
+        start_angle as _,
+        arc_angle as _,
+        Rgb8Pixel::to_color(gctx.fgpxl),
+        clip,
+    );
+    Ok(())
+}
+
+pub async fn draw_polygon(
+    context: &mut dyn WIPICContext,
+    dst: WIPICMemoryId,
+    ptr_x: WIPICWord,
+    ptr_y: WIPICWord,
+    count: i32,
+    pgc: WIPICWord,
+) -> Result<()> {
+    tracing::debug!("MC_grpDrawPolygon({:#x}, {ptr_x:#x}, {ptr_y:#x}, {count}, {pgc:#x})", dst.0);
+
+    let points = read_points(context, ptr_x, ptr_y, count)?;
+    if points.is_empty() {
+        return Ok(());
+    }
+
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(dst)?)?;
+    let gctx: WIPICGraphicsContext = read_generic(context, pgc)?;
+    let mut canvas = framebuffer.canvas(context)?;
+
+    let color = Rgb8Pixel::to_color(gctx.fgpxl);
+    for (i, &(x1, y1)) in points.iter().enumerate() {
+        let (x2, y2) = points[(i + 1) % points.len()];
+        canvas.draw_line(x1, y1, x2, y2, color);
+    }
+
+    Ok(())
+}
+
+pub async fn fill_polygon(
+    context: &mut dyn WIPICContext,
+    dst: WIPICMemoryId,
+    ptr_x: WIPICWord,
+    ptr_y: WIPICWord,
+    count: i32,
+    pgc: WIPICWord,
+) -> Result<()> {
+    tracing::debug!("MC_grpFillPolygon({:#x}, {ptr_x:#x}, {ptr_y:#x}, {count}, {pgc:#x})", dst.0);
+
+    let points = read_points(context, ptr_x, ptr_y, count)?;
+    if points.len() < 3 {
+        return Ok(());
+    }
+
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(dst)?)?;
+    let gctx: WIPICGraphicsContext = read_generic(context, pgc)?;
+    let mut canvas = framebuffer.canvas(context)?;
+
+    let color = Rgb8Pixel::to_color(gctx.fgpxl);
+    let min_y = points.iter().map(|x| x.1).min().unwrap();
+    let max_y = points.iter().map(|x| x.1).max().unwrap();
+
+    // scanline fill with even-odd rule
+    for y in min_y..max_y {
+        let mut crossings = Vec::new();
+        for (i, &(x1, y1)) in points.iter().enumerate() {
+            let (x2, y2) = points[(i + 1) % points.len()];
+            if (y1 <= y && y < y2) || (y2 <= y && y < y1) {
+                crossings.push(x1 + (y - y1) * (x2 - x1) / (y2 - y1));
+            }
+        }
+        crossings.sort_unstable();
+
+        for span in crossings.chunks_exact(2) {
+            let clip = framebuffer_clip(&framebuffer);
+            canvas.fill_rect(span[0], y, (span[1] - span[0] + 1) as _, 1, color, clip);
+        }
+    }
+
+    Ok(())
+}
+
+// pixels are in the same format as MC_grpGetPixelFromRGB
+pub async fn get_rgb_pixels(context: &mut dyn WIPICContext, src: WIPICMemoryId, x: i32, y: i32, w: i32, h: i32, ptr_rgb: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_grpGetRGBPixels({:#x}, {x}, {y}, {w}, {h}, {ptr_rgb:#x})", src.0);
+
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(src)?)?;
+    if w < 0 || h < 0 || x < 0 || y < 0 || (x + w) as u32 > framebuffer.width || (y + h) as u32 > framebuffer.height {
+        return Ok(-9); // M_E_INVALID
+    }
+
+    let image = framebuffer.image(context)?;
+    let pixels = (y..y + h)
+        .flat_map(|py| (x..x + w).map(move |px| (px, py)))
+        .map(|(px, py)| Rgb8Pixel::from_color(image.get_pixel(px, py)))
+        .collect::<Vec<_>>();
+    context.write_bytes(ptr_rgb, cast_slice(&pixels))?;
+
+    Ok(0) // M_E_SUCCESS
+}
+
+pub async fn set_rgb_pixels(context: &mut dyn WIPICContext, dst: WIPICMemoryId, x: i32, y: i32, w: i32, h: i32, ptr_rgb: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_grpSetRGBPixels({:#x}, {x}, {y}, {w}, {h}, {ptr_rgb:#x})", dst.0);
+
+    if w < 0 || h < 0 {
+        return Ok(-9); // M_E_INVALID
+    }
+
+    let mut data = vec![0; (w * h) as usize * size_of::<WIPICWord>()];
+    context.read_bytes(ptr_rgb, &mut data)?;
+    let pixels: Vec<WIPICWord> = pod_collect_to_vec(&data);
+
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(dst)?)?;
+    let mut canvas = framebuffer.canvas(context)?;
+
+    for py in 0..h {
+        for px in 0..w {
+            let (dx, dy) = (x + px, y + py);
+            if dx < 0 || dy < 0 || dx as u32 >= framebuffer.width || dy as u32 >= framebuffer.height {
+                continue;
+            }
+
+            canvas.put_pixel(dx, dy, Rgb8Pixel::to_color(pixels[(py * w + px) as usize]));
+        }
+    }
+
+    Ok(0) // M_E_SUCCESS
+}
+
+fn draw_text(context: &mut dyn WIPICContext, dst: WIPICMemoryId, x: i32, y: i32, string: &str, pgc: WIPICWord) -> Result<()> {
+    let framebuffer: WIPICFramebuffer = read_generic(context, context.data_ptr(dst)?)?;
+    let gctx: WIPICGraphicsContext = read_generic(context, pgc)?;
+    let mut canvas = framebuffer.canvas(context)?;
+
+    canvas.draw_text(string, x, y, TextAlignment::Left, Rgb8Pixel::to_color(gctx.fgpxl));
+    Ok(())
+}
+
+fn framebuffer_clip(framebuffer: &WIPICFramebuffer) -> Clip {
+    Clip {
+        x: 0,
+        y: 0,
+        width: framebuffer.width,
+        height: framebuffer.height,
+    }
+}
+
+// negative length means null terminated string
+fn read_string(context: &dyn WIPICContext, ptr_string: WIPICWord, length: i32) -> Result<String> {
+    let bytes = if length < 0 {
+        read_null_terminated_string_bytes(context, ptr_string)?
+    } else {
+        let mut bytes = vec![0; length as usize];
+        context.read_bytes(ptr_string, &mut bytes)?;
+        if let Some(end) = bytes.iter().position(|&x| x == 0) {
+            bytes.truncate(end);
+        }
+
+        bytes
+    };
+
+    Ok(encoding_rs::EUC_KR.decode(&bytes).0.into_owned())
+}
+
+fn read_unicode_string(context: &dyn WIPICContext, ptr_string: WIPICWord, length: i32) -> Result<String> {
+    let mut units = Vec::new();
+    loop {
+        if length >= 0 && units.len() >= length as usize {
+            break;
+        }
+
+        let unit: u16 = read_generic(context, ptr_string + (units.len() * size_of::<u16>()) as WIPICWord)?;
+        if unit == 0 {
+            break;
+        }
+        units.push(unit);
+    }
+
+    Ok(String::from_utf16_lossy(&units))
+}
+
+fn read_points(context: &dyn WIPICContext, ptr_x: WIPICWord, ptr_y: WIPICWord, count: i32) -> Result<Vec<(i32, i32)>> {
+    (0..count.max(0) as WIPICWord)
+        .map(|i| {
+            let offset = i * size_of::<i32>() as WIPICWord;
+
+            Ok((read_generic(context, ptr_x + offset)?, read_generic(context, ptr_y + offset)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{vec, vec::Vec};
+    use core::mem::size_of;
+
+    use bytemuck::{Zeroable, cast_slice, pod_collect_to_vec};
+
+    use wie_util::{Result, read_generic, write_generic};
+
+    use crate::{WIPICContext, WIPICMemoryId, WIPICWord, context::test::TestContext};
+
+    use super::{
+        create_image, create_offscreen_framebuffer, draw_image, fill_polygon, get_image_framebuffer, get_image_palette, get_rgb_pixels,
+        grp_context::WIPICGraphicsContext, image::WIPICImage, read_unicode_string, set_image_palette, set_image_transparent_index, set_rgb_pixels,
+    };
+
+    // 2x2 4bpp bmp, index 0 is red and index 1 is green
+    fn palette_bmp() -> Vec<u8> {
+        let mut bmp = vec![0u8; 54 + 8 + 8];
+        bmp[0..2].copy_from_slice(b"BM");
+        bmp[10..14].copy_from_slice(&62u32.to_le_bytes());
+        bmp[14..18].copy_from_slice(&40u32.to_le_bytes());
+        bmp[18..22].copy_from_slice(&2u32.to_le_bytes());
+        bmp[22..26].copy_from_slice(&2u32.to_le_bytes());
+        bmp[28..30].copy_from_slice(&4u16.to_le_bytes());
+        bmp[46..50].copy_from_slice(&2u32.to_le_bytes());
+        bmp[54..62].copy_from_slice(&[0, 0, 0xff, 0, 0, 0xff, 0, 0]);
+        bmp[62] = 0x10; // bottom row is [1, 0]
+
+        bmp
+    }
+
+    #[futures_test::test]
+    async fn test_rgb_pixels() -> Result<()> {
+        let mut context = TestContext::new();
+        context.alloc_raw(4)?; // keep memory id 0 unused
+
+        let framebuffer = create_offscreen_framebuffer(&mut context, 2, 2).await?;
+
+        let pixels: [WIPICWord; 4] = [0xff0000, 0x00ff00, 0x0000ff, 0xffffff];
+        let ptr_pixels = context.alloc_raw(16)?;
+        context.write_bytes(ptr_pixels, cast_slice(&pixels))?;
+        assert_eq!(set_rgb_pixels(&mut context, framebuffer, 0, 0, 2, 2, ptr_pixels).await?, 0);
+
+        let ptr_result = context.alloc_raw(16)?;
+        assert_eq!(get_rgb_pixels(&mut context, framebuffer, 0, 0, 2, 2, ptr_result).await?, 0);
+
+        let mut data = [0; 16];
+        context.read_bytes(ptr_result, &mut data)?;
+        assert_eq!(pod_collect_to_vec::<u8, WIPICWord>(&data), pixels);
+
+        // out of framebuffer
+        assert_eq!(get_rgb_pixels(&mut context, framebuffer, 1, 1, 2, 2, ptr_result).await?, -9);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_fill_polygon() -> Result<()> {
+        let mut context = TestContext::new();
+        context.alloc_raw(4)?;
+
+        let framebuffer = create_offscreen_framebuffer(&mut context, 8, 8).await?;
+
+        let mut gctx = WIPICGraphicsContext::zeroed();
+        gctx.fgpxl = 0xffffff;
+        let pgc = context.alloc_raw(size_of::<WIPICGraphicsContext>() as _)?;
+        write_generic(&mut context, pgc, gctx)?;
+
+        // right triangle with vertical edge on the left
+        let xs: [i32; 3] = [0, 0, 6];
+        let ys: [i32; 3] = [0, 6, 6];
+        let ptr_x = context.alloc_raw(12)?;
+        let ptr_y = context.alloc_raw(12)?;
+        context.write_bytes(ptr_x, cast_slice(&xs))?;
+        context.write_bytes(ptr_y, cast_slice(&ys))?;
+        fill_polygon(&mut context, framebuffer, ptr_x, ptr_y, 3, pgc).await?;
+
+        let ptr_result = context.alloc_raw(8 * 8 * 4)?;
+        get_rgb_pixels(&mut context, framebuffer, 0, 0, 8, 8, ptr_result).await?;
+
+        let mut data = [0; 8 * 8 * 4];
+        context.read_bytes(ptr_result, &mut data)?;
+        let pixels: Vec<WIPICWord> = pod_collect_to_vec(&data);
+
+        assert_eq!(pixels[5 * 8 + 1], 0xffffff);
+        assert_eq!(pixels[8 + 5], 0);
+        assert_eq!(pixels[7 * 8], 0);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_palette_image() -> Result<()> {
+        let mut context = TestContext::new();
+        context.alloc_raw(4)?;
+
+        let bmp = palette_bmp();
+        let data = context.alloc(bmp.len() as _)?;
+        context.write_bytes(context.data_ptr(data)?, &bmp)?;
+
+        let ptr_image = context.alloc_raw(4)?;
+        create_image(&mut context, ptr_image, data, 0, bmp.len() as _).await?;
+        let image: WIPICMemoryId = read_generic(&context, ptr_image)?;
+
+        let pixel = |context: &mut TestContext, x: i32, y: i32| -> Result<(u8, u8, u8, u8)> {
+            let image: WIPICImage = read_generic(context, context.data_ptr(image)?)?;
+            let color = image.img.image(context)?.get_pixel(x, y);
+
+            Ok((color.a, color.r, color.g, color.b))
+        };
+
+        // img is in display format, not palette indices
+        let data: WIPICImage = read_generic(&context, context.data_ptr(image)?)?;
+        assert_eq!(data.img.bpp, 32);
+        assert_eq!(pixel(&mut context, 1, 0)?, (0xff, 0xff, 0, 0));
+        assert_eq!(pixel(&mut context, 0, 1)?, (0xff, 0, 0xff, 0));
+
+        // palette image has framebuffer in display format too
+        let framebuffer = get_image_framebuffer(&mut context, image).await?;
+        assert_ne!(framebuffer.0, 0);
+        let ptr_pixels = context.alloc_raw(16)?;
+        assert_eq!(get_rgb_pixels(&mut context, framebuffer, 0, 0, 2, 2, ptr_pixels).await?, 0);
+        assert_eq!(read_generic::<WIPICWord, _>(&context, ptr_pixels + 8)?, 0x00ff00);
+
+        let ptr_palette = context.alloc_raw(8)?;
+        assert_eq!(get_image_palette(&mut context, image, 0, ptr_palette, 2).await?, 2);
+        assert_eq!(read_generic::<WIPICWord, _>(&context, ptr_palette + 4)?, 0x00ff00);
+
+        write_generic(&mut context, ptr_palette, 0x0000ffu32)?;
+        assert_eq!(set_image_palette(&mut context, image, 1, ptr_palette, 1).await?, 0);
+        assert_eq!(set_image_palette(&mut context, image, 255, ptr_palette, 2).await?, -9);
+        assert_eq!(set_image_palette(&mut context, image, -1, ptr_palette, 1).await?, -9);
+        assert_eq!(set_image_palette(&mut context, image, i32::MAX, ptr_palette, i32::MAX).await?, -9);
+        assert_eq!(get_image_palette(&mut context, image, 1, ptr_palette, i32::MAX).await?, -9);
+
+        // draw resolves indices with new palette, without rendering img again
+        let dst = create_offscreen_framebuffer(&mut context, 2, 2).await?;
+        draw_image(&mut context, dst, 0, 0, 2, 2, image, 0, 0, 0).await?;
+        assert_eq!(get_rgb_pixels(&mut context, dst, 0, 0, 2, 2, ptr_pixels).await?, 0);
+        assert_eq!(read_generic::<WIPICWord, _>(&context, ptr_pixels + 8)?, 0x0000ff);
+        let data: WIPICImage = read_generic(&context, context.data_ptr(image)?)?;
+        assert_eq!(data.img_stale, 1);
+        assert_eq!(pixel(&mut context, 0, 1)?, (0xff, 0, 0xff, 0));
+
+        // img is rendered again when framebuffer is requested
+        get_image_framebuffer(&mut context, image).await?;
+        assert_eq!(pixel(&mut context, 0, 1)?, (0xff, 0, 0, 0xff));
+
+        assert_eq!(set_image_transparent_index(&mut context, image, 0).await?, 0);
+        get_image_framebuffer(&mut context, image).await?;
+        assert_eq!(pixel(&mut context, 0, 0)?.0, 0);
+        assert_eq!(pixel(&mut context, 0, 1)?, (0xff, 0, 0, 0xff));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_unicode_string() -> Result<()> {
+        let mut context = TestContext::new();
+
+        let units = "가나a\0".encode_utf16().collect::<Vec<_>>();
+        let address = context.alloc_raw((units.len() * 2) as _)?;
+        context.write_bytes(address, cast_slice(&units))?;
+
+        assert_eq!(read_unicode_string(&context, address, -1)?, "가나a");
+        assert_eq!(read_unicode_string(&context, address, 2)?, "가나");
+
+        Ok(())
+    }
+}
@@ -1,14 +1,20 @@
-use alloc::vec;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::mem::size_of;
 
-use bytemuck::{Pod, Zeroable};
+use bytemuck::{Pod, Zeroable, cast_slice, pod_collect_to_vec};
 
-use wie_backend::canvas::decode_image;
-use wie_util::Result;
+use wie_backend::canvas::{
+    Animation, ArgbPixel, Image, IndexedImage, PixelType, Rgb8Pixel, VecImageBuffer, decode_animation, decode_image, decode_indexed_image,
+};
+use wie_util::{Result, read_generic};
 
 use crate::{WIPICMemoryId, WIPICWord, context::WIPICContext};
 
 use super::WIPICFramebuffer;
 
+const PALETTE_SIZE: WIPICWord = 256;
+const NO_TRANSPARENT_INDEX: WIPICWord = 0xffffffff;
+
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct WIPICImage {
@@ -21,6 +27,25 @@ pub struct WIPICImage {
     pub offset: WIPICWord,
     pub current: WIPICWord,
     pub len: WIPICWord,
+    // not part of MC_GrpImage, wie private fields for indexed images.
+    // draws resolve 8bpp indices directly. img is kept in display format for apps reading it,
+    // and rendered again only when it's requested after palette or transparent index changes.
+    pub palette: WIPICMemoryId,
+    pub indices: WIPICMemoryId,
+    pub transparent_index: WIPICWord,
+    pub img_stale: WIPICWord,
+    // wie private fields for animated images. img points to current frame in frames.
+    pub frames: WIPICMemoryId,
+    pub frame_count: WIPICWord,
+    // framebuffer handle returned by MC_grpGetImageFrameBuffer
+    pub framebuffer: WIPICMemoryId,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct WIPICImageFrame {
+    img: WIPICFramebuffer,
+    delay: WIPICWord,
 }
 
 impl WIPICImage {
@@ -29,9 +54,37 @@ impl WIPICImage {
 
         let mut data = vec![0; len as _];
         context.read_bytes(ptr_image_data + offset, &mut data)?;
-        let image = decode_image(&data)?;
 
-        let img_framebuffer = WIPICFramebuffer::from_image(context, &*image)?;
+        if let Ok(Some(animation)) = decode_animation(&data) {
+            return Self::from_animation(context, buf, offset, len, animation);
+        }
+
+        let (img_framebuffer, palette, indices, transparent_index) = if let Ok(Some(indexed)) = decode_indexed_image(&data) {
+            let palette = context.alloc(PALETTE_SIZE * size_of::<WIPICWord>() as WIPICWord)?;
+            let words = indexed.palette().iter().map(|&x| Rgb8Pixel::from_color(x)).collect::<Vec<_>>();
+            context.write_bytes(context.data_ptr(palette)?, cast_slice(&words))?;
+
+            let indices = context.alloc(indexed.indices().len() as _)?;
+            context.write_bytes(context.data_ptr(indices)?, indexed.indices())?;
+
+            let transparent_index = indexed.transparent_index().map(|x| x as WIPICWord).unwrap_or(NO_TRANSPARENT_INDEX);
+
+            (
+                WIPICFramebuffer::from_image(context, &render_indexed(&indexed))?,
+                palette,
+                indices,
+                transparent_index,
+            )
+        } else {
+            let image = decode_image(&data)?;
+
+            (
+                WIPICFramebuffer::from_image(context, &*image)?,
+                WIPICMemoryId(0),
+                WIPICMemoryId(0),
+                NO_TRANSPARENT_INDEX,
+            )
+        };
         let mask_framebuffer = WIPICFramebuffer::empty();
 
         Ok(Self {
@@ -44,6 +97,153 @@ impl WIPICImage {
             offset,
             current: 0,
             len,
+            palette,
+            indices,
+            transparent_index,
+            img_stale: 0,
+            frames: WIPICMemoryId(0),
+            frame_count: 0,
+            framebuffer: WIPICMemoryId(0),
+        })
+    }
+
+    fn from_animation(context: &mut dyn WIPICContext, buf: WIPICMemoryId, offset: WIPICWord, len: WIPICWord, animation: Animation) -> Result<Self> {
+        let frames = animation
+            .frames
+            .iter()
+            .map(|x| {
+                Ok(WIPICImageFrame {
+                    img: WIPICFramebuffer::from_image(context, &*x.image)?,
+                    delay: x.delay,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let ptr_frames = context.alloc((frames.len() * size_of::<WIPICImageFrame>()) as WIPICWord)?;
+        context.write_bytes(context.data_ptr(ptr_frames)?, cast_slice(&frames))?;
+
+        Ok(Self {
+            img: frames[0].img,
+            mask: WIPICFramebuffer::empty(),
+            loop_count: animation.loop_count,
+            delay: frames[0].delay,
+            animated: 1,
+            buf,
+            offset,
+            current: 0,
+            len,
+            palette: WIPICMemoryId(0),
+            indices: WIPICMemoryId(0),
+            transparent_index: NO_TRANSPARENT_INDEX,
+            img_stale: 0,
+            frames: ptr_frames,
+            frame_count: frames.len() as _,
+            framebuffer: WIPICMemoryId(0),
         })
     }
+
+    pub fn is_animated(&self) -> bool {
+        self.animated != 0
+    }
+
+    // advances to next frame, wrapping around to the first frame
+    pub fn next_frame(&mut self, context: &dyn WIPICContext) -> Result<()> {
+        self.current = (self.current + 1) % self.frame_count;
+
+        let frame: WIPICImageFrame = read_generic(context, self.frame_address(context, self.current)?)?;
+        self.img = frame.img;
+        self.delay = frame.delay;
+
+        Ok(())
+    }
+
+    pub fn destroy(self, context: &mut dyn WIPICContext) -> Result<()> {
+        if self.is_animated() {
+            for i in 0..self.frame_count {
+                let frame: WIPICImageFrame = read_generic(context, self.frame_address(context, i)?)?;
+                context.free(frame.img.buf)?;
+            }
+            context.free(self.frames)?;
+        } else {
+            context.free(self.img.buf)?;
+        }
+
+        for memory in [self.mask.buf, self.palette, self.indices, self.framebuffer] {
+            if memory.0 != 0 {
+                context.free(memory)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn frame_address(&self, context: &dyn WIPICContext, index: WIPICWord) -> Result<WIPICWord> {
+        Ok(context.data_ptr(self.frames)? + index * size_of::<WIPICImageFrame>() as WIPICWord)
+    }
+
+    pub fn is_indexed(&self) -> bool {
+        self.palette.0 != 0
+    }
+
+    pub fn palette(&self, context: &dyn WIPICContext) -> Result<Vec<WIPICWord>> {
+        let mut data = vec![0; (PALETTE_SIZE as usize) * size_of::<WIPICWord>()];
+        context.read_bytes(context.data_ptr(self.palette)?, &mut data)?;
+
+        Ok(pod_collect_to_vec(&data))
+    }
+
+    pub fn set_palette(&mut self, context: &mut dyn WIPICContext, start: WIPICWord, colors: &[WIPICWord]) -> Result<()> {
+        let address = context.data_ptr(self.palette)? + start * size_of::<WIPICWord>() as WIPICWord;
+        context.write_bytes(address, cast_slice(colors))?;
+        self.img_stale = 1;
+
+        Ok(())
+    }
+
+    pub fn set_transparent_index(&mut self, index: WIPICWord) {
+        self.transparent_index = index;
+        self.img_stale = 1;
+    }
+
+    // source image for draws. indexed images are resolved with current palette at draw time
+    pub fn image(&self, context: &mut dyn WIPICContext) -> Result<Box<dyn Image>> {
+        if self.is_indexed() {
+            return Ok(Box::new(self.indexed(context)?));
+        }
+
+        self.img.image(context)
+    }
+
+    // renders img again if palette has changed since last render. draws on img are overwritten, as indices are source of the image
+    pub fn update_img(&mut self, context: &mut dyn WIPICContext) -> Result<()> {
+        if self.img_stale == 0 {
+            return Ok(());
+        }
+
+        let indexed = self.indexed(context)?;
+        self.img.write(context, &render_indexed(&indexed).raw())?;
+        self.img_stale = 0;
+
+        Ok(())
+    }
+
+    fn indexed(&self, context: &dyn WIPICContext) -> Result<IndexedImage> {
+        let mut indices = vec![0; (self.img.width * self.img.height) as usize];
+        context.read_bytes(context.data_ptr(self.indices)?, &mut indices)?;
+
+        let palette = self.palette(context)?.into_iter().map(Rgb8Pixel::to_color).collect();
+        let transparent_index = if self.transparent_index < PALETTE_SIZE {
+            Some(self.transparent_index as u8)
+        } else {
+            None
+        };
+
+        Ok(IndexedImage::new(self.img.width, self.img.height, indices, palette, transparent_index))
+    }
+}
+
+fn render_indexed(indexed: &IndexedImage) -> VecImageBuffer<ArgbPixel> {
+    let pixels = indexed.colors().into_iter().map(ArgbPixel::from_color).collect();
+
+    VecImageBuffer::from_raw(indexed.width(), indexed.height(), pixels)
 }
@@ -1,13 +1,20 @@
 use alloc::{
     boxed::Box,
-    format, str,
+    str,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
-use core::{iter, mem::size_of};
+use core::{
+    cmp::{max, min},
+    iter,
+    mem::size_of,
+};
 
 use bytemuck::{Pod, Zeroable};
 
+use wie_backend::{Instant, PrivateAreaKind, ProgramId, ProgramKind, ProgramState};
+use wie_libc::printf::sprintf;
 use wie_util::{Result, WieError, read_generic, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
 
 use crate::{WIPICMemoryId, WIPICResult, WIPICWord, context::WIPICContext, method::MethodBody};
@@ -18,6 +25,17 @@ pub struct WIPICTimer {
     fn_callback: WIPICWord,
 }
 
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct WIPICProgramInfo {
+    id: WIPICWord,
+    parent_id: WIPICWord,
+    kind: WIPICWord,  // 0: application, 1: library
+    state: WIPICWord, // 0: idle, 1: running, 2: paused, 3: stopped
+    access_level: WIPICWord,
+    name: [u8; 32],
+}
+
 #[derive(Pod, Zeroable, Copy, Clone)]
 #[repr(C)]
 struct ResourceHandle {
@@ -52,51 +70,108 @@ pub async fn def_timer(context: &mut dyn WIPICContext, ptr_timer: WIPICWord, fn_
     Ok(())
 }
 
-pub async fn set_timer(
-    context: &mut dyn WIPICContext,
+struct TimerCallback {
     ptr_timer: WIPICWord,
-    timeout_low: WIPICWord,
-    timeout_high: WIPICWord,
+    fn_callback: WIPICWord,
     param: WIPICWord,
-) -> Result<()> {
-    tracing::debug!("MC_knlSetTimer({:#x}, {:#x}, {:#x}, {:#x})", ptr_timer, timeout_low, timeout_high, param);
+    due: Instant,
+    interval: Option<u64>,
+}
 
-    struct TimerCallback {
-        ptr_timer: WIPICWord,
-        fn_callback: WIPICWord,
-        param: WIPICWord,
-    }
+#[async_trait::async_trait]
+impl MethodBody<WieError> for TimerCallback {
+    #[tracing::instrument(name = "timer", skip_all)]
+    async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
+        // re-arm before calling back, so callback can unset or replace periodic timer
+        if let Some(interval) = self.interval {
+            let now = context.system().platform().now();
+            let due = max(self.due + interval, now + 1);
+
+            context.set_timer(
+                self.ptr_timer,
+                due,
+                Box::new(TimerCallback {
+                    ptr_timer: self.ptr_timer,
+                    fn_callback: self.fn_callback,
+                    param: self.param,
+                    due,
+                    interval: self.interval,
+                }),
+            );
+        }
 
-    #[async_trait::async_trait]
-    impl MethodBody<WieError> for TimerCallback {
-        #[tracing::instrument(name = "timer", skip_all)]
-        async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
-            context.call_function(self.fn_callback, &[self.ptr_timer, self.param]).await?;
+        context.call_function(self.fn_callback, &[self.ptr_timer, self.param]).await?;
 
-            Ok(WIPICResult { results: Vec::new() })
-        }
+        Ok(WIPICResult { results: Vec::new() })
     }
+}
 
+// timers are keyed by timer struct address, so setting same timer again replaces pending one
+fn arm_timer(context: &mut dyn WIPICContext, ptr_timer: WIPICWord, timeout: u64, param: WIPICWord, periodic: bool) -> Result<()> {
     let now = context.system().platform().now();
-    let timeout = (((timeout_high as u64) << 32) | (timeout_low as u64)) as _;
     let timer: WIPICTimer = read_generic(context, ptr_timer)?;
+    let due = now + timeout;
 
     context.set_timer(
-        now + timeout,
+        ptr_timer,
+        due,
         Box::new(TimerCallback {
             ptr_timer,
             fn_callback: timer.fn_callback,
             param,
+            due,
+            interval: periodic.then_some(timeout),
         }),
     );
 
     Ok(())
 }
 
-pub async fn unset_timer(_: &mut dyn WIPICContext, a0: WIPICWord) -> Result<()> {
-    tracing::warn!("stub MC_knlUnsetTimer({:#x})", a0);
+pub async fn set_timer(
+    context: &mut dyn WIPICContext,
+    ptr_timer: WIPICWord,
+    timeout_low: WIPICWord,
+    timeout_high: WIPICWord,
+    param: WIPICWord,
+) -> Result<()> {
+    tracing::debug!("MC_knlSetTimer({:#x}, {:#x}, {:#x}, {:#x})", ptr_timer, timeout_low, timeout_high, param);
 
-    Ok(())
+    let timeout = ((timeout_high as u64) << 32) | (timeout_low as u64);
+
+    arm_timer(context, ptr_timer, timeout, param, false)
+}
+
+// TODO signature is not verified, we assume last parameter is repeat flag
+pub async fn set_timer_ex(
+    context: &mut dyn WIPICContext,
+    ptr_timer: WIPICWord,
+    timeout_low: WIPICWord,
+    timeout_high: WIPICWord,
+    param: WIPICWord,
+    repeat: WIPICWord,
+) -> Result<()> {
+    tracing::debug!(
+        "OEMC_knlSetTimerEx({:#x}, {:#x}, {:#x}, {:#x}, {:#x})",
+        ptr_timer,
+        timeout_low,
+        timeout_high,
+        param,
+        repeat
+    );
+
+    let timeout = ((timeout_high as u64) << 32) | (timeout_low as u64);
+
+    arm_timer(context, ptr_timer, timeout, param, repeat != 0)
+}
+
+pub async fn unset_timer(context: &mut dyn WIPICContext, ptr_timer: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlUnsetTimer({:#x})", ptr_timer);
+
+    if !context.system().event_queue().cancel_timer(ptr_timer) {
+        return Ok(-9); // M_E_INVALID
+    }
+
+    Ok(0) // M_E_SUCCESS
 }
 
 pub async fn alloc(context: &mut dyn WIPICContext, size: WIPICWord) -> Result<WIPICMemoryId> {
@@ -167,41 +242,25 @@ pub async fn get_resource(context: &mut dyn WIPICContext, id: WIPICWord, buf: WI
     Ok(0)
 }
 
-pub async fn printk(context: &mut dyn WIPICContext, ptr_format: WIPICWord, a0: WIPICWord, a1: WIPICWord, a2: WIPICWord, a3: WIPICWord) -> Result<()> {
-    tracing::debug!("MC_knlPrintk({:#x}, {:#x}, {:#x}, {:#x}, {:#x})", ptr_format, a0, a1, a2, a3);
+pub async fn printk(context: &mut dyn WIPICContext, ptr_format: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_knlPrintk({ptr_format:#x}, ...)");
 
-    let format_string = read_null_terminated_string_bytes(context, ptr_format)?;
-    let format_string = encoding_rs::EUC_KR.decode(&format_string).0;
-
-    let result = sprintf(context, &format_string, &[a0, a1, a2, a3])?;
+    let format = read_null_terminated_string_bytes(context, ptr_format)?;
+    let result = sprintf(&*context, &format, &mut *context.var_args(1))?;
 
+    let result = encoding_rs::EUC_KR.decode(&result).0;
     context.system().platform().write_stdout(result.as_bytes());
 
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-pub async fn sprintk(
-    context: &mut dyn WIPICContext,
-    dest: WIPICWord,
-    ptr_format: WIPICWord,
-    a0: WIPICWord,
-    a1: WIPICWord,
-    a2: WIPICWord,
-    a3: WIPICWord,
-    a4: WIPICWord,
-    a5: WIPICWord,
-) -> Result<WIPICWord> {
-    tracing::debug!("MC_knlSprintk({dest:#x}, {ptr_format:#x}, {a1}, {a2}, {a3}, {a4}, {a5})",);
-
-    let format_string = read_null_terminated_string_bytes(context, ptr_format)?;
-    let format_string = encoding_rs::EUC_KR.decode(&format_string).0;
-
-    let result = sprintf(context, &format_string, &[a0, a1, a2, a3, a4, a5])?;
+pub async fn sprintk(context: &mut dyn WIPICContext, dest: WIPICWord, ptr_format: WIPICWord) -> Result<WIPICWord> {
+    tracing::debug!("MC_knlSprintk({dest:#x}, {ptr_format:#x}, ...)");
 
-    let result_bytes = encoding_rs::EUC_KR.encode(&result).0;
+    let format = read_null_terminated_string_bytes(context, ptr_format)?;
+    let result = sprintf(&*context, &format, &mut *context.var_args(2))?;
 
-    write_null_terminated_string_bytes(context, dest, &result_bytes)?;
+    write_null_terminated_string_bytes(context, dest, &result)?;
 
     Ok(result.len() as _)
 }
@@ -218,80 +277,6 @@ pub async fn get_free_memory(_context: &mut dyn WIPICContext) -> Result<i32> {
     Ok(0x100000) // TODO hardcoded
 }
 
-fn sprintf(context: &mut dyn WIPICContext, format: &str, args: &[u32]) -> Result<String> {
-    let mut result = String::with_capacity(format.len());
-    let mut chars = format.chars();
-    let mut arg_iter = args.iter();
-
-    while let Some(x) = chars.next() {
-        if x == '%' {
-            let mut flag = None;
-            let mut width = None;
-            loop {
-                let c = chars.next();
-                if c.is_none() {
-                    // broken formats..
-                    result.push('%');
-                    break;
-                }
-                let c = c.unwrap();
-
-                match c {
-                    '%' => {
-                        result.push('%');
-                        break;
-                    }
-                    'd' => {
-                        let arg = *arg_iter.next().unwrap() as i32;
-                        if let Some('0') = flag {
-                            result += &format!("{:0width$}", arg, width = width.unwrap_or(0));
-                        } else {
-                            result += &format!("{:width$}", arg, width = width.unwrap_or(0));
-                        }
-                        break;
-                    }
-                    's' => {
-                        let ptr = *arg_iter.next().unwrap();
-                        if ptr == 0 {
-                            result += "(null)";
-                            break;
-                        }
-
-                        let str = read_null_terminated_string_bytes(context, ptr)?;
-                        let str = encoding_rs::EUC_KR.decode(&str).0;
-
-                        result += &str;
-                        break;
-                    }
-                    'c' => {
-                        let byte = arg_iter.next().unwrap();
-                        result.push(*byte as u8 as char);
-                        break;
-                    }
-                    'x' => {
-                        let byte = arg_iter.next().unwrap();
-                        result += &format!("{byte:x}");
-                        break;
-                    }
-                    '0' => flag = Some('0'),
-                    '1'..='9' => {
-                        if let Some(x) = width {
-                            width = Some(x * 10 + c.to_digit(10).unwrap() as usize)
-                        } else {
-                            width = Some(c.to_digit(10).unwrap() as usize)
-                        }
-                    }
-                    _ => unimplemented!("Unknown format: {}", c),
-                }
-            }
-        } else {
-            result.push(x);
-        }
-    }
-
-    Ok(result)
-}
-
 pub async fn exit(context: &mut dyn WIPICContext, code: i32) -> Result<()> {
     tracing::debug!("MC_knlExit({})", code);
 
@@ -300,10 +285,10 @@ pub async fn exit(context: &mut dyn WIPICContext, code: i32) -> Result<()> {
     Ok(())
 }
 
-pub async fn get_cur_program_id(_context: &mut dyn WIPICContext) -> Result<WIPICWord> {
-    tracing::warn!("stub MC_knlGetCurProgramID()");
+pub async fn get_cur_program_id(context: &mut dyn WIPICContext) -> Result<WIPICWord> {
+    tracing::debug!("MC_knlGetCurProgramID()");
 
-    Ok(1)
+    Ok(context.system().programs().current())
 }
 
 pub async fn get_program_name(context: &mut dyn WIPICContext, name_buf: WIPICWord, buf_size: i32) -> Result<i32> {
@@ -322,15 +307,375 @@ pub async fn get_program_name(context: &mut dyn WIPICContext, name_buf: WIPICWor
     Ok(0)
 }
 
+// names are written null separated and the list ends with empty name, returns number of names
+pub async fn get_exec_names(context: &mut dyn WIPICContext, buf: WIPICWord, buf_size: i32) -> Result<i32> {
+    tracing::debug!("MC_knlGetExecNames({buf:#x}, {buf_size})");
+
+    let names = context.system().programs().names().map(|x| x.to_string()).collect::<Vec<_>>();
+
+    let mut data = names.iter().flat_map(|x| x.bytes().chain(iter::once(0))).collect::<Vec<_>>();
+    data.push(0);
+
+    if buf_size < data.len() as i32 {
+        return Ok(-18); // M_E_SHORTBUF
+    }
+
+    context.write_bytes(buf, &data)?;
+
+    Ok(names.len() as _)
+}
+
+struct ProgramEntry {
+    fn_entry: WIPICWord,
+}
+
+#[async_trait::async_trait]
+impl MethodBody<WieError> for ProgramEntry {
+    #[tracing::instrument(name = "program", skip_all)]
+    async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
+        context.call_function(self.fn_entry, &[]).await?;
+
+        Ok(WIPICResult { results: Vec::new() })
+    }
+}
+
+// executables in package which are not registered yet are registered on first use
+async fn find_program(context: &mut dyn WIPICContext, name: &str) -> Result<Option<ProgramId>> {
+    if let Some(id) = context.system().programs().find(name) {
+        return Ok(Some(id));
+    }
+
+    if context.get_resource_size(name).await?.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(context.system().programs().register(name, name)))
+}
+
+// executable is loaded only once, later calls return entry of loaded one
+async fn load_program(context: &mut dyn WIPICContext, id: ProgramId) -> Result<WIPICWord> {
+    let (entry, path) = {
+        let programs = context.system().programs();
+        let program = programs.get(id).unwrap();
+
+        (program.entry, program.path.clone())
+    };
+
+    if let Some(entry) = entry {
+        return Ok(entry);
+    }
+
+    let entry = context.load_executable(id, &path).await?;
+    context.system().programs().get_mut(id).unwrap().entry = Some(entry);
+
+    Ok(entry)
+}
+
+async fn start_program(context: &mut dyn WIPICContext, ptr_name: WIPICWord, exclusive: bool) -> Result<i32> {
+    let name = String::from_utf8_lossy(&read_null_terminated_string_bytes(context, ptr_name)?).into_owned();
+
+    let Some(id) = find_program(context, &name).await? else {
+        return Ok(-12); // M_E_NOENT
+    };
+
+    let state = context.system().programs().get(id).unwrap().state;
+    if matches!(state, ProgramState::Running | ProgramState::Paused) {
+        return Ok(-1); // M_E_ERROR
+    }
+
+    let fn_entry = load_program(context, id).await?;
+
+    context.system().programs().start(id, exclusive);
+    context.spawn(Box::new(ProgramEntry { fn_entry }))?;
+
+    Ok(id as _)
+}
+
+pub async fn execute(context: &mut dyn WIPICContext, ptr_name: WIPICWord, ptr_args: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlExecute({ptr_name:#x}, {ptr_args:#x})");
+
+    start_program(context, ptr_name, true).await
+}
+
+// unlike execute, parent program keeps running
+pub async fn mexecute(context: &mut dyn WIPICContext, ptr_name: WIPICWord, ptr_args: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlMExecute({ptr_name:#x}, {ptr_args:#x})");
+
+    start_program(context, ptr_name, false).await
+}
+
+// loads library module and writes its entry to ptr_entry, returns program id of module
+pub async fn load(context: &mut dyn WIPICContext, ptr_name: WIPICWord, ptr_entry: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlLoad({ptr_name:#x}, {ptr_entry:#x})");
+
+    let name = String::from_utf8_lossy(&read_null_terminated_string_bytes(context, ptr_name)?).into_owned();
+
+    let Some(id) = find_program(context, &name).await? else {
+        return Ok(-12); // M_E_NOENT
+    };
+
+    let fn_entry = load_program(context, id).await?;
+
+    {
+        let mut programs = context.system().programs();
+        let current = programs.current();
+        let program = programs.get_mut(id).unwrap();
+
+        if program.state == ProgramState::Idle {
+            program.kind = ProgramKind::Library;
+            program.state = ProgramState::Running;
+            program.parent = Some(current);
+        }
+    }
+
+    if ptr_entry != 0 {
+        write_generic(context, ptr_entry, fn_entry)?;
+    }
+
+    Ok(id as _)
+}
+
+// guest code of stopped program can't be interrupted, so it keeps running until it returns
+pub async fn program_stop(context: &mut dyn WIPICContext, id: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlProgramStop({id})");
+
+    let is_main = {
+        let mut programs = context.system().programs();
+        let Some(program) = programs.get(id) else {
+            return Ok(-12); // M_E_NOENT
+        };
+        let is_main = program.parent.is_none();
+
+        programs.stop(id);
+
+        is_main
+    };
+
+    if is_main {
+        context.system().platform().exit();
+    }
+
+    Ok(0)
+}
+
+pub async fn get_parent_program_id(context: &mut dyn WIPICContext) -> Result<WIPICWord> {
+    tracing::debug!("MC_knlGetParentProgramID()");
+
+    let programs = context.system().programs();
+
+    Ok(programs.get(programs.current()).and_then(|x| x.parent).unwrap_or(0))
+}
+
+pub async fn get_program_info(context: &mut dyn WIPICContext, id: WIPICWord, ptr_info: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlGetProgramInfo({id}, {ptr_info:#x})");
+
+    let info = {
+        let programs = context.system().programs();
+        let Some(program) = programs.get(id) else {
+            return Ok(-12); // M_E_NOENT
+        };
+
+        let mut name = [0; 32];
+        let length = min(program.name.len(), name.len() - 1);
+        name[..length].copy_from_slice(&program.name.as_bytes()[..length]);
+
+        WIPICProgramInfo {
+            id,
+            parent_id: program.parent.unwrap_or(0),
+            kind: match program.kind {
+                ProgramKind::Application => 0,
+                ProgramKind::Library => 1,
+            },
+            state: match program.state {
+                ProgramState::Idle => 0,
+                ProgramState::Running => 1,
+                ProgramState::Paused => 2,
+                ProgramState::Stopped => 3,
+            },
+            access_level: program.access_level,
+            name,
+        }
+    };
+
+    write_generic(context, ptr_info, info)?;
+
+    Ok(0)
+}
+
+pub async fn get_access_level(context: &mut dyn WIPICContext) -> Result<i32> {
+    tracing::debug!("MC_knlGetAccessLevel()");
+
+    let programs = context.system().programs();
+
+    Ok(programs.get(programs.current()).map(|x| x.access_level).unwrap_or(0) as _)
+}
+
+// shared buffer id is memory id of the buffer, so apps can get pointer with MC_GETDPTR
+pub async fn create_shared_buf(context: &mut dyn WIPICContext, ptr_name: WIPICWord, size: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlCreateSharedBuf({ptr_name:#x}, {size})");
+
+    let name = String::from_utf8_lossy(&read_null_terminated_string_bytes(context, ptr_name)?).into_owned();
+    if context.system().programs().shared_buffer(&name).is_some() {
+        return Ok(-1); // M_E_ERROR
+    }
+
+    let memory = context.alloc(size)?;
+    context.write_bytes(context.data_ptr(memory)?, &vec![0; size as usize])?;
+
+    context.system().programs().create_shared_buffer(&name, memory.0, size);
+
+    Ok(memory.0 as _)
+}
+
+pub async fn destroy_shared_buf(context: &mut dyn WIPICContext, id: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlDestroySharedBuf({id:#x})");
+
+    let mut programs = context.system().programs();
+    let Some(name) = programs.shared_buffer_name(id) else {
+        return Ok(-25); // M_E_INVALIDHANDLE
+    };
+    programs.remove_shared_buffer(&name);
+    drop(programs);
+
+    context.free(WIPICMemoryId(id))?;
+
+    Ok(0)
+}
+
+pub async fn get_shared_buf(context: &mut dyn WIPICContext, ptr_name: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlGetSharedBuf({ptr_name:#x})");
+
+    let name = String::from_utf8_lossy(&read_null_terminated_string_bytes(context, ptr_name)?).into_owned();
+
+    match context.system().programs().shared_buffer(&name) {
+        Some(x) => Ok(x.handle as _),
+        None => Ok(-12), // M_E_NOENT
+    }
+}
+
+pub async fn get_shared_buf_size(context: &mut dyn WIPICContext, id: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlGetSharedBufSize({id:#x})");
+
+    let programs = context.system().programs();
+
+    match programs.shared_buffer_name(id).and_then(|x| programs.shared_buffer(&x)) {
+        Some(x) => Ok(x.size as _),
+        None => Ok(-25), // M_E_INVALIDHANDLE
+    }
+}
+
+// buffer is reallocated, so it returns new id of the buffer
+pub async fn resize_shared_buf(context: &mut dyn WIPICContext, id: WIPICWord, size: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_knlResizeSharedBuf({id:#x}, {size})");
+
+    let (name, buffer) = {
+        let programs = context.system().programs();
+        let Some(name) = programs.shared_buffer_name(id) else {
+            return Ok(-25); // M_E_INVALIDHANDLE
+        };
+        let buffer = programs.shared_buffer(&name).unwrap();
+
+        (name, buffer)
+    };
+
+    // size is given by app, so we allocate on app memory before making any host buffer of that size
+    let memory = match context.alloc(size) {
+        Ok(x) => x,
+        Err(WieError::AllocationFailure) => return Ok(-1), // M_E_ERROR
+        Err(e) => return Err(e),
+    };
+
+    let copy_size = min(buffer.size, size);
+    let mut data = vec![0; size as usize];
+    context.read_bytes(context.data_ptr(WIPICMemoryId(id))?, &mut data[..copy_size as usize])?;
+
+    context.write_bytes(context.data_ptr(memory)?, &data)?;
+    context.free(WIPICMemoryId(id))?;
+
+    context.system().programs().create_shared_buffer(&name, memory.0, size);
+
+    Ok(memory.0 as _)
+}
+
+// private area is kept until program stops, creating it again returns existing one
+async fn create_private_area(context: &mut dyn WIPICContext, kind: PrivateAreaKind, size: WIPICWord) -> Result<WIPICWord> {
+    let current = context.system().programs().current();
+    if let Some(address) = context.system().programs().private_area(current, kind) {
+        return Ok(address);
+    }
+
+    let address = context.alloc_raw(size)?;
+    context.write_bytes(address, &vec![0; size as usize])?;
+
+    context.system().programs().set_private_area(current, kind, address);
+
+    Ok(address)
+}
+
+fn get_private_area(context: &mut dyn WIPICContext, kind: PrivateAreaKind) -> WIPICWord {
+    let programs = context.system().programs();
+
+    programs.private_area(programs.current(), kind).unwrap_or(0)
+}
+
+pub async fn create_app_private_area(context: &mut dyn WIPICContext, size: WIPICWord) -> Result<WIPICWord> {
+    tracing::debug!("OEMC_knlCreateAppPrivateArea({size})");
+
+    create_private_area(context, PrivateAreaKind::Application, size).await
+}
+
+pub async fn get_app_private_area(context: &mut dyn WIPICContext) -> Result<WIPICWord> {
+    tracing::debug!("OEMC_knlGetAppPrivateArea()");
+
+    Ok(get_private_area(context, PrivateAreaKind::Application))
+}
+
+pub async fn create_lib_private_area(context: &mut dyn WIPICContext, size: WIPICWord) -> Result<WIPICWord> {
+    tracing::debug!("OEMC_knlCreateLibPrivateArea({size})");
+
+    create_private_area(context, PrivateAreaKind::Library, size).await
+}
+
+pub async fn get_lib_private_area(context: &mut dyn WIPICContext) -> Result<WIPICWord> {
+    tracing::debug!("OEMC_knlGetLibPrivateArea()");
+
+    Ok(get_private_area(context, PrivateAreaKind::Library))
+}
+
 #[cfg(test)]
 mod test {
-    use alloc::{boxed::Box, string::String};
-
-    use wie_util::{Result, read_null_terminated_string_bytes, write_null_terminated_string_bytes};
+    use alloc::{boxed::Box, string::String, vec};
+
+    use test_utils::TestPlatform;
+    use wie_backend::{DefaultTaskRunner, PrivateAreaKind, ProgramKind, ProgramState, System};
+    use wie_util::{Result, read_generic, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
+
+    use crate::{
+        WIPICContext,
+        context::test::{TEST_ENTRY_BASE, TestContext},
+        method::MethodImpl,
+    };
+
+    use super::{
+        create_app_private_area, create_lib_private_area, create_shared_buf, def_timer, destroy_shared_buf, execute, get_app_private_area,
+        get_exec_names, get_lib_private_area, get_parent_program_id, get_shared_buf, get_shared_buf_size, load, mexecute, program_stop,
+        resize_shared_buf, set_timer, sprintk, unset_timer,
+    };
+
+    fn test_context() -> TestContext {
+        let system = System::new(Box::new(TestPlatform::new()), "", "main", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system);
+        context.alloc_raw(4).unwrap(); // avoid null pointer
+
+        context
+    }
 
-    use crate::{WIPICContext, context::test::TestContext, method::MethodImpl};
+    fn write_name(context: &mut TestContext, name: &str) -> Result<u32> {
+        let ptr_name = context.alloc_raw(name.len() as u32 + 1)?;
+        write_null_terminated_string_bytes(context, ptr_name, name.as_bytes())?;
 
-    use super::sprintk;
+        Ok(ptr_name)
+    }
 
     #[futures_test::test]
     async fn test_sprintk() -> Result<()> {
@@ -338,25 +683,216 @@ mod test {
 
         let sprintk = sprintk.into_body();
 
-        let format = context.alloc_raw(10).unwrap();
-        let dest = context.alloc_raw(10).unwrap();
+        let format = context.alloc_raw(32).unwrap();
+        let dest = context.alloc_raw(32).unwrap();
 
         write_null_terminated_string_bytes(&mut context, format, "%d".as_bytes()).unwrap();
-        sprintk
-            .call(&mut context, Box::new([dest, format, 1234, 0, 0, 0, 0, 0, 0, 0]))
-            .await
-            .unwrap();
+        context.call_args = vec![dest, format, 1234];
+        sprintk.call(&mut context, Box::new([dest, format])).await.unwrap();
         let result = read_null_terminated_string_bytes(&context, dest).unwrap();
         assert_eq!(String::from_utf8(result).unwrap(), "1234");
 
         write_null_terminated_string_bytes(&mut context, format, "test %02d".as_bytes()).unwrap();
-        sprintk
-            .call(&mut context, Box::new([dest, format, 1, 0, 0, 0, 0, 0, 0, 0]))
-            .await
-            .unwrap();
+        context.call_args = vec![dest, format, 1];
+        sprintk.call(&mut context, Box::new([dest, format])).await.unwrap();
         let result = read_null_terminated_string_bytes(&context, dest).unwrap();
         assert_eq!(String::from_utf8(result).unwrap(), "test 01");
 
+        // number of arguments is not limited
+        write_null_terminated_string_bytes(&mut context, format, "%d%d%d%d%d%d%d%d%d%d".as_bytes()).unwrap();
+        context.call_args = [dest, format].into_iter().chain(0..10).collect();
+        sprintk.call(&mut context, Box::new([dest, format])).await.unwrap();
+        let result = read_null_terminated_string_bytes(&context, dest).unwrap();
+        assert_eq!(String::from_utf8(result).unwrap(), "0123456789");
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_timer() -> Result<()> {
+        let mut context = test_context();
+
+        let timer = context.alloc_raw(4)?;
+        def_timer(&mut context, timer, 0x1234).await?;
+
+        set_timer(&mut context, timer, 100, 0, 0).await?;
+        assert!(context.system().event_queue().is_timer_pending(timer));
+        let due = context.system().event_queue().next_timer_due().unwrap();
+
+        // re-arm replaces pending timer
+        set_timer(&mut context, timer, 200, 0, 0).await?;
+        let rearmed = context.system().event_queue().next_timer_due().unwrap();
+        assert!(rearmed.raw() >= due.raw() + 100);
+        assert!(context.system().event_queue().pop_due_timer(due).is_none());
+
+        assert_eq!(unset_timer(&mut context, timer).await?, 0);
+        assert!(!context.system().event_queue().is_timer_pending(timer));
+        assert!(context.system().event_queue().next_timer_due().is_none());
+
+        // timer can't be unset twice
+        assert_eq!(unset_timer(&mut context, timer).await?, -9);
+
+        // unset timer can be set again
+        set_timer(&mut context, timer, 100, 0, 0).await?;
+        assert!(context.system().event_queue().is_timer_pending(timer));
+
+        // periodic timer shares same key
+        set_timer_ex(&mut context, timer, 50, 0, 0, 1).await?;
+        assert!(context.system().event_queue().is_timer_pending(timer));
+        assert_eq!(unset_timer(&mut context, timer).await?, 0);
+        assert!(context.system().event_queue().next_timer_due().is_none());
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_shared_buf() -> Result<()> {
+        let system = System::new(Box::new(TestPlatform::new()), "", "main", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system);
+        context.alloc_raw(4)?;
+
+        let name = context.alloc_raw(8)?;
+        write_null_terminated_string_bytes(&mut context, name, b"shared")?;
+
+        let id = create_shared_buf(&mut context, name, 4).await?;
+        assert!(id > 0);
+        assert_eq!(create_shared_buf(&mut context, name, 4).await?, -1);
+        assert_eq!(get_shared_buf(&mut context, name).await?, id);
+
+        write_generic(&mut context, id as u32, 0x1234u32)?;
+
+        let new_id = resize_shared_buf(&mut context, id as _, 8).await?;
+        assert_eq!(get_shared_buf(&mut context, name).await?, new_id);
+        assert_eq!(get_shared_buf_size(&mut context, new_id as _).await?, 8);
+        assert_eq!(read_generic::<u32, _>(&context, new_id as _)?, 0x1234);
+        assert_eq!(read_generic::<u32, _>(&context, new_id as u32 + 4)?, 0);
+
+        // shrinking keeps the head of the buffer
+        let small_id = resize_shared_buf(&mut context, new_id as _, 2).await?;
+        assert_eq!(get_shared_buf_size(&mut context, small_id as _).await?, 2);
+        assert_eq!(read_generic::<u16, _>(&context, small_id as _)?, 0x1234);
+
+        // too big size fails on app memory, without touching the buffer
+        assert_eq!(resize_shared_buf(&mut context, small_id as _, 0xffffffff).await?, -1);
+        assert_eq!(get_shared_buf(&mut context, name).await?, small_id);
+
+        assert_eq!(resize_shared_buf(&mut context, 0x1234, 4).await?, -25);
+
+        assert_eq!(destroy_shared_buf(&mut context, small_id as _).await?, 0);
+        assert_eq!(destroy_shared_buf(&mut context, small_id as _).await?, -25);
+        assert_eq!(get_shared_buf(&mut context, name).await?, -12);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_execute() -> Result<()> {
+        let mut context = test_context();
+        context.add_resource("sub", b"sub");
+
+        let sub = write_name(&mut context, "sub")?;
+        let unknown = write_name(&mut context, "unknown")?;
+
+        assert_eq!(execute(&mut context, unknown, 0).await?, -12);
+
+        let id = execute(&mut context, sub, 0).await?;
+        assert_eq!(id, 2);
+        assert_eq!(context.loaded.len(), 1);
+        assert_eq!(context.spawned.len(), 1);
+        assert_eq!(context.system().programs().current(), 2);
+        assert_eq!(get_parent_program_id(&mut context).await?, 1);
+        assert_eq!(context.system().programs().get(1).unwrap().state, ProgramState::Paused);
+
+        // running program can't be started again
+        assert_eq!(execute(&mut context, sub, 0).await?, -1);
+
+        assert_eq!(program_stop(&mut context, id as _).await?, 0);
+        assert_eq!(context.system().programs().current(), 1);
+        assert_eq!(context.system().programs().get(1).unwrap().state, ProgramState::Running);
+
+        // executable is loaded only once
+        assert_eq!(mexecute(&mut context, sub, 0).await?, id);
+        assert_eq!(context.loaded.len(), 1);
+        assert_eq!(context.spawned.len(), 2);
+        assert_eq!(context.system().programs().get(1).unwrap().state, ProgramState::Running);
+
+        assert_eq!(program_stop(&mut context, 100).await?, -12);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_load() -> Result<()> {
+        let mut context = test_context();
+        context.add_resource("lib", b"lib");
+
+        let lib = write_name(&mut context, "lib")?;
+        let unknown = write_name(&mut context, "unknown")?;
+        let ptr_entry = context.alloc_raw(4)?;
+
+        assert_eq!(load(&mut context, unknown, ptr_entry).await?, -12);
+
+        let id = load(&mut context, lib, ptr_entry).await?;
+        assert_eq!(read_generic::<u32, _>(&context, ptr_entry)?, TEST_ENTRY_BASE + id as u32);
+        {
+            let programs = context.system().programs();
+            let program = programs.get(id as _).unwrap();
+
+            assert_eq!(program.kind, ProgramKind::Library);
+            assert_eq!(program.state, ProgramState::Running);
+            assert_eq!(program.parent, Some(1));
+            assert_eq!(programs.current(), 1);
+        }
+
+        assert_eq!(load(&mut context, lib, 0).await?, id);
+        assert_eq!(context.loaded.len(), 1);
+        assert!(context.spawned.is_empty());
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_private_area() -> Result<()> {
+        let mut context = test_context();
+        context.add_resource("sub", b"sub");
+
+        let app_area = create_app_private_area(&mut context, 16).await?;
+        assert_eq!(get_lib_private_area(&mut context).await?, 0);
+
+        let sub = write_name(&mut context, "sub")?;
+        let id = mexecute(&mut context, sub, 0).await?;
+
+        // each program has its own private areas
+        assert_eq!(get_app_private_area(&mut context).await?, 0);
+        let lib_area = create_lib_private_area(&mut context, 16).await?;
+        assert_ne!(lib_area, app_area);
+        assert_eq!(create_lib_private_area(&mut context, 16).await?, lib_area);
+        assert_eq!(get_lib_private_area(&mut context).await?, lib_area);
+
+        // private areas are released when program stops
+        program_stop(&mut context, id as _).await?;
+        assert_eq!(context.system().programs().private_area(id as _, PrivateAreaKind::Library), None);
+        assert_eq!(get_app_private_area(&mut context).await?, app_area);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_program() -> Result<()> {
+        let system = System::new(Box::new(TestPlatform::new()), "", "main", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system);
+        context.alloc_raw(4)?;
+
+        let buf = context.alloc_raw(8)?;
+        assert_eq!(get_exec_names(&mut context, buf, 4).await?, -18);
+        assert_eq!(get_exec_names(&mut context, buf, 8).await?, 1);
+        assert_eq!(read_null_terminated_string_bytes(&context, buf)?, b"main");
+
+        assert_eq!(get_app_private_area(&mut context).await?, 0);
+        let area = create_app_private_area(&mut context, 16).await?;
+        assert_eq!(create_app_private_area(&mut context, 16).await?, area);
+        assert_eq!(get_app_private_area(&mut context).await?, area);
+
         Ok(())
     }
 }
@@ -1,11 +1,24 @@
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use core::net::{Ipv4Addr, SocketAddrV4};
 
-use wie_util::{Result, WieError};
+use wie_backend::{HttpRequest, HttpResponseHeader, MAX_DATAGRAM_SIZE, SocketError, SocketEvent, SocketHandle, SocketStatus, SocketType};
+use wie_util::{Result, WieError, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
 
 use crate::{WIPICResult, WIPICWord, context::WIPICContext, method::MethodBody};
 
+const MC_SOCKET_STREAM: i32 = 1;
+const MC_SOCKET_DGRAM: i32 = 2;
+
+const MC_AF_INET: u8 = 0;
+
+// indexed by MC_HTTP_METHOD_*
+const HTTP_METHODS: [&str; 3] = ["GET", "POST", "HEAD"];
+
+// interval to check socket status for callbacks
+const POLL_INTERVAL: u64 = 10;
+
 pub async fn connect(context: &mut dyn WIPICContext, cb: WIPICWord, param: WIPICWord) -> Result<i32> {
-    tracing::warn!("stub MC_netConnect({:#x}, {:#x})", cb, param);
+    tracing::debug!("MC_netConnect({:#x}, {:#x})", cb, param);
 
     struct ConnectCallback {
         cb: WIPICWord,
@@ -14,11 +27,12 @@ pub async fn connect(context: &mut dyn WIPICContext, cb: WIPICWord, param: WIPIC
 
     #[async_trait::async_trait]
     impl MethodBody<WieError> for ConnectCallback {
-        #[tracing::instrument(name = "timer", skip_all)]
+        #[tracing::instrument(name = "net", skip_all)]
         async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
             context.system().sleep(1).await; // simulate some delay
 
-            context.call_function(self.cb, &[u32::MAX, self.param]).await?; // callback with M_E_ERROR
+            // we don't have to bring up data connection, sockets can be used right away
+            context.call_function(self.cb, &[0, self.param]).await?; // callback with M_E_SUCCESS
 
             Ok(WIPICResult { results: Vec::new() })
         }
@@ -30,13 +44,693 @@ pub async fn connect(context: &mut dyn WIPICContext, cb: WIPICWord, param: WIPIC
 }
 
 pub async fn close(_context: &mut dyn WIPICContext) -> Result<()> {
-    tracing::warn!("stub MC_netClose()");
+    tracing::debug!("MC_netClose()");
 
     Ok(())
 }
 
-pub async fn socket_close(_context: &mut dyn WIPICContext, fd: i32) -> Result<i32> {
-    tracing::warn!("stub MC_netSocketClose({})", fd);
+pub async fn socket(context: &mut dyn WIPICContext, domain: i32, r#type: i32) -> Result<i32> {
+    tracing::debug!("MC_netSocket({}, {})", domain, r#type);
+
+    let socket_type = match r#type {
+        MC_SOCKET_STREAM => SocketType::Stream,
+        MC_SOCKET_DGRAM => SocketType::Datagram,
+        _ => return Ok(-9), // M_E_INVALID
+    };
+
+    Ok(context.system().sockets().create(socket_type) as _)
+}
+
+pub async fn socket_connect(
+    context: &mut dyn WIPICContext,
+    fd: i32,
+    addr_type: i32,
+    addr: WIPICWord,
+    port: i32,
+    cb: WIPICWord,
+    param: WIPICWord,
+) -> Result<i32> {
+    tracing::debug!(
+        "MC_netSocketConnect({}, {}, {:#x}, {}, {:#x}, {:#x})",
+        fd,
+        addr_type,
+        addr,
+        port,
+        cb,
+        param
+    );
+
+    let system = context.system().clone();
+    if let Err(err) = system.sockets().connect(&system, fd as _, socket_addr(addr, port)) {
+        return Ok(to_error_code(err));
+    }
+
+    if cb != 0 {
+        context.spawn(Box::new(SocketConnectCallback { fd, cb, param }))?;
+    }
+
+    Ok(-19) // M_E_WOULDBLOCK, result is delivered to callback
+}
+
+pub async fn socket_write(context: &mut dyn WIPICContext, fd: i32, ptr_buf: WIPICWord, len: i32) -> Result<i32> {
+    tracing::debug!("MC_netSocketWrite({}, {:#x}, {})", fd, ptr_buf, len);
+
+    let mut buf = vec![0; len.max(0) as usize];
+    context.read_bytes(ptr_buf, &mut buf)?;
+
+    let system = context.system().clone();
+    let result = system.sockets().write(&system, fd as _, &buf);
+
+    Ok(result.map(|x| x as _).unwrap_or_else(to_error_code))
+}
+
+pub async fn socket_read(context: &mut dyn WIPICContext, fd: i32, ptr_buf: WIPICWord, len: i32) -> Result<i32> {
+    tracing::debug!("MC_netSocketRead({}, {:#x}, {})", fd, ptr_buf, len);
+
+    let mut buf = vec![0; len.max(0) as usize];
+    let result = context.system().sockets().read(fd as _, &mut buf);
+
+    match result {
+        Ok(length) => {
+            context.write_bytes(ptr_buf, &buf[..length])?;
+
+            Ok(length as _)
+        }
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+pub async fn socket_close(context: &mut dyn WIPICContext, fd: i32) -> Result<i32> {
+    tracing::debug!("MC_netSocketClose({})", fd);
+
+    let result = context.system().sockets().close(fd as _);
+
+    Ok(result.map(|_| 0).unwrap_or_else(to_error_code))
+}
+
+pub async fn socket_bind(context: &mut dyn WIPICContext, fd: i32, addr_type: i32, addr: WIPICWord, port: i32) -> Result<i32> {
+    tracing::debug!("MC_netSocketBind({}, {}, {:#x}, {})", fd, addr_type, addr, port);
+
+    let system = context.system().clone();
+    let result = system.sockets().bind(&system, fd as _, socket_addr(addr, port));
+
+    Ok(result.map(|_| 0).unwrap_or_else(to_error_code))
+}
+
+pub async fn get_max_packet_length(_context: &mut dyn WIPICContext, fd: i32) -> Result<i32> {
+    tracing::debug!("MC_netGetMaxPacketLength({})", fd);
+
+    Ok(MAX_DATAGRAM_SIZE as _)
+}
+
+pub async fn socket_send_to(
+    context: &mut dyn WIPICContext,
+    fd: i32,
+    ptr_buf: WIPICWord,
+    len: i32,
+    addr_type: i32,
+    addr: WIPICWord,
+    port: i32,
+) -> Result<i32> {
+    tracing::debug!(
+        "MC_netSocketSendTo({}, {:#x}, {}, {}, {:#x}, {})",
+        fd,
+        ptr_buf,
+        len,
+        addr_type,
+        addr,
+        port
+    );
+
+    let mut buf = vec![0; len.max(0) as usize];
+    context.read_bytes(ptr_buf, &mut buf)?;
+
+    let system = context.system().clone();
+    let result = system.sockets().send_to(&system, fd as _, &buf, socket_addr(addr, port));
+
+    Ok(result.map(|x| x as _).unwrap_or_else(to_error_code))
+}
+
+pub async fn socket_rcv_from(
+    context: &mut dyn WIPICContext,
+    fd: i32,
+    ptr_buf: WIPICWord,
+    len: i32,
+    ptr_addr_type: WIPICWord,
+    ptr_addr: WIPICWord,
+    ptr_port: WIPICWord,
+) -> Result<i32> {
+    tracing::debug!(
+        "MC_netSocketRcvFrom({}, {:#x}, {}, {:#x}, {:#x}, {:#x})",
+        fd,
+        ptr_buf,
+        len,
+        ptr_addr_type,
+        ptr_addr,
+        ptr_port
+    );
+
+    let mut buf = vec![0; len.max(0) as usize];
+    let result = context.system().sockets().recv_from(fd as _, &mut buf);
+
+    match result {
+        Ok((length, addr)) => {
+            context.write_bytes(ptr_buf, &buf[..length])?;
+            if ptr_addr_type != 0 {
+                write_generic(context, ptr_addr_type, MC_AF_INET)?;
+            }
+            if ptr_addr != 0 {
+                write_generic(context, ptr_addr, u32::from(*addr.ip()))?;
+            }
+            if ptr_port != 0 {
+                write_generic(context, ptr_port, addr.port())?;
+            }
+
+            Ok(length as _)
+        }
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+// TODO verify signature with real handset
+pub async fn get_host_addr(context: &mut dyn WIPICContext, ptr_name: WIPICWord, ptr_addr: WIPICWord, cb: WIPICWord, param: WIPICWord) -> Result<i32> {
+    let name = read_string(context, ptr_name)?;
+
+    tracing::debug!("MC_netGetHostAddr({}, {:#x}, {:#x}, {:#x})", name, ptr_addr, cb, param);
+
+    // dotted address is resolved right away
+    if let Ok(addr) = name.parse::<Ipv4Addr>() {
+        write_generic(context, ptr_addr, u32::from(addr))?;
+
+        return Ok(0); // M_E_SUCCESS
+    }
+
+    struct GetHostAddrCallback {
+        name: String,
+        ptr_addr: WIPICWord,
+        cb: WIPICWord,
+        param: WIPICWord,
+    }
+
+    #[async_trait::async_trait]
+    impl MethodBody<WieError> for GetHostAddrCallback {
+        #[tracing::instrument(name = "net", skip_all)]
+        async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
+            let system = context.system().clone();
+            let result = system.resolve(&self.name).await;
+
+            let code = match result {
+                Ok(addrs) => {
+                    write_generic(context, self.ptr_addr, u32::from(addrs[0]))?;
+
+                    0 // M_E_SUCCESS
+                }
+                Err(err) => {
+                    tracing::debug!("Failed to resolve {}: {:?}", self.name, err);
+
+                    -1 // M_E_ERROR
+                }
+            };
+
+            if self.cb != 0 {
+                context.call_function(self.cb, &[code as _, self.param]).await?;
+            }
+
+            Ok(WIPICResult { results: Vec::new() })
+        }
+    }
+
+    context.spawn(Box::new(GetHostAddrCallback { name, ptr_addr, cb, param }))?;
+
+    Ok(-19) // M_E_WOULDBLOCK, result is delivered to callback
+}
+
+pub async fn set_read_cb(context: &mut dyn WIPICContext, fd: i32, cb: WIPICWord, param: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_netSetReadCB({}, {:#x}, {:#x})", fd, cb, param);
+
+    set_socket_callback(context, fd, SocketEvent::Readable, cb, param)
+}
+
+pub async fn set_write_cb(context: &mut dyn WIPICContext, fd: i32, cb: WIPICWord, param: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_netSetWriteCB({}, {:#x}, {:#x})", fd, cb, param);
+
+    set_socket_callback(context, fd, SocketEvent::Writable, cb, param)
+}
+
+// callback is called with (fd, result, param) each time socket becomes readable or writable, until replaced or socket is closed.
+// setting null callback cancels previous one.
+fn set_socket_callback(context: &mut dyn WIPICContext, fd: i32, event: SocketEvent, cb: WIPICWord, param: WIPICWord) -> Result<i32> {
+    let subscription = match context.system().sockets().subscribe(fd as _, event) {
+        Ok(x) => x,
+        Err(err) => return Ok(to_error_code(err)),
+    };
+
+    struct SocketCallback {
+        fd: SocketHandle,
+        event: SocketEvent,
+        subscription: u32,
+        cb: WIPICWord,
+        param: WIPICWord,
+    }
+
+    impl SocketCallback {
+        fn should_notify(&self, last: Option<&SocketStatus>, status: &SocketStatus) -> bool {
+            match self.event {
+                SocketEvent::Readable => {
+                    status.readable && last.is_none_or(|x| !x.readable || x.received != status.received || x.error != status.error)
+                }
+                SocketEvent::Writable => status.writable && last.is_none_or(|x| !x.writable),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl MethodBody<WieError> for SocketCallback {
+        #[tracing::instrument(name = "net", skip_all)]
+        async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
+            let mut last = None;
+
+            loop {
+                let status = {
+                    let sockets = context.system().sockets();
+                    if !sockets.is_subscribed(self.fd, self.event, self.subscription) {
+                        break;
+                    }
+
+                    sockets.status(self.fd).unwrap()
+                };
+
+                if self.should_notify(last.as_ref(), &status) {
+                    context.call_function(self.cb, &[self.fd, status_code(&status) as _, self.param]).await?;
+                }
+                last = Some(status);
+
+                context.system().sleep(POLL_INTERVAL).await;
+            }
+
+            Ok(WIPICResult { results: Vec::new() })
+        }
+    }
+
+    if cb != 0 {
+        context.spawn(Box::new(SocketCallback {
+            fd: fd as _,
+            event,
+            subscription,
+            cb,
+            param,
+        }))?;
+    }
+
+    Ok(0)
+}
+
+// http handle is a socket, request body is written and response body is read with socket functions.
+// TODO signatures of http functions are not verified with real handset
+pub async fn http_open(context: &mut dyn WIPICContext, ptr_url: WIPICWord) -> Result<i32> {
+    let url = read_string(context, ptr_url)?;
+
+    tracing::debug!("MC_netHttpOpen({})", url);
+
+    Ok(context.system().sockets().open_http(&url) as _)
+}
+
+pub async fn http_connect(context: &mut dyn WIPICContext, handle: i32, cb: WIPICWord, param: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_netHttpConnect({}, {:#x}, {:#x})", handle, cb, param);
+
+    let system = context.system().clone();
+    if let Err(err) = system.sockets().connect_http(&system, handle as _) {
+        return Ok(to_error_code(err));
+    }
+
+    if cb != 0 {
+        context.spawn(Box::new(SocketConnectCallback { fd: handle, cb, param }))?;
+    }
+
+    Ok(-19) // M_E_WOULDBLOCK, result is delivered to callback
+}
+
+pub async fn http_set_request_method(context: &mut dyn WIPICContext, handle: i32, method: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpSetRequestMethod({}, {})", handle, method);
+
+    let Some(method) = HTTP_METHODS.get(method as usize) else {
+        return Ok(-9); // M_E_INVALID
+    };
+
+    let result = update_http_request(context, handle, |request| request.method = (*method).into());
+
+    Ok(result.map(|_| 0).unwrap_or_else(to_error_code))
+}
+
+pub async fn http_get_request_method(context: &mut dyn WIPICContext, handle: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetRequestMethod({})", handle);
+
+    let request = match context.system().sockets().http_request(handle as _) {
+        Ok(x) => x,
+        Err(err) => return Ok(to_error_code(err)),
+    };
+
+    Ok(HTTP_METHODS.iter().position(|x| *x == request.method).unwrap_or(0) as _)
+}
+
+pub async fn http_set_request_property(context: &mut dyn WIPICContext, handle: i32, ptr_name: WIPICWord, ptr_value: WIPICWord) -> Result<i32> {
+    let name = read_string(context, ptr_name)?;
+    let value = read_string(context, ptr_value)?;
+
+    tracing::debug!("MC_netHttpSetRequestProperty({}, {}, {})", handle, name, value);
+
+    let result = update_http_request(context, handle, |request| request.set_header(&name, &value));
+
+    Ok(result.map(|_| 0).unwrap_or_else(to_error_code))
+}
+
+pub async fn http_get_request_property(
+    context: &mut dyn WIPICContext,
+    handle: i32,
+    ptr_name: WIPICWord,
+    ptr_buf: WIPICWord,
+    len: i32,
+) -> Result<i32> {
+    let name = read_string(context, ptr_name)?;
+
+    tracing::debug!("MC_netHttpGetRequestProperty({}, {}, {:#x}, {})", handle, name, ptr_buf, len);
+
+    let request = match context.system().sockets().http_request(handle as _) {
+        Ok(x) => x,
+        Err(err) => return Ok(to_error_code(err)),
+    };
+
+    match request.header(&name) {
+        Some(value) => write_string(context, ptr_buf, len, value),
+        None => Ok(-12), // M_E_NOENT
+    }
+}
+
+pub async fn http_set_proxy(context: &mut dyn WIPICContext, handle: i32, ptr_host: WIPICWord, port: i32) -> Result<i32> {
+    let host = if ptr_host != 0 { Some(read_string(context, ptr_host)?) } else { None };
+
+    tracing::debug!("MC_netHttpSetProxy({}, {:?}, {})", handle, host, port);
+
+    let result = update_http_request(context, handle, |request| request.proxy = host.map(|x| (x, port as _)));
+
+    Ok(result.map(|_| 0).unwrap_or_else(to_error_code))
+}
+
+pub async fn http_get_proxy(context: &mut dyn WIPICContext, handle: i32, ptr_buf: WIPICWord, len: i32, ptr_port: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetProxy({}, {:#x}, {}, {:#x})", handle, ptr_buf, len, ptr_port);
+
+    let request = match context.system().sockets().http_request(handle as _) {
+        Ok(x) => x,
+        Err(err) => return Ok(to_error_code(err)),
+    };
+
+    let Some((host, port)) = request.proxy else {
+        return Ok(-12); // M_E_NOENT
+    };
+    if ptr_port != 0 {
+        write_generic(context, ptr_port, port as u32)?;
+    }
+
+    write_string(context, ptr_buf, len, &host)
+}
+
+pub async fn http_get_response_code(context: &mut dyn WIPICContext, handle: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetResponseCode({})", handle);
+
+    match http_response(context, handle) {
+        Ok(response) => Ok(response.code as _),
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+pub async fn http_get_response_message(context: &mut dyn WIPICContext, handle: i32, ptr_buf: WIPICWord, len: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetResponseMessage({}, {:#x}, {})", handle, ptr_buf, len);
+
+    match http_response(context, handle) {
+        Ok(response) => write_string(context, ptr_buf, len, &response.message),
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+pub async fn http_get_header_field(context: &mut dyn WIPICContext, handle: i32, ptr_name: WIPICWord, ptr_buf: WIPICWord, len: i32) -> Result<i32> {
+    let name = read_string(context, ptr_name)?;
+
+    tracing::debug!("MC_netHttpGetHeaderField({}, {}, {:#x}, {})", handle, name, ptr_buf, len);
+
+    let response = match http_response(context, handle) {
+        Ok(x) => x,
+        Err(err) => return Ok(to_error_code(err)),
+    };
+
+    match response.header(&name) {
+        Some(value) => write_string(context, ptr_buf, len, value),
+        None => Ok(-12), // M_E_NOENT
+    }
+}
+
+pub async fn http_get_length(context: &mut dyn WIPICContext, handle: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetLength({})", handle);
+
+    match http_response(context, handle) {
+        Ok(response) => Ok(response.content_length().map(|x| x as i32).unwrap_or(-1)), // -1 if unknown
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+pub async fn http_get_type(context: &mut dyn WIPICContext, handle: i32, ptr_buf: WIPICWord, len: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetType({}, {:#x}, {})", handle, ptr_buf, len);
+
+    match http_response(context, handle) {
+        Ok(response) => match response.content_type() {
+            Some(value) => write_string(context, ptr_buf, len, value),
+            None => Ok(-12), // M_E_NOENT
+        },
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+pub async fn http_get_encoding(context: &mut dyn WIPICContext, handle: i32, ptr_buf: WIPICWord, len: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpGetEncoding({}, {:#x}, {})", handle, ptr_buf, len);
+
+    match http_response(context, handle) {
+        Ok(response) => match response.content_encoding() {
+            Some(value) => write_string(context, ptr_buf, len, value),
+            None => Ok(-12), // M_E_NOENT
+        },
+        Err(err) => Ok(to_error_code(err)),
+    }
+}
+
+pub async fn http_close(context: &mut dyn WIPICContext, handle: i32) -> Result<i32> {
+    tracing::debug!("MC_netHttpClose({})", handle);
+
+    let result = context.system().sockets().close(handle as _);
+
+    Ok(result.map(|_| 0).unwrap_or_else(to_error_code))
+}
+
+fn update_http_request<F>(context: &mut dyn WIPICContext, handle: i32, f: F) -> core::result::Result<(), SocketError>
+where
+    F: FnOnce(&mut HttpRequest),
+{
+    let mut sockets = context.system().sockets();
+
+    let mut request = sockets.http_request(handle as _)?;
+    f(&mut request);
 
-    Ok(-1) // M_E_ERROR
+    sockets.set_http_request(handle as _, request)
+}
+
+fn http_response(context: &mut dyn WIPICContext, handle: i32) -> core::result::Result<HttpResponseHeader, SocketError> {
+    context.system().sockets().http_response(handle as _)?.ok_or(SocketError::NotConnected)
+}
+
+// calls back with (fd, result, param) when connection is established or failed
+struct SocketConnectCallback {
+    fd: i32,
+    cb: WIPICWord,
+    param: WIPICWord,
+}
+
+#[async_trait::async_trait]
+impl MethodBody<WieError> for SocketConnectCallback {
+    #[tracing::instrument(name = "net", skip_all)]
+    async fn call(&self, context: &mut dyn WIPICContext, _: Box<[WIPICWord]>) -> Result<WIPICResult> {
+        let status = loop {
+            let status = context.system().sockets().status(self.fd as _);
+            match status {
+                Ok(x) if x.connecting => context.system().sleep(POLL_INTERVAL).await,
+                Ok(x) => break x,
+                Err(_) => return Ok(WIPICResult { results: Vec::new() }), // closed while connecting
+            }
+        };
+
+        context
+            .call_function(self.cb, &[self.fd as _, status_code(&status) as _, self.param])
+            .await?;
+
+        Ok(WIPICResult { results: Vec::new() })
+    }
+}
+
+// TODO we assume address is in host byte order, as returned by MC_netGetHostAddr. verify with real handset
+fn socket_addr(addr: WIPICWord, port: i32) -> SocketAddrV4 {
+    SocketAddrV4::new(Ipv4Addr::from(addr), port as _)
+}
+
+fn read_string(context: &mut dyn WIPICContext, ptr: WIPICWord) -> Result<String> {
+    Ok(String::from_utf8_lossy(&read_null_terminated_string_bytes(context, ptr)?).into_owned())
+}
+
+// returns string length, or M_E_SHORTBUF if it doesn't fit with null terminator
+fn write_string(context: &mut dyn WIPICContext, ptr_buf: WIPICWord, len: i32, value: &str) -> Result<i32> {
+    if value.len() >= len.max(0) as usize {
+        return Ok(-18); // M_E_SHORTBUF
+    }
+    write_null_terminated_string_bytes(context, ptr_buf, value.as_bytes())?;
+
+    Ok(value.len() as _)
+}
+
+fn status_code(status: &SocketStatus) -> i32 {
+    if status.error.is_some() {
+        -1 // M_E_ERROR
+    } else {
+        0 // M_E_SUCCESS
+    }
+}
+
+fn to_error_code(err: SocketError) -> i32 {
+    match err {
+        SocketError::InvalidHandle => -2,     // M_E_BADFD
+        SocketError::InvalidOperation => -9,  // M_E_INVALID
+        SocketError::AlreadyConnected => -10, // M_E_ISCONN
+        SocketError::NotConnected => -15,     // M_E_NOTCONN
+        SocketError::WouldBlock => -19,       // M_E_WOULDBLOCK
+        SocketError::Network(err) => {
+            tracing::debug!("Network error: {:?}", err);
+
+            -1 // M_E_ERROR
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, string::String};
+    use core::net::{Ipv4Addr, SocketAddrV4};
+
+    use test_utils::TestPlatform;
+    use wie_backend::{DefaultTaskRunner, System, TcpSocket};
+    use wie_util::{ByteRead, ByteWrite, Result, read_null_terminated_string_bytes, write_null_terminated_string_bytes};
+
+    use crate::{WIPICContext, context::test::TestContext};
+
+    use super::{
+        MC_SOCKET_STREAM, http_close, http_connect, http_get_length, http_get_response_code, http_get_type, http_open, http_set_request_property,
+        socket, socket_close, socket_connect, socket_read, socket_write,
+    };
+
+    #[futures_test::test]
+    async fn test_socket() -> Result<()> {
+        let platform = TestPlatform::new();
+        let server_addr = SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 2), 8080);
+        let listener = platform.test_network().listen(server_addr);
+
+        let mut system = System::new(Box::new(platform), "", "", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system.clone());
+
+        let fd = socket(&mut context, 0, MC_SOCKET_STREAM).await?;
+        assert!(fd > 0);
+
+        let result = socket_connect(&mut context, fd, 0, u32::from(*server_addr.ip()), server_addr.port() as _, 0, 0).await?;
+        assert_eq!(result, -19); // M_E_WOULDBLOCK
+
+        system.tick()?;
+        let server = listener.accept().unwrap();
+
+        let buf = context.alloc_raw(16)?;
+        assert_eq!(socket_read(&mut context, fd, buf, 16).await?, -19);
+
+        context.write_bytes(buf, b"hello")?;
+        assert_eq!(socket_write(&mut context, fd, buf, 5).await?, 5);
+
+        system.tick()?;
+        assert_eq!(server.read_available(), b"hello");
+
+        server.write(b"world!").await.unwrap();
+        system.tick()?;
+
+        assert_eq!(socket_read(&mut context, fd, buf, 16).await?, 6);
+        let mut data = [0; 6];
+        context.read_bytes(buf, &mut data)?;
+        assert_eq!(&data, b"world!");
+
+        server.shutdown();
+        system.tick()?;
+        assert_eq!(socket_read(&mut context, fd, buf, 16).await?, 0); // end of stream
+
+        assert_eq!(socket_close(&mut context, fd).await?, 0);
+        assert_eq!(socket_close(&mut context, fd).await?, -2); // M_E_BADFD
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_http() -> Result<()> {
+        let platform = TestPlatform::new();
+        let network = platform.test_network();
+        network.add_host("example.com", Ipv4Addr::new(10, 0, 0, 2));
+        let listener = network.listen(SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 2), 80));
+
+        let mut system = System::new(Box::new(platform), "", "", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system.clone());
+
+        let url = context.alloc_raw(64)?;
+        write_null_terminated_string_bytes(&mut context, url, b"http://example.com/notice?id=1")?;
+        let handle = http_open(&mut context, url).await?;
+        assert!(handle > 0);
+
+        let name = context.alloc_raw(16)?;
+        let value = context.alloc_raw(16)?;
+        write_null_terminated_string_bytes(&mut context, name, b"User-Agent")?;
+        write_null_terminated_string_bytes(&mut context, value, b"wie")?;
+        assert_eq!(http_set_request_property(&mut context, handle, name, value).await?, 0);
+
+        assert_eq!(http_connect(&mut context, handle, 0, 0).await?, -19); // M_E_WOULDBLOCK
+        assert_eq!(http_get_response_code(&mut context, handle).await?, -15); // M_E_NOTCONN
+
+        system.tick()?;
+        let server = listener.accept().unwrap();
+
+        let request = String::from_utf8(server.read_available()).unwrap();
+        assert!(request.starts_with("GET /notice?id=1 HTTP/1.1\r\n"));
+        assert!(request.contains("Host: example.com\r\n"));
+        assert!(request.contains("User-Agent: wie\r\n"));
+        assert!(request.ends_with("\r\n\r\n"));
+
+        server
+            .write(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n")
+            .await
+            .unwrap();
+        system.tick()?;
+
+        assert_eq!(http_get_response_code(&mut context, handle).await?, 200);
+        assert_eq!(http_get_length(&mut context, handle).await?, -1);
+
+        let buf = context.alloc_raw(32)?;
+        assert_eq!(http_get_type(&mut context, handle, buf, 32).await?, 10);
+        assert_eq!(read_null_terminated_string_bytes(&context, buf)?, b"text/plain");
+        assert_eq!(http_get_type(&mut context, handle, buf, 4).await?, -18); // M_E_SHORTBUF
+
+        assert_eq!(socket_read(&mut context, handle, buf, 32).await?, 11);
+        let mut data = [0; 11];
+        context.read_bytes(buf, &mut data)?;
+        assert_eq!(&data, b"hello world");
+        assert_eq!(socket_read(&mut context, handle, buf, 32).await?, 0); // end of body
+
+        assert_eq!(http_close(&mut context, handle).await?, 0);
+
+        Ok(())
+    }
 }
@@ -1,9 +1,1310 @@
-use wie_util::Result;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{mem::size_of, ops::Range};
 
-use crate::{WIPICMemoryId, context::WIPICContext};
+use bytemuck::{Pod, Zeroable};
 
-pub async fn create_application_context(_context: &mut dyn WIPICContext) -> Result<WIPICMemoryId> {
-    tracing::warn!("stub MC_uicCreateApplicationContext");
+use wie_backend::{
+    InputConstraint, InputKey,
+    canvas::{Canvas, Clip, Color, PixelType, Rgb8Pixel, TextAlignment, string_width},
+};
+use wie_util::{Result, read_generic, read_null_terminated_string_bytes, write_generic, write_null_terminated_string_bytes};
 
-    Ok(WIPICMemoryId(0))
+use crate::{WIPICMemoryId, WIPICWord, context::WIPICContext};
+
+use super::graphics::{self, framebuffer::WIPICFramebuffer};
+
+const M_E_SUCCESS: i32 = 0;
+const M_E_INVALID: i32 = -9;
+
+const MV_KEY_PRESS_EVENT: i32 = 501;
+const MV_KEY_REPEAT_EVENT: i32 = 503;
+
+const MC_KEY_UP: i32 = -1;
+const MC_KEY_DOWN: i32 = -2;
+const MC_KEY_LEFT: i32 = -3;
+const MC_KEY_RIGHT: i32 = -4;
+const MC_KEY_SELECT: i32 = -5;
+const MC_KEY_CLEAR: i32 = -16;
+
+// events passed to control callbacks
+const MC_UIC_EVENT_SELECTED: WIPICWord = 1;
+const MC_UIC_EVENT_CHANGED: WIPICWord = 2;
+const MC_UIC_EVENT_CANCELLED: WIPICWord = 3;
+
+const MC_UIC_ALIGN_CENTER: WIPICWord = 1;
+const MC_UIC_ALIGN_RIGHT: WIPICWord = 2;
+
+const MC_UIC_TIME_DATE: WIPICWord = 1;
+const MC_UIC_TIME_TIME: WIPICWord = 2;
+
+const DEFAULT_MAX_TEXT_SIZE: usize = 256;
+
+// handsets show korea standard time
+const TIME_OFFSET: u64 = 9 * 60 * 60 * 1000;
+
+const FONT_SIZE: f32 = 10.0;
+const LINE_HEIGHT: i32 = 14;
+const PADDING: i32 = 2;
+
+const BLACK: Color = Color { a: 0xff, r: 0, g: 0, b: 0 };
+const WHITE: Color = Color {
+    a: 0xff,
+    r: 0xff,
+    g: 0xff,
+    b: 0xff,
+};
+const DISABLED: Color = Color {
+    a: 0xff,
+    r: 0x80,
+    g: 0x80,
+    b: 0x80,
+};
+const HIGHLIGHT: Color = Color {
+    a: 0xff,
+    r: 0x31,
+    g: 0x6a,
+    b: 0xc5,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum UicClass {
+    Label = 1,
+    Button = 2,
+    CheckBox = 3,
+    Edit = 4,
+    List = 5,
+    Menu = 6,
+    Dialog = 7,
+    Time = 8,
+}
+
+impl UicClass {
+    const ALL: [Self; 8] = [
+        Self::Label,
+        Self::Button,
+        Self::CheckBox,
+        Self::Edit,
+        Self::List,
+        Self::Menu,
+        Self::Dialog,
+        Self::Time,
+    ];
+
+    fn from_raw(raw: WIPICWord) -> Option<Self> {
+        Self::ALL.into_iter().find(|x| *x as WIPICWord == raw)
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|x| x.name().eq_ignore_ascii_case(name))
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Label => "Label",
+            Self::Button => "Button",
+            Self::CheckBox => "CheckBox",
+            Self::Edit => "Edit",
+            Self::List => "List",
+            Self::Menu => "Menu",
+            Self::Dialog => "Dialog",
+            Self::Time => "Time",
+        }
+    }
+}
+
+// default appearance of controls created in the context
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct WIPICUicApplicationContext {
+    font: WIPICWord,
+    fg_pixel: WIPICWord,
+    bg_pixel: WIPICWord,
+}
+
+impl WIPICUicApplicationContext {
+    fn new() -> Self {
+        Self {
+            font: 0,
+            fg_pixel: Rgb8Pixel::from_color(BLACK),
+            bg_pixel: Rgb8Pixel::from_color(WHITE),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct WIPICUicObject {
+    class: WIPICWord,
+    class_name: WIPICMemoryId,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    enabled: WIPICWord,
+    font: WIPICWord,
+    fg_pixel: WIPICWord,
+    bg_pixel: WIPICWord,
+    label: WIPICMemoryId, // null terminated euc-kr string
+    label_alignment: WIPICWord,
+    callback: WIPICWord,
+    callback_param: WIPICWord,
+    event_handler: WIPICWord,
+    items: WIPICMemoryId, // array of string memory ids of menu or list items
+    item_count: WIPICWord,
+    active_item: i32,
+    checked: WIPICWord,
+    input_method: WIPICWord,
+    time_mask: WIPICWord,
+    time_low: WIPICWord,
+    time_high: WIPICWord,
+    line_gap: i32,
+}
+
+impl WIPICUicObject {
+    fn class(&self) -> Option<UicClass> {
+        UicClass::from_raw(self.class)
+    }
+
+    fn time(&self) -> u64 {
+        ((self.time_high as u64) << 32) | (self.time_low as u64)
+    }
+}
+
+pub async fn create_application_context(context: &mut dyn WIPICContext) -> Result<WIPICMemoryId> {
+    tracing::debug!("MC_uicCreateApplicationContext()");
+
+    let memory = context.alloc(size_of::<WIPICUicApplicationContext>() as WIPICWord)?;
+    write_generic(context, context.data_ptr(memory)?, WIPICUicApplicationContext::new())?;
+
+    Ok(memory)
+}
+
+pub async fn get_class(context: &mut dyn WIPICContext, ptr_name: WIPICWord) -> Result<WIPICWord> {
+    let name = read_string(context, ptr_name)?;
+    tracing::debug!("MC_uicGetClass({:#x}, {})", ptr_name, name);
+
+    match UicClass::from_name(&name) {
+        Some(class) => Ok(class as _),
+        None => {
+            tracing::warn!("unknown uic class {}", name);
+            Ok(0)
+        }
+    }
+}
+
+pub async fn create(context: &mut dyn WIPICContext, app_context: WIPICMemoryId, class: WIPICWord) -> Result<WIPICMemoryId> {
+    tracing::debug!("MC_uicCreate({:#x}, {})", app_context.0, class);
+
+    let Some(class) = UicClass::from_raw(class) else {
+        tracing::warn!("unknown uic class {}", class);
+        return Ok(WIPICMemoryId(0));
+    };
+
+    let defaults = if app_context.0 != 0 {
+        read_generic(context, context.data_ptr(app_context)?)?
+    } else {
+        WIPICUicApplicationContext::new()
+    };
+
+    let input_method = if class == UicClass::Edit {
+        context.system().input_methods().create(InputConstraint::Any, DEFAULT_MAX_TEXT_SIZE)
+    } else {
+        0
+    };
+
+    let object = WIPICUicObject {
+        class: class as _,
+        class_name: alloc_string(context, class.name())?,
+        enabled: 1,
+        font: defaults.font,
+        fg_pixel: defaults.fg_pixel,
+        bg_pixel: defaults.bg_pixel,
+        input_method,
+        time_mask: MC_UIC_TIME_DATE | MC_UIC_TIME_TIME,
+        ..WIPICUicObject::zeroed()
+    };
+
+    let memory = context.alloc(size_of::<WIPICUicObject>() as WIPICWord)?;
+    write_object(context, memory, &object)?;
+
+    // newly created editor takes text input
+    if input_method != 0 {
+        context.system().set_input_method_focus(input_method, true);
+    }
+
+    Ok(memory)
+}
+
+pub async fn destroy(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<()> {
+    tracing::debug!("MC_uicDestroy({:#x})", obj.0);
+
+    let object = read_object(context, obj)?;
+
+    if object.input_method != 0 {
+        context.system().set_input_method_focus(object.input_method, false);
+        context.system().input_methods().destroy(object.input_method);
+    }
+
+    for item in read_items(context, &object)? {
+        context.free(WIPICMemoryId(item))?;
+    }
+    free_if_allocated(context, object.items)?;
+    free_if_allocated(context, object.label)?;
+    free_if_allocated(context, object.class_name)?;
+
+    context.free(obj)
+}
+
+pub async fn repaint(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<()> {
+    tracing::debug!("MC_uicRepaint({:#x})", obj.0);
+
+    repaint_object(context, obj).await
+}
+
+pub async fn paint(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<()> {
+    tracing::debug!("MC_uicPaint({:#x})", obj.0);
+
+    let object = read_object(context, obj)?;
+    let framebuffer = screen_framebuffer(context).await?;
+
+    paint_object(context, &object, &framebuffer)
+}
+
+pub async fn get_class_name(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<WIPICWord> {
+    tracing::debug!("MC_uicGetClassName({:#x})", obj.0);
+
+    let object = read_object(context, obj)?;
+
+    context.data_ptr(object.class_name)
+}
+
+pub async fn is_instance(context: &mut dyn WIPICContext, obj: WIPICMemoryId, class: WIPICWord) -> Result<i32> {
+    tracing::debug!("MC_uicIsInstance({:#x}, {})", obj.0, class);
+
+    let object = read_object(context, obj)?;
+
+    Ok((object.class == class) as _)
+}
+
+// returns 1 if the event is used by the control's event handler or the control itself
+pub async fn handle_event(context: &mut dyn WIPICContext, obj: WIPICMemoryId, r#type: i32, param1: i32, param2: i32) -> Result<i32> {
+    tracing::debug!("MC_uicHandleEvent({:#x}, {}, {}, {})", obj.0, r#type, param1, param2);
+
+    let mut object = read_object(context, obj)?;
+    if object.enabled == 0 {
+        return Ok(0);
+    }
+
+    if object.event_handler != 0 {
+        let handled = context
+            .call_function(object.event_handler, &[obj.0, r#type as _, param1 as _, param2 as _])
+            .await?;
+        if handled != 0 {
+            return Ok(1);
+        }
+
+        object = read_object(context, obj)?;
+    }
+
+    if r#type != MV_KEY_PRESS_EVENT && r#type != MV_KEY_REPEAT_EVENT {
+        return Ok(0);
+    }
+
+    let (handled, event) = handle_key(context, &mut object, param1)?;
+    if !handled {
+        return Ok(0);
+    }
+
+    write_object(context, obj, &object)?;
+    repaint_object(context, obj).await?;
+
+    // callback may destroy the control, so it's called after the control is updated
+    if let Some(event) = event
+        && object.callback != 0
+    {
+        context.call_function(object.callback, &[obj.0, event, object.callback_param]).await?;
+    }
+
+    Ok(1)
+}
+
+pub async fn configure(context: &mut dyn WIPICContext, obj: WIPICMemoryId, x: i32, y: i32, width: i32, height: i32) -> Result<()> {
+    tracing::debug!("MC_uicConfigure({:#x}, {}, {}, {}, {})", obj.0, x, y, width, height);
+
+    update_object(context, obj, |object| {
+        object.x = x;
+        object.y = y;
+        object.width = width;
+        object.height = height;
+    })
+}
+
+pub async fn get_geometry(context: &mut dyn WIPICContext, obj: WIPICMemoryId, ptr_rect: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicGetGeometry({:#x}, {:#x})", obj.0, ptr_rect);
+
+    let object = read_object(context, obj)?;
+
+    write_generic(context, ptr_rect, [object.x, object.y, object.width, object.height])
+}
+
+pub async fn set_enable(context: &mut dyn WIPICContext, obj: WIPICMemoryId, enabled: i32) -> Result<()> {
+    tracing::debug!("MC_uicSetEnable({:#x}, {})", obj.0, enabled);
+
+    let mut object = read_object(context, obj)?;
+    object.enabled = (enabled != 0) as _;
+    write_object(context, obj, &object)?;
+
+    if object.input_method != 0 {
+        context.system().set_input_method_focus(object.input_method, enabled != 0);
+    }
+
+    Ok(())
+}
+
+pub async fn set_callback(context: &mut dyn WIPICContext, obj: WIPICMemoryId, callback: WIPICWord, param: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetCallback({:#x}, {:#x}, {:#x})", obj.0, callback, param);
+
+    update_object(context, obj, |object| {
+        object.callback = callback;
+        object.callback_param = param;
+    })
+}
+
+pub async fn set_event_handler(context: &mut dyn WIPICContext, obj: WIPICMemoryId, handler: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetEventHandler({:#x}, {:#x})", obj.0, handler);
+
+    update_object(context, obj, |object| object.event_handler = handler)
+}
+
+pub async fn set_font(context: &mut dyn WIPICContext, obj: WIPICMemoryId, font: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetFont({:#x}, {:#x})", obj.0, font);
+
+    update_object(context, obj, |object| object.font = font)
+}
+
+pub async fn get_font(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<WIPICWord> {
+    tracing::debug!("MC_uicGetFont({:#x})", obj.0);
+
+    Ok(read_object(context, obj)?.font)
+}
+
+pub async fn set_fg_color(context: &mut dyn WIPICContext, obj: WIPICMemoryId, pixel: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetFgColor({:#x}, {:#x})", obj.0, pixel);
+
+    update_object(context, obj, |object| object.fg_pixel = pixel)
+}
+
+pub async fn set_bg_color(context: &mut dyn WIPICContext, obj: WIPICMemoryId, pixel: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetBgColor({:#x}, {:#x})", obj.0, pixel);
+
+    update_object(context, obj, |object| object.bg_pixel = pixel)
+}
+
+pub async fn set_label(context: &mut dyn WIPICContext, obj: WIPICMemoryId, ptr_label: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetLabel({:#x}, {:#x})", obj.0, ptr_label);
+
+    let mut object = read_object(context, obj)?;
+
+    free_if_allocated(context, object.label)?;
+    object.label = if ptr_label != 0 {
+        let label = read_string(context, ptr_label)?;
+        alloc_string(context, &label)?
+    } else {
+        WIPICMemoryId(0)
+    };
+
+    write_object(context, obj, &object)
+}
+
+pub async fn get_label(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<WIPICWord> {
+    tracing::debug!("MC_uicGetLabel({:#x})", obj.0);
+
+    let object = read_object(context, obj)?;
+    if object.label.0 == 0 {
+        return Ok(0);
+    }
+
+    context.data_ptr(object.label)
+}
+
+pub async fn set_label_alignment(context: &mut dyn WIPICContext, obj: WIPICMemoryId, alignment: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetLabelAlignment({:#x}, {})", obj.0, alignment);
+
+    update_object(context, obj, |object| object.label_alignment = alignment)
+}
+
+pub async fn set_time_mask(context: &mut dyn WIPICContext, obj: WIPICMemoryId, mask: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetTimeMask({:#x}, {:#x})", obj.0, mask);
+
+    update_object(context, obj, |object| object.time_mask = mask)
+}
+
+// time in seconds
+pub async fn set_time(context: &mut dyn WIPICContext, obj: WIPICMemoryId, time: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetTime({:#x}, {})", obj.0, time);
+
+    let time = time as u64 * 1000;
+    update_object(context, obj, |object| {
+        object.time_low = time as _;
+        object.time_high = (time >> 32) as _;
+    })
+}
+
+// time in milliseconds
+pub async fn set_time_long(context: &mut dyn WIPICContext, obj: WIPICMemoryId, time_low: WIPICWord, time_high: WIPICWord) -> Result<()> {
+    tracing::debug!("MC_uicSetTimeLong({:#x}, {:#x}, {:#x})", obj.0, time_low, time_high);
+
+    update_object(context, obj, |object| {
+        object.time_low = time_low;
+        object.time_high = time_high;
+    })
+}
+
+pub async fn get_time(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<u64> {
+    tracing::debug!("MC_uicGetTime({:#x})", obj.0);
+
+    Ok(read_object(context, obj)?.time())
+}
+
+pub async fn add_menu_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, ptr_label: WIPICWord, index: i32) -> Result<i32> {
+    tracing::debug!("MC_uicAddMenuItem({:#x}, {:#x}, {})", obj.0, ptr_label, index);
+
+    add_item(context, obj, ptr_label, index)
+}
+
+pub async fn get_menu_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<WIPICWord> {
+    tracing::debug!("MC_uicGetMenuItem({:#x}, {})", obj.0, index);
+
+    get_item(context, obj, index)
+}
+
+pub async fn remove_menu_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<i32> {
+    tracing::debug!("MC_uicRemoveMenuItem({:#x}, {})", obj.0, index);
+
+    remove_item(context, obj, index)
+}
+
+pub async fn set_active_menu_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<i32> {
+    tracing::debug!("MC_uicSetActiveMenuItem({:#x}, {})", obj.0, index);
+
+    set_active_item(context, obj, index)
+}
+
+pub async fn get_active_menu_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("MC_uicGetActiveMenuItem({:#x})", obj.0);
+
+    get_active_item(context, obj)
+}
+
+// inserts text at the character position, or at the caret if position is negative
+pub async fn insert_text(context: &mut dyn WIPICContext, obj: WIPICMemoryId, pos: i32, ptr_text: WIPICWord, len: i32) -> Result<i32> {
+    tracing::debug!("MC_uicInsertText({:#x}, {}, {:#x}, {})", obj.0, pos, ptr_text, len);
+
+    let object = read_object(context, obj)?;
+
+    let mut bytes = read_null_terminated_string_bytes(context, ptr_text)?;
+    if len >= 0 {
+        bytes.truncate(len as _);
+    }
+    let text = encoding_rs::EUC_KR.decode(&bytes).0;
+
+    let mut input_methods = context.system().input_methods();
+    let Some(input_method) = input_methods.get(object.input_method) else {
+        return Ok(M_E_INVALID);
+    };
+
+    if pos >= 0 {
+        input_method.set_cursor(pos as _);
+    }
+    input_method.insert_text(&text);
+
+    Ok(M_E_SUCCESS)
+}
+
+pub async fn delete_text(context: &mut dyn WIPICContext, obj: WIPICMemoryId, pos: i32, len: i32) -> Result<i32> {
+    tracing::debug!("MC_uicDeleteText({:#x}, {}, {})", obj.0, pos, len);
+
+    let object = read_object(context, obj)?;
+
+    let mut input_methods = context.system().input_methods();
+    let Some(input_method) = input_methods.get(object.input_method) else {
+        return Ok(M_E_INVALID);
+    };
+
+    let mut chars = input_method.text().chars().collect::<Vec<_>>();
+    if pos < 0 || len < 0 || pos as usize > chars.len() {
+        return Ok(M_E_INVALID);
+    }
+
+    let range = pos as usize..(pos as usize + len as usize).min(chars.len());
+    chars.drain(range);
+    input_method.set_text(&chars.into_iter().collect::<String>());
+    input_method.set_cursor(pos as _);
+
+    Ok(M_E_SUCCESS)
+}
+
+pub async fn get_max_text_size(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("MC_uicGetMaxTextSize({:#x})", obj.0);
+
+    let object = read_object(context, obj)?;
+
+    Ok(context
+        .system()
+        .input_methods()
+        .get(object.input_method)
+        .map(|x| x.max_length() as _)
+        .unwrap_or(0))
+}
+
+pub async fn set_max_text_size(context: &mut dyn WIPICContext, obj: WIPICMemoryId, size: i32) -> Result<i32> {
+    tracing::debug!("MC_uicSetMaxTextSize({:#x}, {})", obj.0, size);
+
+    let object = read_object(context, obj)?;
+
+    let mut input_methods = context.system().input_methods();
+    let Some(input_method) = input_methods.get(object.input_method) else {
+        return Ok(M_E_INVALID);
+    };
+    if size < 0 {
+        return Ok(M_E_INVALID);
+    }
+
+    input_method.set_max_length(size as _);
+
+    Ok(M_E_SUCCESS)
+}
+
+// size of the text in bytes, as returned by MC_uicGetText
+pub async fn get_text_size(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("MC_uicGetTextSize({:#x})", obj.0);
+
+    let text = editor_text(context, obj)?;
+
+    Ok(encoding_rs::EUC_KR.encode(&text).0.len() as _)
+}
+
+// copies null terminated text to the buffer, returning copied length without terminator
+pub async fn get_text(context: &mut dyn WIPICContext, obj: WIPICMemoryId, ptr_buf: WIPICWord, buf_size: i32) -> Result<i32> {
+    tracing::debug!("MC_uicGetText({:#x}, {:#x}, {})", obj.0, ptr_buf, buf_size);
+
+    if buf_size <= 0 {
+        return Ok(M_E_INVALID);
+    }
+
+    let text = editor_text(context, obj)?;
+    let bytes = encoding_rs::EUC_KR.encode(&text).0;
+    let length = bytes.len().min(buf_size as usize - 1);
+
+    write_null_terminated_string_bytes(context, ptr_buf, &bytes[..length])?;
+
+    Ok(length as _)
+}
+
+pub async fn add_list_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, ptr_label: WIPICWord, index: i32) -> Result<i32> {
+    tracing::debug!("MC_uicAddListItem({:#x}, {:#x}, {})", obj.0, ptr_label, index);
+
+    add_item(context, obj, ptr_label, index)
+}
+
+pub async fn get_list_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<WIPICWord> {
+    tracing::debug!("MC_uicGetListItem({:#x}, {})", obj.0, index);
+
+    get_item(context, obj, index)
+}
+
+pub async fn remove_list_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<i32> {
+    tracing::debug!("MC_uicRemoveListItem({:#x}, {})", obj.0, index);
+
+    remove_item(context, obj, index)
+}
+
+pub async fn set_active_list_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<i32> {
+    tracing::debug!("MC_uicSetActiveListItem({:#x}, {})", obj.0, index);
+
+    set_active_item(context, obj, index)
+}
+
+pub async fn get_active_list_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("MC_uicGetActiveListItem({:#x})", obj.0);
+
+    get_active_item(context, obj)
+}
+
+pub async fn get_cursor_position(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("OEMC_uicGetCursorPosition({:#x})", obj.0);
+
+    let object = read_object(context, obj)?;
+
+    Ok(context
+        .system()
+        .input_methods()
+        .get(object.input_method)
+        .map(|x| x.cursor() as _)
+        .unwrap_or(M_E_INVALID))
+}
+
+pub async fn set_cursor_position(context: &mut dyn WIPICContext, obj: WIPICMemoryId, pos: i32) -> Result<i32> {
+    tracing::debug!("OEMC_uicSetCursorPosition({:#x}, {})", obj.0, pos);
+
+    let object = read_object(context, obj)?;
+
+    let mut input_methods = context.system().input_methods();
+    let Some(input_method) = input_methods.get(object.input_method) else {
+        return Ok(M_E_INVALID);
+    };
+    if pos < 0 {
+        return Ok(M_E_INVALID);
+    }
+
+    input_method.set_cursor(pos as _);
+
+    Ok(M_E_SUCCESS)
+}
+
+pub async fn set_line_gap(context: &mut dyn WIPICContext, obj: WIPICMemoryId, gap: i32) -> Result<()> {
+    tracing::debug!("OEMC_uicSetLineGap({:#x}, {})", obj.0, gap);
+
+    update_object(context, obj, |object| object.line_gap = gap)
+}
+
+pub async fn get_line_gap(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    tracing::debug!("OEMC_uicGetLineGap({:#x})", obj.0);
+
+    Ok(read_object(context, obj)?.line_gap)
+}
+
+fn read_object(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<WIPICUicObject> {
+    read_generic(context, context.data_ptr(obj)?)
+}
+
+fn write_object(context: &mut dyn WIPICContext, obj: WIPICMemoryId, object: &WIPICUicObject) -> Result<()> {
+    write_generic(context, context.data_ptr(obj)?, *object)
+}
+
+fn update_object<F>(context: &mut dyn WIPICContext, obj: WIPICMemoryId, f: F) -> Result<()>
+where
+    F: FnOnce(&mut WIPICUicObject),
+{
+    let mut object = read_object(context, obj)?;
+    f(&mut object);
+
+    write_object(context, obj, &object)
+}
+
+fn read_string(context: &mut dyn WIPICContext, ptr_string: WIPICWord) -> Result<String> {
+    let bytes = read_null_terminated_string_bytes(context, ptr_string)?;
+
+    Ok(encoding_rs::EUC_KR.decode(&bytes).0.to_string())
+}
+
+fn alloc_string(context: &mut dyn WIPICContext, string: &str) -> Result<WIPICMemoryId> {
+    let bytes = encoding_rs::EUC_KR.encode(string).0;
+
+    let memory = context.alloc(bytes.len() as WIPICWord + 1)?;
+    write_null_terminated_string_bytes(context, context.data_ptr(memory)?, &bytes)?;
+
+    Ok(memory)
+}
+
+fn free_if_allocated(context: &mut dyn WIPICContext, memory: WIPICMemoryId) -> Result<()> {
+    if memory.0 != 0 {
+        context.free(memory)?;
+    }
+
+    Ok(())
+}
+
+fn read_items(context: &mut dyn WIPICContext, object: &WIPICUicObject) -> Result<Vec<WIPICWord>> {
+    if object.item_count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let base = context.data_ptr(object.items)?;
+    (0..object.item_count)
+        .map(|i| read_generic(context, base + i * size_of::<WIPICWord>() as WIPICWord))
+        .collect()
+}
+
+// item array is reallocated on every change, as menus and lists are small
+fn write_items(context: &mut dyn WIPICContext, object: &mut WIPICUicObject, items: &[WIPICWord]) -> Result<()> {
+    free_if_allocated(context, object.items)?;
+
+    object.items = WIPICMemoryId(0);
+    object.item_count = items.len() as _;
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    object.items = context.alloc((items.len() * size_of::<WIPICWord>()) as WIPICWord)?;
+    let base = context.data_ptr(object.items)?;
+    for (i, item) in items.iter().enumerate() {
+        write_generic(context, base + (i * size_of::<WIPICWord>()) as WIPICWord, *item)?;
+    }
+
+    Ok(())
+}
+
+fn read_item_strings(context: &mut dyn WIPICContext, object: &WIPICUicObject) -> Result<Vec<String>> {
+    read_items(context, object)?
+        .into_iter()
+        .map(|x| {
+            let ptr = context.data_ptr(WIPICMemoryId(x))?;
+            read_string(context, ptr)
+        })
+        .collect()
+}
+
+// inserts item at the index, or appends if index is out of range. returns index of the new item
+fn add_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, ptr_label: WIPICWord, index: i32) -> Result<i32> {
+    let mut object = read_object(context, obj)?;
+    let mut items = read_items(context, &object)?;
+
+    let label = read_string(context, ptr_label)?;
+    let item = alloc_string(context, &label)?;
+
+    let index = if index >= 0 && (index as usize) < items.len() {
+        index as usize
+    } else {
+        items.len()
+    };
+    items.insert(index, item.0);
+
+    if (index as i32) <= object.active_item && items.len() > 1 {
+        object.active_item += 1;
+    }
+
+    write_items(context, &mut object, &items)?;
+    write_object(context, obj, &object)?;
+
+    Ok(index as _)
+}
+
+fn get_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<WIPICWord> {
+    let object = read_object(context, obj)?;
+    let items = read_items(context, &object)?;
+
+    match items.get(index as usize) {
+        Some(&item) if index >= 0 => context.data_ptr(WIPICMemoryId(item)),
+        _ => Ok(0),
+    }
+}
+
+fn remove_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<i32> {
+    let mut object = read_object(context, obj)?;
+    let mut items = read_items(context, &object)?;
+
+    if index < 0 || index as usize >= items.len() {
+        return Ok(M_E_INVALID);
+    }
+
+    let item = items.remove(index as _);
+    context.free(WIPICMemoryId(item))?;
+
+    if index < object.active_item || object.active_item as usize >= items.len() {
+        object.active_item = (object.active_item - 1).max(0);
+    }
+
+    write_items(context, &mut object, &items)?;
+    write_object(context, obj, &object)?;
+
+    Ok(M_E_SUCCESS)
+}
+
+fn set_active_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId, index: i32) -> Result<i32> {
+    let mut object = read_object(context, obj)?;
+    if index < 0 || index as WIPICWord >= object.item_count {
+        return Ok(M_E_INVALID);
+    }
+
+    object.active_item = index;
+    write_object(context, obj, &object)?;
+
+    Ok(M_E_SUCCESS)
+}
+
+fn get_active_item(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<i32> {
+    let object = read_object(context, obj)?;
+    if object.item_count == 0 {
+        return Ok(-1);
+    }
+
+    Ok(object.active_item)
+}
+
+fn editor_text(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<String> {
+    let object = read_object(context, obj)?;
+
+    Ok(context
+        .system()
+        .input_methods()
+        .get(object.input_method)
+        .map(|x| x.text())
+        .unwrap_or_default())
+}
+
+fn input_key(key: i32) -> Option<InputKey> {
+    Some(match key {
+        0x30..=0x39 => InputKey::Digit((key - 0x30) as u8),
+        0x2a => InputKey::Star,
+        0x23 => InputKey::Pound,
+        MC_KEY_LEFT => InputKey::Left,
+        MC_KEY_RIGHT => InputKey::Right,
+        MC_KEY_CLEAR => InputKey::Clear,
+        _ => return None,
+    })
+}
+
+// updates control state by key, returning whether the key is used and the event to notify to callback
+fn handle_key(context: &mut dyn WIPICContext, object: &mut WIPICUicObject, key: i32) -> Result<(bool, Option<WIPICWord>)> {
+    let Some(class) = object.class() else {
+        return Ok((false, None));
+    };
+
+    Ok(match class {
+        UicClass::Button if key == MC_KEY_SELECT => (true, Some(MC_UIC_EVENT_SELECTED)),
+        UicClass::CheckBox if key == MC_KEY_SELECT => {
+            object.checked ^= 1;
+            (true, Some(MC_UIC_EVENT_CHANGED))
+        }
+        UicClass::List | UicClass::Menu => {
+            let count = object.item_count as i32;
+            let active = match key {
+                // menu wraps around, list leaves the key to the app at its ends
+                MC_KEY_UP if class == UicClass::Menu => (object.active_item - 1).rem_euclid(count.max(1)),
+                MC_KEY_DOWN if class == UicClass::Menu => (object.active_item + 1).rem_euclid(count.max(1)),
+                MC_KEY_UP => (object.active_item - 1).max(0),
+                MC_KEY_DOWN => (object.active_item + 1).min(count - 1).max(0),
+                MC_KEY_SELECT if count > 0 => return Ok((true, Some(MC_UIC_EVENT_SELECTED))),
+                MC_KEY_CLEAR if class == UicClass::Menu => return Ok((true, Some(MC_UIC_EVENT_CANCELLED))),
+                _ => return Ok((false, None)),
+            };
+
+            if active == object.active_item {
+                (false, None)
+            } else {
+                object.active_item = active;
+                (true, Some(MC_UIC_EVENT_CHANGED))
+            }
+        }
+        UicClass::Edit if key == MC_KEY_SELECT => (true, Some(MC_UIC_EVENT_SELECTED)),
+        UicClass::Edit => {
+            let Some(input_key) = input_key(key) else {
+                return Ok((false, None));
+            };
+
+            let now = context.system().platform().now();
+            let handled = context
+                .system()
+                .input_methods()
+                .get(object.input_method)
+                .map(|x| x.handle_key(input_key, now))
+                .unwrap_or(false);
+
+            (handled, handled.then_some(MC_UIC_EVENT_CHANGED))
+        }
+        UicClass::Dialog if key == MC_KEY_SELECT => (true, Some(MC_UIC_EVENT_SELECTED)),
+        UicClass::Dialog if key == MC_KEY_CLEAR => (true, Some(MC_UIC_EVENT_CANCELLED)),
+        _ => (false, None),
+    })
+}
+
+async fn screen_framebuffer(context: &mut dyn WIPICContext) -> Result<WIPICFramebuffer> {
+    let memory = graphics::get_screen_framebuffer(context, 0).await?;
+
+    read_generic(context, context.data_ptr(memory)?)
+}
+
+// paints the control on the screen framebuffer and shows it on the screen
+async fn repaint_object(context: &mut dyn WIPICContext, obj: WIPICMemoryId) -> Result<()> {
+    let object = read_object(context, obj)?;
+    let framebuffer = screen_framebuffer(context).await?;
+
+    paint_object(context, &object, &framebuffer)?;
+
+    let image = framebuffer.image(context)?;
+    context.system().platform().screen().paint(&*image);
+
+    Ok(())
+}
+
+struct EditorState {
+    chars: Vec<char>,
+    cursor: usize,
+    composing: Range<usize>,
+    mode: &'static str,
+}
+
+fn paint_object(context: &mut dyn WIPICContext, object: &WIPICUicObject, framebuffer: &WIPICFramebuffer) -> Result<()> {
+    let Some(class) = object.class() else {
+        return Ok(());
+    };
+
+    let label = if object.label.0 != 0 {
+        let ptr = context.data_ptr(object.label)?;
+        read_string(context, ptr)?
+    } else {
+        String::new()
+    };
+    let items = read_item_strings(context, object)?;
+    let editor = context.system().input_methods().get(object.input_method).map(|x| EditorState {
+        chars: x.text().chars().collect(),
+        cursor: x.cursor(),
+        composing: x.composing().unwrap_or(0..0),
+        mode: x.mode().label(),
+    });
+
+    let mut canvas = framebuffer.canvas(context)?;
+    let mut painter = Painter {
+        canvas: &mut **canvas,
+        x: object.x,
+        y: object.y,
+        width: object.width,
+        height: object.height,
+        foreground: if object.enabled != 0 {
+            Rgb8Pixel::to_color(object.fg_pixel)
+        } else {
+            DISABLED
+        },
+        background: Rgb8Pixel::to_color(object.bg_pixel),
+    };
+
+    painter.fill_rect(object.x, object.y, object.width, object.height, painter.background);
+
+    let (x, y, width, height) = (object.x, object.y, object.width, object.height);
+    let line_y = y + (height - LINE_HEIGHT) / 2;
+    match class {
+        UicClass::Label => painter.draw_aligned_text(&label, line_y, object.label_alignment, painter.foreground),
+        UicClass::Button => {
+            painter.draw_rect(x, y, width, height, painter.foreground);
+            painter.draw_aligned_text(&label, line_y, MC_UIC_ALIGN_CENTER, painter.foreground);
+        }
+        UicClass::CheckBox => {
+            let size = LINE_HEIGHT - 4;
+            painter.draw_rect(x + PADDING, line_y + 2, size, size, painter.foreground);
+            if object.checked != 0 {
+                painter.fill_rect(x + PADDING + 2, line_y + 4, size - 4, size - 4, painter.foreground);
+            }
+            painter.draw_text(&label, x + PADDING * 3 + size, line_y, TextAlignment::Left, painter.foreground);
+        }
+        UicClass::Edit => {
+            painter.draw_rect(x, y, width, height, painter.foreground);
+            if let Some(editor) = editor {
+                painter.draw_editor(&editor, object.enabled != 0);
+            }
+        }
+        UicClass::List | UicClass::Menu => {
+            let mut top = y;
+            if class == UicClass::Menu {
+                painter.draw_rect(x, y, width, height, painter.foreground);
+            }
+            if !label.is_empty() {
+                painter.fill_rect(x, y, width, LINE_HEIGHT + PADDING * 2, painter.foreground);
+                painter.draw_aligned_text(&label, y + PADDING, object.label_alignment, painter.background);
+                top += LINE_HEIGHT + PADDING * 2;
+            }
+
+            painter.draw_items(&items, top, object.active_item, object.line_gap);
+        }
+        UicClass::Dialog => {
+            painter.draw_rect(x, y, width, height, painter.foreground);
+
+            let lines = wrap_text(&label, width - PADDING * 4);
+            let text_height = lines.len() as i32 * (LINE_HEIGHT + object.line_gap);
+            for (i, line) in lines.iter().enumerate() {
+                let line_y = y + (height - text_height) / 2 + i as i32 * (LINE_HEIGHT + object.line_gap);
+                painter.draw_text(line, x + width / 2, line_y, TextAlignment::Center, painter.foreground);
+            }
+        }
+        UicClass::Time => {
+            let text = format_time(object.time(), object.time_mask);
+            painter.draw_aligned_text(&text, line_y, object.label_alignment, painter.foreground);
+        }
+    }
+
+    Ok(())
+}
+
+// draws controls clipped to the control's area
+struct Painter<'a> {
+    canvas: &'a mut dyn Canvas,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    foreground: Color,
+    background: Color,
+}
+
+impl Painter<'_> {
+    fn clip(&self) -> Clip {
+        Clip {
+            x: self.x,
+            y: self.y,
+            width: self.width.max(0) as _,
+            height: self.height.max(0) as _,
+        }
+    }
+
+    fn fill_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        let clip = self.clip();
+        self.canvas.fill_rect(x, y, width as _, height as _, color, clip);
+    }
+
+    fn draw_rect(&mut self, x: i32, y: i32, width: i32, height: i32, color: Color) {
+        if width <= 0 || height <= 0 {
+            return;
+        }
+
+        let clip = self.clip();
+        self.canvas.draw_rect(x, y, width as _, height as _, color, clip);
+    }
+
+    // canvas doesn't clip text, so text is cut to the control's width and lines outside are skipped
+    fn draw_text(&mut self, text: &str, x: i32, y: i32, alignment: TextAlignment, color: Color) {
+        if y < self.y || y + LINE_HEIGHT > self.y + self.height {
+            return;
+        }
+
+        let text = fit_text(text, self.width - PADDING * 2);
+        self.canvas.draw_text(&text, x, y, alignment, color);
+    }
+
+    fn draw_aligned_text(&mut self, text: &str, y: i32, alignment: WIPICWord, color: Color) {
+        let (x, alignment) = match alignment {
+            MC_UIC_ALIGN_CENTER => (self.x + self.width / 2, TextAlignment::Center),
+            MC_UIC_ALIGN_RIGHT => (self.x + self.width - PADDING, TextAlignment::Right),
+            _ => (self.x + PADDING, TextAlignment::Left),
+        };
+
+        self.draw_text(text, x, y, alignment, color);
+    }
+
+    // draws items from the top, scrolled to keep the active item visible
+    fn draw_items(&mut self, items: &[String], top: i32, active: i32, line_gap: i32) {
+        let item_height = LINE_HEIGHT + PADDING * 2 + line_gap;
+        let visible = ((self.y + self.height - top) / item_height).max(1);
+        let first = (active - visible + 1).max(0);
+
+        for (i, item) in items.iter().enumerate().skip(first as _).take(visible as _) {
+            let item_y = top + (i as i32 - first) * item_height;
+            let color = if i as i32 == active {
+                self.fill_rect(self.x + 1, item_y, self.width - 2, item_height, HIGHLIGHT);
+                WHITE
+            } else {
+                self.foreground
+            };
+
+            self.draw_text(item, self.x + PADDING * 2, item_y + PADDING + line_gap / 2, TextAlignment::Left, color);
+        }
+    }
+
+    // single line editor scrolled to keep the caret visible, with input mode shown while enabled
+    fn draw_editor(&mut self, editor: &EditorState, focused: bool) {
+        let line_y = self.y + (self.height - LINE_HEIGHT) / 2;
+
+        let mut area_width = self.width - PADDING * 4;
+        if focused {
+            let label_width = text_width(editor.mode) + PADDING * 2;
+            let label_x = self.x + self.width - PADDING - label_width;
+
+            self.fill_rect(label_x, line_y, label_width, LINE_HEIGHT, HIGHLIGHT);
+            self.canvas.draw_text(editor.mode, label_x + PADDING, line_y, TextAlignment::Left, WHITE);
+            area_width -= label_width + PADDING;
+        }
+
+        let width_of = |range: Range<usize>| text_width(&editor.chars[range].iter().collect::<String>());
+
+        let mut first = 0;
+        while first < editor.cursor && width_of(first..editor.cursor) > area_width {
+            first += 1;
+        }
+        let mut last = first;
+        while last < editor.chars.len() && width_of(first..last + 1) <= area_width {
+            last += 1;
+        }
+
+        let text_x = self.x + PADDING * 2;
+        let text = editor.chars[first..last].iter().collect::<String>();
+        self.canvas.draw_text(&text, text_x, line_y, TextAlignment::Left, self.foreground);
+
+        let start = editor.composing.start.clamp(first, last);
+        let end = editor.composing.end.clamp(first, last);
+        if start < end {
+            let composing_x = text_x + width_of(first..start);
+            let composing = editor.chars[start..end].iter().collect::<String>();
+
+            self.fill_rect(composing_x, line_y, width_of(start..end), LINE_HEIGHT, HIGHLIGHT);
+            self.canvas.draw_text(&composing, composing_x, line_y, TextAlignment::Left, WHITE);
+        }
+
+        if focused {
+            let foreground = self.foreground;
+            self.fill_rect(text_x + width_of(first..editor.cursor), line_y + 1, 1, LINE_HEIGHT - 2, foreground);
+        }
+    }
+}
+
+fn text_width(text: &str) -> i32 {
+    string_width(text, FONT_SIZE) as _
+}
+
+fn fit_text(text: &str, width: i32) -> String {
+    let mut result = String::new();
+    for char in text.chars() {
+        result.push(char);
+        if text_width(&result) > width {
+            result.pop();
+            break;
+        }
+    }
+
+    result
+}
+
+// breaks text into lines which fit in width
+fn wrap_text(text: &str, width: i32) -> Vec<String> {
+    let mut result = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for char in paragraph.chars() {
+            line.push(char);
+            if line.chars().count() > 1 && text_width(&line) > width {
+                line.pop();
+                result.push(line.clone());
+                line.clear();
+                line.push(char);
+            }
+        }
+        result.push(line);
+    }
+
+    result
+}
+
+// formats milliseconds since epoch in korea standard time, showing date and time as selected by mask
+fn format_time(time: u64, mask: WIPICWord) -> String {
+    let seconds = (time + TIME_OFFSET) / 1000;
+    let (year, month, day) = civil_from_days((seconds / 86400) as _);
+    let (hour, minute) = (seconds / 3600 % 24, seconds / 60 % 60);
+
+    match (mask & MC_UIC_TIME_DATE != 0, mask & MC_UIC_TIME_TIME != 0) {
+        (true, false) => format!("{year:04}/{month:02}/{day:02}"),
+        (false, true) => format!("{hour:02}:{minute:02}"),
+        _ => format!("{year:04}/{month:02}/{day:02} {hour:02}:{minute:02}"),
+    }
+}
+
+// gregorian calendar date of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use test_utils::TestPlatform;
+    use wie_backend::{DefaultTaskRunner, System};
+    use wie_util::{Result, read_null_terminated_string_bytes, write_null_terminated_string_bytes};
+
+    use crate::{WIPICContext, WIPICMemoryId, context::test::TestContext};
+
+    use super::{
+        UicClass, add_list_item, create, get_active_list_item, get_list_item, get_text, get_text_size, handle_key, insert_text, read_object,
+        remove_list_item, set_active_list_item,
+    };
+
+    fn write_string(context: &mut TestContext, string: &str) -> u32 {
+        let address = context.alloc_raw(string.len() as u32 + 1).unwrap();
+        write_null_terminated_string_bytes(context, address, string.as_bytes()).unwrap();
+
+        address
+    }
+
+    #[futures_test::test]
+    async fn test_list_items() -> Result<()> {
+        let system = System::new(Box::new(TestPlatform::new()), "", "", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system);
+        context.alloc_raw(4)?; // keep memory id 0 unused
+
+        let list = create(&mut context, WIPICMemoryId(0), UicClass::List as _).await?;
+
+        for item in ["first", "second", "third"] {
+            let ptr = write_string(&mut context, item);
+            add_list_item(&mut context, list, ptr, -1).await?;
+        }
+        let ptr = write_string(&mut context, "zeroth");
+        assert_eq!(add_list_item(&mut context, list, ptr, 0).await?, 0);
+
+        assert_eq!(set_active_list_item(&mut context, list, 2).await?, 0);
+        assert_eq!(set_active_list_item(&mut context, list, 4).await?, -9);
+
+        let ptr = get_list_item(&mut context, list, 2).await?;
+        assert_eq!(read_null_terminated_string_bytes(&context, ptr)?, b"second");
+
+        // removing an item above keeps the same item active
+        remove_list_item(&mut context, list, 0).await?;
+        assert_eq!(get_active_list_item(&mut context, list).await?, 1);
+        assert_eq!(get_list_item(&mut context, list, 3).await?, 0);
+
+        let mut object = read_object(&mut context, list)?;
+        assert_eq!(handle_key(&mut context, &mut object, -2)?, (true, Some(2)));
+        assert_eq!(handle_key(&mut context, &mut object, -2)?, (false, None));
+        assert_eq!(object.active_item, 2);
+
+        Ok(())
+    }
+
+    #[futures_test::test]
+    async fn test_edit_text() -> Result<()> {
+        let system = System::new(Box::new(TestPlatform::new()), "", "", DefaultTaskRunner);
+        let mut context = TestContext::with_system(system);
+        context.alloc_raw(4)?;
+
+        let edit = create(&mut context, WIPICMemoryId(0), UicClass::Edit as _).await?;
+
+        let ptr = write_string(&mut context, "wipi");
+        insert_text(&mut context, edit, -1, ptr, -1).await?;
+        insert_text(&mut context, edit, 0, ptr, 2).await?;
+
+        let mut object = read_object(&mut context, edit)?;
+        assert_eq!(handle_key(&mut context, &mut object, -1)?, (false, None));
+        assert_eq!(handle_key(&mut context, &mut object, -16)?, (true, Some(2)));
+
+        assert_eq!(get_text_size(&mut context, edit).await?, 5);
+
+        let buf = context.alloc_raw(4)?;
+        assert_eq!(get_text(&mut context, edit, buf, 4).await?, 3);
+        assert_eq!(read_null_terminated_string_bytes(&context, buf)?, b"wwi");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(super::format_time(0, 3), "1970/01/01 09:00");
+        assert_eq!(super::format_time(951_782_400_000, 1), "2000/02/29");
+        assert_eq!(super::format_time(1_700_000_000_000, 2), "07:13");
+    }
 }
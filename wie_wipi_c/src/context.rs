@@ -1,6 +1,7 @@
 use alloc::{boxed::Box, vec, vec::Vec};
 
-use wie_backend::{Instant, System};
+use wie_backend::{Instant, ProgramId, System, TimerId};
+use wie_libc::printf::VarArgs;
 use wie_util::{ByteRead, ByteWrite, Result};
 
 use crate::{
@@ -17,11 +18,16 @@ pub trait WIPICContext: ByteRead + ByteWrite + Send {
     fn data_ptr(&self, memory: WIPICMemoryId) -> Result<WIPICWord>;
     fn register_function(&mut self, method: WIPICMethodBody) -> Result<WIPICWord>;
     async fn call_function(&mut self, address: WIPICWord, args: &[WIPICWord]) -> Result<WIPICWord>;
+    /// Reads variadic arguments of current native call, starting from argument slot `first_slot`
+    fn var_args(&self, first_slot: usize) -> Box<dyn VarArgs + '_>;
     fn system(&mut self) -> &mut System;
     fn spawn(&mut self, callback: WIPICMethodBody) -> Result<()>;
     async fn get_resource_size(&self, name: &str) -> Result<Option<usize>>;
     async fn read_resource(&self, name: &str) -> Result<Vec<u8>>;
-    fn set_timer(&mut self, due: Instant, callback: WIPICMethodBody);
+    /// Schedules callback on due, replacing pending timer with same id
+    fn set_timer(&mut self, id: TimerId, due: Instant, callback: WIPICMethodBody);
+    /// Loads executable of sub program or library from resource, returns entry point to call
+    async fn load_executable(&mut self, program: ProgramId, path: &str) -> Result<WIPICWord>;
 }
 
 pub struct WIPICResult {
@@ -80,16 +86,31 @@ impl ResultConverter<()> for () {
 
 #[cfg(test)]
 pub mod test {
-    use alloc::{boxed::Box, vec::Vec};
+    use alloc::{
+        boxed::Box,
+        collections::BTreeMap,
+        string::{String, ToString},
+        vec::Vec,
+    };
 
-    use wie_backend::{Instant, System};
-    use wie_util::{ByteRead, ByteWrite, Result};
+    use wie_backend::{Instant, ProgramId, System, TimerId};
+    use wie_libc::printf::{VarArgs, WordArgs};
+    use wie_util::{ByteRead, ByteWrite, Result, WieError};
 
     use crate::{WIPICContext, WIPICMemoryId, WIPICMethodBody, WIPICWord};
 
+    // entry of executable loaded by test context is this plus program id
+    pub const TEST_ENTRY_BASE: WIPICWord = 0x1000;
+
     pub struct TestContext {
         memory: [u8; 0x10000],
         last_alloc: usize,
+        system: Option<System>,
+        resources: BTreeMap<String, Vec<u8>>,
+        pub spawned: Vec<WIPICMethodBody>,
+        pub loaded: Vec<(ProgramId, String)>,
+        // arguments of current call, including variadic ones
+        pub call_args: Vec<WIPICWord>,
     }
 
     impl TestContext {
@@ -98,6 +119,22 @@ pub mod test {
             Self {
                 memory: [0; 0x10000],
                 last_alloc: 0,
+                system: None,
+                resources: BTreeMap::new(),
+                spawned: Vec::new(),
+                loaded: Vec::new(),
+                call_args: Vec::new(),
+            }
+        }
+
+        pub fn add_resource(&mut self, name: &str, data: &[u8]) {
+            self.resources.insert(name.to_string(), data.to_vec());
+        }
+
+        pub fn with_system(system: System) -> Self {
+            Self {
+                system: Some(system),
+                ..Self::new()
             }
         }
     }
@@ -106,6 +143,9 @@ pub mod test {
     impl WIPICContext for TestContext {
         fn alloc_raw(&mut self, size: WIPICWord) -> Result<WIPICWord> {
             let address = self.last_alloc;
+            if address + size as usize > self.memory.len() {
+                return Err(WieError::AllocationFailure);
+            }
             self.last_alloc += size as usize;
 
             Ok(address as WIPICWord)
@@ -135,24 +175,37 @@ pub mod test {
             todo!()
         }
 
+        fn var_args(&self, first_slot: usize) -> Box<dyn VarArgs + '_> {
+            Box::new(WordArgs::new(self.call_args.get(first_slot..).unwrap_or_default(), first_slot))
+        }
+
         fn system(&mut self) -> &mut System {
-            todo!()
+            self.system.as_mut().unwrap()
         }
 
-        fn spawn(&mut self, _callback: WIPICMethodBody) -> Result<()> {
-            todo!()
+        fn spawn(&mut self, callback: WIPICMethodBody) -> Result<()> {
+            self.spawned.push(callback);
+
+            Ok(())
         }
 
-        async fn get_resource_size(&self, _name: &str) -> Result<Option<usize>> {
-            todo!()
+        async fn get_resource_size(&self, name: &str) -> Result<Option<usize>> {
+            Ok(self.resources.get(name).map(|x| x.len()))
         }
 
-        async fn read_resource(&self, _name: &str) -> Result<Vec<u8>> {
-            todo!()
+        async fn read_resource(&self, name: &str) -> Result<Vec<u8>> {
+            Ok(self.resources[name].clone())
         }
 
-        fn set_timer(&mut self, _due: Instant, _callback: WIPICMethodBody) {
-            todo!()
+        // test context can't call app functions, so timers are only queued without running the callback
+        fn set_timer(&mut self, id: TimerId, due: Instant, _callback: WIPICMethodBody) {
+            self.system().event_queue().set_timer(id, due, || async { Ok(()) });
+        }
+
+        async fn load_executable(&mut self, program: ProgramId, path: &str) -> Result<WIPICWord> {
+            self.loaded.push((program, path.to_string()));
+
+            Ok(TEST_ENTRY_BASE + program)
         }
     }
 
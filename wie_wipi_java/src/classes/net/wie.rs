@@ -1,7 +1,11 @@
 mod card_canvas;
+mod shell_card;
+mod wipi_http_connection_impl;
 mod wipi_midlet;
 
 pub use self::{
     card_canvas::{CardCanvas, WIPIKeyCode},
+    shell_card::ShellCard,
+    wipi_http_connection_impl::WIPIHttpConnectionImpl,
     wipi_midlet::WIPIMIDlet,
 };
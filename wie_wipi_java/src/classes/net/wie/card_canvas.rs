@@ -1,10 +1,15 @@
 use alloc::vec;
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::util::Vector;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::InputKey;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
-use wie_midp::classes::{javax::microedition::lcdui::Graphics, net::wie::MIDPKeyCode};
+use wie_midp::classes::{
+    javax::microedition::lcdui::Graphics,
+    net::wie::{MIDPKeyCode, PointerEventType},
+};
 
 use crate::classes::org::kwis::msp::lcdui::Card;
 
@@ -44,6 +49,37 @@ impl WIPIKeyCode {
         unsafe { core::mem::transmute(value) }
     }
 
+    // for key codes from guest, which may not be one of the known keys
+    pub fn try_from_raw(value: i32) -> Option<Self> {
+        Some(match value {
+            -1 => Self::UP,
+            -2 => Self::DOWN,
+            -3 => Self::LEFT,
+            -4 => Self::RIGHT,
+            -5 => Self::FIRE,
+            -6 => Self::LEFT_SOFT_KEY,
+            -7 => Self::RIGHT_SOFT_KEY,
+            -16 => Self::CLEAR,
+            -10 => Self::CALL,
+            -11 => Self::HANGUP,
+            -13 => Self::VOLUME_UP,
+            -14 => Self::VOLUME_DOWN,
+            48 => Self::NUM0,
+            49 => Self::NUM1,
+            50 => Self::NUM2,
+            51 => Self::NUM3,
+            52 => Self::NUM4,
+            53 => Self::NUM5,
+            54 => Self::NUM6,
+            55 => Self::NUM7,
+            56 => Self::NUM8,
+            57 => Self::NUM9,
+            35 => Self::HASH,
+            42 => Self::STAR,
+            _ => return None,
+        })
+    }
+
     pub fn from_midp_key_code(keycode: MIDPKeyCode) -> Self {
         match keycode {
             MIDPKeyCode::UP => Self::UP,
@@ -72,6 +108,21 @@ impl WIPIKeyCode {
             MIDPKeyCode::KEY_STAR => Self::STAR,
         }
     }
+
+    // keys used for keypad text input
+    pub fn to_input_key(self) -> Option<InputKey> {
+        Some(match self {
+            Self::NUM0 | Self::NUM1 | Self::NUM2 | Self::NUM3 | Self::NUM4 | Self::NUM5 | Self::NUM6 | Self::NUM7 | Self::NUM8 | Self::NUM9 => {
+                InputKey::Digit((self as i32 - Self::NUM0 as i32) as u8)
+            }
+            Self::STAR => InputKey::Star,
+            Self::HASH => InputKey::Pound,
+            Self::LEFT => InputKey::Left,
+            Self::RIGHT => InputKey::Right,
+            Self::CLEAR => InputKey::Clear,
+            _ => return None,
+        })
+    }
 }
 
 // class net.wie.CardCanvas
@@ -89,8 +140,26 @@ impl CardCanvas {
                 JavaMethodProto::new("keyPressed", "(I)V", Self::key_pressed, Default::default()),
                 JavaMethodProto::new("keyRepeated", "(I)V", Self::key_repeated, Default::default()),
                 JavaMethodProto::new("keyReleased", "(I)V", Self::key_released, Default::default()),
+                JavaMethodProto::new("pointerPressed", "(II)V", Self::pointer_pressed, Default::default()),
+                JavaMethodProto::new("pointerReleased", "(II)V", Self::pointer_released, Default::default()),
+                JavaMethodProto::new("pointerDragged", "(II)V", Self::pointer_dragged, Default::default()),
                 JavaMethodProto::new("pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", Self::push_card, Default::default()),
+                JavaMethodProto::new("popCard", "()V", Self::pop_card, Default::default()),
+                JavaMethodProto::new(
+                    "insertCard",
+                    "(Lorg/kwis/msp/lcdui/Card;Lorg/kwis/msp/lcdui/Card;)V",
+                    Self::insert_card,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("removeCard", "(Lorg/kwis/msp/lcdui/Card;)V", Self::remove_card, Default::default()),
                 JavaMethodProto::new("removeAllCards", "()V", Self::remove_all_cards, Default::default()),
+                JavaMethodProto::new("getTopCard", "()Lorg/kwis/msp/lcdui/Card;", Self::get_top_card, Default::default()),
+                JavaMethodProto::new(
+                    "getNextCard",
+                    "(Lorg/kwis/msp/lcdui/Card;)Lorg/kwis/msp/lcdui/Card;",
+                    Self::get_next_card,
+                    Default::default(),
+                ),
                 // wie private
                 JavaMethodProto::new("handleNotifyEvent", "(III)V", Self::handle_notify_event, Default::default()),
             ],
@@ -112,6 +181,7 @@ impl CardCanvas {
         Ok(())
     }
 
+    // cards are painted from the bottom of the stack, so overlay cards are drawn over the cards below them
     async fn paint(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, g: ClassInstanceRef<Graphics>) -> JvmResult<()> {
         tracing::debug!("net.wie.CardCanvas::paint({:?}, {:?})", this, g);
 
@@ -119,14 +189,34 @@ impl CardCanvas {
             .new_class("org/kwis/msp/lcdui/Graphics", "(Ljavax/microedition/lcdui/Graphics;)V", (g,))
             .await?;
 
+        let clip_x: i32 = jvm.invoke_virtual(&graphics, "getClipX", "()I", ()).await?;
+        let clip_y: i32 = jvm.invoke_virtual(&graphics, "getClipY", "()I", ()).await?;
+        let clip_width: i32 = jvm.invoke_virtual(&graphics, "getClipWidth", "()I", ()).await?;
+        let clip_height: i32 = jvm.invoke_virtual(&graphics, "getClipHeight", "()I", ()).await?;
+
         let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
         let length = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
 
         for i in 0..length {
-            let card = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
+            let card: ClassInstanceRef<Card> = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
+            let card_x: i32 = jvm.get_field(&card, "x", "I").await?;
+            let card_y: i32 = jvm.get_field(&card, "y", "I").await?;
+            let card_width: i32 = jvm.get_field(&card, "w", "I").await?;
+            let card_height: i32 = jvm.get_field(&card, "h", "I").await?;
+
+            let _: () = jvm.invoke_virtual(&graphics, "translate", "(II)V", (card_x, card_y)).await?;
+            let _: () = jvm
+                .invoke_virtual(&graphics, "clipRect", "(IIII)V", (0, 0, card_width, card_height))
+                .await?;
+
             let _: () = jvm
                 .invoke_virtual(&card, "paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", (graphics.clone(),))
                 .await?;
+
+            let _: () = jvm.invoke_virtual(&graphics, "translate", "(II)V", (-card_x, -card_y)).await?;
+            let _: () = jvm
+                .invoke_virtual(&graphics, "setClip", "(IIII)V", (clip_x, clip_y, clip_width, clip_height))
+                .await?;
         }
 
         Ok(())
@@ -135,95 +225,230 @@ impl CardCanvas {
     async fn key_pressed(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
         tracing::debug!("net.wie.CardCanvas::keyPressed({:?}, {})", this, key_code);
 
-        let key_code = WIPIKeyCode::from_midp_key_code(MIDPKeyCode::from_raw(key_code));
+        Self::key_notify(jvm, &this, 1, key_code).await
+    }
 
-        let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
-        let length = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+    async fn key_repeated(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::keyRepeated({:?}, {})", this, key_code);
 
-        for i in 0..length {
-            let card = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
-            let propagate: bool = jvm.invoke_virtual(&card, "keyNotify", "(II)Z", (1i32, key_code as i32)).await?;
+        Self::key_notify(jvm, &this, 3, key_code).await
+    }
 
-            if !propagate {
-                break;
-            }
-        }
+    async fn key_released(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::keyReleased({:?}, {})", this, key_code);
 
-        Ok(())
+        Self::key_notify(jvm, &this, 2, key_code).await
     }
 
-    async fn key_repeated(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
-        tracing::debug!("net.wie.CardCanvas::keyRepeated({:?}, {})", this, key_code);
+    async fn pointer_pressed(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::pointerPressed({:?}, {}, {})", this, x, y);
 
-        let key_code = WIPIKeyCode::from_midp_key_code(MIDPKeyCode::from_raw(key_code));
+        Self::pointer_notify(jvm, &this, PointerEventType::PointerPressed, x, y).await
+    }
 
-        let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
-        let length = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+    async fn pointer_released(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::pointerReleased({:?}, {}, {})", this, x, y);
 
-        for i in 0..length {
-            let card = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
-            let propagate: bool = jvm.invoke_virtual(&card, "keyNotify", "(II)Z", (3i32, key_code as i32)).await?;
+        Self::pointer_notify(jvm, &this, PointerEventType::PointerReleased, x, y).await
+    }
 
-            if !propagate {
-                break;
-            }
-        }
+    async fn pointer_dragged(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::pointerDragged({:?}, {}, {})", this, x, y);
 
-        Ok(())
+        Self::pointer_notify(jvm, &this, PointerEventType::PointerDragged, x, y).await
     }
 
-    async fn key_released(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, key_code: i32) -> JvmResult<()> {
-        tracing::debug!("net.wie.CardCanvas::keyReleased({:?}, {})", this, key_code);
+    async fn push_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, c: ClassInstanceRef<Card>) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::pushCard({:?}, {:?})", &this, &c);
 
-        let key_code = WIPIKeyCode::from_midp_key_code(MIDPKeyCode::from_raw(key_code));
+        if c.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "card is null").await);
+        }
 
-        let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
-        let length = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+        // pushing a card already in the stack moves it to the top
+        Self::detach_card(jvm, &this, &c).await?;
 
-        for i in 0..length {
-            let card = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
-            let propagate: bool = jvm.invoke_virtual(&card, "keyNotify", "(II)Z", (2i32, key_code as i32)).await?;
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+        let previous_top = Self::top_card(jvm, &cards).await?;
+        if !previous_top.is_null() {
+            Self::set_shown(jvm, &previous_top, false).await?;
+        }
 
-            if !propagate {
-                break;
-            }
+        let _: () = jvm.invoke_virtual(&cards, "addElement", "(Ljava/lang/Object;)V", (c.clone(),)).await?;
+
+        let _: () = jvm
+            .invoke_virtual(&c, "setCanvas", "(Ljavax/microedition/lcdui/Canvas;)V", (this.clone(),))
+            .await?;
+        Self::set_shown(jvm, &c, true).await?;
+
+        let _: () = jvm.invoke_virtual(&this, "repaint", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn pop_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::popCard({:?})", &this);
+
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+        let top = Self::top_card(jvm, &cards).await?;
+        if top.is_null() {
+            return Ok(());
         }
 
+        let _: () = jvm.invoke_virtual(&this, "removeCard", "(Lorg/kwis/msp/lcdui/Card;)V", (top,)).await?;
+
         Ok(())
     }
 
-    async fn push_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, c: ClassInstanceRef<Card>) -> JvmResult<()> {
-        tracing::debug!("net.wie.CardCanvas::pushCard({:?}, {:?})", &this, &c);
+    // inserts card right above `next`, or to the bottom of the stack if `next` is null
+    async fn insert_card(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        c: ClassInstanceRef<Card>,
+        next: ClassInstanceRef<Card>,
+    ) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::insertCard({:?}, {:?}, {:?})", &this, &c, &next);
 
-        let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
-        let _: () = jvm.invoke_virtual(&cards, "addElement", "(Ljava/lang/Object;)V", (c.clone(),)).await?;
+        if c.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "card is null").await);
+        }
+
+        Self::detach_card(jvm, &this, &c).await?;
+
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+        let index = if next.is_null() {
+            0
+        } else {
+            match Self::index_of(jvm, &cards, &next).await? {
+                Some(x) => x + 1,
+                None => return Err(jvm.exception("java/lang/IllegalArgumentException", "card is not in the stack").await),
+            }
+        };
+
+        let size: i32 = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+        if index == size {
+            return jvm.invoke_virtual(&this, "pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", (c,)).await;
+        }
 
+        let _: () = jvm
+            .invoke_virtual(&cards, "insertElementAt", "(Ljava/lang/Object;I)V", (c.clone(), index))
+            .await?;
         let _: () = jvm
             .invoke_virtual(&c, "setCanvas", "(Ljavax/microedition/lcdui/Canvas;)V", (this.clone(),))
             .await?;
-        let _: () = jvm.invoke_virtual(&c, "showNotify", "(Z)V", (true,)).await?;
 
         let _: () = jvm.invoke_virtual(&this, "repaint", "()V", ()).await?;
 
         Ok(())
     }
 
+    async fn remove_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, c: ClassInstanceRef<Card>) -> JvmResult<()> {
+        tracing::debug!("net.wie.CardCanvas::removeCard({:?}, {:?})", &this, &c);
+
+        if c.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "card is null").await);
+        }
+
+        if Self::detach_card(jvm, &this, &c).await? {
+            let _: () = jvm.invoke_virtual(&this, "repaint", "()V", ()).await?;
+        }
+
+        Ok(())
+    }
+
     async fn remove_all_cards(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
         tracing::debug!("net.wie.CardCanvas::removeAllCards");
 
-        let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
-        let length = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+        let top = Self::top_card(jvm, &cards).await?;
+        if !top.is_null() {
+            Self::set_shown(jvm, &top, false).await?;
+        }
 
+        let length = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
         for i in 0..length {
-            let card = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
+            let card: ClassInstanceRef<Card> = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
             let _: () = jvm
                 .invoke_virtual(&card, "setCanvas", "(Ljavax/microedition/lcdui/Canvas;)V", (None,))
                 .await?;
-            let _: () = jvm.invoke_virtual(&card, "showNotify", "(Z)V", (false,)).await?;
         }
 
         let _: () = jvm.invoke_virtual(&cards, "removeAllElements", "()V", ()).await?;
 
+        let _: () = jvm.invoke_virtual(&this, "repaint", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn get_top_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Card>> {
+        tracing::debug!("net.wie.CardCanvas::getTopCard({:?})", &this);
+
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+
+        Self::top_card(jvm, &cards).await
+    }
+
+    // returns the card right below `c`
+    async fn get_next_card(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        c: ClassInstanceRef<Card>,
+    ) -> JvmResult<ClassInstanceRef<Card>> {
+        tracing::debug!("net.wie.CardCanvas::getNextCard({:?}, {:?})", &this, &c);
+
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+        match Self::index_of(jvm, &cards, &c).await? {
+            Some(index) if index > 0 => jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (index - 1,)).await,
+            _ => Ok(None.into()),
+        }
+    }
+
+    // key events are delivered from the top card, until a card handles it
+    async fn key_notify(jvm: &Jvm, this: &ClassInstanceRef<Self>, r#type: i32, key_code: i32) -> JvmResult<()> {
+        let key_code = WIPIKeyCode::from_midp_key_code(MIDPKeyCode::from_raw(key_code));
+
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(this, "cards", "Ljava/util/Vector;").await?;
+        let length: i32 = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+
+        for i in (0..length).rev() {
+            let card: ClassInstanceRef<Card> = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
+            let handled: bool = jvm.invoke_virtual(&card, "keyNotify", "(II)Z", (r#type, key_code as i32)).await?;
+
+            if handled {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    // pointer position is passed relative to each card, and only cards under the pointer receive the event
+    async fn pointer_notify(jvm: &Jvm, this: &ClassInstanceRef<Self>, r#type: PointerEventType, x: i32, y: i32) -> JvmResult<()> {
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(this, "cards", "Ljava/util/Vector;").await?;
+        let length: i32 = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+
+        for i in (0..length).rev() {
+            let card: ClassInstanceRef<Card> = jvm.invoke_virtual(&cards, "elementAt", "(I)Ljava/lang/Object;", (i,)).await?;
+            let card_x: i32 = jvm.get_field(&card, "x", "I").await?;
+            let card_y: i32 = jvm.get_field(&card, "y", "I").await?;
+            let card_width: i32 = jvm.get_field(&card, "w", "I").await?;
+            let card_height: i32 = jvm.get_field(&card, "h", "I").await?;
+
+            if x < card_x || y < card_y || x >= card_x + card_width || y >= card_y + card_height {
+                continue;
+            }
+
+            let handled: bool = jvm
+                .invoke_virtual(&card, "pointerNotify", "(III)Z", (r#type as i32, x - card_x, y - card_y))
+                .await?;
+
+            if handled {
+                break;
+            }
+        }
+
         Ok(())
     }
 
@@ -237,11 +462,244 @@ impl CardCanvas {
     ) -> JvmResult<()> {
         tracing::debug!("net.wie.CardCanvas::handleNotifyEvent({this:?}, {}, {param1}, {param2})", r#type);
 
-        let cards = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
-        let top_card = jvm.invoke_virtual(&cards, "firstElement", "()Ljava/lang/Object;", ()).await?;
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(&this, "cards", "Ljava/util/Vector;").await?;
+        let is_empty: bool = jvm.invoke_virtual(&cards, "isEmpty", "()Z", ()).await?;
+        if is_empty {
+            return Ok(());
+        }
 
-        let _: () = jvm.invoke_virtual(&top_card, "notifyEvent", "(III)V", (r#type, param1, param2)).await?;
+        let bottom_card = jvm.invoke_virtual(&cards, "firstElement", "()Ljava/lang/Object;", ()).await?;
+
+        let _: () = jvm
+            .invoke_virtual(&bottom_card, "notifyEvent", "(III)V", (r#type, param1, param2))
+            .await?;
 
         Ok(())
     }
+
+    // removes card from the stack if it's in, notifying the visibility change if it was the top card
+    async fn detach_card(jvm: &Jvm, this: &ClassInstanceRef<Self>, card: &ClassInstanceRef<Card>) -> JvmResult<bool> {
+        let cards: ClassInstanceRef<Vector> = jvm.get_field(this, "cards", "Ljava/util/Vector;").await?;
+        let Some(index) = Self::index_of(jvm, &cards, card).await? else {
+            return Ok(false);
+        };
+
+        let size: i32 = jvm.invoke_virtual(&cards, "size", "()I", ()).await?;
+        let _: () = jvm.invoke_virtual(&cards, "removeElementAt", "(I)V", (index,)).await?;
+        let _: () = jvm
+            .invoke_virtual(card, "setCanvas", "(Ljavax/microedition/lcdui/Canvas;)V", (None,))
+            .await?;
+
+        if index == size - 1 {
+            Self::set_shown(jvm, card, false).await?;
+
+            let new_top = Self::top_card(jvm, &cards).await?;
+            if !new_top.is_null() {
+                Self::set_shown(jvm, &new_top, true).await?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn top_card(jvm: &Jvm, cards: &ClassInstanceRef<Vector>) -> JvmResult<ClassInstanceRef<Card>> {
+        let is_empty: bool = jvm.invoke_virtual(cards, "isEmpty", "()Z", ()).await?;
+        if is_empty {
+            return Ok(None.into());
+        }
+
+        jvm.invoke_virtual(cards, "lastElement", "()Ljava/lang/Object;", ()).await
+    }
+
+    async fn index_of(jvm: &Jvm, cards: &ClassInstanceRef<Vector>, card: &ClassInstanceRef<Card>) -> JvmResult<Option<i32>> {
+        if card.is_null() {
+            return Ok(None);
+        }
+
+        let size: i32 = jvm.invoke_virtual(cards, "size", "()I", ()).await?;
+        for index in 0..size {
+            let item: ClassInstanceRef<Card> = jvm.invoke_virtual(cards, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let same: bool = jvm.invoke_virtual(&item, "equals", "(Ljava/lang/Object;)Z", (card.clone(),)).await?;
+            if same {
+                return Ok(Some(index));
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn set_shown(jvm: &Jvm, card: &ClassInstanceRef<Card>, shown: bool) -> JvmResult<()> {
+        let mut card = card.clone();
+        jvm.put_field(&mut card, "shown", "Z", shown).await?;
+
+        jvm.invoke_virtual(&card, "showNotify", "(Z)V", (shown,)).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, vec};
+
+    use java_class_proto::{JavaFieldProto, JavaMethodProto};
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+    use test_utils::run_jvm_test;
+    use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+    use wie_util::Result;
+
+    use crate::{
+        classes::org::kwis::msp::lcdui::{Card, Graphics},
+        get_protos,
+    };
+
+    use super::WIPIKeyCode;
+
+    // records notifications delivered to the card
+    struct TestCard;
+
+    impl TestCard {
+        fn as_proto() -> WieJavaClassProto {
+            WieJavaClassProto {
+                name: "net/wie/TestCard",
+                parent_class: Some("org/kwis/msp/lcdui/Card"),
+                interfaces: vec![],
+                methods: vec![
+                    JavaMethodProto::new("<init>", "(Z)V", Self::init, Default::default()),
+                    JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+                    JavaMethodProto::new("showNotify", "(Z)V", Self::show_notify, Default::default()),
+                    JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                ],
+                fields: vec![
+                    JavaFieldProto::new("handleKeys", "Z", Default::default()),
+                    JavaFieldProto::new("showCount", "I", Default::default()),
+                    JavaFieldProto::new("hideCount", "I", Default::default()),
+                    JavaFieldProto::new("lastKey", "I", Default::default()),
+                ],
+                access_flags: Default::default(),
+            }
+        }
+
+        // Card(Display) requires running jlet, so we bypass it
+        async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, handle_keys: bool) -> JvmResult<()> {
+            let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
+
+            jvm.put_field(&mut this, "w", "I", 240).await?;
+            jvm.put_field(&mut this, "h", "I", 320).await?;
+            jvm.put_field(&mut this, "handleKeys", "Z", handle_keys).await?;
+
+            Ok(())
+        }
+
+        async fn paint(_: &Jvm, _: &mut WieJvmContext, _: ClassInstanceRef<Self>, _: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+            Ok(())
+        }
+
+        async fn show_notify(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, shown: bool) -> JvmResult<()> {
+            let field = if shown { "showCount" } else { "hideCount" };
+            let count: i32 = jvm.get_field(&this, field, "I").await?;
+
+            jvm.put_field(&mut this, field, "I", count + 1).await
+        }
+
+        async fn key_notify(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, _: i32, key: i32) -> JvmResult<bool> {
+            jvm.put_field(&mut this, "lastKey", "I", key).await?;
+
+            jvm.get_field(&this, "handleKeys", "Z").await
+        }
+    }
+
+    async fn counts(jvm: &Jvm, card: &ClassInstanceRef<Card>) -> JvmResult<(i32, i32, bool)> {
+        let show_count: i32 = jvm.get_field(card, "showCount", "I").await?;
+        let hide_count: i32 = jvm.get_field(card, "hideCount", "I").await?;
+        let shown: bool = jvm.invoke_virtual(card, "isShown", "()Z", ()).await?;
+
+        Ok((show_count, hide_count, shown))
+    }
+
+    async fn top_card(jvm: &Jvm, canvas: &ClassInstanceRef<super::CardCanvas>) -> JvmResult<ClassInstanceRef<Card>> {
+        jvm.invoke_virtual(canvas, "getTopCard", "()Lorg/kwis/msp/lcdui/Card;", ()).await
+    }
+
+    fn protos() -> Box<[Box<[WieJavaClassProto]>]> {
+        Box::new([wie_midp::get_protos().into(), get_protos().into(), Box::new([TestCard::as_proto()])])
+    }
+
+    #[test]
+    fn test_card_stack() -> Result<()> {
+        run_jvm_test(protos(), |jvm| async move {
+            let canvas: ClassInstanceRef<super::CardCanvas> = jvm.new_class("net/wie/CardCanvas", "()V", ()).await?.into();
+            let bottom: ClassInstanceRef<Card> = jvm.new_class("net/wie/TestCard", "(Z)V", (true,)).await?.into();
+            let top: ClassInstanceRef<Card> = jvm.new_class("net/wie/TestCard", "(Z)V", (false,)).await?.into();
+            let middle: ClassInstanceRef<Card> = jvm.new_class("net/wie/TestCard", "(Z)V", (false,)).await?.into();
+
+            let _: () = jvm
+                .invoke_virtual(&canvas, "pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", (bottom.clone(),))
+                .await?;
+            let _: () = jvm
+                .invoke_virtual(&canvas, "pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", (top.clone(),))
+                .await?;
+            assert_eq!(counts(&jvm, &bottom).await?, (1, 1, false));
+            assert_eq!(counts(&jvm, &top).await?, (1, 0, true));
+
+            // inserted card below the top is not shown
+            let _: () = jvm
+                .invoke_virtual(
+                    &canvas,
+                    "insertCard",
+                    "(Lorg/kwis/msp/lcdui/Card;Lorg/kwis/msp/lcdui/Card;)V",
+                    (middle.clone(), bottom.clone()),
+                )
+                .await?;
+            assert_eq!(counts(&jvm, &middle).await?, (0, 0, false));
+
+            let next: ClassInstanceRef<Card> = jvm
+                .invoke_virtual(
+                    &canvas,
+                    "getNextCard",
+                    "(Lorg/kwis/msp/lcdui/Card;)Lorg/kwis/msp/lcdui/Card;",
+                    (top.clone(),),
+                )
+                .await?;
+            let is_middle: bool = jvm.invoke_virtual(&next, "equals", "(Ljava/lang/Object;)Z", (middle.clone(),)).await?;
+            assert!(is_middle);
+
+            // key is passed down until a card handles it
+            let _: () = jvm.invoke_virtual(&canvas, "keyPressed", "(I)V", (53,)).await?;
+            let top_key: i32 = jvm.get_field(&top, "lastKey", "I").await?;
+            let bottom_key: i32 = jvm.get_field(&bottom, "lastKey", "I").await?;
+            assert_eq!((top_key, bottom_key), (53, 53));
+
+            let _: () = jvm.invoke_virtual(&canvas, "popCard", "()V", ()).await?;
+            assert_eq!(counts(&jvm, &top).await?, (1, 1, false));
+            assert_eq!(counts(&jvm, &middle).await?, (1, 0, true));
+
+            let _: () = jvm
+                .invoke_virtual(&canvas, "removeCard", "(Lorg/kwis/msp/lcdui/Card;)V", (middle.clone(),))
+                .await?;
+            assert_eq!(counts(&jvm, &middle).await?, (1, 1, false));
+            assert_eq!(counts(&jvm, &bottom).await?, (2, 1, true));
+
+            let current_top = top_card(&jvm, &canvas).await?;
+            let is_bottom: bool = jvm
+                .invoke_virtual(&current_top, "equals", "(Ljava/lang/Object;)Z", (bottom.clone(),))
+                .await?;
+            assert!(is_bottom);
+
+            let _: () = jvm.invoke_virtual(&canvas, "removeAllCards", "()V", ()).await?;
+            assert_eq!(counts(&jvm, &bottom).await?, (2, 2, false));
+            assert!(top_card(&jvm, &canvas).await?.is_null());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_key_code_from_raw() {
+        assert!(matches!(WIPIKeyCode::try_from_raw(-5), Some(WIPIKeyCode::FIRE)));
+        assert!(matches!(WIPIKeyCode::try_from_raw(-16), Some(WIPIKeyCode::CLEAR)));
+        assert!(matches!(WIPIKeyCode::try_from_raw(53), Some(WIPIKeyCode::NUM5)));
+        assert!(WIPIKeyCode::try_from_raw(0).is_none());
+        assert!(WIPIKeyCode::try_from_raw(-8).is_none());
+        assert!(WIPIKeyCode::try_from_raw(i32::MAX).is_none());
+    }
 }
@@ -0,0 +1,96 @@
+use alloc::{format, vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+use crate::classes::org::kwis::msp::{
+    lcdui::{Display, Graphics},
+    lwc::{ContainerComponent, ShellComponent},
+};
+
+// class net.wie.ShellCard
+// card which puts a lwc shell on the card stack, passing card events to the shell
+pub struct ShellCard;
+
+impl ShellCard {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/ShellCard",
+            parent_class: Some("org/kwis/msp/lcdui/Card"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "<init>",
+                    "(Lorg/kwis/msp/lcdui/Display;Lorg/kwis/msp/lwc/ShellComponent;)V",
+                    Self::init,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+                JavaMethodProto::new("showNotify", "(Z)V", Self::show_notify, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("shell", "Lorg/kwis/msp/lwc/ShellComponent;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        display: ClassInstanceRef<Display>,
+        shell: ClassInstanceRef<ShellComponent>,
+    ) -> JvmResult<()> {
+        let log = format!("net.wie.ShellCard::<init>({:?}, {:?}, {:?})", &this, &display, &shell);
+        tracing::debug!("{}", log); // splitted format as tracing macro doesn't like variable named `display` https://github.com/tokio-rs/tracing/issues/2332
+
+        let _: () = jvm
+            .invoke_special(&this, "org/kwis/msp/lcdui/Card", "<init>", "(Lorg/kwis/msp/lcdui/Display;)V", (display,))
+            .await?;
+
+        jvm.put_field(&mut this, "shell", "Lorg/kwis/msp/lwc/ShellComponent;", shell).await
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("net.wie.ShellCard::paint({:?}, {:?})", &this, &graphics);
+
+        // layout again as the annunciator may have been shown or hidden since
+        let shell = Self::configure_shell(jvm, &this).await?;
+
+        ContainerComponent::paint_component(jvm, &graphics, &shell).await
+    }
+
+    // shell is shown and focused while the card is on top of the stack
+    async fn show_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, shown: bool) -> JvmResult<()> {
+        tracing::debug!("net.wie.ShellCard::showNotify({:?}, {})", &this, shown);
+
+        let shell = Self::configure_shell(jvm, &this).await?;
+        let _: () = jvm.invoke_virtual(&shell, "showNotify", "(Z)V", (shown,)).await?;
+        jvm.invoke_virtual(&shell, "focusNotify", "(Z)V", (shown,)).await
+    }
+
+    async fn key_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32, key: i32) -> JvmResult<bool> {
+        tracing::debug!("net.wie.ShellCard::keyNotify({:?}, {}, {})", &this, r#type, key);
+
+        let shell = Self::shell(jvm, &this).await?;
+
+        jvm.invoke_virtual(&shell, "keyNotify", "(II)Z", (r#type, key)).await
+    }
+
+    // shell fills the card
+    async fn configure_shell(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<ShellComponent>> {
+        let shell = Self::shell(jvm, this).await?;
+
+        let width: i32 = jvm.get_field(this, "w", "I").await?;
+        let height: i32 = jvm.get_field(this, "h", "I").await?;
+        let _: () = jvm.invoke_virtual(&shell, "configure", "(IIIII)V", (0, 0, width, height, 0)).await?;
+
+        Ok(shell)
+    }
+
+    async fn shell(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<ShellComponent>> {
+        jvm.get_field(this, "shell", "Lorg/kwis/msp/lwc/ShellComponent;").await
+    }
+}
@@ -0,0 +1,34 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+// class net.wie.WIPIHttpConnectionImpl
+// midp http connection, also implementing org.kwis.msf.io.HttpConnection
+pub struct WIPIHttpConnectionImpl;
+
+impl WIPIHttpConnectionImpl {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "net/wie/WIPIHttpConnectionImpl",
+            parent_class: Some("net/wie/HttpConnectionImpl"),
+            interfaces: vec!["org/kwis/msf/io/HttpConnection"],
+            methods: vec![JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default())],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, url: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("net.wie.WIPIHttpConnectionImpl::<init>({:?}, {:?})", &this, &url);
+
+        let _: () = jvm
+            .invoke_special(&this, "net/wie/HttpConnectionImpl", "<init>", "(Ljava/lang/String;)V", (url,))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -28,7 +28,11 @@ impl WIPIMIDlet {
                     Default::default(),
                 ),
             ],
-            fields: vec![JavaFieldProto::new("jlet", "Lorg/kwis/msp/lcdui/Jlet;", Default::default())],
+            fields: vec![
+                JavaFieldProto::new("jlet", "Lorg/kwis/msp/lcdui/Jlet;", Default::default()),
+                JavaFieldProto::new("started", "Z", Default::default()),
+                JavaFieldProto::new("destroying", "Z", Default::default()),
+            ],
             access_flags: Default::default(),
         }
     }
@@ -54,9 +58,19 @@ impl WIPIMIDlet {
         Ok(())
     }
 
-    async fn start_app(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+    async fn start_app(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
         tracing::debug!("net.wie.WIPIMIDlet::startApp({:?})", this);
 
+        // midp calls startApp again on resume, but jlet has separate resumeApp
+        let started: bool = jvm.get_field(&this, "started", "Z").await?;
+        if started {
+            let jlet = jvm.get_field(&this, "jlet", "Lorg/kwis/msp/lcdui/Jlet;").await?;
+            let _: () = jvm.invoke_virtual(&jlet, "resumeApp", "()V", ()).await?;
+
+            return Ok(());
+        }
+        jvm.put_field(&mut this, "started", "Z", true).await?;
+
         let args_array = jvm.instantiate_array("Ljava/lang/String;", 0).await?;
 
         let jlet = jvm.get_field(&this, "jlet", "Lorg/kwis/msp/lcdui/Jlet;").await?;
@@ -74,9 +88,11 @@ impl WIPIMIDlet {
         Ok(())
     }
 
-    async fn destroy_app(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, unconditional: bool) -> JvmResult<()> {
+    async fn destroy_app(jvm: &Jvm, _context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, unconditional: bool) -> JvmResult<()> {
         tracing::debug!("net.wie.WIPIMIDlet::destroyApp({:?}, {:?})", this, unconditional);
 
+        jvm.put_field(&mut this, "destroying", "Z", true).await?;
+
         let jlet = jvm.get_field(&this, "jlet", "Lorg/kwis/msp/lcdui/Jlet;").await?;
         let _: () = jvm.invoke_virtual(&jlet, "destroyApp", "(Z)V", (unconditional,)).await?;
 
@@ -1,4 +1,10 @@
+mod connection;
+mod connector;
+mod http_connection;
 mod network;
 mod scheme_not_found_exception;
 
-pub use {network::Network, scheme_not_found_exception::SchemeNotFoundException};
+pub use {
+    connection::Connection, connector::Connector, http_connection::HttpConnection, network::Network,
+    scheme_not_found_exception::SchemeNotFoundException,
+};
@@ -0,0 +1,22 @@
+use alloc::vec;
+
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+// interface org.kwis.msf.io.Connection
+// methods are inherited from javax.microedition.io.Connection, so connection impls can be shared with midp
+pub struct Connection;
+
+impl Connection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msf/io/Connection",
+            parent_class: None,
+            interfaces: vec!["javax/microedition/io/Connection"],
+            methods: vec![],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
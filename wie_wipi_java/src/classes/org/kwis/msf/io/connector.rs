@@ -0,0 +1,303 @@
+use alloc::{format, vec};
+
+use java_class_proto::JavaMethodProto;
+use java_constants::MethodAccessFlags;
+use java_runtime::classes::java::{
+    io::{DataInputStream, InputStream, OutputStream},
+    lang::String,
+};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::javax::microedition::io::connector::{READ, READ_WRITE, WRITE};
+
+use crate::classes::org::kwis::msf::io::Connection;
+
+// class org.kwis.msf.io.Connector
+// only http is provided here, other schemes are available through javax.microedition.io.Connector
+pub struct Connector;
+
+impl Connector {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msf/io/Connector",
+            parent_class: Some("java/lang/Object"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new(
+                    "open",
+                    "(Ljava/lang/String;)Lorg/kwis/msf/io/Connection;",
+                    Self::open,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "open",
+                    "(Ljava/lang/String;I)Lorg/kwis/msf/io/Connection;",
+                    Self::open_with_mode,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "open",
+                    "(Ljava/lang/String;IZ)Lorg/kwis/msf/io/Connection;",
+                    Self::open_with_mode_timeouts,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openInputStream",
+                    "(Ljava/lang/String;)Ljava/io/InputStream;",
+                    Self::open_input_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openDataInputStream",
+                    "(Ljava/lang/String;)Ljava/io/DataInputStream;",
+                    Self::open_data_input_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openOutputStream",
+                    "(Ljava/lang/String;)Ljava/io/OutputStream;",
+                    Self::open_output_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+                JavaMethodProto::new(
+                    "openDataOutputStream",
+                    "(Ljava/lang/String;)Ljava/io/DataOutputStream;",
+                    Self::open_data_output_stream,
+                    MethodAccessFlags::STATIC,
+                ),
+            ],
+            fields: vec![],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn open(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<Connection>> {
+        tracing::debug!("org.kwis.msf.io.Connector::open({:?})", &name);
+
+        Self::open_connection(jvm, name, READ_WRITE).await
+    }
+
+    async fn open_with_mode(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>, mode: i32) -> JvmResult<ClassInstanceRef<Connection>> {
+        tracing::debug!("org.kwis.msf.io.Connector::open({:?}, {})", &name, mode);
+
+        Self::open_connection(jvm, name, mode).await
+    }
+
+    // access mode is not enforced, and we don't have timeouts on connecting
+    async fn open_with_mode_timeouts(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        name: ClassInstanceRef<String>,
+        mode: i32,
+        timeouts: bool,
+    ) -> JvmResult<ClassInstanceRef<Connection>> {
+        tracing::debug!("org.kwis.msf.io.Connector::open({:?}, {}, {})", &name, mode, timeouts);
+
+        Self::open_connection(jvm, name, mode).await
+    }
+
+    async fn open_input_stream(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<InputStream>> {
+        tracing::debug!("org.kwis.msf.io.Connector::openInputStream({:?})", &name);
+
+        let connection = Self::open_connection(jvm, name, READ).await?;
+        let input_stream = jvm.invoke_virtual(&connection, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+
+        // connection is kept open until the stream is closed
+        let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+        Ok(input_stream)
+    }
+
+    async fn open_data_input_stream(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        name: ClassInstanceRef<String>,
+    ) -> JvmResult<ClassInstanceRef<DataInputStream>> {
+        tracing::debug!("org.kwis.msf.io.Connector::openDataInputStream({:?})", &name);
+
+        let input_stream: ClassInstanceRef<InputStream> = jvm
+            .invoke_static(
+                "org/kwis/msf/io/Connector",
+                "openInputStream",
+                "(Ljava/lang/String;)Ljava/io/InputStream;",
+                (name,),
+            )
+            .await?;
+        let data_input_stream = jvm
+            .new_class("java/io/DataInputStream", "(Ljava/io/InputStream;)V", (input_stream,))
+            .await?;
+
+        Ok(data_input_stream.into())
+    }
+
+    async fn open_output_stream(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("org.kwis.msf.io.Connector::openOutputStream({:?})", &name);
+
+        let connection = Self::open_connection(jvm, name, WRITE).await?;
+        let output_stream = jvm
+            .invoke_virtual(&connection, "openOutputStream", "()Ljava/io/OutputStream;", ())
+            .await?;
+
+        let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+
+        Ok(output_stream)
+    }
+
+    async fn open_data_output_stream(jvm: &Jvm, _: &mut WieJvmContext, name: ClassInstanceRef<String>) -> JvmResult<ClassInstanceRef<OutputStream>> {
+        tracing::debug!("org.kwis.msf.io.Connector::openDataOutputStream({:?})", &name);
+
+        let output_stream: ClassInstanceRef<OutputStream> = jvm
+            .invoke_static(
+                "org/kwis/msf/io/Connector",
+                "openOutputStream",
+                "(Ljava/lang/String;)Ljava/io/OutputStream;",
+                (name,),
+            )
+            .await?;
+        let data_output_stream = jvm
+            .new_class("java/io/DataOutputStream", "(Ljava/io/OutputStream;)V", (output_stream,))
+            .await?;
+
+        Ok(data_output_stream.into())
+    }
+
+    async fn open_connection(jvm: &Jvm, name: ClassInstanceRef<String>, mode: i32) -> JvmResult<ClassInstanceRef<Connection>> {
+        if name.is_null() {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Null url").await);
+        }
+        if !(READ..=READ_WRITE).contains(&mode) {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid mode").await);
+        }
+
+        let url = JavaLangString::to_rust_string(jvm, &name).await?;
+        let Some((scheme, _)) = url.split_once(':') else {
+            return Err(jvm.exception("java/lang/IllegalArgumentException", "Invalid url").await);
+        };
+
+        if !scheme.eq_ignore_ascii_case("http") {
+            return Err(jvm
+                .exception("org/kwis/msf/io/SchemeNotFoundException", &format!("Unsupported scheme {scheme}"))
+                .await);
+        }
+
+        let connection = jvm.new_class("net/wie/WIPIHttpConnectionImpl", "(Ljava/lang/String;)V", (name,)).await?;
+
+        Ok(connection.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{boxed::Box, string::String as RustString, vec::Vec};
+    use core::net::{Ipv4Addr, SocketAddrV4};
+
+    use java_runtime::classes::java::io::InputStream;
+    use jvm::{ClassInstanceRef, JavaError, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::{TestPlatform, run_jvm_test, run_jvm_test_with_platform, serve};
+    use wie_backend::TcpSocket;
+    use wie_util::Result;
+
+    use crate::classes::org::kwis::msf::io::Connection;
+
+    const SERVER_ADDR: Ipv4Addr = Ipv4Addr::new(10, 0, 0, 2);
+
+    // reads from server side socket until request header is complete
+    async fn read_request(socket: &dyn TcpSocket) -> Vec<u8> {
+        let mut result = Vec::new();
+        while !result.ends_with(b"\r\n\r\n") {
+            let mut buf = [0; 256];
+            let read = socket.read(&mut buf).await.unwrap();
+            assert_ne!(read, 0, "connection closed");
+            result.extend_from_slice(&buf[..read]);
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_http_connection() -> Result<()> {
+        let platform = TestPlatform::new();
+        let network = platform.test_network();
+        network.add_host("example.com", SERVER_ADDR);
+        let listener = network.listen(SocketAddrV4::new(SERVER_ADDR, 80));
+
+        run_jvm_test_with_platform(
+            platform,
+            Box::new([wie_midp::get_protos().into(), crate::get_protos().into()]),
+            |jvm| async move {
+                let server = async {
+                    let socket = listener.accept_async().await;
+
+                    let request = RustString::from_utf8(read_request(&socket).await).unwrap();
+                    assert!(request.starts_with("GET /ranking HTTP/1.1\r\n"));
+
+                    socket
+                        .write(b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\nok")
+                        .await
+                        .unwrap();
+                    socket.shutdown();
+                };
+
+                let client = async {
+                    let result: i32 = jvm.invoke_static("org/kwis/msf/io/Network", "connect", "()I", ()).await?;
+                    assert_eq!(result, 0);
+
+                    let url = JavaLangString::from_rust_string(&jvm, "http://example.com/ranking").await?;
+                    let connection: ClassInstanceRef<Connection> = jvm
+                        .invoke_static(
+                            "org/kwis/msf/io/Connector",
+                            "open",
+                            "(Ljava/lang/String;)Lorg/kwis/msf/io/Connection;",
+                            (url,),
+                        )
+                        .await?;
+                    assert!(jvm.is_instance(&**connection, "org/kwis/msf/io/HttpConnection"));
+                    assert!(jvm.is_instance(&**connection, "javax/microedition/io/HttpConnection"));
+
+                    let response_code: i32 = jvm.invoke_virtual(&connection, "getResponseCode", "()I", ()).await?;
+                    assert_eq!(response_code, 200);
+
+                    let input_stream: ClassInstanceRef<InputStream> =
+                        jvm.invoke_virtual(&connection, "openInputStream", "()Ljava/io/InputStream;", ()).await?;
+                    let mut body = Vec::new();
+                    loop {
+                        let read: i32 = jvm.invoke_virtual(&input_stream, "read", "()I", ()).await?;
+                        if read == -1 {
+                            break;
+                        }
+                        body.push(read as u8);
+                    }
+                    assert_eq!(body, b"ok");
+
+                    let _: () = jvm.invoke_virtual(&input_stream, "close", "()V", ()).await?;
+                    let _: () = jvm.invoke_virtual(&connection, "close", "()V", ()).await?;
+                    let _: () = jvm.invoke_static("org/kwis/msf/io/Network", "disconnect", "()V", ()).await?;
+
+                    Ok::<_, JavaError>(())
+                };
+
+                serve(client, server).await
+            },
+        )
+    }
+
+    #[test]
+    fn test_unsupported_scheme() -> Result<()> {
+        run_jvm_test(Box::new([wie_midp::get_protos().into(), crate::get_protos().into()]), |jvm| async move {
+            let url = JavaLangString::from_rust_string(&jvm, "socket://10.0.0.2:8080").await?;
+            let result: JvmResult<ClassInstanceRef<Connection>> = jvm
+                .invoke_static(
+                    "org/kwis/msf/io/Connector",
+                    "open",
+                    "(Ljava/lang/String;)Lorg/kwis/msf/io/Connection;",
+                    (url,),
+                )
+                .await;
+            assert!(matches!(result, Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "org/kwis/msf/io/SchemeNotFoundException")));
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,21 @@
+use alloc::vec;
+
+use java_constants::ClassAccessFlags;
+
+use wie_jvm_support::WieJavaClassProto;
+
+// interface org.kwis.msf.io.HttpConnection
+pub struct HttpConnection;
+
+impl HttpConnection {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msf/io/HttpConnection",
+            parent_class: None,
+            interfaces: vec!["org/kwis/msf/io/Connection", "javax/microedition/io/HttpConnection"],
+            methods: vec![],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
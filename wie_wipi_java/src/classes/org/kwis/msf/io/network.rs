@@ -30,13 +30,14 @@ impl Network {
     }
 
     async fn connect(_: &Jvm, _: &mut WieJvmContext) -> JvmResult<i32> {
-        tracing::warn!("stub org.kwis.msf.io.Network::connect()");
+        tracing::debug!("org.kwis.msf.io.Network::connect()");
 
-        Ok(-1)
+        // we don't have to bring up data connection, sockets and http can be used right away
+        Ok(0)
     }
 
     async fn disconnect(_: &Jvm, _: &mut WieJvmContext) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msf.io.Network::disconnect()");
+        tracing::debug!("org.kwis.msf.io.Network::disconnect()");
 
         Ok(())
     }
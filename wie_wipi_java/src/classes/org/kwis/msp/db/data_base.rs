@@ -1,9 +1,9 @@
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::MethodAccessFlags;
 use java_runtime::classes::java::lang::String;
-use jvm::{Array, ClassInstanceRef, Jvm, Result as JvmResult};
+use jvm::{Array, ClassInstanceRef, JavaError, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 use wie_midp::classes::javax::microedition::rms::RecordStore;
@@ -32,6 +32,10 @@ impl DataBase {
                     MethodAccessFlags::STATIC,
                 ),
                 JavaMethodProto::new("getNumberOfRecords", "()I", Self::get_number_of_records, Default::default()),
+                JavaMethodProto::new("getRecordSize", "()I", Self::get_record_size, Default::default()),
+                JavaMethodProto::new("getRecordSize", "(I)I", Self::get_record_size_with_id, Default::default()),
+                JavaMethodProto::new("listRecords", "()[I", Self::list_records, Default::default()),
+                JavaMethodProto::new("deleteRecord", "(I)V", Self::delete_record, Default::default()),
                 JavaMethodProto::new("closeDataBase", "()V", Self::close_data_base, Default::default()),
                 JavaMethodProto::new("insertRecord", "([B)I", Self::insert_record, Default::default()),
                 JavaMethodProto::new("insertRecord", "([BII)I", Self::insert_record_with_offset, Default::default()),
@@ -45,11 +49,10 @@ impl DataBase {
                     MethodAccessFlags::STATIC,
                 ),
             ],
-            fields: vec![JavaFieldProto::new(
-                "recordStore",
-                "Ljavax/microedition/rms/RecordStore;",
-                Default::default(),
-            )],
+            fields: vec![
+                JavaFieldProto::new("recordStore", "Ljavax/microedition/rms/RecordStore;", Default::default()),
+                JavaFieldProto::new("recordSize", "I", Default::default()),
+            ],
             access_flags: Default::default(),
         }
     }
@@ -115,9 +118,10 @@ impl DataBase {
             )
             .await?;
 
-        let instance = jvm
+        let mut instance = jvm
             .new_class("org/kwis/msp/db/DataBase", "(Ljavax/microedition/rms/RecordStore;)V", (record_store,))
             .await?;
+        jvm.put_field(&mut instance, "recordSize", "I", record_size).await?;
 
         Ok(instance.into())
     }
@@ -129,6 +133,58 @@ impl DataBase {
         jvm.invoke_virtual(&record_store, "getNumRecords", "()I", ()).await
     }
 
+    async fn get_record_size(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.db.DataBase::getRecordSize({:?})", &this);
+
+        jvm.get_field(&this, "recordSize", "I").await
+    }
+
+    async fn get_record_size_with_id(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, record_id: i32) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.db.DataBase::getRecordSize({:?}, {})", &this, record_id);
+
+        let record_id = DataBase::to_midp_record_id(record_id);
+
+        let record_store = jvm.get_field(&this, "recordStore", "Ljavax/microedition/rms/RecordStore;").await?;
+        let result = jvm.invoke_virtual(&record_store, "getRecordSize", "(I)I", (record_id,)).await;
+
+        if result.is_err() {
+            return Err(jvm.exception("org/kwis/msp/db/DataBaseRecordException", "Record not found").await);
+        }
+
+        Ok(result.unwrap())
+    }
+
+    async fn list_records(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Array<i32>>> {
+        tracing::debug!("org.kwis.msp.db.DataBase::listRecords({:?})", &this);
+
+        let record_store: ClassInstanceRef<RecordStore> = jvm.get_field(&this, "recordStore", "Ljavax/microedition/rms/RecordStore;").await?;
+        let ids = RecordStore::record_ids(jvm, context, &record_store)
+            .await?
+            .into_iter()
+            .map(DataBase::to_wipi_record_id)
+            .collect::<Vec<_>>();
+
+        let mut result = jvm.instantiate_array("I", ids.len()).await?;
+        jvm.store_array(&mut result, 0, ids).await?;
+
+        Ok(result.into())
+    }
+
+    async fn delete_record(jvm: &Jvm, _context: &mut WieJvmContext, this: ClassInstanceRef<Self>, record_id: i32) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.db.DataBase::deleteRecord({:?}, {})", &this, record_id);
+
+        let record_id = DataBase::to_midp_record_id(record_id);
+
+        let record_store = jvm.get_field(&this, "recordStore", "Ljavax/microedition/rms/RecordStore;").await?;
+        let result: JvmResult<()> = jvm.invoke_virtual(&record_store, "deleteRecord", "(I)V", (record_id,)).await;
+
+        if result.is_err() {
+            return Err(jvm.exception("org/kwis/msp/db/DataBaseRecordException", "Record not found").await);
+        }
+
+        Ok(())
+    }
+
     async fn close_data_base(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<DataBase>) -> JvmResult<()> {
         tracing::debug!("org.kwis.msp.db.DataBase::closeDataBase({:?})", &this);
 
@@ -182,12 +238,16 @@ impl DataBase {
         let record_store = jvm.get_field(&this, "recordStore", "Ljavax/microedition/rms/RecordStore;").await?;
         let result = jvm.invoke_virtual(&record_store, "getRecord", "(I)[B", (record_id,)).await;
 
-        // TODO check exception type
-        if result.is_err() {
-            return Err(jvm.exception("org/kwis/msp/db/DataBaseRecordException", "Record not found").await);
+        match result {
+            Ok(x) => Ok(x),
+            Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "javax/microedition/rms/InvalidRecordIDException") => {
+                Err(jvm.exception("org/kwis/msp/db/DataBaseRecordException", "Record not found").await)
+            }
+            Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "javax/microedition/rms/RecordStoreException") => {
+                Err(jvm.exception("org/kwis/msp/db/DataBaseException", "Failed to read record").await)
+            }
+            Err(x) => Err(x),
         }
-
-        Ok(result.unwrap())
     }
 
     async fn update_record(
@@ -239,16 +299,25 @@ impl DataBase {
     async fn delete_data_base(jvm: &Jvm, _: &mut WieJvmContext, data_base_name: ClassInstanceRef<String>) -> JvmResult<()> {
         tracing::debug!("org.kwis.msp.db.DataBase::deleteDataBase({data_base_name:?})");
 
-        let _: () = jvm
+        let result: JvmResult<()> = jvm
             .invoke_static(
                 "javax/microedition/rms/RecordStore",
                 "deleteRecordStore",
                 "(Ljava/lang/String;)V",
                 (data_base_name,),
             )
-            .await?;
-
-        Ok(())
+            .await;
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "javax/microedition/rms/RecordStoreNotFoundException") => {
+                Err(jvm.exception("org/kwis/msp/db/DataBaseException", "Database not found").await)
+            }
+            Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "javax/microedition/rms/RecordStoreException") => {
+                Err(jvm.exception("org/kwis/msp/db/DataBaseException", "Failed to delete database").await)
+            }
+            Err(x) => Err(x),
+        }
     }
 
     // wipi record id starts with 0 but midp record id starts with 1
@@ -260,3 +329,60 @@ impl DataBase {
         midp_record_id - 1
     }
 }
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::{ClassInstanceRef, JavaError, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    async fn exception_class<T>(jvm: &Jvm, result: JvmResult<T>) -> &'static str {
+        match result {
+            Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "org/kwis/msp/db/DataBaseRecordException") => "DataBaseRecordException",
+            Err(JavaError::JavaException(x)) if jvm.is_instance(&*x, "org/kwis/msp/db/DataBaseException") => "DataBaseException",
+            Err(_) => "other",
+            Ok(_) => "none",
+        }
+    }
+
+    #[test]
+    fn test_exceptions() -> Result<()> {
+        run_jvm_test(Box::new([wie_midp::get_protos().into(), crate::get_protos().into()]), |jvm| async move {
+            let name = JavaLangString::from_rust_string(&jvm, "test").await?;
+            let data_base: ClassInstanceRef<super::DataBase> = jvm
+                .invoke_static(
+                    "org/kwis/msp/db/DataBase",
+                    "openDataBase",
+                    "(Ljava/lang/String;IZ)Lorg/kwis/msp/db/DataBase;",
+                    (name.clone(), 0, true),
+                )
+                .await?;
+
+            let result: JvmResult<ClassInstanceRef<i8>> = jvm.invoke_virtual(&data_base, "selectRecord", "(I)[B", (5,)).await;
+            assert_eq!(exception_class(&jvm, result).await, "DataBaseRecordException");
+
+            // deleting an open database
+            let result: JvmResult<()> = jvm
+                .invoke_static("org/kwis/msp/db/DataBase", "deleteDataBase", "(Ljava/lang/String;)V", (name.clone(),))
+                .await;
+            assert_eq!(exception_class(&jvm, result).await, "DataBaseException");
+
+            let _: () = jvm.invoke_virtual(&data_base, "closeDataBase", "()V", ()).await?;
+            let result: JvmResult<()> = jvm
+                .invoke_static("org/kwis/msp/db/DataBase", "deleteDataBase", "(Ljava/lang/String;)V", (name,))
+                .await;
+            assert_eq!(exception_class(&jvm, result).await, "none");
+
+            let missing = JavaLangString::from_rust_string(&jvm, "missing").await?;
+            let result: JvmResult<()> = jvm
+                .invoke_static("org/kwis/msp/db/DataBase", "deleteDataBase", "(Ljava/lang/String;)V", (missing,))
+                .await;
+            assert_eq!(exception_class(&jvm, result).await, "DataBaseException");
+
+            Ok(())
+        })
+    }
+}
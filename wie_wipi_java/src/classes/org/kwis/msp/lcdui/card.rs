@@ -30,6 +30,7 @@ impl Card {
                 JavaMethodProto::new("serviceRepaints", "()V", Self::service_repaints, Default::default()),
                 JavaMethodProto::new("showNotify", "(Z)V", Self::show_notify, Default::default()),
                 JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("pointerNotify", "(III)Z", Self::pointer_notify, Default::default()),
                 JavaMethodProto::new_abstract("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Default::default()),
                 // wie private
                 JavaMethodProto::new("setCanvas", "(Ljavax/microedition/lcdui/Canvas;)V", Self::set_canvas, Default::default()),
@@ -41,6 +42,7 @@ impl Card {
                 JavaFieldProto::new("y", "I", Default::default()),
                 JavaFieldProto::new("w", "I", Default::default()),
                 JavaFieldProto::new("h", "I", Default::default()),
+                JavaFieldProto::new("shown", "Z", Default::default()),
             ],
             access_flags: ClassAccessFlags::ABSTRACT,
         }
@@ -96,10 +98,11 @@ impl Card {
         Ok(())
     }
 
-    async fn is_shown(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Card>) -> JvmResult<bool> {
-        tracing::warn!("stub org.kwis.msp.lcdui.Card::isShown({:?})", &this);
+    // set by net.wie.CardCanvas when the card enters or leaves the top of the card stack
+    async fn is_shown(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Card>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lcdui.Card::isShown({:?})", &this);
 
-        Ok(true)
+        jvm.get_field(&this, "shown", "Z").await
     }
 
     async fn get_width(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Card>) -> JvmResult<i32> {
@@ -155,8 +158,8 @@ impl Card {
         Ok(())
     }
 
-    async fn show_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Card>) -> JvmResult<()> {
-        tracing::debug!("org.kwis.msp.lcdui.Card::showNotify({:?})", &this);
+    async fn show_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Card>, b: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lcdui.Card::showNotify({:?}, {})", &this, b);
 
         Ok(())
     }
@@ -167,6 +170,12 @@ impl Card {
         Ok(false)
     }
 
+    async fn pointer_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Card>, r#type: i32, x: i32, y: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lcdui.Card::pointerNotify({:?}, {}, {}, {})", &this, r#type, x, y);
+
+        Ok(false)
+    }
+
     async fn set_canvas(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Card>, canvas: ClassInstanceRef<Canvas>) -> JvmResult<()> {
         tracing::debug!("org.kwis.msp.lcdui.Card::setCanvas({:?}, {:?})", &this, &canvas);
 
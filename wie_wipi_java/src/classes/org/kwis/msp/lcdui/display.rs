@@ -45,7 +45,22 @@ impl Display {
                 JavaMethodProto::new("isDoubleBuffered", "()Z", Self::is_double_buffered, Default::default()),
                 JavaMethodProto::new("getDockedCard", "()Lorg/kwis/msp/lcdui/Card;", Self::get_docked_card, Default::default()),
                 JavaMethodProto::new("pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", Self::push_card, Default::default()),
+                JavaMethodProto::new("popCard", "()V", Self::pop_card, Default::default()),
+                JavaMethodProto::new(
+                    "insertCard",
+                    "(Lorg/kwis/msp/lcdui/Card;Lorg/kwis/msp/lcdui/Card;)V",
+                    Self::insert_card,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("removeCard", "(Lorg/kwis/msp/lcdui/Card;)V", Self::remove_card, Default::default()),
                 JavaMethodProto::new("removeAllCards", "()V", Self::remove_all_cards, Default::default()),
+                JavaMethodProto::new("getTopCard", "()Lorg/kwis/msp/lcdui/Card;", Self::get_top_card, Default::default()),
+                JavaMethodProto::new(
+                    "getNextCard",
+                    "(Lorg/kwis/msp/lcdui/Card;)Lorg/kwis/msp/lcdui/Card;",
+                    Self::get_next_card,
+                    Default::default(),
+                ),
                 JavaMethodProto::new(
                     "addJletEventListener",
                     "(Lorg/kwis/msp/lcdui/JletEventListener;)V",
@@ -131,10 +146,13 @@ impl Display {
         Ok(result)
     }
 
-    async fn get_docked_card(_: &Jvm, _: &mut WieJvmContext) -> JvmResult<ClassInstanceRef<Card>> {
-        tracing::warn!("stub org.kwis.msp.lcdui.Display::getDockedCard");
+    // we don't have docking area, so the top card is the one docked on screen
+    async fn get_docked_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Card>> {
+        tracing::debug!("org.kwis.msp.lcdui.Display::getDockedCard({:?})", &this);
 
-        Ok(None.into())
+        let card_canvas = jvm.get_field(&this, "cardCanvas", "Lnet/wie/CardCanvas;").await?;
+
+        jvm.invoke_virtual(&card_canvas, "getTopCard", "()Lorg/kwis/msp/lcdui/Card;", ()).await
     }
 
     async fn is_double_buffered(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
@@ -152,6 +170,70 @@ impl Display {
         Ok(())
     }
 
+    async fn pop_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lcdui.Display::popCard({:?})", &this);
+
+        let card_canvas = jvm.get_field(&this, "cardCanvas", "Lnet/wie/CardCanvas;").await?;
+        let _: () = jvm.invoke_virtual(&card_canvas, "popCard", "()V", ()).await?;
+
+        Ok(())
+    }
+
+    async fn insert_card(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        c: ClassInstanceRef<Card>,
+        next: ClassInstanceRef<Card>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lcdui.Display::insertCard({:?}, {:?}, {:?})", &this, &c, &next);
+
+        let card_canvas = jvm.get_field(&this, "cardCanvas", "Lnet/wie/CardCanvas;").await?;
+        let _: () = jvm
+            .invoke_virtual(
+                &card_canvas,
+                "insertCard",
+                "(Lorg/kwis/msp/lcdui/Card;Lorg/kwis/msp/lcdui/Card;)V",
+                (c, next),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, c: ClassInstanceRef<Card>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lcdui.Display::removeCard({:?}, {:?})", &this, &c);
+
+        let card_canvas = jvm.get_field(&this, "cardCanvas", "Lnet/wie/CardCanvas;").await?;
+        let _: () = jvm
+            .invoke_virtual(&card_canvas, "removeCard", "(Lorg/kwis/msp/lcdui/Card;)V", (c,))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_top_card(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Card>> {
+        tracing::debug!("org.kwis.msp.lcdui.Display::getTopCard({:?})", &this);
+
+        let card_canvas = jvm.get_field(&this, "cardCanvas", "Lnet/wie/CardCanvas;").await?;
+
+        jvm.invoke_virtual(&card_canvas, "getTopCard", "()Lorg/kwis/msp/lcdui/Card;", ()).await
+    }
+
+    async fn get_next_card(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        c: ClassInstanceRef<Card>,
+    ) -> JvmResult<ClassInstanceRef<Card>> {
+        tracing::debug!("org.kwis.msp.lcdui.Display::getNextCard({:?}, {:?})", &this, &c);
+
+        let card_canvas = jvm.get_field(&this, "cardCanvas", "Lnet/wie/CardCanvas;").await?;
+
+        jvm.invoke_virtual(&card_canvas, "getNextCard", "(Lorg/kwis/msp/lcdui/Card;)Lorg/kwis/msp/lcdui/Card;", (c,))
+            .await
+    }
+
     async fn remove_all_cards(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
         tracing::debug!("org.kwis.msp.lcdui.Display::removeAllCards({:?})", &this);
 
@@ -661,4 +661,8 @@ impl Graphics {
 
         Ok(())
     }
+
+    pub async fn midp_graphics(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<MidpGraphics>> {
+        jvm.get_field(this, "midpGraphics", "Ljavax/microedition/lcdui/Graphics;").await
+    }
 }
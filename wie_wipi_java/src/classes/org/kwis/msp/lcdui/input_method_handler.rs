@@ -1,14 +1,23 @@
 use alloc::vec;
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::{InputConstraint, InputMethodHandle, InputMode};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
 
+// text of lwc components has no length limit unless set with setMaxLength
+const UNLIMITED_LENGTH: usize = i32::MAX as _;
+
 // class org.kwis.msp.lcdui.InputMethodHandler
 pub struct InputMethodHandler;
 
 impl InputMethodHandler {
+    pub const MODE_KOREAN: i32 = 1;
+    pub const MODE_LOWER: i32 = 2;
+    pub const MODE_UPPER: i32 = 3;
+    pub const MODE_NUMBER: i32 = 4;
+
     pub fn as_proto() -> WieJavaClassProto {
         WieJavaClassProto {
             name: "org/kwis/msp/lcdui/InputMethodHandler",
@@ -17,23 +26,61 @@ impl InputMethodHandler {
             methods: vec![
                 JavaMethodProto::new("<init>", "(I)V", Self::init, Default::default()),
                 JavaMethodProto::new("setCurrentMode", "(I)Z", Self::set_current_mode, Default::default()),
+                JavaMethodProto::new("getCurrentMode", "()I", Self::get_current_mode, Default::default()),
             ],
-            fields: vec![],
+            fields: vec![JavaFieldProto::new("inputMethod", "I", Default::default())],
             access_flags: Default::default(),
         }
     }
 
-    async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, constraint: i32) -> JvmResult<()> {
-        tracing::debug!("stub org.kwis.msp.lcdui.InputMethodHandler::<init>({this:?}, {constraint})");
+    async fn init(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, constraint: i32) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lcdui.InputMethodHandler::<init>({this:?}, {constraint})");
 
         let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
 
+        let input_method = context
+            .system()
+            .input_methods()
+            .create(InputConstraint::from_raw(constraint), UNLIMITED_LENGTH);
+        jvm.put_field(&mut this, "inputMethod", "I", input_method as i32).await?;
+
         Ok(())
     }
 
-    async fn set_current_mode(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, mode: i32) -> JvmResult<bool> {
-        tracing::warn!("stub org.kwis.msp.lcdui.InputMethodHandler::setCurrentMode({this:?}, {mode})");
+    async fn set_current_mode(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, mode: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lcdui.InputMethodHandler::setCurrentMode({this:?}, {mode})");
+
+        let mode = match mode {
+            Self::MODE_KOREAN => InputMode::Cheonjiin,
+            Self::MODE_LOWER => InputMode::EnglishLower,
+            Self::MODE_UPPER => InputMode::EnglishUpper,
+            Self::MODE_NUMBER => InputMode::Numeric,
+            _ => return Ok(false),
+        };
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let result = context.system().input_methods().get(input_method).is_some_and(|x| x.set_mode(mode));
+
+        Ok(result)
+    }
+
+    async fn get_current_mode(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lcdui.InputMethodHandler::getCurrentMode({this:?})");
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let mode = context.system().input_methods().get(input_method).map(|x| x.mode());
+
+        Ok(match mode {
+            Some(InputMode::Cheonjiin | InputMode::Naragul) => Self::MODE_KOREAN,
+            Some(InputMode::EnglishLower) => Self::MODE_LOWER,
+            Some(InputMode::EnglishUpper) => Self::MODE_UPPER,
+            Some(InputMode::Numeric) | None => Self::MODE_NUMBER,
+        })
+    }
+
+    pub async fn input_method(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<InputMethodHandle> {
+        let input_method: i32 = jvm.get_field(this, "inputMethod", "I").await?;
 
-        Ok(true)
+        Ok(input_method as _)
     }
 }
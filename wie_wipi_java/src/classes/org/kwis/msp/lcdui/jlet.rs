@@ -124,9 +124,16 @@ impl Jlet {
         tracing::debug!("org.kwis.msp.lcdui.Jlet::notifyDestroyed({:?})", &this);
 
         let midlet: ClassInstanceRef<MIDlet> = jvm.get_field(&this, "wipiMidlet", "Lnet/wie/WIPIMIDlet;").await?;
+        if MIDlet::is_destroyed(jvm, &midlet).await? {
+            return Ok(());
+        }
         let _: () = jvm.invoke_virtual(&midlet, "notifyDestroyed", "()V", ()).await?;
 
-        let _: () = jvm.invoke_virtual(&this, "destroyApp", "(Z)V", (false,)).await?;
+        // don't call destroyApp again if we're called from destroyApp
+        let destroying: bool = jvm.get_field(&midlet, "destroying", "Z").await?;
+        if !destroying {
+            let _: () = jvm.invoke_virtual(&this, "destroyApp", "(Z)V", (false,)).await?;
+        }
 
         Ok(())
     }
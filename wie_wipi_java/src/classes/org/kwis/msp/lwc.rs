@@ -1,12 +1,21 @@
 mod annunciator_component;
+mod button_component;
+mod check_box_component;
 mod component;
+mod component_listener;
 mod container_component;
+mod image_component;
+mod label_component;
+mod list_component;
+mod popup_component;
 mod shell_component;
 mod text_box_component;
 mod text_component;
 mod text_field_component;
 
 pub use self::{
-    annunciator_component::AnnunciatorComponent, component::Component, container_component::ContainerComponent, shell_component::ShellComponent,
-    text_box_component::TextBoxComponent, text_component::TextComponent, text_field_component::TextFieldComponent,
+    annunciator_component::AnnunciatorComponent, button_component::ButtonComponent, check_box_component::CheckBoxComponent, component::Component,
+    component_listener::ComponentListener, container_component::ContainerComponent, image_component::ImageComponent, label_component::LabelComponent,
+    list_component::ListComponent, popup_component::PopupComponent, shell_component::ShellComponent, text_box_component::TextBoxComponent,
+    text_component::TextComponent, text_field_component::TextFieldComponent,
 };
@@ -1,38 +1,123 @@
-use alloc::vec;
+use alloc::{format, vec};
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_constants::{FieldAccessFlags, MethodAccessFlags};
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
+use wie_backend::canvas::TextAlignment;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::javax::microedition::lcdui::renderer::{self, BAR_BACKGROUND, BAR_TEXT, LINE_HEIGHT, PADDING};
+
+use crate::classes::org::kwis::msp::{lcdui::Graphics, lwc::Component};
+
+// handsets show korea standard time
+const TIME_OFFSET: u64 = 9 * 60 * 60 * 1000;
 
 // class org.kwis.msp.lwc.AnnunciatorComponent
 pub struct AnnunciatorComponent;
 
 impl AnnunciatorComponent {
+    pub const HEIGHT: i32 = LINE_HEIGHT + PADDING;
+
     pub fn as_proto() -> WieJavaClassProto {
         WieJavaClassProto {
             name: "org/kwis/msp/lwc/AnnunciatorComponent",
-            parent_class: Some("org/kwis/msp/lwc/ShellComponent"),
+            parent_class: Some("org/kwis/msp/lwc/Component"),
             interfaces: vec![],
             methods: vec![
                 JavaMethodProto::new("<init>", "(Z)V", Self::init, Default::default()),
-                JavaMethodProto::new("show", "()V", Self::show, Default::default()),
+                JavaMethodProto::new("show", "()V", Self::show, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("hide", "()V", Self::hide, MethodAccessFlags::STATIC),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("instance", "Lorg/kwis/msp/lwc/AnnunciatorComponent;", FieldAccessFlags::STATIC),
+                JavaFieldProto::new("visible", "Z", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
 
-    async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<AnnunciatorComponent>, a0: bool) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.AnnunciatorComponent::<init>({:?}, {})", &this, a0);
+    // there's a single annunciator on the screen, drawn on top of every shell
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, visible: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.AnnunciatorComponent::<init>({:?}, {})", &this, visible);
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "visible", "Z", visible).await?;
+        jvm.put_static_field(
+            "org/kwis/msp/lwc/AnnunciatorComponent",
+            "instance",
+            "Lorg/kwis/msp/lwc/AnnunciatorComponent;",
+            this,
+        )
+        .await
+    }
+
+    async fn show(jvm: &Jvm, context: &mut WieJvmContext) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.AnnunciatorComponent::show");
+
+        Self::set_visible(jvm, context, true).await
+    }
+
+    async fn hide(jvm: &Jvm, context: &mut WieJvmContext) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.AnnunciatorComponent::hide");
 
-        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/ShellComponent", "<init>", "()V", ()).await?;
+        Self::set_visible(jvm, context, false).await
+    }
+
+    async fn paint(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.AnnunciatorComponent::paint({:?}, {:?})", &this, &graphics);
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+
+        let minutes = (context.system().platform().now().raw() + TIME_OFFSET) / 60000;
+        let time = format!("{:02}:{:02}", (minutes / 60) % 24, minutes % 60);
+
+        renderer.fill_rect(x, y, width, height, BAR_BACKGROUND);
+        renderer.draw_text(
+            &time,
+            x + width - PADDING * 2,
+            y + (height - LINE_HEIGHT) / 2,
+            TextAlignment::Right,
+            BAR_TEXT,
+        );
 
         Ok(())
     }
 
-    async fn show(_: &Jvm, _: &mut WieJvmContext) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.AnnunciatorComponent::show");
+    pub async fn visible_instance(jvm: &Jvm) -> JvmResult<Option<ClassInstanceRef<Self>>> {
+        let instance: ClassInstanceRef<Self> = jvm
+            .get_static_field(
+                "org/kwis/msp/lwc/AnnunciatorComponent",
+                "instance",
+                "Lorg/kwis/msp/lwc/AnnunciatorComponent;",
+            )
+            .await?;
+        if instance.is_null() {
+            return Ok(None);
+        }
+
+        let visible: bool = jvm.get_field(&instance, "visible", "Z").await?;
+
+        Ok(visible.then_some(instance))
+    }
+
+    async fn set_visible(jvm: &Jvm, context: &mut WieJvmContext, visible: bool) -> JvmResult<()> {
+        let mut instance: ClassInstanceRef<Self> = jvm
+            .get_static_field(
+                "org/kwis/msp/lwc/AnnunciatorComponent",
+                "instance",
+                "Lorg/kwis/msp/lwc/AnnunciatorComponent;",
+            )
+            .await?;
+        if instance.is_null() {
+            return Ok(());
+        }
+
+        jvm.put_field(&mut instance, "visible", "Z", visible).await?;
+        renderer::request_redraw(context);
 
         Ok(())
     }
@@ -0,0 +1,112 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::{
+    javax::microedition::lcdui::renderer::{self, BACKGROUND, BORDER, FOREGROUND, HIGHLIGHT, HIGHLIGHT_TEXT, LINE_HEIGHT, PADDING},
+    net::wie::KeyboardEventType,
+};
+
+use crate::classes::{
+    net::wie::WIPIKeyCode,
+    org::kwis::msp::{lcdui::Graphics, lwc::Component},
+};
+
+// class org.kwis.msp.lwc.ButtonComponent
+pub struct ButtonComponent;
+
+impl ButtonComponent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/ButtonComponent",
+            parent_class: Some("org/kwis/msp/lwc/Component"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("setLabel", "(Ljava/lang/String;)V", Self::set_label, Default::default()),
+                JavaMethodProto::new("getLabel", "()Ljava/lang/String;", Self::get_label, Default::default()),
+                JavaMethodProto::new("isFocusable", "()Z", Self::is_focusable, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("label", "Ljava/lang/String;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, label: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::<init>({this:?}, {label:?})");
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await
+    }
+
+    async fn set_label(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, label: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::setLabel({this:?}, {label:?})");
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_label(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::getLabel({this:?})");
+
+        jvm.get_field(&this, "label", "Ljava/lang/String;").await
+    }
+
+    async fn is_focusable(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::isFocusable({this:?})");
+
+        Ok(true)
+    }
+
+    async fn get_preferred_height(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::getPreferredHeight({this:?})");
+
+        Ok(LINE_HEIGHT + PADDING * 4)
+    }
+
+    async fn key_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::keyNotify({this:?}, {type}, {chr})");
+
+        if !matches!(KeyboardEventType::try_from_raw(r#type), Some(KeyboardEventType::KeyPressed))
+            || !matches!(WIPIKeyCode::try_from_raw(chr), Some(WIPIKeyCode::FIRE))
+        {
+            return Ok(false);
+        }
+
+        Component::notify_listener(jvm, &this, Component::ACTION).await?;
+
+        Ok(true)
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ButtonComponent::paint({this:?}, {graphics:?})");
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let focused: bool = jvm.get_field(&this, "focused", "Z").await?;
+        let label: ClassInstanceRef<String> = jvm.get_field(&this, "label", "Ljava/lang/String;").await?;
+        let label = renderer::rust_string(jvm, &label).await?;
+
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+
+        let (background, text) = if focused {
+            (HIGHLIGHT, HIGHLIGHT_TEXT)
+        } else {
+            (BACKGROUND, FOREGROUND)
+        };
+        renderer.fill_rect(x + PADDING, y + PADDING, width - PADDING * 2, height - PADDING * 2, background);
+        renderer.draw_rect(x + PADDING, y + PADDING, width - PADDING * 2 - 1, height - PADDING * 2 - 1, BORDER);
+        renderer.draw_text(&label, x + width / 2, y + (height - LINE_HEIGHT) / 2, TextAlignment::Center, text);
+
+        Ok(())
+    }
+}
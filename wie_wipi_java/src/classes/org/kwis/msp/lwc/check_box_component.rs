@@ -0,0 +1,177 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::{
+    javax::microedition::lcdui::renderer::{self, FOREGROUND, HIGHLIGHT, HIGHLIGHT_TEXT, LINE_HEIGHT, PADDING},
+    net::wie::KeyboardEventType,
+};
+
+use crate::classes::{
+    net::wie::WIPIKeyCode,
+    org::kwis::msp::{lcdui::Graphics, lwc::Component},
+};
+
+// class org.kwis.msp.lwc.CheckBoxComponent
+pub struct CheckBoxComponent;
+
+impl CheckBoxComponent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/CheckBoxComponent",
+            parent_class: Some("org/kwis/msp/lwc/Component"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;Z)V", Self::init, Default::default()),
+                JavaMethodProto::new("isChecked", "()Z", Self::is_checked, Default::default()),
+                JavaMethodProto::new("setChecked", "(Z)V", Self::set_checked, Default::default()),
+                JavaMethodProto::new("isFocusable", "()Z", Self::is_focusable, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("label", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("checked", "Z", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        label: ClassInstanceRef<String>,
+        checked: bool,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.CheckBoxComponent::<init>({this:?}, {label:?}, {checked})");
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "label", "Ljava/lang/String;", label).await?;
+        jvm.put_field(&mut this, "checked", "Z", checked).await
+    }
+
+    async fn is_checked(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.CheckBoxComponent::isChecked({this:?})");
+
+        jvm.get_field(&this, "checked", "Z").await
+    }
+
+    async fn set_checked(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, checked: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.CheckBoxComponent::setChecked({this:?}, {checked})");
+
+        jvm.put_field(&mut this, "checked", "Z", checked).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn is_focusable(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.CheckBoxComponent::isFocusable({this:?})");
+
+        Ok(true)
+    }
+
+    // fire key toggles the check box
+    async fn key_notify(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.CheckBoxComponent::keyNotify({this:?}, {type}, {chr})");
+
+        if !matches!(KeyboardEventType::try_from_raw(r#type), Some(KeyboardEventType::KeyPressed))
+            || !matches!(WIPIKeyCode::try_from_raw(chr), Some(WIPIKeyCode::FIRE))
+        {
+            return Ok(false);
+        }
+
+        let checked: bool = jvm.get_field(&this, "checked", "Z").await?;
+        jvm.put_field(&mut this, "checked", "Z", !checked).await?;
+        renderer::request_redraw(context);
+
+        Component::notify_listener(jvm, &this, Component::ACTION).await?;
+
+        Ok(true)
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.CheckBoxComponent::paint({this:?}, {graphics:?})");
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let focused: bool = jvm.get_field(&this, "focused", "Z").await?;
+        let checked: bool = jvm.get_field(&this, "checked", "Z").await?;
+        let label: ClassInstanceRef<String> = jvm.get_field(&this, "label", "Ljava/lang/String;").await?;
+        let label = renderer::rust_string(jvm, &label).await?;
+
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+
+        let color = if focused {
+            renderer.fill_rect(x, y, width, height, HIGHLIGHT);
+            HIGHLIGHT_TEXT
+        } else {
+            FOREGROUND
+        };
+
+        let line_y = y + (height - LINE_HEIGHT) / 2;
+        renderer.draw_choice_mark(x + PADDING * 2, line_y, false, checked, color);
+        renderer.draw_text(&label, x + PADDING * 4 + LINE_HEIGHT, line_y, TextAlignment::Left, color);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_midp::classes::net::wie::KeyboardEventType;
+    use wie_util::Result;
+
+    use crate::classes::{
+        net::wie::WIPIKeyCode,
+        org::kwis::msp::lwc::{
+            Component,
+            component_listener::test::{TestListener, key_notify, protos},
+        },
+    };
+
+    const PRESSED: i32 = KeyboardEventType::KeyPressed as _;
+    const RELEASED: i32 = KeyboardEventType::KeyReleased as _;
+
+    async fn is_checked(jvm: &Jvm, check_box: &ClassInstanceRef<super::CheckBoxComponent>) -> JvmResult<bool> {
+        jvm.invoke_virtual(check_box, "isChecked", "()Z", ()).await
+    }
+
+    #[test]
+    fn test_key_notify() -> Result<()> {
+        run_jvm_test(protos(), |jvm| async move {
+            let label = JavaLangString::from_rust_string(&jvm, "check").await?;
+            let check_box: ClassInstanceRef<super::CheckBoxComponent> = jvm
+                .new_class("org/kwis/msp/lwc/CheckBoxComponent", "(Ljava/lang/String;Z)V", (label, false))
+                .await?
+                .into();
+            let listener = TestListener::listen(&jvm, &check_box).await?;
+
+            // only pressed fire key toggles
+            assert!(!key_notify(&jvm, &check_box, RELEASED, WIPIKeyCode::FIRE as _).await?);
+            assert!(!key_notify(&jvm, &check_box, PRESSED, WIPIKeyCode::UP as _).await?);
+            assert!(!key_notify(&jvm, &check_box, PRESSED, -99).await?);
+            assert!(!key_notify(&jvm, &check_box, 0, WIPIKeyCode::FIRE as _).await?);
+            assert!(!is_checked(&jvm, &check_box).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (0, 0));
+
+            assert!(key_notify(&jvm, &check_box, PRESSED, WIPIKeyCode::FIRE as _).await?);
+            assert!(is_checked(&jvm, &check_box).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (1, Component::ACTION));
+
+            assert!(key_notify(&jvm, &check_box, PRESSED, WIPIKeyCode::FIRE as _).await?);
+            assert!(!is_checked(&jvm, &check_box).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (2, Component::ACTION));
+
+            Ok(())
+        })
+    }
+}
@@ -1,15 +1,24 @@
 use alloc::vec;
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::MethodAccessFlags;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::javax::microedition::lcdui::renderer::{self, LINE_HEIGHT, PADDING, Renderer};
+
+use crate::classes::org::kwis::msp::{
+    lcdui::Graphics,
+    lwc::{ComponentListener, ContainerComponent},
+};
 
 // class org.kwis.msp.lwc.Component
 pub struct Component;
 
 impl Component {
+    // event passed to ComponentListener when component is activated
+    pub const ACTION: i32 = 1;
+
     pub fn as_proto() -> WieJavaClassProto {
         WieJavaClassProto {
             name: "org/kwis/msp/lwc/Component",
@@ -22,15 +31,47 @@ impl Component {
                 JavaMethodProto::new("showNotify", "(Z)V", Self::show_notify, Default::default()),
                 JavaMethodProto::new("configure", "(IIIII)V", Self::configure, Default::default()),
                 JavaMethodProto::new("setFocus", "()V", Self::set_focus, Default::default()),
+                JavaMethodProto::new("isFocused", "()Z", Self::is_focused, Default::default()),
+                JavaMethodProto::new("isFocusable", "()Z", Self::is_focusable, Default::default()),
+                JavaMethodProto::new("isShown", "()Z", Self::is_shown, Default::default()),
+                JavaMethodProto::new("getX", "()I", Self::get_x, Default::default()),
+                JavaMethodProto::new("getY", "()I", Self::get_y, Default::default()),
+                JavaMethodProto::new("getWidth", "()I", Self::get_width, Default::default()),
                 JavaMethodProto::new("getHeight", "()I", Self::get_height, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new(
+                    "getParent",
+                    "()Lorg/kwis/msp/lwc/ContainerComponent;",
+                    Self::get_parent,
+                    Default::default(),
+                ),
+                JavaMethodProto::new(
+                    "setListener",
+                    "(Lorg/kwis/msp/lwc/ComponentListener;)V",
+                    Self::set_listener,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("repaint", "()V", Self::repaint, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+                // wie private
+                JavaMethodProto::new("layout", "()V", Self::layout, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("x", "I", Default::default()),
+                JavaFieldProto::new("y", "I", Default::default()),
+                JavaFieldProto::new("w", "I", Default::default()),
+                JavaFieldProto::new("h", "I", Default::default()),
+                JavaFieldProto::new("parent", "Lorg/kwis/msp/lwc/ContainerComponent;", Default::default()),
+                JavaFieldProto::new("listener", "Lorg/kwis/msp/lwc/ComponentListener;", Default::default()),
+                JavaFieldProto::new("focused", "Z", Default::default()),
+                JavaFieldProto::new("shown", "Z", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
 
     async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::debug!("stub org.kwis.msp.lwc.Component::<init>({this:?})");
+        tracing::debug!("org.kwis.msp.lwc.Component::<init>({this:?})");
 
         let _: () = jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await?;
 
@@ -38,38 +79,172 @@ impl Component {
     }
 
     async fn key_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::keyNotify({:?}, {:?}, {:?})", &this, r#type, chr);
+        tracing::debug!("org.kwis.msp.lwc.Component::keyNotify({:?}, {:?}, {:?})", &this, r#type, chr);
 
-        Ok(true)
+        // unhandled keys bubble up to the parent container
+        Ok(false)
     }
 
-    async fn focus_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, focus: bool) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::focusNotify({:?}, {:?})", &this, focus);
+    async fn focus_notify(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, focus: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::focusNotify({:?}, {:?})", &this, focus);
+
+        jvm.put_field(&mut this, "focused", "Z", focus).await?;
+        renderer::request_redraw(context);
 
         Ok(())
     }
 
-    async fn show_notify(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, show: bool) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::showNotify({:?}, {:?})", &this, show);
+    async fn show_notify(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, show: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::showNotify({:?}, {:?})", &this, show);
+
+        jvm.put_field(&mut this, "shown", "Z", show).await
+    }
+
+    // last argument is layout attribute of the original implementation, which we don't use
+    async fn configure(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        attr: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::configure({this:?}, {x}, {y}, {w}, {h}, {attr})");
+
+        jvm.put_field(&mut this, "x", "I", x).await?;
+        jvm.put_field(&mut this, "y", "I", y).await?;
+        jvm.put_field(&mut this, "w", "I", w).await?;
+        jvm.put_field(&mut this, "h", "I", h).await?;
+
+        jvm.invoke_virtual(&this, "layout", "()V", ()).await
+    }
+
+    async fn set_focus(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::setFocus({:?})", &this);
+
+        let parent: ClassInstanceRef<ContainerComponent> = jvm.get_field(&this, "parent", "Lorg/kwis/msp/lwc/ContainerComponent;").await?;
+        if parent.is_null() {
+            return Ok(());
+        }
+
+        jvm.invoke_virtual(&parent, "focusComponent", "(Lorg/kwis/msp/lwc/Component;)V", (this,))
+            .await
+    }
+
+    async fn is_focused(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.Component::isFocused({:?})", &this);
+
+        jvm.get_field(&this, "focused", "Z").await
+    }
+
+    async fn is_focusable(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.Component::isFocusable({:?})", &this);
+
+        Ok(false)
+    }
+
+    async fn is_shown(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.Component::isShown({:?})", &this);
+
+        jvm.get_field(&this, "shown", "Z").await
+    }
+
+    async fn get_x(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.Component::getX({:?})", &this);
+
+        jvm.get_field(&this, "x", "I").await
+    }
+
+    async fn get_y(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.Component::getY({:?})", &this);
+
+        jvm.get_field(&this, "y", "I").await
+    }
+
+    async fn get_width(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.Component::getWidth({:?})", &this);
+
+        jvm.get_field(&this, "w", "I").await
+    }
+
+    async fn get_height(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.Component::getHeight({:?})", &this);
+
+        jvm.get_field(&this, "h", "I").await
+    }
+
+    // height wanted in vertical layout of the parent, a single line by default
+    async fn get_preferred_height(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.Component::getPreferredHeight({:?})", &this);
+
+        Ok(LINE_HEIGHT + PADDING * 2)
+    }
+
+    async fn get_parent(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<ContainerComponent>> {
+        tracing::debug!("org.kwis.msp.lwc.Component::getParent({:?})", &this);
+
+        jvm.get_field(&this, "parent", "Lorg/kwis/msp/lwc/ContainerComponent;").await
+    }
+
+    async fn set_listener(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        listener: ClassInstanceRef<ComponentListener>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::setListener({:?}, {:?})", &this, &listener);
+
+        jvm.put_field(&mut this, "listener", "Lorg/kwis/msp/lwc/ComponentListener;", listener)
+            .await
+    }
+
+    async fn repaint(_: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::repaint({:?})", &this);
+
+        renderer::request_redraw(context);
 
         Ok(())
     }
 
-    async fn configure(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, x: i32, y: i32, w: i32, h: i32) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::configure({this:?}, {x}, {y}, {w}, {h})",);
+    async fn paint(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::paint({:?}, {:?})", &this, &graphics);
 
         Ok(())
     }
 
-    async fn set_focus(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::setFocus({:?})", &this,);
+    async fn layout(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.Component::layout({:?})", &this);
 
         Ok(())
     }
 
-    async fn get_height(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::getHeight({:?})", &this,);
+    pub async fn size<T>(jvm: &Jvm, this: &ClassInstanceRef<T>) -> JvmResult<(i32, i32)> {
+        let w: i32 = jvm.get_field(this, "w", "I").await?;
+        let h: i32 = jvm.get_field(this, "h", "I").await?;
+
+        Ok((w, h))
+    }
+
+    // components are painted with graphics translated to their origin, which we return in screen coordinates
+    pub async fn renderer(jvm: &Jvm, graphics: &ClassInstanceRef<Graphics>) -> JvmResult<(Renderer, i32, i32)> {
+        let midp_graphics = Graphics::midp_graphics(jvm, graphics).await?;
+        let origin_x: i32 = jvm.invoke_virtual(&midp_graphics, "getTranslateX", "()I", ()).await?;
+        let origin_y: i32 = jvm.invoke_virtual(&midp_graphics, "getTranslateY", "()I", ()).await?;
+
+        let renderer = Renderer::new(jvm, &midp_graphics).await?;
+
+        Ok((renderer, origin_x, origin_y))
+    }
+
+    pub async fn notify_listener<T>(jvm: &Jvm, this: &ClassInstanceRef<T>, event: i32) -> JvmResult<()> {
+        let listener: ClassInstanceRef<ComponentListener> = jvm.get_field(this, "listener", "Lorg/kwis/msp/lwc/ComponentListener;").await?;
+        if listener.is_null() {
+            return Ok(());
+        }
 
-        Ok(0)
+        jvm.invoke_virtual(&listener, "componentAction", "(Lorg/kwis/msp/lwc/Component;I)V", (this.clone(), event))
+            .await
     }
 }
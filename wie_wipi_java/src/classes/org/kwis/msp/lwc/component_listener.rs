@@ -0,0 +1,106 @@
+use alloc::vec;
+
+use java_class_proto::JavaMethodProto;
+use java_constants::ClassAccessFlags;
+use wie_jvm_support::WieJavaClassProto;
+
+// interface org.kwis.msp.lwc.ComponentListener
+pub struct ComponentListener;
+
+impl ComponentListener {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/ComponentListener",
+            parent_class: None,
+            interfaces: vec![],
+            methods: vec![JavaMethodProto::new_abstract(
+                "componentAction",
+                "(Lorg/kwis/msp/lwc/Component;I)V",
+                Default::default(),
+            )],
+            fields: vec![],
+            access_flags: ClassAccessFlags::INTERFACE,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use alloc::{boxed::Box, vec};
+
+    use java_class_proto::{JavaFieldProto, JavaMethodProto};
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+    use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+
+    use crate::{classes::org::kwis::msp::lwc::Component, get_protos};
+
+    // records actions reported by components
+    pub struct TestListener;
+
+    impl TestListener {
+        fn as_proto() -> WieJavaClassProto {
+            WieJavaClassProto {
+                name: "net/wie/TestComponentListener",
+                parent_class: Some("java/lang/Object"),
+                interfaces: vec!["org/kwis/msp/lwc/ComponentListener"],
+                methods: vec![
+                    JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                    JavaMethodProto::new(
+                        "componentAction",
+                        "(Lorg/kwis/msp/lwc/Component;I)V",
+                        Self::component_action,
+                        Default::default(),
+                    ),
+                ],
+                fields: vec![
+                    JavaFieldProto::new("count", "I", Default::default()),
+                    JavaFieldProto::new("lastEvent", "I", Default::default()),
+                ],
+                access_flags: Default::default(),
+            }
+        }
+
+        async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+            jvm.invoke_special(&this, "java/lang/Object", "<init>", "()V", ()).await
+        }
+
+        async fn component_action(
+            jvm: &Jvm,
+            _: &mut WieJvmContext,
+            mut this: ClassInstanceRef<Self>,
+            _: ClassInstanceRef<Component>,
+            event: i32,
+        ) -> JvmResult<()> {
+            let count: i32 = jvm.get_field(&this, "count", "I").await?;
+            jvm.put_field(&mut this, "count", "I", count + 1).await?;
+
+            jvm.put_field(&mut this, "lastEvent", "I", event).await
+        }
+
+        pub async fn listen<T>(jvm: &Jvm, component: &ClassInstanceRef<T>) -> JvmResult<ClassInstanceRef<Self>> {
+            let listener: ClassInstanceRef<Self> = jvm.new_class("net/wie/TestComponentListener", "()V", ()).await?.into();
+            let _: () = jvm
+                .invoke_virtual(component, "setListener", "(Lorg/kwis/msp/lwc/ComponentListener;)V", (listener.clone(),))
+                .await?;
+
+            Ok(listener)
+        }
+
+        // number of actions and the last event
+        pub async fn actions(jvm: &Jvm, listener: &ClassInstanceRef<Self>) -> JvmResult<(i32, i32)> {
+            let count: i32 = jvm.get_field(listener, "count", "I").await?;
+            let last_event: i32 = jvm.get_field(listener, "lastEvent", "I").await?;
+
+            Ok((count, last_event))
+        }
+    }
+
+    pub fn protos() -> Box<[Box<[WieJavaClassProto]>]> {
+        Box::new([wie_midp::get_protos().into(), get_protos().into(), Box::new([TestListener::as_proto()])])
+    }
+
+    pub async fn key_notify<T>(jvm: &Jvm, component: &ClassInstanceRef<T>, r#type: i32, key: i32) -> JvmResult<bool> {
+        jvm.invoke_virtual(component, "keyNotify", "(II)Z", (r#type, key)).await
+    }
+}
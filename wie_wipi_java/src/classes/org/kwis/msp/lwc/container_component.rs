@@ -1,12 +1,17 @@
 use alloc::vec;
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_constants::MethodAccessFlags;
+use java_runtime::classes::java::util::Vector;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::net::wie::KeyboardEventType;
 
-use crate::classes::org::kwis::msp::lwc::Component;
+use crate::classes::{
+    net::wie::WIPIKeyCode,
+    org::kwis::msp::{lcdui::Graphics, lwc::Component},
+};
 
 // class org.kwis.msp.lwc.ContainerComponent
 pub struct ContainerComponent;
@@ -21,29 +26,476 @@ impl ContainerComponent {
                 JavaMethodProto::new("<init>", "()V", Self::init, MethodAccessFlags::PROTECTED),
                 JavaMethodProto::new("addComponent", "(Lorg/kwis/msp/lwc/Component;)I", Self::add_component, Default::default()),
                 JavaMethodProto::new("removeComponent", "(I)V", Self::remove_component, Default::default()),
+                JavaMethodProto::new("removeAllComponents", "()V", Self::remove_all_components, Default::default()),
+                JavaMethodProto::new("getComponent", "(I)Lorg/kwis/msp/lwc/Component;", Self::get_component, Default::default()),
+                JavaMethodProto::new("getComponentCount", "()I", Self::get_component_count, Default::default()),
+                JavaMethodProto::new(
+                    "getFocusedComponent",
+                    "()Lorg/kwis/msp/lwc/Component;",
+                    Self::get_focused_component,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("isFocusable", "()Z", Self::is_focusable, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("focusNotify", "(Z)V", Self::focus_notify, Default::default()),
+                JavaMethodProto::new("showNotify", "(Z)V", Self::show_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+                // wie private
+                JavaMethodProto::new("layout", "()V", Self::layout, Default::default()),
+                JavaMethodProto::new(
+                    "focusComponent",
+                    "(Lorg/kwis/msp/lwc/Component;)V",
+                    Self::focus_component,
+                    Default::default(),
+                ),
+            ],
+            fields: vec![
+                JavaFieldProto::new("components", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("focusIndex", "I", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
 
-    async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::debug!("stub org.kwis.msp.lwc.ContainerComponent::<init>({this:?})");
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::<init>({this:?})");
 
         let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
 
+        let components = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "components", "Ljava/util/Vector;", components).await?;
+        jvm.put_field(&mut this, "focusIndex", "I", -1).await?;
+
+        Ok(())
+    }
+
+    async fn add_component(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        mut component: ClassInstanceRef<Component>,
+    ) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::addComponent({this:?}, {component:?})");
+
+        if component.is_null() {
+            return Err(jvm.exception("java/lang/NullPointerException", "component is null").await);
+        }
+
+        let components = Self::components(jvm, &this).await?;
+        let index: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+        let _: () = jvm
+            .invoke_virtual(&components, "addElement", "(Ljava/lang/Object;)V", (component.clone(),))
+            .await?;
+        jvm.put_field(&mut component, "parent", "Lorg/kwis/msp/lwc/ContainerComponent;", this.clone())
+            .await?;
+
+        // first focusable component gets focus
+        let focus_index: i32 = jvm.get_field(&this, "focusIndex", "I").await?;
+        let focusable: bool = jvm.invoke_virtual(&component, "isFocusable", "()Z", ()).await?;
+        if focus_index == -1 && focusable {
+            Self::move_focus(jvm, &this, index).await?;
+        }
+
+        let shown: bool = jvm.get_field(&this, "shown", "Z").await?;
+        if shown {
+            let _: () = jvm.invoke_virtual(&component, "showNotify", "(Z)V", (true,)).await?;
+        }
+
+        let _: () = jvm.invoke_virtual(&this, "layout", "()V", ()).await?;
+
+        Ok(index)
+    }
+
+    async fn remove_component(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::removeComponent({this:?}, {index})");
+
+        let components = Self::components(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+        if index < 0 || index >= size {
+            return Err(jvm.exception("java/lang/ArrayIndexOutOfBoundsException", "invalid component index").await);
+        }
+
+        let focus_index: i32 = jvm.get_field(&this, "focusIndex", "I").await?;
+        if focus_index == index {
+            Self::move_focus(jvm, &this, -1).await?;
+        }
+
+        let mut component: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+        let _: () = jvm.invoke_virtual(&components, "removeElementAt", "(I)V", (index,)).await?;
+        jvm.put_field(&mut component, "parent", "Lorg/kwis/msp/lwc/ContainerComponent;", None)
+            .await?;
+        let _: () = jvm.invoke_virtual(&component, "showNotify", "(Z)V", (false,)).await?;
+
+        if focus_index > index {
+            jvm.put_field(&mut this, "focusIndex", "I", focus_index - 1).await?;
+        } else if focus_index == index {
+            // focus moves to the component which took place of the removed one
+            let next = match Self::find_focusable(jvm, &this, index - 1, 1).await? {
+                Some(x) => Some(x),
+                None => Self::find_focusable(jvm, &this, index, -1).await?,
+            };
+            if let Some(next) = next {
+                Self::move_focus(jvm, &this, next).await?;
+            }
+        }
+
+        jvm.invoke_virtual(&this, "layout", "()V", ()).await
+    }
+
+    async fn remove_all_components(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::removeAllComponents({this:?})");
+
+        let components = Self::components(jvm, &this).await?;
+        loop {
+            let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+            if size == 0 {
+                break;
+            }
+
+            let _: () = jvm.invoke_virtual(&this, "removeComponent", "(I)V", (size - 1,)).await?;
+        }
+
         Ok(())
     }
 
-    async fn add_component(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, component: ClassInstanceRef<Component>) -> JvmResult<i32> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::addComponent({this:?}, {component:?})");
+    async fn get_component(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<Component>> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::getComponent({this:?}, {index})");
+
+        let components = Self::components(jvm, &this).await?;
+        jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await
+    }
+
+    async fn get_component_count(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::getComponentCount({this:?})");
+
+        let components = Self::components(jvm, &this).await?;
+        jvm.invoke_virtual(&components, "size", "()I", ()).await
+    }
+
+    async fn get_focused_component(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Component>> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::getFocusedComponent({this:?})");
 
-        Ok(0)
+        Self::focused_component(jvm, &this).await
     }
 
-    async fn remove_component(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.Component::removeComponent({this:?}, {index})");
+    // containers take focus on behalf of their focusable children
+    async fn is_focusable(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::isFocusable({this:?})");
+
+        Ok(Self::find_focusable(jvm, &this, -1, 1).await?.is_some())
+    }
+
+    async fn get_preferred_height(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::getPreferredHeight({this:?})");
+
+        let components = Self::components(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+
+        let mut height = 0;
+        for index in 0..size {
+            let component: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let preferred_height: i32 = jvm.invoke_virtual(&component, "getPreferredHeight", "()I", ()).await?;
+            height += preferred_height;
+        }
+
+        Ok(height)
+    }
+
+    // keys go to the focused component first, and move focus with up and down keys if not handled
+    async fn key_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::keyNotify({this:?}, {type}, {chr})");
+
+        let focused = Self::focused_component(jvm, &this).await?;
+        if !focused.is_null() {
+            let handled: bool = jvm.invoke_virtual(&focused, "keyNotify", "(II)Z", (r#type, chr)).await?;
+            if handled {
+                return Ok(true);
+            }
+        }
+
+        if !matches!(
+            KeyboardEventType::try_from_raw(r#type),
+            Some(KeyboardEventType::KeyPressed | KeyboardEventType::KeyRepeated)
+        ) {
+            return Ok(false);
+        }
+
+        let direction = match WIPIKeyCode::try_from_raw(chr) {
+            Some(WIPIKeyCode::UP) => -1,
+            Some(WIPIKeyCode::DOWN) => 1,
+            _ => return Ok(false),
+        };
+
+        let focus_index: i32 = jvm.get_field(&this, "focusIndex", "I").await?;
+        match Self::find_focusable(jvm, &this, focus_index, direction).await? {
+            Some(next) => {
+                Self::move_focus(jvm, &this, next).await?;
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn focus_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, focus: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::focusNotify({this:?}, {focus})");
+
+        let _: () = jvm
+            .invoke_special(&this, "org/kwis/msp/lwc/Component", "focusNotify", "(Z)V", (focus,))
+            .await?;
+
+        let focused = Self::focused_component(jvm, &this).await?;
+        if !focused.is_null() {
+            let _: () = jvm.invoke_virtual(&focused, "focusNotify", "(Z)V", (focus,)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn show_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, show: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::showNotify({this:?}, {show})");
+
+        let _: () = jvm
+            .invoke_special(&this, "org/kwis/msp/lwc/Component", "showNotify", "(Z)V", (show,))
+            .await?;
+
+        let components = Self::components(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+        for index in 0..size {
+            let component: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let _: () = jvm.invoke_virtual(&component, "showNotify", "(Z)V", (show,)).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::paint({this:?}, {graphics:?})");
+
+        let components = Self::components(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+        for index in 0..size {
+            let component: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            Self::paint_component(jvm, &graphics, &component).await?;
+        }
 
         Ok(())
     }
+
+    // stacks components vertically with their preferred height, filling container width
+    async fn layout(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::layout({this:?})");
+
+        let (width, _) = Component::size(jvm, &this).await?;
+
+        let components = Self::components(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+
+        let mut y = 0;
+        for index in 0..size {
+            let component: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let height: i32 = jvm.invoke_virtual(&component, "getPreferredHeight", "()I", ()).await?;
+
+            let _: () = jvm.invoke_virtual(&component, "configure", "(IIIII)V", (0, y, width, height, 0)).await?;
+            y += height;
+        }
+
+        Ok(())
+    }
+
+    async fn focus_component(
+        jvm: &Jvm,
+        _: &mut WieJvmContext,
+        this: ClassInstanceRef<Self>,
+        component: ClassInstanceRef<Component>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ContainerComponent::focusComponent({this:?}, {component:?})");
+
+        let components = Self::components(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+        for index in 0..size {
+            let item: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let same: bool = jvm.invoke_virtual(&item, "equals", "(Ljava/lang/Object;)Z", (component.clone(),)).await?;
+            if same {
+                return Self::move_focus(jvm, &this, index).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    // paints component with graphics translated and clipped to its bounds
+    pub async fn paint_component<T>(jvm: &Jvm, graphics: &ClassInstanceRef<Graphics>, component: &ClassInstanceRef<T>) -> JvmResult<()> {
+        let x: i32 = jvm.get_field(component, "x", "I").await?;
+        let y: i32 = jvm.get_field(component, "y", "I").await?;
+        let (width, height) = Component::size(jvm, component).await?;
+        if width <= 0 || height <= 0 {
+            return Ok(());
+        }
+
+        let clip_x: i32 = jvm.invoke_virtual(graphics, "getClipX", "()I", ()).await?;
+        let clip_y: i32 = jvm.invoke_virtual(graphics, "getClipY", "()I", ()).await?;
+        let clip_width: i32 = jvm.invoke_virtual(graphics, "getClipWidth", "()I", ()).await?;
+        let clip_height: i32 = jvm.invoke_virtual(graphics, "getClipHeight", "()I", ()).await?;
+
+        // clip is kept in screen coordinates
+        let _: () = jvm.invoke_virtual(graphics, "translate", "(II)V", (x, y)).await?;
+        let origin_x: i32 = jvm.invoke_virtual(graphics, "getTranslateX", "()I", ()).await?;
+        let origin_y: i32 = jvm.invoke_virtual(graphics, "getTranslateY", "()I", ()).await?;
+        let _: () = jvm
+            .invoke_virtual(graphics, "clipRect", "(IIII)V", (origin_x, origin_y, width, height))
+            .await?;
+
+        let _: () = jvm
+            .invoke_virtual(component, "paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", (graphics.clone(),))
+            .await?;
+
+        let _: () = jvm.invoke_virtual(graphics, "translate", "(II)V", (-x, -y)).await?;
+        let _: () = jvm
+            .invoke_virtual(graphics, "setClip", "(IIII)V", (clip_x, clip_y, clip_width, clip_height))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn components(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Vector>> {
+        jvm.get_field(this, "components", "Ljava/util/Vector;").await
+    }
+
+    async fn focused_component(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Component>> {
+        let focus_index: i32 = jvm.get_field(this, "focusIndex", "I").await?;
+        if focus_index < 0 {
+            return Ok(None.into());
+        }
+
+        let components = Self::components(jvm, this).await?;
+        jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (focus_index,))
+            .await
+    }
+
+    // finds next focusable component from `from` in `direction`, excluding `from`
+    async fn find_focusable(jvm: &Jvm, this: &ClassInstanceRef<Self>, from: i32, direction: i32) -> JvmResult<Option<i32>> {
+        let components = Self::components(jvm, this).await?;
+        let size: i32 = jvm.invoke_virtual(&components, "size", "()I", ()).await?;
+
+        let mut index = from + direction;
+        while index >= 0 && index < size {
+            let component: ClassInstanceRef<Component> = jvm.invoke_virtual(&components, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let focusable: bool = jvm.invoke_virtual(&component, "isFocusable", "()Z", ()).await?;
+            if focusable {
+                return Ok(Some(index));
+            }
+
+            index += direction;
+        }
+
+        Ok(None)
+    }
+
+    // moves focus to component at `index`, or clears focus if index is -1
+    async fn move_focus(jvm: &Jvm, this: &ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        let focus_index: i32 = jvm.get_field(this, "focusIndex", "I").await?;
+        if focus_index == index {
+            return Ok(());
+        }
+
+        // components only get focus while the container has it
+        let focused: bool = jvm.get_field(this, "focused", "Z").await?;
+
+        let old = Self::focused_component(jvm, this).await?;
+        if focused && !old.is_null() {
+            let _: () = jvm.invoke_virtual(&old, "focusNotify", "(Z)V", (false,)).await?;
+        }
+
+        let mut this = this.clone();
+        jvm.put_field(&mut this, "focusIndex", "I", index).await?;
+
+        let new = Self::focused_component(jvm, &this).await?;
+        if focused && !new.is_null() {
+            let _: () = jvm.invoke_virtual(&new, "focusNotify", "(Z)V", (true,)).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::boxed::Box;
+
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_util::Result;
+
+    use crate::{
+        classes::{net::wie::WIPIKeyCode, org::kwis::msp::lwc::Component},
+        get_protos,
+    };
+
+    async fn new_component(jvm: &Jvm, class: &str, label: &str) -> JvmResult<ClassInstanceRef<Component>> {
+        let label = JavaLangString::from_rust_string(jvm, label).await?;
+
+        Ok(jvm.new_class(class, "(Ljava/lang/String;)V", (label,)).await?.into())
+    }
+
+    async fn key_pressed(jvm: &Jvm, component: &ClassInstanceRef<super::ContainerComponent>, key: WIPIKeyCode) -> JvmResult<bool> {
+        jvm.invoke_virtual(component, "keyNotify", "(II)Z", (1, key as i32)).await
+    }
+
+    async fn is_focused(jvm: &Jvm, component: &ClassInstanceRef<Component>) -> JvmResult<bool> {
+        jvm.invoke_virtual(component, "isFocused", "()Z", ()).await
+    }
+
+    #[test]
+    fn test_layout_and_focus() -> Result<()> {
+        run_jvm_test(Box::new([wie_midp::get_protos().into(), get_protos().into()]), |jvm| async move {
+            let container: ClassInstanceRef<super::ContainerComponent> =
+                jvm.new_class("org/kwis/msp/lwc/ContainerComponent", "()V", ()).await?.into();
+            let _: () = jvm.invoke_virtual(&container, "configure", "(IIIII)V", (0, 0, 240, 320, 0)).await?;
+            let _: () = jvm.invoke_virtual(&container, "focusNotify", "(Z)V", (true,)).await?;
+
+            let label = new_component(&jvm, "org/kwis/msp/lwc/LabelComponent", "label").await?;
+            let first = new_component(&jvm, "org/kwis/msp/lwc/ButtonComponent", "first").await?;
+            let second = new_component(&jvm, "org/kwis/msp/lwc/ButtonComponent", "second").await?;
+            for component in [&label, &first, &second] {
+                let _: i32 = jvm
+                    .invoke_virtual(&container, "addComponent", "(Lorg/kwis/msp/lwc/Component;)I", (component.clone(),))
+                    .await?;
+            }
+
+            // components are stacked vertically with container width
+            let label_height: i32 = jvm.invoke_virtual(&label, "getHeight", "()I", ()).await?;
+            let first_y: i32 = jvm.invoke_virtual(&first, "getY", "()I", ()).await?;
+            let second_width: i32 = jvm.invoke_virtual(&second, "getWidth", "()I", ()).await?;
+            assert_eq!(first_y, label_height);
+            assert_eq!(second_width, 240);
+
+            // label isn't focusable, so the first button gets focus
+            assert!(!is_focused(&jvm, &label).await?);
+            assert!(is_focused(&jvm, &first).await?);
+
+            assert!(key_pressed(&jvm, &container, WIPIKeyCode::DOWN).await?);
+            assert!(!is_focused(&jvm, &first).await?);
+            assert!(is_focused(&jvm, &second).await?);
+
+            // keys not handled at the end of focus chain bubble up
+            assert!(!key_pressed(&jvm, &container, WIPIKeyCode::DOWN).await?);
+            assert!(is_focused(&jvm, &second).await?);
+
+            assert!(key_pressed(&jvm, &container, WIPIKeyCode::UP).await?);
+            assert!(is_focused(&jvm, &first).await?);
+
+            // removing focused component moves focus to the next one
+            let _: () = jvm.invoke_virtual(&container, "removeComponent", "(I)V", (1,)).await?;
+            assert!(!is_focused(&jvm, &first).await?);
+            assert!(is_focused(&jvm, &second).await?);
+
+            let second_y: i32 = jvm.invoke_virtual(&second, "getY", "()I", ()).await?;
+            assert_eq!(second_y, label_height);
+
+            Ok(())
+        })
+    }
 }
@@ -0,0 +1,92 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::javax::microedition::lcdui::{
+    Image as MidpImage,
+    renderer::{self, LINE_HEIGHT, PADDING},
+};
+
+use crate::classes::org::kwis::msp::{
+    lcdui::{Graphics, Image},
+    lwc::Component,
+};
+
+// class org.kwis.msp.lwc.ImageComponent
+pub struct ImageComponent;
+
+impl ImageComponent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/ImageComponent",
+            parent_class: Some("org/kwis/msp/lwc/Component"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Lorg/kwis/msp/lcdui/Image;)V", Self::init, Default::default()),
+                JavaMethodProto::new("setImage", "(Lorg/kwis/msp/lcdui/Image;)V", Self::set_image, Default::default()),
+                JavaMethodProto::new("getImage", "()Lorg/kwis/msp/lcdui/Image;", Self::get_image, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("image", "Lorg/kwis/msp/lcdui/Image;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, image: ClassInstanceRef<Image>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ImageComponent::<init>({this:?}, {image:?})");
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "image", "Lorg/kwis/msp/lcdui/Image;", image).await
+    }
+
+    async fn set_image(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, image: ClassInstanceRef<Image>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ImageComponent::setImage({this:?}, {image:?})");
+
+        jvm.put_field(&mut this, "image", "Lorg/kwis/msp/lcdui/Image;", image).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_image(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Image>> {
+        tracing::debug!("org.kwis.msp.lwc.ImageComponent::getImage({this:?})");
+
+        jvm.get_field(&this, "image", "Lorg/kwis/msp/lcdui/Image;").await
+    }
+
+    async fn get_preferred_height(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ImageComponent::getPreferredHeight({this:?})");
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(&this, "image", "Lorg/kwis/msp/lcdui/Image;").await?;
+        if image.is_null() {
+            return Ok(LINE_HEIGHT + PADDING * 2);
+        }
+
+        let height: i32 = jvm.invoke_virtual(&image, "getHeight", "()I", ()).await?;
+
+        Ok(height + PADDING * 2)
+    }
+
+    // image is centered in the component
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ImageComponent::paint({this:?}, {graphics:?})");
+
+        let image: ClassInstanceRef<Image> = jvm.get_field(&this, "image", "Lorg/kwis/msp/lcdui/Image;").await?;
+        if image.is_null() {
+            return Ok(());
+        }
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let midp_image = Image::midp_image(jvm, &image).await?;
+        let image = MidpImage::image(jvm, &midp_image).await?;
+
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+        renderer.draw_image(&*image, x + (width - image.width() as i32) / 2, y + (height - image.height() as i32) / 2);
+
+        Ok(())
+    }
+}
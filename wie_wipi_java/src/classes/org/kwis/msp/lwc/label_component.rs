@@ -0,0 +1,112 @@
+use alloc::{string::String as RustString, vec, vec::Vec};
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::javax::microedition::lcdui::renderer::{self, FOREGROUND, LINE_HEIGHT, PADDING};
+
+use crate::classes::org::kwis::msp::{
+    lcdui::Graphics,
+    lwc::{Component, ContainerComponent},
+};
+
+// class org.kwis.msp.lwc.LabelComponent
+pub struct LabelComponent;
+
+impl LabelComponent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/LabelComponent",
+            parent_class: Some("org/kwis/msp/lwc/Component"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("setText", "(Ljava/lang/String;)V", Self::set_text, Default::default()),
+                JavaMethodProto::new("getText", "()Ljava/lang/String;", Self::get_text, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("text", "Ljava/lang/String;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.LabelComponent::<init>({this:?}, {text:?})");
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await
+    }
+
+    async fn set_text(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, text: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.LabelComponent::setText({this:?}, {text:?})");
+
+        jvm.put_field(&mut this, "text", "Ljava/lang/String;", text).await?;
+        renderer::request_redraw(context);
+
+        // height depends on the number of lines
+        let parent: ClassInstanceRef<ContainerComponent> = jvm.get_field(&this, "parent", "Lorg/kwis/msp/lwc/ContainerComponent;").await?;
+        if !parent.is_null() {
+            let _: () = jvm.invoke_virtual(&parent, "layout", "()V", ()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_text(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("org.kwis.msp.lwc.LabelComponent::getText({this:?})");
+
+        jvm.get_field(&this, "text", "Ljava/lang/String;").await
+    }
+
+    // text is wrapped to the width of the parent
+    async fn get_preferred_height(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.LabelComponent::getPreferredHeight({this:?})");
+
+        let parent: ClassInstanceRef<ContainerComponent> = jvm.get_field(&this, "parent", "Lorg/kwis/msp/lwc/ContainerComponent;").await?;
+        let (width, _) = if parent.is_null() {
+            Component::size(jvm, &this).await?
+        } else {
+            Component::size(jvm, &parent).await?
+        };
+        if width <= 0 {
+            // not laid out yet
+            return Ok(LINE_HEIGHT + PADDING * 2);
+        }
+
+        let lines = Self::lines(jvm, &this, width).await?;
+
+        Ok(lines.len() as i32 * LINE_HEIGHT + PADDING * 2)
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.LabelComponent::paint({this:?}, {graphics:?})");
+
+        let (width, _) = Component::size(jvm, &this).await?;
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+
+        let lines = Self::lines(jvm, &this, width).await?;
+        for (i, line) in lines.iter().enumerate() {
+            renderer.draw_text(
+                line,
+                x + PADDING * 2,
+                y + PADDING + i as i32 * LINE_HEIGHT,
+                TextAlignment::Left,
+                FOREGROUND,
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn lines(jvm: &Jvm, this: &ClassInstanceRef<Self>, width: i32) -> JvmResult<Vec<RustString>> {
+        let text: ClassInstanceRef<String> = jvm.get_field(this, "text", "Ljava/lang/String;").await?;
+        let text = renderer::rust_string(jvm, &text).await?;
+
+        Ok(renderer::wrap_text(&text, width - PADDING * 4))
+    }
+}
@@ -0,0 +1,304 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::{lang::String, util::Vector};
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::{
+    javax::microedition::lcdui::renderer::{self, BORDER, FOREGROUND, HIGHLIGHT, HIGHLIGHT_TEXT, LINE_HEIGHT, PADDING},
+    net::wie::KeyboardEventType,
+};
+
+use crate::classes::{
+    net::wie::WIPIKeyCode,
+    org::kwis::msp::{lcdui::Graphics, lwc::Component},
+};
+
+// class org.kwis.msp.lwc.ListComponent
+pub struct ListComponent;
+
+impl ListComponent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/ListComponent",
+            parent_class: Some("org/kwis/msp/lwc/Component"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("append", "(Ljava/lang/String;)I", Self::append, Default::default()),
+                JavaMethodProto::new("delete", "(I)V", Self::delete, Default::default()),
+                JavaMethodProto::new("deleteAll", "()V", Self::delete_all, Default::default()),
+                JavaMethodProto::new("getString", "(I)Ljava/lang/String;", Self::get_string, Default::default()),
+                JavaMethodProto::new("size", "()I", Self::size, Default::default()),
+                JavaMethodProto::new("getSelectedIndex", "()I", Self::get_selected_index, Default::default()),
+                JavaMethodProto::new("setSelectedIndex", "(I)V", Self::set_selected_index, Default::default()),
+                JavaMethodProto::new("isFocusable", "()Z", Self::is_focusable, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("items", "Ljava/util/Vector;", Default::default()),
+                JavaFieldProto::new("selectedIndex", "I", Default::default()),
+                JavaFieldProto::new("topIndex", "I", Default::default()),
+            ],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::<init>({this:?})");
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+
+        let items = jvm.new_class("java/util/Vector", "()V", ()).await?;
+        jvm.put_field(&mut this, "items", "Ljava/util/Vector;", items).await?;
+        jvm.put_field(&mut this, "selectedIndex", "I", -1).await?;
+
+        Ok(())
+    }
+
+    async fn append(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, item: ClassInstanceRef<String>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::append({this:?}, {item:?})");
+
+        let items = Self::items(jvm, &this).await?;
+        let index: i32 = jvm.invoke_virtual(&items, "size", "()I", ()).await?;
+        let _: () = jvm.invoke_virtual(&items, "addElement", "(Ljava/lang/Object;)V", (item,)).await?;
+
+        // first item is selected
+        if index == 0 {
+            jvm.put_field(&mut this, "selectedIndex", "I", 0).await?;
+        }
+        renderer::request_redraw(context);
+
+        Ok(index)
+    }
+
+    async fn delete(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::delete({this:?}, {index})");
+
+        let items = Self::items(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&items, "size", "()I", ()).await?;
+        if index < 0 || index >= size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid item index").await);
+        }
+
+        let _: () = jvm.invoke_virtual(&items, "removeElementAt", "(I)V", (index,)).await?;
+
+        let selected_index: i32 = jvm.get_field(&this, "selectedIndex", "I").await?;
+        if selected_index > index || selected_index == size - 1 {
+            jvm.put_field(&mut this, "selectedIndex", "I", selected_index - 1).await?;
+        }
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn delete_all(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::deleteAll({this:?})");
+
+        let items = Self::items(jvm, &this).await?;
+        let _: () = jvm.invoke_virtual(&items, "removeAllElements", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "selectedIndex", "I", -1).await?;
+        jvm.put_field(&mut this, "topIndex", "I", 0).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_string(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, index: i32) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::getString({this:?}, {index})");
+
+        let items = Self::items(jvm, &this).await?;
+        jvm.invoke_virtual(&items, "elementAt", "(I)Ljava/lang/Object;", (index,)).await
+    }
+
+    async fn size(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::size({this:?})");
+
+        let items = Self::items(jvm, &this).await?;
+        jvm.invoke_virtual(&items, "size", "()I", ()).await
+    }
+
+    async fn get_selected_index(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::getSelectedIndex({this:?})");
+
+        jvm.get_field(&this, "selectedIndex", "I").await
+    }
+
+    async fn set_selected_index(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, index: i32) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::setSelectedIndex({this:?}, {index})");
+
+        let items = Self::items(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&items, "size", "()I", ()).await?;
+        if index < 0 || index >= size {
+            return Err(jvm.exception("java/lang/IndexOutOfBoundsException", "invalid item index").await);
+        }
+
+        jvm.put_field(&mut this, "selectedIndex", "I", index).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn is_focusable(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::isFocusable({this:?})");
+
+        Ok(true)
+    }
+
+    async fn get_preferred_height(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::getPreferredHeight({this:?})");
+
+        let items = Self::items(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&items, "size", "()I", ()).await?;
+
+        Ok(size.max(1) * LINE_HEIGHT + PADDING * 2)
+    }
+
+    // up and down keys move selection, and go to the parent at the edges of the list to move focus
+    async fn key_notify(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::keyNotify({this:?}, {type}, {chr})");
+
+        let Some(r#type) = KeyboardEventType::try_from_raw(r#type) else {
+            return Ok(false);
+        };
+        if !matches!(r#type, KeyboardEventType::KeyPressed | KeyboardEventType::KeyRepeated) {
+            return Ok(false);
+        }
+
+        let selected_index: i32 = jvm.get_field(&this, "selectedIndex", "I").await?;
+        let items = Self::items(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&items, "size", "()I", ()).await?;
+
+        let next_index = match WIPIKeyCode::try_from_raw(chr) {
+            Some(WIPIKeyCode::UP) => selected_index - 1,
+            Some(WIPIKeyCode::DOWN) => selected_index + 1,
+            Some(WIPIKeyCode::FIRE) if matches!(r#type, KeyboardEventType::KeyPressed) && selected_index >= 0 => {
+                Component::notify_listener(jvm, &this, Component::ACTION).await?;
+
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+
+        if next_index < 0 || next_index >= size {
+            return Ok(false);
+        }
+
+        jvm.put_field(&mut this, "selectedIndex", "I", next_index).await?;
+        renderer::request_redraw(context);
+
+        Ok(true)
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ListComponent::paint({this:?}, {graphics:?})");
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let focused: bool = jvm.get_field(&this, "focused", "Z").await?;
+        let selected_index: i32 = jvm.get_field(&this, "selectedIndex", "I").await?;
+        let top_index: i32 = jvm.get_field(&this, "topIndex", "I").await?;
+
+        let items = Self::items(jvm, &this).await?;
+        let size: i32 = jvm.invoke_virtual(&items, "size", "()I", ()).await?;
+
+        // scroll to keep selected item visible
+        let visible_lines = ((height - PADDING * 2) / LINE_HEIGHT).max(1);
+        let top_index = top_index.clamp((selected_index - visible_lines + 1).max(0), selected_index.max(0));
+        jvm.put_field(&mut this, "topIndex", "I", top_index).await?;
+
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+
+        for index in top_index..size.min(top_index + visible_lines) {
+            let item: ClassInstanceRef<String> = jvm.invoke_virtual(&items, "elementAt", "(I)Ljava/lang/Object;", (index,)).await?;
+            let item = renderer::rust_string(jvm, &item).await?;
+
+            let line_y = y + PADDING + (index - top_index) * LINE_HEIGHT;
+            let mut color = FOREGROUND;
+            if index == selected_index {
+                if focused {
+                    renderer.fill_rect(x, line_y, width, LINE_HEIGHT, HIGHLIGHT);
+                    color = HIGHLIGHT_TEXT;
+                } else {
+                    renderer.draw_rect(x, line_y, width - 1, LINE_HEIGHT - 1, BORDER);
+                }
+            }
+
+            renderer.draw_text(&item, x + PADDING * 2, line_y, TextAlignment::Left, color);
+        }
+
+        Ok(())
+    }
+
+    async fn items(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Vector>> {
+        jvm.get_field(this, "items", "Ljava/util/Vector;").await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_midp::classes::net::wie::KeyboardEventType;
+    use wie_util::Result;
+
+    use crate::classes::{
+        net::wie::WIPIKeyCode,
+        org::kwis::msp::lwc::{
+            Component,
+            component_listener::test::{TestListener, key_notify, protos},
+        },
+    };
+
+    const PRESSED: i32 = KeyboardEventType::KeyPressed as _;
+    const RELEASED: i32 = KeyboardEventType::KeyReleased as _;
+    const REPEATED: i32 = KeyboardEventType::KeyRepeated as _;
+
+    async fn selected_index(jvm: &Jvm, list: &ClassInstanceRef<super::ListComponent>) -> JvmResult<i32> {
+        jvm.invoke_virtual(list, "getSelectedIndex", "()I", ()).await
+    }
+
+    #[test]
+    fn test_key_notify() -> Result<()> {
+        run_jvm_test(protos(), |jvm| async move {
+            let list: ClassInstanceRef<super::ListComponent> = jvm.new_class("org/kwis/msp/lwc/ListComponent", "()V", ()).await?.into();
+            for item in ["first", "second", "third"] {
+                let item = JavaLangString::from_rust_string(&jvm, item).await?;
+                let _: i32 = jvm.invoke_virtual(&list, "append", "(Ljava/lang/String;)I", (item,)).await?;
+            }
+            let listener = TestListener::listen(&jvm, &list).await?;
+
+            assert_eq!(selected_index(&jvm, &list).await?, 0);
+
+            assert!(key_notify(&jvm, &list, PRESSED, WIPIKeyCode::DOWN as _).await?);
+            assert!(key_notify(&jvm, &list, REPEATED, WIPIKeyCode::DOWN as _).await?);
+            assert_eq!(selected_index(&jvm, &list).await?, 2);
+
+            // moving past the edge is left to the parent
+            assert!(!key_notify(&jvm, &list, PRESSED, WIPIKeyCode::DOWN as _).await?);
+            assert_eq!(selected_index(&jvm, &list).await?, 2);
+
+            // released, unknown keys and unknown event types are not handled
+            assert!(!key_notify(&jvm, &list, RELEASED, WIPIKeyCode::UP as _).await?);
+            assert!(!key_notify(&jvm, &list, PRESSED, 1234).await?);
+            assert!(!key_notify(&jvm, &list, 99, WIPIKeyCode::UP as _).await?);
+            assert_eq!(selected_index(&jvm, &list).await?, 2);
+
+            assert!(key_notify(&jvm, &list, PRESSED, WIPIKeyCode::UP as _).await?);
+            assert_eq!(selected_index(&jvm, &list).await?, 1);
+
+            // fire activates the selected item, but not on repeat
+            assert!(!key_notify(&jvm, &list, REPEATED, WIPIKeyCode::FIRE as _).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (0, 0));
+            assert!(key_notify(&jvm, &list, PRESSED, WIPIKeyCode::FIRE as _).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (1, Component::ACTION));
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,170 @@
+use alloc::vec;
+
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
+use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
+use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::{
+    javax::microedition::lcdui::renderer::{self, BACKGROUND, BAR_BACKGROUND, BAR_TEXT, BORDER, FOREGROUND, LINE_HEIGHT, PADDING, TITLE_HEIGHT},
+    net::wie::KeyboardEventType,
+};
+
+use crate::classes::{
+    net::wie::WIPIKeyCode,
+    org::kwis::msp::{lcdui::Graphics, lwc::Component},
+};
+
+// class org.kwis.msp.lwc.PopupComponent
+// shell showing a message box over the cards below, closed with fire or clear key
+pub struct PopupComponent;
+
+impl PopupComponent {
+    pub fn as_proto() -> WieJavaClassProto {
+        WieJavaClassProto {
+            name: "org/kwis/msp/lwc/PopupComponent",
+            parent_class: Some("org/kwis/msp/lwc/ShellComponent"),
+            interfaces: vec![],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;)V", Self::init, Default::default()),
+                JavaMethodProto::new("setMessage", "(Ljava/lang/String;)V", Self::set_message, Default::default()),
+                JavaMethodProto::new("getMessage", "()Ljava/lang/String;", Self::get_message, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+            ],
+            fields: vec![JavaFieldProto::new("message", "Ljava/lang/String;", Default::default())],
+            access_flags: Default::default(),
+        }
+    }
+
+    async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, message: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.PopupComponent::<init>({this:?}, {message:?})");
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/ShellComponent", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "message", "Ljava/lang/String;", message).await
+    }
+
+    async fn set_message(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        message: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.PopupComponent::setMessage({this:?}, {message:?})");
+
+        jvm.put_field(&mut this, "message", "Ljava/lang/String;", message).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn get_message(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("org.kwis.msp.lwc.PopupComponent::getMessage({this:?})");
+
+        jvm.get_field(&this, "message", "Ljava/lang/String;").await
+    }
+
+    // popup is modal, so it consumes every key
+    async fn key_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.PopupComponent::keyNotify({this:?}, {type}, {chr})");
+
+        if matches!(KeyboardEventType::try_from_raw(r#type), Some(KeyboardEventType::KeyPressed))
+            && matches!(WIPIKeyCode::try_from_raw(chr), Some(WIPIKeyCode::FIRE | WIPIKeyCode::CLEAR))
+        {
+            let _: () = jvm.invoke_virtual(&this, "hide", "()V", ()).await?;
+            Component::notify_listener(jvm, &this, Component::ACTION).await?;
+        }
+
+        Ok(true)
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.PopupComponent::paint({this:?}, {graphics:?})");
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let title: ClassInstanceRef<String> = jvm.get_field(&this, "title", "Ljava/lang/String;").await?;
+        let message: ClassInstanceRef<String> = jvm.get_field(&this, "message", "Ljava/lang/String;").await?;
+        let message = renderer::rust_string(jvm, &message).await?;
+
+        let box_width = width - PADDING * 8;
+        let lines = renderer::wrap_text(&message, box_width - PADDING * 4);
+        let title_height = if title.is_null() { 0 } else { TITLE_HEIGHT };
+        let box_height = (title_height + lines.len() as i32 * LINE_HEIGHT + PADDING * 4).min(height);
+
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+        let box_x = x + (width - box_width) / 2;
+        let box_y = y + (height - box_height) / 2;
+
+        renderer.fill_rect(box_x, box_y, box_width, box_height, BACKGROUND);
+        renderer.draw_rect(box_x, box_y, box_width - 1, box_height - 1, BORDER);
+
+        if !title.is_null() {
+            let title = renderer::rust_string(jvm, &title).await?;
+
+            renderer.fill_rect(box_x, box_y, box_width, TITLE_HEIGHT, BAR_BACKGROUND);
+            renderer.draw_text(
+                &title,
+                box_x + box_width / 2,
+                box_y + (TITLE_HEIGHT - LINE_HEIGHT) / 2,
+                TextAlignment::Center,
+                BAR_TEXT,
+            );
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let line_y = box_y + title_height + PADDING * 2 + i as i32 * LINE_HEIGHT;
+            renderer.draw_text(line, box_x + PADDING * 2, line_y, TextAlignment::Left, FOREGROUND);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jvm::{ClassInstanceRef, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_midp::classes::net::wie::KeyboardEventType;
+    use wie_util::Result;
+
+    use crate::classes::{
+        net::wie::WIPIKeyCode,
+        org::kwis::msp::lwc::{
+            Component,
+            component_listener::test::{TestListener, key_notify, protos},
+        },
+    };
+
+    const PRESSED: i32 = KeyboardEventType::KeyPressed as _;
+    const RELEASED: i32 = KeyboardEventType::KeyReleased as _;
+
+    #[test]
+    fn test_key_notify() -> Result<()> {
+        run_jvm_test(protos(), |jvm| async move {
+            let message = JavaLangString::from_rust_string(&jvm, "message").await?;
+            let popup: ClassInstanceRef<super::PopupComponent> = jvm
+                .new_class("org/kwis/msp/lwc/PopupComponent", "(Ljava/lang/String;)V", (message,))
+                .await?
+                .into();
+            let listener = TestListener::listen(&jvm, &popup).await?;
+
+            // popup is modal, so every key is consumed without closing it
+            assert!(key_notify(&jvm, &popup, PRESSED, WIPIKeyCode::NUM1 as _).await?);
+            assert!(key_notify(&jvm, &popup, RELEASED, WIPIKeyCode::FIRE as _).await?);
+            assert!(key_notify(&jvm, &popup, PRESSED, 0).await?);
+            assert!(key_notify(&jvm, &popup, -1234, WIPIKeyCode::FIRE as _).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (0, 0));
+
+            assert!(key_notify(&jvm, &popup, PRESSED, WIPIKeyCode::FIRE as _).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (1, Component::ACTION));
+
+            assert!(key_notify(&jvm, &popup, PRESSED, WIPIKeyCode::CLEAR as _).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (2, Component::ACTION));
+
+            Ok(())
+        })
+    }
+}
@@ -1,15 +1,32 @@
 use alloc::vec;
 
-use java_class_proto::JavaMethodProto;
+use java_class_proto::{JavaFieldProto, JavaMethodProto};
+use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
+
+use wie_backend::canvas::TextAlignment;
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::{
+    javax::microedition::lcdui::renderer::{self, BACKGROUND, BAR_BACKGROUND, BAR_TEXT, LINE_HEIGHT, PADDING, Renderer, TITLE_HEIGHT},
+    net::wie::KeyboardEventType,
+};
 
-use crate::classes::org::kwis::msp::lwc::Component;
+use crate::classes::{
+    net::wie::{ShellCard, WIPIKeyCode},
+    org::kwis::msp::{
+        lcdui::{Card, Display, Graphics},
+        lwc::{AnnunciatorComponent, Component, ContainerComponent},
+    },
+};
 
 // class org.kwis.msp.lwc.ShellComponent
 pub struct ShellComponent;
 
 impl ShellComponent {
+    // events passed to ComponentListener when soft keys are pressed
+    pub const LEFT_SOFTKEY: i32 = 2;
+    pub const RIGHT_SOFTKEY: i32 = 3;
+
     pub fn as_proto() -> WieJavaClassProto {
         WieJavaClassProto {
             name: "org/kwis/msp/lwc/ShellComponent",
@@ -17,48 +34,333 @@ impl ShellComponent {
             interfaces: vec![],
             methods: vec![
                 JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("setTitle", "(Ljava/lang/String;)V", Self::set_title, Default::default()),
+                JavaMethodProto::new("getTitle", "()Ljava/lang/String;", Self::get_title, Default::default()),
                 JavaMethodProto::new(
                     "setWorkComponent",
                     "(Lorg/kwis/msp/lwc/Component;)V",
                     Self::set_work_component,
                     Default::default(),
                 ),
+                JavaMethodProto::new(
+                    "getWorkComponent",
+                    "()Lorg/kwis/msp/lwc/Component;",
+                    Self::get_work_component,
+                    Default::default(),
+                ),
+                JavaMethodProto::new("setSoftkey", "(ILjava/lang/String;)V", Self::set_softkey, Default::default()),
                 JavaMethodProto::new("show", "()V", Self::show, Default::default()),
                 JavaMethodProto::new("hide", "()V", Self::hide, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
+                // wie private
+                JavaMethodProto::new("layout", "()V", Self::layout, Default::default()),
+            ],
+            fields: vec![
+                JavaFieldProto::new("title", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("workComponent", "Lorg/kwis/msp/lwc/Component;", Default::default()),
+                JavaFieldProto::new("leftSoftkey", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("rightSoftkey", "Ljava/lang/String;", Default::default()),
+                JavaFieldProto::new("card", "Lorg/kwis/msp/lcdui/Card;", Default::default()),
             ],
-            fields: vec![],
             access_flags: Default::default(),
         }
     }
 
     async fn init(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::debug!("stub org.kwis.msp.lwc.ShellComponent::<init>({this:?})");
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::<init>({this:?})");
 
-        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
+        let _: () = jvm
+            .invoke_special(&this, "org/kwis/msp/lwc/ContainerComponent", "<init>", "()V", ())
+            .await?;
 
         Ok(())
     }
 
+    async fn set_title(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<Self>, title: ClassInstanceRef<String>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::setTitle({this:?}, {title:?})");
+
+        jvm.put_field(&mut this, "title", "Ljava/lang/String;", title).await?;
+        renderer::request_redraw(context);
+
+        jvm.invoke_virtual(&this, "layout", "()V", ()).await
+    }
+
+    async fn get_title(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::getTitle({this:?})");
+
+        jvm.get_field(&this, "title", "Ljava/lang/String;").await
+    }
+
+    // work component is the only child of the shell, filling the area between title and soft keys
     async fn set_work_component(
-        _: &Jvm,
-        _: &mut WieJvmContext,
-        this: ClassInstanceRef<Self>,
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
         component: ClassInstanceRef<Component>,
     ) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.ShellComponent::setWorkComponent({this:?}, {component:?})");
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::setWorkComponent({this:?}, {component:?})");
+
+        let _: () = jvm.invoke_virtual(&this, "removeAllComponents", "()V", ()).await?;
+        jvm.put_field(&mut this, "workComponent", "Lorg/kwis/msp/lwc/Component;", component.clone())
+            .await?;
+
+        if !component.is_null() {
+            let _: i32 = jvm
+                .invoke_virtual(&this, "addComponent", "(Lorg/kwis/msp/lwc/Component;)I", (component.clone(),))
+                .await?;
+            let _: () = jvm
+                .invoke_virtual(&this, "focusComponent", "(Lorg/kwis/msp/lwc/Component;)V", (component,))
+                .await?;
+        }
+        renderer::request_redraw(context);
 
         Ok(())
     }
 
-    async fn show(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.ShellComponent::show({this:?})");
+    async fn get_work_component(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<ClassInstanceRef<Component>> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::getWorkComponent({this:?})");
 
-        Ok(())
+        jvm.get_field(&this, "workComponent", "Lorg/kwis/msp/lwc/Component;").await
     }
 
-    async fn hide(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.ShellComponent::hide({this:?})");
+    // index 0 is the left soft key, 1 is the right one
+    async fn set_softkey(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<Self>,
+        index: i32,
+        label: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::setSoftkey({this:?}, {index}, {label:?})");
 
-        Ok(())
+        let field = match index {
+            0 => "leftSoftkey",
+            1 => "rightSoftkey",
+            _ => return Err(jvm.exception("java/lang/IllegalArgumentException", "invalid soft key index").await),
+        };
+        jvm.put_field(&mut this, field, "Ljava/lang/String;", label).await?;
+        renderer::request_redraw(context);
+
+        jvm.invoke_virtual(&this, "layout", "()V", ()).await
+    }
+
+    async fn show(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::show({this:?})");
+
+        let card: ClassInstanceRef<Card> = jvm.get_field(&this, "card", "Lorg/kwis/msp/lcdui/Card;").await?;
+        if !card.is_null() {
+            return Ok(());
+        }
+
+        let display: ClassInstanceRef<Display> = jvm
+            .invoke_static("org/kwis/msp/lcdui/Display", "getDefaultDisplay", "()Lorg/kwis/msp/lcdui/Display;", [])
+            .await?;
+        let card: ClassInstanceRef<ShellCard> = jvm
+            .new_class(
+                "net/wie/ShellCard",
+                "(Lorg/kwis/msp/lcdui/Display;Lorg/kwis/msp/lwc/ShellComponent;)V",
+                (display.clone(), this.clone()),
+            )
+            .await?
+            .into();
+        jvm.put_field(&mut this, "card", "Lorg/kwis/msp/lcdui/Card;", card.clone()).await?;
+
+        jvm.invoke_virtual(&display, "pushCard", "(Lorg/kwis/msp/lcdui/Card;)V", (card,)).await
+    }
+
+    async fn hide(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::hide({this:?})");
+
+        let card: ClassInstanceRef<Card> = jvm.get_field(&this, "card", "Lorg/kwis/msp/lcdui/Card;").await?;
+        if card.is_null() {
+            return Ok(());
+        }
+
+        jvm.put_field(&mut this, "card", "Lorg/kwis/msp/lcdui/Card;", None).await?;
+
+        let display: ClassInstanceRef<Display> = jvm.get_field(&card, "display", "Lorg/kwis/msp/lcdui/Display;").await?;
+        jvm.invoke_virtual(&display, "removeCard", "(Lorg/kwis/msp/lcdui/Card;)V", (card,)).await
+    }
+
+    // soft keys not handled by the work component are reported to the listener
+    async fn key_notify(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::keyNotify({this:?}, {type}, {chr})");
+
+        let handled: bool = jvm
+            .invoke_special(&this, "org/kwis/msp/lwc/ContainerComponent", "keyNotify", "(II)Z", (r#type, chr))
+            .await?;
+        if handled {
+            return Ok(true);
+        }
+
+        if !matches!(KeyboardEventType::try_from_raw(r#type), Some(KeyboardEventType::KeyPressed)) {
+            return Ok(false);
+        }
+
+        let (field, event) = match WIPIKeyCode::try_from_raw(chr) {
+            Some(WIPIKeyCode::LEFT_SOFT_KEY) => ("leftSoftkey", Self::LEFT_SOFTKEY),
+            Some(WIPIKeyCode::RIGHT_SOFT_KEY) => ("rightSoftkey", Self::RIGHT_SOFTKEY),
+            _ => return Ok(false),
+        };
+
+        let label: ClassInstanceRef<String> = jvm.get_field(&this, field, "Ljava/lang/String;").await?;
+        if label.is_null() {
+            return Ok(false);
+        }
+
+        Component::notify_listener(jvm, &this, event).await?;
+
+        Ok(true)
+    }
+
+    async fn paint(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>, graphics: ClassInstanceRef<Graphics>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::paint({this:?}, {graphics:?})");
+
+        let (width, height) = Component::size(jvm, &this).await?;
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+        renderer.fill_rect(x, y, width, height, BACKGROUND);
+
+        let annunciator = AnnunciatorComponent::visible_instance(jvm).await?;
+        if let Some(annunciator) = &annunciator {
+            ContainerComponent::paint_component(jvm, &graphics, annunciator).await?;
+        }
+
+        let (top, bottom) = Self::bar_heights(jvm, &this).await?;
+
+        let title: ClassInstanceRef<String> = jvm.get_field(&this, "title", "Ljava/lang/String;").await?;
+        if !title.is_null() {
+            let title = renderer::rust_string(jvm, &title).await?;
+            let title_y = y + top - TITLE_HEIGHT;
+
+            renderer.fill_rect(x, title_y, width, TITLE_HEIGHT, BAR_BACKGROUND);
+            Self::draw_bar_text(&mut renderer, &title, x + width / 2, title_y, TITLE_HEIGHT, TextAlignment::Center);
+        }
+
+        if bottom > 0 {
+            let bar_y = y + height - bottom;
+            renderer.fill_rect(x, bar_y, width, bottom, BAR_BACKGROUND);
+
+            let left: ClassInstanceRef<String> = jvm.get_field(&this, "leftSoftkey", "Ljava/lang/String;").await?;
+            let left = renderer::rust_string(jvm, &left).await?;
+            Self::draw_bar_text(&mut renderer, &left, x + PADDING * 2, bar_y, bottom, TextAlignment::Left);
+
+            let right: ClassInstanceRef<String> = jvm.get_field(&this, "rightSoftkey", "Ljava/lang/String;").await?;
+            let right = renderer::rust_string(jvm, &right).await?;
+            Self::draw_bar_text(&mut renderer, &right, x + width - PADDING * 2, bar_y, bottom, TextAlignment::Right);
+        }
+
+        jvm.invoke_special(
+            &this,
+            "org/kwis/msp/lwc/ContainerComponent",
+            "paint",
+            "(Lorg/kwis/msp/lcdui/Graphics;)V",
+            (graphics,),
+        )
+        .await
+    }
+
+    async fn layout(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<Self>) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.ShellComponent::layout({this:?})");
+
+        let (width, height) = Component::size(jvm, &this).await?;
+
+        if let Some(annunciator) = AnnunciatorComponent::visible_instance(jvm).await? {
+            let _: () = jvm
+                .invoke_virtual(&annunciator, "configure", "(IIIII)V", (0, 0, width, AnnunciatorComponent::HEIGHT, 0))
+                .await?;
+        }
+
+        let work_component: ClassInstanceRef<Component> = jvm.get_field(&this, "workComponent", "Lorg/kwis/msp/lwc/Component;").await?;
+        if work_component.is_null() {
+            return Ok(());
+        }
+
+        let (top, bottom) = Self::bar_heights(jvm, &this).await?;
+        jvm.invoke_virtual(&work_component, "configure", "(IIIII)V", (0, top, width, height - top - bottom, 0))
+            .await
+    }
+
+    // heights of the area above the work component (annunciator and title), and below it (soft keys)
+    async fn bar_heights(jvm: &Jvm, this: &ClassInstanceRef<Self>) -> JvmResult<(i32, i32)> {
+        let mut top = 0;
+        if AnnunciatorComponent::visible_instance(jvm).await?.is_some() {
+            top += AnnunciatorComponent::HEIGHT;
+        }
+
+        let title: ClassInstanceRef<String> = jvm.get_field(this, "title", "Ljava/lang/String;").await?;
+        if !title.is_null() {
+            top += TITLE_HEIGHT;
+        }
+
+        let left: ClassInstanceRef<String> = jvm.get_field(this, "leftSoftkey", "Ljava/lang/String;").await?;
+        let right: ClassInstanceRef<String> = jvm.get_field(this, "rightSoftkey", "Ljava/lang/String;").await?;
+        let bottom = if left.is_null() && right.is_null() { 0 } else { TITLE_HEIGHT };
+
+        Ok((top, bottom))
+    }
+
+    fn draw_bar_text(renderer: &mut Renderer, text: &str, x: i32, y: i32, height: i32, alignment: TextAlignment) {
+        renderer.draw_text(text, x, y + (height - LINE_HEIGHT) / 2, alignment, BAR_TEXT);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use jvm::{ClassInstanceRef, runtime::JavaLangString};
+
+    use test_utils::run_jvm_test;
+    use wie_midp::classes::net::wie::KeyboardEventType;
+    use wie_util::Result;
+
+    use crate::classes::{
+        net::wie::WIPIKeyCode,
+        org::kwis::msp::lwc::{
+            Component,
+            component_listener::test::{TestListener, key_notify, protos},
+        },
+    };
+
+    const PRESSED: i32 = KeyboardEventType::KeyPressed as _;
+    const RELEASED: i32 = KeyboardEventType::KeyReleased as _;
+
+    #[test]
+    fn test_key_notify() -> Result<()> {
+        run_jvm_test(protos(), |jvm| async move {
+            let shell: ClassInstanceRef<super::ShellComponent> = jvm.new_class("org/kwis/msp/lwc/ShellComponent", "()V", ()).await?.into();
+            let _: () = jvm.invoke_virtual(&shell, "configure", "(IIIII)V", (0, 0, 240, 320, 0)).await?;
+            let listener = TestListener::listen(&jvm, &shell).await?;
+
+            // soft key without label is not handled
+            assert!(!key_notify(&jvm, &shell, PRESSED, WIPIKeyCode::LEFT_SOFT_KEY as _).await?);
+
+            let label = JavaLangString::from_rust_string(&jvm, "menu").await?;
+            let _: () = jvm.invoke_virtual(&shell, "setSoftkey", "(ILjava/lang/String;)V", (0, label)).await?;
+
+            assert!(!key_notify(&jvm, &shell, RELEASED, WIPIKeyCode::LEFT_SOFT_KEY as _).await?);
+            assert!(!key_notify(&jvm, &shell, PRESSED, WIPIKeyCode::RIGHT_SOFT_KEY as _).await?);
+            assert!(!key_notify(&jvm, &shell, PRESSED, 0x7fff).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (0, 0));
+
+            assert!(key_notify(&jvm, &shell, PRESSED, WIPIKeyCode::LEFT_SOFT_KEY as _).await?);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (1, super::ShellComponent::LEFT_SOFTKEY));
+
+            // keys go to the work component first
+            let list: ClassInstanceRef<Component> = jvm.new_class("org/kwis/msp/lwc/ListComponent", "()V", ()).await?.into();
+            for item in ["first", "second"] {
+                let item = JavaLangString::from_rust_string(&jvm, item).await?;
+                let _: i32 = jvm.invoke_virtual(&list, "append", "(Ljava/lang/String;)I", (item,)).await?;
+            }
+            let _: () = jvm
+                .invoke_virtual(&shell, "setWorkComponent", "(Lorg/kwis/msp/lwc/Component;)V", (list.clone(),))
+                .await?;
+
+            assert!(key_notify(&jvm, &shell, PRESSED, WIPIKeyCode::DOWN as _).await?);
+            let selected_index: i32 = jvm.invoke_virtual(&list, "getSelectedIndex", "()I", ()).await?;
+            assert_eq!(selected_index, 1);
+            assert_eq!(TestListener::actions(&jvm, &listener).await?, (1, super::ShellComponent::LEFT_SOFTKEY));
+
+            Ok(())
+        })
     }
 }
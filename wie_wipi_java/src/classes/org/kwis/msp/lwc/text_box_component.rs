@@ -5,6 +5,10 @@ use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult};
 
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::javax::microedition::lcdui::renderer::{LINE_HEIGHT, PADDING};
+
+// text box shows several lines of text
+const VISIBLE_LINES: i32 = 4;
 
 // class org.kwis.msp.lwc.TextBoxComponent
 pub struct TextBoxComponent;
@@ -15,7 +19,10 @@ impl TextBoxComponent {
             name: "org/kwis/msp/lwc/TextBoxComponent",
             parent_class: Some("org/kwis/msp/lwc/TextComponent"),
             interfaces: vec![],
-            methods: vec![JavaMethodProto::new("<init>", "(Ljava/lang/String;I)V", Self::init, Default::default())],
+            methods: vec![
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;I)V", Self::init, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+            ],
             fields: vec![],
             access_flags: Default::default(),
         }
@@ -28,15 +35,24 @@ impl TextBoxComponent {
         data: ClassInstanceRef<String>,
         constraint: i32,
     ) -> JvmResult<()> {
-        tracing::warn!(
-            "stub org.kwis.msp.lwc.TextBoxComponent::<init>({:?}, {:?}, {:?})",
-            &this,
-            &data,
-            constraint
-        );
+        tracing::debug!("org.kwis.msp.lwc.TextBoxComponent::<init>({:?}, {:?}, {:?})", &this, &data, constraint);
 
-        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/TextComponent", "<init>", "()V", ()).await?;
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "org/kwis/msp/lwc/TextComponent",
+                "<init>",
+                "(Ljava/lang/String;I)V",
+                (data, constraint),
+            )
+            .await?;
 
         Ok(())
     }
+
+    async fn get_preferred_height(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<TextBoxComponent>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.TextBoxComponent::getPreferredHeight({:?})", &this);
+
+        Ok(LINE_HEIGHT * VISIBLE_LINES + PADDING * 2)
+    }
 }
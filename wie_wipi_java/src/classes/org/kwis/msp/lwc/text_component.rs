@@ -1,10 +1,25 @@
-use alloc::vec;
+use alloc::{string::String as RustString, vec};
 
 use java_class_proto::{JavaFieldProto, JavaMethodProto};
 use java_runtime::classes::java::lang::String;
 use jvm::{ClassInstanceRef, Jvm, Result as JvmResult, runtime::JavaLangString};
 
+use wie_backend::{InputConstraint, InputKey, InputMethodHandle};
 use wie_jvm_support::{WieJavaClassProto, WieJvmContext};
+use wie_midp::classes::{
+    javax::microedition::lcdui::renderer::{self, LINE_HEIGHT, PADDING},
+    net::wie::KeyboardEventType,
+};
+
+use crate::classes::{
+    net::wie::WIPIKeyCode,
+    org::kwis::msp::{
+        lcdui::{Graphics, InputMethodHandler},
+        lwc::Component,
+    },
+};
+
+const PASSWORD: i32 = 0x10000;
 
 // class org.kwis.msp.lwc.TextComponent
 pub struct TextComponent;
@@ -17,19 +32,27 @@ impl TextComponent {
             interfaces: vec![],
             methods: vec![
                 JavaMethodProto::new("<init>", "()V", Self::init, Default::default()),
+                JavaMethodProto::new("<init>", "(Ljava/lang/String;I)V", Self::init_with_text, Default::default()),
                 JavaMethodProto::new("setMaxLength", "(I)V", Self::set_max_length, Default::default()),
                 JavaMethodProto::new("getString", "()Ljava/lang/String;", Self::get_string, Default::default()),
+                JavaMethodProto::new("setString", "(Ljava/lang/String;)V", Self::set_string, Default::default()),
+                JavaMethodProto::new("isFocusable", "()Z", Self::is_focusable, Default::default()),
+                JavaMethodProto::new("getPreferredHeight", "()I", Self::get_preferred_height, Default::default()),
+                JavaMethodProto::new("keyNotify", "(II)Z", Self::key_notify, Default::default()),
+                JavaMethodProto::new("focusNotify", "(Z)V", Self::focus_notify, Default::default()),
+                JavaMethodProto::new("paint", "(Lorg/kwis/msp/lcdui/Graphics;)V", Self::paint, Default::default()),
             ],
             fields: vec![
                 JavaFieldProto::new("m_cPos", "I", Default::default()),
                 JavaFieldProto::new("imHandler", "Lorg/kwis/msp/lcdui/InputMethodHandler;", Default::default()),
+                JavaFieldProto::new("constraint", "I", Default::default()),
             ],
             access_flags: Default::default(),
         }
     }
 
     async fn init(jvm: &Jvm, _: &mut WieJvmContext, mut this: ClassInstanceRef<TextComponent>) -> JvmResult<()> {
-        tracing::debug!("stub org.kwis.msp.lwc.TextComponent::<init>({:?})", &this);
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::<init>({:?})", &this);
 
         let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/Component", "<init>", "()V", ()).await?;
 
@@ -42,17 +65,178 @@ impl TextComponent {
         Ok(())
     }
 
-    async fn set_max_length(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<TextComponent>, max_length: i32) -> JvmResult<()> {
-        tracing::warn!("stub org.kwis.msp.lwc.TextComponent::<init>({:?}, {})", &this, max_length);
+    async fn init_with_text(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        mut this: ClassInstanceRef<TextComponent>,
+        text: ClassInstanceRef<String>,
+        constraint: i32,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::<init>({:?}, {:?}, {})", &this, &text, constraint);
+
+        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/TextComponent", "<init>", "()V", ()).await?;
+
+        jvm.put_field(&mut this, "constraint", "I", constraint).await?;
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        if let Some(x) = context.system().input_methods().get(input_method) {
+            x.set_constraint(InputConstraint::from_raw(constraint));
+        }
+
+        let text = if text.is_null() {
+            RustString::new()
+        } else {
+            JavaLangString::to_rust_string(jvm, &text).await?
+        };
+
+        Self::store_text(jvm, context, this, &text).await
+    }
+
+    async fn set_max_length(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<TextComponent>, max_length: i32) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::setMaxLength({:?}, {})", &this, max_length);
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let cursor = context.system().input_methods().get(input_method).map(|x| {
+            x.set_max_length(max_length.max(0) as _);
+            x.cursor()
+        });
+
+        if let Some(cursor) = cursor {
+            jvm.put_field(&mut this, "m_cPos", "I", cursor as i32).await?;
+        }
 
         Ok(())
     }
 
-    async fn get_string(jvm: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<TextComponent>) -> JvmResult<ClassInstanceRef<String>> {
-        tracing::warn!("stub org.kwis.msp.lwc.TextComponent::<init>({:?})", &this);
+    async fn get_string(jvm: &Jvm, context: &mut WieJvmContext, this: ClassInstanceRef<TextComponent>) -> JvmResult<ClassInstanceRef<String>> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::getString({:?})", &this);
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let text = context.system().input_methods().get(input_method).map(|x| x.text()).unwrap_or_default();
 
-        let result = JavaLangString::from_rust_string(jvm, "temp").await?;
+        let result = JavaLangString::from_rust_string(jvm, &text).await?;
 
         Ok(result.into())
     }
+
+    async fn set_string(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<TextComponent>,
+        text: ClassInstanceRef<String>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::setString({:?}, {:?})", &this, &text);
+
+        let text = if text.is_null() {
+            RustString::new()
+        } else {
+            JavaLangString::to_rust_string(jvm, &text).await?
+        };
+
+        Self::store_text(jvm, context, this, &text).await
+    }
+
+    async fn is_focusable(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<TextComponent>) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::isFocusable({:?})", &this);
+
+        Ok(true)
+    }
+
+    async fn get_preferred_height(_: &Jvm, _: &mut WieJvmContext, this: ClassInstanceRef<TextComponent>) -> JvmResult<i32> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::getPreferredHeight({:?})", &this);
+
+        Ok(LINE_HEIGHT + PADDING * 2)
+    }
+
+    async fn key_notify(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<TextComponent>, r#type: i32, chr: i32) -> JvmResult<bool> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::keyNotify({:?}, {}, {})", &this, r#type, chr);
+
+        let Some(key) = WIPIKeyCode::try_from_raw(chr).and_then(WIPIKeyCode::to_input_key) else {
+            return Ok(false);
+        };
+
+        // holding a key repeats only editing keys, as repeated keypad keys would cycle multi-tap letters
+        match KeyboardEventType::try_from_raw(r#type) {
+            Some(KeyboardEventType::KeyPressed) => {}
+            Some(KeyboardEventType::KeyRepeated) if matches!(key, InputKey::Left | InputKey::Right | InputKey::Clear) => {}
+            _ => return Ok(false),
+        }
+
+        let input_method = Self::input_method(jvm, &this).await?;
+        let now = context.system().platform().now();
+        let result = context
+            .system()
+            .input_methods()
+            .get(input_method)
+            .and_then(|x| x.handle_key(key, now).then(|| x.cursor()));
+
+        let Some(cursor) = result else {
+            return Ok(false);
+        };
+
+        jvm.put_field(&mut this, "m_cPos", "I", cursor as i32).await?;
+        renderer::request_redraw(context);
+
+        Ok(true)
+    }
+
+    async fn focus_notify(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<TextComponent>, focus: bool) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::focusNotify({:?}, {})", &this, focus);
+
+        // leaving the component finishes the syllable being composed
+        let input_method = Self::input_method(jvm, &this).await?;
+        if !focus && let Some(x) = context.system().input_methods().get(input_method) {
+            x.commit();
+        }
+        context.system().set_input_method_focus(input_method, focus);
+
+        jvm.put_field(&mut this, "focused", "Z", focus).await?;
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    // the editor fills the component
+    async fn paint(
+        jvm: &Jvm,
+        context: &mut WieJvmContext,
+        this: ClassInstanceRef<TextComponent>,
+        graphics: ClassInstanceRef<Graphics>,
+    ) -> JvmResult<()> {
+        tracing::debug!("org.kwis.msp.lwc.TextComponent::paint({:?}, {:?})", &this, &graphics);
+
+        let constraint: i32 = jvm.get_field(&this, "constraint", "I").await?;
+        let focused: bool = jvm.get_field(&this, "focused", "Z").await?;
+        let input_method = Self::input_method(jvm, &this).await?;
+
+        let (width, height) = Component::size(jvm, &this).await?;
+
+        let (mut renderer, x, y) = Component::renderer(jvm, &graphics).await?;
+        if let Some(input_method) = context.system().input_methods().get(input_method) {
+            renderer.draw_text_editor(x, y, width, height, input_method, constraint & PASSWORD != 0, focused);
+        }
+
+        Ok(())
+    }
+
+    async fn store_text(jvm: &Jvm, context: &mut WieJvmContext, mut this: ClassInstanceRef<TextComponent>, text: &str) -> JvmResult<()> {
+        let input_method = Self::input_method(jvm, &this).await?;
+        let cursor = context.system().input_methods().get(input_method).map(|x| {
+            x.set_text(text);
+            x.cursor()
+        });
+
+        if let Some(cursor) = cursor {
+            jvm.put_field(&mut this, "m_cPos", "I", cursor as i32).await?;
+        }
+        renderer::request_redraw(context);
+
+        Ok(())
+    }
+
+    async fn input_method(jvm: &Jvm, this: &ClassInstanceRef<TextComponent>) -> JvmResult<InputMethodHandle> {
+        let im_handler: ClassInstanceRef<InputMethodHandler> = jvm.get_field(this, "imHandler", "Lorg/kwis/msp/lcdui/InputMethodHandler;").await?;
+
+        InputMethodHandler::input_method(jvm, &im_handler).await
+    }
 }
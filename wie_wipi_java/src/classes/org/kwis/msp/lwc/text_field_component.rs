@@ -28,14 +28,17 @@ impl TextFieldComponent {
         data: ClassInstanceRef<String>,
         constraint: i32,
     ) -> JvmResult<()> {
-        tracing::warn!(
-            "stub org.kwis.msp.lwc.TextFieldComponent::<init>({:?}, {:?}, {:?})",
-            &this,
-            &data,
-            constraint
-        );
+        tracing::debug!("org.kwis.msp.lwc.TextFieldComponent::<init>({:?}, {:?}, {:?})", &this, &data, constraint);
 
-        let _: () = jvm.invoke_special(&this, "org/kwis/msp/lwc/TextComponent", "<init>", "()V", ()).await?;
+        let _: () = jvm
+            .invoke_special(
+                &this,
+                "org/kwis/msp/lwc/TextComponent",
+                "<init>",
+                "(Ljava/lang/String;I)V",
+                (data, constraint),
+            )
+            .await?;
 
         Ok(())
     }
@@ -6,8 +6,11 @@ pub mod classes;
 
 use wie_jvm_support::WieJavaClassProto;
 
-pub fn get_protos() -> [WieJavaClassProto; 34] {
+pub fn get_protos() -> [WieJavaClassProto; 46] {
     [
+        crate::classes::org::kwis::msf::io::Connection::as_proto(),
+        crate::classes::org::kwis::msf::io::Connector::as_proto(),
+        crate::classes::org::kwis::msf::io::HttpConnection::as_proto(),
         crate::classes::org::kwis::msf::io::Network::as_proto(),
         crate::classes::org::kwis::msf::io::SchemeNotFoundException::as_proto(),
         crate::classes::org::kwis::msp::db::DataBase::as_proto(),
@@ -28,9 +31,16 @@ pub fn get_protos() -> [WieJavaClassProto; 34] {
         crate::classes::org::kwis::msp::lcdui::Jlet::as_proto(),
         crate::classes::org::kwis::msp::lcdui::JletEventListener::as_proto(),
         crate::classes::org::kwis::msp::lwc::Component::as_proto(),
+        crate::classes::org::kwis::msp::lwc::ComponentListener::as_proto(),
         crate::classes::org::kwis::msp::lwc::ContainerComponent::as_proto(),
         crate::classes::org::kwis::msp::lwc::ShellComponent::as_proto(),
         crate::classes::org::kwis::msp::lwc::AnnunciatorComponent::as_proto(),
+        crate::classes::org::kwis::msp::lwc::PopupComponent::as_proto(),
+        crate::classes::org::kwis::msp::lwc::LabelComponent::as_proto(),
+        crate::classes::org::kwis::msp::lwc::ButtonComponent::as_proto(),
+        crate::classes::org::kwis::msp::lwc::CheckBoxComponent::as_proto(),
+        crate::classes::org::kwis::msp::lwc::ListComponent::as_proto(),
+        crate::classes::org::kwis::msp::lwc::ImageComponent::as_proto(),
         crate::classes::org::kwis::msp::lwc::TextComponent::as_proto(),
         crate::classes::org::kwis::msp::lwc::TextBoxComponent::as_proto(),
         crate::classes::org::kwis::msp::lwc::TextFieldComponent::as_proto(),
@@ -41,6 +51,8 @@ pub fn get_protos() -> [WieJavaClassProto; 34] {
         crate::classes::org::kwis::msp::media::Vibrator::as_proto(),
         crate::classes::org::kwis::msp::media::Volume::as_proto(),
         crate::classes::net::wie::CardCanvas::as_proto(),
+        crate::classes::net::wie::ShellCard::as_proto(),
+        crate::classes::net::wie::WIPIHttpConnectionImpl::as_proto(),
         crate::classes::net::wie::WIPIMIDlet::as_proto(),
     ]
 }